@@ -1,24 +1,48 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use crate::commands::advice::AdviceCommand;
+use crate::commands::aigen::AigenCommand;
 use crate::commands::api::ApiCommand;
+use crate::commands::backend::BackendCommand;
+use crate::commands::bench::{self, BenchCommand};
+use crate::commands::branch::BranchCommand;
 use crate::commands::build::BuildCommand;
 use crate::commands::check::CheckCommand;
 use crate::commands::db::DbCommand;
+use crate::commands::demo::DemoCommand;
 use crate::commands::deploy::DeployCommand;
+use crate::commands::deps::DepsCommand;
 use crate::commands::design::DesignCommand;
 use crate::commands::dev::DevCommand;
+use crate::commands::docker::DockerCommand;
 use crate::commands::docs::DocsCommand;
 use crate::commands::fmt::FmtCommand;
 use crate::commands::function::FunctionCommand;
+use crate::commands::jobs::JobsCommand;
 use crate::commands::lint::LintCommand;
+use crate::commands::mock::MockCommand;
+use crate::commands::plugins::{self, PluginsCommand};
+use crate::commands::pr::PrCommand;
 use crate::commands::preflight::PreflightCommand;
+use crate::commands::prompts::PromptsCommand;
 use crate::commands::release::ReleaseCommand;
+use crate::commands::report::ReportCommand;
+use crate::commands::run::RunCommand;
+use crate::commands::self_update::{self, SelfUpdateCommand};
 use crate::commands::setup::SetupCommand;
+use crate::commands::stats::StatsCommand;
+use crate::commands::storage::StorageCommand;
 use crate::commands::test::TestCommand;
+use crate::commands::ui::UiCommand;
+use crate::commands::users::UsersCommand;
+use crate::commands::wasm::WasmCommand;
+use crate::utils::backend::DEFAULT_BACKEND_URL;
 use crate::utils::find_project_root;
+use crate::utils::output::{OutputFormat, OutputFormatter};
+use crate::utils::process;
 
 #[derive(Parser)]
 #[command(name = "akatsuki")]
@@ -52,6 +76,9 @@ For detailed command help, run:
 pub struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Output format (json: one JSON document on stdout, for scripts)
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
 }
 
 #[derive(Subcommand)]
@@ -83,39 +110,65 @@ enum Commands {
         /// Target to build: frontend, backend, or all (default)
         #[arg(value_enum, default_value = "all")]
         target: BuildTarget,
+        /// Print time and peak memory of each external command
+        #[arg(long)]
+        profile: bool,
     },
     /// Database operations (Supabase)
     ///
-    /// Commands: push, migration-new, check, status, link
-    #[command(about = "Database operations (push | migration-new | check | status | link)")]
+    /// Commands: push, migration-new, check, status, link, branch
+    #[command(about = "Database operations (push | migration-new | check | status | link | branch)")]
     Db {
         #[command(subcommand)]
         action: DbAction,
     },
+    /// Showcase data for a fresh deployment
+    ///
+    /// Commands: seed, clean
+    #[command(about = "Demo user, records and images (seed | clean)")]
+    Demo {
+        #[command(subcommand)]
+        action: DemoAction,
+    },
+    /// Per-branch / per-worktree development environments
+    ///
+    /// Commands: setup
+    #[command(about = "Branch environments (setup)")]
+    Branch {
+        #[command(subcommand)]
+        action: BranchAction,
+    },
     /// Edge Function operations (Supabase)
     ///
-    /// Commands: new, deploy
-    #[command(about = "Edge Function operations (new | deploy)")]
+    /// Commands: new, deploy, schedule, schedules, unschedule
+    #[command(
+        about = "Edge Function operations (new | deploy | schedule | schedules | unschedule)"
+    )]
     Function {
         #[command(subcommand)]
         action: FunctionAction,
     },
     /// HEADLESS API Generator
     ///
-    /// Commands: new, list, delete
-    #[command(about = "HEADLESS API Generator (new | list | delete)")]
+    /// Commands: new, list, delete, new-endpoint
+    #[command(about = "HEADLESS API Generator (new | list | delete | new-endpoint)")]
     Api {
         #[command(subcommand)]
         action: ApiAction,
     },
-    /// Run type checks (tsc, cargo check)
+    /// Run type checks (tsc, cargo check), bundle budgets and the accessibility audit
     ///
-    /// Targets: frontend | backend | cli | admin-cli | all (default)
-    #[command(about = "Run type checks [frontend | backend | cli | admin-cli | all]")]
+    /// Targets: frontend | backend | cli | admin-cli | budget | a11y | all (default)
+    #[command(
+        about = "Run type checks [frontend | backend | cli | admin-cli | budget | a11y | all]"
+    )]
     Check {
         /// Target to check
         #[arg(value_enum, default_value = "all")]
         target: CheckTarget,
+        /// Print time and peak memory of each external command
+        #[arg(long)]
+        profile: bool,
     },
     /// Run linters (eslint, clippy)
     ///
@@ -146,14 +199,24 @@ enum Commands {
         /// Target for preflight checks
         #[arg(value_enum, default_value = "all")]
         target: PreflightTarget,
+        /// Print time and peak memory of each external command
+        #[arg(long)]
+        profile: bool,
+        /// Re-run lint / check / test even when their inputs are unchanged
+        #[arg(long)]
+        force: bool,
+        /// First check generated API code against the entity schemas (`api verify`)
+        #[arg(long)]
+        verify_api: bool,
     },
     /// Run tests
     ///
-    /// Targets: frontend | backend | all (default)
-    /// Options: -w (watch), --ui (UI dashboard), --coverage (coverage report)
-    #[command(about = "Run tests [frontend | backend | all]")]
+    /// Targets: frontend | backend | all (default) | visual
+    /// Options: -w (watch), --ui (UI dashboard), --coverage (coverage report),
+    /// --update (accept visual changes)
+    #[command(about = "Run tests [frontend | backend | all | visual]")]
     Test {
-        /// Target to test: frontend, backend, or all (default)
+        /// Target to test: frontend, backend, all (default), or visual
         #[arg(value_enum, default_value = "all")]
         target: TestTarget,
         /// Watch mode (re-run tests on file changes)
@@ -165,6 +228,9 @@ enum Commands {
         /// Generate coverage report
         #[arg(long)]
         coverage: bool,
+        /// Visual: replace the baselines with the current screenshots
+        #[arg(long)]
+        update: bool,
     },
     /// Deploy the project
     Deploy {
@@ -172,9 +238,17 @@ enum Commands {
         #[arg(value_enum, default_value = "all")]
         target: DeployTarget,
     },
+    /// Local container stack: Postgres + app-backend + frontend preview
+    ///
+    /// Commands: init, build, up
+    #[command(about = "Docker stack for demos and offline work (init | build | up)")]
+    Docker {
+        #[command(subcommand)]
+        action: DockerAction,
+    },
     /// Browse project documentation
     ///
-    /// Commands: all, components, models, repositories, services, hooks, pages
+    /// Commands: all, components, models, repositories, services, hooks, pages, routes
     #[command(about = "Browse project documentation (all | components | models | ...)")]
     Docs {
         #[command(subcommand)]
@@ -185,12 +259,197 @@ enum Commands {
     },
     /// Get contextual development advice
     ///
-    /// Commands: rule, prompt, ai
-    #[command(about = "Get contextual development advice (rule | prompt | ai)")]
+    /// Commands: rule, prompt, ai, report
+    #[command(about = "Get contextual development advice (rule | prompt | ai | report)")]
     Advice {
         #[command(subcommand)]
         action: AdviceAction,
     },
+    /// Reusable prompt snippets in workspace/prompts/
+    ///
+    /// Commands: list, new, render
+    #[command(about = "Prompt library in workspace/prompts (list | new | render)")]
+    Prompts {
+        #[command(subcommand)]
+        action: PromptsAction,
+    },
+    /// Manage backend background jobs (admin)
+    ///
+    /// Commands: list, retry, cancel, purge
+    #[command(about = "Manage backend background jobs (list | retry | cancel | purge)")]
+    Jobs {
+        #[command(subcommand)]
+        action: JobsAction,
+        /// Backend base URL
+        #[arg(
+            long,
+            global = true,
+            env = "AKATSUKI_BACKEND_URL",
+            default_value = DEFAULT_BACKEND_URL
+        )]
+        url: String,
+        /// Admin access token (JWT with app_metadata.role = admin, or the service role key)
+        #[arg(
+            long,
+            global = true,
+            env = "AKATSUKI_ADMIN_TOKEN",
+            hide_env_values = true
+        )]
+        token: Option<String>,
+    },
+    /// Manage Supabase Auth users (admin)
+    ///
+    /// Commands: list, invite, set-role, disable
+    #[command(about = "Manage Supabase Auth users (list | invite | set-role | disable)")]
+    Users {
+        #[command(subcommand)]
+        action: UsersAction,
+    },
+    /// Manage Supabase Storage objects
+    ///
+    /// Commands: ls, upload, download, sign
+    #[command(about = "Manage Supabase Storage objects (ls | upload | download | sign)")]
+    Storage {
+        #[command(subcommand)]
+        action: StorageAction,
+    },
+    /// Inspect AI generation quality (admin)
+    ///
+    /// Commands: feedback-stats
+    #[command(about = "Inspect AI generation quality (feedback-stats)")]
+    Aigen {
+        #[command(subcommand)]
+        action: AigenAction,
+        /// Backend base URL
+        #[arg(
+            long,
+            global = true,
+            env = "AKATSUKI_BACKEND_URL",
+            default_value = DEFAULT_BACKEND_URL
+        )]
+        url: String,
+        /// Admin access token (JWT with app_metadata.role = admin, or the service role key)
+        #[arg(
+            long,
+            global = true,
+            env = "AKATSUKI_ADMIN_TOKEN",
+            hide_env_values = true
+        )]
+        token: Option<String>,
+    },
+    /// Call the app-backend API (local or prod)
+    ///
+    /// Usage: akatsuki backend call /api/aigen/chat --body req.json
+    #[command(about = "Call the backend API (call)")]
+    Backend {
+        #[command(subcommand)]
+        action: BackendAction,
+    },
+    /// Load-test the app-backend before deploys
+    ///
+    /// Usage: akatsuki bench backend --route /health --concurrency 50 --duration 30s
+    #[command(about = "Benchmark the backend (backend)")]
+    Bench {
+        #[command(subcommand)]
+        action: BenchAction,
+    },
+    /// Serve a local mock of the backend API
+    ///
+    /// Responses come from mocks/*.json fixtures, falling back to examples
+    /// synthesized from packages/app-backend/openapi.yaml
+    #[command(about = "Local mock API from mocks/*.json + the OpenAPI contract")]
+    Mock {
+        /// Port to listen on
+        #[arg(long, short, default_value = "8000")]
+        port: u16,
+        /// Fixture directory (default: <project>/mocks)
+        #[arg(long)]
+        dir: Option<PathBuf>,
+        /// OpenAPI spec (default: <project>/packages/app-backend/openapi.yaml)
+        #[arg(long)]
+        spec: Option<PathBuf>,
+        /// Delay added to every response in milliseconds (fixtures can override)
+        #[arg(long, default_value = "0")]
+        latency: u64,
+    },
+    /// WASM module registry and frontend loader generation
+    ///
+    /// Commands: list, loader
+    #[command(about = "WASM modules (list | loader)")]
+    Wasm {
+        #[command(subcommand)]
+        action: WasmAction,
+    },
+    /// Dependency maintenance across cargo crates and npm workspaces
+    ///
+    /// Commands: outdated, update, audit, licenses
+    #[command(about = "Dependency maintenance (outdated | update | audit | licenses)")]
+    Deps {
+        #[command(subcommand)]
+        action: DepsAction,
+    },
+    /// External plugins (`akatsuki-<name>` executables on PATH)
+    ///
+    /// Commands: list
+    #[command(about = "External plugins on PATH (list)")]
+    Plugins {
+        #[command(subcommand)]
+        action: PluginsAction,
+    },
+    /// GitHub pull request helpers (uses the gh CLI)
+    ///
+    /// Commands: create, check
+    #[command(about = "Pull request helpers (create | check)")]
+    Pr {
+        #[command(subcommand)]
+        action: PrAction,
+    },
+    /// Aggregate check / lint / test / docs lint / db check into one report
+    ///
+    /// Commands: ci
+    #[command(about = "Generate reports (ci)")]
+    Report {
+        #[command(subcommand)]
+        action: ReportAction,
+    },
+    /// Run tasks from akatsuki.toml [tasks.<name>] in dependency order
+    ///
+    /// `build` runs `build` or every `<package>:build` task. Tasks whose input
+    /// files are unchanged since their last successful run are skipped.
+    #[command(about = "Run akatsuki.toml tasks with dependencies and caching")]
+    Run {
+        /// Task name (lists the defined tasks when omitted)
+        task: Option<String>,
+
+        /// Only run tasks whose inputs changed since the base branch (and their dependents)
+        #[arg(long)]
+        affected: bool,
+
+        /// Base branch for --affected (default: origin/HEAD, main or master)
+        #[arg(long, requires = "affected")]
+        base: Option<String>,
+
+        /// Ignore the cache and run every selected task
+        #[arg(long)]
+        force: bool,
+
+        /// Show which tasks would run without running them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Show which commands dominate your workflow time (opt-in telemetry)
+    ///
+    /// Enable with `[telemetry] enabled = true` in akatsuki.toml or AKATSUKI_TELEMETRY=1
+    #[command(about = "Command usage and time breakdown (from opt-in telemetry)")]
+    Stats {
+        /// Only include the last N days
+        #[arg(long)]
+        days: Option<u32>,
+    },
+    /// Terminal dashboard: dev servers, check/lint/test results, pending
+    /// migrations, docs coverage and advice, with keys to run each command
+    #[command(about = "Interactive terminal dashboard for the VibeCoding loop")]
+    Ui,
     /// Generate shell completion script
     ///
     /// Usage: akatsuki completion zsh > ~/.zsh/completions/_akatsuki
@@ -203,40 +462,170 @@ enum Commands {
     #[command(about = "List all available commands")]
     List,
     /// Install akatsuki CLI globally (cargo install --path packages/akatsuki-cli)
+    ///
+    /// With --from-release, downloads the prebuilt binary from GitHub releases
+    /// instead (no repository checkout or Rust toolchain needed).
     #[command(about = "Install akatsuki CLI globally")]
-    Install,
-    /// Release CLI: update version, create tag, and push
+    Install {
+        /// Download the prebuilt binary of a GitHub release (latest stable without VERSION)
+        #[arg(long, value_name = "VERSION", num_args = 0..=1, default_missing_value = "latest")]
+        from_release: Option<String>,
+        /// Install into <PREFIX>/bin (default: ~/.cargo/bin)
+        #[arg(long)]
+        prefix: Option<PathBuf>,
+    },
+    /// Update akatsuki to the newest GitHub release (prebuilt binary)
+    #[command(about = "Update the akatsuki binary from GitHub releases (--channel beta for prereleases)")]
+    SelfUpdate {
+        /// Release channel: stable, or beta to also get prereleases
+        #[arg(long, value_enum, default_value = "stable")]
+        channel: ReleaseChannel,
+        /// Install this version instead of the newest (e.g., 1.4.0-beta.1)
+        #[arg(long)]
+        version: Option<String>,
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Release CLI: update version, create tag, push, and verify the GitHub release
     ///
-    /// Usage: akatsuki release --version 1.0.0
-    #[command(about = "Release CLI (update version, tag, push)")]
+    /// Usage: akatsuki release 1.0.0 (beta: akatsuki release 1.4.0-beta.1 --channel beta)
+    #[command(about = "Release CLI (update version, tag, push, verify release)")]
     Release {
-        /// New version (e.g., 1.0.0)
-        #[arg(long, short)]
-        version: String,
+        /// New version (e.g., 1.0.0 or 1.4.0-beta.1)
+        #[arg(value_name = "VERSION", required_unless_present = "version")]
+        new_version: Option<String>,
+        /// New version (same as the positional VERSION)
+        #[arg(long, short, conflicts_with = "new_version")]
+        version: Option<String>,
+        /// Release channel: beta publishes a GitHub prerelease
+        #[arg(long, value_enum, default_value = "stable")]
+        channel: ReleaseChannel,
         /// Skip confirmation prompt
         #[arg(long)]
         yes: bool,
+        /// Only wait for and verify the GitHub release of an already pushed tag
+        #[arg(long)]
+        verify_only: bool,
+        /// Show what the release would do without changing anything
+        #[arg(long, conflicts_with = "verify_only")]
+        dry_run: bool,
     },
+    /// Any other subcommand runs the `akatsuki-<name>` plugin
+    #[command(external_subcommand)]
+    External(Vec<String>),
+}
+
+impl Commands {
+    /// Commands with a structured result for `--output json`
+    fn supports_json(&self) -> bool {
+        matches!(
+            self,
+            Commands::Docs {
+                action: DocsAction::All
+                    | DocsAction::Components
+                    | DocsAction::Models
+                    | DocsAction::Repositories
+                    | DocsAction::Services
+                    | DocsAction::Hooks
+                    | DocsAction::Pages
+                    | DocsAction::Routes
+                    | DocsAction::Lint { .. }
+                    | DocsAction::ContextSize { .. },
+                ..
+            } | Commands::Db {
+                action: DbAction::Check
+                    | DbAction::Branch {
+                        action: DbBranchAction::List
+                    }
+            } | Commands::Api {
+                action: ApiAction::Check { .. } | ApiAction::List | ApiAction::Verify { .. }
+            } | Commands::Advice {
+                action: AdviceAction::Rule { task: None, .. }
+            } | Commands::Setup {
+                action: SetupAction::Check { fix: false, .. }
+            } | Commands::Bench { .. }
+                | Commands::Users {
+                    action: UsersAction::List { .. }
+                }
+                | Commands::Storage {
+                    action: StorageAction::Ls { .. } | StorageAction::Sign { .. }
+                }
+                | Commands::Function {
+                    action: FunctionAction::Schedules
+                }
+                | Commands::Design {
+                    action: DesignAction::Status { set: None, .. }
+                }
+                | Commands::Report {
+                    action: ReportAction::Ci { .. }
+                }
+                | Commands::Deps {
+                    action: DepsAction::Outdated | DepsAction::Audit | DepsAction::Licenses { .. }
+                }
+                | Commands::Jobs {
+                    action: JobsAction::List { .. },
+                    ..
+                }
+                | Commands::Wasm {
+                    action: WasmAction::List
+                }
+                | Commands::Plugins {
+                    action: PluginsAction::List
+                }
+                | Commands::Stats { .. }
+        )
+    }
 }
 
 #[derive(Subcommand)]
 pub enum DesignAction {
     /// Create new design document
+    ///
+    /// The document starts with YAML front matter (feature, template, status,
+    /// owner, entities, theme, dates) read by `design status` and `advice`.
     New {
         /// Feature name in kebab-case (e.g., user-dashboard)
         feature_name: String,
         /// Theme to apply (e.g., corporate-blue, minimal-dark)
         #[arg(long, short)]
         theme: Option<String>,
+        /// Document variant
+        #[arg(long, value_enum, default_value = "page")]
+        template: DesignTemplate,
+        /// Owner (default: git user.name)
+        #[arg(long)]
+        owner: Option<String>,
+        /// Linked entity, repeatable (e.g., --entity Article --entity Comment)
+        #[arg(long = "entity", value_name = "ENTITY")]
+        entities: Vec<String>,
+    },
+    /// Show the status of workspace design documents, or change one
+    Status {
+        /// Feature name (omit to list every document in workspace/)
+        feature_name: Option<String>,
+        /// New status
+        #[arg(long, value_enum, requires = "feature_name")]
+        set: Option<DesignStatus>,
     },
     /// List all design examples
     List,
     /// Copy an example design interactively
     Use,
     /// Publish design to examples
+    ///
+    /// Referenced images are copied to docs/examples/<feature>/, a screenshot
+    /// of the running route is captured when a headless Chrome / Chromium /
+    /// Edge is installed, and docs/examples/README.md is regenerated.
     Publish {
         /// Feature name in kebab-case (e.g., user-dashboard)
         feature_name: String,
+        /// Page to capture (default: http://localhost:5173/<feature-name>)
+        #[arg(long)]
+        url: Option<String>,
+        /// Skip the screenshot
+        #[arg(long, conflicts_with = "url")]
+        no_screenshot: bool,
     },
     /// List all available themes
     Themes,
@@ -258,10 +647,37 @@ pub enum DesignAction {
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DesignTemplate {
+    /// Screens, routing, theme and layers (default)
+    Page,
+    /// Entities, endpoints, errors and RLS
+    Api,
+    /// Trigger, steps, state and failure handling
+    Workflow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DesignStatus {
+    Draft,
+    InProgress,
+    Completed,
+}
+
 #[derive(Subcommand)]
 pub enum SetupAction {
     /// Check setup status and prerequisites
-    Check,
+    Check {
+        /// Print the check results as JSON
+        #[arg(long, conflicts_with = "fix")]
+        json: bool,
+
+        /// Offer to run the corrective command for each failed check
+        #[arg(long)]
+        fix: bool,
+    },
     /// Interactive setup wizard for new projects
     Init,
 }
@@ -286,10 +702,57 @@ pub enum BuildTarget {
     All,
 }
 
+#[derive(Subcommand)]
+pub enum BranchAction {
+    /// Prepare this worktree: copy env files, link the branch's Supabase project, seed
+    ///
+    /// The Supabase project is --project-ref, `[branch.projects]` in akatsuki.toml,
+    /// or a Supabase preview branch when `[branch] supabase_branching = true`.
+    Setup {
+        /// Checkout to copy env files from (default: the main worktree)
+        #[arg(long)]
+        from: Option<PathBuf>,
+        /// Link to this Supabase project instead of the configured one
+        #[arg(long)]
+        project_ref: Option<String>,
+        /// Overwrite env files that already exist in this worktree
+        #[arg(long)]
+        force: bool,
+        /// Don't push migrations and supabase/seed.sql
+        #[arg(long)]
+        no_seed: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DemoAction {
+    /// Create the demo user, seed records for every entity with a factory and
+    /// upload sample images (SUPABASE_URL / SUPABASE_SERVICE_ROLE_KEY from the
+    /// environment or packages/app-backend/.env)
+    Seed {
+        /// Records per entity (default: `records` in [demo] of akatsuki.toml)
+        #[arg(long, short)]
+        records: Option<usize>,
+        /// Skip the confirmation when the URL points at the production project
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Remove the records, images and user created by `demo seed`
+    Clean {
+        /// Skip the confirmation when the URL points at the production project
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum DbAction {
     /// Push local migrations to remote database
-    Push,
+    Push {
+        /// Push to a Supabase database branch instead of the linked project
+        #[arg(long)]
+        branch: Option<String>,
+    },
     /// Create a new migration file
     MigrationNew {
         /// Migration name
@@ -301,6 +764,123 @@ pub enum DbAction {
     Status,
     /// Link to Supabase project
     Link,
+    /// Disposable database branches (Supabase Branching)
+    Branch {
+        #[command(subcommand)]
+        action: DbBranchAction,
+    },
+    /// Open psql with DATABASE_URL from packages/app-backend/.env
+    Console {
+        /// Skip the confirmation when the URL points at the production project
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Run one SQL statement with psql (DATABASE_URL from packages/app-backend/.env)
+    Query {
+        /// SQL statement
+        sql: String,
+        /// Print the rows as a JSON array (SELECT statements)
+        #[arg(long, conflicts_with = "csv")]
+        json: bool,
+        /// Print the rows as CSV
+        #[arg(long)]
+        csv: bool,
+        /// Skip the confirmation when the URL points at the production project
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Generate an entity-relationship diagram from the migrations
+    ///
+    /// Writes docs/database/erd.md (Mermaid) or erd.puml (PlantUML); existing
+    /// files are regenerated by `akatsuki docs sync`.
+    Erd {
+        /// Diagram format
+        #[arg(long, value_enum, default_value = "mermaid")]
+        format: ErdFormat,
+        /// Output file (default: docs/database/erd.md or erd.puml)
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Read the schema of the linked database (supabase db dump) instead of the migrations
+        #[arg(long)]
+        linked: bool,
+        /// Print the diagram instead of writing the file
+        #[arg(long)]
+        stdout: bool,
+    },
+    /// Propose ALTER statements for drift between the entity schemas (YAML) and the migrations
+    ///
+    /// Destructive changes (DROP COLUMN) are written commented out.
+    Plan {
+        /// Entity schema files (default: every YAML with `tableName:` in the project)
+        files: Vec<PathBuf>,
+        /// Compare with the linked database (supabase db dump) instead of the migrations
+        #[arg(long)]
+        linked: bool,
+        /// Write the plan to supabase/migrations/<timestamp>_schema_drift.sql
+        #[arg(long)]
+        write: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ErdFormat {
+    Mermaid,
+    Plantuml,
+}
+
+#[derive(Subcommand)]
+pub enum DbBranchAction {
+    /// Create a database branch
+    Create {
+        /// Branch name
+        name: String,
+        /// Link this checkout to the new branch
+        #[arg(long)]
+        switch: bool,
+    },
+    /// List database branches
+    List,
+    /// Delete a database branch
+    Delete {
+        /// Branch name
+        name: String,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Link this checkout to a branch (or back to the parent project)
+    Switch {
+        /// Branch name
+        #[arg(required_unless_present = "parent")]
+        name: Option<String>,
+        /// Switch back to the parent (production) project
+        #[arg(long, conflicts_with = "name")]
+        parent: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DockerAction {
+    /// Generate Dockerfiles (app-backend, frontend preview) and docker-compose.yml
+    Init {
+        /// Overwrite existing files
+        #[arg(long)]
+        force: bool,
+    },
+    /// Build the images (docker compose build)
+    Build {
+        /// Services to build (db, backend, frontend; all when omitted)
+        services: Vec<String>,
+    },
+    /// Start the stack (docker compose up)
+    Up {
+        /// Run in the background
+        #[arg(short, long)]
+        detach: bool,
+        /// Rebuild the images before starting
+        #[arg(long)]
+        build: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -315,6 +895,33 @@ pub enum FunctionAction {
         /// Function name (optional, deploys all if omitted)
         name: Option<String>,
     },
+    /// Invoke an edge function on a cron schedule (writes a pg_cron migration)
+    Schedule {
+        /// Function name (supabase/functions/<name>)
+        name: String,
+        /// Cron expression in UTC, e.g. "0 3 * * *", or "30 seconds"
+        #[arg(long)]
+        cron: String,
+        /// Job name (default: the function name)
+        #[arg(long)]
+        job: Option<String>,
+        /// JSON request body
+        #[arg(long, default_value = "{}")]
+        body: String,
+        /// Print the migration without writing it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// List the cron jobs created by the migrations
+    Schedules,
+    /// Remove a cron job (writes a migration)
+    Unschedule {
+        /// Job name (the function name unless scheduled with --job)
+        job: String,
+        /// Print the migration without writing it
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -328,7 +935,11 @@ pub enum CheckTarget {
     /// Check admin-cli only (cargo check)
     #[value(name = "admin-cli")]
     AdminCli,
-    /// Check all targets
+    /// Check frontend bundle sizes against akatsuki.toml budgets (runs vite build)
+    Budget,
+    /// Audit the [a11y] routes and wired admin pages with axe-core (headless Chrome)
+    A11y,
+    /// Check all type check targets (budget and a11y run separately)
     All,
 }
 
@@ -385,6 +996,8 @@ pub enum TestTarget {
     Backend,
     /// Test both frontend and backend
     All,
+    /// Screenshot the `[visual]` routes and diff them against the baselines
+    Visual,
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -421,16 +1034,326 @@ pub enum DocsAction {
     Hooks,
     /// List all page components
     Pages,
+    /// Print frontend/backend route table and flag unregistered generated pages
+    Routes,
     /// Check documentation coverage and list undocumented files
-    Lint,
+    ///
+    /// Results are cached per file (mtime + size) in .akatsuki/cache/docs.json
+    Lint {
+        /// Re-read every file instead of trusting the cache
+        #[arg(long)]
+        no_cache: bool,
+    },
     /// Sync component list to documentation file (e.g., AGENT.md)
+    ///
+    /// Updates the SYNC:COMPONENTS section of every `[docs] sync_targets` file
+    /// in akatsuki.toml (default: AGENT.md)
     Sync {
-        /// Target file to update
-        #[arg(long, default_value = "AGENT.md")]
-        target: String,
+        /// Target file to update (instead of the configured targets)
+        #[arg(long)]
+        target: Option<String>,
         /// Show diff without applying changes
         #[arg(long)]
         dry_run: bool,
+        /// Keep running and re-sync whenever the frontend sources or migrations change
+        #[arg(long, short, conflicts_with = "dry_run")]
+        watch: bool,
+    },
+    /// Estimate the token size of AI context files (AGENT.md etc.) against budgets
+    ///
+    /// Files and budgets come from `[docs]` in akatsuki.toml; files over budget
+    /// get trim suggestions (sections with missing paths or long unchanged)
+    ContextSize {
+        /// Files to measure (default: `[docs] context_files`)
+        files: Vec<String>,
+        /// Token budget per file (overrides akatsuki.toml)
+        #[arg(long)]
+        budget: Option<usize>,
+    },
+    /// Index markdown docs into the backend for RAG (`/api/aigen/ask-docs`)
+    Index {
+        /// Files or directories to index (relative to project root)
+        #[arg(default_values_t = ["docs".to_string(), "AGENT.md".to_string()])]
+        paths: Vec<String>,
+        /// Backend base URL
+        #[arg(long, env = "AKATSUKI_BACKEND_URL", default_value = DEFAULT_BACKEND_URL)]
+        url: String,
+        /// Admin access token (JWT with app_metadata.role = admin, or the service role key)
+        #[arg(long, env = "AKATSUKI_ADMIN_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+        /// Embedding namespace
+        #[arg(long, default_value = "project-docs")]
+        namespace: String,
+        /// Remove previously indexed documents that no longer exist
+        #[arg(long)]
+        prune: bool,
+        /// Do not wait for the indexing job to finish
+        #[arg(long)]
+        no_wait: bool,
+        /// List files that would be indexed without uploading
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BackendAction {
+    /// Send a request and pretty-print the response with latency
+    Call {
+        /// Route path including query string (e.g., /api/admin/jobs?status=failed)
+        route: String,
+        /// JSON request body file ("-" reads stdin)
+        #[arg(long, short)]
+        body: Option<PathBuf>,
+        /// HTTP method (default: post with --body, otherwise get)
+        #[arg(long, short, value_enum)]
+        method: Option<HttpMethod>,
+        /// Target environment
+        #[arg(long, value_enum, default_value = "local")]
+        env: BackendEnv,
+        /// Override the base URL resolved from --env
+        #[arg(long)]
+        url: Option<String>,
+        /// Override the access token resolved from --env
+        #[arg(long)]
+        token: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BenchAction {
+    /// Repeat one request from concurrent workers and report latency
+    /// percentiles, throughput and the error rate
+    Backend {
+        /// Route path including query string
+        #[arg(long, default_value = "/health")]
+        route: String,
+        /// Number of workers sending requests back to back
+        #[arg(long, short, default_value = "50")]
+        concurrency: usize,
+        /// How long to send requests (e.g. 30s, 2m, 500ms)
+        #[arg(long, short, default_value = "30s", value_parser = bench::parse_duration)]
+        duration: std::time::Duration,
+        /// HTTP method (default: post with --body, otherwise get)
+        #[arg(long, short, value_enum)]
+        method: Option<HttpMethod>,
+        /// JSON request body file ("-" reads stdin)
+        #[arg(long, short)]
+        body: Option<PathBuf>,
+        /// Target environment
+        #[arg(long, value_enum, default_value = "local")]
+        env: BackendEnv,
+        /// Override the base URL resolved from --env
+        #[arg(long)]
+        url: Option<String>,
+        /// Override the access token resolved from --env
+        #[arg(long)]
+        token: Option<String>,
+        /// Exit non-zero when the error rate exceeds this percentage
+        #[arg(long)]
+        max_error_rate: Option<f64>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum BackendEnv {
+    /// AKATSUKI_BACKEND_URL (default: http://localhost:8000) + AKATSUKI_ADMIN_TOKEN
+    Local,
+    /// AKATSUKI_BACKEND_PROD_URL (or VITE_API_BASE_URL in app-frontend/.env.production)
+    /// + AKATSUKI_PROD_ADMIN_TOKEN
+    Prod,
+}
+
+#[derive(Subcommand)]
+pub enum WasmAction {
+    /// List modules in wasm-modules/ with their build status and exported functions
+    List,
+    /// Generate packages/app-frontend/src/wasm/index.ts from the built modules
+    Loader {
+        /// Fail if the generated loader is out of date instead of writing it
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PluginsAction {
+    /// List plugins discovered on PATH
+    List,
+}
+
+#[derive(Subcommand)]
+pub enum DepsAction {
+    /// List outdated dependencies (cargo outdated + npm outdated)
+    Outdated,
+    /// Apply safe version bumps (patch only unless --minor)
+    Update {
+        /// Also apply minor bumps (0.x minor bumps are treated as major and skipped)
+        #[arg(long)]
+        minor: bool,
+        /// Print the update commands without running them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Fail on known vulnerabilities (cargo audit + npm audit)
+    Audit,
+    /// Report third-party licenses and fail on licenses denied in akatsuki.toml
+    Licenses {
+        /// Include npm devDependencies (not shipped in the production bundle)
+        #[arg(long)]
+        include_dev: bool,
+        /// Write a NOTICE file (default path: NOTICE in the project root)
+        #[arg(long, num_args = 0..=1, default_missing_value = "NOTICE")]
+        notice: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum JobsAction {
+    /// List jobs (newest first)
+    List {
+        /// Filter by status (queued, generating, completed, failed, cancelled)
+        #[arg(long)]
+        status: Option<String>,
+        /// Filter by job kind (e.g., upscale, docs-index)
+        #[arg(long)]
+        kind: Option<String>,
+        /// Sort field (created_at, updated_at, kind, status); prefix with - for descending
+        #[arg(long, allow_hyphen_values = true)]
+        sort: Option<String>,
+        /// Page number (1-based)
+        #[arg(long, default_value = "1")]
+        page: u32,
+        /// Jobs per page (max 100)
+        #[arg(long, default_value = "50")]
+        limit: u32,
+    },
+    /// Retry a failed or cancelled job
+    Retry {
+        /// Job ID
+        id: String,
+    },
+    /// Cancel a queued or running job
+    Cancel {
+        /// Job ID
+        id: String,
+    },
+    /// Remove finished jobs older than the given age
+    Purge {
+        /// Minimum age in hours
+        #[arg(long, default_value = "24")]
+        older_than_hours: u32,
+        /// Only purge jobs with this status (completed, failed, cancelled)
+        #[arg(long)]
+        status: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum UsersAction {
+    /// List users with their role and status
+    List {
+        /// Only users with this app_metadata.role
+        #[arg(long)]
+        role: Option<String>,
+        /// Only disabled (banned) users
+        #[arg(long)]
+        disabled: bool,
+    },
+    /// Send an invitation email
+    Invite {
+        /// Email address
+        email: String,
+        /// Role (app_metadata.role) set right after the invitation
+        #[arg(long)]
+        role: Option<String>,
+        /// Page the invitation link opens (must be an allowed redirect URL)
+        #[arg(long)]
+        redirect_to: Option<String>,
+        /// Skip the confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Set or clear a user's role (app_metadata.role)
+    SetRole {
+        /// Email address or user ID
+        user: String,
+        /// New role (e.g., admin)
+        #[arg(required_unless_present = "clear")]
+        role: Option<String>,
+        /// Remove the role instead
+        #[arg(long, conflicts_with = "role")]
+        clear: bool,
+        /// Skip the confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Block a user from signing in (existing sessions end when their token expires)
+    Disable {
+        /// Email address or user ID
+        user: String,
+        /// Allow the user to sign in again
+        #[arg(long)]
+        enable: bool,
+        /// Skip the confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum StorageAction {
+    /// List objects under a bucket or folder
+    Ls {
+        /// <bucket>[/<prefix>]
+        target: String,
+        /// Every object below the prefix instead of one level
+        #[arg(short, long)]
+        recursive: bool,
+    },
+    /// Upload files; existing objects are replaced
+    ///
+    /// Usage: akatsuki storage upload assets/images 'public/**/*.png' logo.svg
+    Upload {
+        /// Destination <bucket>[/<prefix>]
+        target: String,
+        /// Files, directories (their contents) or quoted globs (`*`, `?`, `**`)
+        #[arg(required = true)]
+        sources: Vec<String>,
+        /// Transfers at a time
+        #[arg(short, long, default_value = "4")]
+        jobs: usize,
+    },
+    /// Download an object, or every object under a prefix
+    Download {
+        /// <bucket>/<path> or <bucket>[/<prefix>]
+        target: String,
+        /// File or directory to write to (default: the current directory)
+        dest: Option<PathBuf>,
+        /// Transfers at a time
+        #[arg(short, long, default_value = "4")]
+        jobs: usize,
+    },
+    /// Print a signed URL for an object (works for private buckets)
+    Sign {
+        /// <bucket>/<path>
+        target: String,
+        /// Seconds until the URL expires
+        #[arg(long, default_value = "3600")]
+        expires_in: u64,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AigenAction {
+    /// Thumbs up/down totals per job kind and the most used feedback tags
+    FeedbackStats {
+        /// Only this job kind / endpoint (e.g., upscale, agent-execute)
+        #[arg(long)]
+        kind: Option<String>,
+        /// Look-back window in days (max 365)
+        #[arg(long, default_value = "30")]
+        days: u32,
     },
 }
 
@@ -451,8 +1374,14 @@ pub enum AdviceAction {
         /// Enable test coverage checking (disabled by default for VibeCoding)
         #[arg(long)]
         enable_test_coverage: bool,
+        /// Write the prompt to this file instead of printing it
+        #[arg(long, value_name = "FILE")]
+        out: Option<PathBuf>,
     },
     /// Automatic AI invocation (requires claude command)
+    ///
+    /// The answer ends with suggested follow-up commands, which are offered
+    /// one by one and only run after confirmation.
     Ai {
         /// Optional custom question
         task: Option<String>,
@@ -462,9 +1391,160 @@ pub enum AdviceAction {
         /// Enable test coverage checking (disabled by default for VibeCoding)
         #[arg(long)]
         enable_test_coverage: bool,
+        /// Also write the answer (the prompt with --backend markdown) to this file
+        #[arg(long, value_name = "FILE")]
+        out: Option<PathBuf>,
+        /// Do not offer to run the suggested follow-up actions
+        #[arg(long)]
+        no_actions: bool,
+        /// Claude model (e.g. sonnet, opus; default from [advice.claude])
+        #[arg(long)]
+        model: Option<String>,
+        /// Maximum agentic turns for claude
+        #[arg(long)]
+        max_turns: Option<u32>,
+        /// Seconds to wait for the answer (default 600)
+        #[arg(long, value_name = "SECS")]
+        timeout: Option<u64>,
+    },
+    /// Send the rule-based analysis to a Slack / Discord webhook (e.g. daily from CI)
+    ///
+    /// The webhook URL is read from the env var named in [advice.report] of
+    /// akatsuki.toml (SLACK_WEBHOOK_URL / DISCORD_WEBHOOK_URL by default).
+    Report {
+        /// Where to send the report
+        #[arg(long, value_enum)]
+        to: ReportTarget,
+        /// Append the AI answer (requires claude command)
+        #[arg(long)]
+        ai: bool,
+        /// Optional custom question for the AI answer
+        #[arg(long, requires = "ai")]
+        task: Option<String>,
+        /// Webhook URL (overrides akatsuki.toml / env)
+        #[arg(long)]
+        webhook_url: Option<String>,
+        /// Print the webhook payload instead of sending it
+        #[arg(long)]
+        dry_run: bool,
+        /// Enable test coverage checking (disabled by default for VibeCoding)
+        #[arg(long)]
+        enable_test_coverage: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ReportTarget {
+    Slack,
+    Discord,
+}
+
+#[derive(Subcommand)]
+pub enum PromptsAction {
+    /// List prompts with their descriptions and variables
+    List,
+    /// Create workspace/prompts/<name>.md from a template
+    New {
+        /// Prompt name in kebab-case (e.g., code-review)
+        name: String,
+        /// Template to start from
+        #[arg(long, short, value_enum, default_value = "blank")]
+        template: PromptTemplate,
+        /// Overwrite an existing prompt
+        #[arg(long)]
+        force: bool,
+    },
+    /// Fill in `{{variables}}` and print, copy or send the prompt
+    ///
+    /// Missing variables are asked for interactively.
+    /// Example: git diff | akatsuki prompts render review --var diff=@- --send
+    Render {
+        /// Prompt name
+        name: String,
+        /// Variable value: key=value, key=@file or key=@- (stdin)
+        #[arg(long = "var", short, value_name = "KEY=VALUE")]
+        vars: Vec<String>,
+        /// Copy the rendered prompt to the clipboard instead of printing it
+        #[arg(long)]
+        copy: bool,
+        /// Send the rendered prompt to `claude -p` and print the answer
+        #[arg(long)]
+        send: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PromptTemplate {
+    /// Empty prompt with a single {{task}}
+    Blank,
+    /// Code review of a {{diff}}
+    Review,
+    /// Feature implementation following AGENT.md
+    Feature,
+    /// Root cause and regression test for a bug
+    Bugfix,
+}
+
+#[derive(Subcommand)]
+pub enum PrAction {
+    /// Generate the PR description from the branch and run `gh pr create`
+    ///
+    /// The description lists commits, the design doc, changed entity schemas,
+    /// new migrations, `akatsuki advice` detections and `pr check` results.
+    Create {
+        /// Base branch (default: origin's default branch)
+        #[arg(long)]
+        base: Option<String>,
+        /// PR title (default: the only commit's subject, or the branch name)
+        #[arg(long)]
+        title: Option<String>,
+        /// Open as draft
+        #[arg(long)]
+        draft: bool,
+        /// Skip the advice section (runs tsc / eslint / cargo check)
+        #[arg(long)]
+        no_advice: bool,
+        /// Print the title and description without creating the PR
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Check the branch against PR rules (migrations, generated code consistency)
+    Check {
+        /// Base branch (default: origin's default branch)
+        #[arg(long)]
+        base: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ReportAction {
+    /// Run check, lint, test, docs lint and db check; write Markdown + JSON report
+    ///
+    /// Every step runs even if an earlier one fails. Under GitHub Actions the
+    /// failures are also printed as `::error file=...` annotations and the
+    /// Markdown is appended to $GITHUB_STEP_SUMMARY.
+    Ci {
+        /// Steps to skip (comma-separated)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        skip: Vec<CiStep>,
+        /// Output directory for ci.md / ci.json (relative to the project root)
+        #[arg(long, default_value = ".akatsuki/reports")]
+        out_dir: PathBuf,
+        /// Print GitHub Actions annotations even outside GitHub Actions
+        #[arg(long)]
+        annotations: bool,
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum CiStep {
+    Check,
+    Lint,
+    Test,
+    DocsLint,
+    DbCheck,
+}
+
 #[derive(Subcommand)]
 pub enum ApiAction {
     /// Generate new CRUD API from entity schema
@@ -477,25 +1557,79 @@ pub enum ApiAction {
         /// Interactive mode (prompt for fields)
         #[arg(long, short)]
         interactive: bool,
-        /// Generate from existing database types
+        /// Generate from an existing table in supabase/functions/_shared/database.types.ts
+        /// (regenerated with `supabase gen types` when the table is missing)
         #[arg(long)]
         from_db: bool,
+        /// Register the AdminPage route in App.tsx and the demo in ExamplesPage.tsx
+        #[arg(long)]
+        auto_wire: bool,
+        /// Also generate a GraphQL Edge Function (Yoga) and typed GraphQL hooks
+        #[arg(long)]
+        with_graphql: bool,
+        /// Show what would be generated (and the --auto-wire diff) without writing files
+        #[arg(long)]
+        dry_run: bool,
+        /// Overwrite hand-edited files and write a new table migration
+        #[arg(long)]
+        force: bool,
     },
     /// Batch generate multiple CRUD APIs from schema files
     Batch {
         /// Schema files (YAML) - processed in order
         #[arg(required = true)]
         files: Vec<PathBuf>,
+        /// Register the AdminPage routes in App.tsx and the demos in ExamplesPage.tsx
+        #[arg(long)]
+        auto_wire: bool,
+        /// Also generate a GraphQL Edge Function (Yoga) and typed GraphQL hooks
+        #[arg(long)]
+        with_graphql: bool,
+        /// Show what would be generated (and the --auto-wire diff) without writing files
+        #[arg(long)]
+        dry_run: bool,
+        /// Overwrite hand-edited files and write a new table migration
+        #[arg(long)]
+        force: bool,
     },
     /// List all generated APIs
     List,
+    /// Regenerate an API after its schema changed, with an ALTER TABLE migration
+    ///
+    /// Compares the schema with the one its table migration was generated from
+    /// (.akatsuki/schemas/<table>.yaml). Output: supabase/migrations/<timestamp>_update_<table>_table.sql
+    Update {
+        /// Entity name (or its table name)
+        entity_name: String,
+        /// Schema definition file (YAML); default: the project's schema for the entity
+        #[arg(long, short)]
+        schema: Option<PathBuf>,
+        /// Show the migration and the files that would be regenerated without writing them
+        #[arg(long)]
+        dry_run: bool,
+        /// Overwrite hand-edited files
+        #[arg(long)]
+        force: bool,
+    },
     /// Delete generated API files
     Delete {
-        /// Entity name to delete
+        /// Entity name to delete (or its table name)
         entity_name: String,
         /// Skip confirmation prompt
         #[arg(long, short)]
         force: bool,
+        /// Show the files, wiring and drop migration without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Check generated code against the entity schemas without writing files
+    ///
+    /// Renders every registered schema in memory and reports generated files
+    /// that are missing, edited by hand or behind their schema
+    Verify {
+        /// Show the diff between each out-of-sync file and the rendered code
+        #[arg(long)]
+        diff: bool,
     },
     /// Validate schema file(s) without generating
     Check {
@@ -503,42 +1637,144 @@ pub enum ApiAction {
         #[arg(required = true)]
         files: Vec<PathBuf>,
     },
+    /// Generate faker-based test data factories from entity schemas
+    ///
+    /// Output: packages/app-frontend/src/test/factories/<Entity>Factory.ts
+    Factories {
+        /// Schema files (YAML); references between them get `build<Entity>WithRelations`
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+        /// Show what would be generated without writing files
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Import entity schemas from a design document
+    ///
+    /// Reads ```yaml schema blocks and field tables under `### Entity: <Name>` headings.
+    /// Output: <out-dir>/<entity>-schema.yaml
+    FromDesign {
+        /// Design document (e.g., workspace/blog-design.md)
+        design_file: PathBuf,
+        /// Directory for the schema files (default: workspace/)
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+        /// Overwrite existing schema files
+        #[arg(long, short)]
+        force: bool,
+        /// Print the schemas without writing files
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Scaffold an axum endpoint module in app-backend
+    NewEndpoint {
+        /// Endpoint name (e.g., summarize-text) - becomes the module and handler name
+        name: String,
+        /// HTTP method
+        #[arg(long, short, value_enum, default_value = "post")]
+        method: HttpMethod,
+        /// Route path (e.g., /api/aigen/summarize or /api/reports/:id)
+        #[arg(long, short)]
+        path: String,
+        /// Overwrite an existing module file
+        #[arg(long, short)]
+        force: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl HttpMethod {
+    /// axum routing function name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpMethod::Get => "get",
+            HttpMethod::Post => "post",
+            HttpMethod::Put => "put",
+            HttpMethod::Patch => "patch",
+            HttpMethod::Delete => "delete",
+        }
+    }
+
+    /// Whether the request input is read from a JSON body (otherwise from the query string)
+    pub fn has_body(&self) -> bool {
+        matches!(self, HttpMethod::Post | HttpMethod::Put | HttpMethod::Patch)
+    }
+}
+
+/// CLI release channel: beta releases are GitHub prereleases (X.Y.Z-beta.N)
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ReleaseChannel {
+    Stable,
+    Beta,
+}
+
+impl ReleaseChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReleaseChannel::Stable => "stable",
+            ReleaseChannel::Beta => "beta",
+        }
+    }
 }
 
 impl Cli {
+    pub fn output(&self) -> OutputFormatter {
+        OutputFormatter::new(self.output)
+    }
+
     pub fn run(self) -> Result<()> {
+        let output = self.output();
+        if output.is_json() && !self.command.supports_json() {
+            anyhow::bail!("This command has no JSON output yet; run it without `--output json`");
+        }
+
         match self.command {
             Commands::Design { action } => {
                 let cmd = DesignCommand::new();
-                cmd.execute(action)
+                cmd.execute(action, output)
             }
             Commands::Setup { action } => {
                 let cmd = SetupCommand::new();
-                cmd.execute(action)
+                cmd.execute(action, output)
             }
             Commands::Dev { target } => {
                 let cmd = DevCommand::new();
                 cmd.execute(target)
             }
-            Commands::Build { target } => {
+            Commands::Build { target, profile } => {
                 let cmd = BuildCommand::new();
-                cmd.execute(target)
+                process::profiled(profile, || cmd.execute(target))
             }
             Commands::Db { action } => {
                 let cmd = DbCommand::new();
+                cmd.execute(action, output)
+            }
+            Commands::Demo { action } => {
+                let cmd = DemoCommand::new();
+                cmd.execute(action)
+            }
+            Commands::Branch { action } => {
+                let cmd = BranchCommand::new();
                 cmd.execute(action)
             }
             Commands::Function { action } => {
                 let cmd = FunctionCommand::new();
-                cmd.execute(action)
+                cmd.execute(action, output)
             }
             Commands::Api { action } => {
                 let cmd = ApiCommand::new();
-                cmd.execute(action)
+                cmd.execute(action, output)
             }
-            Commands::Check { target } => {
+            Commands::Check { target, profile } => {
                 let cmd = CheckCommand::new();
-                cmd.execute(target)
+                process::profiled(profile, || cmd.execute(target))
             }
             Commands::Lint { target, fix } => {
                 let cmd = LintCommand::new();
@@ -548,37 +1784,148 @@ impl Cli {
                 let cmd = FmtCommand::new();
                 cmd.execute(target)
             }
-            Commands::Preflight { target } => {
+            Commands::Preflight {
+                target,
+                profile,
+                force,
+                verify_api,
+            } => {
                 let cmd = PreflightCommand::new();
-                cmd.execute(target)
+                process::profiled(profile, || cmd.execute(target, force, verify_api))
             }
             Commands::Test {
                 target,
                 watch,
                 ui,
                 coverage,
+                update,
             } => {
                 let cmd = TestCommand::new();
-                cmd.execute(target, watch, ui, coverage)
+                cmd.execute(target, watch, ui, coverage, update)
             }
             Commands::Deploy { target } => {
                 let cmd = DeployCommand::new();
                 cmd.execute(target)
             }
+            Commands::Docker { action } => {
+                let cmd = DockerCommand::new();
+                cmd.execute(action)
+            }
             Commands::Docs { action, search } => {
                 let cmd = DocsCommand::new();
-                cmd.execute(action, search.as_deref())
+                cmd.execute(action, search.as_deref(), output)
             }
             Commands::Advice { action } => {
                 let cmd = AdviceCommand::new();
+                cmd.execute(action, output)
+            }
+            Commands::Prompts { action } => {
+                let cmd = PromptsCommand::new();
+                cmd.execute(action)
+            }
+            Commands::Jobs { action, url, token } => {
+                let cmd = JobsCommand::new(&url, token)?;
+                cmd.execute(action, output)
+            }
+            Commands::Users { action } => {
+                let cmd = UsersCommand::new();
+                cmd.execute(action, output)
+            }
+            Commands::Storage { action } => {
+                let cmd = StorageCommand::new();
+                cmd.execute(action, output)
+            }
+            Commands::Aigen { action, url, token } => {
+                let cmd = AigenCommand::new(&url, token)?;
+                cmd.execute(action)
+            }
+            Commands::Backend { action } => {
+                let cmd = BackendCommand::new();
                 cmd.execute(action)
             }
+            Commands::Bench { action } => {
+                let cmd = BenchCommand::new();
+                cmd.execute(action, output)
+            }
+            Commands::Mock {
+                port,
+                dir,
+                spec,
+                latency,
+            } => {
+                let cmd = MockCommand::new();
+                cmd.execute(port, dir, spec, latency)
+            }
+            Commands::Wasm { action } => {
+                let cmd = WasmCommand::new();
+                cmd.execute(action, output)
+            }
+            Commands::Deps { action } => {
+                let cmd = DepsCommand::new();
+                cmd.execute(action, output)
+            }
+            Commands::Plugins { action } => {
+                let cmd = PluginsCommand::new();
+                cmd.execute(action, output)
+            }
+            Commands::External(args) => plugins::run_external(args),
+            Commands::Pr { action } => {
+                let cmd = PrCommand::new();
+                cmd.execute(action)
+            }
+            Commands::Report { action } => {
+                let cmd = ReportCommand::new();
+                cmd.execute(action, output)
+            }
+            Commands::Run {
+                task,
+                affected,
+                base,
+                force,
+                dry_run,
+            } => {
+                let cmd = RunCommand::new();
+                cmd.execute(task, affected, base, force, dry_run)
+            }
+            Commands::Stats { days } => {
+                let cmd = StatsCommand::new();
+                cmd.execute(days, output)
+            }
+            Commands::Ui => {
+                let cmd = UiCommand::new();
+                cmd.execute()
+            }
             Commands::Completion { shell } => Self::generate_completion(shell),
             Commands::List => Self::list_all_commands(),
-            Commands::Install => Self::install_cli(),
-            Commands::Release { version, yes } => {
+            Commands::Install {
+                from_release,
+                prefix,
+            } => match from_release {
+                Some(version) => self_update::install_from_release(
+                    Some(version.as_str()).filter(|v| *v != "latest"),
+                    prefix.as_deref(),
+                ),
+                None => Self::install_cli(prefix),
+            },
+            Commands::SelfUpdate {
+                channel,
+                version,
+                yes,
+            } => {
+                let cmd = SelfUpdateCommand::new();
+                cmd.execute(channel, version, yes)
+            }
+            Commands::Release {
+                new_version,
+                version,
+                channel,
+                yes,
+                verify_only,
+                dry_run,
+            } => {
+                let version = new_version.or(version).unwrap_or_default();
                 let cmd = ReleaseCommand::new();
-                cmd.execute(&version, yes)
+                cmd.execute(&version, channel, yes, verify_only, dry_run)
             }
         }
     }
@@ -617,6 +1964,7 @@ impl Cli {
         println!("akatsuki check backend            # Backend (cargo check)");
         println!("akatsuki check cli                # CLI (tsc --noEmit)");
         println!("akatsuki check admin-cli          # admin-cli (cargo check)");
+        println!("akatsuki check budget             # バンドルサイズ予算チェック（akatsuki.toml）");
         println!();
 
         println!("# Lint（静的解析）");
@@ -641,6 +1989,9 @@ impl Cli {
         println!("akatsuki preflight frontend       # Frontend のみ");
         println!("akatsuki preflight backend        # Backend のみ");
         println!("akatsuki preflight admin-cli      # admin-cli のみ");
+        println!("akatsuki preflight --profile      # 外部コマンドごとの所要時間・ピークメモリを表示");
+        println!("akatsuki preflight --force        # 入力が変わっていないステップもキャッシュを無視して再実行");
+        println!("akatsuki preflight --verify-api   # 先に api verify で生成コードとスキーマのズレを確認");
         println!();
 
         println!("# テスト");
@@ -662,10 +2013,29 @@ impl Cli {
         );
         println!("akatsuki db status                # データベース状態確認");
         println!("akatsuki db link                  # Supabase プロジェクトにリンク");
+        println!("akatsuki db console               # psql で接続（packages/app-backend/.env の DATABASE_URL）");
+        println!("akatsuki db query \"select ...\"    # SQL を 1 つ実行（--json / --csv、本番 ref なら確認）");
+        println!("akatsuki db erd                   # Migration から ER 図を生成（docs/database/erd.md、--format plantuml）");
+        println!("akatsuki db plan                  # Entity YAML と Migration の差分から ALTER 文を提案（--write で Migration 化）");
+        println!("akatsuki db branch create <name>  # データベースブランチ作成（Supabase Branching）");
+        println!("akatsuki db branch list|delete|switch  # 一覧 / 削除 / リンク切替（switch --parent で本番に戻る）");
+        println!("akatsuki db push --branch <name>  # ブランチに Migration 適用");
+        println!();
+
+        println!("# デモデータ");
+        println!("akatsuki demo seed                # デモユーザー + 全エンティティのレコード（Factory）+ サンプル画像");
+        println!("akatsuki demo clean               # demo seed で作成したデータを削除");
+        println!();
+
+        println!("# ブランチ環境（git worktree ごとに分離）");
+        println!("akatsuki branch setup             # env コピー → ブランチ用 Supabase にリンク → seed");
+        println!("akatsuki branch setup --project-ref <ref>  # リンク先プロジェクトを指定");
         println!();
 
         println!("# 設計ワークフロー");
         println!("akatsuki design new <name>        # デザインドキュメント作成");
+        println!("akatsuki design new <name> --template api --entity Article  # page / api / workflow");
+        println!("akatsuki design status            # workspace のデザイン一覧（front matter）");
         println!("akatsuki design list              # デザイン例一覧");
         println!("akatsuki design use               # デザイン例をコピー");
         println!("akatsuki design publish <name>    # デザインを examples に公開");
@@ -679,12 +2049,19 @@ impl Cli {
         println!("akatsuki docs services            # Service クラス一覧");
         println!("akatsuki docs hooks               # Custom Hooks 一覧");
         println!("akatsuki docs pages               # Page コンポーネント一覧");
+        println!("akatsuki docs routes              # ルート一覧（App.tsx + axum）、未登録ページ検出");
         println!(
             "akatsuki docs lint                # ドキュメント網羅率チェック（JSDoc未記載検出）"
         );
+        println!("akatsuki docs lint --no-cache     # キャッシュを使わず全ファイルを再読込");
         println!(
             "akatsuki docs sync                # AGENT.md のコンポーネントリスト自動更新"
         );
+        println!("akatsuki docs sync --watch        # ソース変更を監視して sync_targets を自動再同期");
+        println!("akatsuki docs context-size        # AGENT.md など AI コンテキストの推定トークン数と予算超過・削減候補");
+        println!(
+            "akatsuki docs index               # docs/ を Backend に RAG インデックス（ask-docs 用）"
+        );
         println!("akatsuki docs all --search \"RAG\"  # 全レイヤー横断検索");
         println!();
 
@@ -695,32 +2072,154 @@ impl Cli {
         );
         println!("akatsuki advice ai                # AI自動分析（claude command経由）");
         println!("akatsuki advice ai --backend=markdown  # プロンプト生成のみ");
+        println!("akatsuki advice ai --out report.md     # 回答をファイルにも保存");
+        println!();
+
+        println!("# プロンプトライブラリ（workspace/prompts/）");
+        println!("akatsuki prompts list             # プロンプト一覧（説明・変数）");
+        println!("akatsuki prompts new <name> -t review  # テンプレートから作成（blank/review/feature/bugfix）");
+        println!("akatsuki prompts render <name> --var key=value  # {{{{変数}}}} を埋めて出力（--copy / --send で claude へ）");
+        println!();
+
+        println!("# HEADLESS API Generator");
+        println!(
+            "akatsuki api new <Entity> --schema <file.yaml> --auto-wire  # CRUD生成 + Route/Demo 自動登録"
+        );
+        println!(
+            "akatsuki api update <Entity>  # スキーマ変更を ALTER TABLE migration にしてコードを再生成"
+        );
+        println!(
+            "akatsuki api verify [--diff]  # 生成コードをスキーマから再レンダリングして比較（手編集・スキーマとのズレを検出）"
+        );
+        println!(
+            "akatsuki api factories <file.yaml>...  # テストデータ factory 生成 (src/test/factories, faker)"
+        );
+        println!(
+            "akatsuki api from-design <design.md>  # 設計書のエンティティ表 / YAML から workspace/<entity>-schema.yaml を生成"
+        );
+        println!();
+
+        println!("# Backend API（Rust/axum）");
+        println!(
+            "akatsuki api new-endpoint <name> --method post --path /api/...  # ハンドラー雛形生成"
+        );
         println!();
 
         println!("# Edge Functions");
         println!("akatsuki function new <name>      # Edge Function 作成");
         println!("akatsuki function deploy [name]   # Edge Function デプロイ");
+        println!("akatsuki function schedule <name> --cron \"0 3 * * *\"  # pg_cron で定期実行（migration 生成）");
+        println!("akatsuki function schedules        # 定期実行ジョブ一覧（migration から）");
+        println!("akatsuki function unschedule <job> # 定期実行を解除（migration 生成）");
+        println!();
+
+        println!("# ジョブ管理（Backend Admin API）");
+        println!("akatsuki jobs list                # ジョブ一覧（--status/--kind で絞り込み）");
+        println!("akatsuki jobs retry <id>          # 失敗したジョブを再実行");
+        println!("akatsuki jobs cancel <id>         # 実行待ち・実行中のジョブをキャンセル");
+        println!("akatsuki jobs purge               # 完了済みの古いジョブを削除");
+        println!("akatsuki aigen feedback-stats     # 生成結果への評価（👍/👎）を kind 別に集計");
+        println!();
+
+        println!("# ユーザー管理（Supabase Auth、service role key）");
+        println!("akatsuki users list               # ユーザー一覧（--role / --disabled で絞り込み）");
+        println!("akatsuki users invite <email>     # 招待メール送信（--role admin で招待と同時にロール設定）");
+        println!("akatsuki users set-role <user> admin  # app_metadata.role を設定（--clear で解除）");
+        println!("akatsuki users disable <user>     # サインイン停止（--enable で解除）");
+        println!("# 変更系は確認プロンプト（-y で省略）、実行結果は .akatsuki/audit/users.jsonl に記録");
+        println!();
+
+        println!("# Storage（Supabase Storage、service role key）");
+        println!("akatsuki storage ls <bucket>[/<prefix>]   # オブジェクト一覧（-r で再帰）");
+        println!("akatsuki storage upload <bucket>/<prefix> 'assets/**/*.png'  # glob / ディレクトリを並列アップロード（-j で同時数）");
+        println!("akatsuki storage download <bucket>/<path> [dest]  # オブジェクト、または prefix 配下をまとめて取得");
+        println!("akatsuki storage sign <bucket>/<path>     # 署名付き URL（--expires-in 秒）");
+        println!();
+
+        println!("# Backend API 呼び出し");
+        println!(
+            "akatsuki backend call <route>     # GET（--body req.json で POST）、JSON整形 + レイテンシ表示"
+        );
+        println!("akatsuki backend call <route> --env prod  # 本番 Backend を呼び出し");
+        println!("akatsuki mock                     # モック API (mocks/*.json + openapi.yaml の例) を :8000 で起動");
+        println!("akatsuki mock --latency 300       # 全レスポンスに 300ms の遅延を追加");
+        println!();
+
+        println!("# WASM モジュール");
+        println!("akatsuki wasm list                # wasm-modules/ のビルド状態と公開関数一覧");
+        println!("akatsuki wasm loader              # フロントエンド用ローダー (src/wasm/index.ts) を生成");
+        println!();
+
+        println!("# 依存関係");
+        println!("akatsuki deps outdated            # cargo/npm の古い依存を一覧（バンプ種別付き）");
+        println!("akatsuki deps update [--minor]    # 安全なバンプのみ適用（デフォルトは patch のみ）");
+        println!("akatsuki deps audit               # 既知の脆弱性があれば失敗（cargo audit + npm audit）");
+        println!("akatsuki deps licenses --notice   # ライセンス集計 + 禁止ライセンス検出 + NOTICE 出力");
         println!();
 
         println!("# デプロイ");
         println!("akatsuki deploy backend           # Backend を Shuttle にデプロイ");
         println!();
 
+        println!("# Docker（ローカル / オフライン用）");
+        println!("akatsuki docker init              # Dockerfile（backend / frontend preview）と docker-compose.yml を生成");
+        println!("akatsuki docker build             # イメージをビルド（docker compose build）");
+        println!("akatsuki docker up [-d]           # Postgres + Backend + Frontend preview を起動");
+        println!();
+
         println!("# セットアップ");
         println!("akatsuki setup check              # セットアップ状態確認");
+        println!("akatsuki setup check --json       # チェック結果を JSON 出力");
+        println!("akatsuki setup check --fix        # 失敗したチェックの修正コマンドを確認しながら実行");
         println!("akatsuki setup init               # 対話式セットアップウィザード");
         println!();
 
+        println!("# プラグイン");
+        println!("akatsuki plugins list             # PATH 上の akatsuki-<name> プラグイン一覧");
+        println!("akatsuki <name> [args...]         # akatsuki-<name> を実行（プロジェクト情報を環境変数で受け渡し）");
+        println!();
+
+        println!("# Pull Request（gh CLI）");
+        println!("akatsuki pr create                # コミット・スキーマ・Migration・advice から PR 説明文を生成して gh pr create");
+        println!("akatsuki pr create --dry-run      # 説明文のみ表示");
+        println!("akatsuki pr check                 # Migration の追記のみ・順序、生成コードとスキーマの整合性をチェック");
+        println!();
+
+        println!("# CI レポート");
+        println!("akatsuki report ci                # check/lint/test/docs lint/db check を集計（Markdown + JSON、GitHub annotations）");
+        println!("akatsuki report ci --skip test,db-check  # 指定ステップを除外");
+        println!();
+
+        println!("# タスクランナー（akatsuki.toml [tasks]）");
+        println!("akatsuki run                      # 定義済みタスク一覧");
+        println!("akatsuki run build                # build / <package>:build を依存順に実行（入力ハッシュでキャッシュ）");
+        println!("akatsuki run build --affected     # ベースブランチからの変更に影響するタスクのみ");
+        println!();
+
+        println!("# ダッシュボード");
+        println!("akatsuki ui                       # TUI ダッシュボード（サーバー/check・lint・test/マイグレーション/docs/advice、キーで各コマンド実行）");
+        println!();
+
+        println!("# 利用統計（オプトイン）");
+        println!("akatsuki stats [--days 7]         # コマンド別の実行回数・所要時間・失敗率");
+        println!();
+
         println!("# ユーティリティ");
         println!("akatsuki completion <shell>       # Shell completion スクリプト生成 (zsh/bash/fish/powershell)");
         println!("akatsuki list                     # 全コマンド一覧（このリスト）");
+        println!("akatsuki <command> --output json  # 結果を JSON で出力（docs / db check / api check / advice rule / setup check）");
         println!(
             "akatsuki install                  # CLI をグローバルインストール (cargo install)"
         );
+        println!("akatsuki install --from-release   # GitHub Releases のバイナリをインストール（SHA256 検証、Rust 不要、--prefix 指定可）");
+        println!("akatsuki self-update              # GitHub Releases の最新バイナリに更新（--channel beta でプレリリース）");
         println!();
 
         println!("# リリース");
-        println!("akatsuki release -v <VERSION>     # CLI リリース（バージョン更新、タグ作成、push）");
+        println!("akatsuki release <VERSION>        # CLI リリース（バージョン更新、タグ作成、push、GitHub Release の検証）");
+        println!("akatsuki release 1.4.0-beta.1 --channel beta  # プレリリース（GitHub prerelease、Formula は更新しない）");
+        println!("akatsuki release <VERSION> --verify-only  # push 済みタグの Release アセットとチェックサムを検証し、リリースノートを公開");
+        println!("akatsuki release <VERSION> --dry-run  # 実行内容（Cargo.toml / CHANGELOG / コミット / タグ）のみ表示");
         println!();
 
         println!("💡 詳細なヘルプ: akatsuki <command> --help");
@@ -729,7 +2228,7 @@ impl Cli {
         Ok(())
     }
 
-    fn install_cli() -> Result<()> {
+    fn install_cli(prefix: Option<PathBuf>) -> Result<()> {
         use std::process::Command;
 
         println!("\n🔧 Installing akatsuki CLI globally...\n");
@@ -755,8 +2254,12 @@ impl Cli {
         println!();
 
         // Run cargo install
-        let status = Command::new("cargo")
-            .args(["install", "--path", cli_path.to_str().unwrap()])
+        let mut command = Command::new("cargo");
+        command.args(["install", "--path", cli_path.to_str().unwrap()]);
+        if let Some(prefix) = &prefix {
+            command.arg("--root").arg(prefix);
+        }
+        let status = command
             .status()
             .map_err(|e| anyhow::anyhow!("Failed to run cargo install: {}\n\nMake sure cargo is installed and available in PATH.", e))?;
 
@@ -768,7 +2271,10 @@ impl Cli {
         println!("✅ akatsuki CLI installed successfully!");
         println!();
         println!("💡 Next steps:");
-        println!("   1. Make sure ~/.cargo/bin is in your PATH");
+        println!(
+            "   1. Make sure {} is in your PATH",
+            prefix.map_or("~/.cargo/bin".to_string(), |p| p.join("bin").display().to_string())
+        );
         println!("   2. Run: akatsuki --version");
         println!(
             "   3. Set up shell completion: akatsuki completion zsh > ~/.zsh/completions/_akatsuki"
@@ -0,0 +1,154 @@
+/**
+ * Advice Actions
+ * Follow-up steps suggested by `akatsuki advice ai`
+ *
+ * The AI is asked to end its answer with a fenced YAML block holding an
+ * `actions:` list. Each action is shown and only run after the user confirms
+ * it; nothing runs without a terminal to ask on.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Deserialize;
+use std::io::IsTerminal;
+use std::path::Path;
+
+use crate::utils::platform;
+
+/// Appended to the prompt so the answer ends with machine-readable steps
+pub const INSTRUCTIONS: &str = "\n## 🛠️ Follow-up Actions\n\n\
+End your answer with a fenced ```yaml block containing the shell commands \
+to run next (from the project root), in order, for example:\n\n\
+```yaml\n\
+actions:\n  \
+- description: Check the migrations against the local database\n    \
+command: akatsuki db check\n\
+```\n\n\
+Only include commands that are safe to run. Use `actions: []` when there is nothing to run.\n";
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Action {
+    pub description: String,
+    pub command: String,
+}
+
+#[derive(Deserialize)]
+struct ActionsBlock {
+    actions: Vec<Action>,
+}
+
+/// Actions from the last fenced block with an `actions:` list (empty if none)
+pub fn parse(answer: &str) -> Vec<Action> {
+    let mut blocks = Vec::new();
+    let mut current: Option<String> = None;
+    for line in answer.lines() {
+        let fence = line.trim_start().starts_with("```");
+        match current.as_mut() {
+            Some(block) if fence => {
+                blocks.push(std::mem::take(block));
+                current = None;
+            }
+            Some(block) => {
+                block.push_str(line);
+                block.push('\n');
+            }
+            None if fence => current = Some(String::new()),
+            None => {}
+        }
+    }
+
+    blocks
+        .iter()
+        .rev()
+        .filter(|block| block.trim_start().starts_with("actions:"))
+        .find_map(|block| serde_yaml::from_str::<ActionsBlock>(block).ok())
+        .map(|block| block.actions)
+        .unwrap_or_default()
+}
+
+/// Offer each action in turn; stops at the first declined step or failure
+pub fn offer(root: &Path, actions: &[Action]) -> Result<()> {
+    if actions.is_empty() {
+        return Ok(());
+    }
+
+    println!("\n{}", "🛠️  Suggested actions".cyan().bold());
+    for (i, action) in actions.iter().enumerate() {
+        println!("  {}. {}", i + 1, action.description);
+        println!("     {}", format!("$ {}", action.command).bright_black());
+    }
+
+    if !std::io::stdin().is_terminal() {
+        println!("\n💡 Run in a terminal to execute them step by step");
+        return Ok(());
+    }
+
+    for (i, action) in actions.iter().enumerate() {
+        println!();
+        let run = dialoguer::Confirm::new()
+            .with_prompt(format!(
+                "[{}/{}] Run `{}`?",
+                i + 1,
+                actions.len(),
+                action.command
+            ))
+            .default(false)
+            .interact()?;
+        if !run {
+            println!("⏭️  Stopped before step {}", i + 1);
+            return Ok(());
+        }
+
+        let status = platform::shell(&action.command)
+            .current_dir(root)
+            .status()
+            .with_context(|| format!("Failed to run: {}", action.command))?;
+        if !status.success() {
+            anyhow::bail!("Step {} failed ({}): {}", i + 1, status, action.command);
+        }
+        println!("{} {}", "✅".green(), action.description);
+    }
+
+    println!("\n✅ All suggested actions completed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_actions_block() {
+        let answer = "Next, apply the migration.\n\n\
+                      ```bash\nakatsuki db push\n```\n\n\
+                      ```yaml\n\
+                      actions:\n  \
+                      - description: Apply migrations\n    \
+                      command: akatsuki db push\n  \
+                      - description: Regenerate docs\n    \
+                      command: akatsuki docs sync\n\
+                      ```\n";
+        assert_eq!(
+            parse(answer),
+            [
+                Action {
+                    description: "Apply migrations".to_string(),
+                    command: "akatsuki db push".to_string(),
+                },
+                Action {
+                    description: "Regenerate docs".to_string(),
+                    command: "akatsuki docs sync".to_string(),
+                },
+            ]
+        );
+
+        assert!(parse("No block here").is_empty());
+        assert!(parse("```yaml\nactions: []\n```\n").is_empty());
+        assert!(parse("```yaml\nactions:\n  - oops\n```\n").is_empty());
+    }
+
+    #[test]
+    fn test_instructions_example_parses() {
+        assert_eq!(parse(INSTRUCTIONS).len(), 1);
+    }
+}
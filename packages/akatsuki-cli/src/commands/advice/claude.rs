@@ -0,0 +1,135 @@
+/**
+ * Claude Invocation
+ * Non-interactive `claude -p` runs for `akatsuki advice ai` / `advice report --ai`
+ *
+ * The prompt goes to stdin and the answer is captured, so it can be saved,
+ * posted or parsed. A run that exceeds the timeout is killed.
+ */
+use anyhow::{Context, Result};
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::Stdio;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::utils::config::AdviceClaudeConfig;
+use crate::utils::platform;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClaudeOptions {
+    pub model: Option<String>,
+    pub max_turns: Option<u32>,
+    pub timeout: Duration,
+}
+
+impl ClaudeOptions {
+    /// `[advice.claude]` settings, overridden by command-line flags
+    pub fn resolve(
+        config: &AdviceClaudeConfig,
+        model: Option<String>,
+        max_turns: Option<u32>,
+        timeout_secs: Option<u64>,
+    ) -> Self {
+        Self {
+            model: model.or_else(|| config.model.clone()),
+            max_turns: max_turns.or(config.max_turns),
+            timeout: Duration::from_secs(timeout_secs.unwrap_or(config.timeout_secs)),
+        }
+    }
+
+    fn args(&self) -> Vec<String> {
+        let mut args = vec!["-p".to_string()];
+        if let Some(model) = &self.model {
+            args.extend(["--model".to_string(), model.clone()]);
+        }
+        if let Some(max_turns) = self.max_turns {
+            args.extend(["--max-turns".to_string(), max_turns.to_string()]);
+        }
+        args
+    }
+}
+
+/// Run `claude -p` in `root` with the prompt on stdin and return its answer
+pub fn ask(root: &Path, prompt: &str, options: &ClaudeOptions) -> Result<String> {
+    let mut child = platform::command("claude")
+        .args(options.args())
+        .current_dir(root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .context(
+            "Failed to execute 'claude' command\n\n\
+             Make sure 'claude' command is installed and available in PATH.\n\
+             Fallback: Use 'akatsuki advice prompt' to generate markdown for manual paste.",
+        )?;
+
+    // Read stdout on its own thread so a long answer cannot fill the pipe
+    // while we are still writing the prompt or polling for exit
+    let mut stdout = child.stdout.take().context("claude stdout not captured")?;
+    let reader = thread::spawn(move || {
+        let mut answer = Vec::new();
+        stdout.read_to_end(&mut answer).map(|_| answer)
+    });
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(prompt.as_bytes())?;
+    }
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() >= options.timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            anyhow::bail!(
+                "Claude did not answer within {}s (raise --timeout or [advice.claude] timeout_secs)",
+                options.timeout.as_secs()
+            );
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    let answer = reader
+        .join()
+        .map_err(|_| anyhow::anyhow!("Failed to read the claude output"))??;
+    if !status.success() {
+        anyhow::bail!("Claude command failed with status: {}", status);
+    }
+    Ok(String::from_utf8_lossy(&answer).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_and_args() {
+        let config = AdviceClaudeConfig {
+            model: Some("sonnet".to_string()),
+            max_turns: Some(3),
+            timeout_secs: 300,
+        };
+
+        let options = ClaudeOptions::resolve(&config, None, None, None);
+        assert_eq!(options.timeout, Duration::from_secs(300));
+        assert_eq!(
+            options.args(),
+            ["-p", "--model", "sonnet", "--max-turns", "3"]
+        );
+
+        let options = ClaudeOptions::resolve(&config, Some("opus".to_string()), Some(1), Some(30));
+        assert_eq!(
+            options.args(),
+            ["-p", "--model", "opus", "--max-turns", "1"]
+        );
+        assert_eq!(options.timeout, Duration::from_secs(30));
+
+        let options = ClaudeOptions::resolve(&AdviceClaudeConfig::default(), None, None, None);
+        assert_eq!(options.args(), ["-p"]);
+    }
+}
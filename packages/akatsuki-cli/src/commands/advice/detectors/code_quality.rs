@@ -3,6 +3,7 @@ use std::path::Path;
 use std::process::Command;
 
 use super::{Detection, DetectionCategory, Detector};
+use crate::utils::platform;
 
 pub struct CodeQualityDetector;
 
@@ -13,7 +14,7 @@ impl Detector for CodeQualityDetector {
         // Check TypeScript type errors
         let frontend_dir = project_root.join("packages/app-frontend");
         if frontend_dir.exists() {
-            if let Ok(output) = Command::new("npx")
+            if let Ok(output) = platform::command("npx")
                 .args(["tsc", "--noEmit"])
                 .current_dir(&frontend_dir)
                 .output()
@@ -38,7 +39,7 @@ impl Detector for CodeQualityDetector {
 
         // Check ESLint errors
         if frontend_dir.exists() {
-            if let Ok(output) = Command::new("npx")
+            if let Ok(output) = platform::command("npx")
                 .args(["eslint", "src", "--max-warnings", "0"])
                 .current_dir(&frontend_dir)
                 .output()
@@ -4,6 +4,9 @@ use std::path::Path;
 use std::process::Command;
 
 use super::{Detection, DetectionCategory, Detector};
+use crate::cli::DesignStatus;
+use crate::commands::design::front_matter;
+use crate::utils::platform;
 
 pub struct DocsDetector;
 
@@ -75,23 +78,14 @@ impl Detector for DocsDetector {
 
 impl DocsDetector {
     fn find_design_docs(project_root: &Path) -> Result<Vec<std::path::PathBuf>> {
-        let output = Command::new("find")
-            .args([
-                project_root.to_str().unwrap(),
-                "-maxdepth",
-                "1",
-                "-type",
-                "f",
-                "-name",
-                "*-design.md",
-            ])
-            .output()?;
-
-        let docs: Vec<_> = String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .filter(|line| !line.is_empty())
-            .map(|line| std::path::PathBuf::from(line))
-            .collect();
+        // `design new` writes to workspace/; older docs may sit at the root
+        let is_design = |name: &str| name.ends_with("-design.md");
+        let mut docs = platform::find_files(project_root, 1, is_design);
+        docs.extend(platform::find_files(
+            &project_root.join("workspace"),
+            1,
+            is_design,
+        ));
 
         Ok(docs)
     }
@@ -101,6 +95,13 @@ impl DocsDetector {
 
         for doc_path in docs {
             if let Ok(content) = fs::read_to_string(doc_path) {
+                // Front matter status is authoritative when present
+                if let Ok(Some(meta)) = front_matter::parse(&content) {
+                    if meta.status == DesignStatus::Completed {
+                        continue;
+                    }
+                }
+
                 // Check for common markers of incomplete docs
                 let is_incomplete = content.contains("TODO")
                     || content.contains("TBD")
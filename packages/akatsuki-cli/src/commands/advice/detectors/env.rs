@@ -0,0 +1,32 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::{Detection, DetectionCategory, Detector};
+use crate::utils::env_file::{self, ENV_DIRS};
+
+pub struct EnvDetector;
+
+impl Detector for EnvDetector {
+    fn detect(&self, project_root: &Path) -> Result<Vec<Detection>> {
+        let mut detections = Vec::new();
+
+        for dir in ENV_DIRS {
+            let Some(drift) = env_file::env_drift(&project_root.join(dir))? else {
+                continue;
+            };
+            if !drift.is_empty() {
+                detections.push(Detection::new(
+                    DetectionCategory::EnvExampleDrift,
+                    format!(
+                        "{}/.env drifted from .env.example ({})",
+                        dir,
+                        drift.describe()
+                    ),
+                    4,
+                ));
+            }
+        }
+
+        Ok(detections)
+    }
+}
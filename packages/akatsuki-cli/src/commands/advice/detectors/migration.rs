@@ -42,7 +42,7 @@ impl Detector for MigrationDetector {
                             // Extract filename (everything after first 3 chars)
                             let path_part = line.get(3..)?;
                             // Get just the filename from the path
-                            path_part.split('/').last().map(|s| s.to_string())
+                            path_part.split('/').next_back().map(|s| s.to_string())
                         })
                         .collect();
 
@@ -3,6 +3,7 @@ use std::path::Path;
 
 mod code_quality;
 mod docs;
+mod env;
 mod git;
 mod migration;
 mod refactor;
@@ -10,6 +11,7 @@ mod test;
 
 pub use code_quality::CodeQualityDetector;
 pub use docs::DocsDetector;
+pub use env::EnvDetector;
 pub use git::GitDetector;
 pub use migration::MigrationDetector;
 pub use refactor::RefactorDetector;
@@ -29,6 +31,7 @@ pub struct Detection {
 }
 
 /// Detection categories
+#[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DetectionCategory {
     // Migration & Git
@@ -54,6 +57,7 @@ pub enum DetectionCategory {
     DesignDocument,
     IncompleteDesignDoc,
     MissingDesignDoc,
+    EnvExampleDrift,
 
     // General
     CheckRequired,
@@ -1,9 +1,9 @@
 use anyhow::Result;
 use std::fs;
 use std::path::Path;
-use std::process::Command;
 
 use super::{Detection, DetectionCategory, Detector};
+use crate::utils::platform;
 
 pub struct RefactorDetector;
 
@@ -66,41 +66,30 @@ impl Detector for RefactorDetector {
         // Check Rust files for large modules
         let cargo_toml = project_root.join("Cargo.toml");
         if cargo_toml.exists() {
-            if let Ok(output) = Command::new("find")
-                .args([
-                    project_root.to_str().unwrap(),
-                    "-name",
-                    "*.rs",
-                    "-type",
-                    "f",
-                ])
-                .output()
-            {
-                let rust_files = String::from_utf8_lossy(&output.stdout);
-                let large_rust_files: Vec<_> = rust_files
-                    .lines()
-                    .filter_map(|path| {
-                        let path = Path::new(path);
-                        if let Ok(content) = fs::read_to_string(path) {
-                            let lines = content.lines().count();
-                            if lines > 400 {
-                                return Some((path.file_name()?.to_str()?.to_string(), lines));
-                            }
+            let rust_files =
+                platform::find_files(project_root, usize::MAX, |name| name.ends_with(".rs"));
+            let large_rust_files: Vec<_> = rust_files
+                .iter()
+                .filter_map(|path| {
+                    if let Ok(content) = fs::read_to_string(path) {
+                        let lines = content.lines().count();
+                        if lines > 400 {
+                            return Some((path.file_name()?.to_str()?.to_string(), lines));
                         }
-                        None
-                    })
-                    .collect();
-
-                if !large_rust_files.is_empty() {
-                    detections.push(Detection::new(
-                        DetectionCategory::CodeComplexity,
-                        format!(
-                            "{} large Rust files detected (400+ lines)",
-                            large_rust_files.len()
-                        ),
-                        7,
-                    ));
-                }
+                    }
+                    None
+                })
+                .collect();
+
+            if !large_rust_files.is_empty() {
+                detections.push(Detection::new(
+                    DetectionCategory::CodeComplexity,
+                    format!(
+                        "{} large Rust files detected (400+ lines)",
+                        large_rust_files.len()
+                    ),
+                    7,
+                ));
             }
         }
 
@@ -110,32 +99,12 @@ impl Detector for RefactorDetector {
 
 impl RefactorDetector {
     fn find_large_files(dir: &Path, threshold: usize) -> Result<Vec<(String, usize)>> {
-        let output = Command::new("find")
-            .args([
-                dir.to_str().unwrap(),
-                "-type",
-                "f",
-                "(",
-                "-name",
-                "*.ts",
-                "-o",
-                "-name",
-                "*.tsx",
-                ")",
-                "!",
-                "-name",
-                "*.test.*",
-                "!",
-                "-name",
-                "*.spec.*",
-            ])
-            .output()?;
-
-        let files = String::from_utf8_lossy(&output.stdout);
+        let files = platform::find_files(dir, usize::MAX, |name| {
+            is_typescript(name) && !name.contains(".test.") && !name.contains(".spec.")
+        });
         let large_files: Vec<(String, usize)> = files
-            .lines()
+            .iter()
             .filter_map(|path| {
-                let path = Path::new(path);
                 if let Ok(content) = fs::read_to_string(path) {
                     let lines = content.lines().count();
                     if lines > threshold {
@@ -150,26 +119,10 @@ impl RefactorDetector {
     }
 
     fn find_deeply_nested_files(dir: &Path, max_indent: usize) -> Result<Vec<String>> {
-        let output = Command::new("find")
-            .args([
-                dir.to_str().unwrap(),
-                "-type",
-                "f",
-                "(",
-                "-name",
-                "*.ts",
-                "-o",
-                "-name",
-                "*.tsx",
-                ")",
-            ])
-            .output()?;
-
-        let files = String::from_utf8_lossy(&output.stdout);
+        let files = platform::find_files(dir, usize::MAX, is_typescript);
         let nested_files: Vec<String> = files
-            .lines()
+            .iter()
             .filter_map(|path| {
-                let path = Path::new(path);
                 if let Ok(content) = fs::read_to_string(path) {
                     // Simple heuristic: check for lines with 6+ levels of indentation
                     let has_deep_nesting = content.lines().any(|line| {
@@ -189,3 +142,7 @@ impl RefactorDetector {
         Ok(nested_files)
     }
 }
+
+fn is_typescript(name: &str) -> bool {
+    name.ends_with(".ts") || name.ends_with(".tsx")
+}
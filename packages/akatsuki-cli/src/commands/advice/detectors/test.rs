@@ -3,6 +3,7 @@ use std::path::Path;
 use std::process::Command;
 
 use super::{Detection, DetectionCategory, Detector};
+use crate::utils::platform;
 
 pub struct TestDetector;
 
@@ -14,7 +15,7 @@ impl Detector for TestDetector {
         let frontend_dir = project_root.join("packages/app-frontend");
         if frontend_dir.exists() {
             // Run tests with --passWithNoTests to avoid failure when no tests exist
-            if let Ok(output) = Command::new("npm")
+            if let Ok(output) = platform::command("npm")
                 .args(["test", "--", "--passWithNoTests", "--watchAll=false"])
                 .current_dir(&frontend_dir)
                 .output()
@@ -82,31 +83,12 @@ impl Detector for TestDetector {
 
 impl TestDetector {
     fn count_test_files(dir: &Path) -> Result<usize> {
-        let output = Command::new("find")
-            .args([
-                dir.to_str().unwrap(),
-                "-type",
-                "f",
-                "(",
-                "-name",
-                "*.test.ts",
-                "-o",
-                "-name",
-                "*.test.tsx",
-                "-o",
-                "-name",
-                "*.spec.ts",
-                "-o",
-                "-name",
-                "*.spec.tsx",
-                ")",
-            ])
-            .output()?;
-
-        let count = String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .filter(|line| !line.is_empty())
-            .count();
+        let count = platform::find_files(dir, usize::MAX, |name| {
+            [".test.ts", ".test.tsx", ".spec.ts", ".spec.tsx"]
+                .iter()
+                .any(|suffix| name.ends_with(suffix))
+        })
+        .len();
 
         Ok(count)
     }
@@ -117,31 +99,12 @@ impl TestDetector {
             return Ok(0);
         }
 
-        let output = Command::new("find")
-            .args([
-                src_dir.to_str().unwrap(),
-                "-type",
-                "f",
-                "(",
-                "-name",
-                "*.ts",
-                "-o",
-                "-name",
-                "*.tsx",
-                ")",
-                "!",
-                "-name",
-                "*.test.*",
-                "!",
-                "-name",
-                "*.spec.*",
-            ])
-            .output()?;
-
-        let count = String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .filter(|line| !line.is_empty())
-            .count();
+        let count = platform::find_files(&src_dir, usize::MAX, |name| {
+            (name.ends_with(".ts") || name.ends_with(".tsx"))
+                && !name.contains(".test.")
+                && !name.contains(".spec.")
+        })
+        .len();
 
         Ok(count)
     }
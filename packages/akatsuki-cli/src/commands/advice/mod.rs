@@ -1,15 +1,27 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use colored::Colorize;
 use std::fs;
-use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+mod actions;
+mod claude;
 mod detectors;
+mod report;
 mod rules;
 
-use crate::cli::{AIBackend, AdviceAction};
+use crate::cli::{AIBackend, AdviceAction, ReportTarget};
+use crate::utils::config::ProjectConfig;
+use crate::utils::output::OutputFormatter;
+use claude::ClaudeOptions;
+use report::Report;
 use rules::{Advice, RuleEngine};
 
+/// Rule-based "current situation" lines (used by `akatsuki pr create`)
+pub fn current_situation(project_root: &Path) -> Result<Vec<String>> {
+    Ok(RuleEngine::new().analyze(project_root, false)?.situation)
+}
+
 pub struct AdviceCommand {
     project_root: PathBuf,
 }
@@ -51,7 +63,7 @@ impl AdviceCommand {
         }
     }
 
-    pub fn execute(&self, action: AdviceAction) -> Result<()> {
+    pub fn execute(&self, action: AdviceAction, output: OutputFormatter) -> Result<()> {
         match action {
             AdviceAction::Rule {
                 task,
@@ -60,28 +72,62 @@ impl AdviceCommand {
                 if let Some(task_name) = task {
                     self.show_task_workflow(&task_name)
                 } else {
-                    self.show_contextual_advice(enable_test_coverage)
+                    self.show_contextual_advice(enable_test_coverage, output)
                 }
             }
             AdviceAction::Prompt {
                 task,
                 enable_test_coverage,
-            } => self.show_prompt_advice(task.as_deref(), enable_test_coverage),
+                out,
+            } => self.show_prompt_advice(task.as_deref(), enable_test_coverage, out.as_deref()),
             AdviceAction::Ai {
                 task,
                 backend,
                 enable_test_coverage,
-            } => self.invoke_ai_backend(task.as_deref(), backend, enable_test_coverage),
+                out,
+                no_actions,
+                model,
+                max_turns,
+                timeout,
+            } => {
+                let config = ProjectConfig::load()?.advice.claude;
+                let claude = ClaudeOptions::resolve(&config, model, max_turns, timeout);
+                self.invoke_ai_backend(
+                    task.as_deref(),
+                    backend,
+                    enable_test_coverage,
+                    out.as_deref(),
+                    no_actions,
+                    &claude,
+                )
+            }
+            AdviceAction::Report {
+                to,
+                ai,
+                task,
+                webhook_url,
+                dry_run,
+                enable_test_coverage,
+            } => self.send_report(
+                to,
+                ai,
+                task.as_deref(),
+                webhook_url,
+                dry_run,
+                enable_test_coverage,
+            ),
         }
     }
 
-    fn show_contextual_advice(&self, enable_test_coverage: bool) -> Result<()> {
+    fn show_contextual_advice(
+        &self,
+        enable_test_coverage: bool,
+        output: OutputFormatter,
+    ) -> Result<()> {
         let engine = RuleEngine::new();
         let advice = engine.analyze(&self.project_root, enable_test_coverage)?;
 
-        advice.print();
-
-        Ok(())
+        output.emit(&advice, Advice::print)
     }
 
     fn show_task_workflow(&self, task: &str) -> Result<()> {
@@ -91,13 +137,25 @@ impl AdviceCommand {
         Ok(())
     }
 
-    fn show_prompt_advice(&self, task: Option<&str>, enable_test_coverage: bool) -> Result<()> {
+    fn show_prompt_advice(
+        &self,
+        task: Option<&str>,
+        enable_test_coverage: bool,
+        out: Option<&Path>,
+    ) -> Result<()> {
         // Generate markdown prompt for manual copy-paste
         let engine = RuleEngine::new();
         let static_advice = engine.analyze(&self.project_root, enable_test_coverage)?;
         let context = self.collect_ai_context()?;
         let prompt = self.build_ai_prompt(&static_advice, &context, task);
 
+        if let Some(out) = out {
+            write_output(out, &prompt)?;
+            println!("📋 AI analysis prompt written to {}", out.display());
+            println!("💡 Paste it into Claude Code for AI-powered advice.");
+            return Ok(());
+        }
+
         println!("\n📋 AI Analysis Prompt\n");
         println!("Copy the following to Claude Code for advanced advice:\n");
         println!("---");
@@ -113,60 +171,144 @@ impl AdviceCommand {
         task: Option<&str>,
         backend: AIBackend,
         enable_test_coverage: bool,
+        out: Option<&Path>,
+        no_actions: bool,
+        claude: &ClaudeOptions,
     ) -> Result<()> {
         match backend {
             AIBackend::Markdown => {
                 // Same as prompt subcommand
-                self.show_prompt_advice(task, enable_test_coverage)
+                self.show_prompt_advice(task, enable_test_coverage, out)
             }
             AIBackend::Claude => {
                 // Automatic invocation via claude command
-                self.invoke_claude_command(task, enable_test_coverage)
+                let answer = self.invoke_claude_command(task, enable_test_coverage, claude)?;
+                if let Some(out) = out {
+                    write_output(out, &answer)?;
+                    println!("📝 Answer written to {}", out.display());
+                }
+                if no_actions {
+                    return Ok(());
+                }
+                actions::offer(&self.project_root, &actions::parse(&answer))
             }
         }
     }
 
-    fn invoke_claude_command(&self, task: Option<&str>, enable_test_coverage: bool) -> Result<()> {
+    /// Ask Claude with the project context, print the answer and return it
+    fn invoke_claude_command(
+        &self,
+        task: Option<&str>,
+        enable_test_coverage: bool,
+        claude: &ClaudeOptions,
+    ) -> Result<String> {
         println!("\n🤖 Invoking Claude Code AI...\n");
 
         // 1. Collect context and build prompt
         let engine = RuleEngine::new();
         let static_advice = engine.analyze(&self.project_root, enable_test_coverage)?;
         let context = self.collect_ai_context()?;
-        let prompt = self.build_ai_prompt(&static_advice, &context, task);
+        let mut prompt = self.build_ai_prompt(&static_advice, &context, task);
+        prompt.push_str(actions::INSTRUCTIONS);
 
-        // 2. Invoke claude command with prompt via stdin
-        let mut child = Command::new("claude")
-            .stdin(std::process::Stdio::piped())
-            .stdout(std::process::Stdio::inherit())
-            .stderr(std::process::Stdio::inherit())
-            .spawn()
-            .map_err(|e| {
-                anyhow::anyhow!(
-                    "Failed to execute 'claude' command: {}\n\n\
-                     Make sure 'claude' command is installed and available in PATH.\n\
-                     Fallback: Use 'akatsuki advice prompt' to generate markdown for manual paste.",
-                    e
-                )
-            })?;
+        // 2. Invoke claude -p with the prompt via stdin
+        let answer = claude::ask(&self.project_root, &prompt, claude)?;
+        println!("{}", answer);
+        println!("\n✅ AI analysis complete!");
 
-        // Write prompt to stdin
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin.write_all(prompt.as_bytes())?;
-        }
+        Ok(answer)
+    }
 
-        // Wait for completion
-        let status = child.wait()?;
+    fn send_report(
+        &self,
+        target: ReportTarget,
+        ai: bool,
+        task: Option<&str>,
+        webhook_url: Option<String>,
+        dry_run: bool,
+        enable_test_coverage: bool,
+    ) -> Result<()> {
+        let advice_config = ProjectConfig::load()?.advice;
+        let config = advice_config.report;
+        let enable_test_coverage = enable_test_coverage || config.enable_test_coverage;
 
-        if !status.success() {
-            anyhow::bail!("Claude command failed with status: {}", status);
-        }
+        // Resolve the destination before the (slow) analysis so a missing URL fails fast
+        let webhook_url = if dry_run {
+            None
+        } else {
+            let env_name = match target {
+                ReportTarget::Slack => &config.slack_webhook_env,
+                ReportTarget::Discord => &config.discord_webhook_env,
+            };
+            let url = webhook_url
+                .or_else(|| std::env::var(env_name).ok().filter(|v| !v.is_empty()))
+                .with_context(|| {
+                    format!(
+                        "No webhook URL: set {} (or pass --webhook-url / configure [advice.report] in akatsuki.toml)",
+                        env_name
+                    )
+                })?;
+            Some(url)
+        };
 
-        println!("\n✅ AI analysis complete!");
+        let engine = RuleEngine::new();
+        let advice = engine.analyze(&self.project_root, enable_test_coverage)?;
 
+        let ai_answer = if ai || config.include_ai {
+            let context = self.collect_ai_context()?;
+            let prompt = self.build_ai_prompt(&advice, &context, task);
+            let claude = ClaudeOptions::resolve(&advice_config.claude, None, None, None);
+            Some(claude::ask(&self.project_root, &prompt, &claude)?)
+        } else {
+            None
+        };
+
+        let project_name = self
+            .project_root
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "project".to_string());
+        let report = Report {
+            title: config
+                .title
+                .clone()
+                .unwrap_or_else(|| format!("{} health report", project_name)),
+            advice: &advice,
+            ai_answer,
+            revision: self.git_revision(),
+        };
+        let payload = report.payload(target);
+
+        let Some(webhook_url) = webhook_url else {
+            // --dry-run
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+            return Ok(());
+        };
+        report::send(&webhook_url, &payload)?;
+        println!(
+            "{} Report sent to {}",
+            "✅".green(),
+            format!("{:?}", target).to_lowercase()
+        );
         Ok(())
     }
 
+    /// "main @ 1a2b3c4" for the report footer
+    fn git_revision(&self) -> Option<String> {
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(&self.project_root)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+        let branch = git(&["rev-parse", "--abbrev-ref", "HEAD"])?;
+        let commit = git(&["rev-parse", "--short", "HEAD"])?;
+        Some(format!("{} @ {}", branch, commit))
+    }
+
     fn collect_ai_context(&self) -> Result<AIContext> {
         let mut context = AIContext::default();
 
@@ -278,7 +420,7 @@ impl AdviceCommand {
                 prompt.push_str(&format!("- {}\n", item));
             }
         }
-        prompt.push_str("\n");
+        prompt.push('\n');
 
         // Recent git activity
         if !context.git_history.is_empty() {
@@ -294,7 +436,7 @@ impl AdviceCommand {
             for file in &context.modified_files {
                 prompt.push_str(&format!("- {}\n", file));
             }
-            prompt.push_str("\n");
+            prompt.push('\n');
         }
 
         // Documentation coverage
@@ -316,19 +458,32 @@ impl AdviceCommand {
             for (i, step) in advice.steps.iter().enumerate() {
                 prompt.push_str(&format!("{}. {}\n", i + 1, step));
             }
-            prompt.push_str("\n");
+            prompt.push('\n');
         }
 
         // Question
         prompt.push_str("## ❓ Question\n\n");
         let question = task.unwrap_or("Based on the current project state, what should I work on next? Please provide specific, actionable steps.");
         prompt.push_str(question);
-        prompt.push_str("\n");
+        prompt.push('\n');
 
         prompt
     }
 }
 
+/// Write `--out` files, creating parent directories
+fn write_output(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let mut content = content.to_string();
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
 #[derive(Default)]
 struct AIContext {
     git_history: String,
@@ -0,0 +1,259 @@
+/**
+ * Advice Report
+ * Rule-based analysis (and optional AI answer) rendered as a Slack / Discord webhook message
+ */
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use super::rules::Advice;
+use crate::cli::ReportTarget;
+
+/// Slack section blocks reject text longer than 3000 characters
+const SLACK_TEXT_LIMIT: usize = 3000;
+/// Discord embed field values are limited to 1024 characters
+const DISCORD_FIELD_LIMIT: usize = 1024;
+/// Discord embed descriptions are limited to 4096 characters
+const DISCORD_DESCRIPTION_LIMIT: usize = 4096;
+
+const COLOR_OK: u32 = 0x22c55e;
+const COLOR_ATTENTION: u32 = 0xf59e0b;
+
+pub struct Report<'a> {
+    pub title: String,
+    pub advice: &'a Advice,
+    pub ai_answer: Option<String>,
+    /// Branch and short commit, e.g. "main @ 1a2b3c4"
+    pub revision: Option<String>,
+}
+
+impl Report<'_> {
+    /// Webhook body for `target`
+    pub fn payload(&self, target: ReportTarget) -> Value {
+        match target {
+            ReportTarget::Slack => self.slack_payload(),
+            ReportTarget::Discord => self.discord_payload(),
+        }
+    }
+
+    fn is_clean(&self) -> bool {
+        self.advice.steps.is_empty()
+    }
+
+    fn situation_lines(&self) -> String {
+        if self.advice.situation.is_empty() {
+            "• No issues detected".to_string()
+        } else {
+            bullets(&self.advice.situation)
+        }
+    }
+
+    /// Numbered steps; indented sub-items ("  - ...") stay unnumbered
+    fn steps_lines(&self) -> String {
+        let mut number = 0;
+        self.advice
+            .steps
+            .iter()
+            .map(|step| {
+                if step.starts_with(' ') {
+                    format!("    {}", step.trim_start())
+                } else {
+                    number += 1;
+                    format!("{}. {}", number, step)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn status(&self) -> &'static str {
+        if self.is_clean() {
+            "✅ All clear"
+        } else {
+            "⚠️ Needs attention"
+        }
+    }
+
+    fn slack_payload(&self) -> Value {
+        let mut blocks = vec![
+            json!({
+                "type": "header",
+                "text": { "type": "plain_text", "text": truncate(&self.title, 150) }
+            }),
+            section(&format!(
+                "*{}*\n{}",
+                self.status(),
+                truncate(&self.situation_lines(), SLACK_TEXT_LIMIT - 40)
+            )),
+        ];
+
+        if !self.is_clean() {
+            blocks.push(section(&format!(
+                "*Recommended next steps*\n{}",
+                truncate(&self.steps_lines(), SLACK_TEXT_LIMIT - 40)
+            )));
+        }
+        if let Some(answer) = &self.ai_answer {
+            blocks.push(json!({ "type": "divider" }));
+            blocks.push(section(&format!(
+                "*🤖 AI advice*\n{}",
+                truncate(answer, SLACK_TEXT_LIMIT - 40)
+            )));
+        }
+        if let Some(revision) = &self.revision {
+            blocks.push(json!({
+                "type": "context",
+                "elements": [{ "type": "mrkdwn", "text": format!("`{}` · akatsuki advice report", revision) }]
+            }));
+        }
+
+        json!({
+            // Notification / fallback text
+            "text": format!("{}: {}", self.title, self.status()),
+            "blocks": blocks,
+        })
+    }
+
+    fn discord_payload(&self) -> Value {
+        let mut fields = vec![json!({
+            "name": "Current situation",
+            "value": truncate(&self.situation_lines(), DISCORD_FIELD_LIMIT),
+        })];
+        if !self.is_clean() {
+            fields.push(json!({
+                "name": "Recommended next steps",
+                "value": truncate(&self.steps_lines(), DISCORD_FIELD_LIMIT),
+            }));
+        }
+
+        let mut embed = json!({
+            "title": truncate(&format!("{} — {}", self.title, self.status()), 256),
+            "color": if self.is_clean() { COLOR_OK } else { COLOR_ATTENTION },
+            "fields": fields,
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+        });
+        if let Some(answer) = &self.ai_answer {
+            embed["description"] = json!(truncate(answer, DISCORD_DESCRIPTION_LIMIT));
+        }
+        if let Some(revision) = &self.revision {
+            embed["footer"] = json!({ "text": revision });
+        }
+
+        json!({
+            "username": "Akatsuki",
+            "embeds": [embed],
+        })
+    }
+}
+
+/// POST the payload to the webhook
+pub fn send(webhook_url: &str, payload: &Value) -> Result<()> {
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()?
+        .post(webhook_url)
+        .json(payload)
+        .send()
+        .context("Failed to reach the webhook")?
+        .error_for_status()
+        .context("Webhook rejected the report")?;
+    Ok(())
+}
+
+fn section(text: &str) -> Value {
+    json!({
+        "type": "section",
+        "text": { "type": "mrkdwn", "text": text }
+    })
+}
+
+fn bullets(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|item| format!("• {}", item))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Cut `text` to at most `max` characters, marking the cut with "…"
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(max.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advice() -> Advice {
+        Advice {
+            situation: vec!["2 uncommitted files".to_string()],
+            steps: vec![
+                "Fix code quality issues:".to_string(),
+                "  - Run linter: npx eslint src --fix".to_string(),
+                "git add -A && git commit".to_string(),
+            ],
+            hints: None,
+        }
+    }
+
+    #[test]
+    fn test_slack_payload() {
+        let advice = advice();
+        let report = Report {
+            title: "akatsuki health report".to_string(),
+            advice: &advice,
+            ai_answer: Some("Ship it".to_string()),
+            revision: Some("main @ 1a2b3c4".to_string()),
+        };
+        let payload = report.payload(ReportTarget::Slack);
+
+        assert_eq!(
+            payload["text"],
+            "akatsuki health report: ⚠️ Needs attention"
+        );
+        let blocks = payload["blocks"].as_array().unwrap();
+        assert_eq!(blocks[0]["type"], "header");
+        assert!(blocks[1]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("• 2 uncommitted files"));
+        assert!(blocks[2]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .ends_with("    - Run linter: npx eslint src --fix\n2. git add -A && git commit"));
+        assert_eq!(blocks.last().unwrap()["type"], "context");
+    }
+
+    #[test]
+    fn test_discord_payload_clean() {
+        let advice = Advice {
+            situation: Vec::new(),
+            steps: Vec::new(),
+            hints: None,
+        };
+        let report = Report {
+            title: "akatsuki health report".to_string(),
+            advice: &advice,
+            ai_answer: None,
+            revision: None,
+        };
+        let embed = &report.payload(ReportTarget::Discord)["embeds"][0];
+
+        assert_eq!(embed["color"], COLOR_OK);
+        assert_eq!(embed["fields"].as_array().unwrap().len(), 1);
+        assert_eq!(embed["fields"][0]["value"], "• No issues detected");
+        assert!(embed.get("description").is_none());
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("abc", 3), "abc");
+        assert_eq!(truncate("abcdef", 4), "abc…");
+        assert_eq!(truncate("日本語テキスト", 3), "日本…");
+    }
+}
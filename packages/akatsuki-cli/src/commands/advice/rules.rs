@@ -1,10 +1,11 @@
 use anyhow::Result;
 use colored::Colorize;
+use serde::Serialize;
 use std::path::Path;
 
 use super::detectors::{
-    CodeQualityDetector, Detection, DetectionCategory, Detector, DocsDetector, GitDetector,
-    MigrationDetector, RefactorDetector, TestDetector,
+    CodeQualityDetector, Detection, DetectionCategory, Detector, DocsDetector, EnvDetector,
+    GitDetector, MigrationDetector, RefactorDetector, TestDetector,
 };
 
 pub struct RuleEngine {
@@ -20,6 +21,7 @@ impl RuleEngine {
             Box::new(TestDetector),
             Box::new(RefactorDetector),
             Box::new(DocsDetector),
+            Box::new(EnvDetector),
         ];
 
         Self { detectors }
@@ -84,6 +86,9 @@ impl RuleEngine {
             d.category == DetectionCategory::IncompleteDesignDoc
                 || d.category == DetectionCategory::MissingDesignDoc
         });
+        let has_env_drift = detections
+            .iter()
+            .any(|d| d.category == DetectionCategory::EnvExampleDrift);
         let is_clean = detections
             .iter()
             .any(|d| d.category == DetectionCategory::Clean);
@@ -141,6 +146,12 @@ impl RuleEngine {
             hints.push("".to_string());
         }
 
+        if has_env_drift {
+            steps.push("Sync .env.example with .env:".to_string());
+            steps.push("  - Document new keys: akatsuki setup check --fix".to_string());
+            steps.push("  - Set keys the example requires in your .env".to_string());
+        }
+
         if has_incomplete_docs {
             steps.push("Complete design documentation:".to_string());
             steps.push("  - Fill in TODO/TBD sections in *-design.md files".to_string());
@@ -179,6 +190,7 @@ impl RuleEngine {
     }
 }
 
+#[derive(Debug, Serialize)]
 pub struct Advice {
     pub situation: Vec<String>,
     pub steps: Vec<String>,
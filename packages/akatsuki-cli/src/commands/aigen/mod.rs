@@ -0,0 +1,124 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde::Deserialize;
+
+use crate::cli::AigenAction;
+use crate::utils::backend::BackendClient;
+
+pub struct AigenCommand {
+    client: BackendClient,
+}
+
+#[derive(Debug, Deserialize)]
+struct FeedbackCounts {
+    group: String,
+    total: i64,
+    up: i64,
+    down: i64,
+    positive_rate: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagCount {
+    tag: String,
+    count: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct FeedbackStats {
+    since: String,
+    total: i64,
+    up: i64,
+    down: i64,
+    positive_rate: Option<f64>,
+    by_kind: Vec<FeedbackCounts>,
+    top_tags: Vec<TagCount>,
+}
+
+impl AigenCommand {
+    pub fn new(url: &str, token: Option<String>) -> Result<Self> {
+        if token.is_none() {
+            anyhow::bail!(
+                "Admin token required. Set AKATSUKI_ADMIN_TOKEN or pass --token \
+                 (JWT with app_metadata.role = admin, or the service role key)"
+            );
+        }
+
+        Ok(Self {
+            client: BackendClient::new(url)?.with_token(token),
+        })
+    }
+
+    pub fn execute(&self, action: AigenAction) -> Result<()> {
+        match action {
+            AigenAction::FeedbackStats { kind, days } => self.feedback_stats(kind.as_deref(), days),
+        }
+    }
+
+    fn feedback_stats(&self, kind: Option<&str>, days: u32) -> Result<()> {
+        let mut path = format!("/api/admin/aigen/feedback/stats?days={}", days);
+        if let Some(kind) = kind {
+            path.push_str(&format!("&kind={}", kind));
+        }
+        let stats: FeedbackStats = self.client.get_json(&path)?;
+
+        if stats.total == 0 {
+            println!(
+                "{}",
+                format!("No feedback in the last {} days", days).yellow()
+            );
+            return Ok(());
+        }
+
+        println!(
+            "\n👍 {}  👎 {}  ({} total, {} positive since {})",
+            stats.up.to_string().green(),
+            stats.down.to_string().red(),
+            stats.total,
+            Self::format_rate(stats.positive_rate),
+            stats.since
+        );
+
+        println!(
+            "\n{:<20}  {:>6}  {:>6}  {:>6}  POSITIVE",
+            "KIND", "TOTAL", "UP", "DOWN"
+        );
+        for group in &stats.by_kind {
+            println!(
+                "{:<20}  {:>6}  {:>6}  {:>6}  {}",
+                group.group,
+                group.total,
+                group.up,
+                group.down,
+                Self::format_rate(group.positive_rate)
+            );
+        }
+
+        if !stats.top_tags.is_empty() {
+            let tags: Vec<String> = stats
+                .top_tags
+                .iter()
+                .map(|t| format!("{} ({})", t.tag, t.count))
+                .collect();
+            println!("\n{} {}", "Top tags:".bold(), tags.join(", "));
+        }
+
+        Ok(())
+    }
+
+    fn format_rate(rate: Option<f64>) -> String {
+        match rate {
+            Some(rate) => {
+                let text = format!("{:.0}%", rate * 100.0);
+                if rate >= 0.7 {
+                    text.green().to_string()
+                } else if rate >= 0.4 {
+                    text.yellow().to_string()
+                } else {
+                    text.red().to_string()
+                }
+            }
+            None => "-".dimmed().to_string(),
+        }
+    }
+}
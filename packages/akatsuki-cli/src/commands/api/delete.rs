@@ -0,0 +1,343 @@
+/**
+ * API Delete
+ * Removes the files `api new` generated for an entity and its App.tsx /
+ * ExamplesPage.tsx wiring. The create migration stays (it may already be
+ * applied); a new migration drops the table instead.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::list::{
+    crud_functions, file_names, FUNCTIONS_DIR, MIGRATIONS_DIR, MODELS_DIR, SERVICES_DIR,
+};
+use super::manifest::snapshot_path;
+use super::schema::EntitySchema;
+use super::wiring::{self, WireEdit};
+use crate::commands::db::plan::find_schemas;
+
+const REPOSITORIES_DIR: &str = "supabase/functions/_shared/repositories";
+const HOOKS_DIR: &str = "packages/app-frontend/src/hooks";
+const ADMIN_PAGES_DIR: &str = "packages/app-frontend/src/pages/admin/entities";
+const FEATURES_DIR: &str = "packages/app-frontend/src/components/features";
+const CLI_CLIENTS_DIR: &str = "packages/app-cli/clients";
+
+/// Everything `api delete` would change for one entity
+struct DeletePlan {
+    name: String,
+    table_name: String,
+    /// Existing generated files and Edge Function directories
+    files: Vec<PathBuf>,
+    /// Create migrations of the table (kept)
+    migrations: Vec<PathBuf>,
+    /// New migration dropping the table (`None` when no create migration
+    /// exists or a drop migration already follows it)
+    rollback: Option<PathBuf>,
+    unwire: Vec<WireEdit>,
+}
+
+impl DeletePlan {
+    fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.rollback.is_none() && self.unwire.is_empty()
+    }
+}
+
+pub fn execute(project_root: &Path, entity_name: &str, force: bool, dry_run: bool) -> Result<()> {
+    println!("🗑️ Delete API: {}", entity_name.bright_white());
+    println!("{}", "─".repeat(50).bright_black());
+
+    let (name, table_name) = resolve(project_root, entity_name)?;
+    let plan = plan(project_root, name, table_name);
+    print_plan(project_root, &plan);
+
+    if plan.is_empty() {
+        println!(
+            "\n{}",
+            format!("Nothing generated for {} remains", plan.name).yellow()
+        );
+        return Ok(());
+    }
+    if dry_run {
+        println!("\n{}", "Dry run: nothing was changed".yellow());
+        return Ok(());
+    }
+    if !force
+        && !dialoguer::Confirm::new()
+            .with_prompt(format!("Delete the generated API for {}?", plan.name))
+            .default(false)
+            .interact()?
+    {
+        println!("{}", "Cancelled".yellow());
+        return Ok(());
+    }
+
+    apply(project_root, &plan)?;
+
+    println!(
+        "\n{}",
+        format!("✅ Deleted the generated API for {}", plan.name).green()
+    );
+    if plan.rollback.is_some() {
+        println!("\n{}", "💡 Next steps:".cyan());
+        println!("   • Review the drop migration, then run: akatsuki db push");
+        println!("   • Remove the schema YAML if the entity is gone for good");
+    }
+    Ok(())
+}
+
+/// `(entity, table)` from a schema YAML or a generated Edge Function, by
+/// entity or table name
+fn resolve(root: &Path, entity_name: &str) -> Result<(String, String)> {
+    let from_schema = find_schemas(root).into_iter().find_map(|path| {
+        let schema = EntitySchema::from_yaml(&path).ok()?;
+        (schema.name == entity_name || schema.table_name == entity_name)
+            .then_some((schema.name, schema.table_name))
+    });
+    if let Some(found) = from_schema {
+        return Ok(found);
+    }
+
+    crud_functions(root)
+        .into_iter()
+        .find(|(table, name)| name == entity_name || table == entity_name)
+        .map(|(table, name)| (name, table))
+        .with_context(|| {
+            format!(
+                "No schema or generated Edge Function found for {} (see `akatsuki api list`)",
+                entity_name
+            )
+        })
+}
+
+fn plan(root: &Path, name: String, table_name: String) -> DeletePlan {
+    let candidates = [
+        root.join(FUNCTIONS_DIR)
+            .join(format!("{}-crud", table_name)),
+        root.join(FUNCTIONS_DIR)
+            .join(format!("{}-webhooks", table_name)),
+        root.join(FUNCTIONS_DIR)
+            .join(format!("{}-graphql", table_name)),
+        root.join(REPOSITORIES_DIR)
+            .join(format!("{}Repository.ts", name)),
+        root.join(MODELS_DIR).join(format!("{}.ts", name)),
+        root.join(SERVICES_DIR).join(format!("{}Service.ts", name)),
+        root.join(HOOKS_DIR).join(format!("use{}s.ts", name)),
+        root.join(HOOKS_DIR).join(format!("use{}sGraphQL.ts", name)),
+        root.join(ADMIN_PAGES_DIR)
+            .join(format!("{}AdminPage.tsx", name)),
+        root.join(FEATURES_DIR)
+            .join(&table_name)
+            .join(format!("{}sDemo.tsx", name)),
+        root.join(CLI_CLIENTS_DIR)
+            .join(format!("{}sClient.js", name)),
+        snapshot_path(root, &table_name),
+    ];
+    let files = candidates
+        .into_iter()
+        .filter(|path| path.exists())
+        .collect();
+
+    // Timestamped names sort chronologically
+    let migration_names = file_names(&root.join(MIGRATIONS_DIR));
+    let create_suffix = format!("_create_{}_table.sql", table_name);
+    let drop_suffix = format!("_drop_{}_table.sql", table_name);
+    let last_create = migration_names
+        .iter()
+        .rposition(|file| file.ends_with(&create_suffix));
+    let last_drop = migration_names
+        .iter()
+        .rposition(|file| file.ends_with(&drop_suffix));
+    let rollback = match (last_create, last_drop) {
+        (Some(create), drop) if drop.is_none_or(|drop| drop < create) => {
+            Some(root.join(MIGRATIONS_DIR).join(format!(
+                "{}{}",
+                chrono::Local::now().format("%Y%m%d%H%M%S"),
+                drop_suffix
+            )))
+        }
+        _ => None,
+    };
+    let migrations = migration_names
+        .iter()
+        .filter(|file| file.ends_with(&create_suffix))
+        .map(|file| root.join(MIGRATIONS_DIR).join(file))
+        .collect();
+
+    let unwire = wiring::unplan(root, &name, &table_name);
+    DeletePlan {
+        name,
+        table_name,
+        files,
+        migrations,
+        rollback,
+        unwire,
+    }
+}
+
+/// Drops everything the create migration made (indexes, policies and
+/// triggers go with the tables)
+fn rollback_sql(table_name: &str, create_migration: Option<&Path>) -> String {
+    let created_by = create_migration
+        .and_then(|path| path.file_name())
+        .map(|name| format!(" (rolls back {})", name.to_string_lossy()))
+        .unwrap_or_default();
+    format!(
+        "-- Drop {table} table
+-- Auto-generated by `akatsuki api delete`{created_by}
+
+DROP TABLE IF EXISTS public.{table}_webhook_deliveries;
+DROP TABLE IF EXISTS public.{table};
+
+DROP FUNCTION IF EXISTS public.update_{table}_updated_at();
+DROP FUNCTION IF EXISTS public.notify_{table}_webhooks();
+",
+        table = table_name,
+        created_by = created_by
+    )
+}
+
+fn print_plan(root: &Path, plan: &DeletePlan) {
+    let relative = |path: &Path| {
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+
+    println!(
+        "Entity: {} (table {})\n",
+        plan.name.bright_white(),
+        plan.table_name
+    );
+
+    if !plan.files.is_empty() {
+        println!("  {} Files to delete:", "🗑️".bright_blue());
+        for file in &plan.files {
+            let suffix = if file.is_dir() { "/" } else { "" };
+            println!("    {} {}{}", "-".red(), relative(file), suffix);
+        }
+    }
+
+    if !plan.unwire.is_empty() {
+        println!("\n  {} Wiring to remove:", "🔌".bright_blue());
+        for edit in &plan.unwire {
+            println!("    {}", relative(&edit.path));
+            for line in wiring::removal_diff(edit) {
+                println!("      {}", line);
+            }
+        }
+    }
+
+    if !plan.migrations.is_empty() || plan.rollback.is_some() {
+        println!("\n  {} Migrations:", "🗄️".bright_blue());
+        for migration in &plan.migrations {
+            println!(
+                "    {} {} {}",
+                "•".bright_blue(),
+                relative(migration),
+                "(kept)".bright_black()
+            );
+        }
+        if let Some(rollback) = &plan.rollback {
+            println!("    {} {}", "+".green(), relative(rollback));
+        }
+    }
+}
+
+fn apply(root: &Path, plan: &DeletePlan) -> Result<()> {
+    for path in &plan.files {
+        if path.is_dir() {
+            fs::remove_dir_all(path)
+        } else {
+            fs::remove_file(path)
+        }
+        .with_context(|| format!("Failed to delete {}", path.display()))?;
+    }
+
+    // The demo component lives in a directory of its own
+    let demo_dir = root.join(FEATURES_DIR).join(&plan.table_name);
+    if fs::read_dir(&demo_dir).is_ok_and(|mut entries| entries.next().is_none()) {
+        fs::remove_dir(&demo_dir)
+            .with_context(|| format!("Failed to delete {}", demo_dir.display()))?;
+    }
+
+    for edit in &plan.unwire {
+        fs::write(&edit.path, &edit.updated)
+            .with_context(|| format!("Failed to write {}", edit.path.display()))?;
+    }
+
+    if let Some(rollback) = &plan.rollback {
+        fs::write(
+            rollback,
+            rollback_sql(
+                &plan.table_name,
+                plan.migrations.last().map(PathBuf::as_path),
+            ),
+        )
+        .with_context(|| format!("Failed to write {}", rollback.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::api::test_support::{write, ARTICLE_SCHEMA};
+
+    #[test]
+    fn test_delete_removes_generated_files() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        write(root, "schemas/article.yaml", ARTICLE_SCHEMA);
+        write(
+            root,
+            "supabase/migrations/20250101000000_create_articles_table.sql",
+            "",
+        );
+        write(root, "supabase/functions/articles-crud/index.ts", "");
+        write(root, "supabase/functions/articles-crud/schema.ts", "");
+        write(root, "packages/app-frontend/src/models/Article.ts", "");
+        write(
+            root,
+            "packages/app-frontend/src/components/features/articles/ArticlesDemo.tsx",
+            "",
+        );
+        write(root, "packages/app-frontend/src/models/Tag.ts", "");
+
+        let (name, table_name) = resolve(root, "articles").unwrap();
+        let plan = plan(root, name, table_name);
+        assert_eq!(plan.name, "Article");
+        assert_eq!(plan.files.len(), 3);
+        assert!(plan.unwire.is_empty());
+        let rollback = plan.rollback.clone().unwrap();
+        assert!(rollback
+            .to_string_lossy()
+            .ends_with("_drop_articles_table.sql"));
+
+        apply(root, &plan).unwrap();
+        assert!(!root.join("supabase/functions/articles-crud").exists());
+        assert!(!root
+            .join("packages/app-frontend/src/components/features/articles")
+            .exists());
+        assert!(root
+            .join("packages/app-frontend/src/models/Tag.ts")
+            .exists());
+        assert!(root
+            .join("supabase/migrations/20250101000000_create_articles_table.sql")
+            .exists());
+        let sql = fs::read_to_string(rollback).unwrap();
+        assert!(sql.contains("DROP TABLE IF EXISTS public.articles;"));
+        assert!(sql.contains("rolls back 20250101000000_create_articles_table.sql"));
+
+        // The drop migration now follows the create migration
+        let again = super::plan(root, "Article".to_string(), "articles".to_string());
+        assert!(again.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_unknown_entity() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(resolve(root.path(), "Missing").is_err());
+    }
+}
@@ -0,0 +1,278 @@
+/**
+ * Backend Endpoint Generator
+ * Scaffolds an axum handler module in packages/app-backend
+ *
+ * Generates:
+ * - src/<name>.rs (Request/Response + handler + router + test)
+ * - `mod <name>;` and `.merge(<name>::router())` in src/main.rs
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use super::templates::{self, TemplateEngine};
+use crate::cli::HttpMethod;
+use crate::utils::find_project_root;
+
+const BACKEND_SRC: &str = "packages/app-backend/src";
+
+#[derive(Debug, Serialize)]
+struct AxumHandlerContext {
+    handler_name: String,
+    type_name: String,
+    method: &'static str,
+    path: String,
+    path_params: Vec<String>,
+    extractor: &'static str,
+    extract_imports: String,
+}
+
+/// Validated `api new-endpoint` input
+#[derive(Debug)]
+pub struct EndpointSpec {
+    /// Module and handler name (snake_case)
+    pub module: String,
+    pub method: HttpMethod,
+    pub path: String,
+}
+
+impl EndpointSpec {
+    pub fn new(name: &str, method: HttpMethod, path: &str) -> Result<Self> {
+        let module = templates::snake_case(name);
+        let valid_ident = module
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase())
+            && module
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid_ident {
+            anyhow::bail!(
+                "Invalid endpoint name '{}': use letters, digits, '-' or '_' (e.g. summarize-text)",
+                name
+            );
+        }
+
+        if !path.starts_with("/api/") {
+            anyhow::bail!("Path must start with /api/ (got '{}')", path);
+        }
+        if path.split('/').any(|segment| segment == ":") {
+            anyhow::bail!("Path parameter without a name in '{}'", path);
+        }
+
+        Ok(Self {
+            module,
+            method,
+            path: path.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// `:param` segments of the path, in order
+    pub fn path_params(&self) -> Vec<String> {
+        self.path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix(':'))
+            .map(templates::snake_case)
+            .collect()
+    }
+
+    fn context(&self) -> AxumHandlerContext {
+        let path_params = self.path_params();
+        let extractor = if self.method.has_body() {
+            "Json"
+        } else {
+            "Query"
+        };
+
+        let mut imports = Vec::new();
+        if !path_params.is_empty() {
+            imports.push("Path");
+        }
+        if extractor == "Query" {
+            imports.push("Query");
+        }
+        imports.push("State");
+
+        AxumHandlerContext {
+            handler_name: self.module.clone(),
+            type_name: templates::pascal_case(&self.module),
+            method: self.method.as_str(),
+            path: self.path.clone(),
+            path_params,
+            extractor,
+            extract_imports: imports.join(", "),
+        }
+    }
+}
+
+pub struct EndpointGenerator {
+    spec: EndpointSpec,
+    template_engine: TemplateEngine,
+}
+
+impl EndpointGenerator {
+    pub fn new(spec: EndpointSpec) -> Result<Self> {
+        Ok(Self {
+            spec,
+            template_engine: TemplateEngine::new()?,
+        })
+    }
+
+    pub fn render_module(&self) -> Result<String> {
+        self.template_engine
+            .render("axum_handler", &self.spec.context())
+    }
+
+    /// Write the module and register it in main.rs
+    pub fn write_to_disk(&self, force: bool) -> Result<()> {
+        let src_dir = find_project_root().join(BACKEND_SRC);
+        if !src_dir.is_dir() {
+            anyhow::bail!("Backend source directory not found: {}", src_dir.display());
+        }
+
+        let module_path = src_dir.join(format!("{}.rs", self.spec.module));
+        if module_path.exists() && !force {
+            anyhow::bail!(
+                "{} already exists (use --force to overwrite)",
+                module_path.display()
+            );
+        }
+
+        fs::write(&module_path, self.render_module()?)?;
+        println!(
+            "  {} {}",
+            "✓".green(),
+            module_path.display().to_string().bright_white()
+        );
+
+        let main_path = src_dir.join("main.rs");
+        self.register(&main_path)?;
+
+        Ok(())
+    }
+
+    fn register(&self, main_path: &Path) -> Result<()> {
+        let main_rs = fs::read_to_string(main_path)
+            .with_context(|| format!("Failed to read {}", main_path.display()))?;
+
+        match register_module(&main_rs, &self.spec.module)? {
+            Some(updated) => {
+                fs::write(main_path, updated)?;
+                println!(
+                    "  {} {} (mod + router)",
+                    "✓".green(),
+                    main_path.display().to_string().bright_white()
+                );
+            }
+            None => println!(
+                "  {} {} already registers {}",
+                "•".bright_blue(),
+                main_path.display(),
+                self.spec.module
+            ),
+        }
+
+        Ok(())
+    }
+}
+
+/// Add `mod <module>;` (sorted) and `.merge(<module>::router())` to main.rs.
+///
+/// Returns `None` when the module is already registered.
+pub fn register_module(main_rs: &str, module: &str) -> Result<Option<String>> {
+    let mod_line = format!("mod {};", module);
+    let merge_line = format!(".merge({}::router())", module);
+    if main_rs.lines().any(|line| line.trim() == mod_line) {
+        return Ok(None);
+    }
+
+    let mut lines: Vec<String> = main_rs.lines().map(str::to_string).collect();
+
+    let last_merge = lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with(".merge("))
+        .context("Could not find `.merge(...)` calls in create_router")?;
+    let indent: String = lines[last_merge]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    lines.insert(last_merge + 1, format!("{}{}", indent, merge_line));
+
+    let mod_lines: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("mod ") && line.ends_with(';'))
+        .map(|(index, _)| index)
+        .collect();
+    let insert_at = mod_lines
+        .iter()
+        .find(|&&index| lines[index].as_str() > mod_line.as_str())
+        .copied()
+        .or_else(|| mod_lines.last().map(|index| index + 1))
+        .unwrap_or(0);
+    lines.insert(insert_at, mod_line);
+
+    let mut updated = lines.join("\n");
+    if main_rs.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(Some(updated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAIN_RS: &str = "mod config;\nmod jobs;\nmod state;\n\nfn create_router(state: AppState) -> Router {\n    Router::new()\n        .merge(jobs::router())\n        .with_state(state)\n}\n";
+
+    #[test]
+    fn test_spec_validation() {
+        let spec =
+            EndpointSpec::new("summarize-text", HttpMethod::Post, "/api/aigen/summarize/").unwrap();
+        assert_eq!(spec.module, "summarize_text");
+        assert_eq!(spec.path, "/api/aigen/summarize");
+
+        assert!(EndpointSpec::new("1st", HttpMethod::Post, "/api/x").is_err());
+        assert!(EndpointSpec::new("ok", HttpMethod::Post, "/x").is_err());
+    }
+
+    #[test]
+    fn test_path_params() {
+        let spec = EndpointSpec::new(
+            "GetReport",
+            HttpMethod::Get,
+            "/api/reports/:id/items/:itemId",
+        )
+        .unwrap();
+        assert_eq!(spec.module, "get_report");
+        assert_eq!(spec.path_params(), vec!["id", "item_id"]);
+    }
+
+    #[test]
+    fn test_render_module() {
+        let spec = EndpointSpec::new("get_report", HttpMethod::Get, "/api/reports/:id").unwrap();
+        let generator = EndpointGenerator::new(spec).unwrap();
+        let module = generator.render_module().unwrap();
+
+        assert!(module.contains("extract::{Path, Query, State},"));
+        assert!(module.contains("struct GetReportRequest"));
+        assert!(module.contains("Path(id): Path<String>,"));
+        assert!(module.contains("Query(payload): Query<GetReportRequest>,"));
+        assert!(module.contains(r#".route("/api/reports/:id", get(get_report))"#));
+    }
+
+    #[test]
+    fn test_register_module() {
+        let updated = register_module(MAIN_RS, "summarize").unwrap().unwrap();
+        assert!(updated.contains("mod jobs;\nmod state;\nmod summarize;\n"));
+        assert!(updated
+            .contains("        .merge(jobs::router())\n        .merge(summarize::router())\n"));
+
+        let updated = register_module(MAIN_RS, "images").unwrap().unwrap();
+        assert!(updated.contains("mod config;\nmod images;\nmod jobs;\n"));
+
+        assert!(register_module(&updated, "images").unwrap().is_none());
+    }
+}
@@ -0,0 +1,150 @@
+/**
+ * Test Data Factory Generator
+ * Faker-based builders per entity for tests and Storybook stories
+ *
+ * Generates (packages/app-frontend/src/test/factories/):
+ * - <Entity>Factory.ts (build<Entity> / build<Entity>List / build<Entity>WithRelations)
+ * - helpers.ts (seedFactories, fitLength)
+ * - index.ts (re-exports every factory in the directory)
+ */
+use anyhow::Result;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use super::generator::GeneratedFile;
+use super::generator_contexts::FactoryContext;
+use super::schema::EntitySchema;
+use super::templates::TemplateEngine;
+use crate::utils::find_project_root;
+
+pub const FACTORIES_DIR: &str = "packages/app-frontend/src/test/factories";
+
+pub struct FactoryGenerator<'a> {
+    schemas: &'a [EntitySchema],
+    template_engine: TemplateEngine,
+}
+
+impl<'a> FactoryGenerator<'a> {
+    pub fn new(schemas: &'a [EntitySchema]) -> Result<Self> {
+        Ok(Self {
+            schemas,
+            template_engine: TemplateEngine::new()?,
+        })
+    }
+
+    /// One factory per schema, the shared helpers and the index
+    pub fn generate_all(&self) -> Result<Vec<GeneratedFile>> {
+        let dir = find_project_root().join(FACTORIES_DIR);
+        let entities: Vec<&EntitySchema> = self.schemas.iter().collect();
+
+        let mut files = Vec::new();
+        for schema in self.schemas {
+            let context = FactoryContext::from_schema(schema, &entities);
+            files.push(GeneratedFile {
+                path: dir.join(format!("{}Factory.ts", schema.name)),
+                content: self.template_engine.render("factory", &context)?,
+                description: format!("Factory (build{} / build{}List)", schema.name, schema.name),
+            });
+        }
+
+        files.push(GeneratedFile {
+            path: dir.join("helpers.ts"),
+            content: self
+                .template_engine
+                .render("factory_helpers", &serde_json::json!({}))?,
+            description: "Factory helpers (seedFactories, fitLength)".to_string(),
+        });
+
+        // Keep factories generated in earlier runs exported
+        let mut modules = existing_factories(&dir);
+        modules.extend(self.schemas.iter().map(|s| format!("{}Factory", s.name)));
+        files.push(GeneratedFile {
+            path: dir.join("index.ts"),
+            content: render_index(&modules),
+            description: "Factory index (re-exports)".to_string(),
+        });
+
+        Ok(files)
+    }
+}
+
+/// `<Entity>Factory` modules already present in the factories directory
+fn existing_factories(dir: &Path) -> BTreeSet<String> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    name.strip_suffix("Factory.ts")
+                        .map(|s| format!("{}Factory", s))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn render_index(modules: &BTreeSet<String>) -> String {
+    let mut index = String::from(
+        "/**\n * Test Data Factories\n * Auto-generated by HEADLESS API Generator (akatsuki api factories)\n */\nexport * from './helpers'\n",
+    );
+    for module in modules {
+        index.push_str(&format!("export * from './{}'\n", module));
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(yaml: &str) -> EntitySchema {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_factory_respects_enums_validation_and_relations() {
+        let schemas = vec![
+            schema("name: Author\ntableName: authors\nfields:\n  - name: displayName\n    dbName: display_name\n    type: string\n    required: true\noperations: []\nrls: []\n"),
+            schema(concat!(
+                "name: Article\ntableName: articles\nfields:\n",
+                "  - name: id\n    dbName: id\n    type: uuid\n    primaryKey: true\n",
+                "  - name: title\n    dbName: title\n    type: string\n    validation:\n      minLength: 5\n      maxLength: 40\n",
+                "  - name: status\n    dbName: status\n    type: enum\n    enumValues: [draft, published]\n",
+                "  - name: views\n    dbName: views\n    type: integer\n    validation:\n      min: 1\n      max: 10\n",
+                "  - name: authorId\n    dbName: author_id\n    type: uuid\n    references: authors(id)\n",
+                "  - name: userId\n    dbName: user_id\n    type: uuid\n    references: auth.users(id)\n",
+                "operations: []\nrls: []\n",
+            )),
+        ];
+        let files = FactoryGenerator::new(&schemas)
+            .unwrap()
+            .generate_all()
+            .unwrap();
+        let article = &files[1].content;
+
+        assert!(article.contains("import { fitLength } from './helpers'"));
+        assert!(article.contains("import { buildAuthor } from './AuthorFactory'"));
+        assert!(
+            article.contains("title: fitLength(faker.lorem.sentence({ min: 3, max: 6 }), 5, 40),")
+        );
+        assert!(article
+            .contains("status: faker.helpers.arrayElement(['draft', 'published'] as const),"));
+        assert!(article.contains("views: faker.number.int({ min: 1, max: 10 }),"));
+        assert!(article.contains("userId: faker.string.uuid(),"));
+        assert!(article.contains("  const author = buildAuthor()\n"));
+        assert!(article.contains("    authorId: author.id ?? undefined,\n"));
+        assert!(article.contains("return { article, author }"));
+
+        let author = &files[0].content;
+        assert!(author.contains("displayName: faker.person.fullName(),"));
+        assert!(!author.contains("WithRelations"));
+        assert!(!author.contains("fitLength"));
+
+        let index = &files.last().unwrap().content;
+        assert!(
+            index.contains("export * from './ArticleFactory'\nexport * from './AuthorFactory'\n")
+        );
+    }
+}
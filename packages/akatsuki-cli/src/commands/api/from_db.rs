@@ -0,0 +1,593 @@
+/**
+ * Database Types Import
+ * `akatsuki api new <Entity> --from-db` → EntitySchema of an existing table
+ *
+ * Columns, nullability, defaults (optional in `Insert`), enums and foreign
+ * keys come from supabase/functions/_shared/database.types.ts, the output of
+ * `supabase gen types typescript` (regenerated when the table is missing).
+ * TypeScript has no uuid / timestamp / integer types, so the column types of
+ * the migrations refine them when the table is created there; otherwise
+ * column names decide (`id`, `*_id` → uuid, `*_at` → timestamp).
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use super::from_design::{crud_operations, field, owner_policies};
+use super::schema::{EntitySchema, Field, FieldType};
+use super::templates::{camel_case, pascal_case, plural, singular, snake_case};
+use crate::commands::db::ddl::{self, regex, Column};
+use crate::utils::platform;
+
+pub const DATABASE_TYPES: &str = "supabase/functions/_shared/database.types.ts";
+
+const INTEGER_TYPES: [&str; 9] = [
+    "integer",
+    "int",
+    "int2",
+    "int4",
+    "int8",
+    "smallint",
+    "bigint",
+    "serial",
+    "bigserial",
+];
+
+/// One column of a table's `Row` type
+#[derive(Debug)]
+struct TypedColumn {
+    name: String,
+    /// TypeScript type without `| null`
+    ts_type: String,
+    nullable: bool,
+    /// Optional in `Insert` (nullable or with a default)
+    has_default: bool,
+}
+
+/// A table as `supabase gen types typescript` describes it
+#[derive(Debug)]
+struct TableTypes {
+    table_name: String,
+    columns: Vec<TypedColumn>,
+    /// Column → `table(column)` from `Relationships`
+    references: BTreeMap<String, String>,
+    /// Enum name → values
+    enums: BTreeMap<String, Vec<String>>,
+}
+
+/// Schema for the table named by `entity_name` (entity or table name)
+pub fn import(root: &Path, entity_name: &str) -> Result<EntitySchema> {
+    let path = root.join(DATABASE_TYPES);
+    let content = fs::read_to_string(&path).unwrap_or_default();
+    let types = match find_table(&content, entity_name) {
+        Some(types) => types,
+        None => {
+            println!(
+                "   {} not in {}, regenerating (supabase gen types typescript)",
+                entity_name, DATABASE_TYPES
+            );
+            let content = generate_types(root)?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, &content)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            find_table(&content, entity_name).with_context(|| {
+                format!(
+                    "No table for {} in {} (tables: {})",
+                    entity_name,
+                    DATABASE_TYPES,
+                    table_names(&content).join(", ")
+                )
+            })?
+        }
+    };
+
+    let name = if types.table_name == entity_name && !entity_name.contains(char::is_uppercase) {
+        pascal_case(&singular(entity_name))
+    } else {
+        pascal_case(entity_name)
+    };
+
+    // Migrations are optional: tables created outside them only have TS types
+    let migrations = ddl::schema_from_migrations(root).unwrap_or_default();
+    let columns = migrations
+        .tables
+        .get(&types.table_name)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let indexed: Vec<&str> = migrations
+        .indexes
+        .values()
+        .filter(|index| index.table == types.table_name && index.columns.len() == 1)
+        .map(|index| index.columns[0].as_str())
+        .collect();
+
+    let schema = to_schema(name, &types, columns, &indexed);
+    if !schema.fields.iter().any(|f| f.db_name == "user_id") {
+        println!(
+            "{} {}",
+            "⚠".yellow(),
+            format!(
+                "{} has no user_id column: the generated API assumes per-user rows, review it",
+                types.table_name
+            )
+            .yellow()
+        );
+    }
+    Ok(schema)
+}
+
+fn to_schema(
+    name: String,
+    types: &TableTypes,
+    migration: &[Column],
+    indexed: &[&str],
+) -> EntitySchema {
+    let fields: Vec<Field> = types
+        .columns
+        .iter()
+        .map(|column| {
+            let declared = migration.iter().find(|c| c.name == column.name);
+            let sql_type = declared.map(|c| c.data_type.trim_end_matches("[]"));
+            let (field_type, enum_values, array_type) = field_type(column, sql_type, &types.enums);
+
+            let mut field = field(
+                &camel_case(&column.name),
+                &column.name,
+                field_type,
+                !column.has_default,
+            );
+            field.enum_values = enum_values;
+            field.array_type = array_type;
+            field.primary_key = match declared {
+                Some(declared) => declared.primary_key,
+                None => column.name == "id",
+            };
+            field.references = declared
+                .and_then(|c| c.references.as_ref())
+                .map(|(table, column)| format!("{}({})", table, column))
+                .or_else(|| types.references.get(&column.name).cloned());
+            field.index = !field.primary_key && indexed.contains(&column.name.as_str());
+            field.auto_update = column.name == "updated_at";
+            if column.has_default && !column.nullable {
+                field.default = match field_type {
+                    FieldType::Uuid if field.primary_key => Some("gen_random_uuid()".to_string()),
+                    FieldType::Timestamp => Some("NOW()".to_string()),
+                    _ => None,
+                };
+            }
+            field
+        })
+        .collect();
+
+    let owned = fields.iter().any(|f| f.db_name == "user_id");
+    EntitySchema {
+        name,
+        table_name: types.table_name.clone(),
+        fields,
+        operations: crud_operations(if owned { &["userId"] } else { &[] }),
+        // The table keeps its policies; these only document the usual ones
+        rls: if owned {
+            owner_policies(&types.table_name)
+        } else {
+            Vec::new()
+        },
+        admin_roles: Vec::new(),
+        optimistic_locking: false,
+        soft_delete: false,
+        strict_dates: false,
+        webhooks: Vec::new(),
+        relations: Vec::new(),
+        graphql: false,
+        documentation: None,
+    }
+}
+
+/// (type, enum values, array element type) of a column
+fn field_type(
+    column: &TypedColumn,
+    sql_type: Option<&str>,
+    enums: &BTreeMap<String, Vec<String>>,
+) -> (FieldType, Option<Vec<String>>, Option<String>) {
+    let (element, is_array) = match column.ts_type.strip_suffix("[]") {
+        Some(element) => (element, true),
+        None => (column.ts_type.as_str(), false),
+    };
+
+    if let Some(caps) = regex(r#"^Database\["public"\]\["Enums"\]\["(\w+)"\]$"#).captures(element) {
+        return if is_array {
+            (FieldType::Array, None, Some("string".to_string()))
+        } else {
+            (FieldType::Enum, enums.get(&caps[1]).cloned(), None)
+        };
+    }
+
+    let scalar = match element {
+        "boolean" => FieldType::Boolean,
+        "Json" => FieldType::Json,
+        "number" => match sql_type {
+            Some(sql) if !INTEGER_TYPES.contains(&sql) => FieldType::Number,
+            _ => FieldType::Integer,
+        },
+        "string" => match sql_type {
+            Some("uuid") => FieldType::Uuid,
+            Some(sql) if sql.starts_with("timestamp") || sql == "date" => FieldType::Timestamp,
+            Some(_) => FieldType::String,
+            None if column.name == "id" || column.name.ends_with("_id") => FieldType::Uuid,
+            None if column.name.ends_with("_at") => FieldType::Timestamp,
+            None => FieldType::String,
+        },
+        _ => FieldType::String,
+    };
+    if !is_array {
+        return (scalar, None, None);
+    }
+    let element_type = match scalar {
+        FieldType::Boolean => "boolean",
+        FieldType::Number | FieldType::Integer => "number",
+        FieldType::Uuid => "uuid",
+        _ => "string",
+    };
+    (FieldType::Array, None, Some(element_type.to_string()))
+}
+
+/// The table for `entity_name` as given, or its snake_case / plural form
+fn find_table(content: &str, entity_name: &str) -> Option<TableTypes> {
+    let snake = snake_case(entity_name);
+    [entity_name.to_string(), plural(&snake), snake]
+        .iter()
+        .find_map(|table| parse_table(content, table))
+}
+
+fn parse_table(content: &str, table_name: &str) -> Option<TableTypes> {
+    let public = member(database(content)?, "public")?;
+    let table = member(member(public, "Tables")?, table_name)?;
+
+    let insert = members(member(table, "Insert").unwrap_or_default());
+    let columns = members(member(table, "Row")?)
+        .into_iter()
+        .map(|(name, _, ts_type)| {
+            let mut parts: Vec<&str> = ts_type.split('|').map(str::trim).collect();
+            let nullable = parts.contains(&"null");
+            parts.retain(|part| !part.is_empty() && *part != "null");
+            let has_default = insert
+                .iter()
+                .find(|(column, _, _)| *column == name)
+                .is_none_or(|(_, optional, _)| *optional);
+            TypedColumn {
+                ts_type: parts.join(" | "),
+                name,
+                nullable,
+                has_default,
+            }
+        })
+        .collect();
+
+    let references = member(table, "Relationships")
+        .map(relationships)
+        .unwrap_or_default();
+    let enums = member(public, "Enums")
+        .map(|body| {
+            members(body)
+                .into_iter()
+                .filter(|(name, _, _)| !name.starts_with('['))
+                .map(|(name, _, values)| {
+                    let values = values
+                        .split('|')
+                        .map(|value| value.trim().trim_matches('"').to_string())
+                        .filter(|value| !value.is_empty())
+                        .collect();
+                    (name, values)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(TableTypes {
+        table_name: table_name.to_string(),
+        columns,
+        references,
+        enums,
+    })
+}
+
+fn table_names(content: &str) -> Vec<String> {
+    database(content)
+        .and_then(|body| member(body, "public"))
+        .and_then(|public| member(public, "Tables"))
+        .map(|tables| {
+            members(tables)
+                .into_iter()
+                .filter(|(_, _, ts_type)| ts_type == "{")
+                .map(|(name, _, _)| name)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Body of `export type Database = { ... }` (or the older `interface`)
+fn database(content: &str) -> Option<&str> {
+    let start = ["Database = {", "interface Database {"]
+        .iter()
+        .find_map(|marker| content.find(marker).map(|i| i + marker.len()))?;
+    let end = closing(&content[start..])?;
+    Some(&content[start..start + end])
+}
+
+/// Body between the brackets of the `key: {` / `key: [` member at the top
+/// level of `body`
+fn member<'a>(body: &'a str, key: &str) -> Option<&'a str> {
+    let mut depth = 0;
+    let mut offset = 0;
+    for line in body.split_inclusive('\n') {
+        if depth == 0 {
+            if let Some((name, rest)) = line.trim().split_once(':') {
+                let opens_block = matches!(rest.trim(), "{" | "[");
+                if opens_block && name.trim().trim_matches('"') == key {
+                    let start = offset + line.len();
+                    let end = closing(&body[start..])?;
+                    return Some(&body[start..start + end]);
+                }
+            }
+        }
+        depth += bracket_delta(line);
+        offset += line.len();
+    }
+    None
+}
+
+/// `(name, optional, type)` of the top-level members of `body`; a type
+/// opening a block is `{` / `[`, union types continued on `| ...` lines are
+/// joined
+fn members(body: &str) -> Vec<(String, bool, String)> {
+    let mut found: Vec<(String, bool, String)> = Vec::new();
+    let mut depth = 0;
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if depth == 0 {
+            if trimmed.starts_with('|') {
+                if let Some((_, _, ts_type)) = found.last_mut() {
+                    ts_type.push_str(&format!(" {}", trimmed));
+                    *ts_type = ts_type.trim_start_matches(['|', ' ']).to_string();
+                }
+            } else if let Some((name, ts_type)) = trimmed.split_once(':') {
+                let optional = name.ends_with('?');
+                let name = name.trim_end_matches('?').trim().trim_matches('"');
+                found.push((name.to_string(), optional, ts_type.trim().to_string()));
+            }
+        }
+        depth += bracket_delta(line);
+    }
+    found
+}
+
+/// Single-column foreign keys of a `Relationships` array
+fn relationships(body: &str) -> BTreeMap<String, String> {
+    let list = |text: &str| -> Vec<String> {
+        text.split(',')
+            .map(|item| item.trim().trim_matches('"').to_string())
+            .filter(|item| !item.is_empty())
+            .collect()
+    };
+    let columns = regex(r"columns: \[([^\]]*)\]");
+    let relation = regex(r#"referencedRelation: "(\w+)""#);
+    let referenced = regex(r"referencedColumns: \[([^\]]*)\]");
+
+    body.split("foreignKeyName")
+        .skip(1)
+        .filter_map(|entry| {
+            let columns = list(&columns.captures(entry)?[1]);
+            let table = relation.captures(entry)?[1].to_string();
+            let referenced = list(&referenced.captures(entry)?[1]);
+            match (columns.as_slice(), referenced.as_slice()) {
+                ([column], [target]) => Some((column.clone(), format!("{}({})", table, target))),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Offset of the bracket closing the one just before `text`
+fn closing(text: &str) -> Option<usize> {
+    let mut depth = 1;
+    let mut in_string = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '{' | '[' if !in_string => depth += 1,
+            '}' | ']' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn bracket_delta(line: &str) -> i32 {
+    let mut delta = 0;
+    let mut in_string = false;
+    for c in line.chars() {
+        match c {
+            '"' => in_string = !in_string,
+            '{' | '[' if !in_string => delta += 1,
+            '}' | ']' if !in_string => delta -= 1,
+            _ => {}
+        }
+    }
+    delta
+}
+
+/// `supabase gen types typescript` for the local stack, else the linked project
+fn generate_types(root: &Path) -> Result<String> {
+    let mut errors = Vec::new();
+    for target in ["--local", "--linked"] {
+        let output = platform::command("supabase")
+            .args(["gen", "types", "typescript", target])
+            .current_dir(root)
+            .output()
+            .context("Failed to run supabase gen types. Make sure Supabase CLI is installed.")?;
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+        }
+        errors.push(format!(
+            "{}: {}",
+            target,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    anyhow::bail!(
+        "supabase gen types typescript failed (start the local stack or link a project)\n{}",
+        errors.join("\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPES: &str = r#"export type Json =
+  | string
+  | number
+  | { [key: string]: Json | undefined }
+  | Json[]
+
+export type Database = {
+  public: {
+    Tables: {
+      blog_posts: {
+        Row: {
+          body: string | null
+          created_at: string
+          id: string
+          rating: number | null
+          status: Database["public"]["Enums"]["post_status"]
+          tags: string[] | null
+          metadata: Json | null
+          author_id: string
+          user_id: string
+        }
+        Insert: {
+          body?: string | null
+          created_at?: string
+          id?: string
+          rating?: number | null
+          status?: Database["public"]["Enums"]["post_status"]
+          tags?: string[] | null
+          metadata?: Json | null
+          author_id: string
+          user_id: string
+        }
+        Update: {
+          body?: string | null
+        }
+        Relationships: [
+          {
+            foreignKeyName: "blog_posts_author_id_fkey"
+            columns: ["author_id"]
+            isOneToOne: false
+            referencedRelation: "authors"
+            referencedColumns: ["id"]
+          },
+        ]
+      }
+      authors: {
+        Row: {
+          id: string
+        }
+        Insert: {
+          id?: string
+        }
+        Update: {
+          id?: string
+        }
+        Relationships: []
+      }
+    }
+    Views: {
+      [_ in never]: never
+    }
+    Enums: {
+      post_status:
+        | "draft"
+        | "published"
+    }
+  }
+}
+"#;
+
+    fn field<'a>(schema: &'a EntitySchema, db_name: &str) -> &'a Field {
+        schema.fields.iter().find(|f| f.db_name == db_name).unwrap()
+    }
+
+    #[test]
+    fn test_parse_table_from_types() {
+        let types = find_table(TYPES, "BlogPost").unwrap();
+        assert_eq!(types.table_name, "blog_posts");
+        assert_eq!(types.columns.len(), 9);
+        assert_eq!(types.enums["post_status"], ["draft", "published"]);
+        assert_eq!(types.references["author_id"], "authors(id)");
+        assert_eq!(table_names(TYPES), ["blog_posts", "authors"]);
+
+        let schema = to_schema("BlogPost".to_string(), &types, &[], &[]);
+        assert_eq!(field(&schema, "id").field_type, FieldType::Uuid);
+        assert!(field(&schema, "id").primary_key);
+        assert_eq!(
+            field(&schema, "id").default.as_deref(),
+            Some("gen_random_uuid()")
+        );
+        assert_eq!(
+            field(&schema, "created_at").field_type,
+            FieldType::Timestamp
+        );
+        assert_eq!(field(&schema, "created_at").name, "createdAt");
+        assert!(!field(&schema, "body").required);
+        assert!(field(&schema, "author_id").required);
+        assert_eq!(
+            field(&schema, "author_id").references.as_deref(),
+            Some("authors(id)")
+        );
+        assert_eq!(field(&schema, "status").field_type, FieldType::Enum);
+        assert_eq!(
+            field(&schema, "status").enum_values.as_deref(),
+            Some(&["draft".to_string(), "published".to_string()][..])
+        );
+        assert_eq!(field(&schema, "tags").field_type, FieldType::Array);
+        assert_eq!(field(&schema, "tags").array_type.as_deref(), Some("string"));
+        assert_eq!(field(&schema, "metadata").field_type, FieldType::Json);
+        assert_eq!(schema.operations[0].filters, ["userId"]);
+        assert_eq!(schema.rls.len(), 4);
+    }
+
+    #[test]
+    fn test_migration_types_refine_columns() {
+        let mut migrations = ddl::Schema::default();
+        migrations.apply(
+            "CREATE TABLE public.blog_posts (id UUID PRIMARY KEY, rating NUMERIC(3, 1), \
+             user_id UUID NOT NULL REFERENCES auth.users(id));\
+             CREATE INDEX idx_blog_posts_user_id ON public.blog_posts(user_id);",
+        );
+        let types = find_table(TYPES, "blog_posts").unwrap();
+        let schema = to_schema(
+            "BlogPost".to_string(),
+            &types,
+            &migrations.tables["blog_posts"],
+            &["user_id"],
+        );
+        assert_eq!(field(&schema, "rating").field_type, FieldType::Number);
+        assert_eq!(
+            field(&schema, "user_id").references.as_deref(),
+            Some("auth.users(id)")
+        );
+        assert!(field(&schema, "user_id").index);
+        // Without migration info numbers default to integers
+        let schema = to_schema("BlogPost".to_string(), &types, &[], &[]);
+        assert_eq!(field(&schema, "rating").field_type, FieldType::Integer);
+    }
+}
@@ -0,0 +1,585 @@
+/**
+ * Design Document Import
+ * `akatsuki api from-design <design-file>` → workspace/<entity>-schema.yaml
+ *
+ * Two ways to describe an entity in a design document:
+ * - an embedded ```yaml block holding a full EntitySchema (`name`, `fields`, ...)
+ * - a markdown table under a heading naming the entity:
+ *
+ *   ### Entity: Article (articles)
+ *   | Field | Type | Required | Notes |
+ *   |---|---|---|---|
+ *   | title | string | yes | max 200 |
+ *   | status | enum(draft, published) | yes | default: draft, index |
+ *
+ * Tables are completed with the standard columns (id, userId, createdAt,
+ * updatedAt), CRUD operations and owner RLS policies, like the example schema.
+ */
+use anyhow::{bail, Result};
+use serde_yaml::Value;
+
+use super::schema::{
+    EntitySchema, Field, FieldType, Operation, OperationType, RLSPolicy, Validation,
+};
+use super::templates::{camel_case, pascal_case, plural, snake_case};
+
+/// Entities found in a design document, in document order
+#[derive(Debug, Default)]
+pub struct Import {
+    pub schemas: Vec<EntitySchema>,
+    /// Blocks and tables that looked like entities but could not be used
+    pub warnings: Vec<String>,
+}
+
+pub fn import(content: &str) -> Import {
+    let mut import = Import::default();
+    // (schema, from a YAML block)
+    let mut found: Vec<(EntitySchema, bool)> = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut heading = String::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        if let Some(lang) = line.strip_prefix("```") {
+            let end = (i + 1..lines.len())
+                .find(|&j| lines[j].trim().starts_with("```"))
+                .unwrap_or(lines.len());
+            if matches!(lang.trim(), "yaml" | "yml") {
+                let block = lines[i + 1..end].join("\n");
+                if let Some(schema) = import_yaml(&block, i + 1, &mut import.warnings) {
+                    found.push((schema, true));
+                }
+            }
+            i = end + 1;
+            continue;
+        }
+
+        if line.starts_with('#') {
+            heading = line.trim_start_matches('#').trim().to_string();
+        } else if line.starts_with('|') && lines.get(i + 1).is_some_and(|next| is_separator(next)) {
+            let end = (i + 2..lines.len())
+                .find(|&j| !lines[j].trim().starts_with('|'))
+                .unwrap_or(lines.len());
+            if let Some(schema) =
+                import_table(&heading, &lines[i..end], i + 1, &mut import.warnings)
+            {
+                found.push((schema, false));
+            }
+            i = end;
+            continue;
+        }
+        i += 1;
+    }
+
+    // A YAML block is the more complete definition when both exist
+    let mut seen: Vec<&str> = Vec::new();
+    for (schema, from_yaml) in &found {
+        let shadowed = !from_yaml && found.iter().any(|(s, yaml)| *yaml && s.name == schema.name);
+        if shadowed {
+            import.warnings.push(format!(
+                "{}: table ignored, a YAML block defines it",
+                schema.name
+            ));
+        } else if seen.contains(&schema.name.as_str()) {
+            import.warnings.push(format!(
+                "{}: defined twice, using the first definition",
+                schema.name
+            ));
+        } else {
+            seen.push(&schema.name);
+            import.schemas.push(schema.clone());
+        }
+    }
+    import
+}
+
+/// YAML without nulls, `false` and empty lists (all serde defaults)
+pub fn to_yaml(schema: &EntitySchema) -> Result<String> {
+    let mut value = serde_yaml::to_value(schema)?;
+    prune(&mut value);
+    Ok(serde_yaml::to_string(&value)?)
+}
+
+/// A YAML block counts as a schema when it has `name` and `fields`
+fn import_yaml(block: &str, line: usize, warnings: &mut Vec<String>) -> Option<EntitySchema> {
+    let Ok(Value::Mapping(map)) = serde_yaml::from_str::<Value>(block) else {
+        return None;
+    };
+    if !map.contains_key("name") || !map.contains_key("fields") {
+        return None;
+    }
+    serde_yaml::from_str(block)
+        .map_err(|e| warnings.push(format!("line {}: YAML schema block skipped: {}", line, e)))
+        .ok()
+}
+
+/// A table counts as entity fields when it has Field and Type columns
+fn import_table(
+    heading: &str,
+    rows: &[&str],
+    line: usize,
+    warnings: &mut Vec<String>,
+) -> Option<EntitySchema> {
+    let header: Vec<String> = cells(rows[0])
+        .iter()
+        .map(|cell| cell.to_lowercase())
+        .collect();
+    let column = |names: &[&str]| header.iter().position(|h| names.contains(&h.as_str()));
+    let (Some(name_col), Some(type_col)) = (
+        column(&["field", "name", "column", "field name"]),
+        column(&["type"]),
+    ) else {
+        return None;
+    };
+    let required_col = column(&["required", "null", "nullable"]);
+    let nullable = required_col.is_some_and(|col| header[col] != "required");
+    let default_col = column(&["default"]);
+    let notes_col = column(&["notes", "note", "constraints", "description", "options"]);
+
+    let Some((name, table_name)) = entity_from_heading(heading) else {
+        warnings.push(format!(
+            "line {}: table skipped, no entity heading above it (e.g. `### Entity: Article`)",
+            line
+        ));
+        return None;
+    };
+
+    let mut fields = Vec::new();
+    for (offset, row) in rows[2..].iter().enumerate() {
+        let cells = cells(row);
+        let cell = |col: Option<usize>| col.and_then(|c| cells.get(c)).cloned().unwrap_or_default();
+        let field_name = cell(Some(name_col));
+        if field_name.is_empty() {
+            continue;
+        }
+        let required = match required_col {
+            Some(_) if nullable => !is_yes(&cell(required_col)),
+            Some(_) => is_yes(&cell(required_col)),
+            None => false,
+        };
+        match field_from_row(
+            &field_name,
+            &cell(Some(type_col)),
+            required,
+            &cell(default_col),
+            &cell(notes_col),
+        ) {
+            Ok(field) => fields.push(field),
+            Err(e) => warnings.push(format!(
+                "line {}: {}.{} skipped: {}",
+                line + 2 + offset,
+                name,
+                field_name,
+                e
+            )),
+        }
+    }
+    (!fields.is_empty()).then(|| complete(name, table_name, fields))
+}
+
+/// `Entity: BlogPost (blog_posts)` → ("BlogPost", Some("blog_posts"))
+fn entity_from_heading(heading: &str) -> Option<(String, Option<String>)> {
+    let text = heading
+        .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.')
+        .trim();
+    let prefixed = ["Entity:", "Entity", "Table:", "Table", "Model:"]
+        .iter()
+        .find_map(|prefix| text.strip_prefix(prefix));
+    // Without a prefix only a bare name counts (`### Article`), not `## Data Model`
+    let text = match prefixed {
+        Some(rest) => rest.trim(),
+        None if text.split('(').next()?.trim().contains(' ') => return None,
+        None => text,
+    };
+    let (name, table) = match text.split_once('(') {
+        Some((name, rest)) => (
+            name.trim(),
+            rest.split(')')
+                .next()
+                .map(|table| table.trim().trim_matches('`').to_string()),
+        ),
+        None => (text, None),
+    };
+    let name = pascal_case(&snake_case(name.trim_matches('`')));
+    let valid = name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric());
+    valid.then_some((name, table.filter(|t| !t.is_empty())))
+}
+
+fn field_from_row(
+    name: &str,
+    type_text: &str,
+    required: bool,
+    default: &str,
+    notes: &str,
+) -> Result<Field> {
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        bail!("invalid field name");
+    }
+    let db_name = snake_case(name);
+    let code_name = camel_case(&db_name);
+    let (field_type, enum_values, array_type) = parse_type(type_text)?;
+
+    let mut field = field(&code_name, &db_name, field_type, required);
+    field.enum_values = enum_values;
+    field.array_type = array_type;
+    if !default.is_empty() && default != "-" {
+        field.default = Some(default.to_string());
+    }
+
+    let mut validation = Validation::default();
+    for note in notes.split([',', ';']).map(str::trim) {
+        let lower = note.to_lowercase();
+        let number = || {
+            lower
+                .split_whitespace()
+                .last()
+                .and_then(|n| n.trim_end_matches(')').parse::<usize>().ok())
+        };
+        if lower == "index" || lower == "indexed" {
+            field.index = true;
+        } else if lower == "gin" || lower == "gin index" {
+            field.index = true;
+            field.index_type = Some("gin".to_string());
+        } else if lower == "unique" {
+            field.unique = true;
+        } else if lower == "email" {
+            validation.email = true;
+        } else if lower == "url" {
+            validation.url = true;
+        } else if let Some(value) = strip_key(note, "default") {
+            field.default = Some(value.to_string());
+        } else if let Some(target) = strip_key(note, "references").or(strip_key(note, "fk")) {
+            field.references = Some(target.to_string());
+        } else if let Some(action) = lower.strip_prefix("on delete ") {
+            field.on_delete = Some(action.trim().to_uppercase());
+        } else if lower.starts_with("max") || lower.starts_with("min") {
+            let Some(value) = number() else { continue };
+            let is_max = lower.starts_with("max");
+            match (field_type, is_max) {
+                (FieldType::Number | FieldType::Integer, true) => {
+                    validation.max = Some(value as f64)
+                }
+                (FieldType::Number | FieldType::Integer, false) => {
+                    validation.min = Some(value as f64)
+                }
+                (_, true) => validation.max_length = Some(value),
+                (_, false) => validation.min_length = Some(value),
+            }
+        }
+    }
+    let has_validation = validation.email
+        || validation.url
+        || validation.min.is_some()
+        || validation.max.is_some()
+        || validation.min_length.is_some()
+        || validation.max_length.is_some();
+    if has_validation {
+        field.validation = Some(validation);
+    }
+    Ok(field)
+}
+
+/// `enum(a, b)`, `enum: a | b`, `string[]`, `array<uuid>`, `text`, `int`, ...
+fn parse_type(text: &str) -> Result<(FieldType, Option<Vec<String>>, Option<String>)> {
+    let lower = text.trim().to_lowercase();
+
+    if let Some(rest) = lower.strip_prefix("enum") {
+        let values: Vec<String> = rest
+            .trim_matches(|c: char| c == ':' || c.is_whitespace())
+            .trim_matches(['(', ')', '[', ']'])
+            .split([',', '|', '/'])
+            .map(|value| value.trim().trim_matches(['\'', '"']).to_string())
+            .filter(|value| !value.is_empty())
+            .collect();
+        if values.is_empty() {
+            bail!("enum type needs values, e.g. enum(draft, published)");
+        }
+        return Ok((FieldType::Enum, Some(values), None));
+    }
+
+    let element = lower
+        .strip_suffix("[]")
+        .or_else(|| lower.strip_prefix("array<")?.strip_suffix('>'));
+    if let Some(element) = element {
+        let element = match scalar_type(element) {
+            Some(FieldType::Number | FieldType::Integer) => "number",
+            Some(FieldType::Boolean) => "boolean",
+            Some(FieldType::Uuid) => "uuid",
+            Some(FieldType::String) => "string",
+            _ => bail!("unsupported array element type '{}'", element),
+        };
+        return Ok((FieldType::Array, None, Some(element.to_string())));
+    }
+
+    match scalar_type(&lower) {
+        Some(field_type) => Ok((field_type, None, None)),
+        None => bail!("unknown type '{}'", text.trim()),
+    }
+}
+
+fn scalar_type(name: &str) -> Option<FieldType> {
+    Some(match name.split('(').next().unwrap_or_default().trim() {
+        "string" | "text" | "varchar" | "char" => FieldType::String,
+        "number" | "numeric" | "decimal" | "float" | "double" => FieldType::Number,
+        "integer" | "int" | "bigint" | "smallint" => FieldType::Integer,
+        "boolean" | "bool" => FieldType::Boolean,
+        "uuid" | "id" => FieldType::Uuid,
+        "timestamp" | "timestamptz" | "datetime" | "date" => FieldType::Timestamp,
+        "json" | "jsonb" | "object" => FieldType::Json,
+        _ => return None,
+    })
+}
+
+/// Add the standard columns, CRUD operations and owner RLS policies
+fn complete(name: String, table_name: Option<String>, fields: Vec<Field>) -> EntitySchema {
+    let table_name = table_name.unwrap_or_else(|| plural(&snake_case(&name)));
+    let has = |db_name: &str| fields.iter().any(|f| f.db_name == db_name);
+
+    let mut all = Vec::new();
+    if !has("id") {
+        let mut id = field("id", "id", FieldType::Uuid, true);
+        id.primary_key = true;
+        id.default = Some("gen_random_uuid()".to_string());
+        all.push(id);
+    }
+    if !has("user_id") {
+        let mut user_id = field("userId", "user_id", FieldType::Uuid, true);
+        user_id.references = Some("auth.users(id)".to_string());
+        user_id.on_delete = Some("CASCADE".to_string());
+        user_id.index = true;
+        all.push(user_id);
+    }
+    let needs_created = !has("created_at");
+    let needs_updated = !has("updated_at");
+    all.extend(fields);
+    if needs_created {
+        let mut created = field("createdAt", "created_at", FieldType::Timestamp, true);
+        created.default = Some("NOW()".to_string());
+        created.index = true;
+        all.push(created);
+    }
+    if needs_updated {
+        let mut updated = field("updatedAt", "updated_at", FieldType::Timestamp, true);
+        updated.default = Some("NOW()".to_string());
+        updated.auto_update = true;
+        all.push(updated);
+    }
+
+    let rls = owner_policies(&table_name);
+    EntitySchema {
+        name,
+        table_name,
+        fields: all,
+        operations: crud_operations(&["userId"]),
+        rls,
+        admin_roles: Vec::new(),
+        optimistic_locking: false,
+        soft_delete: false,
+        strict_dates: false,
+        webhooks: Vec::new(),
+        relations: Vec::new(),
+        graphql: false,
+        documentation: None,
+    }
+}
+
+/// List / get / create / update / delete, listing filterable by `list_filters`
+pub(super) fn crud_operations(list_filters: &[&str]) -> Vec<Operation> {
+    [
+        OperationType::List,
+        OperationType::Get,
+        OperationType::Create,
+        OperationType::Update,
+        OperationType::Delete,
+    ]
+    .into_iter()
+    .map(|op_type| Operation {
+        op_type,
+        name: None,
+        description: None,
+        filters: if op_type == OperationType::List {
+            list_filters.iter().map(|f| f.to_string()).collect()
+        } else {
+            Vec::new()
+        },
+        limit: None,
+        pagination: None,
+    })
+    .collect()
+}
+
+/// Policies limiting every action to the rows of `auth.uid() = user_id`
+pub(super) fn owner_policies(table_name: &str) -> Vec<RLSPolicy> {
+    let owner = "auth.uid() = user_id".to_string();
+    let policy = |action: &str, verb: &str, using: bool, check: bool| RLSPolicy {
+        action: action.to_string(),
+        name: format!("Users can {} own {}", verb, table_name),
+        using: using.then(|| owner.clone()),
+        with_check: check.then(|| owner.clone()),
+    };
+    vec![
+        policy("SELECT", "view", true, false),
+        policy("INSERT", "insert", false, true),
+        policy("UPDATE", "update", true, true),
+        policy("DELETE", "delete", true, false),
+    ]
+}
+
+pub(super) fn field(name: &str, db_name: &str, field_type: FieldType, required: bool) -> Field {
+    Field {
+        name: name.to_string(),
+        db_name: db_name.to_string(),
+        field_type,
+        required,
+        default: None,
+        primary_key: false,
+        references: None,
+        on_delete: None,
+        index: false,
+        index_type: None,
+        unique: false,
+        enum_values: None,
+        array_type: None,
+        validation: None,
+        auto_update: false,
+    }
+}
+
+fn prune(value: &mut Value) {
+    match value {
+        Value::Mapping(map) => {
+            map.retain(|_, v| {
+                prune(v);
+                !matches!(v, Value::Null | Value::Bool(false))
+                    && !matches!(v, Value::Sequence(s) if s.is_empty())
+            });
+        }
+        Value::Sequence(items) => items.iter_mut().for_each(prune),
+        _ => {}
+    }
+}
+
+fn cells(row: &str) -> Vec<String> {
+    row.trim()
+        .trim_start_matches('|')
+        .trim_end_matches('|')
+        .split('|')
+        .map(|cell| cell.trim().trim_matches('`').trim().to_string())
+        .collect()
+}
+
+fn is_separator(line: &str) -> bool {
+    let line = line.trim();
+    line.starts_with('|')
+        && line.contains('-')
+        && line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '))
+}
+
+fn is_yes(cell: &str) -> bool {
+    matches!(
+        cell.to_lowercase().as_str(),
+        "yes" | "y" | "true" | "required" | "x" | "✓" | "✔" | "✅" | "○" | "◯" | "必須"
+    )
+}
+
+/// `default: draft` / `default draft` → "draft"
+fn strip_key<'a>(note: &'a str, key: &str) -> Option<&'a str> {
+    let rest = note.get(..key.len())?;
+    if !rest.eq_ignore_ascii_case(key) {
+        return None;
+    }
+    let value = note[key.len()..].trim_start_matches([':', ' ', '=']).trim();
+    (!value.is_empty()).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESIGN: &str = "# Blog - Design Document\n\n\
+        ### Entity: Article\n\n\
+        | Field | Type | Required | Notes |\n\
+        |---|---|---|---|\n\
+        | title | string | yes | max 200 |\n\
+        | `status` | enum(draft, published) | yes | default: draft, index |\n\
+        | tags | string[] | no | gin |\n\
+        | view_count | int | | min 0 |\n\
+        | score | money | yes | |\n\n\
+        ### 2. Category (categories)\n\n\
+        | Name | Type | Nullable |\n\
+        |:--|:--|:--|\n\
+        | label | text | no |\n\n\
+        ```yaml\n\
+        name: Comment\n\
+        tableName: comments\n\
+        fields:\n  - name: body\n    dbName: body\n    type: string\n\
+        operations: []\n\
+        rls: []\n\
+        ```\n\n\
+        | Screen | Route |\n|---|---|\n| Home | / |\n";
+
+    #[test]
+    fn test_import_tables_and_yaml() {
+        let import = import(DESIGN);
+        let names: Vec<&str> = import.schemas.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Article", "Category", "Comment"]);
+        assert_eq!(import.warnings.len(), 1);
+        assert!(import.warnings[0].contains("Article.score skipped: unknown type 'money'"));
+
+        let article = &import.schemas[0];
+        assert_eq!(article.table_name, "articles");
+        let fields: Vec<&str> = article.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "id",
+                "userId",
+                "title",
+                "status",
+                "tags",
+                "viewCount",
+                "createdAt",
+                "updatedAt"
+            ]
+        );
+        let status = &article.fields[3];
+        assert_eq!(status.field_type, FieldType::Enum);
+        assert_eq!(status.default.as_deref(), Some("draft"));
+        assert!(status.index && status.required);
+        assert_eq!(article.fields[4].array_type.as_deref(), Some("string"));
+        assert_eq!(article.fields[4].index_type.as_deref(), Some("gin"));
+        assert_eq!(
+            article.fields[2].validation.as_ref().unwrap().max_length,
+            Some(200)
+        );
+        assert_eq!(article.fields[5].db_name, "view_count");
+        assert!(!article.fields[5].required);
+
+        let category = &import.schemas[1];
+        assert_eq!(category.table_name, "categories");
+        assert!(category.fields[2].required);
+    }
+
+    #[test]
+    fn test_to_yaml_is_a_valid_schema() {
+        let import = import(DESIGN);
+        let yaml = to_yaml(&import.schemas[0]).unwrap();
+        assert!(!yaml.contains("null"));
+        assert!(!yaml.contains("false"));
+        let schema = EntitySchema::from_yaml_str(&yaml).unwrap();
+        assert_eq!(schema.fields.len(), 8);
+        assert_eq!(schema.rls.len(), 4);
+    }
+
+    #[test]
+    fn test_entity_from_heading() {
+        assert_eq!(
+            entity_from_heading("Entity: blog_post (posts)"),
+            Some(("BlogPost".to_string(), Some("posts".to_string())))
+        );
+        assert_eq!(entity_from_heading("Screen Design (UX-Focused)"), None);
+    }
+}
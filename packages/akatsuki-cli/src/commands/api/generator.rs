@@ -9,28 +9,41 @@ use std::fs;
 use std::path::PathBuf;
 
 use super::generator_contexts::{
-    AdminPageContext, CLIClientContext, DemoComponentContext, EdgeFunctionContext, HookContext,
-    ModelContext, RepositoryEdgeContext, ServiceContext,
+    relations_to_context, AdminPageContext, CLIClientContext, DemoComponentContext,
+    EdgeFunctionContext, GraphqlContext, HookContext, ModelContext, RelationAccessorContext,
+    RepositoryEdgeContext, ServiceContext, WebhookFunctionContext,
 };
+use super::manifest::{snapshot_path, ManifestWriter};
 use super::schema::EntitySchema;
 use super::templates::TemplateEngine;
+use super::{DeclaredColumn, DeclaredIndex, DeclaredTable};
 use crate::utils::find_project_root;
 
 pub struct GeneratedFiles {
     // Backend (Supabase Edge Functions)
-    pub migration: GeneratedFile,
+    /// `None` for tables that already exist (`api new --from-db`)
+    pub migration: Option<GeneratedFile>,
     pub zod_schema: GeneratedFile,
     pub repository_edge: GeneratedFile,
     pub edge_function: GeneratedFile,
+    /// Only when the schema has `webhooks`
+    pub webhook_function: Option<GeneratedFile>,
+    /// Only with `graphql: true` / `--with-graphql`
+    pub graphql_function: Option<GeneratedFile>,
     // Frontend (React)
     pub model: GeneratedFile,
     pub service: GeneratedFile,
     pub hook: GeneratedFile,
+    /// Only with `graphql: true` / `--with-graphql`
+    pub graphql_hook: Option<GeneratedFile>,
     // UI Components
     pub admin_page: GeneratedFile,
     pub demo_component: GeneratedFile,
     // CLI (Node.js)
     pub cli_client: GeneratedFile,
+    /// The schema itself (.akatsuki/schemas/<table>.yaml), written with the
+    /// migration: what `api update` diffs against
+    pub schema_snapshot: GeneratedFile,
 }
 
 pub struct GeneratedFile {
@@ -39,53 +52,85 @@ pub struct GeneratedFile {
     pub description: String,
 }
 
-impl GeneratedFiles {
+impl GeneratedFile {
     pub fn write_to_disk(&self) -> Result<()> {
-        // Backend
-        self.write_file(&self.migration)?;
-        self.write_file(&self.zod_schema)?;
-        self.write_file(&self.repository_edge)?;
-        self.write_file(&self.edge_function)?;
-
-        // Frontend
-        self.write_file(&self.model)?;
-        self.write_file(&self.service)?;
-        self.write_file(&self.hook)?;
-
-        // UI Components
-        self.write_file(&self.admin_page)?;
-        self.write_file(&self.demo_component)?;
-
-        // CLI
-        self.write_file(&self.cli_client)?;
-
-        Ok(())
-    }
-
-    fn write_file(&self, file: &GeneratedFile) -> Result<()> {
         // Create parent directory if not exists
-        if let Some(parent) = file.path.parent() {
+        if let Some(parent) = self.path.parent() {
             fs::create_dir_all(parent)?;
         }
 
         // Write file
-        fs::write(&file.path, &file.content)?;
+        fs::write(&self.path, &self.content)?;
 
         println!(
             "  {} {}",
             "✓".green(),
-            file.path.display().to_string().bright_white()
+            self.path.display().to_string().bright_white()
         );
 
         Ok(())
     }
+}
+
+impl GeneratedFiles {
+    /// Every file except the (timestamped) migration
+    pub fn code_files(&self) -> Vec<&GeneratedFile> {
+        let mut files = vec![
+            &self.zod_schema,
+            &self.repository_edge,
+            &self.edge_function,
+        ];
+        files.extend(self.webhook_function.as_ref());
+        files.extend(self.graphql_function.as_ref());
+        files.extend([&self.model, &self.service, &self.hook]);
+        files.extend(self.graphql_hook.as_ref());
+        files.extend([
+            &self.admin_page,
+            &self.demo_component,
+            &self.cli_client,
+        ]);
+        files
+    }
+
+    /// Write every file; hand-edited code files are only replaced with
+    /// `force` (see `ManifestWriter`)
+    pub fn write_to_disk(&self, force: bool) -> Result<()> {
+        if let Some(migration) = &self.migration {
+            migration.write_to_disk()?;
+            self.schema_snapshot.write_to_disk()?;
+        }
+
+        let mut writer = ManifestWriter::open(&find_project_root(), force)?;
+        for file in self.code_files() {
+            writer.write(file)?;
+        }
+        writer.finish()
+    }
+
+    /// Drop the migration when an earlier `*_create_<table>_table.sql`
+    /// exists: regenerating must not create the table twice (schema changes
+    /// go through `akatsuki db plan`)
+    pub fn keep_existing_migration(&mut self) {
+        let Some(existing) = self.migration.as_ref().and_then(existing_migration) else {
+            return;
+        };
+        println!(
+            "  {} {} {}",
+            "=".bright_black(),
+            existing.display(),
+            "(table migration exists, not regenerated)".bright_black()
+        );
+        self.migration = None;
+    }
 
     pub fn print_summary(&self) {
         println!(
             "\n  {} Backend (Supabase Edge Functions):",
             "📦".bright_blue()
         );
-        println!("    {} {}", "•".bright_blue(), self.migration.description);
+        if let Some(migration) = &self.migration {
+            println!("    {} {}", "•".bright_blue(), migration.description);
+        }
         println!("    {} {}", "•".bright_blue(), self.zod_schema.description);
         println!(
             "    {} {}",
@@ -97,11 +142,20 @@ impl GeneratedFiles {
             "•".bright_blue(),
             self.edge_function.description
         );
+        if let Some(webhook_function) = &self.webhook_function {
+            println!("    {} {}", "•".bright_blue(), webhook_function.description);
+        }
+        if let Some(graphql_function) = &self.graphql_function {
+            println!("    {} {}", "•".bright_blue(), graphql_function.description);
+        }
 
         println!("\n  {} Frontend (React):", "⚛️".bright_blue());
         println!("    {} {}", "•".bright_blue(), self.model.description);
         println!("    {} {}", "•".bright_blue(), self.service.description);
         println!("    {} {}", "•".bright_blue(), self.hook.description);
+        if let Some(graphql_hook) = &self.graphql_hook {
+            println!("    {} {}", "•".bright_blue(), graphql_hook.description);
+        }
 
         println!("\n  {} UI Components:", "🎨".bright_blue());
         println!("    {} {}", "•".bright_blue(), self.admin_page.description);
@@ -116,6 +170,20 @@ impl GeneratedFiles {
     }
 }
 
+/// Same migration name with any timestamp
+fn existing_migration(migration: &GeneratedFile) -> Option<PathBuf> {
+    let name = migration.path.file_name()?.to_str()?;
+    let suffix = &name[name.find('_')?..];
+    fs::read_dir(migration.path.parent()?)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .find(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(suffix))
+        })
+}
+
 pub struct CodeGenerator {
     schema: EntitySchema,
     template_engine: TemplateEngine,
@@ -134,19 +202,23 @@ impl CodeGenerator {
     pub fn generate_all(&self) -> Result<GeneratedFiles> {
         Ok(GeneratedFiles {
             // Backend
-            migration: self.generate_migration()?,
+            migration: Some(self.generate_migration()?),
             zod_schema: self.generate_zod_schema()?,
             repository_edge: self.generate_repository_edge()?,
             edge_function: self.generate_edge_function()?,
+            webhook_function: self.generate_webhook_function()?,
+            graphql_function: self.generate_graphql_function()?,
             // Frontend
             model: self.generate_model()?,
             service: self.generate_service()?,
             hook: self.generate_hook()?,
+            graphql_hook: self.generate_graphql_hook()?,
             // UI Components
             admin_page: self.generate_admin_page()?,
             demo_component: self.generate_demo_component()?,
             // CLI
             cli_client: self.generate_cli_client()?,
+            schema_snapshot: self.generate_schema_snapshot()?,
         })
     }
 
@@ -165,7 +237,7 @@ impl CodeGenerator {
         Ok(GeneratedFile {
             path,
             content,
-            description: format!("Migration (Table + RLS + Indexes)"),
+            description: "Migration (Table + RLS + Indexes)".to_string(),
         })
     }
 
@@ -183,7 +255,7 @@ impl CodeGenerator {
         Ok(GeneratedFile {
             path,
             content,
-            description: format!("Zod Schema (Validation)"),
+            description: "Zod Schema (Validation)".to_string(),
         })
     }
 
@@ -200,7 +272,7 @@ impl CodeGenerator {
         Ok(GeneratedFile {
             path,
             content,
-            description: format!("Repository (Edge Functions)"),
+            description: "Repository (Edge Functions)".to_string(),
         })
     }
 
@@ -218,10 +290,52 @@ impl CodeGenerator {
         Ok(GeneratedFile {
             path,
             content,
-            description: format!("Edge Function (createAkatsukiHandler)"),
+            description: "Edge Function (createAkatsukiHandler)".to_string(),
         })
     }
 
+    fn generate_webhook_function(&self) -> Result<Option<GeneratedFile>> {
+        if self.schema.webhooks.is_empty() {
+            return Ok(None);
+        }
+
+        let context = WebhookFunctionContext::from_schema(&self.schema);
+        let content = self.template_engine.render("webhook_function", &context)?;
+
+        let project_root = find_project_root();
+        let path = project_root
+            .join("supabase/functions")
+            .join(format!("{}-webhooks", self.schema.table_name))
+            .join("index.ts");
+
+        Ok(Some(GeneratedFile {
+            path,
+            content,
+            description: "Webhook Function (signed change forwarding)".to_string(),
+        }))
+    }
+
+    fn generate_graphql_function(&self) -> Result<Option<GeneratedFile>> {
+        if !self.schema.graphql {
+            return Ok(None);
+        }
+
+        let context = GraphqlContext::from_schema(&self.schema);
+        let content = self.template_engine.render("graphql_function", &context)?;
+
+        let project_root = find_project_root();
+        let path = project_root
+            .join("supabase/functions")
+            .join(format!("{}-graphql", self.schema.table_name))
+            .join("index.ts");
+
+        Ok(Some(GeneratedFile {
+            path,
+            content,
+            description: "GraphQL Function (Yoga typeDefs + resolvers)".to_string(),
+        }))
+    }
+
     // ================== Frontend Generators ==================
 
     fn generate_model(&self) -> Result<GeneratedFile> {
@@ -236,7 +350,7 @@ impl CodeGenerator {
         Ok(GeneratedFile {
             path,
             content,
-            description: format!("Model (fromDatabase/toDatabase)"),
+            description: "Model (fromDatabase/toDatabase)".to_string(),
         })
     }
 
@@ -252,7 +366,7 @@ impl CodeGenerator {
         Ok(GeneratedFile {
             path,
             content,
-            description: format!("Service (EdgeFunctionService wrapper)"),
+            description: "Service (EdgeFunctionService wrapper)".to_string(),
         })
     }
 
@@ -268,10 +382,30 @@ impl CodeGenerator {
         Ok(GeneratedFile {
             path,
             content,
-            description: format!("Hook (React Query CRUD)"),
+            description: "Hook (React Query CRUD)".to_string(),
         })
     }
 
+    fn generate_graphql_hook(&self) -> Result<Option<GeneratedFile>> {
+        if !self.schema.graphql {
+            return Ok(None);
+        }
+
+        let context = GraphqlContext::from_schema(&self.schema);
+        let content = self.template_engine.render("graphql_hook", &context)?;
+
+        let project_root = find_project_root();
+        let path = project_root
+            .join("packages/app-frontend/src/hooks")
+            .join(format!("use{}sGraphQL.ts", self.schema.name));
+
+        Ok(Some(GeneratedFile {
+            path,
+            content,
+            description: "Hook (React Query GraphQL)".to_string(),
+        }))
+    }
+
     // ================== UI Component Generators ==================
 
     fn generate_admin_page(&self) -> Result<GeneratedFile> {
@@ -324,6 +458,20 @@ impl CodeGenerator {
             description: format!("CLI Client ({}sClient)", self.schema.name),
         })
     }
+
+    fn generate_schema_snapshot(&self) -> Result<GeneratedFile> {
+        let content = format!(
+            "# {} as of its last migration (compared by `akatsuki api update`)\n{}",
+            self.schema.table_name,
+            serde_yaml::to_string(&self.schema)?
+        );
+
+        Ok(GeneratedFile {
+            path: snapshot_path(&find_project_root(), &self.schema.table_name),
+            content,
+            description: format!("Schema snapshot ({})", self.schema.table_name),
+        })
+    }
 }
 
 /// Context for migration template
@@ -334,8 +482,17 @@ struct MigrationContext {
     fields: Vec<FieldContext>,
     indexed_fields: Vec<FieldContext>,
     rls: Vec<RLSPolicyContext>,
+    /// Quoted SQL list of `adminRoles`, e.g. `'admin', 'editor'`
+    admin_roles_sql: Option<String>,
+    /// Trigger events for webhooks, e.g. `INSERT OR UPDATE`
+    webhook_events_sql: Option<String>,
     has_updated_at: bool,
     documentation: DocumentationContext,
+    optimistic_locking: bool,
+    soft_delete: bool,
+    relations: Vec<RelationAccessorContext>,
+    /// Join table links are managed by the row's `user_id` owner
+    owner_column: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -451,6 +608,24 @@ impl MigrationContext {
             })
             .collect();
 
+        let admin_roles_sql = (!schema.admin_roles.is_empty()).then(|| {
+            schema
+                .admin_roles
+                .iter()
+                .map(|role| format!("'{}'", role))
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+
+        let webhook_events = schema.webhook_events();
+        let webhook_events_sql = (!webhook_events.is_empty()).then(|| {
+            webhook_events
+                .iter()
+                .map(|event| event.as_str())
+                .collect::<Vec<_>>()
+                .join(" OR ")
+        });
+
         // === 4. Check if schema has updatedAt field (for trigger generation) ===
         let has_updated_at = schema.fields.iter().any(|f| f.name == "updatedAt");
 
@@ -460,6 +635,8 @@ impl MigrationContext {
             fields,
             indexed_fields,
             rls,
+            admin_roles_sql,
+            webhook_events_sql,
             has_updated_at,
             documentation: DocumentationContext {
                 description: schema
@@ -467,7 +644,91 @@ impl MigrationContext {
                     .as_ref()
                     .and_then(|d| d.description.clone()),
             },
+            optimistic_locking: schema.optimistic_locking,
+            soft_delete: schema.soft_delete,
+            relations: relations_to_context(schema),
+            owner_column: schema.fields.iter().any(|f| f.db_name == "user_id"),
+        }
+    }
+}
+
+impl FieldContext {
+    /// Column definition as the migration template writes it (type and constraints)
+    fn column_sql(&self) -> String {
+        let mut sql = self.sql_type.clone();
+        if self.required {
+            sql.push_str(" NOT NULL");
+        }
+        if let Some(default) = &self.default {
+            sql.push_str(&format!(" DEFAULT {}", default));
+        }
+        if self.primary_key {
+            sql.push_str(" PRIMARY KEY");
         }
+        if self.unique {
+            sql.push_str(" UNIQUE");
+        }
+        if let Some(references) = &self.references {
+            sql.push_str(&format!(" REFERENCES {}", references));
+            if let Some(on_delete) = &self.on_delete {
+                sql.push_str(&format!(" ON DELETE {}", on_delete));
+            }
+        }
+        if let Some(values) = &self.enum_values {
+            let values: Vec<String> = values.iter().map(|v| format!("'{}'", v)).collect();
+            sql.push_str(&format!(
+                " CHECK ({} IN ({}))",
+                self.db_name,
+                values.join(", ")
+            ));
+        }
+        sql
+    }
+}
+
+/// Columns and indexes the schema's migration creates (for `akatsuki db plan`)
+pub fn declared_table(schema: &EntitySchema) -> DeclaredTable {
+    let context = MigrationContext::from_schema(schema);
+    let mut columns: Vec<DeclaredColumn> = context
+        .fields
+        .iter()
+        .map(|field| DeclaredColumn {
+            name: field.db_name.clone(),
+            sql_type: field.sql_type.clone(),
+            not_null: field.required || field.primary_key,
+            definition: field.column_sql(),
+        })
+        .collect();
+    if context.optimistic_locking {
+        columns.push(DeclaredColumn {
+            name: "version".to_string(),
+            sql_type: "INTEGER".to_string(),
+            not_null: true,
+            definition: "INTEGER NOT NULL DEFAULT 1".to_string(),
+        });
+    }
+    if context.soft_delete {
+        columns.push(DeclaredColumn {
+            name: "deleted_at".to_string(),
+            sql_type: "TIMESTAMPTZ".to_string(),
+            not_null: false,
+            definition: "TIMESTAMPTZ".to_string(),
+        });
+    }
+
+    DeclaredTable {
+        name: context.name.clone(),
+        table_name: context.table_name.clone(),
+        columns,
+        indexes: context
+            .indexed_fields
+            .iter()
+            .map(|field| DeclaredIndex {
+                name: format!("idx_{}_{}", context.table_name, field.db_name),
+                column: field.db_name.clone(),
+                gin: field.index_type.as_deref() == Some("gin"),
+            })
+            .collect(),
     }
 }
 
@@ -481,6 +742,11 @@ struct ZodSchemaContext {
     writable_fields: Vec<ZodFieldContext>,
     updatable_fields: Vec<ZodFieldContext>,
     operations: Vec<OperationContext>,
+    /// Columns accepted by `orderBy` (list / export)
+    sortable_columns: Vec<String>,
+    optimistic_locking: bool,
+    soft_delete: bool,
+    relations: Vec<RelationAccessorContext>,
 }
 
 #[derive(Debug, Serialize)]
@@ -488,6 +754,8 @@ struct ZodFieldContext {
     name: String,
     db_name: String,
     zod_type: String,
+    /// Zod type used in `filters` (coerced, see `Field::zod_filter_type`)
+    filter_zod_type: String,
     required: bool,
     enum_values: Option<Vec<String>>,
 }
@@ -499,6 +767,7 @@ struct OperationContext {
     description: Option<String>,
     filters: Vec<String>,
     limit: Option<usize>,
+    pagination: Option<String>,
 }
 
 impl ZodSchemaContext {
@@ -509,7 +778,8 @@ impl ZodSchemaContext {
             .map(|f| ZodFieldContext {
                 name: f.name.clone(),
                 db_name: f.db_name.clone(),
-                zod_type: f.zod_type(),
+                zod_type: f.zod_type(schema.strict_dates),
+                filter_zod_type: f.zod_filter_type(schema.strict_dates),
                 required: f.required,
                 enum_values: f.enum_values.clone(),
             })
@@ -521,7 +791,8 @@ impl ZodSchemaContext {
             .map(|f| ZodFieldContext {
                 name: f.name.clone(),
                 db_name: f.db_name.clone(),
-                zod_type: f.zod_type(),
+                zod_type: f.zod_type(schema.strict_dates),
+                filter_zod_type: f.zod_filter_type(schema.strict_dates),
                 required: f.required,
                 enum_values: f.enum_values.clone(),
             })
@@ -533,7 +804,8 @@ impl ZodSchemaContext {
             .map(|f| ZodFieldContext {
                 name: f.name.clone(),
                 db_name: f.db_name.clone(),
-                zod_type: f.zod_type(),
+                zod_type: f.zod_type(schema.strict_dates),
+                filter_zod_type: f.zod_filter_type(schema.strict_dates),
                 required: f.required,
                 enum_values: f.enum_values.clone(),
             })
@@ -545,7 +817,8 @@ impl ZodSchemaContext {
             .map(|f| ZodFieldContext {
                 name: f.name.clone(),
                 db_name: f.db_name.clone(),
-                zod_type: f.zod_type(),
+                zod_type: f.zod_type(schema.strict_dates),
+                filter_zod_type: f.zod_filter_type(schema.strict_dates),
                 required: f.required,
                 enum_values: f.enum_values.clone(),
             })
@@ -573,6 +846,7 @@ impl ZodSchemaContext {
                     .cloned()
                     .collect(),
                 limit: op.limit,
+                pagination: op.pagination.map(|p| p.as_str().to_string()),
             })
             .collect();
 
@@ -584,6 +858,353 @@ impl ZodSchemaContext {
             writable_fields,
             updatable_fields,
             operations,
+            sortable_columns: schema
+                .sortable_fields()
+                .iter()
+                .map(|f| f.db_name.clone())
+                .collect(),
+            optimistic_locking: schema.optimistic_locking,
+            soft_delete: schema.soft_delete,
+            relations: relations_to_context(schema),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migration_admin_roles_policy() {
+        let mut schema: EntitySchema = serde_yaml::from_str(
+            "name: Article\ntableName: articles\nfields: []\noperations: []\nrls: []\n",
+        )
+        .unwrap();
+        let engine = TemplateEngine::new().unwrap();
+
+        let sql = engine
+            .render("migration", &MigrationContext::from_schema(&schema))
+            .unwrap();
+        assert!(!sql.contains("Admin roles can manage"));
+
+        schema.admin_roles = vec!["admin".to_string(), "editor".to_string()];
+        let sql = engine
+            .render("migration", &MigrationContext::from_schema(&schema))
+            .unwrap();
+        assert!(sql.contains("CREATE POLICY \"Admin roles can manage articles\""));
+        assert!(sql
+            .contains("USING ((auth.jwt() -> 'app_metadata' ->> 'role') IN ('admin', 'editor'))"));
+    }
+
+    #[test]
+    fn test_migration_version_column() {
+        let mut schema: EntitySchema = serde_yaml::from_str(
+            "name: Article\ntableName: articles\nfields:\n  - name: title\n    dbName: title\n    type: string\noperations: []\nrls: []\n",
+        )
+        .unwrap();
+        schema.optimistic_locking = true;
+        let sql = TemplateEngine::new()
+            .unwrap()
+            .render("migration", &MigrationContext::from_schema(&schema))
+            .unwrap();
+        assert!(sql.contains("  title TEXT,\n  version INTEGER NOT NULL DEFAULT 1\n);"));
+    }
+
+    #[test]
+    fn test_declared_table_matches_migration() {
+        let mut schema: EntitySchema = serde_yaml::from_str(
+            r#"
+name: Article
+tableName: articles
+fields:
+  - name: id
+    dbName: id
+    type: uuid
+    primaryKey: true
+    default: gen_random_uuid()
+  - name: userId
+    dbName: user_id
+    type: uuid
+    required: true
+    references: auth.users(id)
+    onDelete: CASCADE
+    index: true
+  - name: status
+    dbName: status
+    type: enum
+    enumValues: [draft, published]
+    default: draft
+  - name: tags
+    dbName: tags
+    type: array
+    default: "[]"
+    index: true
+    indexType: gin
+operations: []
+rls: []
+"#,
+        )
+        .unwrap();
+        schema.optimistic_locking = true;
+        let sql = TemplateEngine::new()
+            .unwrap()
+            .render("migration", &MigrationContext::from_schema(&schema))
+            .unwrap();
+
+        let table = declared_table(&schema);
+        for column in &table.columns {
+            let line = format!("  {} {}", column.name, column.definition);
+            assert!(sql.contains(&line), "{} not in\n{}", line, sql);
         }
+        assert_eq!(table.columns[1].sql_type, "UUID");
+        assert!(table.columns[1].not_null);
+        assert_eq!(table.columns[4].name, "version");
+        assert_eq!(table.indexes.len(), 2);
+        assert_eq!(table.indexes[0].name, "idx_articles_user_id");
+        assert!(table.indexes[1].gin);
+    }
+
+    #[test]
+    fn test_zod_schema_strict_dates_and_coercion() {
+        let yaml = "name: Event\ntableName: events\nfields:\n  - name: startsAt\n    dbName: starts_at\n    type: timestamp\n  - name: seats\n    dbName: seats\n    type: integer\noperations:\n  - type: list\n    filters: [startsAt, seats]\n    limit: 50\n  - type: get\nrls: []\n";
+        let engine = TemplateEngine::new().unwrap();
+
+        let mut schema: EntitySchema = serde_yaml::from_str(yaml).unwrap();
+        let ts = engine
+            .render("zod_schema", &ZodSchemaContext::from_schema(&schema))
+            .unwrap();
+        assert!(ts.contains("  startsAt: z.string(),\n"));
+        assert!(ts.contains("      seats: z.coerce.number().int().optional(),\n"));
+        assert!(ts.contains("    limit: z.coerce.number().int().positive().max(50).optional(),\n"));
+        assert!(ts.contains("    id: idSchema,\n"));
+
+        schema.strict_dates = true;
+        let ts = engine
+            .render("zod_schema", &ZodSchemaContext::from_schema(&schema))
+            .unwrap();
+        assert!(ts.contains("  startsAt: z.string().datetime({ offset: true }),\n"));
+        assert!(ts.contains(
+            "      startsAt: z.coerce.date().transform((date) => date.toISOString()).optional(),\n"
+        ));
+    }
+
+    #[test]
+    fn test_graphql_function_and_hooks() {
+        let yaml = concat!(
+            "name: Task\ntableName: tasks\ngraphql: true\nfields:\n",
+            "  - name: id\n    dbName: id\n    type: uuid\n    primaryKey: true\n",
+            "  - name: title\n    dbName: title\n    type: string\n    required: true\n",
+            "  - name: status\n    dbName: status\n    type: enum\n    enumValues: [todo, done]\n",
+            "  - name: dueAt\n    dbName: due_at\n    type: timestamp\n",
+            "operations:\n  - type: list\n    filters: [status]\n  - type: get\n  - type: create\n",
+            "  - type: update\n  - type: delete\n  - type: custom\n    name: my\nrls: []\n",
+        );
+        let schema: EntitySchema = serde_yaml::from_str(yaml).unwrap();
+        let files = CodeGenerator::new(schema.clone()).generate_all().unwrap();
+
+        let function = files.graphql_function.as_ref().unwrap();
+        assert!(function.path.ends_with("supabase/functions/tasks-graphql/index.ts"));
+        let ts = &function.content;
+        assert!(ts.contains("  enum TaskStatus {\n    todo\n    done\n  }"));
+        assert!(ts.contains("    title: String!\n    status: TaskStatus\n    dueAt: String\n"));
+        assert!(ts.contains("    tasks(filter: TaskFilter, limit: Int, offset: Int, orderBy: String, ascending: Boolean): [Task!]!"));
+        assert!(ts.contains("    myTasks: [Task!]!"));
+        assert!(ts.contains("    createTask(input: CreateTaskInput!): Task!"));
+        assert!(ts.contains("  dueAt: 'due_at',\n"));
+        assert!(ts.contains("graphqlEndpoint: '/tasks-graphql',"));
+
+        let hook = &files.graphql_hook.as_ref().unwrap().content;
+        assert!(hook.contains("export function useTasksQuery(variables: TasksQueryVariables = {}) {"));
+        assert!(hook.contains("tasks(filter: $filter, limit: $limit, offset: $offset, orderBy: $orderBy, ascending: $ascending) { ${TASK_FIELDS} }"));
+        assert!(hook.contains("export function useMyTasksQuery() {"));
+        assert!(hook.contains("export function useDeleteTaskMutation() {"));
+
+        let mut plain = schema;
+        plain.graphql = false;
+        let files = CodeGenerator::new(plain).generate_all().unwrap();
+        assert!(files.graphql_function.is_none() && files.graphql_hook.is_none());
+    }
+
+    #[test]
+    fn test_migration_webhooks_trigger() {
+        let schema: EntitySchema = serde_yaml::from_str(
+            "name: Article\ntableName: articles\nfields: []\noperations: []\nrls: []\nwebhooks:\n  - url: https://example.com/hook\n    events: [INSERT, UPDATE]\n    secretEnv: ARTICLE_WEBHOOK_SECRET\n",
+        )
+        .unwrap();
+        let sql = TemplateEngine::new()
+            .unwrap()
+            .render("migration", &MigrationContext::from_schema(&schema))
+            .unwrap();
+        assert!(sql.contains("CREATE TABLE IF NOT EXISTS public.articles_webhook_deliveries"));
+        assert!(sql.contains("AFTER INSERT OR UPDATE ON public.articles"));
+        assert!(sql.contains("/functions/v1/articles-webhooks"));
+    }
+
+    #[test]
+    fn test_relations() {
+        let schema = EntitySchema::from_yaml_str(concat!(
+            "name: Article\ntableName: articles\nfields:\n",
+            "  - name: id\n    dbName: id\n    type: uuid\n    primaryKey: true\n",
+            "  - name: userId\n    dbName: user_id\n    type: uuid\n    required: true\n",
+            "operations:\n  - type: get\nrls: []\nrelations:\n",
+            "  - name: comments\n    type: hasMany\n    target: Comment\n    targetTable: comments\n",
+            "  - name: tags\n    type: manyToMany\n    target: Tag\n    targetTable: tags\n",
+        ))
+        .unwrap();
+        let files = CodeGenerator::new(schema).generate_all().unwrap();
+
+        let sql = &files.migration.as_ref().unwrap().content;
+        assert!(sql.contains("-- 7. Relations"));
+        assert!(sql.contains(
+            "CREATE INDEX IF NOT EXISTS idx_comments_article_id ON public.comments(article_id);"
+        ));
+        assert!(sql.contains("CREATE TABLE IF NOT EXISTS public.article_tags (\n  article_id UUID NOT NULL REFERENCES public.articles(id) ON DELETE CASCADE,\n  tag_id UUID NOT NULL REFERENCES public.tags(id) ON DELETE CASCADE,"));
+        assert!(sql.contains("PRIMARY KEY (article_id, tag_id)"));
+        assert!(sql.contains("parent.id = article_tags.article_id AND parent.user_id = auth.uid()"));
+
+        let zod = &files.zod_schema.content;
+        assert!(zod.contains("action: z.literal('listComments'),"));
+        assert!(zod.contains(
+            "action: z.literal('unlinkTags'),\n    id: idSchema,\n    targetId: idSchema,"
+        ));
+        assert!(!zod.contains("linkComments"));
+
+        let repository = &files.repository_edge.content;
+        assert!(repository.contains("import type { Comment } from './CommentRepository.ts'"));
+        assert!(repository.contains("async listComments(id: string, options?: { limit?: number; offset?: number }): Promise<Comment[]> {"));
+        assert!(repository.contains(".select('tags(*)')"));
+        assert!(repository.contains(
+            ".upsert([{ article_id: id, tag_id: targetId }], { ignoreDuplicates: true })"
+        ));
+
+        assert!(files.edge_function.content.contains("case 'linkTags': {"));
+        assert!(files
+            .service
+            .content
+            .contains("import type { TagDatabaseRecord } from '../models/Tag'"));
+        assert!(files.service.content.contains("EdgeFunctionService.invoke<CommentDatabaseRecord[]>('articles-crud', {\n      action: 'listComments',"));
+        let hook = &files.hook.content;
+        assert!(hook.contains("export function useArticleComments("));
+        assert!(hook.contains("export function useArticleTags("));
+        assert!(hook.contains("Tag.fromDatabase(data)"));
+    }
+
+    #[test]
+    fn test_list_pagination() {
+        let yaml = |pagination: &str| {
+            format!(
+                concat!(
+                    "name: Post\ntableName: posts\nfields:\n",
+                    "  - name: id\n    dbName: id\n    type: uuid\n    primaryKey: true\n",
+                    "  - name: title\n    dbName: title\n    type: string\n",
+                    "  - name: createdAt\n    dbName: created_at\n    type: timestamp\n",
+                    "operations:\n  - type: list\n    pagination: {}\n  - type: get\nrls: []\n",
+                ),
+                pagination
+            )
+        };
+
+        let schema = EntitySchema::from_yaml_str(&yaml("cursor")).unwrap();
+        let files = CodeGenerator::new(schema).generate_all().unwrap();
+        assert!(files
+            .zod_schema
+            .content
+            .contains("    cursor: z.string().min(1).optional(),\n"));
+        assert!(files
+            .repository_edge
+            .content
+            .contains("async findPageAfter(filters: {"));
+        assert!(files
+            .edge_function
+            .content
+            .contains("return postRepo.findPageAfter({"));
+        assert!(files
+            .service
+            .content
+            .contains("EdgeFunctionService.invoke<PostPage>('posts-crud', {"));
+        let hook = &files.hook.content;
+        assert!(hook.contains("useInfiniteQuery"));
+        assert!(hook.contains("export function usePostsInfinite("));
+        assert!(hook.contains("nextCursor"));
+
+        let schema = EntitySchema::from_yaml_str(&yaml("offset")).unwrap();
+        let files = CodeGenerator::new(schema).generate_all().unwrap();
+        assert!(files
+            .zod_schema
+            .content
+            .contains("    offset: z.coerce.number()"));
+        assert!(files
+            .repository_edge
+            .content
+            .contains("async findPage(filters: {"));
+        assert!(files
+            .repository_edge
+            .content
+            .contains(".select('*', { count: 'exact' })"));
+        assert!(files.hook.content.contains("nextOffset"));
+
+        let schema =
+            EntitySchema::from_yaml_str(&yaml("offset").replace("\n    pagination: offset", ""))
+                .unwrap();
+        let files = CodeGenerator::new(schema).generate_all().unwrap();
+        assert!(files
+            .edge_function
+            .content
+            .contains("return postRepo.findAll({"));
+        assert!(!files.hook.content.contains("useInfiniteQuery"));
+    }
+
+    #[test]
+    fn test_soft_delete() {
+        let yaml = concat!(
+            "name: Post\ntableName: posts\nsoftDelete: true\nfields:\n",
+            "  - name: id\n    dbName: id\n    type: uuid\n    primaryKey: true\n",
+            "  - name: title\n    dbName: title\n    type: string\n",
+            "operations:\n  - type: list\n  - type: delete\nrls: []\n",
+        );
+        let schema = EntitySchema::from_yaml_str(yaml).unwrap();
+        assert!(schema.soft_delete);
+        let table = declared_table(&schema);
+        assert_eq!(table.columns.last().unwrap().name, "deleted_at");
+
+        let files = CodeGenerator::new(schema).generate_all().unwrap();
+        let migration = files.migration.unwrap().content;
+        assert!(migration.contains("  title TEXT,\n  deleted_at TIMESTAMPTZ\n);"));
+        let zod = &files.zod_schema.content;
+        assert!(zod.contains("    includeDeleted: z.boolean().optional(),\n"));
+        assert!(zod.contains("action: z.literal('restore'),"));
+        let repository = &files.repository_edge.content;
+        assert!(repository.contains(".update({ deleted_at: new Date().toISOString() })"));
+        assert!(repository.contains("async restore(id: string): Promise<Post | null> {"));
+        assert!(repository.contains("query = query.is('deleted_at', null)"));
+        let edge_function = &files.edge_function.content;
+        assert!(edge_function.contains("case 'restore': {"));
+        assert!(edge_function.contains("includeDeleted: input.includeDeleted,"));
+        assert!(files.service.content.contains("action: 'restore',"));
+        assert!(files
+            .hook
+            .content
+            .contains("restorePost: (id) => restoreMutation.mutate(id),"));
+        assert!(files
+            .cli_client
+            .content
+            .contains("includeDeleted: filters.includeDeleted,"));
+
+        // Without softDelete, deletes stay hard deletes
+        let schema = EntitySchema::from_yaml_str(&yaml.replace("softDelete: true\n", "")).unwrap();
+        let files = CodeGenerator::new(schema).generate_all().unwrap();
+        assert!(!files.migration.unwrap().content.contains("deleted_at"));
+        assert!(files
+            .repository_edge
+            .content
+            .contains(".delete().eq('id', id)"));
+        assert!(!files.zod_schema.content.contains("includeDeleted"));
+        assert!(!files.hook.content.contains("restore"));
+
+        let conflicting = yaml.replace(
+            "operations:",
+            "  - name: deletedAt\n    dbName: deleted_at\n    type: timestamp\noperations:",
+        );
+        assert!(EntitySchema::from_yaml_str(&conflicting).is_err());
     }
 }
@@ -10,13 +10,14 @@
 use serde::Serialize;
 use std::collections::HashSet;
 
-use super::schema::{EntitySchema, Field, Operation, OperationType};
+use super::schema::{EntitySchema, Field, Operation, OperationType, RelationType};
 
 // ============================================================================
 // Core Traits - DSL → AST → View transformation
 // ============================================================================
 
 /// Type-safe transformation trait from Schema types to Context types
+#[allow(clippy::wrong_self_convention)]
 pub trait IntoContext<T> {
     fn into_context(&self) -> T;
 }
@@ -78,6 +79,8 @@ pub struct OperationContext {
     pub description: Option<String>,
     pub filters: Vec<String>,
     pub limit: Option<usize>,
+    /// "cursor" | "offset" (list only)
+    pub pagination: Option<String>,
 }
 
 impl IntoContext<OperationContext> for Operation {
@@ -88,6 +91,7 @@ impl IntoContext<OperationContext> for Operation {
             description: self.description.clone(),
             filters: self.filters.clone(),
             limit: self.limit,
+            pagination: self.pagination.map(|p| p.as_str().to_string()),
         }
     }
 }
@@ -142,6 +146,7 @@ impl<'a> OperationContextBuilder<'a> {
                     .cloned()
                     .collect(),
                 limit: op.limit,
+                pagination: op.pagination.map(|p| p.as_str().to_string()),
             })
             .collect()
     }
@@ -159,6 +164,76 @@ pub struct CustomOpContext {
     pub limit: Option<usize>,
 }
 
+// ============================================================================
+// Relation Accessor Context - nested list / link operations
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelationAccessorContext {
+    /// Accessor name (camelCase, e.g., "comments")
+    pub name: String,
+    /// Suffix of the generated actions and methods (`listComments`)
+    pub pascal_name: String,
+    pub kind: &'static str,
+    pub target: String,
+    pub target_table: String,
+    /// Column pointing at this entity: on the target table (hasMany) or on
+    /// the join table (manyToMany)
+    pub foreign_key: String,
+    /// manyToMany only
+    pub join_table: Option<String>,
+    /// Join table column pointing at the target (manyToMany)
+    pub target_key: String,
+}
+
+pub(super) fn relations_to_context(schema: &EntitySchema) -> Vec<RelationAccessorContext> {
+    schema
+        .relations
+        .iter()
+        .map(|relation| {
+            let mut chars = relation.name.chars();
+            let pascal_name = chars
+                .next()
+                .map(|first| first.to_uppercase().collect::<String>() + chars.as_str())
+                .unwrap_or_default();
+            let many_to_many = relation.kind == RelationType::ManyToMany;
+            RelationAccessorContext {
+                name: relation.name.clone(),
+                pascal_name,
+                kind: relation.kind.as_str(),
+                target: relation.target.clone(),
+                target_table: relation.target_table.clone(),
+                foreign_key: if many_to_many {
+                    schema.foreign_key_column()
+                } else {
+                    relation.foreign_key(schema)
+                },
+                join_table: many_to_many.then(|| relation.join_table(schema)),
+                target_key: relation.target_key(),
+            }
+        })
+        .collect()
+}
+
+/// Relation targets other than the entity itself, deduplicated (type imports)
+/// Pagination of the list operation ("cursor" | "offset")
+fn list_pagination(schema: &EntitySchema) -> Option<String> {
+    schema
+        .operation(OperationType::List)
+        .and_then(|op| op.pagination)
+        .map(|p| p.as_str().to_string())
+}
+
+fn related_entities(schema: &EntitySchema) -> Vec<String> {
+    let mut entities: Vec<String> = Vec::new();
+    for relation in &schema.relations {
+        if relation.target != schema.name && !entities.contains(&relation.target) {
+            entities.push(relation.target.clone());
+        }
+    }
+    entities
+}
+
 // ============================================================================
 // Helper functions for common field set conversions
 // ============================================================================
@@ -191,6 +266,18 @@ pub struct RepositoryEdgeContext {
     pub list_filters: Vec<String>,
     pub all_filters: Vec<String>,
     pub custom_operations: Vec<CustomOpContext>,
+    pub has_export: bool,
+    /// Row cap for CSV export (export operation `limit`, default 10000)
+    pub export_limit: usize,
+    /// Update takes the expected `version` (optimisticLocking)
+    pub optimistic_locking: bool,
+    /// Delete sets `deleted_at`; finders skip deleted rows (softDelete)
+    pub soft_delete: bool,
+    pub relations: Vec<RelationAccessorContext>,
+    /// Repositories whose row types the relation methods return
+    pub related_entities: Vec<String>,
+    /// Paged finder for the list operation ("cursor" | "offset")
+    pub list_pagination: Option<String>,
 }
 
 impl RepositoryEdgeContext {
@@ -240,6 +327,16 @@ impl RepositoryEdgeContext {
             list_filters,
             all_filters,
             custom_operations,
+            has_export: schema.operation(OperationType::Export).is_some(),
+            export_limit: schema
+                .operation(OperationType::Export)
+                .and_then(|op| op.limit)
+                .unwrap_or(10_000),
+            optimistic_locking: schema.optimistic_locking,
+            soft_delete: schema.soft_delete,
+            relations: relations_to_context(schema),
+            related_entities: related_entities(schema),
+            list_pagination: list_pagination(schema),
         }
     }
 }
@@ -251,6 +348,52 @@ pub struct EdgeFunctionContext {
     pub table_name: String,
     pub operations: Vec<OperationContext>,
     pub writable_fields: Vec<FieldContext>,
+    pub optimistic_locking: bool,
+    pub soft_delete: bool,
+    pub relations: Vec<RelationAccessorContext>,
+}
+
+/// Context for Webhook Function template
+#[derive(Debug, Serialize)]
+pub struct WebhookFunctionContext {
+    pub name: String,
+    pub table_name: String,
+    pub webhooks: Vec<WebhookContext>,
+    /// Distinct `secretEnv` names (for the setup instructions)
+    pub secret_envs: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookContext {
+    pub url: String,
+    pub events: Vec<&'static str>,
+    pub secret_env: String,
+}
+
+impl WebhookFunctionContext {
+    pub fn from_schema(schema: &EntitySchema) -> Self {
+        let mut secret_envs: Vec<String> = Vec::new();
+        for webhook in &schema.webhooks {
+            if !secret_envs.contains(&webhook.secret_env) {
+                secret_envs.push(webhook.secret_env.clone());
+            }
+        }
+
+        Self {
+            name: schema.name.clone(),
+            table_name: schema.table_name.clone(),
+            webhooks: schema
+                .webhooks
+                .iter()
+                .map(|webhook| WebhookContext {
+                    url: webhook.url.clone(),
+                    events: webhook.events.iter().map(|event| event.as_str()).collect(),
+                    secret_env: webhook.secret_env.clone(),
+                })
+                .collect(),
+            secret_envs,
+        }
+    }
 }
 
 /// Context for Frontend Model template
@@ -262,6 +405,8 @@ pub struct ModelContext {
     pub writable_fields: Vec<FieldContext>,
     pub updatable_fields: Vec<FieldContext>,
     pub enum_fields: Vec<EnumFieldContext>,
+    pub optimistic_locking: bool,
+    pub soft_delete: bool,
 }
 
 impl ModelContext {
@@ -273,6 +418,8 @@ impl ModelContext {
             writable_fields: fields_to_context(&schema.writable_fields()),
             updatable_fields: fields_to_context(&schema.updatable_fields()),
             enum_fields: enum_fields_to_context(schema),
+            optimistic_locking: schema.optimistic_locking,
+            soft_delete: schema.soft_delete,
         }
     }
 }
@@ -286,6 +433,11 @@ pub struct ServiceContext {
     pub writable_fields: Vec<FieldContext>,
     pub updatable_fields: Vec<FieldContext>,
     pub enum_fields: Vec<EnumFieldContext>,
+    pub optimistic_locking: bool,
+    pub soft_delete: bool,
+    pub relations: Vec<RelationAccessorContext>,
+    /// Models whose record types the relation accessors return
+    pub related_entities: Vec<String>,
 }
 
 impl ServiceContext {
@@ -298,6 +450,10 @@ impl ServiceContext {
             writable_fields: fields_to_context(&schema.writable_fields()),
             updatable_fields: fields_to_context(&schema.updatable_fields()),
             enum_fields: enum_fields_to_context(schema),
+            optimistic_locking: schema.optimistic_locking,
+            soft_delete: schema.soft_delete,
+            relations: relations_to_context(schema),
+            related_entities: related_entities(schema),
         }
     }
 }
@@ -311,6 +467,13 @@ pub struct HookContext {
     pub writable_fields: Vec<FieldContext>,
     pub updatable_fields: Vec<FieldContext>,
     pub enum_fields: Vec<EnumFieldContext>,
+    pub optimistic_locking: bool,
+    pub soft_delete: bool,
+    pub relations: Vec<RelationAccessorContext>,
+    /// Models whose record types the relation accessors return
+    pub related_entities: Vec<String>,
+    /// Paged list ("cursor" | "offset"): page metadata and an infinite query hook
+    pub list_pagination: Option<String>,
 }
 
 impl HookContext {
@@ -325,6 +488,11 @@ impl HookContext {
             writable_fields: fields_to_context(&schema.writable_fields()),
             updatable_fields: fields_to_context(&schema.updatable_fields()),
             enum_fields: enum_fields_to_context(schema),
+            optimistic_locking: schema.optimistic_locking,
+            soft_delete: schema.soft_delete,
+            relations: relations_to_context(schema),
+            related_entities: related_entities(schema),
+            list_pagination: list_pagination(schema),
         }
     }
 }
@@ -338,6 +506,8 @@ pub struct CLIClientContext {
     pub writable_fields: Vec<FieldContext>,
     pub updatable_fields: Vec<FieldContext>,
     pub enum_fields: Vec<EnumFieldContext>,
+    pub optimistic_locking: bool,
+    pub soft_delete: bool,
 }
 
 impl CLIClientContext {
@@ -377,6 +547,8 @@ impl CLIClientContext {
             writable_fields: fields_to_context(&schema.writable_fields()),
             updatable_fields: fields_to_context(&schema.updatable_fields()),
             enum_fields,
+            optimistic_locking: schema.optimistic_locking,
+            soft_delete: schema.soft_delete,
         }
     }
 }
@@ -388,6 +560,9 @@ impl EdgeFunctionContext {
             table_name: schema.table_name.clone(),
             operations: OperationContextBuilder::new(schema).build(),
             writable_fields: fields_to_context(&schema.writable_fields()),
+            optimistic_locking: schema.optimistic_locking,
+            soft_delete: schema.soft_delete,
+            relations: relations_to_context(schema),
         }
     }
 }
@@ -404,6 +579,17 @@ pub struct AdminPageContext {
     pub enum_fields: Vec<EnumFieldContext>,
     pub has_content_field: bool,
     pub examples: Vec<std::collections::HashMap<String, String>>,
+    /// Schema has an export operation (CSV export button)
+    pub has_export: bool,
+    /// `limit` of the list operation (caps the page size)
+    pub list_limit: Option<usize>,
+    /// Pagination of the list operation; cursor pages only sort by created_at
+    pub list_pagination: Option<String>,
+    /// Roles allowed into the page (empty = any authenticated user)
+    pub admin_roles: Vec<String>,
+    /// Show the recent webhook deliveries section
+    pub has_webhooks: bool,
+    pub optimistic_locking: bool,
 }
 
 /// Extended field context for UI components
@@ -458,6 +644,14 @@ impl AdminPageContext {
             enum_fields: enum_fields_to_context(schema),
             has_content_field: schema.fields.iter().any(|f| f.name == "content"),
             examples: Vec::new(),
+            has_export: schema.operation(OperationType::Export).is_some(),
+            list_limit: schema
+                .operation(OperationType::List)
+                .and_then(|op| op.limit),
+            list_pagination: list_pagination(schema),
+            admin_roles: schema.admin_roles.clone(),
+            has_webhooks: !schema.webhooks.is_empty(),
+            optimistic_locking: schema.optimistic_locking,
         }
     }
 }
@@ -473,6 +667,7 @@ pub struct DemoComponentContext {
     pub display_fields: Vec<UIFieldContext>,
     pub enum_fields: Vec<EnumFieldContext>,
     pub has_content_field: bool,
+    pub optimistic_locking: bool,
 }
 
 impl DemoComponentContext {
@@ -495,6 +690,192 @@ impl DemoComponentContext {
             display_fields,
             enum_fields: enum_fields_to_context(schema),
             has_content_field: schema.fields.iter().any(|f| f.name == "content"),
+            optimistic_locking: schema.optimistic_locking,
+        }
+    }
+}
+
+/// Context for Test Data Factory template
+#[derive(Debug, Serialize)]
+pub struct FactoryContext {
+    pub name: String,
+    /// Variable name of the built record (camelCase entity name)
+    pub var_name: String,
+    pub fields: Vec<FactoryFieldContext>,
+    pub relations: Vec<RelationContext>,
+    /// Entities whose factories are imported (deduplicated `relations`)
+    pub related_entities: Vec<String>,
+    pub uses_fit_length: bool,
+    pub optimistic_locking: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FactoryFieldContext {
+    pub name: String,
+    pub expression: String,
+}
+
+/// Foreign key to another generated entity
+#[derive(Debug, Serialize)]
+pub struct RelationContext {
+    pub field: String,
+    pub entity: String,
+    /// Variable holding the referenced record (`authorId` → `author`)
+    pub alias: String,
+}
+
+impl FactoryContext {
+    /// `entities` are the schemas factories are generated for in the same run;
+    /// references to other tables (e.g. `auth.users`) stay random UUIDs
+    pub fn from_schema(schema: &EntitySchema, entities: &[&EntitySchema]) -> Self {
+        let fields: Vec<FactoryFieldContext> = schema
+            .writable_fields()
+            .iter()
+            .map(|f| FactoryFieldContext {
+                name: f.name.clone(),
+                expression: f.faker_expression(),
+            })
+            .collect();
+
+        let relations: Vec<RelationContext> = schema
+            .writable_fields()
+            .iter()
+            .filter_map(|f| {
+                let table = f.referenced_table()?;
+                let entity = entities
+                    .iter()
+                    .find(|e| e.table_name == table && e.name != schema.name)?;
+                let alias = f
+                    .name
+                    .strip_suffix("Id")
+                    .filter(|alias| !alias.is_empty())
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("{}Record", f.name));
+                Some(RelationContext {
+                    field: f.name.clone(),
+                    entity: entity.name.clone(),
+                    alias,
+                })
+            })
+            .collect();
+
+        let mut related_entities: Vec<String> = Vec::new();
+        for relation in &relations {
+            if !related_entities.contains(&relation.entity) {
+                related_entities.push(relation.entity.clone());
+            }
+        }
+
+        Self {
+            name: schema.name.clone(),
+            var_name: schema.var_name(),
+            uses_fit_length: fields.iter().any(|f| f.expression.contains("fitLength(")),
+            fields,
+            relations,
+            related_entities,
+            optimistic_locking: schema.optimistic_locking,
+        }
+    }
+}
+
+/// Context for the GraphQL function and GraphQL hook templates
+#[derive(Debug, Serialize)]
+pub struct GraphqlContext {
+    pub name: String,
+    pub table_name: String,
+    pub var_name: String,
+    /// Object type fields besides the fixed id / userId / createdAt / updatedAt
+    /// (also the update input, every field optional)
+    pub fields: Vec<GraphqlFieldContext>,
+    pub create_fields: Vec<GraphqlFieldContext>,
+    pub enums: Vec<GraphqlEnumContext>,
+    /// Filters of every operation (fields of the `<Entity>Filter` input)
+    pub filters: Vec<String>,
+    pub operations: Vec<OperationContext>,
+    pub has_queries: bool,
+    pub has_mutations: bool,
+    pub optimistic_locking: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GraphqlFieldContext {
+    pub name: String,
+    pub db_name: String,
+    /// Without the `!`; templates add it for required fields
+    pub graphql_type: String,
+    pub typescript_type: String,
+    pub required: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GraphqlEnumContext {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+impl GraphqlContext {
+    pub fn from_schema(schema: &EntitySchema) -> Self {
+        let to_context = |f: &Field| GraphqlFieldContext {
+            name: f.name.clone(),
+            db_name: f.db_name.clone(),
+            graphql_type: f.graphql_type(&schema.name),
+            typescript_type: f.typescript_type(),
+            required: f.required,
+        };
+
+        let fields = schema
+            .updatable_fields()
+            .into_iter()
+            .map(to_context)
+            .collect();
+        let create_fields = schema
+            .writable_fields()
+            .into_iter()
+            .filter(|f| f.name != "userId")
+            .map(to_context)
+            .collect();
+
+        let enums = schema
+            .enum_fields()
+            .into_iter()
+            .filter_map(|f| {
+                Some(GraphqlEnumContext {
+                    name: f.graphql_type(&schema.name),
+                    values: f.graphql_enum_values()?.to_vec(),
+                })
+            })
+            .collect();
+
+        let operations: Vec<OperationContext> = schema
+            .operations
+            .iter()
+            .filter(|op| op.op_type != OperationType::Export)
+            .map(|op| op.into_context())
+            .collect();
+
+        let mut filters: Vec<String> = Vec::new();
+        for filter in operations.iter().flat_map(|op| &op.filters) {
+            if !filters.contains(filter) {
+                filters.push(filter.clone());
+            }
+        }
+
+        Self {
+            name: schema.name.clone(),
+            table_name: schema.table_name.clone(),
+            var_name: schema.var_name(),
+            fields,
+            create_fields,
+            enums,
+            filters,
+            has_queries: operations
+                .iter()
+                .any(|op| matches!(op.op_type.as_str(), "list" | "get" | "custom")),
+            has_mutations: operations
+                .iter()
+                .any(|op| matches!(op.op_type.as_str(), "create" | "update" | "delete")),
+            operations,
+            optimistic_locking: schema.optimistic_locking,
         }
     }
 }
@@ -537,6 +918,7 @@ mod tests {
                     description: None,
                     filters: vec!["type".to_string()],
                     limit: None,
+                    pagination: None,
                 },
                 Operation {
                     op_type: OperationType::Custom,
@@ -544,9 +926,17 @@ mod tests {
                     description: None,
                     filters: vec!["type".to_string()], // This should be filtered out for HookContext
                     limit: None,
+                    pagination: None,
                 },
             ],
             rls: vec![],
+            admin_roles: Vec::new(),
+            optimistic_locking: false,
+            soft_delete: false,
+            strict_dates: false,
+            graphql: false,
+            webhooks: Vec::new(),
+            relations: Vec::new(),
             documentation: None,
         }
     }
@@ -596,6 +986,7 @@ mod tests {
             description: Some("My items".to_string()),
             filters: vec!["userId".to_string()],
             limit: Some(50),
+            pagination: None,
         };
 
         let ctx: OperationContext = op.into_context();
@@ -664,6 +1055,193 @@ mod tests {
         }
     }
 
+    // -------------------------------------------------------------------------
+    // AdminPageContext / RepositoryEdgeContext tests (table + export)
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_admin_page_with_export_and_paging() {
+        use crate::commands::api::templates::TemplateEngine;
+
+        let mut schema = create_test_schema();
+        schema.operations[0].limit = Some(30);
+        schema.operations.push(Operation {
+            op_type: OperationType::Export,
+            name: None,
+            description: None,
+            filters: vec![],
+            limit: None,
+            pagination: None,
+        });
+        let engine = TemplateEngine::new().unwrap();
+
+        let ctx = AdminPageContext::from_schema(&schema);
+        assert!(ctx.has_export);
+        assert_eq!(ctx.list_limit, Some(30));
+        let page = engine.render("admin_page", &ctx).unwrap();
+        assert!(page.contains("{ key: 'title', label: 'Title', sortKey: 'title' },"));
+        assert!(page.contains("const PAGE_SIZES = [10, 25, 50].filter((size) => size < 30)"));
+        assert!(page.contains("await MaterialService.exportCsv("));
+
+        let repo_ctx = RepositoryEdgeContext::from_schema(&schema);
+        assert_eq!(repo_ctx.export_limit, 10_000);
+        let repository = engine.render("repository_edge", &repo_ctx).unwrap();
+        assert!(repository.contains("async exportCsv("));
+        assert!(
+            repository.contains("const rows = await this.findAll({ ...filters, limit: 10000 })")
+        );
+    }
+
+    #[test]
+    fn test_admin_page_without_export() {
+        use crate::commands::api::templates::TemplateEngine;
+
+        let ctx = AdminPageContext::from_schema(&create_test_schema());
+        assert!(!ctx.has_export);
+        let page = TemplateEngine::new()
+            .unwrap()
+            .render("admin_page", &ctx)
+            .unwrap();
+        assert!(!page.contains("exportCsv"));
+        assert!(page.contains("const PAGE_SIZES = [10, 25, 50]\n"));
+        assert!(!page.contains("useAuth"));
+    }
+
+    #[test]
+    fn test_optimistic_locking_update() {
+        use crate::commands::api::templates::TemplateEngine;
+
+        let mut schema = create_test_schema();
+        schema.optimistic_locking = true;
+        schema.operations.push(Operation {
+            op_type: OperationType::Update,
+            name: None,
+            description: None,
+            filters: vec![],
+            limit: None,
+            pagination: None,
+        });
+        let engine = TemplateEngine::new().unwrap();
+
+        let repository = engine
+            .render(
+                "repository_edge",
+                &RepositoryEdgeContext::from_schema(&schema),
+            )
+            .unwrap();
+        assert!(repository.contains(".update({ ...updates, version: expectedVersion + 1 })"));
+        assert!(repository.contains(".eq('version', expectedVersion)"));
+
+        let edge_function = engine
+            .render("edge_function", &EdgeFunctionContext::from_schema(&schema))
+            .unwrap();
+        assert!(edge_function.contains("status: 409,"));
+        assert!(edge_function.contains("code: 'MATERIAL_VERSION_CONFLICT',"));
+
+        let page = engine
+            .render("admin_page", &AdminPageContext::from_schema(&schema))
+            .unwrap();
+        assert!(page.contains("if (isMaterialVersionConflict(err)) {"));
+        assert!(page.contains("onClick={handleLoadLatest}"));
+    }
+
+    #[test]
+    fn test_cli_client_retries_and_iteration() {
+        use crate::commands::api::templates::TemplateEngine;
+
+        let mut schema = create_test_schema();
+        schema.operations[0].limit = Some(50);
+        let client = TemplateEngine::new()
+            .unwrap()
+            .render("cli_client", &CLIClientContext::from_schema(&schema))
+            .unwrap();
+        assert!(client.contains("return await this.client.invoke<T>('materials-crud', body)"));
+        assert!(client.contains("    pageSize: number = 50\n  ): AsyncGenerator<Material> {"));
+        assert!(client.contains("const page = await this.list(filters, pageSize, offset)"));
+        assert!(!client.contains("this.client.invoke<Material"));
+    }
+
+    #[test]
+    fn test_edge_function_error_envelope() {
+        use crate::commands::api::templates::TemplateEngine;
+
+        let engine = TemplateEngine::new().unwrap();
+        let edge_function = engine
+            .render(
+                "edge_function",
+                &EdgeFunctionContext::from_schema(&create_test_schema()),
+            )
+            .unwrap();
+        assert!(edge_function.contains("inputSchema: z.unknown(),"));
+        assert!(edge_function
+            .contains("const input: MaterialCrudInput = MaterialCrudSchema.parse(rawInput)"));
+        assert!(edge_function.contains("throw toHttpError(error, requestId)"));
+        assert!(edge_function.contains("status: 422,"));
+        assert!(edge_function.contains("'23505': { status: 409, code: 'CONFLICT' },"));
+        assert!(edge_function.contains("response.headers.set('X-Request-Id', requestId)"));
+        assert!(edge_function.contains("const FUNCTION_NAME = 'materials-crud'"));
+
+        let repository = engine
+            .render(
+                "repository_edge",
+                &RepositoryEdgeContext::from_schema(&create_test_schema()),
+            )
+            .unwrap();
+        assert!(repository.contains("${error.message}`, { cause: error })"));
+    }
+
+    #[test]
+    fn test_admin_page_with_admin_roles() {
+        use crate::commands::api::templates::TemplateEngine;
+
+        let mut schema = create_test_schema();
+        schema.admin_roles = vec!["admin".to_string(), "editor".to_string()];
+        let page = TemplateEngine::new()
+            .unwrap()
+            .render("admin_page", &AdminPageContext::from_schema(&schema))
+            .unwrap();
+        assert!(page.contains("const ADMIN_ROLES = ['admin', 'editor']"));
+        assert!(page.contains("return <MaterialAdminContent />"));
+        assert!(page.contains("function MaterialAdminContent() {"));
+        assert_eq!(
+            page.matches("export function MaterialAdminPage()").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_webhook_function_context() {
+        use crate::commands::api::schema::{Webhook, WebhookEvent};
+        use crate::commands::api::templates::TemplateEngine;
+
+        let mut schema = create_test_schema();
+        schema.webhooks = vec![
+            Webhook {
+                url: "https://example.com/a".to_string(),
+                events: WebhookEvent::all(),
+                secret_env: "SHARED_SECRET".to_string(),
+            },
+            Webhook {
+                url: "https://example.com/b".to_string(),
+                events: vec![WebhookEvent::Delete],
+                secret_env: "SHARED_SECRET".to_string(),
+            },
+        ];
+
+        let ctx = WebhookFunctionContext::from_schema(&schema);
+        assert_eq!(ctx.secret_envs, vec!["SHARED_SECRET".to_string()]);
+
+        let engine = TemplateEngine::new().unwrap();
+        let function = engine.render("webhook_function", &ctx).unwrap();
+        assert!(function.contains("url: 'https://example.com/b',\n    events: ['DELETE'],"));
+        assert!(function.contains("'INSERT', 'UPDATE', 'DELETE'"));
+
+        let page = engine
+            .render("admin_page", &AdminPageContext::from_schema(&schema))
+            .unwrap();
+        assert!(page.contains("from('materials_webhook_deliveries')"));
+    }
+
     // -------------------------------------------------------------------------
     // ServiceContext tests (no deduplication needed)
     // -------------------------------------------------------------------------
@@ -674,7 +1252,7 @@ mod tests {
         let ctx = ServiceContext::from_schema(&schema);
 
         // Service doesn't deduplicate - backend handles it
-        let custom_op = ctx
+        let _custom_op = ctx
             .operations
             .iter()
             .find(|op| op.name == Some("my".to_string()))
@@ -0,0 +1,378 @@
+/**
+ * API List
+ * Entities with generated CRUD APIs, found from the entity schemas (YAML)
+ * and the `supabase/functions/<table>-crud` Edge Functions, with the layers
+ * that exist or are missing and those older than their schema
+ */
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::schema::EntitySchema;
+use crate::commands::db::plan::find_schemas;
+use crate::utils::output::OutputFormatter;
+
+pub(super) const FUNCTIONS_DIR: &str = "supabase/functions";
+pub(super) const MIGRATIONS_DIR: &str = "supabase/migrations";
+pub(super) const MODELS_DIR: &str = "packages/app-frontend/src/models";
+pub(super) const SERVICES_DIR: &str = "packages/app-frontend/src/services";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Layer {
+    Migration,
+    EdgeFunction,
+    Model,
+    Service,
+}
+
+impl Layer {
+    const ALL: [Layer; 4] = [
+        Layer::Migration,
+        Layer::EdgeFunction,
+        Layer::Model,
+        Layer::Service,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Layer::Migration => "Migration",
+            Layer::EdgeFunction => "Edge Fn",
+            Layer::Model => "Model",
+            Layer::Service => "Service",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct LayerStatus {
+    layer: Layer,
+    /// Generated file (`None` when the layer is missing)
+    file: Option<String>,
+    /// Older than the schema YAML
+    stale: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ApiEntity {
+    name: String,
+    table_name: String,
+    /// Schema YAML (`None` for APIs found only from their Edge Function)
+    schema: Option<String>,
+    layers: Vec<LayerStatus>,
+}
+
+impl ApiEntity {
+    fn missing(&self) -> usize {
+        self.layers.iter().filter(|l| l.file.is_none()).count()
+    }
+
+    fn stale(&self) -> usize {
+        self.layers.iter().filter(|l| l.stale).count()
+    }
+}
+
+/// `api list` result
+#[derive(Debug, Serialize)]
+struct ApiList {
+    entities: Vec<ApiEntity>,
+}
+
+pub fn execute(project_root: &Path, output: OutputFormatter) -> Result<()> {
+    let list = collect(project_root);
+    output.emit(&list, print)
+}
+
+fn collect(root: &Path) -> ApiList {
+    let relative = |path: &Path| {
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+
+    // (name, table, schema file)
+    let mut found: Vec<(String, String, Option<PathBuf>)> = Vec::new();
+    for path in find_schemas(root) {
+        let Ok(schema) = EntitySchema::from_yaml(&path) else {
+            continue;
+        };
+        if !found
+            .iter()
+            .any(|(_, table, _)| *table == schema.table_name)
+        {
+            found.push((schema.name, schema.table_name, Some(path)));
+        }
+    }
+    for (table, name) in crud_functions(root) {
+        if !found.iter().any(|(_, known, _)| *known == table) {
+            found.push((name, table, None));
+        }
+    }
+    found.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let migrations = file_names(&root.join(MIGRATIONS_DIR));
+    let entities = found
+        .into_iter()
+        .map(|(name, table_name, schema)| {
+            let schema_modified = schema.as_deref().and_then(modified);
+            let layers = Layer::ALL
+                .into_iter()
+                .map(|layer| {
+                    let file = match layer {
+                        Layer::Migration => {
+                            let suffix = format!("_create_{}_table.sql", table_name);
+                            migrations
+                                .iter()
+                                .rev()
+                                .find(|file| file.ends_with(&suffix))
+                                .map(|file| root.join(MIGRATIONS_DIR).join(file))
+                        }
+                        Layer::EdgeFunction => Some(
+                            root.join(FUNCTIONS_DIR)
+                                .join(format!("{}-crud", table_name))
+                                .join("index.ts"),
+                        ),
+                        Layer::Model => Some(root.join(MODELS_DIR).join(format!("{}.ts", name))),
+                        Layer::Service => {
+                            Some(root.join(SERVICES_DIR).join(format!("{}Service.ts", name)))
+                        }
+                    }
+                    .filter(|file| file.is_file());
+
+                    let stale = match (&file, schema_modified) {
+                        (Some(file), Some(schema)) => modified(file).is_some_and(|m| m < schema),
+                        _ => false,
+                    };
+                    LayerStatus {
+                        layer,
+                        file: file.as_deref().map(relative),
+                        stale,
+                    }
+                })
+                .collect();
+
+            ApiEntity {
+                name,
+                table_name,
+                schema: schema.as_deref().map(relative),
+                layers,
+            }
+        })
+        .collect();
+
+    ApiList { entities }
+}
+
+/// `(table, entity)` of each `<table>-crud` Edge Function
+pub(super) fn crud_functions(root: &Path) -> Vec<(String, String)> {
+    file_names(&root.join(FUNCTIONS_DIR))
+        .into_iter()
+        .filter_map(|dir| {
+            let table = dir.strip_suffix("-crud")?.to_string();
+            let index =
+                fs::read_to_string(root.join(FUNCTIONS_DIR).join(&dir).join("index.ts")).ok()?;
+            let name = repository_entity(&index).unwrap_or_else(|| table.clone());
+            Some((table, name))
+        })
+        .collect()
+}
+
+/// Entity of the `import { <Entity>Repository } from ...` line of a generated Edge Function
+fn repository_entity(index: &str) -> Option<String> {
+    index.lines().find_map(|line| {
+        let imported = line.trim().strip_prefix("import { ")?;
+        let name = imported
+            .split_whitespace()
+            .next()?
+            .strip_suffix("Repository")?;
+        (!name.is_empty()).then(|| name.to_string())
+    })
+}
+
+/// Sorted entry names of a directory (empty when it does not exist)
+pub(super) fn file_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .collect();
+    names.sort();
+    names
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn print(list: &ApiList) {
+    println!("{}", "📋 Generated APIs".bright_cyan().bold());
+    println!("{}", "─".repeat(50).bright_black());
+
+    if list.entities.is_empty() {
+        println!("\n{}", "No generated APIs found".yellow());
+        println!("Run: akatsuki api new <Entity> --schema <file.yaml>");
+        return;
+    }
+
+    let name_width = list
+        .entities
+        .iter()
+        .map(|e| e.name.len())
+        .chain(["Entity".len()])
+        .max()
+        .unwrap_or_default();
+    let table_width = list
+        .entities
+        .iter()
+        .map(|e| e.table_name.len())
+        .chain(["Table".len()])
+        .max()
+        .unwrap_or_default();
+
+    print!("\n{:<name_width$}  {:<table_width$}", "Entity", "Table");
+    for layer in Layer::ALL {
+        print!("  {:<9}", layer.label());
+    }
+    println!();
+
+    for entity in &list.entities {
+        print!(
+            "{}  {:<table_width$}",
+            format!("{:<name_width$}", entity.name).bright_white(),
+            entity.table_name
+        );
+        for status in &entity.layers {
+            let cell = format!("{:<9}", status_mark(status));
+            let cell = match (&status.file, status.stale) {
+                (None, _) => cell.red(),
+                (Some(_), true) => cell.yellow(),
+                (Some(_), false) => cell.green(),
+            };
+            print!("  {}", cell);
+        }
+        println!();
+    }
+    println!(
+        "\n{}",
+        "✓ generated  ✗ missing  ⚠ older than the schema".bright_black()
+    );
+
+    let incomplete: Vec<&ApiEntity> = list
+        .entities
+        .iter()
+        .filter(|e| e.missing() > 0 || e.stale() > 0)
+        .collect();
+    println!("{}", "─".repeat(50).bright_black());
+    println!(
+        "{} entit{}, {} complete and up to date",
+        list.entities.len(),
+        if list.entities.len() == 1 { "y" } else { "ies" },
+        list.entities.len() - incomplete.len()
+    );
+    for entity in incomplete {
+        let hint = match &entity.schema {
+            Some(schema) => format!("akatsuki api new {} --schema {}", entity.name, schema),
+            None => "no schema YAML found".to_string(),
+        };
+        println!(
+            "  {} {}: {} missing, {} stale → {}",
+            "⚠".yellow(),
+            entity.name,
+            entity.missing(),
+            entity.stale(),
+            hint.bright_black()
+        );
+    }
+}
+
+fn status_mark(status: &LayerStatus) -> &'static str {
+    match (&status.file, status.stale) {
+        (None, _) => "✗",
+        (Some(_), true) => "⚠",
+        (Some(_), false) => "✓",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::api::test_support::{write, ARTICLE_SCHEMA};
+    use std::time::Duration;
+
+    fn set_modified(root: &Path, path: &str, time: SystemTime) {
+        fs::File::options()
+            .write(true)
+            .open(root.join(path))
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_repository_entity() {
+        let index = "import { z } from 'zod'\nimport { ArticleRepository } from '../_shared/repositories/ArticleRepository.ts'\n";
+        assert_eq!(repository_entity(index).as_deref(), Some("Article"));
+        assert_eq!(repository_entity("import { z } from 'zod'"), None);
+    }
+
+    #[test]
+    fn test_collect_correlates_layers() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        write(root, "schemas/article.yaml", ARTICLE_SCHEMA);
+        write(
+            root,
+            "supabase/migrations/20250101000000_create_articles_table.sql",
+            "",
+        );
+        write(
+            root,
+            "supabase/functions/articles-crud/index.ts",
+            "import { ArticleRepository } from '../_shared/repositories/ArticleRepository.ts'\n",
+        );
+        write(root, "packages/app-frontend/src/models/Article.ts", "");
+        write(
+            root,
+            "supabase/functions/comments-crud/index.ts",
+            "import { CommentRepository } from '../_shared/repositories/CommentRepository.ts'\n",
+        );
+
+        // The model predates the last schema edit
+        let edited = SystemTime::now() - Duration::from_secs(60);
+        set_modified(root, "schemas/article.yaml", edited);
+        set_modified(
+            root,
+            "packages/app-frontend/src/models/Article.ts",
+            edited - Duration::from_secs(60),
+        );
+
+        let list = collect(root);
+        let summary: Vec<(&str, Option<&str>, Vec<&str>)> = list
+            .entities
+            .iter()
+            .map(|e| {
+                (
+                    e.name.as_str(),
+                    e.schema.as_deref(),
+                    e.layers.iter().map(status_mark).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    "Article",
+                    Some("schemas/article.yaml"),
+                    vec!["✓", "✓", "⚠", "✗"]
+                ),
+                ("Comment", None, vec!["✗", "✓", "✗", "✗"]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,335 @@
+/**
+ * Generation Manifest
+ * Hashes of the files written by `api new` / `api batch`, kept in
+ * .akatsuki/generated.json (commit it with the generated code)
+ *
+ * A file that no longer matches its recorded hash was edited by hand, so
+ * regeneration asks what to do with it instead of overwriting it:
+ * overwrite / skip / show diff / write `<file>.new`. Without a terminal the
+ * new version goes to `<file>.new`; `--force` overwrites everything.
+ *
+ * Next to it, .akatsuki/schemas/<table>.yaml keeps the schema each table's
+ * migration was generated from, for `api update`.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use dialoguer::Select;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use super::generator::GeneratedFile;
+use super::schema::EntitySchema;
+use crate::utils::content_hash::ContentHasher;
+use crate::utils::platform::slash_path;
+
+pub const MANIFEST_PATH: &str = ".akatsuki/generated.json";
+pub const SNAPSHOT_DIR: &str = ".akatsuki/schemas";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    /// Project-relative path → hash of the content the generator wrote
+    files: BTreeMap<String, String>,
+}
+
+#[derive(Debug, PartialEq)]
+enum FileState {
+    /// Not on disk yet
+    New,
+    /// Already has the generated content
+    Unchanged,
+    /// As generated last time, safe to replace
+    Generated,
+    /// Edited since generation (or not written by the generator)
+    Modified,
+}
+
+enum Resolution {
+    Overwrite,
+    Skip,
+    WriteNew,
+}
+
+/// Writes generated files, leaving hand-edited ones alone unless told otherwise
+pub struct ManifestWriter {
+    root: PathBuf,
+    manifest: Manifest,
+    force: bool,
+    interactive: bool,
+}
+
+impl ManifestWriter {
+    pub fn open(root: &Path, force: bool) -> Result<Self> {
+        Ok(Self {
+            root: root.to_path_buf(),
+            manifest: load(root)?,
+            force,
+            interactive: std::io::stdin().is_terminal(),
+        })
+    }
+
+    pub fn write(&mut self, file: &GeneratedFile) -> Result<()> {
+        let key = slash_path(file.path.strip_prefix(&self.root).unwrap_or(&file.path));
+        let existing = fs::read_to_string(&file.path).ok();
+        let state = file_state(
+            existing.as_deref(),
+            self.manifest.files.get(&key).map(String::as_str),
+            &file.content,
+        );
+
+        match state {
+            FileState::New | FileState::Generated => file.write_to_disk()?,
+            FileState::Modified if self.force => file.write_to_disk()?,
+            FileState::Unchanged => println!(
+                "  {} {} {}",
+                "=".bright_black(),
+                file.path.display(),
+                "(unchanged)".bright_black()
+            ),
+            FileState::Modified => {
+                let current = existing.unwrap_or_default();
+                match self.resolve(file, &current)? {
+                    Resolution::Overwrite => file.write_to_disk()?,
+                    // The recorded hash stays, so the file is still seen as edited
+                    Resolution::Skip => {
+                        println!(
+                            "  {} {} {}",
+                            "⏭".yellow(),
+                            file.path.display(),
+                            "(edited, kept)".bright_black()
+                        );
+                        return Ok(());
+                    }
+                    Resolution::WriteNew => {
+                        let path = new_path(&file.path);
+                        fs::write(&path, &file.content)
+                            .with_context(|| format!("Failed to write {}", path.display()))?;
+                        println!(
+                            "  {} {} {}",
+                            "⚠".yellow(),
+                            path.display().to_string().bright_white(),
+                            "(edited file kept, merge by hand)".bright_black()
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        self.manifest.files.insert(key, hash(&file.content));
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<()> {
+        let path = self.root.join(MANIFEST_PATH);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(&self.manifest)? + "\n";
+        fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    fn resolve(&self, file: &GeneratedFile, current: &str) -> Result<Resolution> {
+        println!(
+            "\n  {} {} was edited since it was generated",
+            "✎".yellow(),
+            file.path.display().to_string().bright_white()
+        );
+        if !self.interactive {
+            return Ok(Resolution::WriteNew);
+        }
+
+        let new_name = new_path(&file.path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let items = [
+            "Overwrite with the generated version".to_string(),
+            "Skip (keep my changes)".to_string(),
+            "Show diff".to_string(),
+            format!("Write {} next to it", new_name),
+        ];
+        loop {
+            let choice = Select::new()
+                .with_prompt("What should happen to it?")
+                .items(&items)
+                .default(1)
+                .interact()?;
+            match choice {
+                0 => return Ok(Resolution::Overwrite),
+                1 => return Ok(Resolution::Skip),
+                2 => print_diff(current, &file.content),
+                _ => return Ok(Resolution::WriteNew),
+            }
+        }
+    }
+}
+
+/// Recorded hashes by project-relative path (empty without a manifest)
+pub fn recorded_hashes(root: &Path) -> Result<BTreeMap<String, String>> {
+    Ok(load(root)?.files)
+}
+
+fn load(root: &Path) -> Result<Manifest> {
+    let path = root.join(MANIFEST_PATH);
+    match fs::read_to_string(&path) {
+        Ok(content) => {
+            serde_json::from_str(&content).with_context(|| format!("Invalid {}", path.display()))
+        }
+        Err(_) => Ok(Manifest::default()),
+    }
+}
+
+pub fn snapshot_path(root: &Path, table_name: &str) -> PathBuf {
+    root.join(SNAPSHOT_DIR).join(format!("{}.yaml", table_name))
+}
+
+/// Schema of the table's last generated migration, if one was recorded
+pub fn load_snapshot(root: &Path, table_name: &str) -> Result<Option<EntitySchema>> {
+    let path = snapshot_path(root, table_name);
+    if !path.exists() {
+        return Ok(None);
+    }
+    EntitySchema::from_yaml(&path)
+        .map(Some)
+        .with_context(|| format!("Invalid schema snapshot {}", path.display()))
+}
+
+fn file_state(existing: Option<&str>, recorded: Option<&str>, generated: &str) -> FileState {
+    match existing {
+        None => FileState::New,
+        Some(content) if content == generated => FileState::Unchanged,
+        Some(content) if recorded == Some(hash(content).as_str()) => FileState::Generated,
+        Some(_) => FileState::Modified,
+    }
+}
+
+pub(super) fn hash(content: &str) -> String {
+    let mut hasher = ContentHasher::new();
+    hasher.write(content.as_bytes());
+    hasher.finish_hex()
+}
+
+/// `Hook.ts` → `Hook.ts.new`
+fn new_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".new");
+    PathBuf::from(name)
+}
+
+pub(super) fn print_diff(current: &str, generated: &str) {
+    println!("\n  {} {}", "- yours".red(), "+ generated".green());
+    for (marker, number, line) in diff_lines(current, generated) {
+        let line = format!("{:>5} {} {}", number, marker, line);
+        let line = if marker == '-' {
+            line.red()
+        } else {
+            line.green()
+        };
+        println!("{}", line);
+    }
+    println!();
+}
+
+/// Removed ('-', line number in `old`) and added ('+', line number in `new`)
+/// lines, from the longest common subsequence
+fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<(char, usize, &'a str)> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // common[i][j]: LCS length of old[i..] and new[j..]
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || common[i][j + 1] >= common[i + 1][j]) {
+            lines.push(('+', j + 1, new[j]));
+            j += 1;
+        } else {
+            lines.push(('-', i + 1, old[i]));
+            i += 1;
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::api::test_support::generated;
+
+    #[test]
+    fn test_file_state() {
+        let recorded = hash("v1");
+        assert_eq!(file_state(None, None, "v2"), FileState::New);
+        assert_eq!(file_state(Some("v2"), None, "v2"), FileState::Unchanged);
+        assert_eq!(
+            file_state(Some("v1"), Some(&recorded), "v2"),
+            FileState::Generated
+        );
+        assert_eq!(
+            file_state(Some("v1 + edit"), Some(&recorded), "v2"),
+            FileState::Modified
+        );
+        // Files the generator never wrote are not replaced silently
+        assert_eq!(file_state(Some("mine"), None, "v2"), FileState::Modified);
+    }
+
+    #[test]
+    fn test_regeneration_keeps_edited_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("src/Hook.ts");
+
+        let mut writer = ManifestWriter::open(dir.path(), false).unwrap();
+        writer.write(&generated(path.clone(), "v1\n")).unwrap();
+        writer.finish().unwrap();
+        let manifest = fs::read_to_string(dir.path().join(MANIFEST_PATH)).unwrap();
+        assert!(manifest.contains("\"src/Hook.ts\""));
+
+        // Untouched: regenerated in place
+        let mut writer = ManifestWriter::open(dir.path(), false).unwrap();
+        writer.write(&generated(path.clone(), "v2\n")).unwrap();
+        writer.finish().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "v2\n");
+
+        // Edited: the new version goes next to it
+        fs::write(&path, "v2\nmine\n").unwrap();
+        let mut writer = ManifestWriter::open(dir.path(), false).unwrap();
+        writer.interactive = false;
+        writer.write(&generated(path.clone(), "v3\n")).unwrap();
+        writer.finish().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "v2\nmine\n");
+        assert_eq!(
+            fs::read_to_string(dir.path().join("src/Hook.ts.new")).unwrap(),
+            "v3\n"
+        );
+
+        // --force: today's behavior
+        let mut writer = ManifestWriter::open(dir.path(), true).unwrap();
+        writer.write(&generated(path.clone(), "v3\n")).unwrap();
+        writer.write(&generated(path.clone(), "v4\n")).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "v4\n");
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let diff = diff_lines("a\nb\nc\nd\n", "a\nc\nx\nd\n");
+        assert_eq!(diff, vec![('-', 2, "b"), ('+', 3, "x")]);
+        assert!(diff_lines("same\n", "same\n").is_empty());
+    }
+}
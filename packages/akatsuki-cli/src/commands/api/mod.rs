@@ -8,19 +8,228 @@
  * - Frontend (Model + Repository + Service + Hook + Component)
  * - CLI Tools (Client + Examples)
  */
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
-use std::path::PathBuf;
-
-use crate::cli::ApiAction;
-
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::cli::{ApiAction, HttpMethod};
+use crate::commands::design::front_matter as design_front_matter;
+use crate::utils::find_project_root;
+use crate::utils::output::OutputFormatter;
+
+mod delete;
+mod endpoint;
+mod factories;
+mod from_db;
+mod from_design;
 mod generator;
 mod generator_contexts;
+mod list;
+mod manifest;
 mod schema;
-mod templates;
-
+pub(crate) mod templates;
+#[cfg(test)]
+mod test_support;
+mod update;
+mod verify;
+mod wiring;
+
+use endpoint::{EndpointGenerator, EndpointSpec};
+use factories::FactoryGenerator;
 use generator::CodeGenerator;
-use schema::EntitySchema;
+use schema::{EntitySchema, FieldType};
+
+pub use factories::FACTORIES_DIR;
+
+/// What `api new --schema <path>` would produce, for tools that inspect a
+/// schema without writing files (e.g. `akatsuki pr`)
+pub struct SchemaInfo {
+    pub name: String,
+    pub table_name: String,
+    pub fields: usize,
+    pub operations: Vec<&'static str>,
+    /// Generated code files (path, content); the timestamped migration is excluded
+    pub generated: Vec<(PathBuf, String)>,
+}
+
+pub fn inspect_schema(path: &Path) -> Result<SchemaInfo> {
+    let schema = EntitySchema::from_yaml(path)?;
+    let generated = CodeGenerator::new(schema.clone())
+        .generate_all()?
+        .code_files()
+        .into_iter()
+        .map(|file| (file.path.clone(), file.content.clone()))
+        .collect();
+
+    Ok(SchemaInfo {
+        name: schema.name.clone(),
+        table_name: schema.table_name.clone(),
+        fields: schema.fields.len(),
+        operations: schema
+            .operations
+            .iter()
+            .map(|op| op.op_type.as_str())
+            .collect(),
+        generated,
+    })
+}
+
+/// Table as a schema's migration creates it (see `declared_table`)
+pub struct DeclaredTable {
+    pub name: String,
+    pub table_name: String,
+    /// Declared fields, plus `version` with `optimisticLocking`
+    pub columns: Vec<DeclaredColumn>,
+    pub indexes: Vec<DeclaredIndex>,
+}
+
+pub struct DeclaredColumn {
+    pub name: String,
+    pub sql_type: String,
+    pub not_null: bool,
+    /// Type and constraints as written in the migration
+    pub definition: String,
+}
+
+pub struct DeclaredIndex {
+    /// `idx_<table>_<column>`
+    pub name: String,
+    pub column: String,
+    pub gin: bool,
+}
+
+/// The table `api new --schema <path>` would create, for comparing entity
+/// schemas with the migrations (e.g. `akatsuki db plan`)
+pub fn declared_table(path: &Path) -> Result<DeclaredTable> {
+    Ok(generator::declared_table(&EntitySchema::from_yaml(path)?))
+}
+
+/// Columns `akatsuki demo seed` fills from an entity's factory output
+pub struct SeedEntity {
+    pub name: String,
+    pub table_name: String,
+    pub columns: Vec<SeedColumn>,
+}
+
+pub struct SeedColumn {
+    /// Field name in the factory output (camelCase)
+    pub field: String,
+    pub column: String,
+    pub required: bool,
+    /// Referenced table of a foreign key (e.g. `authors`, `auth.users`)
+    pub references: Option<String>,
+    pub is_string: bool,
+}
+
+/// Columns of a schema's table that seeding sets: timestamps are left to
+/// their defaults, and so are non-UUID primary keys (factories build UUIDs)
+pub fn seed_entity(path: &Path) -> Result<SeedEntity> {
+    let schema = EntitySchema::from_yaml(path)?;
+    let columns = schema
+        .fields
+        .iter()
+        .filter(|f| f.name != "createdAt" && f.name != "updatedAt")
+        .filter(|f| !f.primary_key || f.field_type == FieldType::Uuid)
+        .map(|f| SeedColumn {
+            field: f.name.clone(),
+            column: f.db_name.clone(),
+            required: f.required,
+            references: f.referenced_table().map(str::to_string),
+            is_string: f.field_type == FieldType::String,
+        })
+        .collect();
+    Ok(SeedEntity {
+        name: schema.name,
+        table_name: schema.table_name,
+        columns,
+    })
+}
+
+/// Admin page routes registered by `--auto-wire` in App.tsx (e.g. for
+/// `akatsuki check a11y`)
+pub fn admin_routes(root: &Path) -> Vec<String> {
+    std::fs::read_to_string(root.join(wiring::APP_TSX))
+        .map(|app| wiring::wired_routes(&app))
+        .unwrap_or_default()
+}
+
+/// `api verify` as a preflight step: generated code must match its schemas
+pub fn verify_generated(root: &Path) -> Result<()> {
+    verify::execute(root, false, OutputFormatter::default())
+}
+
+/// Flags shared by `api new` and `api batch`
+#[derive(Clone, Copy)]
+struct GenerateOptions {
+    auto_wire: bool,
+    with_graphql: bool,
+    dry_run: bool,
+    force: bool,
+}
+
+/// `api check` result
+#[derive(Debug, Serialize)]
+struct SchemaCheckReport {
+    valid: usize,
+    invalid: usize,
+    files: Vec<SchemaCheck>,
+}
+
+#[derive(Debug, Serialize)]
+struct SchemaCheck {
+    file: String,
+    valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity: Option<CheckedEntity>,
+    /// Recommended fields the schema lacks
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CheckedEntity {
+    name: String,
+    table_name: String,
+    fields: usize,
+    operations: usize,
+}
+
+impl SchemaCheck {
+    fn run(path: &Path) -> Self {
+        let file = path.display().to_string();
+        match EntitySchema::from_yaml(path) {
+            Ok(schema) => Self {
+                file,
+                valid: true,
+                suggestions: ApiCommand::check_recommended_fields(&schema),
+                entity: Some(CheckedEntity {
+                    name: schema.name,
+                    table_name: schema.table_name,
+                    fields: schema.fields.len(),
+                    operations: schema.operations.len(),
+                }),
+                error: None,
+            },
+            Err(e) => Self {
+                file,
+                valid: false,
+                entity: None,
+                suggestions: Vec::new(),
+                error: Some(e.to_string()),
+            },
+        }
+    }
+
+    fn file_name(&self) -> String {
+        Path::new(&self.file)
+            .file_name()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.file.clone())
+    }
+}
 
 pub struct ApiCommand;
 
@@ -29,84 +238,316 @@ impl ApiCommand {
         Self
     }
 
-    pub fn execute(&self, action: ApiAction) -> Result<()> {
+    pub fn execute(&self, action: ApiAction, output: OutputFormatter) -> Result<()> {
         match action {
             ApiAction::New {
                 entity_name,
                 schema,
                 interactive,
                 from_db,
-            } => self.generate_new(entity_name, schema, interactive, from_db),
-            ApiAction::Batch { files } => self.generate_batch(files),
-            ApiAction::List => self.list_apis(),
-            ApiAction::Delete { entity_name, force } => self.delete_api(entity_name, force),
-            ApiAction::Check { files } => self.check_schemas(files),
+                auto_wire,
+                with_graphql,
+                dry_run,
+                force,
+            } => self.generate_new(
+                entity_name,
+                schema,
+                interactive,
+                from_db,
+                GenerateOptions {
+                    auto_wire,
+                    with_graphql,
+                    dry_run,
+                    force,
+                },
+            ),
+            ApiAction::Batch {
+                files,
+                auto_wire,
+                with_graphql,
+                dry_run,
+                force,
+            } => self.generate_batch(
+                files,
+                GenerateOptions {
+                    auto_wire,
+                    with_graphql,
+                    dry_run,
+                    force,
+                },
+            ),
+            ApiAction::List => list::execute(&find_project_root(), output),
+            ApiAction::Update {
+                entity_name,
+                schema,
+                dry_run,
+                force,
+            } => update::execute(&find_project_root(), &entity_name, schema, dry_run, force),
+            ApiAction::Delete {
+                entity_name,
+                force,
+                dry_run,
+            } => delete::execute(&find_project_root(), &entity_name, force, dry_run),
+            ApiAction::Verify { diff } => verify::execute(&find_project_root(), diff, output),
+            ApiAction::Check { files } => self.check_schemas(files, output),
+            ApiAction::NewEndpoint {
+                name,
+                method,
+                path,
+                force,
+            } => self.generate_endpoint(name, method, path, force),
+            ApiAction::Factories { files, dry_run } => self.generate_factories(files, dry_run),
+            ApiAction::FromDesign {
+                design_file,
+                out_dir,
+                force,
+                dry_run,
+            } => self.import_design(design_file, out_dir, force, dry_run),
         }
     }
 
-    fn check_schemas(&self, files: Vec<PathBuf>) -> Result<()> {
+    fn import_design(
+        &self,
+        design_file: PathBuf,
+        out_dir: Option<PathBuf>,
+        force: bool,
+        dry_run: bool,
+    ) -> Result<()> {
+        println!("{}", "📐 Design → Entity Schema".bright_cyan().bold());
+        println!("{}", "─".repeat(50).bright_black());
+
+        let content = std::fs::read_to_string(&design_file)
+            .with_context(|| format!("Failed to read {}", design_file.display()))?;
+        let import = from_design::import(&content);
+        for warning in &import.warnings {
+            println!("{} {}", "⚠".yellow(), warning.yellow());
+        }
+        if let Ok(Some(meta)) = design_front_matter::parse(&content) {
+            for entity in &meta.entities {
+                if !import.schemas.iter().any(|schema| &schema.name == entity) {
+                    println!(
+                        "{} {}",
+                        "⚠".yellow(),
+                        format!("{} is listed in the front matter but not defined", entity)
+                            .yellow()
+                    );
+                }
+            }
+        }
+        if import.schemas.is_empty() {
+            anyhow::bail!(
+                "No entities found in {}\n\nTip: add a `### Entity: <Name>` heading with a | Field | Type | table, or a ```yaml schema block",
+                design_file.display()
+            );
+        }
+
+        let out_dir = match out_dir {
+            Some(dir) => dir,
+            None => crate::utils::get_workspace_dir()?,
+        };
+        let mut written = Vec::new();
+        for schema in &import.schemas {
+            let yaml = from_design::to_yaml(schema)?;
+            // Same validation as `api check`
+            EntitySchema::from_yaml_str(&yaml)
+                .with_context(|| format!("Imported schema for {} is invalid", schema.name))?;
+            let path = out_dir.join(format!(
+                "{}-schema.yaml",
+                templates::kebab_case(&schema.name)
+            ));
+
+            if dry_run {
+                println!("\n{} {}", "📄".bright_blue(), path.display());
+                print!("{}", yaml);
+                continue;
+            }
+            if path.exists() && !force {
+                println!(
+                    "{} {} exists, skipped (use --force to overwrite)",
+                    "⏭".yellow(),
+                    path.display()
+                );
+                continue;
+            }
+            std::fs::create_dir_all(&out_dir)?;
+            let header = format!(
+                "# Imported from {} by `akatsuki api from-design`\n",
+                design_file.display()
+            );
+            std::fs::write(&path, header + &yaml)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!(
+                "  {} {} ({} fields) → {}",
+                "✓".green(),
+                schema.name.bright_white(),
+                schema.fields.len(),
+                path.display()
+            );
+            written.push(path);
+        }
+
+        if written.is_empty() {
+            return Ok(());
+        }
+        println!("\n{}", "🚀 Next steps:".bright_cyan());
+        println!("  1. Review the generated schemas (operations, RLS, validation)");
+        let files: Vec<String> = written.iter().map(|p| p.display().to_string()).collect();
         println!(
-            "{}",
-            "🔍 HEADLESS API Schema Validator".bright_cyan().bold()
+            "  2. Generate: {}",
+            format!("akatsuki api batch {}", files.join(" ")).bright_white()
         );
+        Ok(())
+    }
+
+    fn generate_factories(&self, files: Vec<PathBuf>, dry_run: bool) -> Result<()> {
+        println!("{}", "🏭 Test Data Factory Generator".bright_cyan().bold());
         println!("{}", "─".repeat(50).bright_black());
-        println!("📁 Validating {} schema file(s)...\n", files.len());
 
-        let mut valid_count = 0;
-        let mut error_count = 0;
+        let schemas = files
+            .iter()
+            .map(|path| EntitySchema::from_yaml(path))
+            .collect::<Result<Vec<_>>>()?;
+        let generated = FactoryGenerator::new(&schemas)?.generate_all()?;
 
-        for (index, path) in files.iter().enumerate() {
-            let file_name = path
-                .file_name()
-                .map(|s| s.to_string_lossy().to_string())
-                .unwrap_or_else(|| path.display().to_string());
+        if dry_run {
+            println!(
+                "\n{}",
+                "🔍 Dry run - files that would be generated:".bright_cyan()
+            );
+            for file in &generated {
+                println!("  {} {}", "•".bright_blue(), file.path.display());
+            }
+            return Ok(());
+        }
+
+        println!("\n{}", "📝 Generating files...".bright_cyan());
+        for file in &generated {
+            file.write_to_disk()?;
+        }
+
+        println!(
+            "\n{}",
+            "✅ Successfully generated factories!".green().bold()
+        );
+        println!("\n{}", "🚀 Next steps:".bright_cyan());
+        println!(
+            "  1. Install faker: {}",
+            "npm install -D @faker-js/faker --workspace app-frontend".bright_white()
+        );
+        println!(
+            "  2. Use in tests/stories: {}",
+            format!(
+                "import {{ build{}, seedFactories }} from '@/test/factories'",
+                schemas[0].name
+            )
+            .bright_white()
+        );
+        Ok(())
+    }
+
+    fn generate_endpoint(
+        &self,
+        name: String,
+        method: HttpMethod,
+        path: String,
+        force: bool,
+    ) -> Result<()> {
+        println!("{}", "🦀 Backend Endpoint Generator".bright_cyan().bold());
+        println!("{}", "─".repeat(50).bright_black());
+
+        let spec = EndpointSpec::new(&name, method, &path)?;
+        println!(
+            "{} {} {} → {}::{}",
+            "✓".green(),
+            spec.method.as_str().to_uppercase(),
+            spec.path.bright_white(),
+            spec.module,
+            spec.module
+        );
+
+        println!("\n{}", "📝 Generating files...".bright_cyan());
+        let module = spec.module.clone();
+        EndpointGenerator::new(spec)?.write_to_disk(force)?;
 
+        println!("\n{}", "✅ Successfully generated endpoint!".green().bold());
+        println!("\n{}", "🚀 Next steps:".bright_cyan());
+        println!(
+            "  1. Implement the handler in packages/app-backend/src/{}.rs",
+            module
+        );
+        println!("  2. Run tests: {}", "akatsuki test backend".bright_white());
+        println!("  3. Start locally: {}", "akatsuki dev backend".bright_white());
+
+        Ok(())
+    }
+
+    fn check_schemas(&self, files: Vec<PathBuf>, output: OutputFormatter) -> Result<()> {
+        let files: Vec<SchemaCheck> = files.iter().map(|path| SchemaCheck::run(path)).collect();
+        let valid = files.iter().filter(|check| check.valid).count();
+        let report = SchemaCheckReport {
+            valid,
+            invalid: files.len() - valid,
+            files,
+        };
+
+        output.emit(&report, Self::print_schema_checks)?;
+        if report.invalid > 0 {
+            return Err(output.failed(format!(
+                "{} schema file(s) failed validation",
+                report.invalid
+            )));
+        }
+        if !output.is_json() {
+            println!("\n{}", "✅ All schemas are valid!".green().bold());
+        }
+        Ok(())
+    }
+
+    fn print_schema_checks(report: &SchemaCheckReport) {
+        println!(
+            "{}",
+            "🔍 HEADLESS API Schema Validator".bright_cyan().bold()
+        );
+        println!("{}", "─".repeat(50).bright_black());
+        println!("📁 Validating {} schema file(s)...\n", report.files.len());
+
+        for (index, check) in report.files.iter().enumerate() {
             print!(
                 "{} [{}/{}] {}",
                 "→".bright_blue(),
                 index + 1,
-                files.len(),
-                file_name.bright_white()
+                report.files.len(),
+                check.file_name().bright_white()
             );
 
-            match EntitySchema::from_yaml(path) {
-                Ok(schema) => {
+            match (&check.entity, &check.error) {
+                (Some(entity), _) => {
                     println!(" {}", "✓".green());
                     println!(
                         "    {} Entity: {}, Table: {}, Fields: {}, Operations: {}",
                         "•".bright_blue(),
-                        schema.name,
-                        schema.table_name,
-                        schema.fields.len(),
-                        schema.operations.len()
+                        entity.name,
+                        entity.table_name,
+                        entity.fields,
+                        entity.operations
                     );
-
-                    // Check for recommended fields
-                    let suggestions = Self::check_recommended_fields(&schema);
-                    for suggestion in &suggestions {
+                    for suggestion in &check.suggestions {
                         println!("    {} {}", "⚠".yellow(), suggestion.yellow());
                     }
-
-                    valid_count += 1;
                 }
-                Err(e) => {
+                (None, error) => {
                     println!(" {}", "✗".red());
-                    println!("    {} {}", "Error:".red(), e);
-                    error_count += 1;
+                    println!("    {} {}", "Error:".red(), error.as_deref().unwrap_or(""));
                 }
             }
         }
 
         println!("\n{}", "─".repeat(50).bright_black());
         println!("{}", "📊 Validation Summary".bright_cyan().bold());
-        println!("  {} Valid:   {}", "✓".green(), valid_count);
-        if error_count > 0 {
-            println!("  {} Invalid: {}", "✗".red(), error_count);
-            anyhow::bail!("{} schema file(s) failed validation", error_count);
+        println!("  {} Valid:   {}", "✓".green(), report.valid);
+        if report.invalid > 0 {
+            println!("  {} Invalid: {}", "✗".red(), report.invalid);
         }
-
-        println!("\n{}", "✅ All schemas are valid!".green().bold());
-        Ok(())
     }
 
     /// Check for recommended fields and return suggestions
@@ -139,12 +580,19 @@ impl ApiCommand {
         schema_path: Option<PathBuf>,
         interactive: bool,
         from_db: bool,
+        options: GenerateOptions,
     ) -> Result<()> {
+        let GenerateOptions {
+            auto_wire,
+            with_graphql,
+            dry_run,
+            force,
+        } = options;
         println!("{}", "🚀 HEADLESS API Generator".bright_cyan().bold());
         println!("{}", "─".repeat(50).bright_black());
 
         // Parse schema
-        let entity_schema = if let Some(path) = schema_path {
+        let mut entity_schema = if let Some(path) = schema_path {
             println!("📖 Reading schema from: {}", path.display());
             EntitySchema::from_yaml(&path)?
         } else if interactive {
@@ -156,6 +604,9 @@ impl ApiCommand {
         } else {
             anyhow::bail!("Please specify one of: --schema <file>, --interactive, or --from-db");
         };
+        entity_schema.graphql |= with_graphql;
+        // `--from-db` takes a table name; the hints below need the entity name
+        let entity_name = entity_schema.name.clone();
 
         println!(
             "\n{} Entity: {}",
@@ -172,11 +623,32 @@ impl ApiCommand {
 
         // Generate code
         println!("\n{}", "📝 Generating files...".bright_cyan());
-        let generator = CodeGenerator::new(entity_schema);
-        let files = generator.generate_all()?;
+        let generator = CodeGenerator::new(entity_schema.clone());
+        let mut files = generator.generate_all()?;
+        if from_db {
+            // The table already exists
+            files.migration = None;
+        } else if !force {
+            files.keep_existing_migration();
+        }
+
+        if dry_run {
+            println!("\n{}", "🔍 Dry run - files that would be generated:".bright_cyan());
+            files.print_summary();
+            if auto_wire {
+                println!("\n{}", "🔌 Wiring (not applied):".bright_cyan());
+                wiring::apply(&wiring::plan(&[&entity_schema])?, true)?;
+            }
+            return Ok(());
+        }
 
         // Write files
-        files.write_to_disk()?;
+        files.write_to_disk(force)?;
+        if from_db {
+            Self::save_imported_schema(&entity_schema)?;
+            // The table as it already exists, for `api update`
+            files.schema_snapshot.write_to_disk()?;
+        }
 
         println!("\n{}", "✅ Successfully generated CRUD API!".green().bold());
         println!("\n{}", "📁 Generated files:".bright_cyan());
@@ -184,7 +656,13 @@ impl ApiCommand {
 
         println!("\n{}", "🚀 Next steps:".bright_cyan());
         println!("  1. Review generated files");
-        println!("  2. Run migration: {}", "akatsuki db push".bright_white());
+        match files.migration {
+            Some(_) => println!("  2. Run migration: {}", "akatsuki db push".bright_white()),
+            None => println!(
+                "  2. No migration needed: {} already exists",
+                entity_schema.table_name
+            ),
+        }
         println!(
             "  3. Deploy Edge Function: {}",
             format!(
@@ -194,6 +672,43 @@ impl ApiCommand {
             .bright_white()
         );
         println!("  4. Test in Browser: http://localhost:5173/examples");
+        let mut step = 5;
+        if entity_schema.graphql {
+            println!(
+                "  {}. Deploy GraphQL: {}",
+                step,
+                format!(
+                    "akatsuki function deploy {}-graphql",
+                    entity_schema.table_name
+                )
+                .bright_white()
+            );
+            step += 1;
+        }
+        if !entity_schema.webhooks.is_empty() {
+            println!(
+                "  {}. Deploy webhooks: {}",
+                step,
+                format!(
+                    "akatsuki function deploy {}-webhooks",
+                    entity_schema.table_name
+                )
+                .bright_white()
+            );
+            for webhook in &entity_schema.webhooks {
+                println!(
+                    "     {} {}",
+                    "secret:".bright_black(),
+                    format!("supabase secrets set {}=...", webhook.secret_env).bright_white()
+                );
+            }
+        }
+
+        if auto_wire {
+            println!("\n{}", "🔌 Wiring routes and demo:".bright_cyan());
+            wiring::apply(&wiring::plan(&[&entity_schema])?, false)?;
+            return Ok(());
+        }
 
         println!("\n{}", "📌 Add routes to App.tsx:".bright_cyan());
         println!(
@@ -226,35 +741,47 @@ impl ApiCommand {
             .bright_white()
         );
         println!("  {}", format!("<{}sDemo />", entity_name).bright_white());
+        println!(
+            "\n💡 Use {} to insert these automatically",
+            "--auto-wire".bright_white()
+        );
 
         Ok(())
     }
 
-    fn list_apis(&self) -> Result<()> {
-        println!("{}", "📋 Generated APIs".bright_cyan().bold());
-        println!("{}", "─".repeat(50).bright_black());
-        println!("\n{}", "Not implemented yet".yellow());
-        println!("This will list all entities with generated CRUD APIs");
-        Ok(())
-    }
-
-    fn delete_api(&self, entity_name: String, force: bool) -> Result<()> {
-        println!(
-            "{} Delete API: {}",
-            "🗑️".to_string(),
-            entity_name.bright_white()
-        );
-        println!("{}", "─".repeat(50).bright_black());
-
-        if !force {
-            println!("\n{}", "Not implemented yet".yellow());
-            println!("This will delete all generated files for the entity");
+    /// Keep the schema inferred by `--from-db` so it can be edited and
+    /// regenerated with `--schema`. An existing file is never overwritten.
+    fn save_imported_schema(schema: &EntitySchema) -> Result<()> {
+        let path = crate::utils::get_workspace_dir()?.join(format!(
+            "{}-schema.yaml",
+            templates::kebab_case(&schema.name)
+        ));
+        if path.exists() {
+            return Ok(());
         }
-
+        let yaml = from_design::to_yaml(schema)?;
+        let header = format!(
+            "# Imported from {} by `akatsuki api new --from-db`\n",
+            from_db::DATABASE_TYPES
+        );
+        std::fs::create_dir_all(path.parent().unwrap_or(Path::new(".")))?;
+        std::fs::write(&path, header + &yaml)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        println!("{} Schema saved to {}", "📄".bright_blue(), path.display());
         Ok(())
     }
 
-    fn generate_batch(&self, files: Vec<std::path::PathBuf>) -> Result<()> {
+    fn generate_batch(
+        &self,
+        files: Vec<std::path::PathBuf>,
+        options: GenerateOptions,
+    ) -> Result<()> {
+        let GenerateOptions {
+            auto_wire,
+            with_graphql,
+            dry_run,
+            force,
+        } = options;
         println!("{}", "🚀 HEADLESS API Batch Generator".bright_cyan().bold());
         println!("{}", "─".repeat(50).bright_black());
         println!("📁 Processing {} schema files...\n", files.len());
@@ -262,6 +789,7 @@ impl ApiCommand {
         let mut success_count = 0;
         let mut error_count = 0;
         let mut results: Vec<(String, bool, String)> = Vec::new();
+        let mut generated: Vec<EntitySchema> = Vec::new();
 
         for (index, path) in files.iter().enumerate() {
             let file_name = path
@@ -279,21 +807,35 @@ impl ApiCommand {
 
             // Parse schema
             match EntitySchema::from_yaml(path) {
-                Ok(entity_schema) => {
+                Ok(mut entity_schema) => {
+                    entity_schema.graphql |= with_graphql;
                     let entity_name = entity_schema.name.clone();
 
                     // Generate code
-                    let generator = CodeGenerator::new(entity_schema);
+                    let generator = CodeGenerator::new(entity_schema.clone());
                     match generator.generate_all() {
-                        Ok(generated_files) => match generated_files.write_to_disk() {
+                        Ok(mut generated_files) => match if dry_run {
+                            Ok(())
+                        } else {
+                            if !force {
+                                generated_files.keep_existing_migration();
+                            }
+                            generated_files.write_to_disk(force)
+                        } {
                             Ok(_) => {
                                 println!(
-                                    "  {} {} generated successfully",
+                                    "  {} {} {}",
                                     "✓".green(),
-                                    entity_name.bright_white()
+                                    entity_name.bright_white(),
+                                    if dry_run {
+                                        "would be generated"
+                                    } else {
+                                        "generated successfully"
+                                    }
                                 );
                                 success_count += 1;
                                 results.push((entity_name, true, "OK".to_string()));
+                                generated.push(entity_schema);
                             }
                             Err(e) => {
                                 println!("  {} {} failed to write: {}", "✗".red(), entity_name, e);
@@ -324,7 +866,21 @@ impl ApiCommand {
             println!("  {} Failed:  {}", "✗".red(), error_count);
         }
 
-        if success_count > 0 {
+        if auto_wire && !generated.is_empty() {
+            println!(
+                "\n{}",
+                if dry_run {
+                    "🔌 Wiring (not applied):"
+                } else {
+                    "🔌 Wiring routes and demos:"
+                }
+                .bright_cyan()
+            );
+            let schemas: Vec<&EntitySchema> = generated.iter().collect();
+            wiring::apply(&wiring::plan(&schemas)?, dry_run)?;
+        }
+
+        if success_count > 0 && !dry_run {
             println!("\n{}", "🚀 Next steps:".bright_cyan());
             println!("  1. Review generated files");
             println!("  2. Run migrations: {}", "akatsuki db push".bright_white());
@@ -8,6 +8,9 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+use super::templates::snake_case;
+use crate::utils::find_project_root;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EntitySchema {
     /// Entity name (PascalCase, e.g., "Article", "User")
@@ -26,6 +29,39 @@ pub struct EntitySchema {
     /// RLS policies
     pub rls: Vec<RLSPolicy>,
 
+    /// Roles (JWT `app_metadata.role`) allowed into the generated admin page.
+    /// Also generates RLS policies granting these roles access to all rows.
+    #[serde(default, rename = "adminRoles")]
+    pub admin_roles: Vec<String>,
+
+    /// Add a `version` column and compare-and-swap updates (409 on mismatch)
+    #[serde(default, rename = "optimisticLocking")]
+    pub optimistic_locking: bool,
+
+    /// Add a `deleted_at` column: delete only marks rows, lists hide them
+    /// unless `includeDeleted`, and a `restore` action brings them back
+    #[serde(default, rename = "softDelete")]
+    pub soft_delete: bool,
+
+    /// Validate timestamps as ISO 8601 (with offset) in the Zod schema and
+    /// coerce timestamp filters (`"2025-01-01"`, epoch ms, ...) to ISO strings
+    #[serde(default, rename = "strictDates")]
+    pub strict_dates: bool,
+
+    /// Outgoing webhooks fired on INSERT / UPDATE / DELETE
+    #[serde(default)]
+    pub webhooks: Vec<Webhook>,
+
+    /// Related entities (hasMany / manyToMany), exposed as nested list
+    /// operations and typed accessors
+    #[serde(default)]
+    pub relations: Vec<Relation>,
+
+    /// Also generate a GraphQL function (Yoga) and typed hooks
+    /// (`api new --with-graphql` sets this too)
+    #[serde(default)]
+    pub graphql: bool,
+
     /// Optional documentation
     #[serde(default)]
     pub documentation: Option<Documentation>,
@@ -124,7 +160,7 @@ impl FieldType {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Validation {
     #[serde(default, rename = "minLength")]
     pub min_length: Option<usize>,
@@ -168,6 +204,29 @@ pub struct Operation {
     /// Max limit
     #[serde(default)]
     pub limit: Option<usize>,
+
+    /// Paged list responses (list only): `cursor` (keyset on created_at, id)
+    /// or `offset` (with total count)
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Pagination {
+    /// `{ items, nextCursor }`; the cursor encodes the last row's created_at and id
+    Cursor,
+    /// `{ items, total, nextOffset }`
+    Offset,
+}
+
+impl Pagination {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Pagination::Cursor => "cursor",
+            Pagination::Offset => "offset",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -178,6 +237,8 @@ pub enum OperationType {
     Create,
     Update,
     Delete,
+    /// CSV export of all rows visible to the caller
+    Export,
     Custom,
 }
 
@@ -190,6 +251,7 @@ impl OperationType {
             OperationType::Create => "create",
             OperationType::Update => "update",
             OperationType::Delete => "delete",
+            OperationType::Export => "export",
             OperationType::Custom => "custom",
         }
     }
@@ -212,6 +274,83 @@ pub struct RLSPolicy {
     pub with_check: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    /// Endpoint receiving the signed POST
+    pub url: String,
+
+    /// Row events to forward (default: all)
+    #[serde(default = "WebhookEvent::all")]
+    pub events: Vec<WebhookEvent>,
+
+    /// Edge Function secret holding the HMAC key (e.g., "ARTICLE_WEBHOOK_SECRET")
+    #[serde(rename = "secretEnv")]
+    pub secret_env: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WebhookEvent {
+    Insert,
+    Update,
+    Delete,
+}
+
+impl WebhookEvent {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Insert, Self::Update, Self::Delete]
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::Insert => "INSERT",
+            WebhookEvent::Update => "UPDATE",
+            WebhookEvent::Delete => "DELETE",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Relation {
+    /// Accessor name (camelCase, e.g., "comments" → `listComments`)
+    pub name: String,
+
+    #[serde(rename = "type")]
+    pub kind: RelationType,
+
+    /// Related entity (PascalCase, e.g., "Comment")
+    pub target: String,
+
+    /// Related table (snake_case, e.g., "comments")
+    #[serde(rename = "targetTable")]
+    pub target_table: String,
+
+    /// hasMany: column of the target table pointing back here
+    /// (default: `<entity>_id`, e.g., "article_id")
+    #[serde(default, rename = "foreignKey")]
+    pub foreign_key: Option<String>,
+
+    /// manyToMany: join table (default: `<entity>_<targetTable>`, e.g., "article_tags")
+    #[serde(default)]
+    pub through: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RelationType {
+    HasMany,
+    ManyToMany,
+}
+
+impl RelationType {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            RelationType::HasMany => "hasMany",
+            RelationType::ManyToMany => "manyToMany",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Documentation {
     #[serde(default)]
@@ -231,27 +370,96 @@ impl EntitySchema {
     /// Parse from YAML file
     pub fn from_yaml(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
-        let schema: EntitySchema = serde_yaml::from_str(&content)?;
+        Self::from_yaml_str(&content)
+    }
+
+    /// Parse and validate schema YAML
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        let schema: EntitySchema = serde_yaml::from_str(content)?;
+
+        // Role names are interpolated into SQL policies
+        if let Some(role) = schema.admin_roles.iter().find(|role| {
+            role.is_empty()
+                || !role
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        }) {
+            anyhow::bail!(
+                "Invalid adminRoles entry '{}': use letters, digits, '-' or '_'",
+                role
+            );
+        }
+
+        if schema.optimistic_locking && schema.fields.iter().any(|f| f.db_name == "version") {
+            anyhow::bail!(
+                "optimisticLocking adds the `version` column itself; remove the `version` field"
+            );
+        }
+
+        if schema.soft_delete && schema.fields.iter().any(|f| f.db_name == "deleted_at") {
+            anyhow::bail!(
+                "softDelete adds the `deleted_at` column itself; remove the `deleted_at` field"
+            );
+        }
+
+        for webhook in &schema.webhooks {
+            let valid_url = (webhook.url.starts_with("https://")
+                || webhook.url.starts_with("http://"))
+                && !webhook
+                    .url
+                    .contains(|c: char| c.is_whitespace() || c == '\'' || c == '"');
+            if !valid_url {
+                anyhow::bail!("Invalid webhook url '{}'", webhook.url);
+            }
+            let valid_env = !webhook.secret_env.is_empty()
+                && webhook
+                    .secret_env
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+            if !valid_env {
+                anyhow::bail!(
+                    "Webhook secretEnv must be an env var name like ARTICLE_WEBHOOK_SECRET (got '{}')",
+                    webhook.secret_env
+                );
+            }
+            if webhook.events.is_empty() {
+                anyhow::bail!("Webhook for '{}' has no events", webhook.url);
+            }
+        }
+
+        schema.validate_relations()?;
+        schema.validate_pagination()?;
+
         Ok(schema)
     }
 
     /// Interactive mode (CLI prompts)
-    pub fn from_interactive(entity_name: &str) -> Result<Self> {
+    pub fn from_interactive(_entity_name: &str) -> Result<Self> {
         // TODO: Implement interactive schema builder
         anyhow::bail!("Interactive mode not implemented yet. Please use --schema <file>")
     }
 
-    /// Parse from Database Types (Supabase)
+    /// Reverse-engineer an existing table from the Supabase database types
+    /// (`entity_name` may also be the table name)
     pub fn from_database_types(entity_name: &str) -> Result<Self> {
-        // TODO: Parse supabase/functions/_shared/database.types.ts
-        anyhow::bail!("Database Types parsing not implemented yet. Please use --schema <file>")
+        super::from_db::import(&find_project_root(), entity_name)
     }
 
     /// Get field by name
+    #[allow(dead_code)]
     pub fn get_field(&self, name: &str) -> Option<&Field> {
         self.fields.iter().find(|f| f.name == name)
     }
 
+    /// Entity name as a variable (`BlogPost` → `blogPost`)
+    pub fn var_name(&self) -> String {
+        let mut chars = self.name.chars();
+        chars
+            .next()
+            .map(|first| first.to_lowercase().collect::<String>() + chars.as_str())
+            .unwrap_or_default()
+    }
+
     /// Get writable fields (exclude auto-generated)
     pub fn writable_fields(&self) -> Vec<&Field> {
         self.fields
@@ -285,6 +493,152 @@ impl EntitySchema {
             .filter(|f| matches!(f.field_type, FieldType::Enum))
             .collect()
     }
+
+    /// Get fields that can be used for ORDER BY (scalar columns)
+    pub fn sortable_fields(&self) -> Vec<&Field> {
+        self.fields
+            .iter()
+            .filter(|f| !matches!(f.field_type, FieldType::Array | FieldType::Json))
+            .collect()
+    }
+
+    /// Row events that at least one webhook listens to (trigger order)
+    pub fn webhook_events(&self) -> Vec<WebhookEvent> {
+        WebhookEvent::all()
+            .into_iter()
+            .filter(|event| self.webhooks.iter().any(|w| w.events.contains(event)))
+            .collect()
+    }
+
+    /// Get the first operation of a type
+    pub fn operation(&self, op_type: OperationType) -> Option<&Operation> {
+        self.operations.iter().find(|op| op.op_type == op_type)
+    }
+
+    /// Column referencing this entity from related tables (`BlogPost` → `blog_post_id`)
+    pub fn foreign_key_column(&self) -> String {
+        format!("{}_id", snake_case(&self.name))
+    }
+
+    /// Only list operations page; cursors are built from `id` and `created_at`
+    fn validate_pagination(&self) -> Result<()> {
+        for op in &self.operations {
+            match op.pagination {
+                Some(_) if op.op_type != OperationType::List => anyhow::bail!(
+                    "pagination is only supported on list operations (found on {})",
+                    op.name.as_deref().unwrap_or(op.op_type.as_str())
+                ),
+                Some(Pagination::Cursor) => {
+                    let has_id = self
+                        .fields
+                        .iter()
+                        .any(|f| f.primary_key && f.db_name == "id");
+                    let has_created_at = self.fields.iter().any(|f| f.db_name == "created_at");
+                    if !has_id || !has_created_at {
+                        anyhow::bail!(
+                            "cursor pagination needs an `id` primary key and a `created_at` column on {}",
+                            self.name
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Relation names and table / column names are interpolated into SQL and
+    /// TypeScript identifiers
+    fn validate_relations(&self) -> Result<()> {
+        if self.relations.is_empty() {
+            return Ok(());
+        }
+        if !self
+            .fields
+            .iter()
+            .any(|f| f.primary_key && f.db_name == "id")
+        {
+            anyhow::bail!("relations need an `id` primary key on {}", self.name);
+        }
+
+        let sql_name = |value: &str| {
+            value.starts_with(|c: char| c.is_ascii_lowercase())
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        };
+        for (i, relation) in self.relations.iter().enumerate() {
+            let valid_name = relation.name.starts_with(|c: char| c.is_ascii_lowercase())
+                && relation.name.chars().all(|c| c.is_ascii_alphanumeric());
+            if !valid_name {
+                anyhow::bail!(
+                    "Relation name must be camelCase like `comments` (got '{}')",
+                    relation.name
+                );
+            }
+            if self.relations[..i].iter().any(|r| r.name == relation.name) {
+                anyhow::bail!("Duplicate relation '{}'", relation.name);
+            }
+            let valid_target = relation
+                .target
+                .starts_with(|c: char| c.is_ascii_uppercase())
+                && relation.target.chars().all(|c| c.is_ascii_alphanumeric());
+            if !valid_target {
+                anyhow::bail!(
+                    "Relation '{}': target must be an entity name like `Comment` (got '{}')",
+                    relation.name,
+                    relation.target
+                );
+            }
+            let tables = [
+                Some(&relation.target_table),
+                relation.foreign_key.as_ref(),
+                relation.through.as_ref(),
+            ];
+            if let Some(name) = tables.into_iter().flatten().find(|name| !sql_name(name)) {
+                anyhow::bail!(
+                    "Relation '{}': '{}' is not a snake_case table or column name",
+                    relation.name,
+                    name
+                );
+            }
+            match relation.kind {
+                RelationType::HasMany if relation.through.is_some() => anyhow::bail!(
+                    "Relation '{}': `through` is only for manyToMany",
+                    relation.name
+                ),
+                RelationType::ManyToMany if relation.foreign_key.is_some() => anyhow::bail!(
+                    "Relation '{}': `foreignKey` is only for hasMany (the join table has `{}` and `{}`)",
+                    relation.name,
+                    self.foreign_key_column(),
+                    relation.target_key()
+                ),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Relation {
+    /// hasMany: target column pointing at `schema`
+    pub fn foreign_key(&self, schema: &EntitySchema) -> String {
+        self.foreign_key
+            .clone()
+            .unwrap_or_else(|| schema.foreign_key_column())
+    }
+
+    /// manyToMany: join table between `schema` and the target
+    pub fn join_table(&self, schema: &EntitySchema) -> String {
+        self.through
+            .clone()
+            .unwrap_or_else(|| format!("{}_{}", snake_case(&schema.name), self.target_table))
+    }
+
+    /// manyToMany: join table column pointing at the target (`Tag` → `tag_id`)
+    pub fn target_key(&self) -> String {
+        format!("{}_id", snake_case(&self.target))
+    }
 }
 
 impl Field {
@@ -381,8 +735,8 @@ impl Field {
         }
     }
 
-    /// Get Zod type
-    pub fn zod_type(&self) -> String {
+    /// Get Zod type (`strict_dates`: timestamps must be ISO 8601 with offset)
+    pub fn zod_type(&self, strict_dates: bool) -> String {
         match self.field_type {
             FieldType::String => {
                 let mut zod = "z.string()".to_string();
@@ -406,6 +760,9 @@ impl Field {
             FieldType::Integer => "z.number().int()".to_string(),
             FieldType::Boolean => "z.boolean()".to_string(),
             FieldType::Uuid => "z.string().uuid()".to_string(),
+            FieldType::Timestamp if strict_dates => {
+                "z.string().datetime({ offset: true })".to_string()
+            }
             FieldType::Timestamp => "z.string()".to_string(),
             FieldType::Enum => {
                 if let Some(ref values) = self.enum_values {
@@ -441,6 +798,185 @@ impl Field {
             _ => "z.any()".to_string(),
         }
     }
+
+    /// Get Zod type for list/export filters, which may come from query strings:
+    /// numbers are coerced, strict timestamps are parsed and normalized to ISO
+    pub fn zod_filter_type(&self, strict_dates: bool) -> String {
+        match self.field_type {
+            FieldType::Number => "z.coerce.number()".to_string(),
+            FieldType::Integer => "z.coerce.number().int()".to_string(),
+            FieldType::Timestamp if strict_dates => {
+                "z.coerce.date().transform((date) => date.toISOString())".to_string()
+            }
+            _ => self.zod_type(strict_dates),
+        }
+    }
+
+    /// GraphQL type without the non-null marker; enums become `<Entity><Field>`
+    /// enum types when every value is a valid GraphQL name
+    pub fn graphql_type(&self, entity: &str) -> String {
+        match self.field_type {
+            FieldType::String | FieldType::Timestamp => "String".to_string(),
+            FieldType::Number => "Float".to_string(),
+            FieldType::Integer => "Int".to_string(),
+            FieldType::Boolean => "Boolean".to_string(),
+            FieldType::Uuid => "ID".to_string(),
+            FieldType::Enum => match self.graphql_enum_values() {
+                Some(_) => format!("{}{}", entity, self.pascal_name()),
+                None => "String".to_string(),
+            },
+            FieldType::Array => {
+                let element = match self.array_type.as_deref() {
+                    Some("number") => "Float",
+                    Some("boolean") => "Boolean",
+                    Some("uuid") => "ID",
+                    _ => "String",
+                };
+                format!("[{}!]", element)
+            }
+            FieldType::Json => "JSON".to_string(),
+        }
+    }
+
+    /// Enum values, when all of them can be GraphQL enum values
+    pub fn graphql_enum_values(&self) -> Option<&[String]> {
+        let values = self.enum_values.as_deref()?;
+        let valid = |value: &String| {
+            value
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        };
+        (!values.is_empty() && values.iter().all(valid)).then_some(values)
+    }
+
+    fn pascal_name(&self) -> String {
+        let mut chars = self.name.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().collect::<String>() + chars.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Referenced table of a foreign key (`"articles(id)"` → `"articles"`)
+    pub fn referenced_table(&self) -> Option<&str> {
+        let reference = self.references.as_deref()?;
+        let table = reference.split('(').next()?.trim();
+        Some(table.strip_prefix("public.").unwrap_or(table))
+    }
+
+    /// Get faker expression (test data factories) respecting enum values and validation
+    pub fn faker_expression(&self) -> String {
+        let validation = self.validation.clone().unwrap_or_default();
+        match self.field_type {
+            FieldType::String => self.faker_string(&validation),
+            FieldType::Number => format!(
+                "faker.number.float({{ min: {}, max: {}, fractionDigits: 2 }})",
+                validation.min.unwrap_or(0.0),
+                validation.max.unwrap_or(1000.0)
+            ),
+            FieldType::Integer => format!(
+                "faker.number.int({{ min: {}, max: {} }})",
+                validation.min.map(|min| min.ceil() as i64).unwrap_or(0),
+                validation.max.map(|max| max.floor() as i64).unwrap_or(1000)
+            ),
+            FieldType::Boolean => "faker.datatype.boolean()".to_string(),
+            FieldType::Uuid => "faker.string.uuid()".to_string(),
+            FieldType::Timestamp => "faker.date.recent().toISOString()".to_string(),
+            FieldType::Enum => match self.enum_values.as_deref() {
+                Some(values) if !values.is_empty() => format!(
+                    "faker.helpers.arrayElement([{}] as const)",
+                    values
+                        .iter()
+                        .map(|v| format!("'{}'", v))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                _ => "faker.lorem.word()".to_string(),
+            },
+            FieldType::Array => format!(
+                "faker.helpers.multiple(() => {}, {{ count: 3 }})",
+                self.faker_element(self.array_type.as_deref().unwrap_or("string"))
+            ),
+            FieldType::Json => "{ note: faker.lorem.word() }".to_string(),
+        }
+    }
+
+    fn faker_string(&self, validation: &Validation) -> String {
+        if let Some(ref pattern) = validation.pattern {
+            let pattern = pattern.trim_start_matches('^').trim_end_matches('$');
+            return format!(
+                "faker.helpers.fromRegExp(/{}/)",
+                pattern.replace('/', "\\/")
+            );
+        }
+        let name = self.name.to_lowercase();
+        if validation.email || name.contains("email") {
+            return "faker.internet.email()".to_string();
+        }
+        if validation.url || name.ends_with("url") || name.ends_with("link") {
+            return "faker.internet.url()".to_string();
+        }
+
+        let value = if name.contains("name") {
+            "faker.person.fullName()"
+        } else if name.contains("title") || name.contains("subject") {
+            "faker.lorem.sentence({ min: 3, max: 6 })"
+        } else if ["description", "body", "content", "bio", "summary", "note"]
+            .iter()
+            .any(|word| name.contains(word))
+        {
+            "faker.lorem.paragraph()"
+        } else if name.contains("phone") {
+            "faker.phone.number()"
+        } else if name.contains("slug") {
+            "faker.lorem.slug()"
+        } else if name.contains("color") {
+            "faker.color.rgb()"
+        } else {
+            "faker.lorem.words(3)"
+        };
+
+        match (validation.min_length, validation.max_length) {
+            (None, None) => value.to_string(),
+            (min, Some(max)) => format!("fitLength({}, {}, {})", value, min.unwrap_or(0), max),
+            (Some(min), None) => format!("fitLength({}, {})", value, min),
+        }
+    }
+
+    fn faker_element(&self, element_type: &str) -> &str {
+        match element_type {
+            "number" => "faker.number.float({ max: 100, fractionDigits: 2 })",
+            "integer" => "faker.number.int(100)",
+            "boolean" => "faker.datatype.boolean()",
+            "uuid" => "faker.string.uuid()",
+            _ => "faker.lorem.word()",
+        }
+    }
+}
+
+// Default implementation for Field (used in tests)
+impl Default for Field {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            db_name: String::new(),
+            field_type: FieldType::String,
+            required: false,
+            default: None,
+            primary_key: false,
+            references: None,
+            on_delete: None,
+            index: false,
+            index_type: None,
+            unique: false,
+            enum_values: None,
+            array_type: None,
+            validation: None,
+            auto_update: false,
+        }
+    }
 }
 
 // ============================================================================
@@ -462,6 +998,7 @@ mod tests {
         assert_eq!(OperationType::Create.as_str(), "create");
         assert_eq!(OperationType::Update.as_str(), "update");
         assert_eq!(OperationType::Delete.as_str(), "delete");
+        assert_eq!(OperationType::Export.as_str(), "export");
         assert_eq!(OperationType::Custom.as_str(), "custom");
     }
 
@@ -535,6 +1072,7 @@ mod tests {
                     description: None,
                     filters: vec!["type".to_string()],
                     limit: None,
+                    pagination: None,
                 },
                 Operation {
                     op_type: OperationType::Custom,
@@ -542,9 +1080,17 @@ mod tests {
                     description: None,
                     filters: vec!["type".to_string()],
                     limit: None,
+                    pagination: None,
                 },
             ],
             rls: vec![],
+            admin_roles: Vec::new(),
+            optimistic_locking: false,
+            soft_delete: false,
+            strict_dates: false,
+            graphql: false,
+            webhooks: Vec::new(),
+            relations: Vec::new(),
             documentation: None,
         }
     }
@@ -633,43 +1179,81 @@ mod tests {
             }),
             ..Default::default()
         };
-        assert_eq!(field.zod_type(), "z.string().min(1).max(100)");
+        assert_eq!(field.zod_type(false), "z.string().min(1).max(100)");
     }
-}
 
-// Default implementation for Field (used in tests)
-impl Default for Field {
-    fn default() -> Self {
-        Self {
-            name: String::new(),
-            db_name: String::new(),
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            primary_key: false,
-            references: None,
-            on_delete: None,
-            index: false,
-            index_type: None,
-            unique: false,
-            enum_values: None,
-            array_type: None,
-            validation: None,
-            auto_update: false,
-        }
+    // -------------------------------------------------------------------------
+    // Relation tests
+    // -------------------------------------------------------------------------
+
+    const RELATIONS_YAML: &str = "name: BlogPost\ntableName: blog_posts\nfields:\n  - name: id\n    dbName: id\n    type: uuid\n    primaryKey: true\noperations: []\nrls: []\nrelations:\n";
+
+    #[test]
+    fn test_relation_defaults() {
+        let schema = EntitySchema::from_yaml_str(&format!(
+            "{}  - name: comments\n    type: hasMany\n    target: Comment\n    targetTable: comments\n  - name: tags\n    type: manyToMany\n    target: Tag\n    targetTable: tags\n",
+            RELATIONS_YAML
+        ))
+        .unwrap();
+        let (comments, tags) = (&schema.relations[0], &schema.relations[1]);
+        assert_eq!(comments.kind, RelationType::HasMany);
+        assert_eq!(comments.foreign_key(&schema), "blog_post_id");
+        assert_eq!(tags.join_table(&schema), "blog_post_tags");
+        assert_eq!(tags.target_key(), "tag_id");
     }
-}
 
-impl Default for Validation {
-    fn default() -> Self {
-        Self {
-            min_length: None,
-            max_length: None,
-            min: None,
-            max: None,
-            email: false,
-            url: false,
-            pattern: None,
+    #[test]
+    fn test_relation_validation() {
+        let invalid = [
+            "  - name: Comments\n    type: hasMany\n    target: Comment\n    targetTable: comments\n",
+            "  - name: tags\n    type: manyToMany\n    target: Tag\n    targetTable: tags\n    foreignKey: post_id\n",
+            "  - name: comments\n    type: hasMany\n    target: Comment\n    targetTable: comments\n    through: post_comments\n",
+            "  - name: comments\n    type: hasMany\n    target: Comment\n    targetTable: \"comments; DROP\"\n",
+            "  - name: tags\n    type: manyToMany\n    target: Tag\n    targetTable: tags\n  - name: tags\n    type: manyToMany\n    target: Tag\n    targetTable: tags\n",
+        ];
+        for relations in invalid {
+            let yaml = format!("{}{}", RELATIONS_YAML, relations);
+            assert!(EntitySchema::from_yaml_str(&yaml).is_err(), "{}", relations);
         }
+
+        let without_id = RELATIONS_YAML.replace("    primaryKey: true\n", "");
+        let yaml = format!(
+            "{}  - name: comments\n    type: hasMany\n    target: Comment\n    targetTable: comments\n",
+            without_id
+        );
+        assert!(EntitySchema::from_yaml_str(&yaml).is_err());
+    }
+
+    #[test]
+    fn test_pagination_validation() {
+        let yaml = |fields: &str, operations: &str| {
+            format!(
+                "name: Article\ntableName: articles\nfields:\n  - name: id\n    dbName: id\n    type: uuid\n    primaryKey: true\n{}operations:\n{}rls: []\n",
+                fields, operations
+            )
+        };
+        let created_at = "  - name: createdAt\n    dbName: created_at\n    type: timestamp\n";
+
+        let schema = EntitySchema::from_yaml_str(&yaml(
+            created_at,
+            "  - type: list\n    pagination: cursor\n",
+        ))
+        .unwrap();
+        assert_eq!(schema.operations[0].pagination, Some(Pagination::Cursor));
+        assert!(
+            EntitySchema::from_yaml_str(&yaml("", "  - type: list\n    pagination: offset\n"))
+                .is_ok()
+        );
+
+        // Cursor without created_at, pagination on a non-list operation
+        assert!(
+            EntitySchema::from_yaml_str(&yaml("", "  - type: list\n    pagination: cursor\n"))
+                .is_err()
+        );
+        assert!(EntitySchema::from_yaml_str(&yaml(
+            created_at,
+            "  - type: get\n    pagination: offset\n"
+        ))
+        .is_err());
     }
 }
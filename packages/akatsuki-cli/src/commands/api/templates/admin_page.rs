@@ -2,9 +2,14 @@
 ///
 /// Generates TypeScript Admin Page with:
 /// - CRUD operations with Table/Form UI
+/// - Server-side pagination and column sorting
+/// - Column show/hide (persisted in localStorage)
+/// - CSV export (when the schema has an `export` operation)
+/// - Role check via JWT `app_metadata.role` (when the schema sets `adminRoles`)
+/// - Edit conflict banner (when the schema sets `optimisticLocking`)
+/// - Recent webhook deliveries (when the schema has `webhooks`)
 /// - Dummy data generation button
 /// - Powered by generated hooks
-
 pub const ADMIN_PAGE_TEMPLATE: &str = r##"/**
  * {{ name }} Admin Page
  * Auto-generated by HEADLESS API Generator
@@ -12,14 +17,30 @@ pub const ADMIN_PAGE_TEMPLATE: &str = r##"/**
  * Features:
  * - Full CRUD operations
  * - Dummy data generation
- * - Table view with actions
+ * - Server-driven table: pagination, column sorting, column visibility
+{%- if has_export %}
+ * - CSV export (export operation)
+{%- endif %}
+{%- if optimistic_locking %}
+ * - Edit conflict handling (optimistic locking, 409 on stale version)
+{%- endif %}
+{%- if has_webhooks %}
+ * - Recent webhook deliveries ({{ table_name }}_webhook_deliveries)
+{%- endif %}
+{%- if admin_roles %}
+ * - Role check: only {{ admin_roles | join(", ") }} (JWT app_metadata.role)
+{%- endif %}
  *
  * Add to App.tsx:
  *   import { {{ name }}AdminPage } from './pages/admin/entities/{{ name }}AdminPage'
  *   <Route path="/admin/{{ name | lower }}s" element={<{{ name }}AdminPage />} />
  */
 
-import { useState } from 'react'
+import { useEffect, useState } from 'react'
+{%- if has_webhooks %}
+import { useQuery } from '@tanstack/react-query'
+import { supabase } from '../../../lib/supabase'
+{%- endif %}
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '../../../components/ui/card'
 import { Button } from '../../../components/ui/button'
 import { Input } from '../../../components/ui/input'
@@ -42,8 +63,21 @@ import {
   DialogTitle,
   DialogTrigger,
 } from '../../../components/ui/dialog'
-import { use{{ name }}s } from '../../../hooks/use{{ name }}s'
+import {
+  DropdownMenu,
+  DropdownMenuCheckboxItem,
+  DropdownMenuContent,
+  DropdownMenuLabel,
+  DropdownMenuTrigger,
+} from '../../../components/ui/dropdown-menu'
+import { use{{ name }}s{% if optimistic_locking %}, is{{ name }}VersionConflict{% endif %} } from '../../../hooks/use{{ name }}s'
+{%- if has_export %}
+import { {{ name }}Service } from '../../../services/{{ name }}Service'
+{%- endif %}
 import type { {{ name }} } from '../../../models/{{ name }}'
+{%- if admin_roles %}
+import { useAuth } from '../../../contexts/AuthContext'
+{%- endif %}
 
 // Dummy data templates - customize these for your entity
 const DUMMY_DATA_TEMPLATES = [
@@ -70,11 +104,98 @@ const DUMMY_DATA_TEMPLATES = [
 {%- endfor %}
 ]
 
+// Table columns - sortKey is the DB column passed to the list operation
+{%- if list_pagination == "cursor" %}
+// (cursor pagination pages through created_at order only)
+{%- endif %}
+const COLUMNS: { key: string; label: string; sortKey: string | null }[] = [
+{%- for field in display_fields %}
+  { key: '{{ field.name }}', label: '{{ field.name | pascal_case }}', sortKey: {% if field.field_type == "array" or field.field_type == "json" or list_pagination == "cursor" %}null{% else %}'{{ field.db_name }}'{% endif %} },
+{%- endfor %}
+  { key: 'createdAt', label: 'Created', sortKey: 'created_at' },
+]
+
+const PAGE_SIZES = [10, 25, 50]{% if list_limit %}.filter((size) => size < {{ list_limit }}){% endif %}
+const HIDDEN_COLUMNS_KEY = 'akatsuki:admin:{{ table_name }}:hidden-columns'
+{% if has_webhooks %}
+interface WebhookDelivery {
+  id: string
+  event: 'INSERT' | 'UPDATE' | 'DELETE'
+  record_id: string | null
+  url: string
+  status_code: number | null
+  success: boolean
+  error: string | null
+  duration_ms: number | null
+  created_at: string
+}
+{% endif %}
+function loadHiddenColumns(): string[] {
+  try {
+    return JSON.parse(localStorage.getItem(HIDDEN_COLUMNS_KEY) ?? '[]')
+  } catch {
+    return []
+  }
+}
+
+{%- if admin_roles %}
+// Roles allowed in (Supabase JWT app_metadata.role, enforced again by RLS)
+const ADMIN_ROLES = [{% for role in admin_roles %}'{{ role }}'{% if not loop.last %}, {% endif %}{% endfor %}]
+
 export function {{ name }}AdminPage() {
+  const { user, loading } = useAuth()
+
+  if (loading) {
+    return <p className="text-center py-8 text-gray-500">Loading...</p>
+  }
+
+  const role = user?.app_metadata?.role
+  if (typeof role !== 'string' || !ADMIN_ROLES.includes(role)) {
+    return (
+      <Card>
+        <CardHeader>
+          <CardTitle>Access denied</CardTitle>
+          <CardDescription>
+            {{ name }} management requires one of these roles: {ADMIN_ROLES.join(', ')}
+          </CardDescription>
+        </CardHeader>
+      </Card>
+    )
+  }
+
+  return <{{ name }}AdminContent />
+}
+
+function {{ name }}AdminContent() {
+{%- else %}
+export function {{ name }}AdminPage() {
+{%- endif %}
   const [isCreateOpen, setIsCreateOpen] = useState(false)
   const [isEditOpen, setIsEditOpen] = useState(false)
   const [editingItem, setEditingItem] = useState<{{ name }} | null>(null)
+{%- if optimistic_locking %}
+  // Set when the edited row changed on the server since the dialog opened
+  const [hasConflict, setHasConflict] = useState(false)
+{%- endif %}
   const [isGenerating, setIsGenerating] = useState(false)
+{%- if has_export %}
+  const [isExporting, setIsExporting] = useState(false)
+{%- endif %}
+
+  // Table state (sorting / pagination run on the server)
+  const [page, setPage] = useState(0)
+  const [pageSize, setPageSize] = useState(PAGE_SIZES[0] ?? 10)
+  const [sortKey, setSortKey] = useState('created_at')
+  const [sortAscending, setSortAscending] = useState(false)
+{%- if list_pagination == "cursor" %}
+  // Cursor of every page visited so far (index = page, page 0 has none)
+  const [cursors, setCursors] = useState<(string | undefined)[]>([undefined])
+{%- endif %}
+  const [hiddenColumns, setHiddenColumns] = useState<string[]>(loadHiddenColumns)
+
+  useEffect(() => {
+    localStorage.setItem(HIDDEN_COLUMNS_KEY, JSON.stringify(hiddenColumns))
+  }, [hiddenColumns])
 
   // Form state
 {%- for field in writable_fields %}
@@ -88,7 +209,11 @@ export function {{ name }}AdminPage() {
     isLoading,
     create{{ name }},
     isCreating,
+{%- if optimistic_locking %}
+    update{{ name }}Async,
+{%- else %}
     update{{ name }},
+{%- endif %}
     isUpdating,
     delete{{ name }},
     isDeleting,
@@ -98,7 +223,76 @@ export function {{ name }}AdminPage() {
     set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }},
 {%- endif %}
 {%- endfor %}
-  } = use{{ name }}s({ mine: true })
+{%- if optimistic_locking %}
+    refetch,
+{%- endif %}
+{%- if list_pagination == "cursor" %}
+    nextCursor,
+  } = use{{ name }}s({
+    mine: true,
+    limit: pageSize,
+    cursor: cursors[page],
+    ascending: sortAscending,
+  })
+{%- else %}
+  } = use{{ name }}s({
+    mine: true,
+    // Fetch one extra row to know whether a next page exists
+    limit: pageSize + 1,
+    offset: page * pageSize,
+    orderBy: sortKey,
+    ascending: sortAscending,
+  })
+{%- endif %}
+{% if has_webhooks %}
+  const { data: deliveries = [], refetch: refetchDeliveries } = useQuery({
+    queryKey: ['{{ table_name }}_webhook_deliveries'],
+    queryFn: async () => {
+      const { data, error } = await supabase
+        .from('{{ table_name }}_webhook_deliveries')
+        .select('*')
+        .order('created_at', { ascending: false })
+        .limit(20)
+      if (error) throw error
+      return data as WebhookDelivery[]
+    },
+  })
+{% endif %}
+{%- if list_pagination == "cursor" %}
+  const rows = {{ name | lower }}s ?? []
+  const hasNextPage = nextCursor !== null
+
+  const goToNextPage = () => {
+    setCursors((visited) => [...visited.slice(0, page + 1), nextCursor ?? undefined])
+    setPage(page + 1)
+  }
+{%- else %}
+  const rows = ({{ name | lower }}s ?? []).slice(0, pageSize)
+  const hasNextPage = ({{ name | lower }}s?.length ?? 0) > pageSize
+{%- endif %}
+  const isVisible = (key: string) => !hiddenColumns.includes(key)
+
+  const toggleColumn = (key: string) => {
+    setHiddenColumns((hidden) =>
+      hidden.includes(key) ? hidden.filter((k) => k !== key) : [...hidden, key]
+    )
+  }
+
+  const handleSort = (key: string | null) => {
+    if (!key) return
+    if (key === sortKey) {
+      setSortAscending(!sortAscending)
+    } else {
+      setSortKey(key)
+      setSortAscending(true)
+    }
+    setPage(0)
+  }
+
+  const sortIndicator = (key: string | null) => {
+    if (!key || key !== sortKey) return ''
+    return sortAscending ? ' ▲' : ' ▼'
+  }
 
   const resetForm = () => {
 {%- for field in writable_fields %}
@@ -127,8 +321,51 @@ export function {{ name }}AdminPage() {
     set{{ field.name | pascal_case }}(item.{{ field.name }}{% if not field.required %} ?? {{ field.typescript_default }}{% endif %})
 {%- endif %}
 {%- endfor %}
+{%- if optimistic_locking %}
+    setHasConflict(false)
+{%- endif %}
     setIsEditOpen(true)
   }
+{%- if optimistic_locking %}
+
+  const handleUpdate = async () => {
+    if (!editingItem?.id) return
+    try {
+      await update{{ name }}Async(
+        editingItem.id,
+        {
+{%- for field in updatable_fields %}
+          {{ field.name }},
+{%- endfor %}
+        },
+        editingItem.version!
+      )
+    } catch (err) {
+      if (is{{ name }}VersionConflict(err)) {
+        // Keep the dialog open so the user can decide what to do with their edits
+        setHasConflict(true)
+      } else {
+        alert(`Update failed: ${err instanceof Error ? err.message : String(err)}`)
+      }
+      return
+    }
+    resetForm()
+    setEditingItem(null)
+    setIsEditOpen(false)
+  }
+
+  const handleLoadLatest = async () => {
+    if (!editingItem?.id) return
+    const { data } = await refetch()
+    const latest = data?.find((item) => item.id === editingItem.id)
+    if (!latest) {
+      alert('This {{ name | lower }} was deleted or is no longer on this page.')
+      setIsEditOpen(false)
+      return
+    }
+    handleEdit(latest)
+  }
+{%- else %}
 
   const handleUpdate = () => {
     if (!editingItem?.id) return
@@ -141,6 +378,7 @@ export function {{ name }}AdminPage() {
     setEditingItem(null)
     setIsEditOpen(false)
   }
+{%- endif %}
 
   const handleDelete = (id: string) => {
     if (confirm('Are you sure you want to delete this {{ name | lower }}?')) {
@@ -162,7 +400,28 @@ export function {{ name }}AdminPage() {
     }
   }
 
-  const totalCount = {{ name | lower }}s?.length ?? 0
+{%- if has_export %}
+  const handleExport = async () => {
+    setIsExporting(true)
+    try {
+      const result = await {{ name }}Service.exportCsv({ orderBy: sortKey, ascending: sortAscending })
+      if (result.error) throw result.error
+      if (!result.data) return
+
+      const blob = new Blob([result.data.csv], { type: 'text/csv;charset=utf-8' })
+      const url = URL.createObjectURL(blob)
+      const link = document.createElement('a')
+      link.href = url
+      link.download = result.data.filename
+      link.click()
+      URL.revokeObjectURL(url)
+    } catch (err) {
+      alert(`Export failed: ${err instanceof Error ? err.message : String(err)}`)
+    } finally {
+      setIsExporting(false)
+    }
+  }
+{%- endif %}
 
   return (
     <div className="space-y-6">
@@ -173,6 +432,11 @@ export function {{ name }}AdminPage() {
           <p className="text-gray-500">Manage {{ name | lower }}s in the system</p>
         </div>
         <div className="flex gap-2">
+{%- if has_export %}
+          <Button variant="outline" onClick={handleExport} disabled={isExporting}>
+            {isExporting ? '⏳ Exporting...' : '⬇️ Export CSV'}
+          </Button>
+{%- endif %}
           <Button
             variant="outline"
             onClick={handleGenerateDummyData}
@@ -251,8 +515,8 @@ export function {{ name }}AdminPage() {
         <CardContent>
           <div className="grid grid-cols-2 md:grid-cols-4 gap-4">
             <div className="bg-blue-50 p-4 rounded-lg">
-              <p className="text-2xl font-bold">{totalCount}</p>
-              <p className="text-sm text-gray-500">Total {{ name }}s</p>
+              <p className="text-2xl font-bold">{rows.length}</p>
+              <p className="text-sm text-gray-500">{{ name }}s on page {page + 1}</p>
             </div>
           </div>
         </CardContent>
@@ -260,78 +524,216 @@ export function {{ name }}AdminPage() {
 
       {/* Table */}
       <Card>
-        <CardHeader>
-          <CardTitle>📋 {{ name }} List</CardTitle>
-          <CardDescription>
-            All {{ name | lower }}s in the system
-          </CardDescription>
+        <CardHeader className="flex flex-row items-start justify-between space-y-0">
+          <div className="space-y-1.5">
+            <CardTitle>📋 {{ name }} List</CardTitle>
+            <CardDescription>
+              All {{ name | lower }}s in the system
+            </CardDescription>
+          </div>
+          <DropdownMenu>
+            <DropdownMenuTrigger asChild>
+              <Button variant="outline" size="sm">Columns</Button>
+            </DropdownMenuTrigger>
+            <DropdownMenuContent align="end">
+              <DropdownMenuLabel>Visible columns</DropdownMenuLabel>
+              {COLUMNS.map((column) => (
+                <DropdownMenuCheckboxItem
+                  key={column.key}
+                  checked={isVisible(column.key)}
+                  onCheckedChange={() => toggleColumn(column.key)}
+                >
+                  {column.label}
+                </DropdownMenuCheckboxItem>
+              ))}
+            </DropdownMenuContent>
+          </DropdownMenu>
         </CardHeader>
         <CardContent>
           {isLoading ? (
             <p className="text-center py-8 text-gray-500">Loading...</p>
-          ) : totalCount === 0 ? (
+          ) : rows.length === 0 && page === 0 ? (
             <p className="text-center py-8 text-gray-500">
               No {{ name | lower }}s yet. Create one or generate dummy data!
             </p>
           ) : (
-            <Table>
-              <TableHeader>
-                <TableRow>
-{%- for field in display_fields %}
-                  <TableHead>{{ field.name | pascal_case }}</TableHead>
-{%- endfor %}
-                  <TableHead>Created</TableHead>
-                  <TableHead className="text-right">Actions</TableHead>
-                </TableRow>
-              </TableHeader>
-              <TableBody>
-                { {{ name | lower }}s?.map((item) => (
-                  <TableRow key={item.id}>
+            <>
+              <Table>
+                <TableHeader>
+                  <TableRow>
+                    {COLUMNS.filter((column) => isVisible(column.key)).map((column) => (
+                      <TableHead
+                        key={column.key}
+                        className={column.sortKey ? 'cursor-pointer select-none' : undefined}
+                        onClick={() => handleSort(column.sortKey)}
+                      >
+                        {column.label}
+                        {sortIndicator(column.sortKey)}
+                      </TableHead>
+                    ))}
+                    <TableHead className="text-right">Actions</TableHead>
+                  </TableRow>
+                </TableHeader>
+                <TableBody>
+                  {rows.map((item) => (
+                    <TableRow key={item.id}>
 {%- for field in display_fields %}
 {%- if field.field_type == "enum" %}
-                    <TableCell>
-                      <Badge variant="secondary">
-                        {item.{{ field.name }}}
-                      </Badge>
-                    </TableCell>
+                      {isVisible('{{ field.name }}') && (
+                        <TableCell>
+                          <Badge variant="secondary">
+                            {item.{{ field.name }}}
+                          </Badge>
+                        </TableCell>
+                      )}
+{%- elif field.field_type == "array" %}
+                      {isVisible('{{ field.name }}') && (
+                        <TableCell className="max-w-xs truncate">{item.{{ field.name }}?.join(', ')}</TableCell>
+                      )}
+{%- elif field.field_type == "boolean" %}
+                      {isVisible('{{ field.name }}') && (
+                        <TableCell>{item.{{ field.name }} ? '✓' : '-'}</TableCell>
+                      )}
 {%- else %}
-                    <TableCell className="max-w-xs truncate">{item.{{ field.name }}}</TableCell>
+                      {isVisible('{{ field.name }}') && (
+                        <TableCell className="max-w-xs truncate">{String(item.{{ field.name }} ?? '')}</TableCell>
+                      )}
 {%- endif %}
 {%- endfor %}
-                    <TableCell className="text-sm text-gray-500">
-                      {item.createdAt ? new Date(item.createdAt).toLocaleDateString() : '-'}
-                    </TableCell>
-                    <TableCell className="text-right">
-                      <div className="flex justify-end gap-2">
-                        <Button size="sm" variant="outline" onClick={() => handleEdit(item)}>
-                          Edit
-                        </Button>
+                      {isVisible('createdAt') && (
+                        <TableCell className="text-sm text-gray-500">
+                          {item.createdAt ? new Date(item.createdAt).toLocaleDateString() : '-'}
+                        </TableCell>
+                      )}
+                      <TableCell className="text-right">
+                        <div className="flex justify-end gap-2">
+                          <Button size="sm" variant="outline" onClick={() => handleEdit(item)}>
+                            Edit
+                          </Button>
 {%- for field in enum_fields %}
 {%- if field.enum_values | length >= 2 %}
-                        <Button
-                          size="sm"
-                          variant="secondary"
-                          onClick={() => {
-                            if (item.{{ field.name }} === '{{ field.enum_values[0] }}') {
-                              set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}(item.id!)
-                            } else {
-                              set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}(item.id!)
-                            }
-                          }}
-                        >
-                          Toggle {{ field.name | pascal_case }}
-                        </Button>
+                          <Button
+                            size="sm"
+                            variant="secondary"
+                            onClick={() => {
+                              if (item.{{ field.name }} === '{{ field.enum_values[0] }}') {
+                                set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}(item.id!{% if optimistic_locking %}, item.version!{% endif %})
+                              } else {
+                                set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}(item.id!{% if optimistic_locking %}, item.version!{% endif %})
+                              }
+                            }}
+                          >
+                            Toggle {{ field.name | pascal_case }}
+                          </Button>
 {%- endif %}
 {%- endfor %}
-                        <Button
-                          size="sm"
-                          variant="destructive"
-                          onClick={() => handleDelete(item.id!)}
-                          disabled={isDeleting}
-                        >
-                          Delete
-                        </Button>
-                      </div>
+                          <Button
+                            size="sm"
+                            variant="destructive"
+                            onClick={() => handleDelete(item.id!)}
+                            disabled={isDeleting}
+                          >
+                            Delete
+                          </Button>
+                        </div>
+                      </TableCell>
+                    </TableRow>
+                  ))}
+                </TableBody>
+              </Table>
+
+              {/* Pagination */}
+              <div className="flex items-center justify-between pt-4">
+                <div className="flex items-center gap-2 text-sm text-gray-500">
+                  <span>Rows per page</span>
+                  <select
+                    className="border rounded-md p-1"
+                    value={pageSize}
+                    onChange={(e) => {
+                      setPageSize(Number(e.target.value))
+                      setPage(0)
+                    }}
+                  >
+                    {PAGE_SIZES.map((size) => (
+                      <option key={size} value={size}>
+                        {size}
+                      </option>
+                    ))}
+                  </select>
+                </div>
+                <div className="flex items-center gap-2">
+                  <span className="text-sm text-gray-500">Page {page + 1}</span>
+                  <Button
+                    size="sm"
+                    variant="outline"
+                    onClick={() => setPage(page - 1)}
+                    disabled={page === 0}
+                  >
+                    Previous
+                  </Button>
+                  <Button
+                    size="sm"
+                    variant="outline"
+{%- if list_pagination == "cursor" %}
+                    onClick={goToNextPage}
+{%- else %}
+                    onClick={() => setPage(page + 1)}
+{%- endif %}
+                    disabled={!hasNextPage}
+                  >
+                    Next
+                  </Button>
+                </div>
+              </div>
+            </>
+          )}
+        </CardContent>
+      </Card>
+{%- if has_webhooks %}
+
+      {/* Webhook Deliveries */}
+      <Card>
+        <CardHeader className="flex flex-row items-center justify-between">
+          <div>
+            <CardTitle>Recent Webhook Deliveries</CardTitle>
+            <CardDescription>Last 20 change notifications sent by {{ table_name }}-webhooks</CardDescription>
+          </div>
+          <Button variant="outline" size="sm" onClick={() => refetchDeliveries()}>
+            Refresh
+          </Button>
+        </CardHeader>
+        <CardContent>
+          {deliveries.length === 0 ? (
+            <p className="text-center py-8 text-gray-500">No deliveries yet.</p>
+          ) : (
+            <Table>
+              <TableHeader>
+                <TableRow>
+                  <TableHead>Time</TableHead>
+                  <TableHead>Event</TableHead>
+                  <TableHead>Record</TableHead>
+                  <TableHead>URL</TableHead>
+                  <TableHead>Result</TableHead>
+                </TableRow>
+              </TableHeader>
+              <TableBody>
+                {deliveries.map((delivery) => (
+                  <TableRow key={delivery.id}>
+                    <TableCell className="text-sm text-gray-500">
+                      {new Date(delivery.created_at).toLocaleString()}
+                    </TableCell>
+                    <TableCell>
+                      <Badge variant="outline">{delivery.event}</Badge>
+                    </TableCell>
+                    <TableCell className="font-mono text-xs">{delivery.record_id ?? '-'}</TableCell>
+                    <TableCell className="max-w-xs truncate text-sm">{delivery.url}</TableCell>
+                    <TableCell>
+                      <Badge variant={delivery.success ? 'default' : 'destructive'}>
+                        {delivery.status_code ?? 'ERR'}
+                      </Badge>
+                      {delivery.error && (
+                        <span className="ml-2 text-xs text-red-600">{delivery.error}</span>
+                      )}
                     </TableCell>
                   </TableRow>
                 ))}
@@ -340,6 +742,7 @@ export function {{ name }}AdminPage() {
           )}
         </CardContent>
       </Card>
+{%- endif %}
 
       {/* Edit Dialog */}
       <Dialog open={isEditOpen} onOpenChange={setIsEditOpen}>
@@ -350,6 +753,16 @@ export function {{ name }}AdminPage() {
               Update the {{ name | lower }} details.
             </DialogDescription>
           </DialogHeader>
+{%- if optimistic_locking %}
+          {hasConflict && (
+            <div className="rounded-md border border-amber-300 bg-amber-50 p-3 text-sm text-amber-900">
+              <p>This {{ name | lower }} was changed by someone else while you were editing.</p>
+              <Button size="sm" variant="outline" className="mt-2" onClick={handleLoadLatest}>
+                Load latest version (discards your edits)
+              </Button>
+            </div>
+          )}
+{%- endif %}
           <div className="space-y-4 py-4">
 {%- for field in updatable_fields %}
 {%- if field.name == "content" %}
@@ -391,7 +804,7 @@ export function {{ name }}AdminPage() {
             <Button variant="outline" onClick={() => setIsEditOpen(false)}>
               Cancel
             </Button>
-            <Button onClick={handleUpdate} disabled={isUpdating}>
+            <Button onClick={handleUpdate} disabled={isUpdating{% if optimistic_locking %} || hasConflict{% endif %}}>
               {isUpdating ? 'Updating...' : 'Update'}
             </Button>
           </DialogFooter>
@@ -411,5 +824,9 @@ mod tests {
         assert!(ADMIN_PAGE_TEMPLATE.contains("{{ name }}AdminPage"));
         assert!(ADMIN_PAGE_TEMPLATE.contains("use{{ name }}s"));
         assert!(ADMIN_PAGE_TEMPLATE.contains("Generate Dummy Data"));
+        assert!(ADMIN_PAGE_TEMPLATE.contains("offset: page * pageSize"));
+        assert!(ADMIN_PAGE_TEMPLATE.contains("DropdownMenuCheckboxItem"));
+        assert!(ADMIN_PAGE_TEMPLATE.contains("exportCsv"));
+        assert!(ADMIN_PAGE_TEMPLATE.contains("ADMIN_ROLES.includes(role)"));
     }
 }
@@ -0,0 +1,99 @@
+/// Axum Handler Template for the Rust backend (app-backend)
+///
+/// Generates a flat backend module with:
+/// - Request/Response structs
+/// - Handler with input validation
+/// - `router()` for `create_router` registration
+/// - Unit test for the validation logic
+pub const AXUM_HANDLER_TEMPLATE: &str = r#"use axum::{
+    extract::{ {{- extract_imports -}} },
+    http::StatusCode,
+    routing::{{ method }},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::state::AppState;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Deserialize)]
+struct {{ type_name }}Request {
+    input: String,
+}
+
+#[derive(Debug, Serialize)]
+struct {{ type_name }}Response {
+    result: String,
+}
+
+fn validate(request: &{{ type_name }}Request) -> Result<(), StatusCode> {
+    if request.input.trim().is_empty() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    Ok(())
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+async fn {{ handler_name }}(
+    State(_state): State<AppState>,
+{%- if path_params | length == 1 %}
+    Path({{ path_params[0] }}): Path<String>,
+{%- elif path_params %}
+    Path(({{ path_params | join(", ") }})): Path<({% for _ in path_params %}String{% if not loop.last %}, {% endif %}{% endfor %})>,
+{%- endif %}
+    {{ extractor }}(payload): {{ extractor }}<{{ type_name }}Request>,
+) -> Result<Json<{{ type_name }}Response>, StatusCode> {
+    validate(&payload)?;
+{%- if path_params %}
+    tracing::debug!({% for param in path_params %}{{ param }} = %{{ param }}, {% endfor %}"{{ method | upper }} {{ path }}");
+{%- endif %}
+
+    // TODO: implement {{ handler_name }}
+    Ok(Json({{ type_name }}Response {
+        result: payload.input,
+    }))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new().route("{{ path }}", {{ method }}({{ handler_name }}))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let valid = {{ type_name }}Request {
+            input: "hello".to_string(),
+        };
+        assert!(validate(&valid).is_ok());
+
+        let blank = {{ type_name }}Request {
+            input: "  ".to_string(),
+        };
+        assert_eq!(validate(&blank), Err(StatusCode::UNPROCESSABLE_ENTITY));
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_syntax() {
+        assert!(AXUM_HANDLER_TEMPLATE.contains("pub fn router() -> Router<AppState>"));
+        assert!(AXUM_HANDLER_TEMPLATE.contains("{{ type_name }}Request"));
+    }
+}
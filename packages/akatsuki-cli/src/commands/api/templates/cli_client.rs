@@ -4,8 +4,9 @@
 /// - AkatsukiClient wrapper
 /// - CRUD operations
 /// - Custom operations from schema
+/// - Session refresh on 401 and retries with backoff for transient failures
+/// - Async iteration over paginated list results
 /// - Full type safety
-
 pub const CLI_CLIENT_TEMPLATE: &str = r#"/**
  * {{ name }}s API Client (app-cli)
  * Auto-generated by HEADLESS API Generator
@@ -13,6 +14,8 @@ pub const CLI_CLIENT_TEMPLATE: &str = r#"/**
  * Convenience wrapper for {{ table_name }}-crud Edge Function
  * - Supabase Auth integrated
  * - AkatsukiResponse parsing
+ * - 401: refreshes the session once and retries
+ * - Network errors / 429 / 502-504: retried with exponential backoff
  * - Full TypeScript support
  *
  * Usage:
@@ -25,10 +28,29 @@ pub const CLI_CLIENT_TEMPLATE: &str = r#"/**
  *
  * const {{ name | lower }}sClient = new {{ name }}sClient(client)
  * const {{ name | lower }}s = await {{ name | lower }}sClient.list()
+{%- for op in operations %}{% if op.op_type == "list" %}
+ *
+ * // Every page, fetched on demand
+ * for await (const {{ name | lower }} of {{ name | lower }}sClient.iterate()) {
+ *   console.log({{ name | lower }}.id)
+ * }
+{%- endif %}{% endfor %}
  * ```
  */
 
-import { AkatsukiClient } from '../client.js'
+import { AkatsukiClient, AkatsukiError } from '../client.js'
+
+/**
+ * Retry policy for transient failures
+ */
+export interface RetryOptions {
+  /** Retries after the first attempt (default: 3) */
+  maxRetries?: number
+  /** First backoff delay in ms, doubled on every retry with jitter (default: 500) */
+  baseDelayMs?: number
+}
+
+const TRANSIENT_STATUSES = new Set([429, 502, 503, 504])
 
 /**
  * {{ name }} type
@@ -41,6 +63,12 @@ export interface {{ name }} {
   {{ field.db_name }}: {{ field.typescript_type }}{% if not field.required %} | null{% endif %}
 {%- endif %}
 {%- endfor %}
+{%- if optimistic_locking %}
+  version: number
+{%- endif %}
+{%- if soft_delete %}
+  deleted_at: string | null
+{%- endif %}
   created_at: string
   updated_at: string
 }
@@ -69,30 +97,158 @@ export interface {{ name }}UpdateInput {
  * {{ name }}s API Client
  */
 export class {{ name }}sClient {
-  constructor(private client: AkatsukiClient) {}
+  constructor(
+    private client: AkatsukiClient,
+    private retry: RetryOptions = {}
+  ) {}
+
+  /**
+   * Call {{ table_name }}-crud with session refresh and retries
+   */
+  private async invoke<T>(body: Record<string, unknown>): Promise<T> {
+    const maxRetries = this.retry.maxRetries ?? 3
+    const baseDelayMs = this.retry.baseDelayMs ?? 500
+    let retries = 0
+    let refreshed = false
+
+    for (;;) {
+      try {
+        return await this.client.invoke<T>('{{ table_name }}-crud', body)
+      } catch (err) {
+        if (!(err instanceof AkatsukiError)) throw err
+
+        // Expired access token: refresh once, then replay the request
+        if (err.status === 401 && !refreshed) {
+          refreshed = true
+          await this.client.refreshSession()
+          continue
+        }
+
+        const transient =
+          err.code === 'NETWORK_ERROR' || (err.status !== undefined && TRANSIENT_STATUSES.has(err.status))
+        if (!transient || retries >= maxRetries) throw err
+
+        const delayMs = baseDelayMs * 2 ** retries * (0.5 + Math.random() / 2)
+        retries++
+        await new Promise((resolve) => setTimeout(resolve, delayMs))
+      }
+    }
+  }
 {%- for op in operations %}
-{%- if op.op_type == "list" %}
+{%- if op.op_type == "list" and op.pagination == "cursor" %}
+
+  /**
+   * One page of {{ name | lower }}s (pass nextCursor as `cursor` for the next one)
+   */
+  async list(
+    filters: { {% for filter in op.filters %}{{ filter }}?: string{% if not loop.last or soft_delete %}, {% endif %}{% endfor %}{% if soft_delete %}includeDeleted?: boolean{% endif %} } = {},
+    limit: number = 20,
+    cursor?: string
+  ): Promise<{ items: {{ name }}[]; nextCursor: string | null }> {
+    return this.invoke<{ items: {{ name }}[]; nextCursor: string | null }>({
+      action: 'list',
+      filters,
+      limit,
+      cursor,
+{%- if soft_delete %}
+      includeDeleted: filters.includeDeleted,
+{%- endif %}
+    })
+  }
+
+  /**
+   * Iterate over every {{ name | lower }} matching filters, one page per request
+   */
+  async *iterate(
+    filters: { {% for filter in op.filters %}{{ filter }}?: string{% if not loop.last or soft_delete %}, {% endif %}{% endfor %}{% if soft_delete %}includeDeleted?: boolean{% endif %} } = {},
+    pageSize: number = {{ op.limit | default(value=20) }}
+  ): AsyncGenerator<{{ name }}> {
+    let cursor: string | undefined
+    do {
+      const page = await this.list(filters, pageSize, cursor)
+      yield* page.items
+      cursor = page.nextCursor ?? undefined
+    } while (cursor)
+  }
+{%- elif op.op_type == "list" and op.pagination == "offset" %}
+
+  /**
+   * One page of {{ name | lower }}s with the total count
+   */
+  async list(
+    filters: { {% for filter in op.filters %}{{ filter }}?: string{% if not loop.last or soft_delete %}, {% endif %}{% endfor %}{% if soft_delete %}includeDeleted?: boolean{% endif %} } = {},
+    limit: number = 20,
+    offset: number = 0
+  ): Promise<{ items: {{ name }}[]; total: number; nextOffset: number | null }> {
+    return this.invoke<{ items: {{ name }}[]; total: number; nextOffset: number | null }>({
+      action: 'list',
+      filters,
+      limit,
+      offset,
+{%- if soft_delete %}
+      includeDeleted: filters.includeDeleted,
+{%- endif %}
+    })
+  }
+
+  /**
+   * Iterate over every {{ name | lower }} matching filters, one page per request
+   */
+  async *iterate(
+    filters: { {% for filter in op.filters %}{{ filter }}?: string{% if not loop.last or soft_delete %}, {% endif %}{% endfor %}{% if soft_delete %}includeDeleted?: boolean{% endif %} } = {},
+    pageSize: number = {{ op.limit | default(value=20) }}
+  ): AsyncGenerator<{{ name }}> {
+    let offset: number | null = 0
+    while (offset !== null) {
+      const page = await this.list(filters, pageSize, offset)
+      yield* page.items
+      offset = page.nextOffset
+    }
+  }
+{%- elif op.op_type == "list" %}
 
   /**
    * Get {{ name | lower }}s with filters
    */
   async list(
-    filters: { {% for filter in op.filters %}{{ filter }}?: string{% if not loop.last %}, {% endif %}{% endfor %} } = {},
-    limit: number = 20
+    filters: { {% for filter in op.filters %}{{ filter }}?: string{% if not loop.last or soft_delete %}, {% endif %}{% endfor %}{% if soft_delete %}includeDeleted?: boolean{% endif %} } = {},
+    limit: number = 20,
+    offset: number = 0
   ): Promise<{{ name }}[]> {
-    return this.client.invoke<{{ name }}[]>('{{ table_name }}-crud', {
+    return this.invoke<{{ name }}[]>({
       action: 'list',
       filters,
       limit,
+      offset,
+{%- if soft_delete %}
+      includeDeleted: filters.includeDeleted,
+{%- endif %}
     })
   }
+
+  /**
+   * Iterate over every {{ name | lower }} matching filters, one page per request
+   * (stops at the first empty page, so the server's page size cap never ends it early)
+   */
+  async *iterate(
+    filters: { {% for filter in op.filters %}{{ filter }}?: string{% if not loop.last or soft_delete %}, {% endif %}{% endfor %}{% if soft_delete %}includeDeleted?: boolean{% endif %} } = {},
+    pageSize: number = {{ op.limit | default(value=20) }}
+  ): AsyncGenerator<{{ name }}> {
+    let offset = 0
+    for (;;) {
+      const page = await this.list(filters, pageSize, offset)
+      if (page.length === 0) return
+      yield* page
+      offset += page.length
+    }
+  }
 {%- elif op.op_type == "get" %}
 
   /**
    * Get {{ name | lower }} by ID
    */
   async getById(id: string): Promise<{{ name }}> {
-    return this.client.invoke<{{ name }}>('{{ table_name }}-crud', {
+    return this.invoke<{{ name }}>({
       action: 'get',
       id,
     })
@@ -103,7 +259,7 @@ export class {{ name }}sClient {
    * Create {{ name | lower }}
    */
   async create(data: {{ name }}CreateInput): Promise<{{ name }}> {
-    return this.client.invoke<{{ name }}>('{{ table_name }}-crud', {
+    return this.invoke<{{ name }}>({
       action: 'create',
       data,
     })
@@ -113,31 +269,54 @@ export class {{ name }}sClient {
   /**
    * Update {{ name | lower }}
    */
+{%- if optimistic_locking %}
+  async update(id: string, data: {{ name }}UpdateInput, version: number): Promise<{{ name }}> {
+    return this.invoke<{{ name }}>({
+      action: 'update',
+      id,
+      version,
+      data,
+    })
+  }
+{%- else %}
   async update(id: string, data: {{ name }}UpdateInput): Promise<{{ name }}> {
-    return this.client.invoke<{{ name }}>('{{ table_name }}-crud', {
+    return this.invoke<{{ name }}>({
       action: 'update',
       id,
       data,
     })
   }
+{%- endif %}
 {%- elif op.op_type == "delete" %}
 
   /**
    * Delete {{ name | lower }}
    */
   async delete(id: string): Promise<{ deleted: boolean }> {
-    return this.client.invoke<{ deleted: boolean }>('{{ table_name }}-crud', {
+    return this.invoke<{ deleted: boolean }>({
       action: 'delete',
       id,
     })
   }
+{%- if soft_delete %}
+
+  /**
+   * Restore soft-deleted {{ name | lower }}
+   */
+  async restore(id: string): Promise<{{ name }}> {
+    return this.invoke<{{ name }}>({
+      action: 'restore',
+      id,
+    })
+  }
+{%- endif %}
 {%- elif op.op_type == "custom" %}
 
   /**
    * {{ op.description | default(value="Custom operation: " ~ op.name) }}
    */
   async {{ op.name }}({% if op.filters | length > 0 %}filters: { {% for filter in op.filters %}{{ filter }}?: string{% if not loop.last %}, {% endif %}{% endfor %} } = {}{% endif %}{% if op.limit %}{% if op.filters | length > 0 %}, {% endif %}limit: number = {{ op.limit }}{% endif %}): Promise<{{ name }}[]> {
-    return this.client.invoke<{{ name }}[]>('{{ table_name }}-crud', {
+    return this.invoke<{{ name }}[]>({
       action: '{{ op.name }}',
 {%- if op.filters | length > 0 %}
       filters,
@@ -155,15 +334,15 @@ export class {{ name }}sClient {
   /**
    * Set {{ name | lower }} {{ field.name }} to '{{ field.enum_values[1] }}'
    */
-  async {{ field.enum_values[1] }}(id: string): Promise<{{ name }}> {
-    return this.update(id, { {{ field.name }}: '{{ field.enum_values[1] }}' })
+  async {{ field.enum_values[1] }}(id: string{% if optimistic_locking %}, version: number{% endif %}): Promise<{{ name }}> {
+    return this.update(id, { {{ field.name }}: '{{ field.enum_values[1] }}' }{% if optimistic_locking %}, version{% endif %})
   }
 
   /**
    * Set {{ name | lower }} {{ field.name }} to '{{ field.enum_values[0] }}'
    */
-  async un{{ field.enum_values[1] }}(id: string): Promise<{{ name }}> {
-    return this.update(id, { {{ field.name }}: '{{ field.enum_values[0] }}' })
+  async un{{ field.enum_values[1] }}(id: string{% if optimistic_locking %}, version: number{% endif %}): Promise<{{ name }}> {
+    return this.update(id, { {{ field.name }}: '{{ field.enum_values[0] }}' }{% if optimistic_locking %}, version{% endif %})
   }
 {%- endif %}
 {%- endfor %}
@@ -179,5 +358,7 @@ mod tests {
         assert!(CLI_CLIENT_TEMPLATE.contains("{{ name }}sClient"));
         assert!(CLI_CLIENT_TEMPLATE.contains("AkatsukiClient"));
         assert!(CLI_CLIENT_TEMPLATE.contains("interface {{ name }}"));
+        assert!(CLI_CLIENT_TEMPLATE.contains("async *iterate("));
+        assert!(CLI_CLIENT_TEMPLATE.contains("await this.client.refreshSession()"));
     }
 }
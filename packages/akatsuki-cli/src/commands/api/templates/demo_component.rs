@@ -4,7 +4,6 @@
 /// - CRUD operations
 /// - Code example
 /// - Feature list
-
 pub const DEMO_COMPONENT_TEMPLATE: &str = r##"/**
  * {{ name }}s Demo Card
  * Auto-generated by HEADLESS API Generator
@@ -30,6 +29,9 @@ export function {{ name }}sDemo() {
 {%- endif %}
 {%- endfor %}
   const [editingId, setEditingId] = useState<string | null>(null)
+{%- if optimistic_locking %}
+  const [editingVersion, setEditingVersion] = useState<number | null>(null)
+{%- endif %}
 
   const {
     {{ name | lower }}s,
@@ -55,6 +57,9 @@ export function {{ name }}sDemo() {
 {%- endif %}
 {%- endfor %}
     setEditingId(null)
+{%- if optimistic_locking %}
+    setEditingVersion(null)
+{%- endif %}
   }
 
   const handleCreate = () => {
@@ -70,6 +75,9 @@ export function {{ name }}sDemo() {
 
   const handleEdit = (item: {{ name }}) => {
     setEditingId(item.id)
+{%- if optimistic_locking %}
+    setEditingVersion(item.version)
+{%- endif %}
 {%- for field in writable_fields %}
 {%- if field.name != "userId" %}
     set{{ field.name | pascal_case }}(item.{{ field.name }}{% if not field.required %} ?? {{ field.typescript_default }}{% endif %})
@@ -78,12 +86,12 @@ export function {{ name }}sDemo() {
   }
 
   const handleUpdate = () => {
-    if (!editingId) return
+    if (!editingId{% if optimistic_locking %} || editingVersion === null{% endif %}) return
     update{{ name }}(editingId, {
 {%- for field in updatable_fields %}
       {{ field.name }},
 {%- endfor %}
-    })
+    }{% if optimistic_locking %}, editingVersion{% endif %})
     resetForm()
   }
 
@@ -218,9 +226,9 @@ create{{ name }}({ {% for field in writable_fields %}{% if field.name != "userId
                     variant="secondary"
                     onClick={() => {
                       if (item.{{ field.name }} === '{{ field.enum_values[0] }}') {
-                        set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}(item.id!)
+                        set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}(item.id!{% if optimistic_locking %}, item.version!{% endif %})
                       } else {
-                        set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}(item.id!)
+                        set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}(item.id!{% if optimistic_locking %}, item.version!{% endif %})
                       }
                     }}
                   >
@@ -2,7 +2,6 @@
  * Edge Function Template (minijinja)
  * Generates TypeScript Edge Function handler
  */
-
 pub const EDGE_FUNCTION_TEMPLATE: &str = r#"/**
  * {{ name }} CRUD Edge Function
  *
@@ -11,112 +10,318 @@ pub const EDGE_FUNCTION_TEMPLATE: &str = r#"/**
  * - Zod Validation
  * - Repository DI
  * - RLS対応
+ * - 統一エラーエンベロープ（Zod 422 + field issues / PostgREST エラー分類 / 500 + request id）
+ * - 構造化ログ（1 リクエスト 1 行の JSON、X-Request-Id で相関）
  *
  * Supported Actions:
 {%- for op in operations %}
- * - {{ op.op_type }}{% if op.name %} ({{ op.name }}){% endif %}{% if op.description %}: {{ op.description }}{% endif %}
+ * - {{ op.op_type }}{% if op.name %} ({{ op.name }}){% endif %}{% if op.pagination %} ({{ op.pagination }} pagination){% endif %}{% if op.op_type == "delete" and soft_delete %} (soft delete){% endif %}{% if op.description %}: {{ op.description }}{% endif %}
+{%- if op.op_type == "delete" and soft_delete %}
+ * - restore: undo a soft delete
+{%- endif %}
+{%- endfor %}
+{%- for relation in relations %}
+ * - list{{ relation.pascal_name }}{% if relation.join_table %} / link{{ relation.pascal_name }} / unlink{{ relation.pascal_name }}{% endif %}: {{ relation.target }} rows ({{ relation.kind }})
 {%- endfor %}
  */
 
+import { z } from 'https://deno.land/x/zod@v3.23.8/mod.ts'
 import { createAkatsukiHandler } from '../_shared/handler.ts'
 import { {{ name }}Repository } from '../_shared/repositories/{{ name }}Repository.ts'
 import { {{ name }}CrudSchema, type {{ name }}CrudInput } from './schema.ts'
 
+const FUNCTION_NAME = '{{ table_name }}-crud'
+
 Deno.serve(async (req) => {
-  return createAkatsukiHandler<{{ name }}CrudInput, any>(req, {
-    inputSchema: {{ name }}CrudSchema,
-    logic: async ({ input, userClient, adminClient, repos }) => {
+  const requestId = req.headers.get('x-request-id') ?? crypto.randomUUID()
+  const startedAt = performance.now()
+  let action: string | undefined
+
+  const response = await createAkatsukiHandler<unknown, any>(req, {
+    // Parsed inside logic so validation failures become 422 with field issues
+    inputSchema: z.unknown(),
+    logic: async ({ input: rawInput, userClient }) => {
       // Create Repository with userClient (RLS enabled)
       const {{ table_name|singular }}Repo = new {{ name }}Repository(userClient)
 
-      switch (input.action) {
+      try {
+        const input: {{ name }}CrudInput = {{ name }}CrudSchema.parse(rawInput)
+        action = input.action
+
+        switch (input.action) {
 {%- for op in operations %}
   {%- if op.op_type == "list" %}
-        case 'list': {
-          // Get {{ table_name }} with filters
-          return {{ table_name|singular }}Repo.findAll({
-            {%- for filter in op.filters %}
-            {{ filter }}: input.filters?.{{ filter }},
-            {%- endfor %}
-            limit: input.limit || 20,
-          })
-        }
-  {%- elif op.op_type == "get" %}
-        case 'get': {
-          // Get {{ table_name|singular }} by ID
-          const {{ table_name|singular }} = await {{ table_name|singular }}Repo.findById(input.id)
-          if (!{{ table_name|singular }}) {
-            throw Object.assign(new Error('{{ name }} not found'), {
-              status: 404,
-              code: '{{ name|snake_case|upper }}_NOT_FOUND',
+          case 'list': {
+    {%- if op.pagination == "cursor" %}
+            // One page of {{ table_name }} after input.cursor: { items, nextCursor }
+            return {{ table_name|singular }}Repo.findPageAfter({
+              {%- for filter in op.filters %}
+              {{ filter }}: input.filters?.{{ filter }},
+              {%- endfor %}
+              limit: input.limit || 20,
+              cursor: input.cursor,
+              ascending: input.ascending,
+              {%- if soft_delete %}
+              includeDeleted: input.includeDeleted,
+              {%- endif %}
             })
+    {%- elif op.pagination == "offset" %}
+            // One page of {{ table_name }}: { items, total, nextOffset }
+            return {{ table_name|singular }}Repo.findPage({
+              {%- for filter in op.filters %}
+              {{ filter }}: input.filters?.{{ filter }},
+              {%- endfor %}
+              limit: input.limit || 20,
+              offset: input.offset,
+              orderBy: input.orderBy,
+              ascending: input.ascending,
+              {%- if soft_delete %}
+              includeDeleted: input.includeDeleted,
+              {%- endif %}
+            })
+    {%- else %}
+            // Get {{ table_name }} with filters
+            return {{ table_name|singular }}Repo.findAll({
+              {%- for filter in op.filters %}
+              {{ filter }}: input.filters?.{{ filter }},
+              {%- endfor %}
+              limit: input.limit || 20,
+              offset: input.offset,
+              orderBy: input.orderBy,
+              ascending: input.ascending,
+              {%- if soft_delete %}
+              includeDeleted: input.includeDeleted,
+              {%- endif %}
+            })
+    {%- endif %}
+          }
+  {%- elif op.op_type == "get" %}
+          case 'get': {
+            // Get {{ table_name|singular }} by ID
+            const {{ table_name|singular }} = await {{ table_name|singular }}Repo.findById(input.id)
+            if (!{{ table_name|singular }}) {
+              throw Object.assign(new Error('{{ name }} not found'), {
+                status: 404,
+                code: '{{ name|snake_case|upper }}_NOT_FOUND',
+              })
+            }
+            return {{ table_name|singular }}
           }
-          return {{ table_name|singular }}
-        }
   {%- elif op.op_type == "create" %}
-        case 'create': {
-          // Create new {{ table_name|singular }}
-          const user = await {{ table_name|singular }}Repo.getCurrentUser()
-          return {{ table_name|singular }}Repo.create({
-            user_id: user.id,
-            {%- for field in writable_fields %}
-            {%- if field.name != "userId" %}
-            {{ field.db_name }}: input.data.{{ field.name }}{% if not field.required %} || {{ field.typescript_default }}{% endif %},
-            {%- endif %}
-            {%- endfor %}
-          })
-        }
-  {%- elif op.op_type == "update" %}
-        case 'update': {
-          // Update {{ table_name|singular }}
-          const {{ table_name|singular }} = await {{ table_name|singular }}Repo.findById(input.id)
-          if (!{{ table_name|singular }}) {
-            throw Object.assign(new Error('{{ name }} not found'), {
-              status: 404,
-              code: '{{ name|snake_case|upper }}_NOT_FOUND',
+          case 'create': {
+            // Create new {{ table_name|singular }}
+            const user = await {{ table_name|singular }}Repo.getCurrentUser()
+            return {{ table_name|singular }}Repo.create({
+              user_id: user.id,
+              {%- for field in writable_fields %}
+              {%- if field.name != "userId" %}
+              {{ field.db_name }}: input.data.{{ field.name }}{% if not field.required %} || {{ field.typescript_default }}{% endif %},
+              {%- endif %}
+              {%- endfor %}
             })
           }
-
-          return {{ table_name|singular }}Repo.update(input.id, input.data)
-        }
-  {%- elif op.op_type == "delete" %}
-        case 'delete': {
-          // Delete {{ table_name|singular }}
-          const {{ table_name|singular }} = await {{ table_name|singular }}Repo.findById(input.id)
-          if (!{{ table_name|singular }}) {
-            throw Object.assign(new Error('{{ name }} not found'), {
-              status: 404,
-              code: '{{ name|snake_case|upper }}_NOT_FOUND',
-            })
+  {%- elif op.op_type == "update" %}
+          case 'update': {
+            // Update {{ table_name|singular }}
+            const {{ table_name|singular }} = await {{ table_name|singular }}Repo.findById(input.id)
+            if (!{{ table_name|singular }}) {
+              throw Object.assign(new Error('{{ name }} not found'), {
+                status: 404,
+                code: '{{ name|snake_case|upper }}_NOT_FOUND',
+              })
+            }
+{% if optimistic_locking %}
+            // Compare-and-swap on version: reject stale writes with 409
+            const updated =
+              {{ table_name|singular }}.version === input.version
+                ? await {{ table_name|singular }}Repo.update(input.id, input.data, input.version)
+                : null
+            if (!updated) {
+              throw Object.assign(new Error('{{ name }} was modified by someone else. Reload and try again.'), {
+                status: 409,
+                code: '{{ name|snake_case|upper }}_VERSION_CONFLICT',
+              })
+            }
+            return updated
+{%- else %}
+            return {{ table_name|singular }}Repo.update(input.id, input.data)
+{%- endif %}
           }
+  {%- elif op.op_type == "delete" %}
+          case 'delete': {
+            // Delete {{ table_name|singular }}
+            const {{ table_name|singular }} = await {{ table_name|singular }}Repo.findById(input.id)
+            if (!{{ table_name|singular }}) {
+              throw Object.assign(new Error('{{ name }} not found'), {
+                status: 404,
+                code: '{{ name|snake_case|upper }}_NOT_FOUND',
+              })
+            }
 
-          await {{ table_name|singular }}Repo.delete(input.id)
-          return { success: true, message: '{{ name }} deleted' }
-        }
-  {%- elif op.op_type == "custom" %}
-        case '{{ op.name }}': {
-          // {{ op.description|default(value=op.name ~ " operation") }}
-          return {{ table_name|singular }}Repo.{{ op.name }}(
-            {%- if op.filters|length > 0 %}{
+            await {{ table_name|singular }}Repo.delete(input.id)
+            return { success: true, message: '{{ name }} deleted' }
+          }
+    {%- if soft_delete %}
+          case 'restore': {
+            // Undo a soft delete
+            const restored = await {{ table_name|singular }}Repo.restore(input.id)
+            if (!restored) {
+              throw Object.assign(new Error('Deleted {{ name }} not found'), {
+                status: 404,
+                code: '{{ name|snake_case|upper }}_NOT_FOUND',
+              })
+            }
+            return restored
+          }
+    {%- endif %}
+  {%- elif op.op_type == "export" %}
+          case 'export': {
+            // Export {{ table_name }} visible to the caller as CSV
+            const csv = await {{ table_name|singular }}Repo.exportCsv({
               {%- for filter in op.filters %}
               {{ filter }}: input.filters?.{{ filter }},
               {%- endfor %}
-            }{% endif %}
-          )
-        }
+              orderBy: input.orderBy,
+              ascending: input.ascending,
+            })
+            return { filename: '{{ table_name }}.csv', csv }
+          }
+  {%- elif op.op_type == "custom" %}
+          case '{{ op.name }}': {
+            // {{ op.description|default(value=op.name ~ " operation") }}
+            return {{ table_name|singular }}Repo.{{ op.name }}(
+              {%- if op.filters|length > 0 %}{
+                {%- for filter in op.filters %}
+                {{ filter }}: input.filters?.{{ filter }},
+                {%- endfor %}
+              }{% endif %}
+            )
+          }
+  {%- endif %}
+{%- endfor %}
+{%- for relation in relations %}
+          case 'list{{ relation.pascal_name }}': {
+            // {{ relation.target }} rows of the {{ table_name|singular }} ({{ relation.kind }})
+            return {{ table_name|singular }}Repo.list{{ relation.pascal_name }}(input.id, {
+              limit: input.limit,
+              offset: input.offset,
+            })
+          }
+  {%- if relation.join_table %}
+          case 'link{{ relation.pascal_name }}': {
+            await {{ table_name|singular }}Repo.link{{ relation.pascal_name }}(input.id, input.targetId)
+            return { success: true }
+          }
+          case 'unlink{{ relation.pascal_name }}': {
+            await {{ table_name|singular }}Repo.unlink{{ relation.pascal_name }}(input.id, input.targetId)
+            return { success: true }
+          }
   {%- endif %}
 {%- endfor %}
 
-        default:
-          throw Object.assign(new Error('Invalid action'), {
-            status: 400,
-            code: 'INVALID_ACTION',
-          })
+          default:
+            throw Object.assign(new Error('Invalid action'), {
+              status: 400,
+              code: 'INVALID_ACTION',
+            })
+        }
+      } catch (error) {
+        throw toHttpError(error, requestId)
       }
     },
   })
+
+  response.headers.set('X-Request-Id', requestId)
+  log(response.status >= 500 ? 'error' : response.status >= 400 ? 'warn' : 'info', {
+    requestId,
+    event: 'request',
+    method: req.method,
+    action,
+    status: response.status,
+    durationMs: Math.round(performance.now() - startedAt),
+  })
+  return response
 })
 
+/** One JSON object per line so the Supabase log explorer can filter on fields */
+function log(level: 'info' | 'warn' | 'error', fields: Record<string, unknown>) {
+  const line = JSON.stringify({ level, fn: FUNCTION_NAME, ...fields })
+  if (level === 'error') console.error(line)
+  else if (level === 'warn') console.warn(line)
+  else console.log(line)
+}
+
+/** Postgres / PostgREST error codes -> HTTP status + error code */
+const POSTGREST_ERRORS: Record<string, { status: number; code: string }> = {
+  '23505': { status: 409, code: 'CONFLICT' }, // unique_violation
+  '23503': { status: 409, code: 'FOREIGN_KEY_VIOLATION' },
+  '23502': { status: 422, code: 'VALIDATION_FAILED' }, // not_null_violation
+  '23514': { status: 422, code: 'VALIDATION_FAILED' }, // check_violation
+  '22P02': { status: 422, code: 'VALIDATION_FAILED' }, // invalid_text_representation
+  '42501': { status: 403, code: 'FORBIDDEN' }, // RLS / insufficient_privilege
+  PGRST116: { status: 404, code: 'NOT_FOUND' }, // .single() matched no rows
+  PGRST301: { status: 401, code: 'UNAUTHORIZED' }, // JWT expired or invalid
+}
+
+/**
+ * Map anything thrown by the logic onto the error envelope
+ * (createAkatsukiHandler forwards status / code / message / details)
+ */
+function toHttpError(error: any, requestId: string) {
+  if (error instanceof z.ZodError) {
+    return Object.assign(new Error('Validation failed'), {
+      status: 422,
+      code: 'VALIDATION_FAILED',
+      details: {
+        requestId,
+        issues: error.issues.map((issue) => ({
+          path: issue.path.join('.'),
+          message: issue.message,
+          code: issue.code,
+        })),
+      },
+    })
+  }
+
+  // Errors thrown above with an explicit status (404, 409, ...) pass through
+  if (error?.status) {
+    return Object.assign(error, { details: { requestId, ...error.details } })
+  }
+
+  // Repositories attach the PostgrestError as `cause`
+  const pgError = error?.cause ?? error
+  if (typeof pgError?.code === 'string' && 'hint' in pgError) {
+    const mapped = POSTGREST_ERRORS[pgError.code]
+    log(mapped ? 'warn' : 'error', {
+      requestId,
+      event: 'postgrest_error',
+      pgCode: pgError.code,
+      message: pgError.message,
+      details: pgError.details,
+      hint: pgError.hint,
+    })
+    if (mapped) {
+      return Object.assign(new Error(pgError.message), {
+        ...mapped,
+        details: { requestId, pgCode: pgError.code },
+      })
+    }
+    return Object.assign(new Error(`Database error (request id: ${requestId})`), {
+      status: 500,
+      code: 'DATABASE_ERROR',
+      details: { requestId, pgCode: pgError.code },
+    })
+  }
+
+  // Unexpected: keep the stack in the logs, return only the correlation id
+  log('error', { requestId, event: 'unhandled_error', message: error?.message, stack: error?.stack })
+  return Object.assign(new Error(`Internal server error (request id: ${requestId})`), {
+    status: 500,
+    code: 'INTERNAL_ERROR',
+    details: { requestId },
+  })
+}
+
 /* To invoke locally:
 
   1. Run `supabase start`
@@ -124,11 +329,15 @@ Deno.serve(async (req) => {
 
 {%- for op in operations %}
   {%- if op.op_type == "list" %}
-  # List {{ table_name }}
+  # List {{ table_name }}{% if op.pagination == "cursor" %} (pass the returned nextCursor as "cursor" for the next page){% endif %}
   curl -i --location --request POST 'http://127.0.0.1:54321/functions/v1/{{ table_name }}-crud' \
     --header 'Authorization: Bearer YOUR_JWT_TOKEN' \
     --header 'Content-Type: application/json' \
-    --data '{"action":"list","limit":10}'
+    {%- if op.pagination == "cursor" %}
+    --data '{"action":"list","limit":10,"ascending":false}'
+    {%- else %}
+    --data '{"action":"list","limit":10,"offset":0,"orderBy":"created_at","ascending":false}'
+    {%- endif %}
   {%- elif op.op_type == "create" %}
 
   # Create {{ table_name|singular }}
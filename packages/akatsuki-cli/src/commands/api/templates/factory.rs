@@ -0,0 +1,86 @@
+/// Test Data Factory Template for Frontend
+///
+/// Generates faker-based builders returning `<Entity>Data`:
+/// - build<Entity>(overrides) - One record (enum values and validation rules respected)
+/// - build<Entity>List(count, overrides) - Several records
+/// - build<Entity>WithRelations(overrides) - Record plus the records it references
+///   (only for references to entities generated in the same run)
+pub const FACTORY_TEMPLATE: &str = r#"/**
+ * {{ name }} Test Data Factory
+ * Auto-generated by HEADLESS API Generator (akatsuki api factories)
+ *
+ * Usage (tests / Storybook stories):
+ *   build{{ name }}({ ...overrides })
+ *   build{{ name }}List(5)
+{%- if relations %}
+ *   build{{ name }}WithRelations()
+{%- endif %}
+ */
+import { faker } from '@faker-js/faker'
+import type { {{ name }}Data } from '../../models/{{ name }}'
+{%- if uses_fit_length %}
+import { fitLength } from './helpers'
+{%- endif %}
+{%- for entity in related_entities %}
+import { build{{ entity }} } from './{{ entity }}Factory'
+{%- endfor %}
+
+export function build{{ name }}(overrides: Partial<{{ name }}Data> = {}): {{ name }}Data {
+  return {
+    id: faker.string.uuid(),
+{%- for field in fields %}
+    {{ field.name }}: {{ field.expression }},
+{%- endfor %}
+{%- if optimistic_locking %}
+    version: 1,
+{%- endif %}
+    createdAt: faker.date.past().toISOString(),
+    updatedAt: faker.date.recent().toISOString(),
+    ...overrides,
+  }
+}
+
+export function build{{ name }}List(count: number, overrides: Partial<{{ name }}Data> = {}): {{ name }}Data[] {
+  return Array.from({ length: count }, () => build{{ name }}(overrides))
+}
+{%- if relations %}
+
+/** {{ name }} together with the records its foreign keys point at */
+export function build{{ name }}WithRelations(overrides: Partial<{{ name }}Data> = {}) {
+{%- for rel in relations %}
+  const {{ rel.alias }} = build{{ rel.entity }}()
+{%- endfor %}
+  const {{ var_name }} = build{{ name }}({
+{%- for rel in relations %}
+    {{ rel.field }}: {{ rel.alias }}.id ?? undefined,
+{%- endfor %}
+    ...overrides,
+  })
+  return { {{ var_name }}{% for rel in relations %}, {{ rel.alias }}{% endfor %} }
+}
+{%- endif %}
+
+"#;
+
+/// Shared helpers for the generated factories (`src/test/factories/helpers.ts`)
+pub const FACTORY_HELPERS_TEMPLATE: &str = r#"/**
+ * Test Data Factory Helpers
+ * Auto-generated by HEADLESS API Generator (akatsuki api factories)
+ */
+import { faker } from '@faker-js/faker'
+
+/** Make factory output reproducible (call in beforeEach or at the top of a story) */
+export function seedFactories(seed = 42): void {
+  faker.seed(seed)
+}
+
+/** Pad with lorem words / truncate so a value satisfies minLength / maxLength */
+export function fitLength(value: string, min = 0, max?: number): string {
+  let result = value
+  while (result.length < min) {
+    result = `${result} ${faker.lorem.word()}`.trim()
+  }
+  return max === undefined ? result : result.slice(0, max)
+}
+
+"#;
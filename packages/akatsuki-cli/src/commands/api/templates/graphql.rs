@@ -0,0 +1,456 @@
+/**
+ * GraphQL Templates (minijinja)
+ * Generates a GraphQL Yoga Edge Function and typed React Query hooks
+ * (`akatsuki api new --with-graphql` / `graphql: true` in the schema)
+ */
+pub const GRAPHQL_FUNCTION_TEMPLATE: &str = r#"/**
+ * {{ name }} GraphQL Edge Function
+ *
+ * Auto-generated by HEADLESS API Generator (--with-graphql)
+ * - GraphQL Yoga: POST /functions/v1/{{ table_name }}-graphql (GET opens GraphiQL)
+ * - Resolvers reuse {{ name }}Repository with the caller's JWT (RLS enabled)
+ * - Fields are camelCase; columns are mapped with FIELD_COLUMNS
+ */
+
+import { createClient, SupabaseClient } from 'https://esm.sh/@supabase/supabase-js@2'
+import { createGraphQLError, createSchema, createYoga } from 'npm:graphql-yoga@5'
+import {
+  {{ name }}Repository,
+  type {{ name }}Insert,
+  type {{ name }}Update,
+} from '../_shared/repositories/{{ name }}Repository.ts'
+
+export const typeDefs = /* GraphQL */ `
+  scalar JSON
+{%- for enum in enums %}
+
+  enum {{ enum.name }} {
+{%- for value in enum.values %}
+    {{ value }}
+{%- endfor %}
+  }
+{%- endfor %}
+
+  type {{ name }} {
+    id: ID!
+    userId: ID!
+{%- for field in fields %}
+    {{ field.name }}: {{ field.graphql_type }}{% if field.required %}!{% endif %}
+{%- endfor %}
+{%- if optimistic_locking %}
+    version: Int!
+{%- endif %}
+    createdAt: String!
+    updatedAt: String!
+  }
+{%- if filters %}
+
+  input {{ name }}Filter {
+{%- for filter in filters %}
+    {{ filter }}: String
+{%- endfor %}
+  }
+{%- endif %}
+{%- if create_fields %}
+
+  input Create{{ name }}Input {
+{%- for field in create_fields %}
+    {{ field.name }}: {{ field.graphql_type }}{% if field.required %}!{% endif %}
+{%- endfor %}
+  }
+{%- endif %}
+{%- if fields %}
+
+  input Update{{ name }}Input {
+{%- for field in fields %}
+    {{ field.name }}: {{ field.graphql_type }}
+{%- endfor %}
+  }
+{%- endif %}
+
+  type Query {
+{%- for op in operations %}
+{%- if op.op_type == "list" %}
+    {{ var_name }}s({% if filters %}filter: {{ name }}Filter, {% endif %}limit: Int, offset: Int, orderBy: String, ascending: Boolean): [{{ name }}!]!
+{%- elif op.op_type == "get" %}
+    {{ var_name }}(id: ID!): {{ name }}
+{%- elif op.op_type == "custom" %}
+    {{ op.name }}{{ name }}s{% if op.filters %}(filter: {{ name }}Filter){% endif %}: [{{ name }}!]!
+{%- endif %}
+{%- endfor %}
+{%- if not has_queries %}
+    _empty: Boolean
+{%- endif %}
+  }
+{%- if has_mutations %}
+
+  type Mutation {
+{%- for op in operations %}
+{%- if op.op_type == "create" %}
+    create{{ name }}{% if create_fields %}(input: Create{{ name }}Input!){% endif %}: {{ name }}!
+{%- elif op.op_type == "update" %}
+    update{{ name }}(id: ID!{% if fields %}, input: Update{{ name }}Input!{% endif %}{% if optimistic_locking %}, version: Int!{% endif %}): {{ name }}!
+{%- elif op.op_type == "delete" %}
+    delete{{ name }}(id: ID!): Boolean!
+{%- endif %}
+{%- endfor %}
+  }
+{%- endif %}
+`
+
+/** GraphQL field → database column */
+const FIELD_COLUMNS: Record<string, string> = {
+  id: 'id',
+  userId: 'user_id',
+{%- for field in fields %}
+  {{ field.name }}: '{{ field.db_name }}',
+{%- endfor %}
+{%- if optimistic_locking %}
+  version: 'version',
+{%- endif %}
+  createdAt: 'created_at',
+  updatedAt: 'updated_at',
+}
+
+/** Database row (snake_case) → GraphQL object (camelCase) */
+function toGraphQL(row: object) {
+  const record = row as Record<string, unknown>
+  return Object.fromEntries(
+    Object.entries(FIELD_COLUMNS).map(([field, column]) => [field, record[column]])
+  )
+}
+
+/** GraphQL input (camelCase) → database columns */
+function toColumns(input: Record<string, unknown> = {}) {
+  return Object.fromEntries(
+    Object.entries(input).map(([field, value]) => [FIELD_COLUMNS[field] ?? field, value])
+  )
+}
+
+function notFound() {
+  return createGraphQLError('{{ name }} not found', {
+    extensions: { code: '{{ name|snake_case|upper }}_NOT_FOUND', http: { status: 404 } },
+  })
+}
+
+interface Context {
+  userClient: SupabaseClient
+  repo: {{ name }}Repository
+}
+
+export const resolvers = {
+  Query: {
+{%- for op in operations %}
+{%- if op.op_type == "list" %}
+    {{ var_name }}s: async (
+      _: unknown,
+      args: {
+        filter?: Record<string, string>
+        limit?: number
+        offset?: number
+        orderBy?: string
+        ascending?: boolean
+      },
+      { repo }: Context
+    ) => {
+      const rows = await repo.findAll({
+        ...args.filter,
+        limit: args.limit ?? 20,
+        offset: args.offset,
+        orderBy: args.orderBy && (FIELD_COLUMNS[args.orderBy] ?? args.orderBy),
+        ascending: args.ascending,
+      })
+      return rows.map(toGraphQL)
+    },
+{%- elif op.op_type == "get" %}
+    {{ var_name }}: async (_: unknown, { id }: { id: string }, { repo }: Context) => {
+      const row = await repo.findById(id)
+      return row ? toGraphQL(row) : null
+    },
+{%- elif op.op_type == "custom" %}
+    {{ op.name }}{{ name }}s: async (
+      _: unknown,
+      {% if op.filters %}{ filter }: { filter?: Record<string, string> }{% else %}_args: unknown{% endif %},
+      { repo }: Context
+    ) => {
+      const rows = await repo.{{ op.name }}({% if op.filters %}{ ...filter }{% endif %})
+      return rows.map(toGraphQL)
+    },
+{%- endif %}
+{%- endfor %}
+  },
+{%- if has_mutations %}
+  Mutation: {
+{%- for op in operations %}
+{%- if op.op_type == "create" %}
+    create{{ name }}: async (
+      _: unknown,
+      { input }: { input?: Record<string, unknown> },
+      { userClient, repo }: Context
+    ) => {
+      const { data } = await userClient.auth.getUser()
+      if (!data.user) {
+        throw createGraphQLError('Not authenticated', {
+          extensions: { code: 'UNAUTHORIZED', http: { status: 401 } },
+        })
+      }
+      const row = await repo.create({ ...toColumns(input), user_id: data.user.id } as {{ name }}Insert)
+      return toGraphQL(row)
+    },
+{%- elif op.op_type == "update" %}
+    update{{ name }}: async (
+      _: unknown,
+      { id, input{% if optimistic_locking %}, version{% endif %} }: { id: string; input?: Record<string, unknown>{% if optimistic_locking %}; version: number{% endif %} },
+      { repo }: Context
+    ) => {
+      const existing = await repo.findById(id)
+      if (!existing) throw notFound()
+{%- if optimistic_locking %}
+
+      // Compare-and-swap on version: reject stale writes with 409
+      const updated =
+        existing.version === version
+          ? await repo.update(id, toColumns(input) as {{ name }}Update, version)
+          : null
+      if (!updated) {
+        throw createGraphQLError('{{ name }} was modified by someone else. Reload and try again.', {
+          extensions: { code: '{{ name|snake_case|upper }}_VERSION_CONFLICT', http: { status: 409 } },
+        })
+      }
+      return toGraphQL(updated)
+{%- else %}
+      return toGraphQL(await repo.update(id, toColumns(input) as {{ name }}Update))
+{%- endif %}
+    },
+{%- elif op.op_type == "delete" %}
+    delete{{ name }}: async (_: unknown, { id }: { id: string }, { repo }: Context) => {
+      if (!(await repo.findById(id))) throw notFound()
+      await repo.delete(id)
+      return true
+    },
+{%- endif %}
+{%- endfor %}
+  },
+{%- endif %}
+}
+
+const yoga = createYoga({
+  schema: createSchema({ typeDefs, resolvers }),
+  // Supabase strips /functions/v1 before the request reaches the function
+  graphqlEndpoint: '/{{ table_name }}-graphql',
+  context: ({ request }): Context => {
+    const userClient = createClient(
+      Deno.env.get('SUPABASE_URL') ?? '',
+      Deno.env.get('SUPABASE_ANON_KEY') ?? '',
+      { global: { headers: { Authorization: request.headers.get('Authorization') ?? '' } } }
+    )
+    return { userClient, repo: new {{ name }}Repository(userClient) }
+  },
+})
+
+Deno.serve((req) => yoga.fetch(req))
+
+"#;
+
+/// Typed React Query hooks for the GraphQL function
+/// (`packages/app-frontend/src/hooks/use<Entity>sGraphQL.ts`)
+pub const GRAPHQL_HOOK_TEMPLATE: &str = r#"/**
+ * {{ name }} GraphQL Hooks (React Query)
+ * Auto-generated by HEADLESS API Generator (--with-graphql)
+ *
+ * Typed hooks for the {{ table_name }}-graphql Edge Function
+ *
+ * Usage:
+ * ```typescript
+{%- for op in operations %}
+{%- if op.op_type == "list" %}
+ * const { data: {{ var_name }}s } = use{{ name }}sQuery({ limit: 20 })
+{%- elif op.op_type == "create" %}
+ * const create{{ name }} = useCreate{{ name }}Mutation()
+{%- endif %}
+{%- endfor %}
+ * ```
+ */
+
+import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query'
+import { supabase } from '../lib/supabase'
+
+export interface {{ name }}Node {
+  id: string
+  userId: string
+{%- for field in fields %}
+  {{ field.name }}: {{ field.typescript_type }}{% if not field.required %} | null{% endif %}
+{%- endfor %}
+{%- if optimistic_locking %}
+  version: number
+{%- endif %}
+  createdAt: string
+  updatedAt: string
+}
+{%- if filters %}
+
+export interface {{ name }}Filter {
+{%- for filter in filters %}
+  {{ filter }}?: string
+{%- endfor %}
+}
+{%- endif %}
+
+export interface Create{{ name }}Input {
+{%- for field in create_fields %}
+  {{ field.name }}{% if not field.required %}?{% endif %}: {{ field.typescript_type }}
+{%- endfor %}
+}
+
+export interface Update{{ name }}Input {
+{%- for field in fields %}
+  {{ field.name }}?: {{ field.typescript_type }}
+{%- endfor %}
+}
+
+const {{ name|snake_case|upper }}_FIELDS = `
+  id
+  userId
+{%- for field in fields %}
+  {{ field.name }}
+{%- endfor %}
+{%- if optimistic_locking %}
+  version
+{%- endif %}
+  createdAt
+  updatedAt
+`
+
+const QUERY_KEY = ['{{ table_name }}', 'graphql'] as const
+
+interface GraphQLResponse<T> {
+  data?: T
+  errors?: { message: string }[]
+}
+
+async function request<T>(query: string, variables: Record<string, unknown> = {}): Promise<T> {
+  const { data, error } = await supabase.functions.invoke<GraphQLResponse<T>>('{{ table_name }}-graphql', {
+    body: { query, variables },
+  })
+  if (error) throw error
+  if (data?.errors?.length) {
+    throw new Error(data.errors.map((e) => e.message).join(', '))
+  }
+  return data?.data as T
+}
+{%- for op in operations %}
+{%- if op.op_type == "list" %}
+
+export interface {{ name }}sQueryVariables {
+{%- if filters %}
+  filter?: {{ name }}Filter
+{%- endif %}
+  limit?: number
+  offset?: number
+  /** Field name (camelCase) or column */
+  orderBy?: string
+  ascending?: boolean
+}
+
+export function use{{ name }}sQuery(variables: {{ name }}sQueryVariables = {}) {
+  return useQuery({
+    queryKey: [...QUERY_KEY, 'list', variables],
+    queryFn: async () => {
+      const result = await request<{ {{ var_name }}s: {{ name }}Node[] }>(
+        `query {{ name }}s({% if filters %}$filter: {{ name }}Filter, {% endif %}$limit: Int, $offset: Int, $orderBy: String, $ascending: Boolean) {
+          {{ var_name }}s({% if filters %}filter: $filter, {% endif %}limit: $limit, offset: $offset, orderBy: $orderBy, ascending: $ascending) { ${ {{- name|snake_case|upper }}_FIELDS} }
+        }`,
+        { ...variables }
+      )
+      return result.{{ var_name }}s
+    },
+  })
+}
+{%- elif op.op_type == "get" %}
+
+export function use{{ name }}Query(id: string | undefined) {
+  return useQuery({
+    queryKey: [...QUERY_KEY, 'detail', id],
+    queryFn: async () => {
+      const result = await request<{ {{ var_name }}: {{ name }}Node | null }>(
+        `query {{ name }}($id: ID!) {
+          {{ var_name }}(id: $id) { ${ {{- name|snake_case|upper }}_FIELDS} }
+        }`,
+        { id }
+      )
+      return result.{{ var_name }}
+    },
+    enabled: !!id,
+  })
+}
+{%- elif op.op_type == "custom" %}
+
+export function use{{ op.name|pascal_case }}{{ name }}sQuery({% if op.filters %}filter: {{ name }}Filter = {}{% endif %}) {
+  return useQuery({
+    queryKey: [...QUERY_KEY, '{{ op.name }}'{% if op.filters %}, filter{% endif %}],
+    queryFn: async () => {
+      const result = await request<{ {{ op.name }}{{ name }}s: {{ name }}Node[] }>(
+        `query {{ op.name|pascal_case }}{{ name }}s{% if op.filters %}($filter: {{ name }}Filter){% endif %} {
+          {{ op.name }}{{ name }}s{% if op.filters %}(filter: $filter){% endif %} { ${ {{- name|snake_case|upper }}_FIELDS} }
+        }`{% if op.filters %},
+        { filter }{% endif %}
+      )
+      return result.{{ op.name }}{{ name }}s
+    },
+  })
+}
+{%- elif op.op_type == "create" %}
+
+export function useCreate{{ name }}Mutation() {
+  const queryClient = useQueryClient()
+  return useMutation({
+    mutationFn: async (input: Create{{ name }}Input) => {
+      const result = await request<{ create{{ name }}: {{ name }}Node }>(
+        `mutation Create{{ name }}($input: Create{{ name }}Input!) {
+          create{{ name }}(input: $input) { ${ {{- name|snake_case|upper }}_FIELDS} }
+        }`,
+        { input }
+      )
+      return result.create{{ name }}
+    },
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: QUERY_KEY }),
+  })
+}
+{%- elif op.op_type == "update" %}
+
+export function useUpdate{{ name }}Mutation() {
+  const queryClient = useQueryClient()
+  return useMutation({
+    mutationFn: async (variables: { id: string; input: Update{{ name }}Input{% if optimistic_locking %}; version: number{% endif %} }) => {
+      const result = await request<{ update{{ name }}: {{ name }}Node }>(
+        `mutation Update{{ name }}($id: ID!, $input: Update{{ name }}Input!{% if optimistic_locking %}, $version: Int!{% endif %}) {
+          update{{ name }}(id: $id, input: $input{% if optimistic_locking %}, version: $version{% endif %}) { ${ {{- name|snake_case|upper }}_FIELDS} }
+        }`,
+        { ...variables }
+      )
+      return result.update{{ name }}
+    },
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: QUERY_KEY }),
+  })
+}
+{%- elif op.op_type == "delete" %}
+
+export function useDelete{{ name }}Mutation() {
+  const queryClient = useQueryClient()
+  return useMutation({
+    mutationFn: async (id: string) => {
+      const result = await request<{ delete{{ name }}: boolean }>(
+        `mutation Delete{{ name }}($id: ID!) {
+          delete{{ name }}(id: $id)
+        }`,
+        { id }
+      )
+      return result.delete{{ name }}
+    },
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: QUERY_KEY }),
+  })
+}
+{%- endif %}
+{%- endfor %}
+
+"#;
@@ -5,7 +5,6 @@
 /// - useMutation for CRUD operations
 /// - Automatic cache invalidation
 /// - Type-safe operations
-
 pub const HOOK_TEMPLATE: &str = r##"/**
  * use{{ name }}s Hook (React Query)
  * Auto-generated by HEADLESS API Generator
@@ -21,9 +20,12 @@ pub const HOOK_TEMPLATE: &str = r##"/**
  * ```
  */
 
-import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query'
+import { keepPreviousData, {% if list_pagination %}useInfiniteQuery, {% endif %}useQuery, useMutation, useQueryClient } from '@tanstack/react-query'
 import { {{ name }}Service } from '../services/{{ name }}Service'
 import { {{ name }} } from '../models/{{ name }}'
+{%- for entity in related_entities %}
+import { {{ entity }} } from '../models/{{ entity }}'
+{%- endfor %}
 {%- for field in enum_fields %}
 import type { {{ name }}{{ field.name | pascal_case }} } from '../models/{{ name }}'
 {%- endfor %}
@@ -47,6 +49,21 @@ interface Use{{ name }}sOptions {
 {%- endfor %}
   /** Limit number of results */
   limit?: number
+{%- if list_pagination == "cursor" %}
+  /** nextCursor of the previous page (rows are ordered by created_at) */
+  cursor?: string
+{%- else %}
+  /** Skip this many rows (server-side pagination) */
+  offset?: number
+  /** Column to sort by (snake_case, default: created_at) */
+  orderBy?: string
+{%- endif %}
+  /** Sort ascending (default: false) */
+  ascending?: boolean
+{%- if soft_delete %}
+  /** Include soft-deleted {{ name | lower }}s (default: false) */
+  includeDeleted?: boolean
+{%- endif %}
   /** Auto-load on mount (default: true) */
   autoLoad?: boolean
 }
@@ -54,6 +71,15 @@ interface Use{{ name }}sOptions {
 interface Use{{ name }}sReturn {
   // Query state
   {{ name | lower }}s: {{ name }}[] | undefined
+{%- if list_pagination == "cursor" %}
+  /** Pass as `cursor` for the next page (null on the last page) */
+  nextCursor: string | null
+{%- elif list_pagination == "offset" %}
+  /** Rows matching the filters across all pages */
+  total: number | undefined
+  /** Pass as `offset` for the next page (null on the last page) */
+  nextOffset: number | null
+{%- endif %}
   isLoading: boolean
   isError: boolean
   error: Error | null
@@ -81,24 +107,31 @@ interface Use{{ name }}sReturn {
 {%- for field in updatable_fields %}
     {{ field.name }}?: {{ field.typescript_type }}
 {%- endfor %}
-  }) => void
+  }{% if optimistic_locking %}, version: number{% endif %}) => void
   update{{ name }}Async: (id: string, data: {
 {%- for field in updatable_fields %}
     {{ field.name }}?: {{ field.typescript_type }}
 {%- endfor %}
-  }) => Promise<{{ name }}>
+  }{% if optimistic_locking %}, version: number{% endif %}) => Promise<{{ name }}>
   isUpdating: boolean
 
   // Delete
   delete{{ name }}: (id: string) => void
   delete{{ name }}Async: (id: string) => Promise<void>
   isDeleting: boolean
+{%- if soft_delete %}
+
+  // Restore (undo a soft delete)
+  restore{{ name }}: (id: string) => void
+  restore{{ name }}Async: (id: string) => Promise<{{ name }}>
+  isRestoring: boolean
+{%- endif %}
 {%- for field in enum_fields %}
 {%- if field.enum_values | length >= 2 %}
 
   // {{ field.name | pascal_case }} helpers
-  set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}: (id: string) => void
-  set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}: (id: string) => void
+  set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}: (id: string{% if optimistic_locking %}, version: number{% endif %}) => void
+  set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}: (id: string{% if optimistic_locking %}, version: number{% endif %}) => void
 {%- endif %}
 {%- endfor %}
 
@@ -106,7 +139,14 @@ interface Use{{ name }}sReturn {
   loading: boolean
   refresh: () => void
 }
-
+{% if optimistic_locking %}
+/**
+ * True when an update was rejected because the {{ name | lower }} changed since it was loaded (409)
+ */
+export function is{{ name }}VersionConflict(error: unknown): boolean {
+  return (error as { code?: string } | null)?.code === '{{ name|snake_case|upper }}_VERSION_CONFLICT'
+}
+{% endif %}
 export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name }}sReturn {
   const {
     mine = true,
@@ -122,13 +162,32 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
 {%- endif %}
 {%- endfor %}
     limit = 20,
+{%- if list_pagination == "cursor" %}
+    cursor,
+{%- else %}
+    offset,
+    orderBy,
+{%- endif %}
+    ascending,
+{%- if soft_delete %}
+    includeDeleted,
+{%- endif %}
     autoLoad = true,
   } = options
 
   const queryClient = useQueryClient()
+{%- if optimistic_locking %}
+
+  // Stale version (409): refetch so the next attempt starts from the latest row
+  const refetchOnConflict = (error: Error) => {
+    if (is{{ name }}VersionConflict(error)) {
+      queryClient.invalidateQueries({ queryKey: ['{{ name | lower }}s'] })
+    }
+  }
+{%- endif %}
 
   // Build query key
-  const queryKey = ['{{ name | lower }}s', { mine, {% for field in enum_fields %}{{ field.name }}, {% endfor %}{% for op in operations %}{% if op.op_type == "custom" %}{{ op.name }}, {% endif %}{% endfor %}limit }]
+  const queryKey = ['{{ name | lower }}s', { mine, {% for field in enum_fields %}{{ field.name }}, {% endfor %}{% for op in operations %}{% if op.op_type == "custom" %}{{ op.name }}, {% endif %}{% endfor %}limit, {% if list_pagination == "cursor" %}cursor{% else %}offset, orderBy{% endif %}, ascending{% if soft_delete %}, includeDeleted{% endif %} }]
 
   /**
    * Query: Fetch {{ name | lower }}s
@@ -136,6 +195,31 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
   const query = useQuery({
     queryKey,
     queryFn: async () => {
+{%- if list_pagination %}
+{%- for op in operations %}
+{%- if op.op_type == "custom" %}
+      if ({{ op.name }}) {
+        const result = await {{ name }}Service.{{ op.name }}({% if op.filters | length > 0 %}{ {% for filter in op.filters %}{{ filter }}: {{ filter }}{% if not loop.last %}, {% endif %}{% endfor %} }{% endif %})
+        if (result.error) throw result.error
+        return {
+          items: result.data?.map((data) => {{ name }}.fromDatabase(data)) || [],
+          {% if list_pagination == "cursor" %}nextCursor: null{% else %}total: result.data?.length ?? 0, nextOffset: null{% endif %},
+        }
+      }
+{%- endif %}
+{%- endfor %}
+      const page = await {{ name }}Service.list({ {% for field in enum_fields %}{{ field.name }}, {% endfor %}limit, {% if list_pagination == "cursor" %}cursor{% else %}offset, orderBy{% endif %}, ascending{% if soft_delete %}, includeDeleted{% endif %} })
+      if (page.error) throw page.error
+      return {
+        items: page.data?.items.map((data) => {{ name }}.fromDatabase(data)) ?? [],
+{%- if list_pagination == "cursor" %}
+        nextCursor: page.data?.nextCursor ?? null,
+{%- else %}
+        total: page.data?.total ?? 0,
+        nextOffset: page.data?.nextOffset ?? null,
+{%- endif %}
+      }
+{%- else %}
       let result
 {%- set ns = namespace(first_condition = true) %}
 {%- for op in operations %}
@@ -147,15 +231,18 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
 {%- endif %}
 {%- endfor %}
       {% if not ns.first_condition %}else {% endif %}if (mine) {
-        result = await {{ name }}Service.list({ {% for field in enum_fields %}{{ field.name }}, {% endfor %}limit })
+        result = await {{ name }}Service.list({ {% for field in enum_fields %}{{ field.name }}, {% endfor %}limit, offset, orderBy, ascending{% if soft_delete %}, includeDeleted{% endif %} })
       } else {
-        result = await {{ name }}Service.list({ {% for field in enum_fields %}{{ field.name }}, {% endfor %}limit })
+        result = await {{ name }}Service.list({ {% for field in enum_fields %}{{ field.name }}, {% endfor %}limit, offset, orderBy, ascending{% if soft_delete %}, includeDeleted{% endif %} })
       }
 
       if (result.error) throw result.error
       return result.data?.map((data) => {{ name }}.fromDatabase(data)) || []
+{%- endif %}
     },
     enabled: autoLoad,
+    // Keep the current page visible while the next one loads
+    placeholderData: keepPreviousData,
   })
 
   /**
@@ -183,12 +270,12 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
    * Mutation: Update {{ name | lower }}
    */
   const updateMutation = useMutation({
-    mutationFn: async ({ id, data }: { id: string; data: {
+    mutationFn: async ({ id, data{% if optimistic_locking %}, version{% endif %} }: { id: string; data: {
 {%- for field in updatable_fields %}
       {{ field.name }}?: {{ field.typescript_type }}
 {%- endfor %}
-    } }) => {
-      const result = await {{ name }}Service.update(id, data)
+    }{% if optimistic_locking %}; version: number{% endif %} }) => {
+      const result = await {{ name }}Service.update(id, data{% if optimistic_locking %}, version{% endif %})
       if (result.error) throw result.error
       if (!result.data) throw new Error('Failed to update {{ name | lower }}')
       return {{ name }}.fromDatabase(result.data)
@@ -196,6 +283,9 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['{{ name | lower }}s'] })
     },
+{%- if optimistic_locking %}
+    onError: refetchOnConflict,
+{%- endif %}
   })
 
   /**
@@ -210,6 +300,23 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
       queryClient.invalidateQueries({ queryKey: ['{{ name | lower }}s'] })
     },
   })
+{%- if soft_delete %}
+
+  /**
+   * Mutation: Restore soft-deleted {{ name | lower }}
+   */
+  const restoreMutation = useMutation({
+    mutationFn: async (id: string) => {
+      const result = await {{ name }}Service.restore(id)
+      if (result.error) throw result.error
+      if (!result.data) throw new Error('Failed to restore {{ name | lower }}')
+      return {{ name }}.fromDatabase(result.data)
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['{{ name | lower }}s'] })
+    },
+  })
+{%- endif %}
 {%- for field in enum_fields %}
 {%- if field.enum_values | length >= 2 %}
 
@@ -217,8 +324,13 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
    * Mutation: Set {{ field.name }} to {{ field.enum_values[1] }}
    */
   const set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}Mutation = useMutation({
+{%- if optimistic_locking %}
+    mutationFn: async ({ id, version }: { id: string; version: number }) => {
+      const result = await {{ name }}Service.set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}(id, version)
+{%- else %}
     mutationFn: async (id: string) => {
       const result = await {{ name }}Service.set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}(id)
+{%- endif %}
       if (result.error) throw result.error
       if (!result.data) throw new Error('Failed to set {{ field.name }} to {{ field.enum_values[1] }}')
       return {{ name }}.fromDatabase(result.data)
@@ -226,14 +338,22 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['{{ name | lower }}s'] })
     },
+{%- if optimistic_locking %}
+    onError: refetchOnConflict,
+{%- endif %}
   })
 
   /**
    * Mutation: Set {{ field.name }} to {{ field.enum_values[0] }}
    */
   const set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}Mutation = useMutation({
+{%- if optimistic_locking %}
+    mutationFn: async ({ id, version }: { id: string; version: number }) => {
+      const result = await {{ name }}Service.set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}(id, version)
+{%- else %}
     mutationFn: async (id: string) => {
       const result = await {{ name }}Service.set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}(id)
+{%- endif %}
       if (result.error) throw result.error
       if (!result.data) throw new Error('Failed to set {{ field.name }} to {{ field.enum_values[0] }}')
       return {{ name }}.fromDatabase(result.data)
@@ -241,13 +361,26 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['{{ name | lower }}s'] })
     },
+{%- if optimistic_locking %}
+    onError: refetchOnConflict,
+{%- endif %}
   })
 {%- endif %}
 {%- endfor %}
 
   return {
     // Query state
+{%- if list_pagination %}
+    {{ name | lower }}s: query.data?.items,
+{%- if list_pagination == "cursor" %}
+    nextCursor: query.data?.nextCursor ?? null,
+{%- else %}
+    total: query.data?.total,
+    nextOffset: query.data?.nextOffset ?? null,
+{%- endif %}
+{%- else %}
     {{ name | lower }}s: query.data,
+{%- endif %}
     isLoading: query.isLoading,
     isError: query.isError,
     error: query.error,
@@ -259,21 +392,41 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
     isCreating: createMutation.isPending,
 
     // Update
+{%- if optimistic_locking %}
+    update{{ name }}: (id, data, version) => updateMutation.mutate({ id, data, version }),
+    update{{ name }}Async: (id, data, version) => updateMutation.mutateAsync({ id, data, version }),
+{%- else %}
     update{{ name }}: (id, data) => updateMutation.mutate({ id, data }),
     update{{ name }}Async: (id, data) => updateMutation.mutateAsync({ id, data }),
+{%- endif %}
     isUpdating: updateMutation.isPending,
 
     // Delete
     delete{{ name }}: (id) => deleteMutation.mutate(id),
     delete{{ name }}Async: (id) => deleteMutation.mutateAsync(id),
     isDeleting: deleteMutation.isPending,
+{%- if soft_delete %}
+
+    // Restore
+    restore{{ name }}: (id) => restoreMutation.mutate(id),
+    restore{{ name }}Async: (id) => restoreMutation.mutateAsync(id),
+    isRestoring: restoreMutation.isPending,
+{%- endif %}
 {%- for field in enum_fields %}
 {%- if field.enum_values | length >= 2 %}
 
     // {{ field.name | pascal_case }} helpers
+{%- if optimistic_locking %}
+    set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}: (id, version) => set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}Mutation.mutate({ id, version }),
+{%- else %}
     set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}: (id) => set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}Mutation.mutate(id),
+{%- endif %}
+{%- if optimistic_locking %}
+    set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}: (id, version) => set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}Mutation.mutate({ id, version }),
+{%- else %}
     set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}: (id) => set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}Mutation.mutate(id),
 {%- endif %}
+{%- endif %}
 {%- endfor %}
 
     // Deprecated aliases
@@ -281,7 +434,86 @@ export function use{{ name }}s(options: Use{{ name }}sOptions = {}): Use{{ name
     refresh: query.refetch,
   }
 }
+{% if list_pagination %}
+/**
+ * use{{ name }}sInfinite Hook - {{ name }}s loaded page by page ({{ list_pagination }} pagination)
+ *
+ * ```typescript
+ * const { {{ name | lower }}s, fetchNextPage, hasNextPage, isFetchingNextPage } = use{{ name }}sInfinite({ pageSize: 20 })
+ * ```
+ */
+export function use{{ name }}sInfinite(
+  options: {
+{%- for field in enum_fields %}
+    /** Filter by {{ field.name }} */
+    {{ field.name }}?: {{ name }}{{ field.name | pascal_case }}
+{%- endfor %}
+    /** Rows per page (default: 20) */
+    pageSize?: number
+{%- if list_pagination == "offset" %}
+    /** Column to sort by (snake_case, default: created_at) */
+    orderBy?: string
+{%- endif %}
+    /** Sort ascending (default: false) */
+    ascending?: boolean
+{%- if soft_delete %}
+    /** Include soft-deleted {{ name | lower }}s (default: false) */
+    includeDeleted?: boolean
+{%- endif %}
+    /** Auto-load on mount (default: true) */
+    autoLoad?: boolean
+  } = {}
+) {
+  const { {% for field in enum_fields %}{{ field.name }}, {% endfor %}pageSize = 20, {% if list_pagination == "offset" %}orderBy, {% endif %}ascending, {% if soft_delete %}includeDeleted, {% endif %}autoLoad = true } = options
 
+  const query = useInfiniteQuery({
+    queryKey: ['{{ name | lower }}s', 'infinite', { {% for field in enum_fields %}{{ field.name }}, {% endfor %}pageSize, {% if list_pagination == "offset" %}orderBy, {% endif %}ascending{% if soft_delete %}, includeDeleted{% endif %} }],
+    queryFn: async ({ pageParam }) => {
+      const result = await {{ name }}Service.list({
+{%- for field in enum_fields %}
+        {{ field.name }},
+{%- endfor %}
+        limit: pageSize,
+{%- if list_pagination == "cursor" %}
+        cursor: pageParam,
+{%- else %}
+        offset: pageParam,
+        orderBy,
+{%- endif %}
+        ascending,
+{%- if soft_delete %}
+        includeDeleted,
+{%- endif %}
+      })
+      if (result.error) throw result.error
+      if (!result.data) throw new Error('Failed to load {{ name | lower }}s')
+      return { ...result.data, items: result.data.items.map((data) => {{ name }}.fromDatabase(data)) }
+    },
+{%- if list_pagination == "cursor" %}
+    initialPageParam: undefined as string | undefined,
+    getNextPageParam: (lastPage) => lastPage.nextCursor ?? undefined,
+{%- else %}
+    initialPageParam: 0,
+    getNextPageParam: (lastPage) => lastPage.nextOffset ?? undefined,
+{%- endif %}
+    enabled: autoLoad,
+  })
+
+  return {
+    {{ name | lower }}s: query.data?.pages.flatMap((page) => page.items) ?? [],
+{%- if list_pagination == "offset" %}
+    total: query.data?.pages[0]?.total,
+{%- endif %}
+    isLoading: query.isLoading,
+    isError: query.isError,
+    error: query.error,
+    fetchNextPage: query.fetchNextPage,
+    hasNextPage: query.hasNextPage,
+    isFetchingNextPage: query.isFetchingNextPage,
+    refetch: query.refetch,
+  }
+}
+{% endif %}
 /**
  * use{{ name }} Hook - Get single {{ name | lower }} by ID
  */
@@ -306,6 +538,70 @@ export function use{{ name }}(id: string, options: { autoLoad?: boolean } = {})
     refetch: query.refetch,
   }
 }
+{%- for relation in relations %}
+
+/**
+ * use{{ name }}{{ relation.pascal_name }} Hook - {{ relation.target }} records of the {{ name | lower }} ({{ relation.kind }})
+ */
+export function use{{ name }}{{ relation.pascal_name }}(
+  id: string,
+  options: { limit?: number; offset?: number; autoLoad?: boolean } = {}
+) {
+  const { limit = 20, offset, autoLoad = true } = options
+{%- if relation.join_table %}
+  const queryClient = useQueryClient()
+{%- endif %}
+  const queryKey = ['{{ name | lower }}', id, '{{ relation.name }}']
+
+  const query = useQuery({
+    queryKey: [...queryKey, { limit, offset }],
+    queryFn: async () => {
+      const result = await {{ name }}Service.list{{ relation.pascal_name }}(id, { limit, offset })
+      if (result.error) throw result.error
+      return result.data?.map((data) => {{ relation.target }}.fromDatabase(data)) || []
+    },
+    enabled: !!id && autoLoad,
+    placeholderData: keepPreviousData,
+  })
+{%- if relation.join_table %}
+
+  const linkMutation = useMutation({
+    mutationFn: async (targetId: string) => {
+      const result = await {{ name }}Service.link{{ relation.pascal_name }}(id, targetId)
+      if (result.error) throw result.error
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey })
+    },
+  })
+
+  const unlinkMutation = useMutation({
+    mutationFn: async (targetId: string) => {
+      const result = await {{ name }}Service.unlink{{ relation.pascal_name }}(id, targetId)
+      if (result.error) throw result.error
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey })
+    },
+  })
+{%- endif %}
+
+  return {
+    {{ relation.name }}: query.data,
+    isLoading: query.isLoading,
+    isError: query.isError,
+    error: query.error,
+    refetch: query.refetch,
+{%- if relation.join_table %}
+    link: (targetId: string) => linkMutation.mutate(targetId),
+    linkAsync: (targetId: string) => linkMutation.mutateAsync(targetId),
+    unlink: (targetId: string) => unlinkMutation.mutate(targetId),
+    unlinkAsync: (targetId: string) => unlinkMutation.mutateAsync(targetId),
+    isLinking: linkMutation.isPending || unlinkMutation.isPending,
+{%- endif %}
+  }
+}
+{%- endfor %}
 "##;
 
 #[cfg(test)]
@@ -2,7 +2,6 @@
  * Migration Template (minijinja)
  * Generates SQL migration file
  */
-
 pub const MIGRATION_TEMPLATE: &str = r#"-- Create {{ table_name }} table
 -- Auto-generated by HEADLESS API Generator
 
@@ -19,8 +18,14 @@ CREATE TABLE IF NOT EXISTS public.{{ table_name }} (
   {%- if field.unique %} UNIQUE{% endif %}
   {%- if field.references %} REFERENCES {{ field.references }}{% if field.on_delete %} ON DELETE {{ field.on_delete }}{% endif %}{% endif %}
   {%- if field.enum_values %} CHECK ({{ field.db_name }} IN ({% for val in field.enum_values %}'{{ val }}'{% if not loop.last %}, {% endif %}{% endfor %})){% endif %}
-  {%- if not loop.last %},{% endif %}
+  {%- if not loop.last or optimistic_locking or soft_delete %},{% endif %}
 {%- endfor %}
+{%- if optimistic_locking %}
+  version INTEGER NOT NULL DEFAULT 1{% if soft_delete %},{% endif %}
+{%- endif %}
+{%- if soft_delete %}
+  deleted_at TIMESTAMPTZ
+{%- endif %}
 );
 
 -- ============================================================
@@ -53,6 +58,14 @@ CREATE POLICY "{{ policy.name }}"
   WITH CHECK ({{ policy.with_check }})
   {%- endif %};
 {% endfor %}
+{%- if admin_roles_sql %}
+-- Admin roles (JWT app_metadata.role) can manage every row
+CREATE POLICY "Admin roles can manage {{ table_name }}"
+  ON public.{{ table_name }}
+  FOR ALL
+  USING ((auth.jwt() -> 'app_metadata' ->> 'role') IN ({{ admin_roles_sql }}))
+  WITH CHECK ((auth.jwt() -> 'app_metadata' ->> 'role') IN ({{ admin_roles_sql }}));
+{% endif %}
 -- ============================================================
 -- 5. Trigger for updated_at
 -- ============================================================
@@ -78,4 +91,138 @@ COMMENT ON TABLE public.{{ table_name }} IS '{{ documentation.description|defaul
 {%- for field in fields %}
 COMMENT ON COLUMN public.{{ table_name }}.{{ field.db_name }} IS '{{ field.name }}';
 {%- endfor %}
+{%- if optimistic_locking %}
+COMMENT ON COLUMN public.{{ table_name }}.version IS 'Row version for optimistic locking (incremented on every update)';
+{%- endif %}
+{%- if soft_delete %}
+COMMENT ON COLUMN public.{{ table_name }}.deleted_at IS 'Soft delete timestamp (NULL = live row)';
+{%- endif %}
+{%- if webhook_events_sql %}
+
+-- ============================================================
+-- 7. Webhooks ({{ table_name }}-webhooks Edge Function)
+-- ============================================================
+-- Requires app.settings.supabase_url / app.settings.service_role_key
+-- (see 20251102110035_add_event_processing_cron.sql)
+
+CREATE EXTENSION IF NOT EXISTS pg_net;
+
+CREATE TABLE IF NOT EXISTS public.{{ table_name }}_webhook_deliveries (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  event TEXT NOT NULL CHECK (event IN ('INSERT', 'UPDATE', 'DELETE')),
+  record_id TEXT,
+  user_id UUID,
+  url TEXT NOT NULL,
+  status_code INTEGER,
+  success BOOLEAN NOT NULL DEFAULT false,
+  error TEXT,
+  duration_ms INTEGER,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_{{ table_name }}_webhook_deliveries_created_at ON public.{{ table_name }}_webhook_deliveries(created_at DESC);
+
+-- Rows are written by the Edge Function (service role); readers only
+ALTER TABLE public.{{ table_name }}_webhook_deliveries ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY "Users can view deliveries of own {{ table_name }}"
+  ON public.{{ table_name }}_webhook_deliveries
+  FOR SELECT
+  USING (auth.uid() = user_id);
+{%- if admin_roles_sql %}
+
+CREATE POLICY "Admin roles can view {{ table_name }} webhook deliveries"
+  ON public.{{ table_name }}_webhook_deliveries
+  FOR SELECT
+  USING ((auth.jwt() -> 'app_metadata' ->> 'role') IN ({{ admin_roles_sql }}));
+{%- endif %}
+
+CREATE OR REPLACE FUNCTION public.notify_{{ table_name }}_webhooks()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+SECURITY DEFINER
+SET search_path = public
+AS $$
+BEGIN
+  PERFORM net.http_post(
+    url := current_setting('app.settings.supabase_url', true) || '/functions/v1/{{ table_name }}-webhooks',
+    headers := jsonb_build_object(
+      'Content-Type', 'application/json',
+      'Authorization', 'Bearer ' || current_setting('app.settings.service_role_key', true)
+    ),
+    body := jsonb_build_object(
+      'event', TG_OP,
+      'table', TG_TABLE_NAME,
+      'record', CASE WHEN TG_OP = 'DELETE' THEN NULL ELSE to_jsonb(NEW) END,
+      'old_record', CASE WHEN TG_OP = 'INSERT' THEN NULL ELSE to_jsonb(OLD) END
+    )
+  );
+  RETURN NULL;
+END;
+$$;
+
+CREATE TRIGGER {{ table_name }}_webhooks_trigger
+  AFTER {{ webhook_events_sql }} ON public.{{ table_name }}
+  FOR EACH ROW
+  EXECUTE FUNCTION public.notify_{{ table_name }}_webhooks();
+{%- endif %}
+{%- if relations %}
+
+-- ============================================================
+-- {% if webhook_events_sql %}8{% else %}7{% endif %}. Relations
+-- ============================================================
+{%- for relation in relations %}
+{%- if relation.join_table %}
+
+-- {{ relation.name }}: {{ table_name }} ⇄ {{ relation.target_table }} (manyToMany)
+CREATE TABLE IF NOT EXISTS public.{{ relation.join_table }} (
+  {{ relation.foreign_key }} UUID NOT NULL REFERENCES public.{{ table_name }}(id) ON DELETE CASCADE,
+  {{ relation.target_key }} UUID NOT NULL REFERENCES public.{{ relation.target_table }}(id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY ({{ relation.foreign_key }}, {{ relation.target_key }})
+);
+
+-- The primary key covers lookups by {{ relation.foreign_key }}
+CREATE INDEX IF NOT EXISTS idx_{{ relation.join_table }}_{{ relation.target_key }} ON public.{{ relation.join_table }}({{ relation.target_key }});
+
+-- Links are visible with their {{ name }}{% if owner_column %} and managed by its owner{% endif %}
+ALTER TABLE public.{{ relation.join_table }} ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY "Users can view {{ relation.join_table }} of visible {{ table_name }}"
+  ON public.{{ relation.join_table }}
+  FOR SELECT
+  USING (EXISTS (SELECT 1 FROM public.{{ table_name }} parent WHERE parent.id = {{ relation.join_table }}.{{ relation.foreign_key }}));
+
+CREATE POLICY "Users can link {{ relation.join_table }} of own {{ table_name }}"
+  ON public.{{ relation.join_table }}
+  FOR INSERT
+  WITH CHECK (EXISTS (SELECT 1 FROM public.{{ table_name }} parent WHERE parent.id = {{ relation.join_table }}.{{ relation.foreign_key }}{% if owner_column %} AND parent.user_id = auth.uid(){% endif %}));
+
+CREATE POLICY "Users can unlink {{ relation.join_table }} of own {{ table_name }}"
+  ON public.{{ relation.join_table }}
+  FOR DELETE
+  USING (EXISTS (SELECT 1 FROM public.{{ table_name }} parent WHERE parent.id = {{ relation.join_table }}.{{ relation.foreign_key }}{% if owner_column %} AND parent.user_id = auth.uid(){% endif %}));
+{%- if admin_roles_sql %}
+
+CREATE POLICY "Admin roles can manage {{ relation.join_table }}"
+  ON public.{{ relation.join_table }}
+  FOR ALL
+  USING ((auth.jwt() -> 'app_metadata' ->> 'role') IN ({{ admin_roles_sql }}))
+  WITH CHECK ((auth.jwt() -> 'app_metadata' ->> 'role') IN ({{ admin_roles_sql }}));
+{%- endif %}
+{%- else %}
+
+-- {{ relation.name }}: {{ relation.target_table }}.{{ relation.foreign_key }} → {{ table_name }} (hasMany)
+-- Indexed here when {{ relation.target_table }} already exists; otherwise set
+-- `index: true` on {{ relation.foreign_key }} in the {{ relation.target }} schema
+DO $$
+BEGIN
+  IF to_regclass('public.{{ relation.target_table }}') IS NOT NULL THEN
+    CREATE INDEX IF NOT EXISTS idx_{{ relation.target_table }}_{{ relation.foreign_key }} ON public.{{ relation.target_table }}({{ relation.foreign_key }});
+  END IF;
+END
+$$;
+{%- endif %}
+{%- endfor %}
+{%- endif %}
 "#;
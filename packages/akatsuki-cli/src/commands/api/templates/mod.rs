@@ -7,14 +7,18 @@ use minijinja::Environment;
 use serde::Serialize;
 
 pub mod admin_page;
+pub mod axum_handler;
 pub mod cli_client;
 pub mod demo_component;
 pub mod edge_function;
+pub mod factory;
+pub mod graphql;
 pub mod hook;
 pub mod migration;
 pub mod model;
 pub mod repository_edge;
 pub mod service;
+pub mod webhook_function;
 pub mod zod_schema;
 
 pub struct TemplateEngine {
@@ -30,11 +34,20 @@ impl TemplateEngine {
         env.add_template("zod_schema", zod_schema::ZOD_SCHEMA_TEMPLATE)?;
         env.add_template("repository_edge", repository_edge::REPOSITORY_EDGE_TEMPLATE)?;
         env.add_template("edge_function", edge_function::EDGE_FUNCTION_TEMPLATE)?;
+        env.add_template("axum_handler", axum_handler::AXUM_HANDLER_TEMPLATE)?;
+        env.add_template(
+            "webhook_function",
+            webhook_function::WEBHOOK_FUNCTION_TEMPLATE,
+        )?;
+        env.add_template("graphql_function", graphql::GRAPHQL_FUNCTION_TEMPLATE)?;
 
         // Register templates - Frontend
         env.add_template("model", model::MODEL_TEMPLATE)?;
         env.add_template("service", service::SERVICE_TEMPLATE)?;
         env.add_template("hook", hook::HOOK_TEMPLATE)?;
+        env.add_template("factory", factory::FACTORY_TEMPLATE)?;
+        env.add_template("factory_helpers", factory::FACTORY_HELPERS_TEMPLATE)?;
+        env.add_template("graphql_hook", graphql::GRAPHQL_HOOK_TEMPLATE)?;
 
         // Register templates - CLI
         env.add_template("cli_client", cli_client::CLI_CLIENT_TEMPLATE)?;
@@ -62,6 +75,83 @@ impl TemplateEngine {
     }
 }
 
+/// PascalCase/camelCase/kebab-case → snake_case
+pub fn snake_case(value: &str) -> String {
+    let mut result = String::new();
+    for (index, c) in value.chars().enumerate() {
+        if c == '-' || c == ' ' {
+            result.push('_');
+        } else if c.is_uppercase() {
+            if index > 0 && !result.ends_with('_') {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// snake_case/kebab-case → PascalCase
+pub fn pascal_case(snake: &str) -> String {
+    snake
+        .split(['_', '-'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// snake_case → camelCase
+pub fn camel_case(snake: &str) -> String {
+    let pascal = pascal_case(snake);
+    let mut chars = pascal.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().collect::<String>() + chars.as_str())
+        .unwrap_or_default()
+}
+
+/// PascalCase/camelCase → kebab-case
+pub fn kebab_case(value: &str) -> String {
+    snake_case(value).replace('_', "-")
+}
+
+/// Plural snake_case noun → singular (`categories` → `category`)
+pub fn singular(plural: &str) -> String {
+    if let Some(stem) = plural.strip_suffix("ies") {
+        return format!("{}y", stem);
+    }
+    for suffix in ["ses", "zes", "xes", "ches", "shes"] {
+        if plural.ends_with(suffix) {
+            return plural[..plural.len() - 2].to_string();
+        }
+    }
+    match plural.strip_suffix('s') {
+        Some(stem) if !stem.ends_with(['s', 'u']) => stem.to_string(),
+        _ => plural.to_string(),
+    }
+}
+
+/// Singular snake_case noun → plural (`category` → `categories`)
+pub fn plural(singular: &str) -> String {
+    if let Some(stem) = singular.strip_suffix('y') {
+        if !stem.ends_with(['a', 'e', 'i', 'o', 'u']) {
+            return format!("{}ies", stem);
+        }
+    }
+    if singular.ends_with(['s', 'x']) || singular.ends_with("ch") || singular.ends_with("sh") {
+        return format!("{}es", singular);
+    }
+    format!("{}s", singular)
+}
+
 /// Custom filters for template engine
 mod filters {
     use minijinja::Value;
@@ -74,20 +164,7 @@ mod filters {
             )
         })?;
 
-        // PascalCase/camelCase → snake_case
-        let result = s
-            .chars()
-            .enumerate()
-            .flat_map(|(i, c)| {
-                if c.is_uppercase() && i > 0 {
-                    vec!['_', c.to_lowercase().next().unwrap()]
-                } else {
-                    vec![c.to_lowercase().next().unwrap()]
-                }
-            })
-            .collect::<String>();
-
-        Ok(Value::from(result))
+        Ok(Value::from(super::snake_case(s)))
     }
 
     pub fn camel_case(value: Value) -> Result<Value, minijinja::Error> {
@@ -98,39 +175,29 @@ mod filters {
             )
         })?;
 
-        // snake_case → camelCase
-        let mut result = String::new();
-        let mut capitalize_next = false;
-
-        for c in s.chars() {
-            if c == '_' {
-                capitalize_next = true;
-            } else if capitalize_next {
-                result.push(c.to_uppercase().next().unwrap());
-                capitalize_next = false;
-            } else {
-                result.push(c);
-            }
-        }
-
-        Ok(Value::from(result))
+        Ok(Value::from(super::camel_case(s)))
     }
 
     pub fn pascal_case(value: Value) -> Result<Value, minijinja::Error> {
-        let camel = camel_case(value)?;
-        let s = camel.as_str().unwrap();
-        let mut chars = s.chars();
-        let result = match chars.next() {
-            Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-            None => String::new(),
-        };
-        Ok(Value::from(result))
+        let s = value.as_str().ok_or_else(|| {
+            minijinja::Error::new(
+                minijinja::ErrorKind::InvalidOperation,
+                "pascal_case filter requires string",
+            )
+        })?;
+
+        Ok(Value::from(super::pascal_case(s)))
     }
 
     pub fn kebab_case(value: Value) -> Result<Value, minijinja::Error> {
-        let snake = snake_case(value)?;
-        let result = snake.as_str().unwrap().replace('_', "-");
-        Ok(Value::from(result))
+        let s = value.as_str().ok_or_else(|| {
+            minijinja::Error::new(
+                minijinja::ErrorKind::InvalidOperation,
+                "kebab_case filter requires string",
+            )
+        })?;
+
+        Ok(Value::from(super::kebab_case(s)))
     }
 
     pub fn singular(value: Value) -> Result<Value, minijinja::Error> {
@@ -141,18 +208,7 @@ mod filters {
             )
         })?;
 
-        // Simple pluralization rules (English)
-        let result = if s.ends_with("ies") {
-            s[..s.len() - 3].to_string() + "y"
-        } else if s.ends_with("ses") || s.ends_with("zes") || s.ends_with("xes") {
-            s[..s.len() - 2].to_string()
-        } else if s.ends_with('s') {
-            s[..s.len() - 1].to_string()
-        } else {
-            s.to_string()
-        };
-
-        Ok(Value::from(result))
+        Ok(Value::from(super::singular(s)))
     }
 
     pub fn upper(value: Value) -> Result<Value, minijinja::Error> {
@@ -193,6 +249,8 @@ mod tests {
             filters::snake_case(Value::from("userId")).unwrap(),
             Value::from("user_id")
         );
+        assert_eq!(snake_case("summarize-text"), "summarize_text");
+        assert_eq!(kebab_case("BlogPost"), "blog-post");
     }
 
     #[test]
@@ -204,6 +262,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_singular() {
+        assert_eq!(singular("blog_posts"), "blog_post");
+        assert_eq!(singular("categories"), "category");
+        assert_eq!(singular("boxes"), "box");
+        assert_eq!(singular("status"), "status");
+        assert_eq!(singular("address"), "address");
+    }
+
+    #[test]
+    fn test_plural() {
+        assert_eq!(plural("category"), "categories");
+        assert_eq!(plural("day"), "days");
+        assert_eq!(plural("box"), "boxes");
+    }
+
     #[test]
     fn test_pascal_case() {
         use minijinja::Value;
@@ -211,5 +285,6 @@ mod tests {
             filters::pascal_case(Value::from("user_profile")).unwrap(),
             Value::from("UserProfile")
         );
+        assert_eq!(pascal_case("sample-module"), "SampleModule");
     }
 }
@@ -7,7 +7,6 @@
 /// - toDatabase() - Convert Model to DB record for INSERT
 /// - toUpdateDatabase() - Convert Model to DB record for UPDATE
 /// - Enum status helpers (if applicable)
-
 pub const MODEL_TEMPLATE: &str = r#"/**
  * {{ name }} Model
  * Auto-generated by HEADLESS API Generator
@@ -27,6 +26,12 @@ export interface {{ name }}Data {
 {%- for field in writable_fields %}
   {{ field.name }}{% if not field.required %}?{% endif %}: {{ field.typescript_type }}{% if not field.required %} | null{% endif %}
 {%- endfor %}
+{%- if optimistic_locking %}
+  version?: number | null
+{%- endif %}
+{%- if soft_delete %}
+  deletedAt?: string | null
+{%- endif %}
   createdAt?: string | null
   updatedAt?: string | null
 }
@@ -38,6 +43,12 @@ export interface {{ name }}DatabaseRecord {
   {{ field.db_name }}: {{ field.typescript_type }}{% if not field.required %} | null{% endif %}
 {%- endif %}
 {%- endfor %}
+{%- if optimistic_locking %}
+  version: number
+{%- endif %}
+{%- if soft_delete %}
+  deleted_at: string | null
+{%- endif %}
   created_at: string
   updated_at: string
 }
@@ -49,6 +60,14 @@ export class {{ name }} {
   {{ field.name }}: {{ field.typescript_type }}{% if not field.required %} | null{% endif %}
 {%- endif %}
 {%- endfor %}
+{%- if optimistic_locking %}
+  /** Row version, sent back on update (optimistic locking) */
+  version: number | null
+{%- endif %}
+{%- if soft_delete %}
+  /** Set when soft-deleted (null = live) */
+  deletedAt: string | null
+{%- endif %}
   createdAt: string | null
   updatedAt: string | null
 
@@ -57,6 +76,12 @@ export class {{ name }} {
 {%- for field in writable_fields %}
     {{ field.name }}{% if not field.required %} = {{ field.typescript_default }}{% endif %},
 {%- endfor %}
+{%- if optimistic_locking %}
+    version = null,
+{%- endif %}
+{%- if soft_delete %}
+    deletedAt = null,
+{%- endif %}
     createdAt = null,
     updatedAt = null,
   }: {{ name }}Data) {
@@ -64,6 +89,12 @@ export class {{ name }} {
 {%- for field in writable_fields %}
     this.{{ field.name }} = {{ field.name }}
 {%- endfor %}
+{%- if optimistic_locking %}
+    this.version = version
+{%- endif %}
+{%- if soft_delete %}
+    this.deletedAt = deletedAt
+{%- endif %}
     this.createdAt = createdAt
     this.updatedAt = updatedAt
   }
@@ -79,6 +110,12 @@ export class {{ name }} {
       {{ field.name }}: data.{{ field.db_name }}{% if field.typescript_type == "string[]" %} || []{% endif %},
 {%- endif %}
 {%- endfor %}
+{%- if optimistic_locking %}
+      version: data.version,
+{%- endif %}
+{%- if soft_delete %}
+      deletedAt: data.deleted_at,
+{%- endif %}
       createdAt: data.created_at,
       updatedAt: data.updated_at,
     })
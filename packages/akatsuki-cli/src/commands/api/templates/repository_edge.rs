@@ -2,7 +2,6 @@
  * Repository (Edge Functions) Template (minijinja)
  * Generates TypeScript Repository for Edge Functions
  */
-
 pub const REPOSITORY_EDGE_TEMPLATE: &str = r#"/**
  * {{ name }} Repository (Edge Functions版)
  * {{ table_name }} テーブルのCRUD操作
@@ -14,11 +13,20 @@ pub const REPOSITORY_EDGE_TEMPLATE: &str = r#"/**
  */
 
 import { BaseRepository } from '../repository.ts'
+{%- for entity in related_entities %}
+import type { {{ entity }} } from './{{ entity }}Repository.ts'
+{%- endfor %}
 
 export interface {{ name }} {
 {%- for field in fields %}
   {{ field.db_name }}: {{ field.typescript_type }}
 {%- endfor %}
+{%- if optimistic_locking %}
+  version: number
+{%- endif %}
+{%- if soft_delete %}
+  deleted_at: string | null
+{%- endif %}
 }
 
 export interface {{ name }}Insert {
@@ -33,23 +41,59 @@ export interface {{ name }}Update {
   {{ field.db_name }}?: {{ field.typescript_type }}
 {%- endfor %}
 }
+{%- if list_pagination == "cursor" %}
+
+/** Opaque list cursor: the created_at and id of the last row of a page */
+function encodeCursor(row: {{ name }}): string {
+  return btoa(JSON.stringify([row.created_at, row.id]))
+}
+
+function decodeCursor(cursor: string): { createdAt: string; id: string } {
+  try {
+    const [createdAt, id] = JSON.parse(atob(cursor))
+    // Both values are interpolated into a PostgREST filter
+    if (
+      typeof createdAt === 'string' &&
+      !Number.isNaN(Date.parse(createdAt)) &&
+      !createdAt.includes('"') &&
+      typeof id === 'string' &&
+      /^[0-9a-f-]+$/i.test(id)
+    ) {
+      return { createdAt, id }
+    }
+  } catch {
+    // Fall through to the 422 below
+  }
+  throw Object.assign(new Error('Invalid cursor'), { status: 422, code: 'INVALID_CURSOR' })
+}
+{%- endif %}
 
 export class {{ name }}Repository extends BaseRepository {
   /**
    * Get {{ table_name|singular }} by ID
    */
+{%- if soft_delete %}
+  async findById(id: string, options?: { includeDeleted?: boolean }): Promise<{{ name }} | null> {
+    let query = this.supabase.from('{{ table_name }}').select('*').eq('id', id)
+    if (!options?.includeDeleted) {
+      query = query.is('deleted_at', null)
+    }
+
+    const { data, error } = await query.single()
+{%- else %}
   async findById(id: string): Promise<{{ name }} | null> {
     const { data, error } = await this.supabase
       .from('{{ table_name }}')
       .select('*')
       .eq('id', id)
       .single()
+{%- endif %}
 
     if (error) {
       if (this.isNotFoundError(error)) {
         return null
       }
-      throw new Error(`Failed to fetch {{ table_name|singular }}: ${error.message}`)
+      throw new Error(`Failed to fetch {{ table_name|singular }}: ${error.message}`, { cause: error })
     }
 
     return data as {{ name }}
@@ -60,7 +104,7 @@ export class {{ name }}Repository extends BaseRepository {
    */
   async findByUserId(
     userId: string,
-    filters?: { {% for filter in list_filters %}{{ filter }}?: any{% if not loop.last %}, {% endif %}{% endfor %} }
+    filters?: { {% for filter in list_filters %}{{ filter }}?: any{% if not loop.last or soft_delete %}, {% endif %}{% endfor %}{% if soft_delete %}includeDeleted?: boolean{% endif %} }
   ): Promise<{{ name }}[]> {
     let query = this.supabase
       .from('{{ table_name }}')
@@ -73,11 +117,16 @@ export class {{ name }}Repository extends BaseRepository {
       query = query.eq('{{ filter }}', filters.{{ filter }})
     }
     {%- endfor %}
+    {%- if soft_delete %}
+    if (!filters?.includeDeleted) {
+      query = query.is('deleted_at', null)
+    }
+    {%- endif %}
 
     const { data, error } = await query
 
     if (error) {
-      throw new Error(`Failed to fetch user {{ table_name }}: ${error.message}`)
+      throw new Error(`Failed to fetch user {{ table_name }}: ${error.message}`, { cause: error })
     }
 
     return (data as {{ name }}[]) || []
@@ -86,7 +135,7 @@ export class {{ name }}Repository extends BaseRepository {
   /**
    * Get current user's {{ table_name }}
    */
-  async findMine(filters?: { {% for filter in list_filters %}{{ filter }}?: any{% if not loop.last %}, {% endif %}{% endfor %} }): Promise<{{ name }}[]> {
+  async findMine(filters?: { {% for filter in list_filters %}{{ filter }}?: any{% if not loop.last or soft_delete %}, {% endif %}{% endfor %}{% if soft_delete %}includeDeleted?: boolean{% endif %} }): Promise<{{ name }}[]> {
     const user = await this.getCurrentUser()
     return this.findByUserId(user.id, filters)
   }
@@ -102,12 +151,32 @@ export class {{ name }}Repository extends BaseRepository {
       .single()
 
     if (error) {
-      throw new Error(`Failed to create {{ table_name|singular }}: ${error.message}`)
+      throw new Error(`Failed to create {{ table_name|singular }}: ${error.message}`, { cause: error })
     }
 
     return result as {{ name }}
   }
+{% if optimistic_locking %}
+  /**
+   * Update {{ table_name|singular }} only if it is still at `expectedVersion` (compare-and-swap)
+   * Returns null when the row was changed (or deleted) since it was read
+   */
+  async update(id: string, updates: {{ name }}Update, expectedVersion: number): Promise<{{ name }} | null> {
+    const { data, error } = await this.supabase
+      .from('{{ table_name }}')
+      .update({ ...updates, version: expectedVersion + 1 })
+      .eq('id', id)
+      .eq('version', expectedVersion)
+      .select()
+      .maybeSingle()
+
+    if (error) {
+      throw new Error(`Failed to update {{ table_name|singular }}: ${error.message}`, { cause: error })
+    }
 
+    return data as {{ name }} | null
+  }
+{%- else %}
   /**
    * Update {{ table_name|singular }}
    */
@@ -120,11 +189,50 @@ export class {{ name }}Repository extends BaseRepository {
       .single()
 
     if (error) {
-      throw new Error(`Failed to update {{ table_name|singular }}: ${error.message}`)
+      throw new Error(`Failed to update {{ table_name|singular }}: ${error.message}`, { cause: error })
     }
 
     return data as {{ name }}
   }
+{%- endif %}
+
+{%- if soft_delete %}
+
+  /**
+   * Soft-delete {{ table_name|singular }} (sets deleted_at; restore() brings it back)
+   */
+  async delete(id: string): Promise<void> {
+    const { error } = await this.supabase
+      .from('{{ table_name }}')
+      .update({ deleted_at: new Date().toISOString() })
+      .eq('id', id)
+      .is('deleted_at', null)
+
+    if (error) {
+      throw new Error(`Failed to delete {{ table_name|singular }}: ${error.message}`, { cause: error })
+    }
+  }
+
+  /**
+   * Restore a soft-deleted {{ table_name|singular }}
+   * Returns null when no deleted row has this id
+   */
+  async restore(id: string): Promise<{{ name }} | null> {
+    const { data, error } = await this.supabase
+      .from('{{ table_name }}')
+      .update({ deleted_at: null })
+      .eq('id', id)
+      .not('deleted_at', 'is', null)
+      .select()
+      .maybeSingle()
+
+    if (error) {
+      throw new Error(`Failed to restore {{ table_name|singular }}: ${error.message}`, { cause: error })
+    }
+
+    return data as {{ name }} | null
+  }
+{%- else %}
 
   /**
    * Delete {{ table_name|singular }}
@@ -133,9 +241,10 @@ export class {{ name }}Repository extends BaseRepository {
     const { error } = await this.supabase.from('{{ table_name }}').delete().eq('id', id)
 
     if (error) {
-      throw new Error(`Failed to delete {{ table_name|singular }}: ${error.message}`)
+      throw new Error(`Failed to delete {{ table_name|singular }}: ${error.message}`, { cause: error })
     }
   }
+{%- endif %}
 
   /**
    * Get {{ table_name }} with filters
@@ -145,11 +254,17 @@ export class {{ name }}Repository extends BaseRepository {
     {{ filter }}?: any
     {%- endfor %}
     limit?: number
+    offset?: number
+    orderBy?: string
+    ascending?: boolean
+    {%- if soft_delete %}
+    includeDeleted?: boolean
+    {%- endif %}
   }): Promise<{{ name }}[]> {
     let query = this.supabase
       .from('{{ table_name }}')
       .select('*')
-      .order('created_at', { ascending: false })
+      .order(filters?.orderBy ?? 'created_at', { ascending: filters?.ascending ?? false })
 
     {%- for filter in all_filters %}
     if (filters?.{{ filter }}) {
@@ -160,19 +275,165 @@ export class {{ name }}Repository extends BaseRepository {
       {%- endif %}
     }
     {%- endfor %}
+    {%- if soft_delete %}
+    if (!filters?.includeDeleted) {
+      query = query.is('deleted_at', null)
+    }
+    {%- endif %}
 
-    if (filters?.limit) {
+    if (filters?.limit && filters.offset !== undefined) {
+      query = query.range(filters.offset, filters.offset + filters.limit - 1)
+    } else if (filters?.limit) {
       query = query.limit(filters.limit)
     }
 
     const { data, error } = await query
 
     if (error) {
-      throw new Error(`Failed to fetch {{ table_name }}: ${error.message}`)
+      throw new Error(`Failed to fetch {{ table_name }}: ${error.message}`, { cause: error })
     }
 
     return (data as {{ name }}[]) || []
   }
+{%- if list_pagination == "offset" %}
+
+  /**
+   * One page of {{ table_name }} with the total row count
+   */
+  async findPage(filters: {
+    {%- for filter in all_filters %}
+    {{ filter }}?: any
+    {%- endfor %}
+    limit: number
+    offset?: number
+    orderBy?: string
+    ascending?: boolean
+    {%- if soft_delete %}
+    includeDeleted?: boolean
+    {%- endif %}
+  }): Promise<{ items: {{ name }}[]; total: number; nextOffset: number | null }> {
+    const offset = filters.offset ?? 0
+    let query = this.supabase
+      .from('{{ table_name }}')
+      .select('*', { count: 'exact' })
+      .order(filters.orderBy ?? 'created_at', { ascending: filters.ascending ?? false })
+      .range(offset, offset + filters.limit - 1)
+
+    {%- for filter in all_filters %}
+    if (filters.{{ filter }}) {
+      {%- if filter == "tag" %}
+      query = query.contains('tags', [filters.{{ filter }}])
+      {%- else %}
+      query = query.eq('{{ filter }}', filters.{{ filter }})
+      {%- endif %}
+    }
+    {%- endfor %}
+    {%- if soft_delete %}
+    if (!filters.includeDeleted) {
+      query = query.is('deleted_at', null)
+    }
+    {%- endif %}
+
+    const { data, error, count } = await query
+
+    if (error) {
+      throw new Error(`Failed to fetch {{ table_name }}: ${error.message}`, { cause: error })
+    }
+
+    const items = (data as {{ name }}[]) || []
+    const total = count ?? offset + items.length
+    const next = offset + items.length
+    return { items, total, nextOffset: items.length > 0 && next < total ? next : null }
+  }
+{%- elif list_pagination == "cursor" %}
+
+  /**
+   * One page of {{ table_name }} after `cursor` (keyset pagination on created_at, id)
+   */
+  async findPageAfter(filters: {
+    {%- for filter in all_filters %}
+    {{ filter }}?: any
+    {%- endfor %}
+    limit: number
+    cursor?: string
+    ascending?: boolean
+    {%- if soft_delete %}
+    includeDeleted?: boolean
+    {%- endif %}
+  }): Promise<{ items: {{ name }}[]; nextCursor: string | null }> {
+    const ascending = filters.ascending ?? false
+    let query = this.supabase
+      .from('{{ table_name }}')
+      .select('*')
+      .order('created_at', { ascending })
+      .order('id', { ascending })
+      // One extra row tells whether another page exists
+      .limit(filters.limit + 1)
+
+    {%- for filter in all_filters %}
+    if (filters.{{ filter }}) {
+      {%- if filter == "tag" %}
+      query = query.contains('tags', [filters.{{ filter }}])
+      {%- else %}
+      query = query.eq('{{ filter }}', filters.{{ filter }})
+      {%- endif %}
+    }
+    {%- endfor %}
+    {%- if soft_delete %}
+    if (!filters.includeDeleted) {
+      query = query.is('deleted_at', null)
+    }
+    {%- endif %}
+
+    if (filters.cursor) {
+      const { createdAt, id } = decodeCursor(filters.cursor)
+      const op = ascending ? 'gt' : 'lt'
+      query = query.or(`created_at.${op}."${createdAt}",and(created_at.eq."${createdAt}",id.${op}.${id})`)
+    }
+
+    const { data, error } = await query
+
+    if (error) {
+      throw new Error(`Failed to fetch {{ table_name }}: ${error.message}`, { cause: error })
+    }
+
+    const rows = (data as {{ name }}[]) || []
+    const items = rows.slice(0, filters.limit)
+    const hasMore = rows.length > filters.limit
+    return { items, nextCursor: hasMore ? encodeCursor(items[items.length - 1]) : null }
+  }
+{%- endif %}
+{%- if has_export %}
+
+  /**
+   * Export {{ table_name }} as CSV (header row + up to {{ export_limit }} rows)
+   */
+  async exportCsv(filters?: {
+    {%- for filter in all_filters %}
+    {{ filter }}?: any
+    {%- endfor %}
+    orderBy?: string
+    ascending?: boolean
+  }): Promise<string> {
+    const rows = await this.findAll({ ...filters, limit: {{ export_limit }} })
+    const columns: (keyof {{ name }})[] = [
+    {%- for field in fields %}
+      '{{ field.db_name }}',
+    {%- endfor %}
+    ]
+
+    const escape = (value: unknown): string => {
+      if (value === null || value === undefined) return ''
+      const text = typeof value === 'object' ? JSON.stringify(value) : String(value)
+      return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text
+    }
+
+    return [
+      columns.join(','),
+      ...rows.map((row) => columns.map((column) => escape(row[column])).join(',')),
+    ].join('\n')
+  }
+{%- endif %}
 {%- for op in custom_operations %}
 
   /**
@@ -184,5 +445,73 @@ export class {{ name }}Repository extends BaseRepository {
     return this.findAll({% if op.filters|length > 0 %}filters{% endif %})
   }
 {%- endfor %}
+{%- for relation in relations %}
+
+  /**
+   * {{ relation.target }} rows of the {{ table_name|singular }} ({{ relation.kind }} {{ relation.name }})
+   */
+  async list{{ relation.pascal_name }}(id: string, options?: { limit?: number; offset?: number }): Promise<{{ relation.target }}[]> {
+    const limit = options?.limit ?? 50
+    const offset = options?.offset ?? 0
+{%- if relation.join_table %}
+    const { data, error } = await this.supabase
+      .from('{{ relation.join_table }}')
+      .select('{{ relation.target_table }}(*)')
+      .eq('{{ relation.foreign_key }}', id)
+      .order('created_at', { ascending: false })
+      .range(offset, offset + limit - 1)
+
+    if (error) {
+      throw new Error(`Failed to fetch {{ relation.name }} of {{ table_name|singular }}: ${error.message}`, { cause: error })
+    }
+
+    return ((data ?? []) as unknown as { {{ relation.target_table }}: {{ relation.target }} | null }[])
+      .map((link) => link.{{ relation.target_table }})
+      .filter((row): row is {{ relation.target }} => row !== null)
+  }
+
+  /**
+   * Link a {{ relation.target }} to the {{ table_name|singular }} (no-op when already linked)
+   */
+  async link{{ relation.pascal_name }}(id: string, targetId: string): Promise<void> {
+    const { error } = await this.supabase
+      .from('{{ relation.join_table }}')
+      .upsert([{ {{ relation.foreign_key }}: id, {{ relation.target_key }}: targetId }], { ignoreDuplicates: true })
+
+    if (error) {
+      throw new Error(`Failed to link {{ relation.name }}: ${error.message}`, { cause: error })
+    }
+  }
+
+  /**
+   * Unlink a {{ relation.target }} from the {{ table_name|singular }}
+   */
+  async unlink{{ relation.pascal_name }}(id: string, targetId: string): Promise<void> {
+    const { error } = await this.supabase
+      .from('{{ relation.join_table }}')
+      .delete()
+      .eq('{{ relation.foreign_key }}', id)
+      .eq('{{ relation.target_key }}', targetId)
+
+    if (error) {
+      throw new Error(`Failed to unlink {{ relation.name }}: ${error.message}`, { cause: error })
+    }
+  }
+{%- else %}
+    const { data, error } = await this.supabase
+      .from('{{ relation.target_table }}')
+      .select('*')
+      .eq('{{ relation.foreign_key }}', id)
+      .order('created_at', { ascending: false })
+      .range(offset, offset + limit - 1)
+
+    if (error) {
+      throw new Error(`Failed to fetch {{ relation.name }} of {{ table_name|singular }}: ${error.message}`, { cause: error })
+    }
+
+    return (data as {{ relation.target }}[]) || []
+  }
+{%- endif %}
+{%- endfor %}
 }
 "#;
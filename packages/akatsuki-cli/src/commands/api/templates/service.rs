@@ -5,7 +5,6 @@
 /// - Type-safe API calls
 /// - CRUD operations (list, get, create, update, delete)
 /// - Custom operations from schema
-
 pub const SERVICE_TEMPLATE: &str = r#"/**
  * {{ name }} Service
  * Auto-generated by HEADLESS API Generator
@@ -18,6 +17,26 @@ pub const SERVICE_TEMPLATE: &str = r#"/**
 
 import { EdgeFunctionService } from './EdgeFunctionService'
 import type { {{ name }}DatabaseRecord } from '../models/{{ name }}'
+{%- for entity in related_entities %}
+import type { {{ entity }}DatabaseRecord } from '../models/{{ entity }}'
+{%- endfor %}
+{%- for op in operations %}
+{%- if op.op_type == "list" and op.pagination %}
+
+/** One page of list() results */
+export interface {{ name }}Page {
+  items: {{ name }}DatabaseRecord[]
+{%- if op.pagination == "cursor" %}
+  /** Pass as `cursor` to get the next page (null on the last page) */
+  nextCursor: string | null
+{%- else %}
+  total: number
+  /** Pass as `offset` to get the next page (null on the last page) */
+  nextOffset: number | null
+{%- endif %}
+}
+{%- endif %}
+{%- endfor %}
 
 export class {{ name }}Service {
 {%- for op in operations %}
@@ -35,18 +54,39 @@ export class {{ name }}Service {
 {%- elif op.op_type == "list" %}
 
   /**
-   * Get {{ name | lower }}s with filters
+   * Get {{ name | lower }}s with filters{% if op.pagination %} (one page per call){% endif %}
    */
   static async list(filters?: {
 {%- for filter in op.filters %}
     {{ filter }}?: string
 {%- endfor %}
     limit?: number
+{%- if op.pagination == "cursor" %}
+    cursor?: string
+{%- else %}
+    offset?: number
+    orderBy?: string
+{%- endif %}
+    ascending?: boolean
+{%- if soft_delete %}
+    /** Also return soft-deleted {{ name | lower }}s */
+    includeDeleted?: boolean
+{%- endif %}
   }) {
-    return EdgeFunctionService.invoke<{{ name }}DatabaseRecord[]>('{{ table_name }}-crud', {
+    return EdgeFunctionService.invoke<{% if op.pagination %}{{ name }}Page{% else %}{{ name }}DatabaseRecord[]{% endif %}>('{{ table_name }}-crud', {
       action: 'list',
       filters,
       limit: filters?.limit,
+{%- if op.pagination == "cursor" %}
+      cursor: filters?.cursor,
+{%- else %}
+      offset: filters?.offset,
+      orderBy: filters?.orderBy,
+{%- endif %}
+      ascending: filters?.ascending,
+{%- if soft_delete %}
+      includeDeleted: filters?.includeDeleted,
+{%- endif %}
     })
   }
 {%- elif op.op_type == "create" %}
@@ -77,11 +117,15 @@ export class {{ name }}Service {
 {%- for field in updatable_fields %}
       {{ field.name }}?: {{ field.typescript_type }}
 {%- endfor %}
-    }
+    }{% if optimistic_locking %},
+    version: number{% endif %}
   ) {
     return EdgeFunctionService.invoke<{{ name }}DatabaseRecord>('{{ table_name }}-crud', {
       action: 'update',
       id,
+{%- if optimistic_locking %}
+      version,
+{%- endif %}
       data,
     })
   }
@@ -96,6 +140,39 @@ export class {{ name }}Service {
       id,
     })
   }
+{%- if soft_delete %}
+
+  /**
+   * Restore soft-deleted {{ name | lower }}
+   */
+  static async restore(id: string) {
+    return EdgeFunctionService.invoke<{{ name }}DatabaseRecord>('{{ table_name }}-crud', {
+      action: 'restore',
+      id,
+    })
+  }
+{%- endif %}
+{%- elif op.op_type == "export" %}
+
+  /**
+   * Export {{ name | lower }}s as CSV
+   */
+  static async exportCsv(options?: {
+{%- if op.filters | length > 0 %}
+    filters?: {
+{%- for filter in op.filters %}
+      {{ filter }}?: string
+{%- endfor %}
+    }
+{%- endif %}
+    orderBy?: string
+    ascending?: boolean
+  }) {
+    return EdgeFunctionService.invoke<{ filename: string; csv: string }>('{{ table_name }}-crud', {
+      action: 'export',
+      ...options,
+    })
+  }
 {%- elif op.op_type == "custom" %}
 
   /**
@@ -124,15 +201,52 @@ export class {{ name }}Service {
   /**
    * Set {{ name | lower }} {{ field.name }} to '{{ field.enum_values[1] }}'
    */
-  static async set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}(id: string) {
-    return this.update(id, { {{ field.name }}: '{{ field.enum_values[1] }}' })
+  static async set{{ field.name | pascal_case }}{{ field.enum_values[1] | pascal_case }}(id: string{% if optimistic_locking %}, version: number{% endif %}) {
+    return this.update(id, { {{ field.name }}: '{{ field.enum_values[1] }}' }{% if optimistic_locking %}, version{% endif %})
   }
 
   /**
    * Set {{ name | lower }} {{ field.name }} to '{{ field.enum_values[0] }}'
    */
-  static async set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}(id: string) {
-    return this.update(id, { {{ field.name }}: '{{ field.enum_values[0] }}' })
+  static async set{{ field.name | pascal_case }}{{ field.enum_values[0] | pascal_case }}(id: string{% if optimistic_locking %}, version: number{% endif %}) {
+    return this.update(id, { {{ field.name }}: '{{ field.enum_values[0] }}' }{% if optimistic_locking %}, version{% endif %})
+  }
+{%- endif %}
+{%- endfor %}
+{%- for relation in relations %}
+
+  /**
+   * {{ relation.target }} records of the {{ name | lower }} ({{ relation.kind }})
+   */
+  static async list{{ relation.pascal_name }}(id: string, options?: { limit?: number; offset?: number }) {
+    return EdgeFunctionService.invoke<{{ relation.target }}DatabaseRecord[]>('{{ table_name }}-crud', {
+      action: 'list{{ relation.pascal_name }}',
+      id,
+      ...options,
+    })
+  }
+{%- if relation.join_table %}
+
+  /**
+   * Link a {{ relation.target | lower }} to the {{ name | lower }}
+   */
+  static async link{{ relation.pascal_name }}(id: string, targetId: string) {
+    return EdgeFunctionService.invoke<{ success: boolean }>('{{ table_name }}-crud', {
+      action: 'link{{ relation.pascal_name }}',
+      id,
+      targetId,
+    })
+  }
+
+  /**
+   * Unlink a {{ relation.target | lower }} from the {{ name | lower }}
+   */
+  static async unlink{{ relation.pascal_name }}(id: string, targetId: string) {
+    return EdgeFunctionService.invoke<{ success: boolean }>('{{ table_name }}-crud', {
+      action: 'unlink{{ relation.pascal_name }}',
+      id,
+      targetId,
+    })
   }
 {%- endif %}
 {%- endfor %}
@@ -0,0 +1,155 @@
+/**
+ * Webhook Function Template (minijinja)
+ * Generates the Edge Function that forwards row changes to webhook URLs
+ */
+pub const WEBHOOK_FUNCTION_TEMPLATE: &str = r#"/**
+ * {{ name }} Webhooks Edge Function
+ *
+ * Auto-generated by HEADLESS API Generator
+ * - Called by {{ table_name }}_webhooks_trigger (pg_net) on row changes
+ * - Forwards each event to the configured URLs
+ * - Signs the raw body: X-Akatsuki-Signature: sha256=<hex HMAC-SHA256(secret, body)>
+ * - Records every attempt in {{ table_name }}_webhook_deliveries
+ *
+ * Targets:
+{%- for webhook in webhooks %}
+ * - {{ webhook.url }} ({{ webhook.events | join(", ") }}) signed with {{ webhook.secret_env }}
+{%- endfor %}
+ *
+ * Set the secrets before deploying:
+{%- for env in secret_envs %}
+ *   supabase secrets set {{ env }}=<random string shared with the receiver>
+{%- endfor %}
+ */
+
+import { createClient } from 'jsr:@supabase/supabase-js@2'
+
+const supabaseUrl = Deno.env.get('SUPABASE_URL')!
+const supabaseServiceKey = Deno.env.get('SUPABASE_SERVICE_ROLE_KEY')!
+
+type WebhookEvent = 'INSERT' | 'UPDATE' | 'DELETE'
+
+interface ChangePayload {
+  event: WebhookEvent
+  table: string
+  record: Record<string, unknown> | null
+  old_record: Record<string, unknown> | null
+}
+
+const TARGETS: { url: string; events: WebhookEvent[]; secretEnv: string }[] = [
+{%- for webhook in webhooks %}
+  {
+    url: '{{ webhook.url }}',
+    events: [{% for event in webhook.events %}'{{ event }}'{% if not loop.last %}, {% endif %}{% endfor %}],
+    secretEnv: '{{ webhook.secret_env }}',
+  },
+{%- endfor %}
+]
+
+const DELIVERY_TIMEOUT_MS = 10_000
+
+async function sign(secret: string, body: string): Promise<string> {
+  const encoder = new TextEncoder()
+  const key = await crypto.subtle.importKey(
+    'raw',
+    encoder.encode(secret),
+    { name: 'HMAC', hash: 'SHA-256' },
+    false,
+    ['sign']
+  )
+  const signature = await crypto.subtle.sign('HMAC', key, encoder.encode(body))
+  return Array.from(new Uint8Array(signature))
+    .map((byte) => byte.toString(16).padStart(2, '0'))
+    .join('')
+}
+
+Deno.serve(async (req) => {
+  // Only the database trigger (service role key) may call this function
+  if (req.headers.get('Authorization') !== `Bearer ${supabaseServiceKey}`) {
+    return new Response('Unauthorized', { status: 401 })
+  }
+
+  const change = (await req.json()) as ChangePayload
+  const row = change.record ?? change.old_record ?? {}
+  const targets = TARGETS.filter((target) => target.events.includes(change.event))
+
+  const deliveries = await Promise.all(
+    targets.map(async (target) => {
+      const deliveryId = crypto.randomUUID()
+      const body = JSON.stringify({
+        id: deliveryId,
+        event: change.event,
+        table: change.table,
+        record: change.record,
+        old_record: change.old_record,
+        occurred_at: new Date().toISOString(),
+      })
+
+      const started = Date.now()
+      let statusCode: number | null = null
+      let error: string | null = null
+      try {
+        const secret = Deno.env.get(target.secretEnv)
+        if (!secret) {
+          throw new Error(`Secret ${target.secretEnv} is not set`)
+        }
+
+        const response = await fetch(target.url, {
+          method: 'POST',
+          headers: {
+            'Content-Type': 'application/json',
+            'X-Akatsuki-Event': `{{ table_name }}.${change.event.toLowerCase()}`,
+            'X-Akatsuki-Delivery': deliveryId,
+            'X-Akatsuki-Signature': `sha256=${await sign(secret, body)}`,
+          },
+          body,
+          signal: AbortSignal.timeout(DELIVERY_TIMEOUT_MS),
+        })
+        statusCode = response.status
+        if (!response.ok) {
+          error = `HTTP ${response.status}`
+        }
+      } catch (err) {
+        error = err instanceof Error ? err.message : String(err)
+      }
+
+      return {
+        id: deliveryId,
+        event: change.event,
+        record_id: row.id ?? null,
+        user_id: row.user_id ?? null,
+        url: target.url,
+        status_code: statusCode,
+        success: error === null,
+        error,
+        duration_ms: Date.now() - started,
+      }
+    })
+  )
+
+  if (deliveries.length > 0) {
+    const supabase = createClient(supabaseUrl, supabaseServiceKey)
+    const { error } = await supabase.from('{{ table_name }}_webhook_deliveries').insert(deliveries)
+    if (error) {
+      console.error('[{{ table_name }}-webhooks] Failed to record deliveries:', error)
+    }
+  }
+
+  const failed = deliveries.filter((delivery) => !delivery.success).length
+  return new Response(
+    JSON.stringify({ success: failed === 0, delivered: deliveries.length - failed, failed }),
+    { headers: { 'Content-Type': 'application/json' } }
+  )
+})
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_syntax() {
+        assert!(WEBHOOK_FUNCTION_TEMPLATE.contains("{{ table_name }}_webhook_deliveries"));
+        assert!(WEBHOOK_FUNCTION_TEMPLATE.contains("X-Akatsuki-Signature"));
+    }
+}
@@ -2,7 +2,6 @@
  * Zod Schema Template (minijinja)
  * Generates TypeScript Zod validation schema for Edge Function
  */
-
 pub const ZOD_SCHEMA_TEMPLATE: &str = r#"import { z } from 'https://deno.land/x/zod@v3.23.8/mod.ts'
 
 /**
@@ -10,10 +9,18 @@ pub const ZOD_SCHEMA_TEMPLATE: &str = r#"import { z } from 'https://deno.land/x/
  * Auto-generated by HEADLESS API Generator
  */
 
+// Row ids (path params): RFC 4122 UUIDs only
+export const idSchema = z
+  .string()
+  .regex(/^[0-9a-f]{8}-[0-9a-f]{4}-[1-8][0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/i, 'Invalid UUID')
+
 // Enum schemas
 {%- for field in enum_fields %}
 export const {{ field.name }}Schema = z.enum([{% for val in field.enum_values %}'{{ val }}'{% if not loop.last %}, {% endif %}{% endfor %}])
 {% endfor %}
+// Columns accepted by orderBy
+export const sortableColumnSchema = z.enum([{% for column in sortable_columns %}'{{ column }}'{% if not loop.last %}, {% endif %}{% endfor %}])
+
 // {{ name }} object schema
 export const {{ name }}Schema = z.object({
 {%- for field in fields %}
@@ -33,7 +40,7 @@ export const {{ name }}CrudSchema = z.discriminatedUnion('action', [
       {%- for filter in op.filters %}
       {%- set filter_field = fields|selectattr("name", "equalto", filter)|first %}
       {%- if filter_field %}
-      {{ filter }}: {{ filter_field.zod_type }}.optional(),
+      {{ filter }}: {{ filter_field.filter_zod_type }}.optional(),
       {%- else %}
       {{ filter }}: z.string().optional(),
       {%- endif %}
@@ -41,14 +48,26 @@ export const {{ name }}CrudSchema = z.discriminatedUnion('action', [
     }).optional(),
     {%- endif %}
     {%- if op.limit %}
-    limit: z.number().int().positive().max({{ op.limit }}).optional(),
+    limit: z.coerce.number().int().positive().max({{ op.limit }}).optional(),
+    {%- endif %}
+    {%- if op.pagination == "cursor" %}
+    // Opaque nextCursor of the previous page (rows are ordered by created_at, id)
+    cursor: z.string().min(1).optional(),
+    {%- else %}
+    offset: z.coerce.number().int().min(0).optional(),
+    orderBy: sortableColumnSchema.optional(),
+    {%- endif %}
+    ascending: z.boolean().optional(),
+    {%- if soft_delete %}
+    // Also return soft-deleted rows
+    includeDeleted: z.boolean().optional(),
     {%- endif %}
   }),
   {%- elif op.op_type == "get" %}
   // Get {{ table_name|singular }} by ID
   z.object({
     action: z.literal('get'),
-    id: z.string().uuid(),
+    id: idSchema,
   }),
   {%- elif op.op_type == "create" %}
   // Create {{ table_name|singular }}
@@ -64,7 +83,11 @@ export const {{ name }}CrudSchema = z.discriminatedUnion('action', [
   // Update {{ table_name|singular }}
   z.object({
     action: z.literal('update'),
-    id: z.string().uuid(),
+    id: idSchema,
+{%- if optimistic_locking %}
+    // Version the client last read; a mismatch is rejected with 409
+    version: z.number().int().positive(),
+{%- endif %}
     data: z.object({
       {%- for field in updatable_fields %}
       {{ field.name }}: {{ field.zod_type }}.optional(),
@@ -75,7 +98,33 @@ export const {{ name }}CrudSchema = z.discriminatedUnion('action', [
   // Delete {{ table_name|singular }}
   z.object({
     action: z.literal('delete'),
-    id: z.string().uuid(),
+    id: idSchema,
+  }),
+  {%- if soft_delete %}
+  // Restore soft-deleted {{ table_name|singular }}
+  z.object({
+    action: z.literal('restore'),
+    id: idSchema,
+  }),
+  {%- endif %}
+  {%- elif op.op_type == "export" %}
+  // Export {{ table_name }} as CSV
+  z.object({
+    action: z.literal('export'),
+    {%- if op.filters|length > 0 %}
+    filters: z.object({
+      {%- for filter in op.filters %}
+      {%- set filter_field = fields|selectattr("name", "equalto", filter)|first %}
+      {%- if filter_field %}
+      {{ filter }}: {{ filter_field.filter_zod_type }}.optional(),
+      {%- else %}
+      {{ filter }}: z.string().optional(),
+      {%- endif %}
+      {%- endfor %}
+    }).optional(),
+    {%- endif %}
+    orderBy: sortableColumnSchema.optional(),
+    ascending: z.boolean().optional(),
   }),
   {%- elif op.op_type == "custom" %}
   // {{ op.description|default(value=op.name ~ " operation") }}
@@ -86,7 +135,7 @@ export const {{ name }}CrudSchema = z.discriminatedUnion('action', [
       {%- for filter in op.filters %}
       {%- set filter_field = fields|selectattr("name", "equalto", filter)|first %}
       {%- if filter_field %}
-      {{ filter }}: {{ filter_field.zod_type }}.optional(),
+      {{ filter }}: {{ filter_field.filter_zod_type }}.optional(),
       {%- else %}
       {{ filter }}: z.string().optional(),
       {%- endif %}
@@ -94,11 +143,32 @@ export const {{ name }}CrudSchema = z.discriminatedUnion('action', [
     }).optional(),
     {%- endif %}
     {%- if op.limit %}
-    limit: z.number().int().positive().max({{ op.limit }}).optional(),
+    limit: z.coerce.number().int().positive().max({{ op.limit }}).optional(),
     {%- endif %}
   }),
   {%- endif %}
 {%- endfor %}
+{%- for relation in relations %}
+  // {{ relation.target }} rows of the {{ table_name|singular }} ({{ relation.kind }} {{ relation.name }})
+  z.object({
+    action: z.literal('list{{ relation.pascal_name }}'),
+    id: idSchema,
+    limit: z.coerce.number().int().positive().max(100).optional(),
+    offset: z.coerce.number().int().min(0).optional(),
+  }),
+  {%- if relation.join_table %}
+  z.object({
+    action: z.literal('link{{ relation.pascal_name }}'),
+    id: idSchema,
+    targetId: idSchema,
+  }),
+  z.object({
+    action: z.literal('unlink{{ relation.pascal_name }}'),
+    id: idSchema,
+    targetId: idSchema,
+  }),
+  {%- endif %}
+{%- endfor %}
 ])
 
 export type {{ name }}CrudInput = z.infer<typeof {{ name }}CrudSchema>
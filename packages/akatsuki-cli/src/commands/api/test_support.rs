@@ -0,0 +1,28 @@
+/**
+ * Test Fixtures
+ * Helpers shared by the tests of the `api` commands
+ */
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::generator::GeneratedFile;
+
+/// Smallest valid entity schema (`Article` / `articles`)
+pub(super) const ARTICLE_SCHEMA: &str =
+    "name: Article\ntableName: articles\nfields: []\noperations: []\nrls: []\n";
+
+/// Write `content` to `root/path`, creating its directories
+pub(super) fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// Generated file at `path` without a description
+pub(super) fn generated(path: PathBuf, content: &str) -> GeneratedFile {
+    GeneratedFile {
+        path,
+        content: content.to_string(),
+        description: String::new(),
+    }
+}
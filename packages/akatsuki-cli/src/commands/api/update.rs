@@ -0,0 +1,264 @@
+/**
+ * API Update
+ * `api update <entity>`: an ALTER TABLE migration for what changed in the
+ * schema since its table migration was generated, plus the regenerated code
+ *
+ * The previous schema is the snapshot in .akatsuki/schemas/<table>.yaml;
+ * entities generated before snapshots existed are compared with the
+ * migrations instead (like `akatsuki db plan`, without enum changes).
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+
+use super::generator::{self, CodeGenerator, GeneratedFile};
+use super::list::MIGRATIONS_DIR;
+use super::manifest::load_snapshot;
+use super::schema::EntitySchema;
+use crate::commands::db::ddl::{self, Schema};
+use crate::commands::db::plan::{self, find_schemas, Change, TablePlan};
+
+pub fn execute(
+    project_root: &Path,
+    entity_name: &str,
+    schema_path: Option<PathBuf>,
+    dry_run: bool,
+    force: bool,
+) -> Result<()> {
+    println!("🔄 Update API: {}", entity_name.bright_white());
+    println!("{}", "─".repeat(50).bright_black());
+
+    let schema_path = match schema_path {
+        Some(path) => path,
+        None => find_schema(project_root, entity_name)?,
+    };
+    let source = schema_path
+        .strip_prefix(project_root)
+        .unwrap_or(&schema_path)
+        .display()
+        .to_string();
+    println!("📖 Reading schema from: {}", source);
+    let mut schema = EntitySchema::from_yaml(&schema_path)?;
+
+    let previous = load_snapshot(project_root, &schema.table_name)?;
+    let baseline = match &previous {
+        Some(previous) => {
+            // `--with-graphql` is not part of the YAML
+            schema.graphql |= previous.graphql;
+            let mut baseline = Schema::default();
+            if let Some(migration) = CodeGenerator::new(previous.clone())
+                .generate_all()?
+                .migration
+            {
+                baseline.apply(&migration.content);
+            }
+            baseline
+        }
+        None => {
+            println!(
+                "{}",
+                "⚠️  No schema snapshot yet: comparing with the migrations instead".yellow()
+            );
+            ddl::schema_from_migrations(project_root)?
+        }
+    };
+
+    let mut changes = match plan::plan_table(&generator::declared_table(&schema), &baseline) {
+        TablePlan::Missing => anyhow::bail!(
+            "No migration creates {} yet: akatsuki api new {} --schema {}",
+            schema.table_name,
+            schema.name,
+            source
+        ),
+        TablePlan::Changes(changes) => changes,
+    };
+    if let Some(previous) = &previous {
+        changes.extend(enum_changes(previous, &schema));
+    }
+
+    let mut files = CodeGenerator::new(schema.clone()).generate_all()?;
+    files.migration = (!changes.is_empty()).then(|| GeneratedFile {
+        path: project_root.join(MIGRATIONS_DIR).join(format!(
+            "{}_update_{}_table.sql",
+            chrono::Local::now().format("%Y%m%d%H%M%S"),
+            schema.table_name
+        )),
+        content: render(&schema.table_name, &source, &changes),
+        description: format!("Migration (ALTER TABLE {})", schema.table_name),
+    });
+
+    match &files.migration {
+        Some(migration) => {
+            println!(
+                "\n{} {} ({} change(s))\n",
+                "≠".yellow(),
+                schema.table_name.bold(),
+                changes.len()
+            );
+            print!("{}", migration.content);
+        }
+        None => println!(
+            "\n{} {} {}",
+            "✓".green(),
+            schema.table_name,
+            "(no table changes)".bright_black()
+        ),
+    }
+
+    if dry_run {
+        println!(
+            "\n{}",
+            "🔍 Dry run - files that would be regenerated:".bright_cyan()
+        );
+        files.print_summary();
+        return Ok(());
+    }
+
+    println!("\n{}", "📝 Writing files...".bright_cyan());
+    files.write_to_disk(force)?;
+    if files.migration.is_none() {
+        files.schema_snapshot.write_to_disk()?;
+    }
+
+    println!("\n{}", format!("✅ Updated {}", schema.name).green().bold());
+    println!("\n{}", "🚀 Next steps:".bright_cyan());
+    println!("  1. Review the migration and the regenerated files");
+    if files.migration.is_some() {
+        println!("  2. Run migration: {}", "akatsuki db push".bright_white());
+    }
+    println!(
+        "  {}. Deploy Edge Function: {}",
+        if files.migration.is_some() { 3 } else { 2 },
+        format!("akatsuki function deploy {}-crud", schema.table_name).bright_white()
+    );
+    Ok(())
+}
+
+/// Schema YAML of an entity, by entity or table name
+fn find_schema(root: &Path, entity_name: &str) -> Result<PathBuf> {
+    find_schemas(root)
+        .into_iter()
+        .find(|path| {
+            EntitySchema::from_yaml(path)
+                .is_ok_and(|schema| schema.name == entity_name || schema.table_name == entity_name)
+        })
+        .with_context(|| {
+            format!(
+                "No schema YAML found for {} (pass it with --schema <file>)",
+                entity_name
+            )
+        })
+}
+
+/// Enum values live in the column's CHECK constraint (named
+/// `<table>_<column>_check` by Postgres); new columns bring theirs along
+fn enum_changes(previous: &EntitySchema, schema: &EntitySchema) -> Vec<Change> {
+    let table = &schema.table_name;
+    let mut changes = Vec::new();
+    for field in &schema.fields {
+        let Some(before) = previous.fields.iter().find(|f| f.db_name == field.db_name) else {
+            continue;
+        };
+        if before.enum_values == field.enum_values {
+            continue;
+        }
+        let constraint = format!("{}_{}_check", table, field.db_name);
+        if before.enum_values.is_some() {
+            changes.push(Change::new(format!(
+                "ALTER TABLE public.{} DROP CONSTRAINT IF EXISTS {};",
+                table, constraint
+            )));
+        }
+        if let Some(values) = &field.enum_values {
+            let values: Vec<String> = values.iter().map(|v| format!("'{}'", v)).collect();
+            // Fails (instead of losing rows) while rows hold a removed value
+            changes.push(Change::new(format!(
+                "ALTER TABLE public.{} ADD CONSTRAINT {} CHECK ({} IN ({}));",
+                table,
+                constraint,
+                field.db_name,
+                values.join(", ")
+            )));
+        }
+    }
+    changes
+}
+
+fn render(table_name: &str, source: &str, changes: &[Change]) -> String {
+    let mut sql = format!(
+        "-- Update {} table\n-- Auto-generated by `akatsuki api update` from {}. Review before applying.\n\n",
+        table_name, source
+    );
+    plan::append_changes(&mut sql, changes);
+    sql
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(fields: &str) -> EntitySchema {
+        EntitySchema::from_yaml_str(&format!(
+            "name: Post\ntableName: posts\nfields:\n  - name: id\n    dbName: id\n    type: uuid\n    primaryKey: true\n{}operations:\n  - type: list\nrls: []\n",
+            fields
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_changes_since_snapshot() {
+        let previous = schema(concat!(
+            "  - name: status\n    dbName: status\n    type: enum\n    enumValues: [draft, published]\n",
+            "  - name: legacy\n    dbName: legacy\n    type: string\n",
+        ));
+        let current = schema(concat!(
+            "  - name: status\n    dbName: status\n    type: enum\n    enumValues: [draft, published, archived]\n",
+            "  - name: views\n    dbName: views\n    type: integer\n    required: true\n    default: \"0\"\n    index: true\n",
+        ));
+
+        let mut baseline = Schema::default();
+        let migration = CodeGenerator::new(previous.clone())
+            .generate_all()
+            .unwrap()
+            .migration
+            .unwrap();
+        baseline.apply(&migration.content);
+        let TablePlan::Changes(mut changes) =
+            plan::plan_table(&generator::declared_table(&current), &baseline)
+        else {
+            panic!("posts should exist");
+        };
+        changes.extend(enum_changes(&previous, &current));
+
+        let sql = render("posts", "schemas/post.yaml", &changes);
+        assert!(sql.starts_with("-- Update posts table\n"));
+        assert!(
+            sql.contains("ALTER TABLE public.posts ADD COLUMN views INTEGER NOT NULL DEFAULT 0;\n")
+        );
+        assert!(sql.contains("-- ALTER TABLE public.posts DROP COLUMN legacy;\n"));
+        assert!(
+            sql.contains("CREATE INDEX IF NOT EXISTS idx_posts_views ON public.posts(views);\n")
+        );
+        assert!(sql.contains(
+            "ALTER TABLE public.posts DROP CONSTRAINT IF EXISTS posts_status_check;\nALTER TABLE public.posts ADD CONSTRAINT posts_status_check CHECK (status IN ('draft', 'published', 'archived'));\n"
+        ));
+
+        assert!(enum_changes(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let previous = schema("  - name: status\n    dbName: status\n    type: enum\n    enumValues: [draft, published]\n");
+        let snapshot = CodeGenerator::new(previous.clone())
+            .generate_all()
+            .unwrap()
+            .schema_snapshot;
+        assert!(snapshot.path.ends_with(".akatsuki/schemas/posts.yaml"));
+        let restored = EntitySchema::from_yaml_str(&snapshot.content).unwrap();
+        assert_eq!(restored.fields.len(), previous.fields.len());
+        assert_eq!(
+            restored.fields[1].enum_values,
+            previous.fields[1].enum_values
+        );
+    }
+}
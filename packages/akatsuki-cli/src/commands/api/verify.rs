@@ -0,0 +1,331 @@
+/**
+ * API Verify
+ * `api verify`: renders the code of every registered entity schema in memory
+ * and compares it with the files on disk, without writing anything
+ *
+ * A schema is registered once `api new` generated from it (its files are in
+ * .akatsuki/generated.json or its table has a snapshot). With the recorded
+ * hashes, a file that differs from the rendered code is either edited by
+ * hand or still as generated but behind its schema (or the templates).
+ */
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use super::generator::{CodeGenerator, GeneratedFile};
+use super::manifest::{self, load_snapshot, recorded_hashes, snapshot_path};
+use super::schema::EntitySchema;
+use crate::commands::db::plan::find_schemas;
+use crate::utils::output::OutputFormatter;
+use crate::utils::platform::slash_path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum FileStatus {
+    /// Same as the rendered code
+    Current,
+    /// Not on disk
+    Missing,
+    /// Edited by hand since it was generated (or not written by the generator)
+    Modified,
+    /// As generated, but the schema or the templates changed since
+    Drift,
+}
+
+impl FileStatus {
+    fn mark(self) -> colored::ColoredString {
+        match self {
+            FileStatus::Current => "✓".green(),
+            FileStatus::Missing => "✗".red(),
+            FileStatus::Modified => "✎".yellow(),
+            FileStatus::Drift => "⚠".yellow(),
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            FileStatus::Current => "up to date",
+            FileStatus::Missing => "missing",
+            FileStatus::Modified => "edited by hand",
+            FileStatus::Drift => "behind the schema",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FileCheck {
+    file: String,
+    status: FileStatus,
+    /// Rendered code, for `--diff` (not part of the JSON)
+    #[serde(skip)]
+    rendered: String,
+}
+
+#[derive(Debug, Serialize)]
+struct EntityCheck {
+    name: String,
+    table_name: String,
+    schema: String,
+    files: Vec<FileCheck>,
+}
+
+impl EntityCheck {
+    fn count(&self, status: FileStatus) -> usize {
+        self.files.iter().filter(|f| f.status == status).count()
+    }
+}
+
+/// Entity schema that no longer parses, so its code cannot be rendered
+#[derive(Debug, Serialize)]
+struct InvalidSchema {
+    schema: String,
+    error: String,
+}
+
+/// `api verify` result
+#[derive(Debug, Serialize)]
+struct VerifyReport {
+    entities: Vec<EntityCheck>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    invalid_schemas: Vec<InvalidSchema>,
+    missing: usize,
+    modified: usize,
+    drift: usize,
+}
+
+impl VerifyReport {
+    fn issues(&self) -> usize {
+        self.missing + self.modified + self.drift
+    }
+}
+
+/// Fails when a generated file is missing, edited by hand or behind its schema
+pub fn execute(project_root: &Path, diff: bool, output: OutputFormatter) -> Result<()> {
+    let report = collect(project_root)?;
+    output.emit(&report, |report| print(project_root, report, diff))?;
+    if !report.invalid_schemas.is_empty() {
+        return Err(output.failed(format!(
+            "{} entity schema(s) failed validation",
+            report.invalid_schemas.len()
+        )));
+    }
+    if report.issues() > 0 {
+        return Err(output.failed(format!(
+            "{} generated file(s) out of sync ({} missing, {} edited by hand, {} behind the schema)",
+            report.issues(),
+            report.missing,
+            report.modified,
+            report.drift
+        )));
+    }
+    Ok(())
+}
+
+fn collect(root: &Path) -> Result<VerifyReport> {
+    let recorded = recorded_hashes(root)?;
+    let mut entities: Vec<EntityCheck> = Vec::new();
+    let mut invalid_schemas = Vec::new();
+
+    for path in find_schemas(root) {
+        let mut schema = match EntitySchema::from_yaml(&path) {
+            Ok(schema) => schema,
+            Err(e) => {
+                invalid_schemas.push(InvalidSchema {
+                    schema: relative(root, &path),
+                    error: format!("{:#}", e),
+                });
+                continue;
+            }
+        };
+        if entities.iter().any(|e| e.table_name == schema.table_name) {
+            continue;
+        }
+        let snapshot = load_snapshot(root, &schema.table_name)?;
+        // `--with-graphql` is not part of the YAML
+        if let Some(snapshot) = &snapshot {
+            schema.graphql |= snapshot.graphql;
+        }
+
+        let generated = CodeGenerator::new(schema.clone()).generate_all()?;
+        let files = generated.code_files();
+        let registered = snapshot_path(root, &schema.table_name).exists()
+            || files
+                .iter()
+                .any(|file| recorded.contains_key(&relative(root, &file.path)));
+        if !registered {
+            continue;
+        }
+
+        entities.push(EntityCheck {
+            name: schema.name,
+            table_name: schema.table_name,
+            schema: relative(root, &path),
+            files: check_files(root, &files, &recorded),
+        });
+    }
+    entities.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+    let total = |status| entities.iter().map(|e| e.count(status)).sum();
+    Ok(VerifyReport {
+        missing: total(FileStatus::Missing),
+        modified: total(FileStatus::Modified),
+        drift: total(FileStatus::Drift),
+        entities,
+        invalid_schemas,
+    })
+}
+
+fn check_files(
+    root: &Path,
+    files: &[&GeneratedFile],
+    recorded: &BTreeMap<String, String>,
+) -> Vec<FileCheck> {
+    files
+        .iter()
+        .map(|file| {
+            let key = relative(root, &file.path);
+            let status = match fs::read_to_string(&file.path) {
+                Err(_) => FileStatus::Missing,
+                Ok(content) if content == file.content => FileStatus::Current,
+                Ok(content) if recorded.get(&key) == Some(&manifest::hash(&content)) => {
+                    FileStatus::Drift
+                }
+                Ok(_) => FileStatus::Modified,
+            };
+            FileCheck {
+                file: key,
+                status,
+                rendered: file.content.clone(),
+            }
+        })
+        .collect()
+}
+
+fn relative(root: &Path, path: &Path) -> String {
+    slash_path(path.strip_prefix(root).unwrap_or(path))
+}
+
+fn print(root: &Path, report: &VerifyReport, diff: bool) {
+    println!("{}", "🔎 Generated code verification".bright_cyan().bold());
+    println!("{}", "─".repeat(50).bright_black());
+
+    for invalid in &report.invalid_schemas {
+        println!(
+            "\n{} {} {}",
+            "✗".red(),
+            invalid.schema.bright_white(),
+            invalid.error.red()
+        );
+    }
+    if report.entities.is_empty() {
+        if !report.invalid_schemas.is_empty() {
+            return;
+        }
+        println!("\n{}", "No generated APIs found".yellow());
+        println!("Run: akatsuki api new <Entity> --schema <file.yaml>");
+        return;
+    }
+
+    for entity in &report.entities {
+        let issues: Vec<&FileCheck> = entity
+            .files
+            .iter()
+            .filter(|f| f.status != FileStatus::Current)
+            .collect();
+        println!(
+            "\n{} {} {}",
+            if issues.is_empty() {
+                "✓".green()
+            } else {
+                "✗".red()
+            },
+            entity.name.bright_white(),
+            format!("({})", entity.schema).bright_black()
+        );
+        for check in issues {
+            println!(
+                "  {} {} {}",
+                check.status.mark(),
+                check.file,
+                format!("({})", check.status.label()).bright_black()
+            );
+            if diff && check.status != FileStatus::Missing {
+                let current = fs::read_to_string(root.join(&check.file)).unwrap_or_default();
+                manifest::print_diff(&current, &check.rendered);
+            }
+        }
+    }
+
+    println!("\n{}", "─".repeat(50).bright_black());
+    let files: usize = report.entities.iter().map(|e| e.files.len()).sum();
+    println!(
+        "{} entit{}, {} file(s), {} out of sync",
+        report.entities.len(),
+        if report.entities.len() == 1 {
+            "y"
+        } else {
+            "ies"
+        },
+        files,
+        report.issues()
+    );
+    if report.drift > 0 || report.missing > 0 {
+        println!(
+            "  {} {}",
+            "→".bright_black(),
+            "regenerate: akatsuki api update <Entity> (or api new <Entity> --schema <file>)"
+                .bright_black()
+        );
+    }
+    if report.modified > 0 {
+        println!(
+            "  {} {}",
+            "→".bright_black(),
+            "edited files: move the changes out of the generated code, or keep them and skip when regenerating"
+                .bright_black()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::api::test_support::{generated, write};
+
+    #[test]
+    fn test_check_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "src/Current.ts", "v2\n");
+        write(root, "src/Drift.ts", "v1\n");
+        write(root, "src/Edited.ts", "v1\nmine\n");
+        let recorded: BTreeMap<String, String> = ["src/Drift.ts", "src/Edited.ts"]
+            .into_iter()
+            .map(|file| (file.to_string(), manifest::hash("v1\n")))
+            .collect();
+
+        let files: Vec<GeneratedFile> = ["Current.ts", "Drift.ts", "Edited.ts", "Missing.ts"]
+            .into_iter()
+            .map(|name| generated(root.join("src").join(name), "v2\n"))
+            .collect();
+        let files: Vec<&GeneratedFile> = files.iter().collect();
+
+        let checks = check_files(root, &files, &recorded);
+        let summary: Vec<(&str, FileStatus)> = checks
+            .iter()
+            .map(|check| (check.file.as_str(), check.status))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("src/Current.ts", FileStatus::Current),
+                ("src/Drift.ts", FileStatus::Drift),
+                ("src/Edited.ts", FileStatus::Modified),
+                ("src/Missing.ts", FileStatus::Missing),
+            ]
+        );
+    }
+}
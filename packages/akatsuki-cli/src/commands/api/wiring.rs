@@ -0,0 +1,505 @@
+/**
+ * Auto-wiring for generated entities (`api new --auto-wire`)
+ *
+ * Inserts into guarded blocks (idempotent, re-runs only add missing lines):
+ * - App.tsx: AdminPage import + <Route>
+ * - ExamplesPage.tsx: Demo component import + <XxxsDemo />
+ *
+ * Markers are created on first use; entries between them are managed by the CLI
+ * (`api delete` removes them again).
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::schema::EntitySchema;
+use crate::utils::find_project_root;
+
+pub const APP_TSX: &str = "packages/app-frontend/src/App.tsx";
+const EXAMPLES_PAGE: &str = "packages/app-frontend/src/pages/ExamplesPage.tsx";
+
+/// Where a missing guarded block is created
+#[derive(Debug, Clone, Copy)]
+enum Anchor {
+    /// After the last top-level import statement
+    AfterImports,
+    /// Before the last line that equals the given text (trimmed)
+    BeforeLast(&'static str),
+}
+
+/// A guarded block: `// akatsuki:<name>:start` ... `// akatsuki:<name>:end`
+#[derive(Debug, Clone, Copy)]
+struct Block {
+    name: &'static str,
+    /// JSX children need `{/* */}` comments
+    jsx: bool,
+    anchor: Anchor,
+}
+
+impl Block {
+    fn marker(&self, edge: &str) -> String {
+        let label = format!("akatsuki:{}:{}", self.name, edge);
+        if self.jsx {
+            format!("{{/* {} */}}", label)
+        } else {
+            format!("// {}", label)
+        }
+    }
+}
+
+const APP_IMPORTS: Block = Block {
+    name: "generated-imports",
+    jsx: false,
+    anchor: Anchor::AfterImports,
+};
+const APP_ROUTES: Block = Block {
+    name: "generated-routes",
+    jsx: true,
+    anchor: Anchor::BeforeLast("</Route>"),
+};
+const EXAMPLES_IMPORTS: Block = Block {
+    name: "generated-demo-imports",
+    jsx: false,
+    anchor: Anchor::AfterImports,
+};
+const EXAMPLES_DEMOS: Block = Block {
+    name: "generated-demos",
+    jsx: true,
+    anchor: Anchor::BeforeLast("</div>"),
+};
+
+/// Pending change to one file
+pub struct WireEdit {
+    pub path: PathBuf,
+    pub original: String,
+    pub updated: String,
+}
+
+impl WireEdit {
+    pub fn is_changed(&self) -> bool {
+        self.original != self.updated
+    }
+}
+
+/// Edits that register `schemas` in App.tsx and ExamplesPage.tsx
+pub fn plan(schemas: &[&EntitySchema]) -> Result<Vec<WireEdit>> {
+    let root = find_project_root();
+
+    let app_path = root.join(APP_TSX);
+    let app = read(&app_path)?;
+    let mut updated_app = app.clone();
+    for schema in schemas {
+        updated_app = wire_app(&updated_app, schema)?;
+    }
+
+    let examples_path = root.join(EXAMPLES_PAGE);
+    let examples = read(&examples_path)?;
+    let mut updated_examples = examples.clone();
+    for schema in schemas {
+        updated_examples = wire_examples(&updated_examples, schema)?;
+    }
+
+    Ok(vec![
+        WireEdit {
+            path: app_path,
+            original: app,
+            updated: updated_app,
+        },
+        WireEdit {
+            path: examples_path,
+            original: examples,
+            updated: updated_examples,
+        },
+    ])
+}
+
+/// Print the diff of each edit and write it unless `dry_run`
+pub fn apply(edits: &[WireEdit], dry_run: bool) -> Result<()> {
+    let root = find_project_root();
+    for edit in edits {
+        let display = edit
+            .path
+            .strip_prefix(&root)
+            .unwrap_or(&edit.path)
+            .display();
+        if !edit.is_changed() {
+            println!("  {} {} already wired", "•".bright_blue(), display);
+            continue;
+        }
+
+        println!(
+            "  {} {}",
+            if dry_run { "~".yellow() } else { "✓".green() },
+            display
+        );
+        for line in insertion_diff(&edit.original, &edit.updated) {
+            println!("    {}", line);
+        }
+        if !dry_run {
+            fs::write(&edit.path, &edit.updated)
+                .with_context(|| format!("Failed to write {}", edit.path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Edits that remove the entries of entity `name` from App.tsx and
+/// ExamplesPage.tsx (files that do not exist are skipped)
+pub fn unplan(root: &Path, name: &str, table_name: &str) -> Vec<WireEdit> {
+    let files = [
+        (APP_TSX, app_entries(name)),
+        (EXAMPLES_PAGE, examples_entries(name, table_name)),
+    ];
+
+    files
+        .into_iter()
+        .filter_map(|(path, entries)| {
+            let path = root.join(path);
+            let original = fs::read_to_string(&path).ok()?;
+            let updated = entries
+                .iter()
+                .fold(original.clone(), |content, (block, entry)| {
+                    remove(&content, *block, entry)
+                });
+            Some(WireEdit {
+                path,
+                original,
+                updated,
+            })
+        })
+        .filter(WireEdit::is_changed)
+        .collect()
+}
+
+/// Paths of the `<Route>`s in the generated block of App.tsx
+pub fn wired_routes(app: &str) -> Vec<String> {
+    let start_marker = APP_ROUTES.marker("start");
+    let end_marker = APP_ROUTES.marker("end");
+    app.lines()
+        .map(str::trim)
+        .skip_while(|line| *line != start_marker)
+        .take_while(|line| *line != end_marker)
+        .filter_map(|line| line.strip_prefix("<Route path=\""))
+        .filter_map(|rest| rest.split('"').next())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Removed lines of an `unplan` edit
+pub fn removal_diff(edit: &WireEdit) -> Vec<String> {
+    added_lines(&edit.updated, &edit.original)
+        .into_iter()
+        .map(|(number, line)| {
+            format!(
+                "{} {}",
+                format!("{:>5} -", number).bright_black(),
+                line.red()
+            )
+        })
+        .collect()
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+fn app_entries(name: &str) -> [(Block, String); 2] {
+    let component = format!("{}AdminPage", name);
+    [
+        (
+            APP_IMPORTS,
+            format!(
+                "import {{ {} }} from './pages/admin/entities/{}'",
+                component, component
+            ),
+        ),
+        // Same path as the generated page header (admin_page template)
+        (
+            APP_ROUTES,
+            format!(
+                "<Route path=\"/admin/{}s\" element={{<{} />}} />",
+                name.to_lowercase(),
+                component
+            ),
+        ),
+    ]
+}
+
+fn examples_entries(name: &str, table_name: &str) -> [(Block, String); 2] {
+    let component = format!("{}sDemo", name);
+    [
+        (
+            EXAMPLES_IMPORTS,
+            format!(
+                "import {{ {} }} from '../components/features/{}/{}'",
+                component, table_name, component
+            ),
+        ),
+        (EXAMPLES_DEMOS, format!("<{} />", component)),
+    ]
+}
+
+pub fn wire_app(content: &str, schema: &EntitySchema) -> Result<String> {
+    app_entries(&schema.name)
+        .iter()
+        .try_fold(content.to_string(), |content, (block, entry)| {
+            upsert(&content, *block, entry)
+        })
+}
+
+pub fn wire_examples(content: &str, schema: &EntitySchema) -> Result<String> {
+    examples_entries(&schema.name, &schema.table_name)
+        .iter()
+        .try_fold(content.to_string(), |content, (block, entry)| {
+            upsert(&content, *block, entry)
+        })
+}
+
+/// Add `entry` to the guarded block, creating the block at its anchor if needed
+fn upsert(content: &str, block: Block, entry: &str) -> Result<String> {
+    let start_marker = block.marker("start");
+    let end_marker = block.marker("end");
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+
+    let start = lines.iter().position(|l| l.trim() == start_marker);
+    let end = lines.iter().position(|l| l.trim() == end_marker);
+    match (start, end) {
+        (Some(start), Some(end)) if start < end => {
+            if lines[start + 1..end].iter().any(|l| l.trim() == entry) {
+                return Ok(content.to_string());
+            }
+            let indent = indent_of(&lines[start]);
+            lines.insert(end, format!("{}{}", indent, entry));
+        }
+        (None, None) => {
+            let (index, indent) = anchor_position(&lines, block.anchor)
+                .with_context(|| format!("Could not find where to insert {}", start_marker))?;
+            lines.splice(
+                index..index,
+                [
+                    format!("{}{}", indent, start_marker),
+                    format!("{}{}", indent, entry),
+                    format!("{}{}", indent, end_marker),
+                ],
+            );
+        }
+        _ => anyhow::bail!(
+            "Broken guard markers: expected '{}' followed by '{}'",
+            start_marker,
+            end_marker
+        ),
+    }
+
+    let mut updated = lines.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+/// Drop `entry` from the guarded block (the markers stay)
+fn remove(content: &str, block: Block, entry: &str) -> String {
+    let start_marker = block.marker("start");
+    let end_marker = block.marker("end");
+    let lines: Vec<&str> = content.lines().collect();
+
+    let start = lines.iter().position(|l| l.trim() == start_marker);
+    let end = lines.iter().position(|l| l.trim() == end_marker);
+    let (Some(start), Some(end)) = (start, end) else {
+        return content.to_string();
+    };
+
+    let mut updated = lines
+        .iter()
+        .enumerate()
+        .filter(|(index, line)| !(start < *index && *index < end && line.trim() == entry))
+        .map(|(_, line)| *line)
+        .collect::<Vec<_>>()
+        .join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated
+}
+
+/// Insert index and indentation for a new block
+fn anchor_position(lines: &[String], anchor: Anchor) -> Option<(usize, String)> {
+    match anchor {
+        Anchor::AfterImports => {
+            // Leading import section only (code samples later in the file may contain `import`)
+            let mut last_import = None;
+            let mut in_import = false;
+            for (index, line) in lines.iter().enumerate() {
+                let trimmed = line.trim();
+                if in_import || trimmed.starts_with("import ") {
+                    // Multi-line imports end at the `from '...'` line
+                    in_import = !(trimmed.contains(" from ")
+                        || trimmed.starts_with("import '")
+                        || trimmed.starts_with("import \""));
+                    if !in_import {
+                        last_import = Some(index);
+                    }
+                } else if !(trimmed.is_empty()
+                    || trimmed.starts_with("//")
+                    || trimmed.starts_with("/*")
+                    || trimmed.starts_with('*'))
+                {
+                    break;
+                }
+            }
+            Some((last_import? + 1, String::new()))
+        }
+        Anchor::BeforeLast(closing) => {
+            let index = lines.iter().rposition(|l| l.trim() == closing)?;
+            // Align with the children of the closing tag (previous non-empty line)
+            let indent = lines[..index]
+                .iter()
+                .rev()
+                .find(|l| !l.trim().is_empty())
+                .map(|l| indent_of(l))
+                .unwrap_or_default();
+            Some((index, indent))
+        }
+    }
+}
+
+fn indent_of(line: &str) -> String {
+    line.chars().take_while(|c| c.is_whitespace()).collect()
+}
+
+/// Added lines with their line numbers (wiring only ever inserts lines)
+fn insertion_diff(original: &str, updated: &str) -> Vec<String> {
+    added_lines(original, updated)
+        .into_iter()
+        .map(|(number, line)| {
+            format!(
+                "{} {}",
+                format!("{:>5} +", number).bright_black(),
+                line.green()
+            )
+        })
+        .collect()
+}
+
+/// Lines of `updated` missing from `original` (1-based line numbers in `updated`)
+fn added_lines<'a>(original: &str, updated: &'a str) -> Vec<(usize, &'a str)> {
+    let old: Vec<&str> = original.lines().collect();
+    let mut cursor = 0;
+    let mut added = Vec::new();
+    for (number, line) in updated.lines().enumerate() {
+        if cursor < old.len() && old[cursor] == line {
+            cursor += 1;
+        } else {
+            added.push((number + 1, line));
+        }
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const APP: &str = "import { Layout } from './components/layout/Layout'
+import {
+  Foo,
+} from './foo'
+
+const SAMPLE = `import { Sample } from './sample'`
+
+function App() {
+  return (
+    <Routes>
+      <Route element={<PrivateLayout />}>
+        <Route path=\"/admin\" element={<AdminDashboard />} />
+      </Route>
+    </Routes>
+  )
+}
+";
+
+    fn schema(name: &str, table_name: &str) -> EntitySchema {
+        serde_yaml::from_str(&format!(
+            "name: {}\ntableName: {}\nfields: []\noperations: []\nrls: []\n",
+            name, table_name
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_wire_app_creates_blocks() {
+        let updated = wire_app(APP, &schema("Article", "articles")).unwrap();
+        assert!(updated.contains(
+            "} from './foo'\n// akatsuki:generated-imports:start\nimport { ArticleAdminPage } from './pages/admin/entities/ArticleAdminPage'\n// akatsuki:generated-imports:end\n"
+        ));
+        assert!(updated.contains(
+            "        <Route path=\"/admin\" element={<AdminDashboard />} />\n        {/* akatsuki:generated-routes:start */}\n        <Route path=\"/admin/articles\" element={<ArticleAdminPage />} />\n        {/* akatsuki:generated-routes:end */}\n      </Route>\n"
+        ));
+    }
+
+    #[test]
+    fn test_wire_app_is_idempotent() {
+        let once = wire_app(APP, &schema("Article", "articles")).unwrap();
+        assert_eq!(
+            wire_app(&once, &schema("Article", "articles")).unwrap(),
+            once
+        );
+
+        let twice = wire_app(&once, &schema("Tag", "tags")).unwrap();
+        assert_eq!(twice.matches("akatsuki:generated-routes:start").count(), 1);
+        assert!(twice.contains(
+            "<Route path=\"/admin/articles\" element={<ArticleAdminPage />} />\n        <Route path=\"/admin/tags\" element={<TagAdminPage />} />\n"
+        ));
+    }
+
+    #[test]
+    fn test_wire_examples() {
+        let page = "import { Card } from '../components/ui/card'\n\nexport function ExamplesPage() {\n  return (\n    <div>\n        <RichEditorDemoCard />\n    </div>\n  )\n}\n";
+        let updated = wire_examples(page, &schema("Article", "articles")).unwrap();
+        assert!(updated.contains(
+            "import { ArticlesDemo } from '../components/features/articles/ArticlesDemo'"
+        ));
+        assert!(updated.contains(
+            "        <ArticlesDemo />\n        {/* akatsuki:generated-demos:end */}\n    </div>"
+        ));
+    }
+
+    #[test]
+    fn test_wired_routes() {
+        let app = wire_app(APP, &schema("Article", "articles")).unwrap();
+        let app = wire_app(&app, &schema("Tag", "tags")).unwrap();
+        assert_eq!(wired_routes(&app), vec!["/admin/articles", "/admin/tags"]);
+        assert!(wired_routes(APP).is_empty());
+    }
+
+    #[test]
+    fn test_remove_entries() {
+        let wired = wire_app(APP, &schema("Article", "articles")).unwrap();
+        let wired = wire_app(&wired, &schema("Tag", "tags")).unwrap();
+
+        let unwired = app_entries("Article")
+            .iter()
+            .fold(wired.clone(), |content, (block, entry)| {
+                remove(&content, *block, entry)
+            });
+        assert!(!unwired.contains("Article"));
+        assert!(unwired.contains("<Route path=\"/admin/tags\" element={<TagAdminPage />} />"));
+        assert_eq!(unwired.matches("akatsuki:generated-routes:").count(), 2);
+        assert_eq!(added_lines(&unwired, &wired).len(), 2);
+    }
+
+    #[test]
+    fn test_broken_markers() {
+        let content = "import { A } from './a'\n// akatsuki:generated-imports:start\n";
+        assert!(upsert(content, APP_IMPORTS, "import { B } from './b'").is_err());
+    }
+
+    #[test]
+    fn test_insertion_diff() {
+        let diff = insertion_diff("a\nb\n", "a\nx\nb\n");
+        assert_eq!(diff.len(), 1);
+        assert!(diff[0].contains("2 +"));
+        assert!(diff[0].contains('x'));
+    }
+}
@@ -0,0 +1,226 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::io::Read;
+use std::path::Path;
+
+use crate::cli::{BackendAction, BackendEnv, HttpMethod};
+use crate::utils::backend::{BackendClient, RawResponse, DEFAULT_BACKEND_URL};
+use crate::utils::find_project_root;
+
+/// Frontend env file whose `VITE_API_BASE_URL` points at the deployed backend
+const FRONTEND_PROD_ENV: &str = "packages/app-frontend/.env.production";
+
+pub struct BackendCommand;
+
+impl BackendCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, action: BackendAction) -> Result<()> {
+        match action {
+            BackendAction::Call {
+                route,
+                body,
+                method,
+                env,
+                url,
+                token,
+            } => self.call(&route, body.as_deref(), method, env, url, token),
+        }
+    }
+
+    fn call(
+        &self,
+        route: &str,
+        body_path: Option<&Path>,
+        method: Option<HttpMethod>,
+        env: BackendEnv,
+        url: Option<String>,
+        token: Option<String>,
+    ) -> Result<()> {
+        if !route.starts_with('/') {
+            anyhow::bail!("Route must start with / (e.g. /api/health)");
+        }
+
+        let base_url = match url {
+            Some(url) => url,
+            None => resolve_base_url(env)?,
+        };
+        let token = token.or_else(|| resolve_token(env));
+        let body = body_path.map(read_body).transpose()?;
+        let method = method.unwrap_or(if body.is_some() {
+            HttpMethod::Post
+        } else {
+            HttpMethod::Get
+        });
+        let http_method: reqwest::Method = method
+            .as_str()
+            .to_uppercase()
+            .parse()
+            .context("Invalid HTTP method")?;
+
+        println!(
+            "{} {} {}{} {}",
+            "→".bright_blue(),
+            http_method.as_str().bold(),
+            base_url.trim_end_matches('/'),
+            route,
+            if token.is_some() {
+                "(authenticated)".bright_black()
+            } else {
+                "(no auth)".bright_black()
+            }
+        );
+
+        let client = BackendClient::new(&base_url)?.with_token(token);
+        let response = client.send_raw(http_method, route, body.as_ref())?;
+        print_response(&response);
+
+        if !response.status.is_success() {
+            anyhow::bail!("Backend returned {}", response.status);
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn resolve_base_url(env: BackendEnv) -> Result<String> {
+    match env {
+        BackendEnv::Local => Ok(std::env::var("AKATSUKI_BACKEND_URL")
+            .ok()
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| DEFAULT_BACKEND_URL.to_string())),
+        BackendEnv::Prod => {
+            if let Some(url) = std::env::var("AKATSUKI_BACKEND_PROD_URL")
+                .ok()
+                .filter(|v| !v.is_empty())
+            {
+                return Ok(url);
+            }
+
+            let env_file = find_project_root().join(FRONTEND_PROD_ENV);
+            std::fs::read_to_string(&env_file)
+                .ok()
+                .and_then(|content| dotenv_value(&content, "VITE_API_BASE_URL"))
+                .with_context(|| {
+                    format!(
+                        "Production backend URL not configured. Set AKATSUKI_BACKEND_PROD_URL \
+                         or VITE_API_BASE_URL in {}",
+                        FRONTEND_PROD_ENV
+                    )
+                })
+        }
+    }
+}
+
+pub(crate) fn resolve_token(env: BackendEnv) -> Option<String> {
+    let key = match env {
+        BackendEnv::Local => "AKATSUKI_ADMIN_TOKEN",
+        BackendEnv::Prod => "AKATSUKI_PROD_ADMIN_TOKEN",
+    };
+    std::env::var(key).ok().filter(|v| !v.is_empty())
+}
+
+/// Value of `key` in a dotenv file (`KEY=value`, optionally quoted)
+fn dotenv_value(content: &str, key: &str) -> Option<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.strip_prefix("export ").unwrap_or(line).split_once('='))
+        .find(|(name, _)| name.trim() == key)
+        .map(|(_, value)| {
+            value
+                .trim()
+                .trim_matches('"')
+                .trim_matches('\'')
+                .to_string()
+        })
+        .filter(|value| !value.is_empty())
+}
+
+pub(crate) fn read_body(path: &Path) -> Result<serde_json::Value> {
+    let raw = if path == Path::new("-") {
+        let mut buffer = String::new();
+        std::io::stdin()
+            .read_to_string(&mut buffer)
+            .context("Failed to read body from stdin")?;
+        buffer
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?
+    };
+
+    serde_json::from_str(&raw).context("Request body is not valid JSON")
+}
+
+fn print_response(response: &RawResponse) {
+    let status = response.status.to_string();
+    let status = if response.status.is_success() {
+        status.green().bold()
+    } else if response.status.is_client_error() {
+        status.yellow().bold()
+    } else {
+        status.red().bold()
+    };
+
+    print!(
+        "{} {}  {}",
+        "←".bright_blue(),
+        status,
+        format!("{} ms", response.elapsed.as_millis()).bright_white()
+    );
+    if let Some(request_id) = &response.request_id {
+        print!(
+            "  {}",
+            format!("x-request-id: {}", request_id).bright_black()
+        );
+    }
+    println!("\n");
+
+    println!("{}", format_body(response));
+}
+
+fn format_body(response: &RawResponse) -> String {
+    if response.body.is_empty() {
+        return "(empty body)".bright_black().to_string();
+    }
+
+    if let Ok(json) = serde_json::from_slice::<serde_json::Value>(&response.body) {
+        return serde_json::to_string_pretty(&json).unwrap_or_default();
+    }
+
+    let content_type = response.content_type.as_deref().unwrap_or("");
+    match std::str::from_utf8(&response.body) {
+        Ok(text) if content_type.is_empty() || content_type.starts_with("text/") => {
+            text.to_string()
+        }
+        _ => format!(
+            "({} bytes of {})",
+            response.body.len(),
+            if content_type.is_empty() {
+                "binary data"
+            } else {
+                content_type
+            }
+        )
+        .bright_black()
+        .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dotenv_value() {
+        let content = "# backend\nVITE_SUPABASE_URL=https://x.supabase.co\nexport VITE_API_BASE_URL=\"https://app.shuttle.app\"\nEMPTY=\n";
+        assert_eq!(
+            dotenv_value(content, "VITE_API_BASE_URL").as_deref(),
+            Some("https://app.shuttle.app")
+        );
+        assert_eq!(dotenv_value(content, "EMPTY"), None);
+        assert_eq!(dotenv_value(content, "MISSING"), None);
+    }
+}
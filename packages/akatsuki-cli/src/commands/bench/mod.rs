@@ -0,0 +1,465 @@
+/**
+ * Bench Command
+ * Small load generator for the app-backend: a fixed number of workers repeat
+ * one request back to back for a duration, then latency percentiles,
+ * throughput and the error rate are reported
+ */
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::cli::{BackendEnv, BenchAction, HttpMethod};
+use crate::commands::backend::{read_body, resolve_base_url, resolve_token};
+use crate::utils::backend::BackendClient;
+use crate::utils::output::OutputFormatter;
+
+/// Status key for requests that got no response (connection error, timeout)
+const NO_RESPONSE: &str = "error";
+
+/// The request every worker repeats
+struct Target {
+    client: BackendClient,
+    method: reqwest::Method,
+    route: String,
+    body: Option<serde_json::Value>,
+}
+
+impl Target {
+    fn send(&self) -> Sample {
+        let started = Instant::now();
+        match self
+            .client
+            .send_raw(self.method.clone(), &self.route, self.body.as_ref())
+        {
+            Ok(response) => Sample {
+                latency: response.elapsed,
+                status: Some(response.status.as_u16()),
+            },
+            Err(_) => Sample {
+                latency: started.elapsed(),
+                status: None,
+            },
+        }
+    }
+}
+
+/// One finished request
+struct Sample {
+    latency: Duration,
+    /// `None` when no response arrived
+    status: Option<u16>,
+}
+
+impl Sample {
+    fn is_error(&self) -> bool {
+        !self
+            .status
+            .is_some_and(|status| (200..400).contains(&status))
+    }
+}
+
+/// Latencies of the requests that got a response, in milliseconds
+#[derive(Debug, Serialize)]
+struct LatencySummary {
+    min_ms: f64,
+    mean_ms: f64,
+    p50_ms: f64,
+    p90_ms: f64,
+    p95_ms: f64,
+    p99_ms: f64,
+    max_ms: f64,
+}
+
+/// `bench backend` result
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    method: String,
+    url: String,
+    concurrency: usize,
+    /// Measured wall time (slightly over `--duration` while the last
+    /// requests finish)
+    duration_secs: f64,
+    requests: usize,
+    /// Responses outside 2xx / 3xx and requests without a response
+    errors: usize,
+    /// `errors / requests` (0.0 - 1.0)
+    error_rate: f64,
+    requests_per_sec: f64,
+    /// `None` when no request got a response
+    latency: Option<LatencySummary>,
+    /// Request count by status code (`"error"` for no response)
+    statuses: BTreeMap<String, usize>,
+}
+
+pub struct BenchCommand;
+
+impl BenchCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, action: BenchAction, output: OutputFormatter) -> Result<()> {
+        match action {
+            BenchAction::Backend {
+                route,
+                concurrency,
+                duration,
+                method,
+                body,
+                env,
+                url,
+                token,
+                max_error_rate,
+            } => {
+                let target = self.target(&route, method, body, env, url, token)?;
+                self.backend(&target, concurrency, duration, max_error_rate, output)
+            }
+        }
+    }
+
+    fn target(
+        &self,
+        route: &str,
+        method: Option<HttpMethod>,
+        body_path: Option<PathBuf>,
+        env: BackendEnv,
+        url: Option<String>,
+        token: Option<String>,
+    ) -> Result<Target> {
+        if !route.starts_with('/') {
+            anyhow::bail!("Route must start with / (e.g. /health)");
+        }
+
+        let base_url = match url {
+            Some(url) => url,
+            None => resolve_base_url(env)?,
+        };
+        let token = token.or_else(|| resolve_token(env));
+        let body = body_path.as_deref().map(read_body).transpose()?;
+        let method = method.unwrap_or(if body.is_some() {
+            HttpMethod::Post
+        } else {
+            HttpMethod::Get
+        });
+
+        Ok(Target {
+            client: BackendClient::new(&base_url)?.with_token(token),
+            method: method.as_str().to_uppercase().parse()?,
+            route: route.to_string(),
+            body,
+        })
+    }
+
+    fn backend(
+        &self,
+        target: &Target,
+        concurrency: usize,
+        duration: Duration,
+        max_error_rate: Option<f64>,
+        output: OutputFormatter,
+    ) -> Result<()> {
+        if concurrency == 0 {
+            anyhow::bail!("--concurrency must be at least 1");
+        }
+        let url = format!("{}{}", target.client.base_url(), target.route);
+
+        // An unreachable backend would fail every request instantly for the whole run
+        target
+            .client
+            .send_raw(target.method.clone(), &target.route, target.body.as_ref())?;
+
+        if !output.is_json() {
+            println!(
+                "{} {} {}",
+                "⚡ Bench".bright_cyan().bold(),
+                target.method.as_str().bold(),
+                url
+            );
+            println!(
+                "{}",
+                format!(
+                    "{} workers for {:.1}s...",
+                    concurrency,
+                    duration.as_secs_f64()
+                )
+                .bright_black()
+            );
+        }
+
+        let (samples, elapsed) = run(target, concurrency, duration);
+        let report = summarize(
+            target.method.to_string(),
+            url,
+            concurrency,
+            &samples,
+            elapsed,
+        );
+        output.emit(&report, print)?;
+
+        if let Some(max) = max_error_rate {
+            if report.error_rate * 100.0 > max {
+                return Err(output.failed(format!(
+                    "Error rate {:.2}% exceeds --max-error-rate {}%",
+                    report.error_rate * 100.0,
+                    max
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Send `target` from `concurrency` threads until `duration` has passed
+fn run(target: &Target, concurrency: usize, duration: Duration) -> (Vec<Sample>, Duration) {
+    let started = Instant::now();
+    let deadline = started + duration;
+
+    let samples = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..concurrency)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut samples = Vec::new();
+                    while Instant::now() < deadline {
+                        samples.push(target.send());
+                    }
+                    samples
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap_or_default())
+            .collect()
+    });
+    (samples, started.elapsed())
+}
+
+fn summarize(
+    method: String,
+    url: String,
+    concurrency: usize,
+    samples: &[Sample],
+    elapsed: Duration,
+) -> BenchReport {
+    let requests = samples.len();
+    let errors = samples.iter().filter(|s| s.is_error()).count();
+
+    let mut statuses = BTreeMap::new();
+    for sample in samples {
+        let key = sample
+            .status
+            .map_or_else(|| NO_RESPONSE.to_string(), |status| status.to_string());
+        *statuses.entry(key).or_insert(0) += 1;
+    }
+
+    let mut latencies: Vec<Duration> = samples
+        .iter()
+        .filter(|s| s.status.is_some())
+        .map(|s| s.latency)
+        .collect();
+    latencies.sort();
+    let latency = (!latencies.is_empty()).then(|| LatencySummary {
+        min_ms: millis(latencies[0]),
+        mean_ms: millis(latencies.iter().sum::<Duration>() / latencies.len() as u32),
+        p50_ms: millis(percentile(&latencies, 50.0)),
+        p90_ms: millis(percentile(&latencies, 90.0)),
+        p95_ms: millis(percentile(&latencies, 95.0)),
+        p99_ms: millis(percentile(&latencies, 99.0)),
+        max_ms: millis(latencies[latencies.len() - 1]),
+    });
+
+    let secs = elapsed.as_secs_f64();
+    BenchReport {
+        method,
+        url,
+        concurrency,
+        duration_secs: round2(secs),
+        requests,
+        errors,
+        error_rate: if requests == 0 {
+            0.0
+        } else {
+            errors as f64 / requests as f64
+        },
+        requests_per_sec: if secs > 0.0 {
+            round2(requests as f64 / secs)
+        } else {
+            0.0
+        },
+        latency,
+        statuses,
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty latencies
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn millis(duration: Duration) -> f64 {
+    round2(duration.as_secs_f64() * 1000.0)
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+/// `30s`, `2m`, `500ms`, `1h` or plain seconds (clap value parser)
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let (number, unit) = match value.find(|c: char| !c.is_ascii_digit() && c != '.') {
+        Some(index) => value.split_at(index),
+        None => (value, "s"),
+    };
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{}' (e.g. 30s, 2m, 500ms)", value))?;
+    let secs = match unit {
+        "ms" => number / 1000.0,
+        "s" => number,
+        "m" => number * 60.0,
+        "h" => number * 3600.0,
+        _ => return Err(format!("unknown unit '{}' (use ms, s, m or h)", unit)),
+    };
+    if secs <= 0.0 {
+        return Err("duration must be greater than zero".to_string());
+    }
+    Ok(Duration::from_secs_f64(secs))
+}
+
+fn print(report: &BenchReport) {
+    println!("{}", "─".repeat(50).bright_black());
+    println!(
+        "Requests:  {} in {:.1}s ({} req/s)",
+        report.requests.to_string().bright_white(),
+        report.duration_secs,
+        report.requests_per_sec
+    );
+
+    let error_line = format!("{} ({:.2}%)", report.errors, report.error_rate * 100.0);
+    println!(
+        "Errors:    {}",
+        if report.errors == 0 {
+            error_line.green()
+        } else {
+            error_line.red()
+        }
+    );
+
+    match &report.latency {
+        Some(latency) => {
+            println!("\nLatency:");
+            for (label, ms) in [
+                ("min", latency.min_ms),
+                ("mean", latency.mean_ms),
+                ("p50", latency.p50_ms),
+                ("p90", latency.p90_ms),
+                ("p95", latency.p95_ms),
+                ("p99", latency.p99_ms),
+                ("max", latency.max_ms),
+            ] {
+                println!("  {:<5} {:>10.2} ms", label, ms);
+            }
+        }
+        None => println!("\n{}", "No request got a response".yellow()),
+    }
+
+    println!("\nStatus codes:");
+    for (status, count) in &report.statuses {
+        let label = format!("{:<5}", status);
+        let label = match status.parse::<u16>() {
+            Ok(code) if (200..400).contains(&code) => label.green(),
+            Ok(code) if (400..500).contains(&code) => label.yellow(),
+            _ => label.red(),
+        };
+        println!("  {} {:>10}", label, count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    fn sample(ms: u64, status: Option<u16>) -> Sample {
+        Sample {
+            latency: Duration::from_millis(ms),
+            status,
+        }
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("10"), Ok(Duration::from_secs(10)));
+        assert!(parse_duration("0s").is_err());
+        assert!(parse_duration("5d").is_err());
+        assert!(parse_duration("fast").is_err());
+    }
+
+    #[test]
+    fn test_summarize_percentiles_and_errors() {
+        let mut samples: Vec<Sample> = (1..=100).map(|ms| sample(ms, Some(200))).collect();
+        samples.push(sample(5, Some(503)));
+        samples.push(sample(3000, None));
+
+        let report = summarize(
+            "GET".to_string(),
+            "http://localhost:8000/health".to_string(),
+            4,
+            &samples,
+            Duration::from_secs(2),
+        );
+        assert_eq!(report.requests, 102);
+        assert_eq!(report.errors, 2);
+        assert_eq!(report.requests_per_sec, 51.0);
+        assert_eq!(report.statuses["200"], 100);
+        assert_eq!(report.statuses["503"], 1);
+        assert_eq!(report.statuses[NO_RESPONSE], 1);
+
+        // The request without a response is left out of the latencies
+        let latency = report.latency.unwrap();
+        assert_eq!(latency.min_ms, 1.0);
+        assert_eq!(latency.p50_ms, 50.0);
+        assert_eq!(latency.p99_ms, 99.0);
+        assert_eq!(latency.max_ms, 100.0);
+    }
+
+    #[test]
+    fn test_run_against_local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(&stream);
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).is_ok_and(|n| n > 0) && line != "\r\n" {
+                        line.clear();
+                    }
+                    let _ = (&stream).write_all(
+                        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+                    );
+                });
+            }
+        });
+
+        let target = Target {
+            client: BackendClient::new(&base_url).unwrap(),
+            method: reqwest::Method::GET,
+            route: "/health".to_string(),
+            body: None,
+        };
+        let (samples, elapsed) = run(&target, 2, Duration::from_millis(200));
+        assert!(elapsed >= Duration::from_millis(200));
+        assert!(!samples.is_empty());
+        assert!(samples.iter().all(|s| s.status == Some(200)));
+    }
+}
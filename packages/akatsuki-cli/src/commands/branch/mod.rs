@@ -0,0 +1,336 @@
+/**
+ * Branch Command
+ * git worktree / ブランチごとの独立した開発環境をセットアップ
+ *
+ * `akatsuki branch setup` copies the env files from the main worktree,
+ * links this worktree to the branch's Supabase project (a fixed ref from
+ * akatsuki.toml or a Supabase preview branch), points the env files at it
+ * and pushes migrations + seed data.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::BranchAction;
+use crate::commands::db::branch::{self as db_branch, parent_project_ref, Branches};
+use crate::utils::config::{BranchConfig, ProjectConfig};
+use crate::utils::find_project_root;
+use crate::utils::git::git;
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+
+pub struct BranchCommand {
+    project_root: PathBuf,
+}
+
+/// Supabase project the worktree gets linked to
+struct Target {
+    /// Branch name recorded for `akatsuki db branch`
+    name: String,
+    project_ref: String,
+    /// Credentials from `supabase branches get` (empty for fixed project refs)
+    credentials: BTreeMap<String, String>,
+}
+
+impl BranchCommand {
+    pub fn new() -> Self {
+        Self {
+            project_root: find_project_root(),
+        }
+    }
+
+    pub fn execute(&self, action: BranchAction) -> Result<()> {
+        match action {
+            BranchAction::Setup {
+                from,
+                project_ref,
+                force,
+                no_seed,
+            } => self.setup(from, project_ref, force, no_seed),
+        }
+    }
+
+    fn setup(
+        &self,
+        from: Option<PathBuf>,
+        project_ref: Option<String>,
+        force: bool,
+        no_seed: bool,
+    ) -> Result<()> {
+        let config = ProjectConfig::load()?.branch;
+        let branch = git(&self.project_root, &["rev-parse", "--abbrev-ref", "HEAD"])?;
+        let source = match from {
+            Some(from) => from,
+            None => self.main_worktree()?,
+        };
+
+        println!(
+            "{}",
+            format!("🌿 Setting up environment for branch: {}", branch)
+                .cyan()
+                .bold()
+        );
+        println!();
+
+        // Step 1: env files
+        println!("{}", "📝 Step 1: Env files".cyan().bold());
+        let copied = self.copy_env_files(&config, &source, force)?;
+        println!();
+
+        // Step 2: Supabase project
+        println!("{}", "🔗 Step 2: Supabase project".cyan().bold());
+        // Branches belong to the project the main worktree is linked to
+        let parent = parent_project_ref(&source).or_else(|| parent_project_ref(&self.project_root));
+        let name = supabase_branch_name(&branch);
+        let target = match project_ref.or_else(|| config.projects.get(&branch).cloned()) {
+            Some(project_ref) => Some(Target {
+                name,
+                project_ref,
+                credentials: BTreeMap::new(),
+            }),
+            None if config.supabase_branching => Some(self.preview_branch(name, parent.clone())?),
+            None => None,
+        };
+        let Some(target) = target else {
+            println!(
+                "   {}",
+                "No branch project configured — keeping the current Supabase link".bright_black()
+            );
+            println!(
+                "   {}",
+                "Set [branch] supabase_branching = true or [branch.projects] in akatsuki.toml"
+                    .bright_black()
+            );
+            println!();
+            println!("{}", "✨ Branch environment ready!".green().bold());
+            return Ok(());
+        };
+
+        match &parent {
+            Some(parent) => db_branch::link(
+                &self.project_root,
+                parent,
+                Some((&target.name, &target.project_ref)),
+            )?,
+            None => self
+                .supabase(&["link", "--project-ref", &target.project_ref])
+                .context("supabase link failed")?,
+        }
+        println!("   {} Linked to {}", "✓".green(), target.project_ref);
+
+        if target.credentials.is_empty() {
+            println!(
+                "   {}",
+                "⚠️  Env files keep the copied credentials — update DATABASE_URL / keys for this project"
+                    .yellow()
+            );
+        } else {
+            let values = env_values(&target.credentials);
+            for path in &copied {
+                let content = fs::read_to_string(path)?;
+                fs::write(path, set_env_values(&content, &values))?;
+                println!(
+                    "   {} Pointed {} at the branch project",
+                    "✓".green(),
+                    self.display(path)
+                );
+            }
+        }
+        println!();
+
+        // Step 3: migrations + seed
+        if no_seed || !config.seed {
+            println!("{}", "⏭️  Step 3: Seed skipped".bright_black());
+        } else {
+            println!("{}", "🌱 Step 3: Migrations + seed".cyan().bold());
+            self.supabase(&["db", "push", "--include-seed"])
+                .context("supabase db push --include-seed failed")?;
+            println!(
+                "   {} Migrations and supabase/seed.sql applied",
+                "✓".green()
+            );
+        }
+        println!();
+
+        println!("{}", "✨ Branch environment ready!".green().bold());
+        Ok(())
+    }
+
+    /// Copy the configured env files from `source`; returns the files now present here
+    fn copy_env_files(
+        &self,
+        config: &BranchConfig,
+        source: &Path,
+        force: bool,
+    ) -> Result<Vec<PathBuf>> {
+        let same_checkout =
+            fs::canonicalize(source).ok() == fs::canonicalize(&self.project_root).ok();
+        let mut present = Vec::new();
+
+        for file in &config.env_files {
+            let dest = self.project_root.join(file);
+            let src = source.join(file);
+            if same_checkout || (dest.exists() && !force) {
+                if dest.exists() {
+                    println!("   {} {} (kept)", "•".bright_black(), file);
+                    present.push(dest);
+                }
+                continue;
+            }
+            if !src.exists() {
+                println!(
+                    "   {} {} not found in {}",
+                    "⚠️".yellow(),
+                    file,
+                    source.display()
+                );
+                continue;
+            }
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(&src, &dest).with_context(|| format!("Failed to copy {}", src.display()))?;
+            println!("   {} {}", "✓".green(), file);
+            present.push(dest);
+        }
+
+        if same_checkout {
+            println!(
+                "   {}",
+                "This is the main worktree — nothing to copy (use --from <path>)".bright_black()
+            );
+        }
+        Ok(present)
+    }
+
+    /// Create (if needed) and describe the Supabase preview branch
+    fn preview_branch(&self, name: String, parent: Option<String>) -> Result<Target> {
+        let branches = Branches::with_parent(&self.project_root, parent);
+        let credentials = match branches.get(&name) {
+            Ok(credentials) => credentials,
+            Err(_) => {
+                println!("   Creating Supabase preview branch {}...", name.bold());
+                branches.create(&name)?;
+                branches.get(&name)?
+            }
+        };
+        println!(
+            "   {} Preview branch {} ({})",
+            "✓".green(),
+            name,
+            credentials.project_ref
+        );
+        Ok(Target {
+            name,
+            project_ref: credentials.project_ref,
+            credentials: credentials.values,
+        })
+    }
+
+    /// Path of the main worktree (first entry of `git worktree list`)
+    fn main_worktree(&self) -> Result<PathBuf> {
+        git(&self.project_root, &["worktree", "list", "--porcelain"])?
+            .lines()
+            .find_map(|line| line.strip_prefix("worktree "))
+            .map(PathBuf::from)
+            .context("Could not find the main worktree (use --from <path>)")
+    }
+
+    fn supabase(&self, args: &[&str]) -> Result<()> {
+        let status = platform::command("supabase")
+            .args(args)
+            .current_dir(&self.project_root)
+            .timed_status()
+            .context("Failed to run supabase. Make sure Supabase CLI is installed.")?;
+        if !status.success() {
+            anyhow::bail!("supabase {} failed", args.join(" "));
+        }
+        Ok(())
+    }
+
+    fn display(&self, path: &Path) -> String {
+        path.strip_prefix(&self.project_root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+}
+
+/// Supabase branch names can't contain `/` (feature/billing -> feature-billing)
+fn supabase_branch_name(branch: &str) -> String {
+    branch
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Frontend / backend env keys filled from the branch credentials
+fn env_values(credentials: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    let mut values = BTreeMap::new();
+    for (key, source) in [
+        ("VITE_SUPABASE_URL", "SUPABASE_URL"),
+        ("VITE_SUPABASE_ANON_KEY", "SUPABASE_ANON_KEY"),
+        ("SUPABASE_URL", "SUPABASE_URL"),
+        ("SUPABASE_ANON_KEY", "SUPABASE_ANON_KEY"),
+        ("DATABASE_URL", "POSTGRES_URL"),
+    ] {
+        if let Some(value) = credentials.get(source) {
+            values.insert(key.to_string(), value.clone());
+        }
+    }
+    values
+}
+
+/// Replace the values of keys already present in an env file (other lines are kept as-is)
+fn set_env_values(content: &str, values: &BTreeMap<String, String>) -> String {
+    let mut out: String = content
+        .lines()
+        .map(|line| match line.split_once('=') {
+            Some((key, _)) if values.contains_key(key.trim()) => {
+                format!("{}={}", key.trim(), values[key.trim()])
+            }
+            _ => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    if content.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_env_values() {
+        let credentials = db_branch::parse_env(
+            "POSTGRES_URL=\"postgresql://postgres.abc:pw@aws-0.pooler.supabase.com:6543/postgres\"\n\
+             SUPABASE_URL=\"https://abcdefghijklmnop.supabase.co\"\n\
+             SUPABASE_ANON_KEY=\"anon\"\n",
+        );
+
+        let env = "# Supabase Configuration\nVITE_SUPABASE_URL=https://old.supabase.co\n\
+                   VITE_SUPABASE_ANON_KEY=old\nVITE_API_BASE_URL=http://localhost:8000\n";
+        assert_eq!(
+            set_env_values(env, &env_values(&credentials)),
+            "# Supabase Configuration\nVITE_SUPABASE_URL=https://abcdefghijklmnop.supabase.co\n\
+             VITE_SUPABASE_ANON_KEY=anon\nVITE_API_BASE_URL=http://localhost:8000\n"
+        );
+    }
+
+    #[test]
+    fn test_supabase_branch_name() {
+        assert_eq!(supabase_branch_name("feature/billing"), "feature-billing");
+        assert_eq!(supabase_branch_name("fix_login"), "fix_login");
+    }
+}
@@ -3,6 +3,8 @@ use colored::Colorize;
 use std::process::Command;
 
 use crate::cli::BuildTarget;
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
 
 pub struct BuildCommand;
 
@@ -22,9 +24,9 @@ impl BuildCommand {
     fn build_frontend(&self) -> Result<()> {
         println!("{}", "🏗️  Building frontend...".cyan());
 
-        let status = Command::new("npm")
+        let status = platform::command("npm")
             .args(["run", "build", "--workspace=app-frontend"])
-            .status()
+            .timed_status()
             .context("Failed to build frontend")?;
 
         if !status.success() {
@@ -41,7 +43,7 @@ impl BuildCommand {
         let status = Command::new("cargo")
             .args(["build", "--release"])
             .current_dir("packages/app-backend")
-            .status()
+            .timed_status()
             .context("Failed to build backend")?;
 
         if !status.success() {
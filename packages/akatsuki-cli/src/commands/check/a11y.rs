@@ -0,0 +1,337 @@
+/**
+ * Accessibility Audit
+ * Runs axe-core (via `npx @axe-core/cli`, headless Chrome) against the
+ * `[a11y]` routes of akatsuki.toml and the admin pages wired into App.tsx
+ *
+ * Violations are summarized by impact; the check fails when more than
+ * `max_violations` reach `fail_on`. The raw axe results are written to
+ * .akatsuki/a11y/results.json.
+ */
+use anyhow::{Context, Result};
+use colored::{ColoredString, Colorize};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::commands::api;
+use crate::commands::design::screenshot::{self, DevServer};
+use crate::utils::config::{A11yConfig, ProjectConfig, CONFIG_FILE};
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+
+const OUTPUT_DIR: &str = ".akatsuki/a11y";
+const RESULTS_FILE: &str = "results.json";
+const DEV_SERVER_LOG: &str = "dev-server.log";
+const AXE_CLI: &str = "@axe-core/cli@4";
+/// Time for the SPA to render after the page load event
+const LOAD_DELAY_MS: &str = "1000";
+
+/// axe-core impact levels, least severe first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Impact {
+    Minor,
+    Moderate,
+    Serious,
+    Critical,
+}
+
+impl Impact {
+    const ALL: [Impact; 4] = [
+        Impact::Critical,
+        Impact::Serious,
+        Impact::Moderate,
+        Impact::Minor,
+    ];
+
+    fn parse(value: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|impact| impact.as_str() == value)
+            .with_context(|| {
+                format!(
+                    "Unknown fail_on '{}' in [a11y] of {} (use minor, moderate, serious or critical)",
+                    value, CONFIG_FILE
+                )
+            })
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Impact::Minor => "minor",
+            Impact::Moderate => "moderate",
+            Impact::Serious => "serious",
+            Impact::Critical => "critical",
+        }
+    }
+
+    fn label(self) -> ColoredString {
+        let label = format!("{:<8}", self.as_str());
+        match self {
+            Impact::Critical => label.red().bold(),
+            Impact::Serious => label.red(),
+            Impact::Moderate => label.yellow(),
+            Impact::Minor => label.bright_black(),
+        }
+    }
+}
+
+/// One page of the `axe --stdout` output
+#[derive(Debug, Deserialize)]
+struct PageResult {
+    url: String,
+    violations: Vec<Violation>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Violation {
+    id: String,
+    /// Missing for rules axe cannot rate; counted as minor
+    impact: Option<Impact>,
+    help: String,
+    help_url: String,
+    nodes: Vec<serde_json::Value>,
+}
+
+impl Violation {
+    fn impact(&self) -> Impact {
+        self.impact.unwrap_or(Impact::Minor)
+    }
+}
+
+pub fn check_a11y(project_root: &Path) -> Result<()> {
+    println!("{}", "♿ Checking accessibility (axe-core)...".cyan());
+
+    let config = ProjectConfig::load()?.a11y;
+    let fail_on = Impact::parse(&config.fail_on)?;
+    let routes = routes(&config, &api::admin_routes(project_root));
+    if routes.is_empty() {
+        anyhow::bail!(
+            "No routes to audit: set routes in [a11y] of {}",
+            CONFIG_FILE
+        );
+    }
+    if let Some(route) = routes.iter().find(|route| !route.starts_with('/')) {
+        anyhow::bail!("a11y route must start with / (got {})", route);
+    }
+
+    let output = project_root.join(OUTPUT_DIR);
+    fs::create_dir_all(&output)?;
+
+    let base_url = config
+        .base_url
+        .clone()
+        .unwrap_or_else(|| screenshot::DEFAULT_BASE_URL.to_string());
+    let server = DevServer::ensure(
+        project_root,
+        &base_url,
+        &output.join(DEV_SERVER_LOG),
+        "a11y",
+        config.base_url.is_none(),
+    )?;
+    let urls: Vec<String> = routes
+        .iter()
+        .map(|route| format!("{}{}", base_url.trim_end_matches('/'), route))
+        .collect();
+    let raw = run_axe(project_root, &urls);
+    drop(server);
+    let raw = raw?;
+
+    let results_path = output.join(RESULTS_FILE);
+    fs::write(&results_path, json_array(&raw))
+        .with_context(|| format!("Failed to write {}", results_path.display()))?;
+    let pages = parse_results(&raw)?;
+
+    for (route, page) in routes.iter().zip(&pages) {
+        print_page(route, page);
+    }
+
+    let counts = count_by_impact(&pages);
+    println!(
+        "\n  {} {}",
+        "Summary:".bright_white(),
+        Impact::ALL
+            .iter()
+            .map(|impact| format!("{} {}", impact.as_str(), counts.get(impact).unwrap_or(&0)))
+            .collect::<Vec<_>>()
+            .join(" · ")
+    );
+    println!("  {} {}", "Results:".bright_white(), results_path.display());
+
+    let failing = failing_count(&counts, fail_on);
+    if failing > config.max_violations {
+        anyhow::bail!(
+            "{} accessibility violation(s) at {} or above (max_violations = {})",
+            failing,
+            fail_on.as_str(),
+            config.max_violations
+        );
+    }
+
+    println!("{}", "✅ Accessibility check passed!".green());
+    Ok(())
+}
+
+/// Configured routes followed by the wired admin pages, without duplicates
+fn routes(config: &A11yConfig, admin_routes: &[String]) -> Vec<String> {
+    let mut routes: Vec<String> = Vec::new();
+    let admin = admin_routes.iter().filter(|_| config.admin_pages);
+    for route in config.routes.iter().chain(admin) {
+        if !routes.contains(route) {
+            routes.push(route.clone());
+        }
+    }
+    routes
+}
+
+/// JSON results of `axe <urls> --stdout`
+fn run_axe(root: &Path, urls: &[String]) -> Result<String> {
+    let output = platform::command("npx")
+        .args(["--yes", AXE_CLI])
+        .args(urls)
+        .args(["--stdout", "--load-delay", LOAD_DELAY_MS])
+        .current_dir(root)
+        .timed_output()
+        .context("Failed to run npx @axe-core/cli")?;
+    if !output.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        anyhow::bail!("axe-core failed (it needs Chrome or Chromium and a matching chromedriver)");
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// npx may print install notices before the JSON
+fn json_array(raw: &str) -> &str {
+    raw.find('[').map(|start| &raw[start..]).unwrap_or(raw)
+}
+
+fn parse_results(raw: &str) -> Result<Vec<PageResult>> {
+    serde_json::from_str(json_array(raw)).context("Could not parse the axe-core results")
+}
+
+fn print_page(route: &str, page: &PageResult) {
+    if page.violations.is_empty() {
+        println!(
+            "  {} {:<30} {}",
+            "✓".green(),
+            route,
+            "no violations".bright_black()
+        );
+        return;
+    }
+    let mut violations: Vec<&Violation> = page.violations.iter().collect();
+    violations.sort_by_key(|violation| std::cmp::Reverse(violation.impact()));
+
+    println!(
+        "  {} {:<30} {} violation(s) {}",
+        "✗".red(),
+        route,
+        violations.len(),
+        page.url.bright_black()
+    );
+    for violation in violations {
+        println!(
+            "      {} {} - {} ({} node(s))",
+            violation.impact().label(),
+            violation.id.bright_white(),
+            violation.help,
+            violation.nodes.len()
+        );
+        println!("               {}", violation.help_url.bright_black());
+    }
+}
+
+/// Violated rules per impact (a rule counts once per page)
+fn count_by_impact(pages: &[PageResult]) -> BTreeMap<Impact, usize> {
+    let mut counts = BTreeMap::new();
+    for violation in pages.iter().flat_map(|page| &page.violations) {
+        *counts.entry(violation.impact()).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn failing_count(counts: &BTreeMap<Impact, usize>, fail_on: Impact) -> usize {
+    counts.range(fail_on..).map(|(_, count)| count).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESULTS: &str = r#"npm warn exec The following package was not found and will be installed: @axe-core/cli@4.10.1
+[
+  {
+    "url": "http://localhost:5173/",
+    "violations": [
+      {
+        "id": "color-contrast",
+        "impact": "serious",
+        "help": "Elements must meet minimum color contrast ratio thresholds",
+        "helpUrl": "https://dequeuniversity.com/rules/axe/4.10/color-contrast",
+        "nodes": [{ "target": [".btn"] }, { "target": [".link"] }]
+      },
+      {
+        "id": "region",
+        "impact": "moderate",
+        "help": "All page content should be contained by landmarks",
+        "helpUrl": "https://dequeuniversity.com/rules/axe/4.10/region",
+        "nodes": [{ "target": ["p"] }]
+      }
+    ]
+  },
+  {
+    "url": "http://localhost:5173/admin/articles",
+    "violations": [
+      {
+        "id": "button-name",
+        "impact": "critical",
+        "help": "Buttons must have discernible text",
+        "helpUrl": "https://dequeuniversity.com/rules/axe/4.10/button-name",
+        "nodes": [{ "target": ["button"] }]
+      },
+      {
+        "id": "unrated",
+        "impact": null,
+        "help": "Unrated rule",
+        "helpUrl": "https://example.com",
+        "nodes": []
+      }
+    ]
+  }
+]"#;
+
+    #[test]
+    fn test_counts_by_impact() {
+        let pages = parse_results(RESULTS).unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[1].url, "http://localhost:5173/admin/articles");
+
+        let counts = count_by_impact(&pages);
+        assert_eq!(counts[&Impact::Critical], 1);
+        assert_eq!(counts[&Impact::Serious], 1);
+        assert_eq!(counts[&Impact::Moderate], 1);
+        assert_eq!(counts[&Impact::Minor], 1);
+        assert_eq!(failing_count(&counts, Impact::Serious), 2);
+        assert_eq!(failing_count(&counts, Impact::Minor), 4);
+        assert_eq!(failing_count(&BTreeMap::new(), Impact::Minor), 0);
+    }
+
+    #[test]
+    fn test_parse_impact() {
+        assert_eq!(Impact::parse("moderate").unwrap(), Impact::Moderate);
+        assert!(Impact::parse("high").is_err());
+        assert!(Impact::Critical > Impact::Serious);
+    }
+
+    #[test]
+    fn test_routes_include_admin_pages() {
+        let admin = vec!["/admin/articles".to_string(), "/".to_string()];
+        let mut config = A11yConfig::default();
+        assert_eq!(routes(&config, &admin), ["/", "/admin/articles"]);
+
+        config.admin_pages = false;
+        assert_eq!(routes(&config, &admin), ["/"]);
+    }
+}
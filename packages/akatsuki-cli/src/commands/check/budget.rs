@@ -0,0 +1,366 @@
+/**
+ * Bundle Budget Check
+ * Runs the frontend production build and compares Vite's reported asset sizes
+ * against the `[budgets]` section of akatsuki.toml
+ *
+ * The sizes of the last passing run are recorded in .akatsuki/budgets.json
+ * so a failure shows what grew since then.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::utils::config::{parse_size, BudgetConfig, ProjectConfig, CONFIG_FILE};
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+
+pub const RECORD_PATH: &str = ".akatsuki/budgets.json";
+
+/// Sizes recorded after the last passing budget check
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BudgetRecord {
+    pub recorded_at: String,
+    pub build_seconds: f64,
+    pub total: u64,
+    pub chunks: BTreeMap<String, u64>,
+}
+
+/// One row of the comparison table
+#[derive(Debug, PartialEq)]
+struct BudgetLine {
+    name: String,
+    size: u64,
+    previous: Option<u64>,
+    budget: Option<u64>,
+}
+
+impl BudgetLine {
+    fn exceeded(&self) -> bool {
+        self.budget.is_some_and(|budget| self.size > budget)
+    }
+}
+
+pub fn check_budget(project_root: &Path) -> Result<()> {
+    println!("{}", "📏 Checking bundle budgets...".cyan());
+
+    let budgets = ProjectConfig::load()?.budgets;
+    if budgets.total.is_none() && budgets.build_seconds.is_none() && budgets.chunks.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "  ⚠️  No [budgets] in {} - sizes are reported only",
+                CONFIG_FILE
+            )
+            .yellow()
+        );
+    }
+
+    let started = Instant::now();
+    let output = platform::command("npm")
+        .args(["run", "build", "--workspace=app-frontend"])
+        .current_dir(project_root)
+        .timed_output()
+        .context("Failed to run frontend build")?;
+    let build_seconds = started.elapsed().as_secs_f64();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        print!("{}", stdout);
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        anyhow::bail!("Frontend build failed");
+    }
+
+    let chunks = parse_vite_output(&stdout);
+    if chunks.is_empty() {
+        anyhow::bail!("Could not find asset sizes in the Vite build output");
+    }
+
+    let record_path = project_root.join(RECORD_PATH);
+    let previous: Option<BudgetRecord> = std::fs::read_to_string(&record_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok());
+
+    let (lines, total) = compare(&chunks, &budgets, previous.as_ref())?;
+    print_lines(&lines, &total);
+
+    let time_budget = budgets.build_seconds;
+    let time_exceeded = time_budget.is_some_and(|limit| build_seconds > limit);
+    println!(
+        "\n  {} {:.1}s{}{}",
+        "⏱️  Build time:".bright_white(),
+        build_seconds,
+        previous
+            .as_ref()
+            .map(|p| format!(" (previous {:.1}s)", p.build_seconds))
+            .unwrap_or_default()
+            .bright_black(),
+        time_budget
+            .map(|limit| format!(" / budget {:.0}s", limit))
+            .unwrap_or_default()
+    );
+
+    let exceeded: Vec<&BudgetLine> = lines
+        .iter()
+        .chain(std::iter::once(&total))
+        .filter(|line| line.exceeded())
+        .collect();
+    if !exceeded.is_empty() || time_exceeded {
+        println!();
+        for line in &exceeded {
+            println!(
+                "  {} {} is {} over budget",
+                "✗".red(),
+                line.name.bright_white(),
+                format_size(line.size - line.budget.unwrap_or_default()).red()
+            );
+        }
+        if time_exceeded {
+            println!("  {} build time is over budget", "✗".red());
+        }
+        anyhow::bail!(
+            "Budget exceeded (sizes in {} were not updated)",
+            RECORD_PATH
+        );
+    }
+
+    let record = BudgetRecord {
+        recorded_at: chrono::Utc::now().to_rfc3339(),
+        build_seconds,
+        total: total.size,
+        chunks,
+    };
+    if let Some(parent) = record_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&record_path, serde_json::to_string_pretty(&record)? + "\n")
+        .with_context(|| format!("Failed to write {}", record_path.display()))?;
+
+    println!("{}", "✅ Bundle budgets passed!".green());
+    println!("  {} {}", "Recorded sizes in".bright_black(), RECORD_PATH);
+    Ok(())
+}
+
+/// Chunk sizes (bytes) from `vite build` output, keyed by chunk name without the hash.
+///
+/// Only JS and CSS assets count toward budgets; chunks sharing a name are summed.
+fn parse_vite_output(output: &str) -> BTreeMap<String, u64> {
+    let mut chunks = BTreeMap::new();
+    for line in output.lines().map(strip_ansi) {
+        // dist/assets/index-DiwrgTda.js   143.36 kB │ gzip: 46.09 kB
+        let mut fields = line.split_whitespace();
+        let Some(file) = fields.next() else {
+            continue;
+        };
+        if !(file.ends_with(".js") || file.ends_with(".css")) || !file.contains('/') {
+            continue;
+        }
+        let (Some(number), Some(unit)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        let Ok(size) = parse_size(&format!("{}{}", number.replace(',', ""), unit)) else {
+            continue;
+        };
+        *chunks.entry(chunk_name(file)).or_insert(0) += size;
+    }
+    chunks
+}
+
+/// `dist/assets/index-DiwrgTda.js` → `index.js`
+fn chunk_name(file: &str) -> String {
+    let file_name = file.rsplit('/').next().unwrap_or(file);
+    let (stem, extension) = file_name.rsplit_once('.').unwrap_or((file_name, ""));
+    let stem = match stem.rsplit_once('-') {
+        // Vite content hashes are 8 base64url characters
+        Some((name, hash))
+            if !name.is_empty()
+                && hash.len() == 8
+                && hash.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') =>
+        {
+            name
+        }
+        _ => stem,
+    };
+    format!("{}.{}", stem, extension)
+}
+
+fn strip_ansi(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // CSI sequence: ESC [ ... final byte (@ through ~)
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) && c != '[' {
+                    break;
+                }
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn compare(
+    chunks: &BTreeMap<String, u64>,
+    budgets: &BudgetConfig,
+    previous: Option<&BudgetRecord>,
+) -> Result<(Vec<BudgetLine>, BudgetLine)> {
+    let parse_budget = |name: &str, value: &str| {
+        parse_size(value).with_context(|| format!("Invalid budget for '{}'", name))
+    };
+
+    let mut lines = Vec::new();
+    for (name, &size) in chunks {
+        lines.push(BudgetLine {
+            name: name.clone(),
+            size,
+            previous: previous.and_then(|p| p.chunks.get(name).copied()),
+            budget: budgets
+                .chunks
+                .get(name)
+                .map(|value| parse_budget(name, value))
+                .transpose()?,
+        });
+    }
+
+    for name in budgets
+        .chunks
+        .keys()
+        .filter(|name| !chunks.contains_key(*name))
+    {
+        println!(
+            "{}",
+            format!(
+                "  ⚠️  Budgeted chunk '{}' was not emitted by the build",
+                name
+            )
+            .yellow()
+        );
+    }
+
+    let total = BudgetLine {
+        name: "total".to_string(),
+        size: chunks.values().sum(),
+        previous: previous.map(|p| p.total),
+        budget: budgets
+            .total
+            .as_deref()
+            .map(|value| parse_budget("total", value))
+            .transpose()?,
+    };
+    Ok((lines, total))
+}
+
+fn print_lines(lines: &[BudgetLine], total: &BudgetLine) {
+    let width = lines
+        .iter()
+        .map(|line| line.name.len())
+        .max()
+        .unwrap_or(0)
+        .max("total".len());
+
+    println!(
+        "\n  {}",
+        format!(
+            "{:<width$}  {:>10}  {:>10}  {:>10}  {:>10}",
+            "CHUNK",
+            "SIZE",
+            "PREVIOUS",
+            "DIFF",
+            "BUDGET",
+            width = width
+        )
+        .bold()
+    );
+    for line in lines.iter().chain(std::iter::once(total)) {
+        let diff = match line.previous {
+            Some(previous) if previous == line.size => "±0".bright_black(),
+            Some(previous) if line.size > previous => {
+                format!("+{}", format_size(line.size - previous)).yellow()
+            }
+            Some(previous) => format!("-{}", format_size(previous - line.size)).green(),
+            None => "new".bright_black(),
+        };
+        let status = if line.exceeded() {
+            "✗".red()
+        } else if line.budget.is_some() {
+            "✓".green()
+        } else {
+            " ".normal()
+        };
+        println!(
+            "{} {:<width$}  {:>10}  {:>10}  {:>10}  {:>10}",
+            status,
+            line.name,
+            format_size(line.size),
+            line.previous
+                .map(format_size)
+                .unwrap_or_else(|| "-".to_string()),
+            diff,
+            line.budget
+                .map(format_size)
+                .unwrap_or_else(|| "-".to_string()),
+            width = width
+        );
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    format!("{:.2} kB", bytes as f64 / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VITE_OUTPUT: &str = "\
+vite v6.0.0 building for production...
+✓ 1712 modules transformed.
+dist/index.html                   0.46 kB │ gzip:  0.30 kB
+dist/assets/index-BvGv9QtR.css   10.34 kB │ gzip:  2.72 kB
+\u{1b}[2mdist/\u{1b}[22m\u{1b}[36massets/index-DiwrgTda.js  \u{1b}[39m\u{1b}[1m\u{1b}[33m1,143.36 kB\u{1b}[39m\u{1b}[22m
+dist/assets/vendor-react-a_b3C9xZ.js   42.10 kB │ gzip: 14.00 kB
+✓ built in 3.21s
+";
+
+    #[test]
+    fn test_parse_vite_output() {
+        let chunks = parse_vite_output(VITE_OUTPUT);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks["index.css"], 10_340);
+        assert_eq!(chunks["index.js"], 1_143_360);
+        assert_eq!(chunks["vendor-react.js"], 42_100);
+    }
+
+    #[test]
+    fn test_chunk_name() {
+        assert_eq!(chunk_name("dist/assets/index-DiwrgTda.js"), "index.js");
+        assert_eq!(chunk_name("dist/assets/my-page.js"), "my-page.js");
+    }
+
+    #[test]
+    fn test_compare_budgets() {
+        let chunks = parse_vite_output(VITE_OUTPUT);
+        let budgets = ProjectConfig::parse(
+            "[budgets]\ntotal = \"2 MB\"\n[budgets.chunks]\n\"index.js\" = \"1 MB\"\n",
+        )
+        .unwrap()
+        .budgets;
+        let previous = BudgetRecord {
+            total: 1_000_000,
+            chunks: BTreeMap::from([("index.js".to_string(), 900_000)]),
+            ..Default::default()
+        };
+
+        let (lines, total) = compare(&chunks, &budgets, Some(&previous)).unwrap();
+        let index = lines.iter().find(|line| line.name == "index.js").unwrap();
+        assert!(index.exceeded());
+        assert_eq!(index.previous, Some(900_000));
+        assert!(!total.exceeded());
+        assert_eq!(total.size, 1_195_800);
+    }
+}
@@ -3,8 +3,14 @@ use colored::Colorize;
 use std::process::Command;
 
 use crate::cli::CheckTarget;
-use crate::utils::find_project_root;
-
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+use crate::utils::{
+    cargo_package_command, find_project_root, CargoWorkspace, ADMIN_CLI_PACKAGE, BACKEND_PACKAGE,
+};
+
+pub mod a11y;
+pub mod budget;
 pub mod navigation;
 
 pub struct CheckCommand;
@@ -20,6 +26,8 @@ impl CheckCommand {
             CheckTarget::Backend => self.check_backend(),
             CheckTarget::Cli => self.check_cli(),
             CheckTarget::AdminCli => self.check_admin_cli(),
+            CheckTarget::Budget => budget::check_budget(&find_project_root()),
+            CheckTarget::A11y => a11y::check_a11y(&find_project_root()),
             CheckTarget::All => self.check_all(),
         }
     }
@@ -27,9 +35,9 @@ impl CheckCommand {
     fn check_frontend(&self) -> Result<()> {
         println!("{}", "🔍 Checking frontend (typecheck)...".cyan());
 
-        let status = Command::new("npm")
+        let status = platform::command("npm")
             .args(["run", "typecheck", "--workspace=app-frontend"])
-            .status()
+            .timed_status()
             .context("Failed to run typecheck")?;
 
         if !status.success() {
@@ -43,11 +51,8 @@ impl CheckCommand {
     fn check_backend(&self) -> Result<()> {
         println!("{}", "🦀 Checking backend (cargo check)...".cyan());
 
-        let project_root = find_project_root();
-        let status = Command::new("cargo")
-            .args(["check"])
-            .current_dir(project_root.join("packages/app-backend"))
-            .status()
+        let status = cargo_package_command(&find_project_root(), BACKEND_PACKAGE, "check")
+            .timed_status()
             .context("Failed to run cargo check")?;
 
         if !status.success() {
@@ -61,9 +66,9 @@ impl CheckCommand {
     fn check_cli(&self) -> Result<()> {
         println!("{}", "📟 Checking CLI (typecheck)...".cyan());
 
-        let status = Command::new("npm")
+        let status = platform::command("npm")
             .args(["run", "typecheck", "--workspace=app-cli"])
-            .status()
+            .timed_status()
             .context("Failed to run typecheck")?;
 
         if !status.success() {
@@ -77,11 +82,8 @@ impl CheckCommand {
     fn check_admin_cli(&self) -> Result<()> {
         println!("{}", "🦀 Checking admin-cli (cargo check)...".cyan());
 
-        let project_root = find_project_root();
-        let status = Command::new("cargo")
-            .args(["check"])
-            .current_dir(project_root.join("packages/akatsuki-cli"))
-            .status()
+        let status = cargo_package_command(&find_project_root(), ADMIN_CLI_PACKAGE, "check")
+            .timed_status()
             .context("Failed to run cargo check")?;
 
         if !status.success() {
@@ -101,13 +103,47 @@ impl CheckCommand {
         self.check_cli()?;
         println!();
 
-        self.check_backend()?;
-        println!();
+        match CargoWorkspace::detect(&find_project_root()) {
+            Some(workspace) => self.check_workspace(&workspace)?,
+            None => {
+                self.check_backend()?;
+                println!();
 
-        self.check_admin_cli()?;
+                self.check_admin_cli()?;
+            }
+        }
         println!();
 
         println!("{}", "✨ All type checks passed!".green().bold());
         Ok(())
     }
+
+    /// One `cargo check --workspace` (shared target dir), plus non-member packages
+    fn check_workspace(&self, workspace: &CargoWorkspace) -> Result<()> {
+        println!(
+            "{}",
+            "🦀 Checking cargo workspace (cargo check --workspace)...".cyan()
+        );
+
+        let status = Command::new("cargo")
+            .args(["check", "--workspace"])
+            .current_dir(&workspace.root)
+            .timed_status()
+            .context("Failed to run cargo check")?;
+
+        if !status.success() {
+            anyhow::bail!("Workspace check failed");
+        }
+        println!("{}", "✅ Workspace check passed!".green());
+
+        if !workspace.contains(BACKEND_PACKAGE) {
+            println!();
+            self.check_backend()?;
+        }
+        if !workspace.contains(ADMIN_CLI_PACKAGE) {
+            println!();
+            self.check_admin_cli()?;
+        }
+        Ok(())
+    }
 }
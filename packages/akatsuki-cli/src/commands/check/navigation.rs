@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
 use regex::Regex;
+use serde::Serialize;
 use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
@@ -58,15 +59,31 @@ pub fn check_navigation_consistency(project_root: &Path) -> Result<bool> {
     Ok(!has_errors)
 }
 
+/// `<Route path="..." element={<Component />} />` declared in the app router
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AppRoute {
+    pub path: String,
+    /// Rendered component (`None` for non-component elements)
+    pub element: Option<String>,
+}
+
 /// Extract route paths from App.jsx
 fn extract_routes(app_jsx: &Path) -> Result<Vec<String>> {
     let content = fs::read_to_string(app_jsx).context("Failed to read App.jsx")?;
+    Ok(parse_routes(&content)
+        .into_iter()
+        .map(|route| route.path)
+        .collect())
+}
 
+/// Parse `<Route path=...>` declarations, honoring `akatsuki-ignore navigation` comments
+pub fn parse_routes(content: &str) -> Vec<AppRoute> {
     let mut routes = Vec::new();
     let mut skip_next = false;
 
     // Match: <Route path="/something" element={...} />
-    let route_re = Regex::new(r#"<Route\s+path="(/[^"]+)""#).unwrap();
+    let route_re = Regex::new(r#"<Route\s+path="(/[^"]*)""#).unwrap();
+    let element_re = Regex::new(r#"element=\{\s*<(\w+)"#).unwrap();
 
     for line in content.lines() {
         // Check for akatsuki-ignore on same line
@@ -89,11 +106,14 @@ fn extract_routes(app_jsx: &Path) -> Result<Vec<String>> {
 
         if let Some(captures) = route_re.captures(line) {
             let path = captures.get(1).unwrap().as_str().to_string();
-            routes.push(path);
+            let element = element_re
+                .captures(line)
+                .map(|c| c.get(1).unwrap().as_str().to_string());
+            routes.push(AppRoute { path, element });
         }
     }
 
-    Ok(routes)
+    routes
 }
 
 /// Extract navigation links from TopNavigation.tsx
@@ -0,0 +1,274 @@
+/**
+ * Database Branches
+ * Supabase Branching のラッパー（使い捨てのデータベースブランチでスキーマを試す）
+ *
+ * `supabase branches ...` always runs against the parent (production)
+ * project. Once a checkout is switched to a branch, `supabase link` points at
+ * the branch, so the parent ref is remembered in .akatsuki/db-branch.json.
+ */
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+
+/// Written by `supabase link`
+const PROJECT_REF_FILE: &str = "supabase/.temp/project-ref";
+const STATE_FILE: &str = ".akatsuki/db-branch.json";
+
+/// Which branch a checkout is linked to
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BranchState {
+    /// Project the branches belong to
+    pub parent: String,
+    /// Branch currently linked (None when linked to the parent)
+    pub current: Option<String>,
+}
+
+impl BranchState {
+    pub fn load(project_root: &Path) -> Option<Self> {
+        let content = fs::read_to_string(project_root.join(STATE_FILE)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn save(&self, project_root: &Path) -> Result<()> {
+        let path = project_root.join(STATE_FILE);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// `db branch list --output json` result
+#[derive(Debug, Serialize)]
+pub struct BranchList {
+    /// Branch this checkout is linked to
+    pub current: Option<String>,
+    /// `supabase branches list -o json` as-is
+    pub branches: serde_json::Value,
+}
+
+/// Connection details of a branch (`supabase branches get <name> -o env`)
+pub struct BranchCredentials {
+    pub project_ref: String,
+    pub values: BTreeMap<String, String>,
+}
+
+impl BranchCredentials {
+    /// Direct connection for migrations (falls back to the pooler URL)
+    pub fn db_url(&self) -> Option<&str> {
+        self.values
+            .get("POSTGRES_URL_NON_POOLING")
+            .or_else(|| self.values.get("POSTGRES_URL"))
+            .map(String::as_str)
+    }
+}
+
+/// `supabase branches` scoped to the parent project
+pub struct Branches {
+    project_root: PathBuf,
+    parent: Option<String>,
+}
+
+impl Branches {
+    pub fn new(project_root: &Path) -> Self {
+        Self::with_parent(project_root, parent_project_ref(project_root))
+    }
+
+    pub fn with_parent(project_root: &Path, parent: Option<String>) -> Self {
+        Self {
+            project_root: project_root.to_path_buf(),
+            parent,
+        }
+    }
+
+    pub fn parent(&self) -> Option<&str> {
+        self.parent.as_deref()
+    }
+
+    pub fn list(&self) -> Result<()> {
+        self.run(&["branches", "list"])
+    }
+
+    /// `supabase branches list -o json`, passed through as-is
+    pub fn list_json(&self) -> Result<serde_json::Value> {
+        let args = self.scoped(&["branches", "list", "-o", "json"]);
+        let output = platform::command("supabase")
+            .args(&args)
+            .current_dir(&self.project_root)
+            .timed_output()
+            .context("Failed to run supabase. Make sure Supabase CLI is installed.")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "supabase branches list failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        serde_json::from_slice(&output.stdout).context("Invalid `supabase branches list` output")
+    }
+
+    pub fn create(&self, name: &str) -> Result<()> {
+        self.run(&["branches", "create", name])
+            .context("supabase branches create failed (branching requires a paid plan)")
+    }
+
+    pub fn delete(&self, name: &str) -> Result<()> {
+        self.run(&["branches", "delete", name])
+    }
+
+    pub fn get(&self, name: &str) -> Result<BranchCredentials> {
+        let args = self.scoped(&["branches", "get", name, "-o", "env"]);
+        let output = platform::command("supabase")
+            .args(&args)
+            .current_dir(&self.project_root)
+            .timed_output()
+            .context("Failed to run supabase. Make sure Supabase CLI is installed.")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "supabase branches get {} failed: {}",
+                name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        let values = parse_env(&String::from_utf8_lossy(&output.stdout));
+        let project_ref = values
+            .get("SUPABASE_URL")
+            .and_then(|url| project_ref_from_url(url))
+            .with_context(|| format!("No SUPABASE_URL in `supabase branches get {}`", name))?;
+        Ok(BranchCredentials {
+            project_ref,
+            values,
+        })
+    }
+
+    fn run(&self, args: &[&str]) -> Result<()> {
+        let args = self.scoped(args);
+        let status = platform::command("supabase")
+            .args(&args)
+            .current_dir(&self.project_root)
+            .timed_status()
+            .context("Failed to run supabase. Make sure Supabase CLI is installed.")?;
+        if !status.success() {
+            anyhow::bail!("supabase {} failed", args.join(" "));
+        }
+        Ok(())
+    }
+
+    fn scoped(&self, args: &[&str]) -> Vec<String> {
+        let mut args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        if let Some(parent) = &self.parent {
+            args.extend(["--project-ref".to_string(), parent.clone()]);
+        }
+        args
+    }
+}
+
+/// Project the checkout is linked to with `supabase link`
+pub fn linked_project_ref(project_root: &Path) -> Option<String> {
+    fs::read_to_string(project_root.join(PROJECT_REF_FILE))
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Parent project of the checkout's branches
+pub fn parent_project_ref(project_root: &Path) -> Option<String> {
+    BranchState::load(project_root)
+        .map(|state| state.parent)
+        .or_else(|| linked_project_ref(project_root))
+}
+
+/// `supabase link` the checkout to a branch (`None` = back to the parent)
+pub fn link(project_root: &Path, parent: &str, branch: Option<(&str, &str)>) -> Result<()> {
+    let project_ref = branch.map_or(parent, |(_, project_ref)| project_ref);
+    let status = platform::command("supabase")
+        .args(["link", "--project-ref", project_ref])
+        .current_dir(project_root)
+        .timed_status()
+        .context("Failed to link project. Make sure Supabase CLI is installed.")?;
+    if !status.success() {
+        anyhow::bail!("Project linking failed");
+    }
+
+    BranchState {
+        parent: parent.to_string(),
+        current: branch.map(|(name, _)| name.to_string()),
+    }
+    .save(project_root)
+}
+
+/// `KEY=value` / `KEY="value"` lines
+pub fn parse_env(output: &str) -> BTreeMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().split_once('='))
+        .filter(|(key, _)| !key.is_empty() && !key.starts_with('#'))
+        .map(|(key, value)| {
+            (
+                key.trim().to_string(),
+                value.trim().trim_matches('"').to_string(),
+            )
+        })
+        .collect()
+}
+
+/// `https://<ref>.supabase.co` -> `<ref>`
+pub fn project_ref_from_url(url: &str) -> Option<String> {
+    let host = url.trim().strip_prefix("https://")?.split('/').next()?;
+    host.strip_suffix(".supabase.co").map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_branch_credentials() {
+        let values = parse_env(
+            "POSTGRES_URL=\"postgresql://postgres.abc:pw@aws-0.pooler.supabase.com:6543/postgres\"\n\
+             POSTGRES_URL_NON_POOLING=\"postgresql://postgres:pw@db.abc.supabase.co:5432/postgres\"\n\
+             SUPABASE_URL=\"https://abcdefghijklmnop.supabase.co\"\n",
+        );
+        assert_eq!(
+            project_ref_from_url(&values["SUPABASE_URL"]).as_deref(),
+            Some("abcdefghijklmnop")
+        );
+        assert_eq!(project_ref_from_url("http://localhost:54321"), None);
+
+        let credentials = BranchCredentials {
+            project_ref: "abcdefghijklmnop".to_string(),
+            values,
+        };
+        assert_eq!(
+            credentials.db_url(),
+            Some("postgresql://postgres:pw@db.abc.supabase.co:5432/postgres")
+        );
+    }
+
+    #[test]
+    fn test_parent_project_ref() {
+        let root = tempfile::tempdir().unwrap();
+        assert_eq!(parent_project_ref(root.path()), None);
+
+        fs::create_dir_all(root.path().join("supabase/.temp")).unwrap();
+        fs::write(root.path().join(PROJECT_REF_FILE), "branchref\n").unwrap();
+        assert_eq!(
+            parent_project_ref(root.path()).as_deref(),
+            Some("branchref")
+        );
+
+        BranchState {
+            parent: "prodref".to_string(),
+            current: Some("schema-test".to_string()),
+        }
+        .save(root.path())
+        .unwrap();
+        assert_eq!(parent_project_ref(root.path()).as_deref(), Some("prodref"));
+    }
+}
@@ -0,0 +1,240 @@
+/**
+ * Database Check
+ * Lists the migrations, their status from the Supabase CLI and lines with
+ * multibyte characters (which can make `supabase db push` fail)
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::utils::output::OutputFormatter;
+use crate::utils::platform;
+
+const MIGRATIONS_PATH: &str = "supabase/migrations";
+
+/// Multibyte lines shown per file in the text output
+const MAX_WARNINGS_PER_FILE: usize = 3;
+
+/// `db check` result
+#[derive(Debug, Serialize)]
+struct MigrationCheck {
+    /// `supabase/migrations` exists
+    directory_found: bool,
+    migrations: Vec<String>,
+    /// `supabase migration list` output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    /// Why the status could not be read (e.g. the project is not linked)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_error: Option<String>,
+    multibyte: Vec<MultibyteLine>,
+}
+
+#[derive(Debug, Serialize)]
+struct MultibyteLine {
+    file: String,
+    line: usize,
+    /// First 50 characters of the line
+    sample: String,
+}
+
+pub fn execute(output: OutputFormatter) -> Result<()> {
+    if !output.is_json() {
+        println!("{}", "🔍 Checking database migrations...".cyan());
+        println!();
+    }
+    let check = collect(Path::new(MIGRATIONS_PATH))?;
+    output.emit(&check, print)
+}
+
+fn collect(migrations_path: &Path) -> Result<MigrationCheck> {
+    let mut check = MigrationCheck {
+        directory_found: migrations_path.exists(),
+        migrations: Vec::new(),
+        status: None,
+        status_error: None,
+        multibyte: Vec::new(),
+    };
+    if !check.directory_found {
+        return Ok(check);
+    }
+
+    if let Ok(entries) = fs::read_dir(migrations_path) {
+        for entry in entries.flatten() {
+            if let Some(filename) = entry.file_name().to_str() {
+                if filename.ends_with(".sql") {
+                    check.migrations.push(filename.to_string());
+                }
+            }
+        }
+    }
+    check.migrations.sort();
+    if check.migrations.is_empty() {
+        return Ok(check);
+    }
+
+    let output = platform::command("supabase")
+        .args(["migration", "list"])
+        .output()
+        .context("Failed to check migration status. Make sure Supabase CLI is installed and you're linked to a project.")?;
+    if output.status.success() {
+        check.status = Some(String::from_utf8_lossy(&output.stdout).to_string());
+    } else {
+        check.status_error = Some(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    for migration in &check.migrations {
+        if let Ok(content) = fs::read_to_string(migrations_path.join(migration)) {
+            for (line_num, line) in content.lines().enumerate() {
+                if !line.is_ascii() {
+                    check.multibyte.push(MultibyteLine {
+                        file: migration.clone(),
+                        line: line_num + 1,
+                        sample: line.chars().take(50).collect(),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(check)
+}
+
+fn print(check: &MigrationCheck) {
+    if !check.directory_found {
+        println!("{}", "⚠️  No migrations directory found".yellow());
+        println!("   Run: akatsuki db migration-new <name> to create your first migration");
+        return;
+    }
+    if check.migrations.is_empty() {
+        println!("{}", "✅ No migration files found".green());
+        return;
+    }
+
+    println!(
+        "{}",
+        format!("📝 Found {} migration file(s):", check.migrations.len()).cyan()
+    );
+    for migration in &check.migrations {
+        println!("   • {}", migration);
+    }
+    println!();
+
+    println!("{}", "🔄 Checking migration status...".cyan());
+    let Some(status) = &check.status else {
+        println!(
+            "{}",
+            format!(
+                "⚠️  Could not check migration status:\n{}",
+                check.status_error.as_deref().unwrap_or_default()
+            )
+            .yellow()
+        );
+        println!();
+        println!(
+            "{}",
+            "💡 Tip: Run 'akatsuki db link' to link to your Supabase project".cyan()
+        );
+        return;
+    };
+    println!("{}", status);
+
+    if let Some(latest_migration) = check.migrations.last() {
+        print_preview(latest_migration);
+    }
+
+    println!();
+    println!("{}", "🔤 Checking for multibyte characters...".cyan());
+    if check.multibyte.is_empty() {
+        println!(
+            "{}",
+            "   ✅ No multibyte characters found (safe for push)".green()
+        );
+    } else {
+        print_multibyte(&check.multibyte);
+    }
+
+    println!();
+    println!("{}", "✅ Migration check complete!".green());
+    println!();
+    println!("{}", "💡 Next steps:".cyan());
+    println!("   • Review migration files above");
+    println!("   • Run: akatsuki db push    - to apply migrations");
+    println!("   • Run: akatsuki db status  - to check database status");
+}
+
+/// First 20 lines of a migration
+fn print_preview(migration: &str) {
+    println!(
+        "{}",
+        format!("📄 Latest migration preview: {}", migration).cyan()
+    );
+    println!("{}", "─".repeat(80).dimmed());
+
+    if let Ok(content) = fs::read_to_string(Path::new(MIGRATIONS_PATH).join(migration)) {
+        let lines: Vec<&str> = content.lines().collect();
+        for line in lines.iter().take(20) {
+            println!("{}", line.dimmed());
+        }
+        if lines.len() > 20 {
+            println!(
+                "{}",
+                format!("... ({} more lines)", lines.len() - 20).dimmed()
+            );
+        }
+    }
+    println!("{}", "─".repeat(80).dimmed());
+}
+
+fn print_multibyte(lines: &[MultibyteLine]) {
+    println!();
+    println!(
+        "{}",
+        "⚠️  WARNING: Multibyte characters detected in migration files"
+            .yellow()
+            .bold()
+    );
+    println!(
+        "{}",
+        "   This may cause 'supabase db push' to fail with encoding errors".yellow()
+    );
+    println!();
+    println!("{}", "   Affected files:".yellow());
+
+    // Group by file and show the first few occurrences per file
+    let mut current_file = "";
+    let mut count_in_file = 0;
+    for warning in lines {
+        if warning.file != current_file {
+            current_file = &warning.file;
+            count_in_file = 0;
+            println!();
+            println!("{}", format!("   📄 {}", warning.file).yellow());
+        }
+
+        count_in_file += 1;
+        if count_in_file <= MAX_WARNINGS_PER_FILE {
+            println!(
+                "{}",
+                format!("      Line {}: {}", warning.line, warning.sample).dimmed()
+            );
+        } else if count_in_file == MAX_WARNINGS_PER_FILE + 1 {
+            let remaining =
+                lines.iter().filter(|w| w.file == warning.file).count() - MAX_WARNINGS_PER_FILE;
+            println!(
+                "{}",
+                format!("      ... and {} more line(s)", remaining).dimmed()
+            );
+        }
+    }
+
+    println!();
+    println!("{}", "💡 Recommendations:".cyan());
+    println!("   1. Remove Japanese/multibyte comments from SQL files");
+    println!("   2. Use only ASCII characters (English) in migration files");
+    println!("   3. Ensure files are saved with UTF-8 encoding");
+    println!("   4. Test with: akatsuki db push --dry-run (if available)");
+    println!();
+}
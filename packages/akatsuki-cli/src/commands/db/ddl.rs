@@ -0,0 +1,544 @@
+/**
+ * Migration DDL
+ * Replays the table DDL of supabase/migrations (or a schema dump of the
+ * linked database) into an in-memory schema for `db erd` and `db plan`
+ *
+ * Only what those need is tracked: tables, columns, primary keys, NOT NULL,
+ * foreign keys and indexes (CREATE / ALTER / DROP TABLE, CREATE / DROP INDEX).
+ */
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::utils::platform;
+
+pub const MIGRATIONS_DIR: &str = "supabase/migrations";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub name: String,
+    pub data_type: String,
+    pub primary_key: bool,
+    pub not_null: bool,
+    /// (table, column) this column points at
+    pub references: Option<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Index {
+    pub table: String,
+    /// Indexed columns (or expressions), without ordering or operator class
+    pub columns: Vec<String>,
+    /// Access method (`btree` unless `USING ...` says otherwise)
+    pub method: String,
+    pub unique: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct Schema {
+    /// Table name (`public.` dropped) → columns in declaration order
+    pub tables: BTreeMap<String, Vec<Column>>,
+    /// Index name → index
+    pub indexes: BTreeMap<String, Index>,
+}
+
+/// Schema after applying every migration in filename (= timestamp) order
+pub fn schema_from_migrations(root: &Path) -> Result<Schema> {
+    let dir = root.join(MIGRATIONS_DIR);
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "sql"))
+        .collect();
+    files.sort();
+
+    let mut schema = Schema::default();
+    for file in files {
+        let sql = fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        schema.apply(&sql);
+    }
+    Ok(schema)
+}
+
+/// Schema of the linked Supabase project (`supabase db dump --schema public`)
+pub fn schema_from_linked_db(root: &Path) -> Result<Schema> {
+    let output = platform::command("supabase")
+        .args(["db", "dump", "--linked", "--schema", "public"])
+        .current_dir(root)
+        .output()
+        .context("Failed to run supabase db dump. Make sure Supabase CLI is installed.")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "supabase db dump failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let mut schema = Schema::default();
+    schema.apply(&String::from_utf8_lossy(&output.stdout));
+    Ok(schema)
+}
+
+impl Schema {
+    /// Apply the table DDL in `sql` (other statements are ignored)
+    pub fn apply(&mut self, sql: &str) {
+        for statement in strip_sql(sql).split(';') {
+            let statement = statement.split_whitespace().collect::<Vec<_>>().join(" ");
+            if let Some(caps) = regex(
+                r"^create (?:(?:global |local )?(?:temp|temporary|unlogged) )?table (?:if not exists )?([\w.]+) ?\(",
+            )
+            .captures(&statement)
+            {
+                let name = table_name(&caps[1]);
+                let body = parenthesized(&statement[caps.get(0).unwrap().end() - 1..]);
+                let mut columns = Vec::new();
+                for element in split_top_level(body) {
+                    if let Some(column) = parse_column(&element) {
+                        columns.push(column);
+                    } else {
+                        apply_constraint(&mut columns, &element);
+                    }
+                }
+                self.tables.insert(name, columns);
+            } else if let Some(caps) =
+                regex(r"^alter table (?:if exists )?(?:only )?([\w.]+) (.*)$").captures(&statement)
+            {
+                let name = table_name(&caps[1]);
+                for action in split_top_level(&caps[2]) {
+                    self.alter(&name, &action);
+                }
+            } else if let Some(caps) = regex(r"^drop table (?:if exists )?(.*)$").captures(&statement)
+            {
+                let names = caps[1].replace(" cascade", "").replace(" restrict", "");
+                for name in names.split(',') {
+                    let name = table_name(name.trim());
+                    self.indexes.retain(|_, index| index.table != name);
+                    self.tables.remove(&name);
+                }
+            } else if let Some(caps) = regex(
+                r"^create (unique )?index (?:concurrently )?(?:if not exists )?([\w.]+) on (?:only )?([\w.]+) ?(?:using (\w+) ?)?\(",
+            )
+            .captures(&statement)
+            {
+                let body = parenthesized(&statement[caps.get(0).unwrap().end() - 1..]);
+                let index = Index {
+                    table: table_name(&caps[3]),
+                    columns: split_top_level(body)
+                        .iter()
+                        .map(|column| index_column(column))
+                        .collect(),
+                    method: caps.get(4).map_or("btree", |m| m.as_str()).to_string(),
+                    unique: caps.get(1).is_some(),
+                };
+                self.indexes.entry(table_name(&caps[2])).or_insert(index);
+            } else if let Some(caps) =
+                regex(r"^drop index (?:concurrently )?(?:if exists )?(.*)$").captures(&statement)
+            {
+                let names = caps[1].replace(" cascade", "").replace(" restrict", "");
+                for name in names.split(',') {
+                    self.indexes.remove(&table_name(name.trim()));
+                }
+            }
+        }
+    }
+
+    fn alter(&mut self, table: &str, action: &str) {
+        if let Some(caps) = regex(r"^rename to ([\w.]+)$").captures(action) {
+            let renamed = table_name(&caps[1]);
+            if let Some(columns) = self.tables.remove(table) {
+                self.tables.insert(renamed.clone(), columns);
+            }
+            for index in self
+                .indexes
+                .values_mut()
+                .filter(|index| index.table == table)
+            {
+                index.table = renamed.clone();
+            }
+            return;
+        }
+
+        let Some(columns) = self.tables.get_mut(table) else {
+            return;
+        };
+        if let Some(rest) = action.strip_prefix("add ") {
+            let rest = rest.strip_prefix("column ").unwrap_or(rest);
+            let rest = rest.strip_prefix("if not exists ").unwrap_or(rest);
+            match parse_column(rest) {
+                Some(column) => {
+                    columns.retain(|existing| existing.name != column.name);
+                    columns.push(column);
+                }
+                None => apply_constraint(columns, rest),
+            }
+        } else if let Some(caps) = regex(r"^drop (?:column )?(?:if exists )?(\w+)").captures(action)
+        {
+            if &caps[1] != "constraint" {
+                columns.retain(|column| column.name != caps[1]);
+                // Postgres drops the indexes on a dropped column
+                self.indexes.retain(|_, index| {
+                    index.table != table || !index.columns.iter().any(|c| c == &caps[1])
+                });
+            }
+        } else if let Some(caps) = regex(r"^rename (?:column )?(\w+) to (\w+)$").captures(action) {
+            if let Some(column) = columns.iter_mut().find(|column| column.name == caps[1]) {
+                column.name = caps[2].to_string();
+            }
+            for index in self
+                .indexes
+                .values_mut()
+                .filter(|index| index.table == table)
+            {
+                for column in index.columns.iter_mut().filter(|c| **c == caps[1]) {
+                    *column = caps[2].to_string();
+                }
+            }
+        } else if let Some(caps) = regex(r"^alter (?:column )?(\w+) (.*)$").captures(action) {
+            let Some(column) = columns.iter_mut().find(|column| column.name == caps[1]) else {
+                return;
+            };
+            let change = &caps[2];
+            if change == "set not null" {
+                column.not_null = true;
+            } else if change == "drop not null" {
+                column.not_null = false;
+            } else if let Some(caps) =
+                regex(r"^(?:set data )?type (.+?)(?: using .*)?$").captures(change)
+            {
+                column.data_type = normalize_type(&caps[1]);
+            }
+        }
+    }
+}
+
+const CONSTRAINT_KEYWORDS: [&str; 7] = [
+    "constraint",
+    "primary",
+    "foreign",
+    "unique",
+    "check",
+    "exclude",
+    "like",
+];
+
+/// Stop words ending a column's type in a column definition
+const TYPE_TERMINATORS: [&str; 11] = [
+    "primary",
+    "not",
+    "null",
+    "references",
+    "default",
+    "unique",
+    "check",
+    "constraint",
+    "generated",
+    "collate",
+    "on",
+];
+
+fn parse_column(definition: &str) -> Option<Column> {
+    let mut words = definition.split(' ');
+    let name = words.next()?;
+    let valid_name = !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+    if !valid_name || CONSTRAINT_KEYWORDS.contains(&name) {
+        return None;
+    }
+
+    let words: Vec<&str> = words.collect();
+    let type_len = words
+        .iter()
+        .position(|word| TYPE_TERMINATORS.contains(word))
+        .unwrap_or(words.len());
+    let data_type = words[..type_len].join(" ");
+    let rest = format!(" {} ", words[type_len..].join(" "));
+
+    Some(Column {
+        name: name.to_string(),
+        data_type: normalize_type(&data_type),
+        primary_key: rest.contains(" primary key "),
+        not_null: rest.contains(" not null ") || rest.contains(" primary key "),
+        references: regex(r" references ([\w.]+) ?(?:\((\w+)\))?")
+            .captures(&rest)
+            .map(|caps| {
+                (
+                    table_name(&caps[1]),
+                    caps.get(2).map_or("id", |m| m.as_str()).to_string(),
+                )
+            }),
+    })
+}
+
+/// PRIMARY KEY (...) / FOREIGN KEY (...) REFERENCES ... table constraints
+fn apply_constraint(columns: &mut [Column], definition: &str) {
+    let definition = regex(r"^constraint \w+ ").replace(definition, "");
+    if let Some(caps) = regex(r"^primary key ?\(([^)]*)\)").captures(&definition) {
+        for name in caps[1].split(',').map(str::trim) {
+            if let Some(column) = columns.iter_mut().find(|column| column.name == name) {
+                column.primary_key = true;
+                column.not_null = true;
+            }
+        }
+    } else if let Some(caps) =
+        regex(r"^foreign key ?\(([^)]*)\) references ([\w.]+) ?(?:\(([^)]*)\))?")
+            .captures(&definition)
+    {
+        let target = table_name(&caps[2]);
+        let target_columns: Vec<&str> = caps.get(3).map_or(vec!["id"], |m| {
+            m.as_str().split(',').map(str::trim).collect()
+        });
+        for (i, name) in caps[1].split(',').map(str::trim).enumerate() {
+            if let Some(column) = columns.iter_mut().find(|column| column.name == name) {
+                let target_column = target_columns.get(i).unwrap_or(&"id");
+                column.references = Some((target.clone(), target_column.to_string()));
+            }
+        }
+    }
+}
+
+/// Column of an index element (`created_at desc` → `created_at`)
+fn index_column(element: &str) -> String {
+    if element.starts_with('(') {
+        return element.to_string();
+    }
+    element.split(' ').next().unwrap_or(element).to_string()
+}
+
+pub fn table_name(raw: &str) -> String {
+    raw.strip_prefix("public.").unwrap_or(raw).to_string()
+}
+
+/// Short type names (`timestamp with time zone` → `timestamptz`, sizes and
+/// schemas dropped)
+pub fn normalize_type(raw: &str) -> String {
+    let without_size = regex(r" ?\([^)]*\)").replace_all(raw.trim(), "");
+    let normalized = match without_size.as_ref() {
+        "timestamp with time zone" => "timestamptz",
+        "timestamp without time zone" => "timestamp",
+        "character varying" => "varchar",
+        "double precision" => "float8",
+        "" => "unknown",
+        other => other,
+    };
+    // Schema-qualified types (extensions.vector) keep the type name only
+    normalized
+        .rsplit('.')
+        .next()
+        .unwrap_or(normalized)
+        .to_string()
+}
+
+/// Lowercased SQL without comments, string literal contents, dollar-quoted
+/// bodies (function definitions) or identifier quotes
+fn strip_sql(sql: &str) -> String {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut out = String::with_capacity(sql.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '-' if chars.get(i + 1) == Some(&'-') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i += 2;
+                out.push(' ');
+            }
+            '\'' => {
+                i += 1;
+                while i < chars.len() {
+                    if chars[i] == '\'' && chars.get(i + 1) == Some(&'\'') {
+                        i += 2;
+                    } else if chars[i] == '\'' {
+                        i += 1;
+                        break;
+                    } else {
+                        i += 1;
+                    }
+                }
+                out.push_str("''");
+            }
+            '$' => {
+                let tag_end = chars[i + 1..]
+                    .iter()
+                    .position(|c| !(c.is_ascii_alphabetic() || *c == '_'))
+                    .map(|offset| i + 1 + offset);
+                match tag_end {
+                    Some(end) if chars[end] == '$' => {
+                        let tag: String = chars[i..=end].iter().collect();
+                        let rest: String = chars[end + 1..].iter().collect();
+                        let body_len = rest.find(&tag).unwrap_or(rest.len());
+                        i = end + 1 + rest[..body_len].chars().count() + tag.chars().count();
+                        out.push_str(" $$ ");
+                    }
+                    _ => {
+                        out.push('$');
+                        i += 1;
+                    }
+                }
+            }
+            '"' => i += 1,
+            c => {
+                out.extend(c.to_lowercase());
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Contents of the parenthesized group `text` starts with
+fn parenthesized(text: &str) -> &str {
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return &text[1..i];
+                }
+            }
+            _ => {}
+        }
+    }
+    text.get(1..).unwrap_or("")
+}
+
+/// Split on commas outside parentheses
+fn split_top_level(text: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in text.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    parts.push(current.trim().to_string());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+pub(crate) fn regex(pattern: &str) -> Regex {
+    Regex::new(pattern).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIGRATIONS: [&str; 3] = [
+        r#"
+        -- Profiles
+        CREATE TABLE IF NOT EXISTS public.profiles (
+          id uuid PRIMARY KEY REFERENCES auth.users(id) ON DELETE CASCADE,
+          "username" character varying(50) NOT NULL UNIQUE,
+          bio text DEFAULT 'hello; world'
+        );
+        CREATE OR REPLACE FUNCTION touch() RETURNS trigger AS $$
+        BEGIN
+          CREATE TABLE ignored (id int);
+          RETURN NEW;
+        END;
+        $$ LANGUAGE plpgsql;
+        "#,
+        r#"
+        CREATE TABLE public.articles (
+          id uuid DEFAULT gen_random_uuid(),
+          author_id uuid,
+          reviewer_id uuid,
+          title text NOT NULL,
+          tags text[],
+          created_at timestamp with time zone NOT NULL DEFAULT now(),
+          CONSTRAINT articles_pkey PRIMARY KEY (id),
+          UNIQUE(author_id, title),
+          CONSTRAINT articles_author_fk FOREIGN KEY (author_id) REFERENCES public.profiles (id)
+        );
+        CREATE TABLE drafts (id uuid PRIMARY KEY);
+        CREATE INDEX IF NOT EXISTS idx_articles_tags ON public.articles USING GIN(tags);
+        CREATE INDEX idx_articles_reviewer_id ON public.articles(reviewer_id);
+        CREATE UNIQUE INDEX articles_title_created ON articles (title, created_at DESC);
+        CREATE INDEX idx_drafts_id ON drafts(id);
+        "#,
+        r#"
+        ALTER TABLE ONLY public.articles
+          ADD COLUMN IF NOT EXISTS views integer NOT NULL DEFAULT 0,
+          DROP COLUMN reviewer_id,
+          ALTER COLUMN author_id SET NOT NULL;
+        ALTER TABLE public.articles RENAME COLUMN title TO headline;
+        DROP TABLE IF EXISTS drafts CASCADE;
+        DROP INDEX IF EXISTS public.idx_articles_tags;
+        CREATE INDEX idx_articles_tags ON public.articles USING gin (tags);
+        "#,
+    ];
+
+    fn schema() -> Schema {
+        let mut schema = Schema::default();
+        for sql in MIGRATIONS {
+            schema.apply(sql);
+        }
+        schema
+    }
+
+    #[test]
+    fn test_replays_table_ddl() {
+        let schema = schema();
+        assert_eq!(
+            schema.tables.keys().collect::<Vec<_>>(),
+            ["articles", "profiles"]
+        );
+
+        let profiles = &schema.tables["profiles"];
+        assert_eq!(profiles.len(), 3);
+        assert!(profiles[0].primary_key);
+        assert_eq!(
+            profiles[0].references,
+            Some(("auth.users".to_string(), "id".to_string()))
+        );
+        assert_eq!(profiles[1].data_type, "varchar");
+        assert!(profiles[1].not_null);
+
+        let articles = &schema.tables["articles"];
+        let names: Vec<&str> = articles.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["id", "author_id", "headline", "tags", "created_at", "views"]
+        );
+        assert!(articles[0].primary_key);
+        assert!(articles[1].not_null);
+        assert_eq!(
+            articles[1].references,
+            Some(("profiles".to_string(), "id".to_string()))
+        );
+        assert_eq!(articles[3].data_type, "text[]");
+        assert_eq!(articles[4].data_type, "timestamptz");
+
+        // Indexes follow column drops/renames and table drops
+        assert_eq!(
+            schema.indexes.keys().collect::<Vec<_>>(),
+            ["articles_title_created", "idx_articles_tags"]
+        );
+        let unique = &schema.indexes["articles_title_created"];
+        assert_eq!(unique.columns, ["headline", "created_at"]);
+        assert!(unique.unique);
+        assert_eq!(unique.method, "btree");
+        assert_eq!(schema.indexes["idx_articles_tags"].method, "gin");
+    }
+}
@@ -0,0 +1,200 @@
+/**
+ * Database ERD
+ * Renders the schema replayed from the migrations (see ddl.rs) as a Mermaid
+ * or PlantUML entity-relationship diagram
+ */
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+use super::ddl::{schema_from_migrations, Column, Schema, MIGRATIONS_DIR};
+use crate::cli::ErdFormat;
+
+pub const MERMAID_OUTPUT: &str = "docs/database/erd.md";
+pub const PLANTUML_OUTPUT: &str = "docs/database/erd.puml";
+
+pub fn default_output(format: ErdFormat) -> &'static str {
+    match format {
+        ErdFormat::Mermaid => MERMAID_OUTPUT,
+        ErdFormat::Plantuml => PLANTUML_OUTPUT,
+    }
+}
+
+/// Regenerate the ERD files that already exist (called by `akatsuki docs sync`).
+/// Returns the files whose contents changed.
+pub fn sync(root: &Path, dry_run: bool) -> Result<Vec<&'static str>> {
+    let existing: Vec<ErdFormat> = [ErdFormat::Mermaid, ErdFormat::Plantuml]
+        .into_iter()
+        .filter(|format| root.join(default_output(*format)).exists())
+        .collect();
+    if existing.is_empty() || !root.join(MIGRATIONS_DIR).exists() {
+        return Ok(Vec::new());
+    }
+
+    let schema = schema_from_migrations(root)?;
+    let mut changed = Vec::new();
+    for format in existing {
+        let path = root.join(default_output(format));
+        let content = render(&schema, format);
+        if fs::read_to_string(&path).ok().as_deref() != Some(content.as_str()) {
+            if !dry_run {
+                fs::write(&path, content)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+            }
+            changed.push(default_output(format));
+        }
+    }
+    Ok(changed)
+}
+
+pub fn render(schema: &Schema, format: ErdFormat) -> String {
+    match format {
+        ErdFormat::Mermaid => render_mermaid(schema),
+        ErdFormat::Plantuml => render_plantuml(schema),
+    }
+}
+
+fn render_mermaid(schema: &Schema) -> String {
+    let mut diagram = String::from("erDiagram\n");
+    for (table, columns) in &schema.tables {
+        diagram.push_str(&format!("    {} {{\n", entity_name(table)));
+        for column in columns {
+            let keys: Vec<&str> = [
+                (column.primary_key, "PK"),
+                (column.references.is_some(), "FK"),
+            ]
+            .into_iter()
+            .filter_map(|(set, key)| set.then_some(key))
+            .collect();
+            diagram.push_str(&format!(
+                "        {} {}{}\n",
+                column.data_type.replace(' ', "_").replace("[]", "_array"),
+                column.name,
+                if keys.is_empty() {
+                    String::new()
+                } else {
+                    format!(" {}", keys.join(", "))
+                }
+            ));
+        }
+        diagram.push_str("    }\n");
+    }
+    for (table, column, target) in relationships(schema) {
+        diagram.push_str(&format!(
+            "    {} {}--o{{ {} : \"{}\"\n",
+            entity_name(&target),
+            if column.not_null { "||" } else { "|o" },
+            entity_name(table),
+            column.name
+        ));
+    }
+
+    format!(
+        "# Database ERD\n\n<!-- Generated by `akatsuki db erd` (updated by `akatsuki docs sync`). Do not edit by hand. -->\n\n{} tables, {} relationships.\n\n```mermaid\n{}```\n",
+        schema.tables.len(),
+        relationships(schema).len(),
+        diagram
+    )
+}
+
+fn render_plantuml(schema: &Schema) -> String {
+    let mut diagram = String::from(
+        "' Generated by `akatsuki db erd` (updated by `akatsuki docs sync`). Do not edit by hand.\n@startuml\nhide circle\nskinparam linetype ortho\n\n",
+    );
+    for (table, columns) in &schema.tables {
+        diagram.push_str(&format!(
+            "entity \"{}\" as {} {{\n",
+            table,
+            entity_name(table)
+        ));
+        let (keys, others): (Vec<&Column>, Vec<&Column>) =
+            columns.iter().partition(|column| column.primary_key);
+        for column in keys {
+            diagram.push_str(&format!(
+                "  * {} : {} <<PK>>\n",
+                column.name, column.data_type
+            ));
+        }
+        diagram.push_str("  --\n");
+        for column in others {
+            diagram.push_str(&format!(
+                "  {}{} : {}{}\n",
+                if column.not_null { "* " } else { "" },
+                column.name,
+                column.data_type,
+                if column.references.is_some() {
+                    " <<FK>>"
+                } else {
+                    ""
+                }
+            ));
+        }
+        diagram.push_str("}\n\n");
+    }
+    for (table, column, target) in relationships(schema) {
+        diagram.push_str(&format!(
+            "{} {}--o{{ {} : {}\n",
+            entity_name(&target),
+            if column.not_null { "||" } else { "|o" },
+            entity_name(table),
+            column.name
+        ));
+    }
+    diagram.push_str("@enduml\n");
+    diagram
+}
+
+/// (table, foreign key column, referenced table)
+fn relationships(schema: &Schema) -> Vec<(&String, &Column, String)> {
+    schema
+        .tables
+        .iter()
+        .flat_map(|(table, columns)| {
+            columns.iter().filter_map(move |column| {
+                column
+                    .references
+                    .as_ref()
+                    .map(|(target, _)| (table, column, target.clone()))
+            })
+        })
+        .collect()
+}
+
+/// Diagram identifier (`auth.users` → `auth_users`)
+fn entity_name(table: &str) -> String {
+    table.replace('.', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renders_mermaid_and_plantuml() {
+        let mut schema = Schema::default();
+        schema.apply(
+            "CREATE TABLE public.profiles (id uuid PRIMARY KEY REFERENCES auth.users(id));
+             CREATE TABLE public.articles (
+               id uuid PRIMARY KEY,
+               author_id uuid NOT NULL REFERENCES public.profiles(id),
+               tags text[]
+             );",
+        );
+
+        let mermaid = render(&schema, ErdFormat::Mermaid);
+        assert!(mermaid.contains("2 tables, 2 relationships."));
+        assert!(mermaid.contains("    articles {\n        uuid id PK\n        uuid author_id FK\n"));
+        assert!(mermaid.contains("        text_array tags\n"));
+        assert!(mermaid.contains("        uuid id PK, FK\n"));
+        assert!(mermaid.contains("    profiles ||--o{ articles : \"author_id\"\n"));
+        assert!(mermaid.contains("    auth_users ||--o{ profiles : \"id\"\n"));
+
+        let plantuml = render(&schema, ErdFormat::Plantuml);
+        assert!(
+            plantuml.contains("entity \"articles\" as articles {\n  * id : uuid <<PK>>\n  --\n")
+        );
+        assert!(plantuml.contains("  * author_id : uuid <<FK>>\n"));
+        assert!(plantuml.contains("  tags : text[]\n"));
+        assert!(plantuml.ends_with("@enduml\n"));
+    }
+}
@@ -1,10 +1,23 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
 use std::fs;
-use std::path::Path;
-use std::process::Command;
+use std::path::{Path, PathBuf};
 
-use crate::cli::DbAction;
+use crate::cli::{DbAction, DbBranchAction, ErdFormat};
+use crate::commands::api;
+use crate::utils::find_project_root;
+use crate::utils::output::OutputFormatter;
+use crate::utils::platform;
+
+pub mod branch;
+pub mod check;
+pub mod console;
+pub mod ddl;
+pub mod erd;
+pub mod plan;
+
+use branch::{BranchList, BranchState, Branches};
+use console::QueryFormat;
 
 pub struct DbCommand;
 
@@ -13,20 +26,204 @@ impl DbCommand {
         Self
     }
 
-    pub fn execute(&self, action: DbAction) -> Result<()> {
+    pub fn execute(&self, action: DbAction, output: OutputFormatter) -> Result<()> {
         match action {
-            DbAction::Push => self.push(),
+            DbAction::Push { branch } => match branch {
+                Some(name) => self.push_branch(&name),
+                None => self.push(),
+            },
             DbAction::MigrationNew { name } => self.migration_new(&name),
-            DbAction::Check => self.check(),
+            DbAction::Check => check::execute(output),
             DbAction::Status => self.status(),
             DbAction::Link => self.link(),
+            DbAction::Branch { action } => self.branch(action, output),
+            DbAction::Console { yes } => {
+                let project_root = find_project_root();
+                let url = console::database_url(&project_root)?;
+                if !console::confirm_target(&project_root, &url, yes)? {
+                    println!("{}", "Cancelled".yellow());
+                    return Ok(());
+                }
+                console::console(&url)
+            }
+            DbAction::Query {
+                sql,
+                json,
+                csv,
+                yes,
+            } => {
+                let project_root = find_project_root();
+                let url = console::database_url(&project_root)?;
+                if !console::confirm_target(&project_root, &url, yes)? {
+                    println!("{}", "Cancelled".yellow());
+                    return Ok(());
+                }
+                let format = if json {
+                    QueryFormat::Json
+                } else if csv {
+                    QueryFormat::Csv
+                } else {
+                    QueryFormat::Table
+                };
+                console::query(&url, &sql, format)
+            }
+            DbAction::Erd {
+                format,
+                out,
+                linked,
+                stdout,
+            } => self.erd(format, out, linked, stdout),
+            DbAction::Plan {
+                files,
+                linked,
+                write,
+            } => self.plan(files, linked, write),
+        }
+    }
+
+    fn plan(&self, files: Vec<PathBuf>, linked: bool, write: bool) -> Result<()> {
+        let project_root = find_project_root();
+        let files = if files.is_empty() {
+            plan::find_schemas(&project_root)
+        } else {
+            files
+        };
+        if files.is_empty() {
+            anyhow::bail!("No entity schemas found (YAML files with `tableName:`)");
+        }
+
+        let schema = if linked {
+            println!(
+                "{}",
+                "🗄️  Reading schema from the linked database...".cyan()
+            );
+            ddl::schema_from_linked_db(&project_root)?
+        } else {
+            ddl::schema_from_migrations(&project_root)?
+        };
+
+        println!(
+            "{}",
+            format!("📐 Comparing {} entity schema(s)...", files.len()).cyan()
+        );
+        let mut plans = Vec::new();
+        for file in &files {
+            let source = file
+                .strip_prefix(&project_root)
+                .unwrap_or(file)
+                .display()
+                .to_string();
+            let declared = match api::declared_table(file) {
+                Ok(declared) => declared,
+                Err(e) => {
+                    println!("{}", format!("⚠️  Skipping {}: {}", source, e).yellow());
+                    continue;
+                }
+            };
+            match plan::plan_table(&declared, &schema) {
+                plan::TablePlan::Missing => println!(
+                    "  {} {} not created by any migration: akatsuki api new {} --schema {}",
+                    "➕".green(),
+                    declared.table_name.bold(),
+                    declared.name,
+                    source
+                ),
+                plan::TablePlan::Changes(changes) if changes.is_empty() => {
+                    println!("  {} {}", "✓".green(), declared.table_name)
+                }
+                plan::TablePlan::Changes(changes) => {
+                    println!(
+                        "  {} {} ({} change(s))",
+                        "≠".yellow(),
+                        declared.table_name.bold(),
+                        changes.len()
+                    );
+                    plans.push((source, declared.table_name, changes));
+                }
+            }
+        }
+
+        if plans.is_empty() {
+            println!("{}", "✅ No ALTER statements needed".green());
+            return Ok(());
+        }
+
+        let sql = plan::render(&plans);
+        println!();
+        print!("{}", sql);
+        if !write {
+            println!();
+            println!("Write it as a migration with: akatsuki db plan --write");
+            return Ok(());
+        }
+
+        let timestamp = chrono::Local::now().format("%Y%m%d%H%M%S");
+        let path = project_root
+            .join(ddl::MIGRATIONS_DIR)
+            .join(format!("{}_schema_drift.sql", timestamp));
+        fs::create_dir_all(project_root.join(ddl::MIGRATIONS_DIR))?;
+        fs::write(&path, sql).with_context(|| format!("Failed to write {}", path.display()))?;
+        println!();
+        println!(
+            "{}",
+            format!(
+                "✅ Migration written: {}",
+                path.strip_prefix(&project_root).unwrap_or(&path).display()
+            )
+            .green()
+        );
+        println!("   Review it, then apply with: akatsuki db push");
+        Ok(())
+    }
+
+    fn erd(
+        &self,
+        format: ErdFormat,
+        out: Option<PathBuf>,
+        linked: bool,
+        stdout: bool,
+    ) -> Result<()> {
+        let project_root = find_project_root();
+        let schema = if linked {
+            println!(
+                "{}",
+                "🗄️  Reading schema from the linked database...".cyan()
+            );
+            ddl::schema_from_linked_db(&project_root)?
+        } else {
+            ddl::schema_from_migrations(&project_root)?
+        };
+        let diagram = erd::render(&schema, format);
+
+        if stdout {
+            print!("{}", diagram);
+            return Ok(());
         }
+
+        let out = out.unwrap_or_else(|| PathBuf::from(erd::default_output(format)));
+        let path = project_root.join(&out);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, diagram).with_context(|| format!("Failed to write {}", path.display()))?;
+
+        println!(
+            "{}",
+            format!(
+                "✅ ERD written to {} ({} tables)",
+                out.display(),
+                schema.tables.len()
+            )
+            .green()
+        );
+        println!("   Kept up to date by: akatsuki docs sync");
+        Ok(())
     }
 
     fn push(&self) -> Result<()> {
         println!("{}", "🗄️  Pushing database migrations...".cyan());
 
-        let status = Command::new("supabase")
+        let status = platform::command("supabase")
             .args(["db", "push"])
             .status()
             .context("Failed to run supabase db push. Make sure Supabase CLI is installed.")?;
@@ -39,10 +236,135 @@ impl DbCommand {
         Ok(())
     }
 
+    fn push_branch(&self, name: &str) -> Result<()> {
+        println!(
+            "{}",
+            format!("🗄️  Pushing database migrations to branch: {}", name).cyan()
+        );
+
+        let credentials = Branches::new(&find_project_root()).get(name)?;
+        let db_url = credentials
+            .db_url()
+            .with_context(|| format!("No database URL for branch {}", name))?;
+
+        let status = platform::command("supabase")
+            .args(["db", "push", "--db-url", db_url])
+            .status()
+            .context("Failed to run supabase db push. Make sure Supabase CLI is installed.")?;
+
+        if !status.success() {
+            anyhow::bail!("Database push failed");
+        }
+
+        println!(
+            "{}",
+            format!(
+                "✅ Migrations pushed to branch {} ({})",
+                name, credentials.project_ref
+            )
+            .green()
+        );
+        Ok(())
+    }
+
+    fn branch(&self, action: DbBranchAction, output: OutputFormatter) -> Result<()> {
+        let project_root = find_project_root();
+        let branches = Branches::new(&project_root);
+
+        match action {
+            DbBranchAction::List => {
+                let current = BranchState::load(&project_root).and_then(|s| s.current);
+                if !output.is_json() {
+                    if let Some(current) = current {
+                        println!("{}", format!("🌿 Linked to branch: {}", current).cyan());
+                    }
+                    return branches.list();
+                }
+                let list = BranchList {
+                    current,
+                    branches: branches.list_json()?,
+                };
+                // Text mode shows supabase's own table instead
+                output.emit(&list, |_| {})
+            }
+            DbBranchAction::Create { name, switch } => {
+                println!(
+                    "{}",
+                    format!("🌱 Creating database branch: {}", name).cyan()
+                );
+                branches.create(&name)?;
+                println!("{}", format!("✅ Branch {} created", name).green());
+                if switch {
+                    self.switch(&project_root, &branches, Some(&name))?;
+                } else {
+                    println!("   Push migrations: akatsuki db push --branch {}", name);
+                }
+                Ok(())
+            }
+            DbBranchAction::Delete { name, yes } => {
+                let current = BranchState::load(&project_root).and_then(|s| s.current);
+                if current.as_deref() == Some(name.as_str()) {
+                    anyhow::bail!(
+                        "This checkout is linked to {}; switch back first: akatsuki db branch switch --parent",
+                        name
+                    );
+                }
+                if !yes
+                    && !dialoguer::Confirm::new()
+                        .with_prompt(format!("Delete database branch {} and all its data?", name))
+                        .default(false)
+                        .interact()?
+                {
+                    println!("{}", "Cancelled".yellow());
+                    return Ok(());
+                }
+                branches.delete(&name)?;
+                println!("{}", format!("✅ Branch {} deleted", name).green());
+                Ok(())
+            }
+            DbBranchAction::Switch { name, .. } => {
+                self.switch(&project_root, &branches, name.as_deref())
+            }
+        }
+    }
+
+    /// Link this checkout to a branch (`None` = the parent project)
+    fn switch(&self, project_root: &Path, branches: &Branches, name: Option<&str>) -> Result<()> {
+        let parent = branches
+            .parent()
+            .context("Not linked to a Supabase project. Run: akatsuki db link")?;
+
+        match name {
+            Some(name) => {
+                let credentials = branches.get(name)?;
+                println!(
+                    "{}",
+                    format!(
+                        "🔗 Switching to branch {} ({})",
+                        name, credentials.project_ref
+                    )
+                    .cyan()
+                );
+                branch::link(project_root, parent, Some((name, &credentials.project_ref)))?;
+                println!("{}", format!("✅ Linked to branch {}", name).green());
+                println!("   Back to the parent project: akatsuki db branch switch --parent");
+            }
+            None => {
+                println!(
+                    "{}",
+                    format!("🔗 Switching to parent project ({})", parent).cyan()
+                );
+                branch::link(project_root, parent, None)?;
+                println!("{}", "✅ Linked to the parent project".green());
+            }
+        }
+        Ok(())
+    }
+
     fn migration_new(&self, name: &str) -> Result<()> {
         println!("{}", format!("📝 Creating new migration: {}", name).cyan());
 
-        let status = Command::new("supabase")
+        let status = platform::command("supabase")
             .args(["migration", "new", name])
             .status()
             .context("Failed to create migration. Make sure Supabase CLI is installed.")?;
@@ -58,7 +380,7 @@ impl DbCommand {
     fn status(&self) -> Result<()> {
         println!("{}", "🔍 Checking database status...".cyan());
 
-        let status = Command::new("supabase")
+        let status = platform::command("supabase")
             .args(["status"])
             .status()
             .context("Failed to check status. Make sure Supabase CLI is installed.")?;
@@ -73,7 +395,7 @@ impl DbCommand {
     fn link(&self) -> Result<()> {
         println!("{}", "🔗 Linking to Supabase project...".cyan());
 
-        let status = Command::new("supabase")
+        let status = platform::command("supabase")
             .args(["link"])
             .status()
             .context("Failed to link project. Make sure Supabase CLI is installed.")?;
@@ -85,193 +407,4 @@ impl DbCommand {
         println!("{}", "✅ Project linked successfully!".green());
         Ok(())
     }
-
-    fn check(&self) -> Result<()> {
-        println!("{}", "🔍 Checking database migrations...".cyan());
-        println!();
-
-        // Step 1: Check if migrations directory exists
-        let migrations_path = Path::new("supabase/migrations");
-        if !migrations_path.exists() {
-            println!("{}", "⚠️  No migrations directory found".yellow());
-            println!("   Run: akatsuki db migration-new <name> to create your first migration");
-            return Ok(());
-        }
-
-        // Step 2: List migration files
-        let mut migrations = Vec::new();
-        if let Ok(entries) = fs::read_dir(migrations_path) {
-            for entry in entries.flatten() {
-                if let Some(filename) = entry.file_name().to_str() {
-                    if filename.ends_with(".sql") {
-                        migrations.push(filename.to_string());
-                    }
-                }
-            }
-        }
-
-        migrations.sort();
-
-        if migrations.is_empty() {
-            println!("{}", "✅ No migration files found".green());
-            return Ok(());
-        }
-
-        println!(
-            "{}",
-            format!("📝 Found {} migration file(s):", migrations.len()).cyan()
-        );
-        for migration in &migrations {
-            println!("   • {}", migration);
-        }
-        println!();
-
-        // Step 3: Check migration status via Supabase CLI
-        println!("{}", "🔄 Checking migration status...".cyan());
-        let output = Command::new("supabase")
-            .args(["migration", "list"])
-            .output()
-            .context("Failed to check migration status. Make sure Supabase CLI is installed and you're linked to a project.")?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            println!(
-                "{}",
-                format!("⚠️  Could not check migration status:\n{}", stderr).yellow()
-            );
-            println!();
-            println!(
-                "{}",
-                "💡 Tip: Run 'akatsuki db link' to link to your Supabase project".cyan()
-            );
-            return Ok(());
-        }
-
-        // Display migration status
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        println!("{}", stdout);
-
-        // Step 4: Show SQL preview for latest migration
-        if let Some(latest_migration) = migrations.last() {
-            println!(
-                "{}",
-                format!("📄 Latest migration preview: {}", latest_migration).cyan()
-            );
-            println!("{}", "─".repeat(80).dimmed());
-
-            let migration_path = migrations_path.join(latest_migration);
-            if let Ok(content) = fs::read_to_string(&migration_path) {
-                // Show first 20 lines or full content if shorter
-                let lines: Vec<&str> = content.lines().collect();
-                let preview_lines = if lines.len() > 20 { 20 } else { lines.len() };
-
-                for line in &lines[..preview_lines] {
-                    println!("{}", line.dimmed());
-                }
-
-                if lines.len() > 20 {
-                    println!(
-                        "{}",
-                        format!("... ({} more lines)", lines.len() - 20).dimmed()
-                    );
-                }
-            }
-            println!("{}", "─".repeat(80).dimmed());
-        }
-
-        // Step 5: Check for multibyte characters (potential encoding issues)
-        println!();
-        println!("{}", "🔤 Checking for multibyte characters...".cyan());
-
-        let mut has_multibyte = false;
-        let mut multibyte_warnings = Vec::new();
-
-        for migration in &migrations {
-            let migration_path = migrations_path.join(migration);
-            if let Ok(content) = fs::read_to_string(&migration_path) {
-                for (line_num, line) in content.lines().enumerate() {
-                    // Check if line contains non-ASCII characters
-                    if line.chars().any(|c| !c.is_ascii()) {
-                        has_multibyte = true;
-                        // Extract the non-ASCII part (first 50 chars max)
-                        let sample: String = line.chars().take(50).collect();
-                        multibyte_warnings.push((migration.clone(), line_num + 1, sample));
-                    }
-                }
-            }
-        }
-
-        if has_multibyte {
-            println!();
-            println!(
-                "{}",
-                "⚠️  WARNING: Multibyte characters detected in migration files"
-                    .yellow()
-                    .bold()
-            );
-            println!(
-                "{}",
-                "   This may cause 'supabase db push' to fail with encoding errors".yellow()
-            );
-            println!();
-            println!("{}", "   Affected files:".yellow());
-
-            // Group by file and show first few occurrences per file
-            let mut current_file = String::new();
-            let mut count_in_file = 0;
-            const MAX_WARNINGS_PER_FILE: usize = 3;
-
-            for (file, line_num, sample) in &multibyte_warnings {
-                if *file != current_file {
-                    current_file = file.clone();
-                    count_in_file = 0;
-                    println!();
-                    println!("{}", format!("   📄 {}", file).yellow());
-                }
-
-                count_in_file += 1;
-                if count_in_file <= MAX_WARNINGS_PER_FILE {
-                    println!(
-                        "{}",
-                        format!("      Line {}: {}", line_num, sample).dimmed()
-                    );
-                } else if count_in_file == MAX_WARNINGS_PER_FILE + 1 {
-                    let remaining = multibyte_warnings
-                        .iter()
-                        .filter(|(f, _, _)| f == file)
-                        .count()
-                        - MAX_WARNINGS_PER_FILE;
-                    if remaining > 0 {
-                        println!(
-                            "{}",
-                            format!("      ... and {} more line(s)", remaining).dimmed()
-                        );
-                    }
-                }
-            }
-
-            println!();
-            println!("{}", "💡 Recommendations:".cyan());
-            println!("   1. Remove Japanese/multibyte comments from SQL files");
-            println!("   2. Use only ASCII characters (English) in migration files");
-            println!("   3. Ensure files are saved with UTF-8 encoding");
-            println!("   4. Test with: akatsuki db push --dry-run (if available)");
-            println!();
-        } else {
-            println!(
-                "{}",
-                "   ✅ No multibyte characters found (safe for push)".green()
-            );
-        }
-
-        println!();
-        println!("{}", "✅ Migration check complete!".green());
-        println!();
-        println!("{}", "💡 Next steps:".cyan());
-        println!("   • Review migration files above");
-        println!("   • Run: akatsuki db push    - to apply migrations");
-        println!("   • Run: akatsuki db status  - to check database status");
-
-        Ok(())
-    }
 }
@@ -0,0 +1,297 @@
+/**
+ * Database Plan
+ * Compares the entity schemas (YAML) with the schema replayed from the
+ * migrations (or the linked database) and proposes ALTER statements for the
+ * drift: added / dropped fields, type and NOT NULL changes, and the
+ * generator's `idx_<table>_<column>` indexes
+ */
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use super::ddl::{normalize_type, Schema};
+use crate::commands::api::DeclaredTable;
+use crate::utils::content_hash::IGNORED_DIRS;
+
+#[derive(Debug, PartialEq)]
+pub struct Change {
+    pub sql: String,
+    /// Drops data: written commented out, to be applied by hand
+    pub destructive: bool,
+}
+
+impl Change {
+    pub fn new(sql: String) -> Self {
+        Self {
+            sql,
+            destructive: false,
+        }
+    }
+
+    pub fn destructive(sql: String) -> Self {
+        Self {
+            sql,
+            destructive: true,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TablePlan {
+    /// No migration creates the table yet
+    Missing,
+    Changes(Vec<Change>),
+}
+
+/// Entity schema YAMLs under the project root (YAML files with `tableName:`);
+/// the snapshots in .akatsuki/schemas are not entity schemas
+pub fn find_schemas(root: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            !(entry.file_type().is_dir()
+                && (IGNORED_DIRS.contains(&name.as_ref()) || name == ".akatsuki"))
+        })
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.into_path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == "yaml" || ext == "yml")
+        })
+        .filter(|path| fs::read_to_string(path).is_ok_and(|content| content.contains("tableName:")))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Statements that bring `schema` in line with the declared table
+pub fn plan_table(declared: &DeclaredTable, schema: &Schema) -> TablePlan {
+    let table = &declared.table_name;
+    let Some(existing) = schema.tables.get(table) else {
+        return TablePlan::Missing;
+    };
+
+    let mut changes = Vec::new();
+    for column in &declared.columns {
+        let Some(current) = existing.iter().find(|c| c.name == column.name) else {
+            changes.push(Change::new(format!(
+                "ALTER TABLE public.{} ADD COLUMN {} {};",
+                table, column.name, column.definition
+            )));
+            continue;
+        };
+        if canonical_type(&current.data_type) != canonical_type(&column.sql_type) {
+            changes.push(Change::new(format!(
+                "ALTER TABLE public.{} ALTER COLUMN {} TYPE {} USING {}::{};",
+                table, column.name, column.sql_type, column.name, column.sql_type
+            )));
+        }
+        if current.not_null != column.not_null {
+            changes.push(Change::new(format!(
+                "ALTER TABLE public.{} ALTER COLUMN {} {} NOT NULL;",
+                table,
+                column.name,
+                if column.not_null { "SET" } else { "DROP" }
+            )));
+        }
+    }
+    for current in existing {
+        if !declared.columns.iter().any(|c| c.name == current.name) {
+            changes.push(Change::destructive(format!(
+                "ALTER TABLE public.{} DROP COLUMN {};",
+                table, current.name
+            )));
+        }
+    }
+
+    let table_indexes: Vec<_> = schema
+        .indexes
+        .iter()
+        .filter(|(_, index)| &index.table == table)
+        .collect();
+    for index in &declared.indexes {
+        let method = if index.gin { "gin" } else { "btree" };
+        let create = if index.gin {
+            format!(
+                "CREATE INDEX IF NOT EXISTS {} ON public.{} USING GIN({});",
+                index.name, table, index.column
+            )
+        } else {
+            format!(
+                "CREATE INDEX IF NOT EXISTS {} ON public.{}({});",
+                index.name, table, index.column
+            )
+        };
+        match table_indexes.iter().find(|(name, _)| **name == index.name) {
+            Some((_, current)) if current.method == method => {}
+            Some(_) => {
+                changes.push(Change::new(format!(
+                    "DROP INDEX IF EXISTS public.{};",
+                    index.name
+                )));
+                changes.push(Change::new(create));
+            }
+            // Same index under a hand-written name
+            None if table_indexes.iter().any(|(_, current)| {
+                current.columns == [index.column.as_str()] && current.method == method
+            }) => {}
+            None => changes.push(Change::new(create)),
+        }
+    }
+    // Generator-named indexes whose field is no longer indexed (indexes of
+    // dropped columns go with the column)
+    for (name, current) in &table_indexes {
+        let generated =
+            current.columns.len() == 1 && **name == format!("idx_{}_{}", table, current.columns[0]);
+        let column_kept = declared
+            .columns
+            .iter()
+            .any(|c| c.name == current.columns[0]);
+        if generated && column_kept && !declared.indexes.iter().any(|i| &i.name == *name) {
+            changes.push(Change::new(format!(
+                "DROP INDEX IF EXISTS public.{};",
+                name
+            )));
+        }
+    }
+
+    TablePlan::Changes(changes)
+}
+
+/// Migration body for the changes of each (schema file, table)
+pub fn render(plans: &[(String, String, Vec<Change>)]) -> String {
+    let mut sql = String::from(
+        "-- Schema drift between the entity schemas and the migrations\n-- Generated by `akatsuki db plan`. Review before applying.\n",
+    );
+    for (source, table, changes) in plans {
+        sql.push_str(&format!("\n-- {} ({})\n", table, source));
+        append_changes(&mut sql, changes);
+    }
+    sql
+}
+
+/// One statement per line, destructive ones commented out
+pub fn append_changes(sql: &mut String, changes: &[Change]) {
+    for change in changes {
+        if change.destructive {
+            sql.push_str(&format!(
+                "-- ⚠️  Destructive (drops data); uncomment to apply:\n-- {}\n",
+                change.sql
+            ));
+        } else {
+            sql.push_str(&format!("{}\n", change.sql));
+        }
+    }
+}
+
+/// Type name with Postgres aliases folded (`int4` = `integer`, `bool` = `boolean`, ...)
+fn canonical_type(raw: &str) -> String {
+    let normalized = normalize_type(&raw.to_lowercase());
+    if let Some(element) = normalized.strip_suffix("[]") {
+        return format!("{}[]", canonical_type(element));
+    }
+    match normalized.as_str() {
+        "int" | "int4" | "serial" | "serial4" => "integer",
+        "int8" | "bigserial" | "serial8" => "bigint",
+        "int2" => "smallint",
+        "bool" => "boolean",
+        "decimal" => "numeric",
+        "float4" => "real",
+        "character varying" => "varchar",
+        other => other,
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::api::{DeclaredColumn, DeclaredIndex};
+
+    fn column(name: &str, sql_type: &str, not_null: bool) -> DeclaredColumn {
+        DeclaredColumn {
+            name: name.to_string(),
+            sql_type: sql_type.to_string(),
+            not_null,
+            definition: format!("{}{}", sql_type, if not_null { " NOT NULL" } else { "" }),
+        }
+    }
+
+    fn index(column: &str, gin: bool) -> DeclaredIndex {
+        DeclaredIndex {
+            name: format!("idx_articles_{}", column),
+            column: column.to_string(),
+            gin,
+        }
+    }
+
+    #[test]
+    fn test_plans_column_and_index_drift() {
+        let mut schema = Schema::default();
+        schema.apply(
+            "CREATE TABLE public.articles (
+               id uuid PRIMARY KEY,
+               views int4 NOT NULL,
+               title varchar(200),
+               body text,
+               status text,
+               legacy text
+             );
+             CREATE INDEX idx_articles_status ON public.articles(status);
+             CREATE INDEX idx_articles_tags ON public.articles(tags);
+             CREATE INDEX articles_body_search ON public.articles USING gin (body);
+             CREATE INDEX idx_articles_legacy ON public.articles(legacy);",
+        );
+        let declared = DeclaredTable {
+            name: "Article".to_string(),
+            table_name: "articles".to_string(),
+            columns: vec![
+                column("id", "UUID", true),
+                column("views", "INTEGER", true),
+                column("title", "TEXT", true),
+                column("body", "TEXT", false),
+                column("status", "TEXT", false),
+                column("tags", "TEXT[]", false),
+            ],
+            indexes: vec![index("body", true), index("tags", true)],
+        };
+
+        let TablePlan::Changes(changes) = plan_table(&declared, &schema) else {
+            panic!("articles should exist");
+        };
+        let sql: Vec<&str> = changes.iter().map(|c| c.sql.as_str()).collect();
+        assert_eq!(
+            sql,
+            [
+                "ALTER TABLE public.articles ALTER COLUMN title TYPE TEXT USING title::TEXT;",
+                "ALTER TABLE public.articles ALTER COLUMN title SET NOT NULL;",
+                "ALTER TABLE public.articles ADD COLUMN tags TEXT[];",
+                "ALTER TABLE public.articles DROP COLUMN legacy;",
+                "DROP INDEX IF EXISTS public.idx_articles_tags;",
+                "CREATE INDEX IF NOT EXISTS idx_articles_tags ON public.articles USING GIN(tags);",
+                "DROP INDEX IF EXISTS public.idx_articles_status;",
+            ]
+        );
+        assert!(changes[3].destructive);
+        assert!(!changes[0].destructive);
+
+        let missing = DeclaredTable {
+            name: "Comment".to_string(),
+            table_name: "comments".to_string(),
+            columns: Vec::new(),
+            indexes: Vec::new(),
+        };
+        assert_eq!(plan_table(&missing, &schema), TablePlan::Missing);
+
+        let sql = render(&[(
+            "schemas/article.yaml".to_string(),
+            "articles".to_string(),
+            changes,
+        )]);
+        assert!(sql.contains("\n-- articles (schemas/article.yaml)\n"));
+        assert!(sql.contains(
+            "-- ⚠️  Destructive (drops data); uncomment to apply:\n-- ALTER TABLE public.articles DROP COLUMN legacy;\n"
+        ));
+    }
+}
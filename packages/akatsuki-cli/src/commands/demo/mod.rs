@@ -0,0 +1,646 @@
+/**
+ * Demo Command
+ * Showcase data for a fresh deployment in one step
+ *
+ * - seed: demo user, factory-built records for every entity with a generated
+ *   factory (`akatsuki api factories`) and sample images in Storage
+ * - clean: removes exactly what `seed` created
+ *
+ * Everything created is recorded in .akatsuki/demo/state.json as it
+ * happens, so `clean` also works after a partial seed.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use crate::cli::DemoAction;
+use crate::commands::api::{self, SeedEntity, FACTORIES_DIR};
+use crate::commands::db::plan::find_schemas;
+use crate::utils::config::{DemoConfig, ProjectConfig, CONFIG_FILE};
+use crate::utils::find_project_root;
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+use crate::utils::supabase::{self, SupabaseAdmin};
+
+const STATE_FILE: &str = ".akatsuki/demo/state.json";
+const RECORDS_SCRIPT: &str = ".akatsuki/demo/build-records.mts";
+const FRONTEND_DIR: &str = "packages/app-frontend";
+const PLACEHOLDER_IMAGES: usize = 3;
+const IMAGE_EXTENSIONS: [(&str, &str); 5] = [
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("webp", "image/webp"),
+    ("gif", "image/gif"),
+];
+/// String fields filled with sample image URLs instead of factory text
+const IMAGE_FIELD_HINTS: [&str; 6] = ["image", "thumbnail", "avatar", "photo", "cover", "picture"];
+
+/// Builds `[{ name, count }]` records with the generated factories and
+/// prints them as `{ <Entity>: [...] }`
+const RECORDS_SCRIPT_SOURCE: &str = r#"// Generated by `akatsuki demo seed`
+import { readFileSync } from 'node:fs'
+import { pathToFileURL } from 'node:url'
+
+const [index, specFile] = process.argv.slice(2)
+const factories = await import(pathToFileURL(index).href)
+factories.seedFactories?.(Date.now())
+const spec: { name: string; count: number }[] = JSON.parse(readFileSync(specFile, 'utf8'))
+const records: Record<string, unknown[]> = {}
+for (const { name, count } of spec) {
+  records[name] = factories[`build${name}List`](count)
+}
+process.stdout.write(JSON.stringify(records))
+"#;
+
+/// What `demo seed` created (.akatsuki/demo/state.json)
+#[derive(Debug, Serialize, Deserialize)]
+struct DemoState {
+    supabase_url: String,
+    user_id: String,
+    email: String,
+    bucket: String,
+    /// Object paths in `bucket`
+    #[serde(default)]
+    images: Vec<String>,
+    /// In insertion order (cleaned in reverse)
+    #[serde(default)]
+    records: Vec<SeededTable>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SeededTable {
+    table: String,
+    ids: Vec<String>,
+}
+
+/// Values shared by every row of a seed run
+struct RowContext<'a> {
+    user_id: &'a str,
+    /// Ids already inserted, by table
+    ids: &'a BTreeMap<String, Vec<String>>,
+    image_urls: &'a [String],
+}
+
+struct Image {
+    name: String,
+    content_type: &'static str,
+    bytes: Vec<u8>,
+}
+
+pub struct DemoCommand;
+
+impl DemoCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, action: DemoAction) -> Result<()> {
+        let root = find_project_root();
+        match action {
+            DemoAction::Seed { records, yes } => seed(&root, records, yes),
+            DemoAction::Clean { yes } => clean(&root, yes),
+        }
+    }
+}
+
+fn seed(root: &Path, records: Option<usize>, yes: bool) -> Result<()> {
+    if root.join(STATE_FILE).exists() {
+        anyhow::bail!(
+            "Demo data from an earlier seed is still recorded in {}; run `akatsuki demo clean` first",
+            STATE_FILE
+        );
+    }
+    let config = ProjectConfig::load()?.demo;
+    let count = records.unwrap_or(config.records);
+    if count == 0 {
+        anyhow::bail!("Nothing to seed: records must be at least 1");
+    }
+
+    let entities = seed_order(seedable_entities(root)?);
+    let admin = SupabaseAdmin::from_env(root)?;
+    if !confirm_target(root, admin.url(), yes)? {
+        println!("{}", "Cancelled".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "🏭 Building {} records per entity with the factories...",
+            count
+        )
+        .cyan()
+    );
+    let mut built = build_records(root, &entities, count)?;
+
+    let password = config.password.clone().unwrap_or_else(generate_password);
+    println!(
+        "{}",
+        format!("👤 Creating demo user {}...", config.email).cyan()
+    );
+    let user_id = admin
+        .create_user(&config.email, &password)
+        .with_context(|| format!("Failed to create the demo user {}", config.email))?;
+    let mut state = DemoState {
+        supabase_url: admin.url().to_string(),
+        user_id: user_id.clone(),
+        email: config.email.clone(),
+        bucket: config.bucket.clone(),
+        images: Vec::new(),
+        records: Vec::new(),
+    };
+    save_state(root, &state)?;
+
+    println!(
+        "{}",
+        format!("🖼  Uploading sample images to '{}'...", config.bucket).cyan()
+    );
+    admin.ensure_bucket(&config.bucket)?;
+    let mut image_urls = Vec::new();
+    for image in images(root, &config)? {
+        let path = format!("{}/{}", user_id, image.name);
+        admin.upload(&config.bucket, &path, image.bytes, image.content_type)?;
+        image_urls.push(admin.public_url(&config.bucket, &path));
+        state.images.push(path);
+        save_state(root, &state)?;
+    }
+
+    let mut ids: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut failures = 0;
+    for entity in &entities {
+        let context = RowContext {
+            user_id: &user_id,
+            ids: &ids,
+            image_urls: &image_urls,
+        };
+        let rows: Result<Vec<Value>, String> = built
+            .remove(&entity.name)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(|(index, record)| to_row(entity, record, index, &context).map(Value::Object))
+            .collect();
+        let inserted = rows
+            .map_err(anyhow::Error::msg)
+            .and_then(|rows| admin.insert(&entity.table_name, &rows));
+        match inserted {
+            Ok(inserted) => {
+                println!(
+                    "  {} {} ({} records)",
+                    "✓".green(),
+                    entity.table_name,
+                    inserted.len()
+                );
+                state.records.push(SeededTable {
+                    table: entity.table_name.clone(),
+                    ids: inserted.clone(),
+                });
+                save_state(root, &state)?;
+                ids.insert(entity.table_name.clone(), inserted);
+            }
+            Err(e) => {
+                failures += 1;
+                println!("  {} {}: {:#}", "✗".red(), entity.table_name, e);
+            }
+        }
+    }
+
+    println!("\n{}", "🎭 Demo data ready".green().bold());
+    println!("  Email:    {}", config.email);
+    println!("  Password: {}", password);
+    println!("  Remove it with `akatsuki demo clean`");
+    if failures > 0 {
+        anyhow::bail!(
+            "{} of {} entities could not be seeded",
+            failures,
+            entities.len()
+        );
+    }
+    Ok(())
+}
+
+fn clean(root: &Path, yes: bool) -> Result<()> {
+    let state: DemoState = fs::read_to_string(root.join(STATE_FILE))
+        .ok()
+        .map(|content| serde_json::from_str(&content))
+        .transpose()
+        .with_context(|| format!("Invalid {}", STATE_FILE))?
+        .with_context(|| format!("No demo data recorded ({} not found)", STATE_FILE))?;
+    let admin = SupabaseAdmin::from_env(root)?;
+    if admin.url() != state.supabase_url.trim_end_matches('/') {
+        anyhow::bail!(
+            "The demo data was seeded into {}, but SUPABASE_URL is {}",
+            state.supabase_url,
+            admin.url()
+        );
+    }
+    if !confirm_target(root, admin.url(), yes)? {
+        println!("{}", "Cancelled".yellow());
+        return Ok(());
+    }
+
+    println!("{}", "🧹 Removing demo data...".cyan());
+    for seeded in state.records.iter().rev() {
+        admin
+            .delete_rows(&seeded.table, &seeded.ids)
+            .with_context(|| format!("Failed to delete the demo records of {}", seeded.table))?;
+        println!(
+            "  {} {} ({} records)",
+            "✓".green(),
+            seeded.table,
+            seeded.ids.len()
+        );
+    }
+    admin.remove_objects(&state.bucket, &state.images)?;
+    println!("  {} {} images", "✓".green(), state.images.len());
+    admin.delete_user(&state.user_id)?;
+    println!("  {} user {}", "✓".green(), state.email);
+
+    fs::remove_file(root.join(STATE_FILE))?;
+    println!("{}", "✅ Demo data removed".green());
+    Ok(())
+}
+
+/// Schemas whose factory has been generated; the others are reported
+fn seedable_entities(root: &Path) -> Result<Vec<SeedEntity>> {
+    let mut entities = Vec::new();
+    let mut tables = BTreeSet::new();
+    let mut missing = Vec::new();
+    for path in find_schemas(root) {
+        let entity = api::seed_entity(&path)?;
+        if !tables.insert(entity.table_name.clone()) {
+            continue;
+        }
+        if root
+            .join(FACTORIES_DIR)
+            .join(format!("{}Factory.ts", entity.name))
+            .exists()
+        {
+            entities.push(entity);
+        } else {
+            missing.push(entity.name);
+        }
+    }
+
+    if !missing.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "⚠️  No factory for {} (skipped; generate it with `akatsuki api factories`)",
+                missing.join(", ")
+            )
+            .yellow()
+        );
+    }
+    if entities.is_empty() {
+        anyhow::bail!(
+            "No entity factories in {}. Generate them with `akatsuki api factories <schema.yaml>...`",
+            FACTORIES_DIR
+        );
+    }
+    Ok(entities)
+}
+
+/// Referenced tables before the tables referencing them (cycles keep schema order)
+fn seed_order(mut pending: Vec<SeedEntity>) -> Vec<SeedEntity> {
+    let tables: BTreeSet<String> = pending.iter().map(|e| e.table_name.clone()).collect();
+    let mut ordered: Vec<SeedEntity> = Vec::new();
+    while !pending.is_empty() {
+        let ready = pending
+            .iter()
+            .position(|entity| {
+                entity
+                    .columns
+                    .iter()
+                    .all(|column| match &column.references {
+                        Some(table) if *table != entity.table_name && tables.contains(table) => {
+                            ordered.iter().any(|done| done.table_name == *table)
+                        }
+                        _ => true,
+                    })
+            })
+            .unwrap_or(0);
+        ordered.push(pending.remove(ready));
+    }
+    ordered
+}
+
+/// Row for one factory record: owner columns get the demo user, foreign
+/// keys point at seeded records and image fields at the uploaded samples
+fn to_row(
+    entity: &SeedEntity,
+    record: &Value,
+    index: usize,
+    context: &RowContext,
+) -> Result<Map<String, Value>, String> {
+    let mut row = Map::new();
+    for column in &entity.columns {
+        let value =
+            if column.column == "user_id" || column.references.as_deref() == Some("auth.users") {
+                Value::String(context.user_id.to_string())
+            } else if let Some(table) = &column.references {
+                match context.ids.get(table).filter(|ids| !ids.is_empty()) {
+                    Some(ids) => Value::String(ids[index % ids.len()].clone()),
+                    None if column.required => {
+                        return Err(format!(
+                            "{} references {}, which has no demo records",
+                            column.column, table
+                        ))
+                    }
+                    None => Value::Null,
+                }
+            } else if column.is_string
+                && is_image_field(&column.field)
+                && !context.image_urls.is_empty()
+            {
+                Value::String(context.image_urls[index % context.image_urls.len()].clone())
+            } else {
+                match record.get(&column.field) {
+                    Some(value) => value.clone(),
+                    None => continue,
+                }
+            };
+        row.insert(column.column.clone(), value);
+    }
+    Ok(row)
+}
+
+fn is_image_field(field: &str) -> bool {
+    let field = field.to_ascii_lowercase();
+    IMAGE_FIELD_HINTS.iter().any(|hint| field.contains(hint))
+}
+
+/// Factory output per entity name, via `npx tsx` in the frontend package
+fn build_records(
+    root: &Path,
+    entities: &[SeedEntity],
+    count: usize,
+) -> Result<BTreeMap<String, Vec<Value>>> {
+    let script = root.join(RECORDS_SCRIPT);
+    let spec = script.with_file_name("spec.json");
+    fs::create_dir_all(script.parent().unwrap_or(root))?;
+    fs::write(&script, RECORDS_SCRIPT_SOURCE)?;
+    let spec_json: Vec<Value> = entities
+        .iter()
+        .map(|entity| serde_json::json!({ "name": entity.name, "count": count }))
+        .collect();
+    fs::write(&spec, serde_json::to_string(&spec_json)?)?;
+
+    let output = platform::command("npx")
+        .args(["--yes", "tsx"])
+        .arg(&script)
+        .arg(root.join(FACTORIES_DIR).join("index.ts"))
+        .arg(&spec)
+        .current_dir(root.join(FRONTEND_DIR))
+        .timed_output()
+        .context("Failed to run npx tsx")?;
+    if !output.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        anyhow::bail!(
+            "Building the demo records failed (is @faker-js/faker installed in {}?)",
+            FRONTEND_DIR
+        );
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // npx may print install notices before the JSON
+    let json = stdout
+        .find('{')
+        .map(|start| &stdout[start..])
+        .unwrap_or(&stdout);
+    serde_json::from_str(json).context("Could not parse the factory output")
+}
+
+/// Ask before writing to the production project; false when the user declines
+fn confirm_target(root: &Path, url: &str, yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    let Some(production) = supabase::production_ref(root, url) else {
+        return Ok(true);
+    };
+
+    println!(
+        "{}",
+        format!(
+            "⚠️  SUPABASE_URL points at the production project ({})",
+            production
+        )
+        .yellow()
+        .bold()
+    );
+    Ok(dialoguer::Confirm::new()
+        .with_prompt("Change demo data in the production project?")
+        .default(false)
+        .interact()?)
+}
+
+/// Images from `images_dir`, or generated placeholders
+fn images(root: &Path, config: &DemoConfig) -> Result<Vec<Image>> {
+    let Some(dir) = &config.images_dir else {
+        return (0..PLACEHOLDER_IMAGES).map(placeholder_image).collect();
+    };
+    let dir = root.join(dir);
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .with_context(|| {
+            format!(
+                "Failed to read images_dir {} ([demo] of {})",
+                dir.display(),
+                CONFIG_FILE
+            )
+        })?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    paths.sort();
+
+    let mut images = Vec::new();
+    for path in paths {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_default();
+        let Some((_, content_type)) = IMAGE_EXTENSIONS.iter().find(|(ext, _)| *ext == extension)
+        else {
+            continue;
+        };
+        images.push(Image {
+            name: path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned(),
+            content_type,
+            bytes: fs::read(&path)?,
+        });
+    }
+    if images.is_empty() {
+        anyhow::bail!("No images (png, jpg, webp, gif) in {}", dir.display());
+    }
+    Ok(images)
+}
+
+/// 640x400 diagonal gradient, a different hue per index
+fn placeholder_image(index: usize) -> Result<Image> {
+    const WIDTH: u32 = 640;
+    const HEIGHT: u32 = 400;
+    const PALETTE: [([u8; 3], [u8; 3]); 3] = [
+        ([239, 68, 68], [249, 115, 22]),
+        ([59, 130, 246], [139, 92, 246]),
+        ([16, 185, 129], [6, 182, 212]),
+    ];
+    let (from, to) = PALETTE[index % PALETTE.len()];
+    let mut pixels = Vec::with_capacity((WIDTH * HEIGHT * 3) as usize);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let t = (x + y) as f32 / (WIDTH + HEIGHT - 2) as f32;
+            for channel in 0..3 {
+                let value = from[channel] as f32 + (to[channel] as f32 - from[channel] as f32) * t;
+                pixels.push(value.round() as u8);
+            }
+        }
+    }
+
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, WIDTH, HEIGHT);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+    Ok(Image {
+        name: format!("sample-{}.png", index + 1),
+        content_type: "image/png",
+        bytes,
+    })
+}
+
+fn generate_password() -> String {
+    use sha2::{Digest, Sha256};
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let digest = Sha256::digest(format!("{}-{}", nanos, std::process::id()));
+    format!("{:x}", digest)[..20].to_string()
+}
+
+fn save_state(root: &Path, state: &DemoState) -> Result<()> {
+    let path = root.join(STATE_FILE);
+    fs::create_dir_all(path.parent().unwrap_or(root))?;
+    fs::write(&path, serde_json::to_string_pretty(state)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::api::SeedColumn;
+
+    fn column(field: &str, column: &str, references: Option<&str>, required: bool) -> SeedColumn {
+        SeedColumn {
+            field: field.to_string(),
+            column: column.to_string(),
+            required,
+            references: references.map(str::to_string),
+            is_string: true,
+        }
+    }
+
+    fn entity(name: &str, table: &str, columns: Vec<SeedColumn>) -> SeedEntity {
+        SeedEntity {
+            name: name.to_string(),
+            table_name: table.to_string(),
+            columns,
+        }
+    }
+
+    #[test]
+    fn test_seed_order() {
+        let comments = entity(
+            "Comment",
+            "comments",
+            vec![
+                column("articleId", "article_id", Some("articles"), true),
+                column("parentId", "parent_id", Some("comments"), false),
+            ],
+        );
+        let articles = entity(
+            "Article",
+            "articles",
+            vec![
+                column("authorId", "author_id", Some("authors"), true),
+                column("userId", "user_id", Some("auth.users"), true),
+            ],
+        );
+        let authors = entity(
+            "Author",
+            "authors",
+            vec![column("name", "name", None, true)],
+        );
+
+        let ordered = seed_order(vec![comments, articles, authors]);
+        let names: Vec<&str> = ordered.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["Author", "Article", "Comment"]);
+    }
+
+    #[test]
+    fn test_to_row() {
+        let article = entity(
+            "Article",
+            "articles",
+            vec![
+                column("title", "title", None, true),
+                column("coverImage", "cover_image", None, false),
+                column("authorId", "author_id", Some("authors"), true),
+                column("ownerId", "owner_id", Some("auth.users"), true),
+                column("editorId", "editor_id", Some("editors"), false),
+                column("summary", "summary", None, false),
+            ],
+        );
+        let ids = BTreeMap::from([(
+            "authors".to_string(),
+            vec!["a-1".to_string(), "a-2".to_string()],
+        )]);
+        let image_urls = vec!["https://x/1.png".to_string()];
+        let context = RowContext {
+            user_id: "u-1",
+            ids: &ids,
+            image_urls: &image_urls,
+        };
+        let record =
+            serde_json::json!({ "title": "Hello", "coverImage": "lorem", "authorId": "fake" });
+
+        let row = to_row(&article, &record, 3, &context).unwrap();
+        assert_eq!(row["title"], "Hello");
+        assert_eq!(row["cover_image"], "https://x/1.png");
+        assert_eq!(row["author_id"], "a-2");
+        assert_eq!(row["owner_id"], "u-1");
+        assert_eq!(row["editor_id"], Value::Null);
+        // Fields the factory did not build keep their column default
+        assert!(!row.contains_key("summary"));
+
+        let empty = BTreeMap::new();
+        let context = RowContext {
+            user_id: "u-1",
+            ids: &empty,
+            image_urls: &[],
+        };
+        let error = to_row(&article, &record, 0, &context).unwrap_err();
+        assert_eq!(
+            error,
+            "author_id references authors, which has no demo records"
+        );
+    }
+
+    #[test]
+    fn test_placeholder_images() {
+        let image = placeholder_image(1).unwrap();
+        assert_eq!(image.name, "sample-2.png");
+        let decoder = png::Decoder::new(image.bytes.as_slice());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (640, 400));
+    }
+}
@@ -0,0 +1,297 @@
+/**
+ * License Inventory
+ * Collects third-party licenses from `cargo metadata` and npm lockfiles
+ */
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::process::Command;
+
+use super::sources::Ecosystem;
+use crate::utils::config::LicenseConfig;
+
+/// npm lockfiles covering the workspace (root workspaces + standalone packages)
+const NPM_LOCKFILES: [&str; 2] = ["package-lock.json", "packages/app-cli/package-lock.json"];
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct PackageLicense {
+    pub ecosystem: Ecosystem,
+    pub name: String,
+    pub version: String,
+    /// SPDX expression as declared by the package (`None` when missing)
+    pub license: Option<String>,
+    pub repository: Option<String>,
+}
+
+impl PackageLicense {
+    pub fn license_label(&self) -> &str {
+        self.license.as_deref().unwrap_or("UNKNOWN")
+    }
+}
+
+// ========================================
+// Collection
+// ========================================
+
+#[derive(Debug, Deserialize)]
+struct CargoMetadata {
+    packages: Vec<CargoPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoPackage {
+    name: String,
+    version: String,
+    license: Option<String>,
+    repository: Option<String>,
+    /// `None` for local path/workspace crates
+    source: Option<String>,
+}
+
+pub fn parse_cargo_metadata(json: &str) -> Result<Vec<PackageLicense>> {
+    let metadata: CargoMetadata =
+        serde_json::from_str(json).context("Failed to parse cargo metadata output")?;
+    Ok(metadata
+        .packages
+        .into_iter()
+        .filter(|package| package.source.is_some())
+        .map(|package| PackageLicense {
+            ecosystem: Ecosystem::Cargo,
+            name: package.name,
+            version: package.version,
+            // Older crates use "MIT/Apache-2.0" for "MIT OR Apache-2.0"
+            license: package.license.map(|l| l.replace('/', " OR ")),
+            repository: package.repository,
+        })
+        .collect())
+}
+
+pub fn cargo_licenses(dir: &Path) -> Result<Vec<PackageLicense>> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--format-version", "1"])
+        .current_dir(dir)
+        .output()
+        .context("Failed to run cargo metadata")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "cargo metadata failed in {}:\n{}",
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    parse_cargo_metadata(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmLockfile {
+    #[serde(default)]
+    packages: BTreeMap<String, NpmLockPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmLockPackage {
+    version: Option<String>,
+    license: Option<String>,
+    #[serde(default)]
+    dev: bool,
+    #[serde(default)]
+    link: bool,
+}
+
+/// Installed packages from a lockfile v2/v3 (`packages` section)
+pub fn parse_npm_lockfile(json: &str, include_dev: bool) -> Result<Vec<PackageLicense>> {
+    let lockfile: NpmLockfile =
+        serde_json::from_str(json).context("Failed to parse package-lock.json")?;
+    Ok(lockfile
+        .packages
+        .into_iter()
+        .filter(|(_, package)| !package.link && (include_dev || !package.dev))
+        .filter_map(|(path, package)| {
+            // "node_modules/a/node_modules/@scope/b" -> "@scope/b" (workspaces have no prefix)
+            let name = path.rsplit_once("node_modules/")?.1.to_string();
+            Some(PackageLicense {
+                ecosystem: Ecosystem::Npm,
+                name,
+                version: package.version?,
+                license: package.license,
+                repository: None,
+            })
+        })
+        .collect())
+}
+
+/// Every third-party package in the workspace, deduplicated and sorted
+pub fn collect(
+    root: &Path,
+    cargo_projects: &[std::path::PathBuf],
+    include_dev: bool,
+) -> Result<Vec<PackageLicense>> {
+    let mut packages = BTreeSet::new();
+    for dir in cargo_projects {
+        packages.extend(cargo_licenses(dir)?);
+    }
+    for lockfile in NPM_LOCKFILES {
+        let path = root.join(lockfile);
+        if !path.exists() {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        packages.extend(
+            parse_npm_lockfile(&content, include_dev)
+                .with_context(|| format!("Invalid {}", lockfile))?,
+        );
+    }
+    Ok(packages.into_iter().collect())
+}
+
+// ========================================
+// Policy
+// ========================================
+
+/// Whether an SPDX expression is disallowed by `deny`.
+///
+/// `OR` alternatives are allowed when any alternative is allowed; `AND` terms
+/// are denied when any term is denied. Parentheses and `WITH` exceptions are ignored.
+pub fn is_denied(expression: &str, deny: &[String]) -> bool {
+    let term_denied = |term: &str| {
+        let id = term.split(" WITH ").next().unwrap_or(term).trim();
+        let id = id.trim_end_matches('+');
+        deny.iter().any(|denied| {
+            id.eq_ignore_ascii_case(denied)
+                || id
+                    .to_ascii_lowercase()
+                    .starts_with(&format!("{}-", denied.to_ascii_lowercase()))
+        })
+    };
+
+    let cleaned = expression.replace(['(', ')'], " ");
+    cleaned
+        .split(" OR ")
+        .all(|alternative| alternative.split(" AND ").any(term_denied))
+}
+
+/// Packages violating the policy (exempted packages are skipped)
+pub fn violations<'a>(
+    packages: &'a [PackageLicense],
+    config: &LicenseConfig,
+) -> Vec<&'a PackageLicense> {
+    packages
+        .iter()
+        .filter(|package| !config.allow_packages.contains(&package.name))
+        .filter(|package| {
+            package
+                .license
+                .as_deref()
+                .is_some_and(|license| is_denied(license, &config.deny))
+        })
+        .collect()
+}
+
+/// NOTICE file listing every third-party package grouped by license
+pub fn render_notice(project: &str, packages: &[PackageLicense]) -> String {
+    let mut by_license: BTreeMap<&str, Vec<&PackageLicense>> = BTreeMap::new();
+    for package in packages {
+        by_license
+            .entry(package.license_label())
+            .or_default()
+            .push(package);
+    }
+
+    let mut notice = format!(
+        "{}\n\nThis product includes third-party software distributed under the\n\
+         following licenses. Generated by `akatsuki deps licenses --notice`.\n",
+        project
+    );
+    for (license, packages) in by_license {
+        notice.push_str(&format!("\n{}\n{}\n", license, "=".repeat(license.len())));
+        for package in packages {
+            notice.push_str(&format!(
+                "- {} {} ({})",
+                package.name,
+                package.version,
+                package.ecosystem.as_str()
+            ));
+            if let Some(repository) = &package.repository {
+                notice.push_str(&format!(" {}", repository));
+            }
+            notice.push('\n');
+        }
+    }
+    notice
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deny() -> Vec<String> {
+        LicenseConfig::default().deny
+    }
+
+    #[test]
+    fn test_is_denied() {
+        assert!(!is_denied("MIT", &deny()));
+        assert!(is_denied("GPL-3.0-only", &deny()));
+        assert!(is_denied("GPL-2.0+", &deny()));
+        assert!(!is_denied("LGPL-3.0", &deny()));
+        assert!(!is_denied("MIT OR GPL-3.0", &deny()));
+        assert!(is_denied("MIT AND GPL-3.0-or-later", &deny()));
+        assert!(is_denied("(GPL-2.0 WITH Classpath-exception-2.0)", &deny()));
+    }
+
+    #[test]
+    fn test_parse_cargo_metadata() {
+        let json = r#"{"packages":[
+            {"name":"akatsuki-cli","version":"0.1.0","license":"MIT","repository":null,"source":null},
+            {"name":"anyhow","version":"1.0.0","license":"MIT/Apache-2.0","repository":"https://github.com/dtolnay/anyhow","source":"registry+https://github.com/rust-lang/crates.io-index"}
+        ]}"#;
+        let packages = parse_cargo_metadata(json).unwrap();
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].license.as_deref(), Some("MIT OR Apache-2.0"));
+    }
+
+    #[test]
+    fn test_parse_npm_lockfile() {
+        let json = r#"{"lockfileVersion":3,"packages":{
+            "":{"name":"akatsuki"},
+            "node_modules/@akatsuki/ai-agent-ui":{"resolved":"packages/ai-agent-ui","link":true},
+            "node_modules/react":{"version":"18.3.1","license":"MIT"},
+            "node_modules/a/node_modules/@scope/b":{"version":"1.0.0"},
+            "node_modules/vitest":{"version":"2.0.0","license":"MIT","dev":true}
+        }}"#;
+        let packages = parse_npm_lockfile(json, false).unwrap();
+        let names: Vec<&str> = packages.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["@scope/b", "react"]);
+        assert_eq!(packages[0].license_label(), "UNKNOWN");
+
+        assert_eq!(parse_npm_lockfile(json, true).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_violations_and_notice() {
+        let package = |name: &str, license: &str| PackageLicense {
+            ecosystem: Ecosystem::Npm,
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            license: Some(license.to_string()),
+            repository: None,
+        };
+        let packages = vec![
+            package("a", "MIT"),
+            package("b", "AGPL-3.0"),
+            package("c", "GPL-3.0"),
+        ];
+        let config = LicenseConfig {
+            allow_packages: vec!["c".to_string()],
+            ..Default::default()
+        };
+        let denied = violations(&packages, &config);
+        assert_eq!(denied.len(), 1);
+        assert_eq!(denied[0].name, "b");
+
+        let notice = render_notice("Akatsuki", &packages);
+        assert!(notice.contains("MIT\n===\n- a 1.0.0 (npm)\n"));
+    }
+}
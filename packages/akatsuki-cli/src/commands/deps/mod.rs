@@ -0,0 +1,558 @@
+/**
+ * Dependency Management Command
+ * Aggregates cargo/npm outdated, audit and license reports across the workspace
+ *
+ * Tools:
+ * - cargo outdated / cargo audit (per crate in packages/ and wasm-modules/)
+ * - npm outdated / npm audit (npm workspaces from the project root)
+ * - cargo metadata / package-lock.json (licenses)
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::cli::DepsAction;
+use crate::utils::config::{ProjectConfig, CONFIG_FILE};
+use crate::utils::find_project_root;
+use crate::utils::output::OutputFormatter;
+use crate::utils::platform;
+
+mod licenses;
+mod sources;
+
+use licenses::PackageLicense;
+use sources::{Bump, Ecosystem, OutdatedDependency, ToolOutput, Vulnerability};
+
+pub struct DepsCommand;
+
+#[derive(Serialize)]
+struct OutdatedEntry<'a> {
+    #[serde(flatten)]
+    dependency: &'a OutdatedDependency,
+    /// Bump to the latest version
+    bump: Option<Bump>,
+}
+
+#[derive(Serialize)]
+struct LicenseCount<'a> {
+    license: &'a str,
+    cargo: usize,
+    npm: usize,
+}
+
+/// `deps licenses` result
+#[derive(Serialize)]
+struct LicenseReport<'a> {
+    /// Most used first
+    licenses: Vec<LicenseCount<'a>>,
+    packages: &'a [PackageLicense],
+    include_dev: bool,
+    /// Packages declaring no license
+    unknown: usize,
+    denied: Vec<&'a PackageLicense>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notice: Option<PathBuf>,
+}
+
+impl DepsCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, action: DepsAction, output: OutputFormatter) -> Result<()> {
+        match action {
+            DepsAction::Outdated => self.outdated(output),
+            DepsAction::Update { minor, dry_run } => self.update(minor, dry_run),
+            DepsAction::Audit => self.audit(output),
+            DepsAction::Licenses {
+                include_dev,
+                notice,
+            } => self.licenses(include_dev, notice.as_deref(), output),
+        }
+    }
+
+    fn outdated(&self, output: OutputFormatter) -> Result<()> {
+        if !output.is_json() {
+            println!("{}", "📦 Outdated Dependencies".bright_cyan().bold());
+            println!("{}", "─".repeat(50).bright_black());
+        }
+
+        let outdated = collect_outdated(output)?;
+        let entries: Vec<OutdatedEntry> = outdated
+            .iter()
+            .map(|dependency| OutdatedEntry {
+                dependency,
+                bump: dependency.latest_bump(),
+            })
+            .collect();
+        output.emit(&entries, |_| print_outdated(&outdated))
+    }
+
+    fn update(&self, minor: bool, dry_run: bool) -> Result<()> {
+        let max = if minor { Bump::Minor } else { Bump::Patch };
+        println!(
+            "{}",
+            format!("⬆️  Updating dependencies (up to {} bumps)", max.as_str())
+                .bright_cyan()
+                .bold()
+        );
+        println!("{}", "─".repeat(50).bright_black());
+
+        let outdated = collect_outdated(OutputFormatter::default())?;
+        let updates: Vec<(&OutdatedDependency, &str, Bump)> = outdated
+            .iter()
+            .filter_map(|dep| {
+                dep.safe_target(max)
+                    .map(|(version, bump)| (dep, version, bump))
+            })
+            .collect();
+
+        if updates.is_empty() {
+            println!("\n{}", "✅ No safe updates available".green());
+            return Ok(());
+        }
+
+        let root = find_project_root();
+        let mut failed = 0;
+        println!();
+        for (dep, version, bump) in &updates {
+            let (program, args, dir) = update_command(&root, dep, version);
+            println!(
+                "  {} {} {} → {} {}",
+                "•".bright_blue(),
+                format!("[{}] {}", dep.project, dep.name).bright_white(),
+                dep.current.bright_black(),
+                version.green(),
+                format!("({})", bump.as_str()).bright_black()
+            );
+            println!(
+                "    {}",
+                format!("$ {} {}", program, args.join(" ")).bright_black()
+            );
+            if dry_run {
+                continue;
+            }
+
+            let status = platform::command(program)
+                .args(&args)
+                .current_dir(&dir)
+                .status()
+                .with_context(|| format!("Failed to run {}", program))?;
+            if !status.success() {
+                println!("    {}", "✗ update failed".red());
+                failed += 1;
+            }
+        }
+
+        let skipped = outdated.len() - updates.len();
+        println!();
+        if dry_run {
+            println!(
+                "{} {} update(s) planned, {} skipped (exceed {} bumps)",
+                "🔍".bright_blue(),
+                updates.len(),
+                skipped,
+                max.as_str()
+            );
+            return Ok(());
+        }
+
+        println!(
+            "{} {} updated, {} failed, {} skipped (exceed {} bumps)",
+            "📊".bright_blue(),
+            updates.len() - failed,
+            failed,
+            skipped,
+            max.as_str()
+        );
+        if failed > 0 {
+            anyhow::bail!("{} dependency update(s) failed", failed);
+        }
+        println!("💡 Verify with: {}", "akatsuki preflight".cyan());
+        Ok(())
+    }
+
+    fn audit(&self, output: OutputFormatter) -> Result<()> {
+        if !output.is_json() {
+            println!("{}", "🛡️  Dependency Audit".bright_cyan().bold());
+            println!("{}", "─".repeat(50).bright_black());
+        }
+
+        let mut vulnerabilities = Vec::new();
+        for dir in sources::cargo_projects()? {
+            if let Some(found) = unwrap_tool(sources::cargo_audit(&dir)?, output) {
+                vulnerabilities.extend(found);
+            }
+        }
+        if let Some(found) = unwrap_tool(sources::npm_audit()?, output) {
+            vulnerabilities.extend(found);
+        }
+
+        output.emit(vulnerabilities.as_slice(), print_vulnerabilities)?;
+        if vulnerabilities.is_empty() {
+            return Ok(());
+        }
+        Err(output.failed(format!(
+            "{} known vulnerabilities found",
+            vulnerabilities.len()
+        )))
+    }
+
+    fn licenses(
+        &self,
+        include_dev: bool,
+        notice: Option<&Path>,
+        output: OutputFormatter,
+    ) -> Result<()> {
+        if !output.is_json() {
+            println!("{}", "⚖️  License Report".bright_cyan().bold());
+            println!("{}", "─".repeat(50).bright_black());
+        }
+
+        let root = find_project_root();
+        let config = ProjectConfig::load()?.licenses;
+        let packages = licenses::collect(&root, &sources::cargo_projects()?, include_dev)?;
+
+        let mut by_license: std::collections::BTreeMap<&str, (usize, usize)> = Default::default();
+        for package in &packages {
+            let counts = by_license.entry(package.license_label()).or_default();
+            match package.ecosystem {
+                Ecosystem::Cargo => counts.0 += 1,
+                Ecosystem::Npm => counts.1 += 1,
+            }
+        }
+        let mut counts: Vec<LicenseCount> = by_license
+            .into_iter()
+            .map(|(license, (cargo, npm))| LicenseCount {
+                license,
+                cargo,
+                npm,
+            })
+            .collect();
+        counts.sort_by_key(|count| std::cmp::Reverse(count.cargo + count.npm));
+
+        let notice = match notice {
+            Some(path) => {
+                let path = root.join(path);
+                let project = project_name(&root);
+                std::fs::write(&path, licenses::render_notice(&project, &packages))
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+                Some(path)
+            }
+            None => None,
+        };
+
+        let report = LicenseReport {
+            licenses: counts,
+            packages: &packages,
+            include_dev,
+            unknown: packages.iter().filter(|p| p.license.is_none()).count(),
+            denied: licenses::violations(&packages, &config),
+            notice,
+        };
+        output.emit(&report, print_licenses)?;
+        if report.denied.is_empty() {
+            return Ok(());
+        }
+        Err(output.failed(format!(
+            "{} packages use disallowed licenses",
+            report.denied.len()
+        )))
+    }
+}
+
+fn print_outdated(outdated: &[OutdatedDependency]) {
+    if outdated.is_empty() {
+        println!("\n{}", "✅ All dependencies are up to date".green());
+        return;
+    }
+
+    let rows: Vec<Vec<String>> = outdated
+        .iter()
+        .map(|dep| {
+            vec![
+                dep.ecosystem.as_str().to_string(),
+                dep.project.clone(),
+                dep.name.clone(),
+                dep.current.clone(),
+                dep.compatible.clone().unwrap_or_else(|| "-".to_string()),
+                dep.latest.clone().unwrap_or_else(|| "-".to_string()),
+                dep.latest_bump()
+                    .map(|bump| bump.as_str().to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+            ]
+        })
+        .collect();
+    println!();
+    print_table(
+        &[
+            "ECOSYSTEM",
+            "PROJECT",
+            "PACKAGE",
+            "CURRENT",
+            "COMPATIBLE",
+            "LATEST",
+            "BUMP",
+        ],
+        &rows,
+    );
+
+    print_bump_summary(outdated);
+    println!(
+        "\n💡 Apply safe bumps: {} (patch) or {} (patch + minor)",
+        "akatsuki deps update".cyan(),
+        "akatsuki deps update --minor".cyan()
+    );
+}
+
+fn print_vulnerabilities(vulnerabilities: &[Vulnerability]) {
+    if vulnerabilities.is_empty() {
+        println!("\n{}", "✅ No known vulnerabilities".green());
+        return;
+    }
+
+    let rows: Vec<Vec<String>> = vulnerabilities
+        .iter()
+        .map(|vuln| {
+            vec![
+                vuln.ecosystem.as_str().to_string(),
+                vuln.project.clone(),
+                vuln.package.clone(),
+                vuln.severity.clone(),
+                vuln.id.clone(),
+                vuln.title.clone(),
+            ]
+        })
+        .collect();
+    println!();
+    print_table(
+        &["ECOSYSTEM", "PROJECT", "PACKAGE", "SEVERITY", "ID", "TITLE"],
+        &rows,
+    );
+
+    print_audit_summary(vulnerabilities);
+}
+
+fn print_licenses(report: &LicenseReport) {
+    println!();
+    print_table(
+        &["LICENSE", "CARGO", "NPM"],
+        &report
+            .licenses
+            .iter()
+            .map(|count| {
+                vec![
+                    count.license.to_string(),
+                    count.cargo.to_string(),
+                    count.npm.to_string(),
+                ]
+            })
+            .collect::<Vec<_>>(),
+    );
+    println!(
+        "\n{} {} packages{}",
+        "📊".bright_blue(),
+        report.packages.len(),
+        if report.include_dev {
+            " (including npm devDependencies)"
+        } else {
+            ""
+        }
+    );
+
+    if report.unknown > 0 {
+        println!(
+            "{}",
+            format!(
+                "⚠️  {} packages declare no license - review them manually",
+                report.unknown
+            )
+            .yellow()
+        );
+    }
+
+    if let Some(path) = &report.notice {
+        println!(
+            "{} {}",
+            "✓".green(),
+            path.display().to_string().bright_white()
+        );
+    }
+
+    if report.denied.is_empty() {
+        println!("\n{}", "✅ No disallowed licenses".green());
+        return;
+    }
+
+    println!("\n{}", "✗ Disallowed licenses".red().bold());
+    for package in &report.denied {
+        println!(
+            "  {} {} {} {}",
+            format!("[{}]", package.ecosystem.as_str()).bright_black(),
+            package.name.bright_white(),
+            package.version.bright_black(),
+            package.license_label().red()
+        );
+    }
+    println!(
+        "\n💡 Replace them, or add reviewed exceptions to {} in {}",
+        "[licenses] allow_packages".cyan(),
+        CONFIG_FILE
+    );
+}
+
+/// Root package.json name, used as the NOTICE heading
+fn project_name(root: &Path) -> String {
+    std::fs::read_to_string(root.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|json| json["name"].as_str().map(str::to_string))
+        .unwrap_or_else(|| "This project".to_string())
+}
+
+/// Outdated dependencies from every crate and the npm workspaces
+fn collect_outdated(output: OutputFormatter) -> Result<Vec<OutdatedDependency>> {
+    let mut outdated = Vec::new();
+    for dir in sources::cargo_projects()? {
+        if !output.is_json() {
+            println!(
+                "{} cargo outdated {}",
+                "→".bright_blue(),
+                sources::project_label(&dir).bright_black()
+            );
+        }
+        if let Some(found) = unwrap_tool(sources::cargo_outdated(&dir)?, output) {
+            outdated.extend(found);
+        }
+    }
+
+    if !output.is_json() {
+        println!(
+            "{} npm outdated {}",
+            "→".bright_blue(),
+            "(workspaces)".bright_black()
+        );
+    }
+    if let Some(found) = unwrap_tool(sources::npm_outdated()?, output) {
+        outdated.extend(found);
+    }
+    Ok(outdated)
+}
+
+/// Warn about a missing tool instead of failing the whole report
+/// (on stderr in JSON mode, where stdout carries the result)
+fn unwrap_tool<T>(tool_output: ToolOutput<T>, output: OutputFormatter) -> Option<T> {
+    match tool_output {
+        ToolOutput::Ok(value) => Some(value),
+        ToolOutput::Missing { tool, install } => {
+            let warning = format!(
+                "  {} {} not installed, skipping ({})",
+                "⚠️".yellow(),
+                tool,
+                install.bright_black()
+            );
+            if output.is_json() {
+                eprintln!("{}", warning);
+            } else {
+                println!("{}", warning);
+            }
+            None
+        }
+    }
+}
+
+/// Command that moves `dep` to `version`: (program, args, working directory)
+fn update_command(
+    root: &Path,
+    dep: &OutdatedDependency,
+    version: &str,
+) -> (&'static str, Vec<String>, std::path::PathBuf) {
+    match dep.ecosystem {
+        Ecosystem::Cargo => (
+            "cargo",
+            vec![
+                "update".to_string(),
+                "-p".to_string(),
+                format!("{}@{}", dep.name, dep.current),
+                "--precise".to_string(),
+                version.to_string(),
+            ],
+            root.join(&dep.project),
+        ),
+        Ecosystem::Npm => {
+            let mut args = vec!["install".to_string(), format!("{}@{}", dep.name, version)];
+            let workspace = Path::new("packages").join(&dep.project);
+            if root.join(&workspace).is_dir() {
+                args.push(format!("--workspace={}", workspace.display()));
+            }
+            ("npm", args, root.to_path_buf())
+        }
+    }
+}
+
+fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!("{}", format_row(headers.to_vec()).bold());
+    for row in rows {
+        println!("{}", format_row(row.iter().map(String::as_str).collect()));
+    }
+}
+
+fn print_bump_summary(outdated: &[OutdatedDependency]) {
+    println!("\n{}", "📊 Summary".bright_cyan().bold());
+    println!("{}", "─".repeat(50).bright_black());
+    for ecosystem in [Ecosystem::Cargo, Ecosystem::Npm] {
+        let deps: Vec<_> = outdated
+            .iter()
+            .filter(|d| d.ecosystem == ecosystem)
+            .collect();
+        let count = |bump: Bump| {
+            deps.iter()
+                .filter(|d| d.latest_bump() == Some(bump))
+                .count()
+        };
+        println!(
+            "  {:<6} {:>3} outdated  ({} patch, {} minor, {} major)",
+            ecosystem.as_str(),
+            deps.len(),
+            count(Bump::Patch).to_string().green(),
+            count(Bump::Minor).to_string().yellow(),
+            count(Bump::Major).to_string().red()
+        );
+    }
+}
+
+fn print_audit_summary(vulnerabilities: &[Vulnerability]) {
+    println!("\n{}", "📊 Summary".bright_cyan().bold());
+    println!("{}", "─".repeat(50).bright_black());
+    for ecosystem in [Ecosystem::Cargo, Ecosystem::Npm] {
+        let count = vulnerabilities
+            .iter()
+            .filter(|v| v.ecosystem == ecosystem)
+            .count();
+        let count = if count == 0 {
+            count.to_string().green()
+        } else {
+            count.to_string().red()
+        };
+        println!("  {:<6} {} vulnerabilities", ecosystem.as_str(), count);
+    }
+}
@@ -0,0 +1,500 @@
+/**
+ * Dependency sources
+ * Runs cargo/npm tooling and normalizes their JSON output
+ */
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::utils::find_project_root;
+use crate::utils::platform;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Ecosystem {
+    Cargo,
+    Npm,
+}
+
+impl Ecosystem {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Ecosystem::Cargo => "cargo",
+            Ecosystem::Npm => "npm",
+        }
+    }
+}
+
+/// Size of a version bump (ordered: Patch < Minor < Major)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Bump {
+    Patch,
+    Minor,
+    /// Incompatible change (major, or minor for 0.x versions)
+    Major,
+}
+
+impl Bump {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Bump::Patch => "patch",
+            Bump::Minor => "minor",
+            Bump::Major => "major",
+        }
+    }
+}
+
+/// `1.2.3` (leading `^`/`~`/`=`/`v` and pre-release/build suffixes ignored)
+fn parse_version(value: &str) -> Option<(u64, u64, u64)> {
+    let core = value
+        .trim()
+        .trim_start_matches(['^', '~', '=', 'v'])
+        .split(['-', '+'])
+        .next()?;
+    let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+    Some((
+        parts.next()??,
+        parts.next().flatten().unwrap_or(0),
+        parts.next().flatten().unwrap_or(0),
+    ))
+}
+
+/// Classify the bump from `current` to `target`; `None` when not newer or unparseable
+pub fn classify_bump(current: &str, target: &str) -> Option<Bump> {
+    let from = parse_version(current)?;
+    let to = parse_version(target)?;
+    if to <= from {
+        return None;
+    }
+
+    Some(if to.0 != from.0 || (from.0 == 0 && to.1 != from.1) {
+        Bump::Major
+    } else if to.1 != from.1 {
+        Bump::Minor
+    } else {
+        Bump::Patch
+    })
+}
+
+/// Outdated dependency, normalized across cargo and npm
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OutdatedDependency {
+    pub ecosystem: Ecosystem,
+    /// Crate directory or npm workspace that depends on it
+    pub project: String,
+    pub name: String,
+    pub current: String,
+    /// Newest version allowed by the declared requirement (lockfile-only update)
+    pub compatible: Option<String>,
+    pub latest: Option<String>,
+}
+
+impl OutdatedDependency {
+    pub fn latest_bump(&self) -> Option<Bump> {
+        classify_bump(&self.current, self.latest.as_deref()?)
+    }
+
+    /// Newest version whose bump does not exceed `max`.
+    ///
+    /// Cargo updates are lockfile-only (`cargo update --precise`), so only the
+    /// requirement-compatible version is considered there.
+    pub fn safe_target(&self, max: Bump) -> Option<(&str, Bump)> {
+        let latest = match self.ecosystem {
+            Ecosystem::Cargo => None,
+            Ecosystem::Npm => self.latest.as_deref(),
+        };
+        [latest, self.compatible.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter_map(|version| Some((version, classify_bump(&self.current, version)?)))
+            .find(|(_, bump)| *bump <= max)
+    }
+}
+
+/// Known vulnerability reported by cargo audit / npm audit
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Vulnerability {
+    pub ecosystem: Ecosystem,
+    pub project: String,
+    pub package: String,
+    pub id: String,
+    pub severity: String,
+    pub title: String,
+}
+
+/// Result of a tool run that may be unavailable (e.g. cargo-outdated not installed)
+pub enum ToolOutput<T> {
+    Ok(T),
+    Missing {
+        tool: &'static str,
+        install: &'static str,
+    },
+}
+
+// ========================================
+// Project discovery
+// ========================================
+
+/// Rust crates in the workspace: packages/* and wasm-modules/* with a Cargo.toml
+pub fn cargo_projects() -> Result<Vec<PathBuf>> {
+    let root = find_project_root();
+    let mut projects = Vec::new();
+    for dir in ["packages", "wasm-modules"] {
+        let Ok(entries) = std::fs::read_dir(root.join(dir)) else {
+            continue;
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if path.join("Cargo.toml").exists() {
+                projects.push(path);
+            }
+        }
+    }
+    projects.sort();
+    Ok(projects)
+}
+
+pub fn project_label(path: &Path) -> String {
+    let root = find_project_root();
+    path.strip_prefix(&root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// Run a command and return stdout; `None` when the tool is not installed.
+/// Non-zero exit codes are expected (outdated/audit report findings that way).
+fn run_json(program: &str, args: &[&str], dir: &Path) -> Result<Option<String>> {
+    let output = match platform::command(program)
+        .args(args)
+        .current_dir(dir)
+        .output()
+    {
+        Ok(output) => output,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err).with_context(|| format!("Failed to run {}", program)),
+    };
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if stderr.contains("no such command") || stderr.contains("no such subcommand") {
+        return Ok(None);
+    }
+    Ok(Some(String::from_utf8_lossy(&output.stdout).into_owned()))
+}
+
+// ========================================
+// cargo outdated / cargo audit
+// ========================================
+
+#[derive(Debug, Deserialize)]
+struct CargoOutdatedReport {
+    dependencies: Vec<CargoOutdatedEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoOutdatedEntry {
+    name: String,
+    project: String,
+    compat: String,
+    latest: String,
+}
+
+/// `cargo outdated` marks "no version" as `---` (or `Removed`)
+fn cargo_version(value: String) -> Option<String> {
+    parse_version(&value).map(|_| value)
+}
+
+pub fn parse_cargo_outdated(project: &str, json: &str) -> Result<Vec<OutdatedDependency>> {
+    let mut outdated = Vec::new();
+    // One JSON document per line (one per workspace member)
+    for line in json.lines().filter(|l| l.trim_start().starts_with('{')) {
+        let report: CargoOutdatedReport =
+            serde_json::from_str(line).context("Failed to parse cargo outdated output")?;
+        outdated.extend(
+            report
+                .dependencies
+                .into_iter()
+                .map(|dep| OutdatedDependency {
+                    ecosystem: Ecosystem::Cargo,
+                    project: project.to_string(),
+                    name: dep.name,
+                    current: dep.project,
+                    compatible: cargo_version(dep.compat),
+                    latest: cargo_version(dep.latest),
+                }),
+        );
+    }
+    Ok(outdated)
+}
+
+pub fn cargo_outdated(dir: &Path) -> Result<ToolOutput<Vec<OutdatedDependency>>> {
+    let args = ["outdated", "--root-deps-only", "--format", "json"];
+    match run_json("cargo", &args, dir)? {
+        Some(json) => Ok(ToolOutput::Ok(parse_cargo_outdated(
+            &project_label(dir),
+            &json,
+        )?)),
+        None => Ok(ToolOutput::Missing {
+            tool: "cargo-outdated",
+            install: "cargo install cargo-outdated",
+        }),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoAuditReport {
+    vulnerabilities: CargoAuditVulnerabilities,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoAuditVulnerabilities {
+    list: Vec<CargoAuditEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoAuditEntry {
+    advisory: CargoAdvisory,
+    package: CargoAuditPackage,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoAdvisory {
+    id: String,
+    title: String,
+    #[serde(default)]
+    cvss: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoAuditPackage {
+    name: String,
+    version: String,
+}
+
+pub fn parse_cargo_audit(project: &str, json: &str) -> Result<Vec<Vulnerability>> {
+    let report: CargoAuditReport =
+        serde_json::from_str(json).context("Failed to parse cargo audit output")?;
+    Ok(report
+        .vulnerabilities
+        .list
+        .into_iter()
+        .map(|entry| Vulnerability {
+            ecosystem: Ecosystem::Cargo,
+            project: project.to_string(),
+            package: format!("{}@{}", entry.package.name, entry.package.version),
+            id: entry.advisory.id,
+            severity: if entry.advisory.cvss.is_some() {
+                "cvss".to_string()
+            } else {
+                "advisory".to_string()
+            },
+            title: entry.advisory.title,
+        })
+        .collect())
+}
+
+pub fn cargo_audit(dir: &Path) -> Result<ToolOutput<Vec<Vulnerability>>> {
+    if !dir.join("Cargo.lock").exists() {
+        // Nothing resolved yet (libraries without a lockfile)
+        return Ok(ToolOutput::Ok(Vec::new()));
+    }
+    match run_json("cargo", &["audit", "--json"], dir)? {
+        Some(json) => Ok(ToolOutput::Ok(parse_cargo_audit(
+            &project_label(dir),
+            &json,
+        )?)),
+        None => Ok(ToolOutput::Missing {
+            tool: "cargo-audit",
+            install: "cargo install cargo-audit",
+        }),
+    }
+}
+
+// ========================================
+// npm outdated / npm audit
+// ========================================
+
+#[derive(Debug, Deserialize)]
+struct NpmOutdatedEntry {
+    current: Option<String>,
+    wanted: Option<String>,
+    latest: Option<String>,
+    dependent: Option<String>,
+}
+
+/// npm reports one object per package, or an array when several workspaces depend on it
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum NpmOutdatedValue {
+    One(NpmOutdatedEntry),
+    Many(Vec<NpmOutdatedEntry>),
+}
+
+pub fn parse_npm_outdated(json: &str) -> Result<Vec<OutdatedDependency>> {
+    if json.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let report: BTreeMap<String, NpmOutdatedValue> =
+        serde_json::from_str(json).context("Failed to parse npm outdated output")?;
+
+    let mut outdated = Vec::new();
+    for (name, value) in report {
+        let entries = match value {
+            NpmOutdatedValue::One(entry) => vec![entry],
+            NpmOutdatedValue::Many(entries) => entries,
+        };
+        for entry in entries {
+            // Not installed yet: nothing to compare against
+            let Some(current) = entry.current else {
+                continue;
+            };
+            outdated.push(OutdatedDependency {
+                ecosystem: Ecosystem::Npm,
+                project: entry.dependent.unwrap_or_else(|| "(root)".to_string()),
+                name: name.clone(),
+                current,
+                compatible: entry.wanted,
+                latest: entry.latest,
+            });
+        }
+    }
+    Ok(outdated)
+}
+
+pub fn npm_outdated() -> Result<ToolOutput<Vec<OutdatedDependency>>> {
+    let args = [
+        "outdated",
+        "--json",
+        "--workspaces",
+        "--include-workspace-root",
+    ];
+    match run_json("npm", &args, &find_project_root())? {
+        Some(json) => Ok(ToolOutput::Ok(parse_npm_outdated(&json)?)),
+        None => Ok(ToolOutput::Missing {
+            tool: "npm",
+            install: "https://nodejs.org/",
+        }),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmAuditReport {
+    #[serde(default)]
+    vulnerabilities: BTreeMap<String, NpmAuditEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmAuditEntry {
+    severity: String,
+    #[serde(default)]
+    via: Vec<serde_json::Value>,
+}
+
+pub fn parse_npm_audit(json: &str) -> Result<Vec<Vulnerability>> {
+    let report: NpmAuditReport =
+        serde_json::from_str(json).context("Failed to parse npm audit output")?;
+    Ok(report
+        .vulnerabilities
+        .into_iter()
+        .map(|(name, entry)| {
+            // `via` holds advisories (objects) or names of vulnerable dependencies (strings)
+            let advisory = entry.via.iter().find_map(|v| v.as_object());
+            let field = |key: &str| {
+                advisory
+                    .and_then(|a| a.get(key))
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+            };
+            Vulnerability {
+                ecosystem: Ecosystem::Npm,
+                project: "(workspace)".to_string(),
+                package: name.clone(),
+                id: field("url")
+                    .and_then(|url| url.rsplit('/').next().map(str::to_string))
+                    .unwrap_or_else(|| "-".to_string()),
+                severity: entry.severity,
+                title: field("title").unwrap_or_else(|| {
+                    let via: Vec<&str> = entry.via.iter().filter_map(|v| v.as_str()).collect();
+                    format!("via {}", via.join(", "))
+                }),
+            }
+        })
+        .collect())
+}
+
+pub fn npm_audit() -> Result<ToolOutput<Vec<Vulnerability>>> {
+    match run_json("npm", &["audit", "--json"], &find_project_root())? {
+        Some(json) => Ok(ToolOutput::Ok(parse_npm_audit(&json)?)),
+        None => Ok(ToolOutput::Missing {
+            tool: "npm",
+            install: "https://nodejs.org/",
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_bump() {
+        assert_eq!(classify_bump("1.2.3", "1.2.4"), Some(Bump::Patch));
+        assert_eq!(classify_bump("^1.2.3", "1.4.0"), Some(Bump::Minor));
+        assert_eq!(classify_bump("1.2.3", "2.0.0"), Some(Bump::Major));
+        assert_eq!(classify_bump("0.11.2", "0.12.0"), Some(Bump::Major));
+        assert_eq!(classify_bump("1.2.3", "1.2.3"), None);
+        assert_eq!(classify_bump("1.2.3", "---"), None);
+    }
+
+    #[test]
+    fn test_parse_cargo_outdated() {
+        let json = r#"{"crate_name":"akatsuki-cli","dependencies":[{"name":"clap","project":"4.5.1","compat":"4.5.4","latest":"4.5.4","kind":"Normal","platform":null},{"name":"colored","project":"2.1.0","compat":"---","latest":"3.0.0","kind":"Normal","platform":null}]}"#;
+        let deps = parse_cargo_outdated("packages/akatsuki-cli", json).unwrap();
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].compatible.as_deref(), Some("4.5.4"));
+        assert_eq!(deps[1].compatible, None);
+        assert_eq!(deps[1].latest_bump(), Some(Bump::Major));
+        assert_eq!(deps[1].safe_target(Bump::Minor), None);
+        assert_eq!(
+            deps[0].safe_target(Bump::Patch),
+            Some(("4.5.4", Bump::Patch))
+        );
+    }
+
+    #[test]
+    fn test_parse_npm_outdated() {
+        let json = r#"{
+          "react": {"current": "18.2.0", "wanted": "18.3.1", "latest": "19.0.0", "dependent": "app-frontend"},
+          "zod": [
+            {"current": "3.22.0", "wanted": "3.23.8", "latest": "3.23.8", "dependent": "app-frontend"},
+            {"wanted": "3.23.8", "latest": "3.23.8", "dependent": "app-cli"}
+          ]
+        }"#;
+        let deps = parse_npm_outdated(json).unwrap();
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].name, "react");
+        assert_eq!(
+            deps[0].safe_target(Bump::Minor),
+            Some(("18.3.1", Bump::Minor))
+        );
+        assert_eq!(deps[1].project, "app-frontend");
+        assert!(parse_npm_outdated("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_audits() {
+        let cargo = r#"{"vulnerabilities":{"found":true,"count":1,"list":[{"advisory":{"id":"RUSTSEC-2024-0001","title":"Bad thing","cvss":null},"package":{"name":"foo","version":"1.0.0"}}]}}"#;
+        let vulns = parse_cargo_audit("packages/app-backend", cargo).unwrap();
+        assert_eq!(vulns[0].id, "RUSTSEC-2024-0001");
+        assert_eq!(vulns[0].package, "foo@1.0.0");
+
+        let npm = r#"{"vulnerabilities":{"semver":{"severity":"high","via":[{"title":"ReDoS","url":"https://github.com/advisories/GHSA-c2qf"}]},"pkg":{"severity":"moderate","via":["semver"]}}}"#;
+        let vulns = parse_npm_audit(npm).unwrap();
+        assert_eq!(vulns.len(), 2);
+        assert_eq!(vulns[0].title, "via semver");
+        assert_eq!(vulns[1].id, "GHSA-c2qf");
+        assert_eq!(vulns[1].severity, "high");
+    }
+}
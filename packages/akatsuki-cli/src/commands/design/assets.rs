@@ -0,0 +1,199 @@
+/**
+ * Design Assets
+ * Copies images referenced by a design document next to the published example
+ *
+ * `![alt](path)` and `<img src="path">` references to local files are copied
+ * into `docs/examples/<feature>/` and rewritten to point there. Remote URLs
+ * and data URIs are left alone.
+ */
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default)]
+pub struct Bundle {
+    /// Document with local image references rewritten into the asset directory
+    pub content: String,
+    /// Copied files, relative to the examples directory
+    pub copied: Vec<String>,
+    /// References that resolved to no file under any of the search directories
+    pub missing: Vec<String>,
+}
+
+/// Local image references in document order (duplicates removed)
+pub fn image_refs(content: &str) -> Vec<String> {
+    let mut refs: Vec<String> = Vec::new();
+    let mut push = |reference: &str| {
+        let reference = reference.trim();
+        if is_local(reference) && !refs.iter().any(|r| r == reference) {
+            refs.push(reference.to_string());
+        }
+    };
+
+    for part in content.split("](").skip(1) {
+        let Some(end) = part.find(')') else { continue };
+        // `![alt](path "title")` or `![alt](<path with spaces>)`
+        let target = match part[..end].strip_prefix('<') {
+            Some(rest) => rest.split('>').next(),
+            None => part[..end].split_whitespace().next(),
+        }
+        .unwrap_or_default();
+        if is_image(target) {
+            push(target);
+        }
+    }
+    for part in content.split("<img").skip(1) {
+        let Some(start) = part.find("src=") else {
+            continue;
+        };
+        let value = &part[start + 4..];
+        let Some(quote) = value.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+            continue;
+        };
+        if let Some(end) = value[1..].find(quote) {
+            push(&value[1..1 + end]);
+        }
+    }
+    refs
+}
+
+/// Copy every local image into `examples_dir/<feature>/`, resolving relative
+/// references against `search_dirs` in order
+pub fn bundle(
+    content: &str,
+    search_dirs: &[&Path],
+    examples_dir: &Path,
+    feature_name: &str,
+) -> Result<Bundle> {
+    let asset_dir = examples_dir.join(feature_name);
+    let mut bundle = Bundle {
+        content: content.to_string(),
+        ..Bundle::default()
+    };
+    let mut used: BTreeMap<String, PathBuf> = BTreeMap::new();
+
+    for reference in image_refs(content) {
+        let Some(source) = resolve(&reference, search_dirs) else {
+            bundle.missing.push(reference);
+            continue;
+        };
+        // Already inside the asset directory (re-publishing)
+        if reference.starts_with(&format!("{}/", feature_name)) {
+            continue;
+        }
+
+        let name = unique_name(&source, &mut used);
+        fs::create_dir_all(&asset_dir)
+            .with_context(|| format!("Failed to create {}", asset_dir.display()))?;
+        fs::copy(&source, asset_dir.join(&name))
+            .with_context(|| format!("Failed to copy {}", source.display()))?;
+
+        let target = format!("{}/{}", feature_name, name);
+        bundle.content = bundle
+            .content
+            .replace(&format!("]({}", reference), &format!("]({}", target))
+            .replace(&format!("](<{}>", reference), &format!("](<{}>", target))
+            .replace(
+                &format!("src=\"{}\"", reference),
+                &format!("src=\"{}\"", target),
+            )
+            .replace(
+                &format!("src='{}'", reference),
+                &format!("src='{}'", target),
+            );
+        bundle.copied.push(target);
+    }
+    Ok(bundle)
+}
+
+fn is_local(reference: &str) -> bool {
+    !reference.is_empty()
+        && !reference.starts_with('#')
+        && !reference.contains("://")
+        && !reference.starts_with("data:")
+        && !reference.starts_with("//")
+}
+
+fn is_image(target: &str) -> bool {
+    let target = target.to_ascii_lowercase();
+    [".png", ".jpg", ".jpeg", ".gif", ".webp", ".svg", ".avif"]
+        .iter()
+        .any(|ext| target.ends_with(ext))
+}
+
+fn resolve(reference: &str, search_dirs: &[&Path]) -> Option<PathBuf> {
+    let path = Path::new(reference);
+    if path.is_absolute() {
+        return path.is_file().then(|| path.to_path_buf());
+    }
+    let relative = reference.trim_start_matches("./");
+    search_dirs
+        .iter()
+        .map(|dir| dir.join(relative))
+        .find(|candidate| candidate.is_file())
+}
+
+/// File name for `source` in the asset directory (`logo-2.png` when two
+/// different files are both called `logo.png`)
+fn unique_name(source: &Path, used: &mut BTreeMap<String, PathBuf>) -> String {
+    let stem = source.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = source
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+    let mut name = format!("{}{}", stem, extension);
+    let mut counter = 2;
+    while used.get(&name).is_some_and(|other| other != source) {
+        name = format!("{}-{}{}", stem, counter, extension);
+        counter += 1;
+    }
+    used.insert(name.clone(), source.to_path_buf());
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_refs() {
+        let content = "![Home](mockups/home.png) ![Remote](https://x.dev/a.png)\n\
+                       [Spec](docs/spec.md) ![Logo](<assets/my logo.svg> \"Logo\")\n\
+                       <img src=\"mockups/home.png\" width=\"300\"> <img src='data:image/png;base64,AA'>\n";
+        assert_eq!(
+            image_refs(content),
+            ["mockups/home.png", "assets/my logo.svg"]
+        );
+    }
+
+    #[test]
+    fn test_bundle_copies_and_rewrites() {
+        let root = tempfile::tempdir().unwrap();
+        let workspace = root.path().join("workspace");
+        fs::create_dir_all(workspace.join("mockups")).unwrap();
+        fs::create_dir_all(root.path().join("shared")).unwrap();
+        fs::write(workspace.join("mockups/home.png"), "a").unwrap();
+        fs::write(root.path().join("shared/home.png"), "b").unwrap();
+        let examples = root.path().join("docs/examples");
+
+        let content = "![A](mockups/home.png)\n<img src=\"shared/home.png\">\n![C](gone.png)\n";
+        let bundle = bundle(
+            content,
+            &[&workspace, root.path()],
+            &examples,
+            "user-dashboard",
+        )
+        .unwrap();
+
+        assert_eq!(
+            bundle.content,
+            "![A](user-dashboard/home.png)\n<img src=\"user-dashboard/home-2.png\">\n![C](gone.png)\n"
+        );
+        assert_eq!(bundle.missing, ["gone.png"]);
+        assert_eq!(
+            fs::read_to_string(examples.join("user-dashboard/home-2.png")).unwrap(),
+            "b"
+        );
+    }
+}
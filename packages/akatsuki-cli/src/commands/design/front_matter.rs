@@ -0,0 +1,170 @@
+/**
+ * Design Front Matter
+ * YAML metadata at the top of `<feature>-design.md`
+ *
+ * Written by `design new`, updated by `design status --set` / `use` / `publish`
+ * and read by `design status` and the advice docs detector. Documents written
+ * before it existed have none; readers fall back to the `**Status:**` line.
+ */
+use anyhow::{Context, Result};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+use crate::cli::{DesignStatus, DesignTemplate};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DesignMeta {
+    pub feature: String,
+    pub template: DesignTemplate,
+    pub status: DesignStatus,
+    pub owner: String,
+    /// Entities (PascalCase) this design adds or changes, e.g. `akatsuki api new` names
+    pub entities: Vec<String>,
+    pub theme: Option<String>,
+    pub created: String,
+    pub updated: String,
+    pub tags: Vec<String>,
+}
+
+impl Default for DesignMeta {
+    fn default() -> Self {
+        let today = today();
+        Self {
+            feature: String::new(),
+            template: DesignTemplate::Page,
+            status: DesignStatus::Draft,
+            owner: String::new(),
+            entities: Vec::new(),
+            theme: None,
+            created: today.clone(),
+            updated: today,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl DesignMeta {
+    /// Metadata for a new document, owned by the git user
+    pub fn new(feature: &str, template: DesignTemplate) -> Self {
+        Self {
+            feature: feature.to_string(),
+            template,
+            owner: git_user().unwrap_or_default(),
+            ..Self::default()
+        }
+    }
+
+    pub fn touch(&mut self) {
+        self.updated = today();
+    }
+}
+
+/// Front matter of `content`, `None` when the document has none
+pub fn parse(content: &str) -> Result<Option<DesignMeta>> {
+    let Some((yaml, _)) = split(content) else {
+        return Ok(None);
+    };
+    serde_yaml::from_str(yaml)
+        .map(Some)
+        .context("Invalid design front matter")
+}
+
+/// `content` with its front matter replaced by (or prefixed with) `meta`
+pub fn render(meta: &DesignMeta, content: &str) -> Result<String> {
+    let body = split(content).map_or(content, |(_, body)| body);
+    let yaml = serde_yaml::to_string(meta)?;
+    Ok(format!(
+        "---\n{}---\n\n{}",
+        yaml,
+        body.trim_start_matches('\n')
+    ))
+}
+
+/// Apply `change` to the front matter; `None` when the document has none
+pub fn update(content: &str, change: impl FnOnce(&mut DesignMeta)) -> Result<Option<String>> {
+    let Some(mut meta) = parse(content)? else {
+        return Ok(None);
+    };
+    change(&mut meta);
+    meta.touch();
+    render(&meta, content).map(Some)
+}
+
+/// Status shown for a document: front matter first, then the legacy
+/// `**Status:**` line
+pub fn status_label(content: &str) -> String {
+    match parse(content) {
+        Ok(Some(meta)) => label(meta.status).to_string(),
+        Ok(None) => content
+            .lines()
+            .find_map(|line| line.strip_prefix("**Status:**"))
+            .map(|status| status.trim().to_string())
+            .unwrap_or_else(|| "Unknown".to_string()),
+        Err(_) => "Invalid front matter".to_string(),
+    }
+}
+
+pub fn label(status: DesignStatus) -> &'static str {
+    match status {
+        DesignStatus::Draft => "Draft",
+        DesignStatus::InProgress => "In Progress",
+        DesignStatus::Completed => "Completed",
+    }
+}
+
+fn split(content: &str) -> Option<(&str, &str)> {
+    let rest = content.strip_prefix("---\n")?;
+    if let Some(yaml) = rest.strip_suffix("\n---") {
+        return Some((yaml, ""));
+    }
+    rest.split_once("\n---\n")
+}
+
+fn today() -> String {
+    Local::now().format("%Y-%m-%d").to_string()
+}
+
+fn git_user() -> Option<String> {
+    let output = Command::new("git")
+        .args(["config", "user.name"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_parse_and_update() {
+        let mut meta = DesignMeta::new("user-dashboard", DesignTemplate::Api);
+        meta.entities = vec!["Article".to_string()];
+        let content = render(&meta, "# User Dashboard\n\n**Status:** Draft\n").unwrap();
+
+        assert!(content.starts_with("---\nfeature: user-dashboard\ntemplate: api\nstatus: draft\n"));
+        assert!(content.ends_with("---\n\n# User Dashboard\n\n**Status:** Draft\n"));
+        assert_eq!(parse(&content).unwrap(), Some(meta));
+        assert_eq!(status_label(&content), "Draft");
+
+        let updated = update(&content, |meta| meta.status = DesignStatus::InProgress)
+            .unwrap()
+            .unwrap();
+        assert!(updated.contains("status: in-progress\n"));
+        assert_eq!(status_label(&updated), "In Progress");
+        assert_eq!(updated.matches("---\n").count(), 2);
+    }
+
+    #[test]
+    fn test_documents_without_front_matter() {
+        let legacy = "# Old Design\n\n---\n\n**Status:** Completed\n";
+        assert_eq!(parse(legacy).unwrap(), None);
+        assert_eq!(update(legacy, |_| {}).unwrap(), None);
+        assert_eq!(status_label(legacy), "Completed");
+        assert!(parse("---\nstatus: someday\n---\n").is_err());
+    }
+}
@@ -0,0 +1,112 @@
+/**
+ * Design Gallery Index
+ * `docs/examples/README.md`, regenerated on every `akatsuki design publish`
+ */
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+use super::front_matter;
+use crate::utils::extract_markdown_metadata;
+
+pub const INDEX_FILE: &str = "README.md";
+
+/// Published examples are `<feature>-design.md`; their assets live in `<feature>/`
+pub const EXAMPLE_SUFFIX: &str = "-design.md";
+
+pub const SCREENSHOT_FILE: &str = "screenshot.png";
+
+/// Whether `file_name` is a published design (not the index or an asset)
+pub fn is_example(file_name: &str) -> bool {
+    file_name.ends_with(EXAMPLE_SUFFIX)
+}
+
+/// Rewrite the index from the examples currently in `examples_dir`
+pub fn write(examples_dir: &Path) -> Result<()> {
+    let content = render(examples_dir)?;
+    let path = examples_dir.join(INDEX_FILE);
+    fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn render(examples_dir: &Path) -> Result<String> {
+    let mut files: Vec<String> = fs::read_dir(examples_dir)?
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| is_example(name))
+        .collect();
+    files.sort();
+
+    let mut out = String::from("# Design Examples\n\n");
+    out.push_str("<!-- Generated by `akatsuki design publish`; edits are overwritten -->\n\n");
+    out.push_str("Copy one into workspace/ with `akatsuki design use`.\n\n");
+    out.push_str("| Example | Status | Tags | Screenshot |\n");
+    out.push_str("|---|---|---|---|\n");
+    for file in files {
+        let content = fs::read_to_string(examples_dir.join(&file))?;
+        let metadata = extract_markdown_metadata(&content);
+        let feature = file.trim_end_matches(EXAMPLE_SUFFIX);
+        let screenshot = format!("{}/{}", feature, SCREENSHOT_FILE);
+        let thumbnail = if examples_dir.join(&screenshot).is_file() {
+            format!("<img src=\"{}\" width=\"240\">", screenshot)
+        } else {
+            String::new()
+        };
+        out.push_str(&format!(
+            "| [{}]({}) | {} | {} | {} |\n",
+            cell(&metadata.title),
+            file,
+            cell(&metadata.status),
+            cell(&tags(&content).join(", ")),
+            thumbnail
+        ));
+    }
+    Ok(out)
+}
+
+/// Front matter tags, or the `**Tags:** a, b` line of older documents
+fn tags(content: &str) -> Vec<String> {
+    if let Ok(Some(meta)) = front_matter::parse(content) {
+        return meta.tags;
+    }
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("**Tags:**"))
+        .map(|tags| {
+            tags.split(',')
+                .map(|tag| tag.trim().to_string())
+                .filter(|tag| !tag.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_index() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("user-dashboard-design.md"),
+            "# User Dashboard - Design Document\n\n**Status:** Completed\n\n**Tags:** AI, CRUD\n",
+        )
+        .unwrap();
+        fs::create_dir_all(dir.path().join("user-dashboard")).unwrap();
+        fs::write(dir.path().join("user-dashboard/screenshot.png"), "").unwrap();
+        fs::write(dir.path().join("notes.md"), "# Not an example\n").unwrap();
+
+        write(dir.path()).unwrap();
+        let index = fs::read_to_string(dir.path().join(INDEX_FILE)).unwrap();
+        assert!(index.contains(
+            "| [User Dashboard - Design Document](user-dashboard-design.md) | Completed | AI, CRUD | <img src=\"user-dashboard/screenshot.png\" width=\"240\"> |"
+        ));
+        assert!(!index.contains("notes.md"));
+        assert!(!is_example(INDEX_FILE));
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Result;
 use colored::*;
 use std::fs;
 
+use super::index;
 use crate::utils::{extract_markdown_metadata, get_examples_dir};
 
 pub fn execute() -> Result<()> {
@@ -22,13 +23,7 @@ pub fn execute() -> Result<()> {
     let mut files: Vec<_> = entries
         .filter_map(|entry| entry.ok())
         .filter(|entry| {
-            entry.path().is_file()
-                && entry
-                    .path()
-                    .extension()
-                    .and_then(|s| s.to_str())
-                    .map(|s| s == "md")
-                    .unwrap_or(false)
+            entry.path().is_file() && index::is_example(&entry.file_name().to_string_lossy())
         })
         .collect();
 
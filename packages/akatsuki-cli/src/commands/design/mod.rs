@@ -1,12 +1,18 @@
+mod assets;
+pub mod front_matter;
+mod index;
 mod list;
 mod new;
 mod publish;
+pub mod screenshot;
+mod status;
 pub mod theme;
 mod use_cmd;
 
 use anyhow::Result;
 
 use crate::cli::DesignAction;
+use crate::utils::output::OutputFormatter;
 
 pub struct DesignCommand;
 
@@ -15,15 +21,33 @@ impl DesignCommand {
         Self
     }
 
-    pub fn execute(&self, action: DesignAction) -> Result<()> {
+    pub fn execute(&self, action: DesignAction, output: OutputFormatter) -> Result<()> {
         match action {
             DesignAction::New {
                 feature_name,
                 theme,
-            } => new::execute(&feature_name, theme.as_deref()),
+                template,
+                owner,
+                entities,
+            } => new::execute(
+                &feature_name,
+                new::NewOptions {
+                    theme: theme.as_deref(),
+                    template,
+                    owner,
+                    entities,
+                },
+            ),
+            DesignAction::Status { feature_name, set } => {
+                status::execute(feature_name.as_deref(), set, output)
+            }
             DesignAction::List => list::execute(),
             DesignAction::Use => use_cmd::execute(),
-            DesignAction::Publish { feature_name } => publish::execute(&feature_name),
+            DesignAction::Publish {
+                feature_name,
+                url,
+                no_screenshot,
+            } => publish::execute(&feature_name, url.as_deref(), !no_screenshot),
             DesignAction::Themes => theme::list_themes(),
             DesignAction::Theme { theme_id, format } => theme::show_theme(&theme_id, &format),
             DesignAction::InsertTheme { file, theme } => theme::insert_theme(&file, &theme),
@@ -2,11 +2,21 @@ use anyhow::{bail, Result};
 use colored::*;
 use std::fs;
 
+use super::front_matter::{self, DesignMeta};
+use crate::cli::DesignTemplate;
 use crate::utils::{
     get_workspace_dir, process_template, process_template_with_theme, validate_feature_name,
 };
 
-pub fn execute(feature_name: &str, theme: Option<&str>) -> Result<()> {
+pub struct NewOptions<'a> {
+    pub theme: Option<&'a str>,
+    pub template: DesignTemplate,
+    pub owner: Option<String>,
+    pub entities: Vec<String>,
+}
+
+pub fn execute(feature_name: &str, options: NewOptions) -> Result<()> {
+    let theme = options.theme;
     // Validate feature name
     if !validate_feature_name(feature_name) {
         bail!(
@@ -28,11 +38,20 @@ pub fn execute(feature_name: &str, theme: Option<&str>) -> Result<()> {
 
     // Process template (with or without theme)
     let content = if let Some(theme_id) = theme {
-        process_template_with_theme(feature_name, theme_id)?
+        process_template_with_theme(feature_name, theme_id, options.template)?
     } else {
-        process_template(feature_name)
+        process_template(feature_name, options.template)
     };
 
+    // Structured metadata for `design status` and `advice`
+    let mut meta = DesignMeta::new(feature_name, options.template);
+    if let Some(owner) = options.owner {
+        meta.owner = owner;
+    }
+    meta.entities = options.entities;
+    meta.theme = theme.map(str::to_string);
+    let content = front_matter::render(&meta, &content)?;
+
     // Write file
     fs::write(&output_path, content)?;
 
@@ -42,6 +61,11 @@ pub fn execute(feature_name: &str, theme: Option<&str>) -> Result<()> {
         "✅ Design document created successfully!".green().bold()
     );
     println!("\n{} {}", "📄 File:".cyan(), output_path.display());
+    println!(
+        "{} {}",
+        "📐 Template:".cyan(),
+        format!("{:?}", options.template).to_lowercase()
+    );
 
     if let Some(theme_id) = theme {
         println!(
@@ -3,12 +3,15 @@ use chrono::Local;
 use colored::*;
 use std::fs;
 
+use super::screenshot::{self, Capture};
+use super::{assets, front_matter, index};
+use crate::cli::DesignStatus;
 use crate::utils::{
     confirm_keep_in_workspace, confirm_overwrite, confirm_publish, get_examples_dir,
-    get_workspace_dir, input_tags,
+    get_project_root, get_workspace_dir, input_tags,
 };
 
-pub fn execute(feature_name: &str) -> Result<()> {
+pub fn execute(feature_name: &str, url: Option<&str>, capture_screenshot: bool) -> Result<()> {
     let workspace_dir = get_workspace_dir()?;
     let examples_dir = get_examples_dir()?;
     let source_path = workspace_dir.join(format!("{}-design.md", feature_name));
@@ -36,12 +39,11 @@ pub fn execute(feature_name: &str) -> Result<()> {
     }
 
     // Check if target already exists
-    if target_path.exists() {
-        if !confirm_overwrite(&format!("{}-design.md", feature_name))? {
+    if target_path.exists()
+        && !confirm_overwrite(&format!("{}-design.md", feature_name))? {
             println!("{}", "❌ Cancelled.".red());
             return Ok(());
         }
-    }
 
     // Optional: Add tags
     let tags = input_tags()?;
@@ -77,16 +79,85 @@ pub fn execute(feature_name: &str) -> Result<()> {
         }
     }
 
+    // Front matter (documents created by `design new`)
+    if let Some(updated) = front_matter::update(&content, |meta| {
+        meta.status = DesignStatus::Completed;
+        for tag in tags.iter().flatten() {
+            if !meta.tags.contains(tag) {
+                meta.tags.push(tag.clone());
+            }
+        }
+    })? {
+        content = updated;
+    }
+
     // Create examples directory if not exists
     if !examples_dir.exists() {
         fs::create_dir_all(&examples_dir)?;
     }
 
+    // Bundle referenced images into docs/examples/<feature>/
+    let project_root = get_project_root()?;
+    let bundle = assets::bundle(
+        &content,
+        &[&workspace_dir, &examples_dir, &project_root],
+        &examples_dir,
+        feature_name,
+    )?;
+    content = bundle.content;
+
+    // Screenshot of the running feature route
+    let screenshot_ref = format!("{}/{}", feature_name, index::SCREENSHOT_FILE);
+    let mut screenshot_saved = false;
+    if capture_screenshot {
+        let url = screenshot::feature_url(feature_name, url);
+        println!("\n{} {}", "📸 Capturing:".cyan(), url);
+        match screenshot::capture(&url, &examples_dir.join(&screenshot_ref)) {
+            Ok(Capture::Saved) => screenshot_saved = true,
+            Ok(Capture::Unreachable) => println!(
+                "{}",
+                "   ⚠️  Not reachable, skipped (start the dev server or pass --url)".yellow()
+            ),
+            Ok(Capture::NoBrowser) => println!(
+                "{}",
+                "   ⚠️  No headless Chrome / Chromium / Edge found, skipped".yellow()
+            ),
+            Err(e) => println!("{} {}", "   ⚠️  Screenshot skipped:".yellow(), e),
+        }
+    }
+    if screenshot_saved && !content.contains(&format!("]({})", screenshot_ref)) {
+        content = insert_after_title(&content, &format!("![Screenshot]({})", screenshot_ref));
+    }
+
     // Write file
     fs::write(&target_path, content)?;
+    index::write(&examples_dir)?;
 
     println!("\n{}", "✅ Design published successfully!".green().bold());
     println!("\n{} {}", "📚 Published to:".cyan(), target_path.display());
+    if !bundle.copied.is_empty() {
+        println!(
+            "{} {} image(s) → {}",
+            "🖼️  Assets:".cyan(),
+            bundle.copied.len(),
+            examples_dir.join(feature_name).display()
+        );
+    }
+    for missing in &bundle.missing {
+        println!("{} {}", "   ⚠️  Image not found:".yellow(), missing);
+    }
+    if screenshot_saved {
+        println!(
+            "{} {}",
+            "📸 Screenshot:".cyan(),
+            examples_dir.join(&screenshot_ref).display()
+        );
+    }
+    println!(
+        "{} {}",
+        "🗂️  Index:".cyan(),
+        examples_dir.join(index::INDEX_FILE).display()
+    );
     if let Some(tag_list) = tags {
         if !tag_list.is_empty() {
             println!("{} {}", "🏷️  Tags:".cyan(), tag_list.join(", "));
@@ -111,3 +182,14 @@ pub fn execute(feature_name: &str) -> Result<()> {
 
     Ok(())
 }
+
+/// Insert `line` as its own paragraph below the `# ` title (or at the top)
+fn insert_after_title(content: &str, line: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let at = lines
+        .iter()
+        .position(|l| l.starts_with("# "))
+        .map_or(0, |title| title + 1);
+    lines.splice(at..at, ["", line]);
+    lines.join("\n")
+}
@@ -0,0 +1,182 @@
+/**
+ * Design Screenshot
+ * Captures the running feature route with a headless Chromium-based browser
+ *
+ * Best effort: publishing continues without a screenshot when no browser is
+ * installed or the dev server is not running.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::utils::config::CONFIG_FILE;
+use crate::utils::platform;
+
+/// Frontend dev server (`npm run dev`, see `akatsuki ui`)
+pub const DEFAULT_BASE_URL: &str = "http://localhost:5173";
+
+const WINDOW_SIZE: &str = "1280,800";
+
+const DEV_SERVER_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Browser executables tried in order (names on PATH or absolute paths)
+const BROWSERS: &[&str] = if cfg!(target_os = "macos") {
+    &[
+        "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+        "/Applications/Chromium.app/Contents/MacOS/Chromium",
+        "/Applications/Microsoft Edge.app/Contents/MacOS/Microsoft Edge",
+    ]
+} else if cfg!(windows) {
+    &[
+        r"C:\Program Files\Google\Chrome\Application\chrome.exe",
+        r"C:\Program Files (x86)\Microsoft\Edge\Application\msedge.exe",
+        "chrome.exe",
+        "msedge.exe",
+    ]
+} else {
+    &[
+        "chromium",
+        "chromium-browser",
+        "google-chrome",
+        "google-chrome-stable",
+        "microsoft-edge",
+    ]
+};
+
+pub enum Capture {
+    Saved,
+    NoBrowser,
+    Unreachable,
+}
+
+/// `--url` or the dev server URL of `/<feature-name>`
+pub fn feature_url(feature_name: &str, url: Option<&str>) -> String {
+    url.map(str::to_string)
+        .unwrap_or_else(|| format!("{}/{}", DEFAULT_BASE_URL, feature_name))
+}
+
+/// Save a PNG of `url` to `output`
+pub fn capture(url: &str, output: &Path) -> Result<Capture> {
+    if !reachable(url) {
+        return Ok(Capture::Unreachable);
+    }
+    let Some(browser) = find_browser() else {
+        return Ok(Capture::NoBrowser);
+    };
+
+    if let Some(parent) = output.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let status = Command::new(&browser)
+        .args([
+            "--headless=new",
+            "--disable-gpu",
+            "--hide-scrollbars",
+            "--no-first-run",
+            &format!("--window-size={}", WINDOW_SIZE),
+            &format!("--screenshot={}", output.display()),
+            url,
+        ])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+    if !status.success() || !output.is_file() {
+        anyhow::bail!("{} failed to capture {}", browser.display(), url);
+    }
+    Ok(Capture::Saved)
+}
+
+/// Frontend dev server started for a run, stopped on drop
+pub struct DevServer(Child);
+
+impl DevServer {
+    /// Run `npm run dev` with its output in `log_path` until `base_url` answers
+    pub fn start(root: &Path, base_url: &str, log_path: &Path) -> Result<Self> {
+        println!("{}", "  🚀 Starting frontend dev server...".blue());
+        let log = File::create(log_path)?;
+        let child = platform::command("npm")
+            .args(["run", "dev", "--workspace=app-frontend"])
+            .current_dir(root)
+            .stdin(Stdio::null())
+            .stdout(log.try_clone()?)
+            .stderr(log)
+            .spawn()
+            .context("Failed to start frontend dev server")?;
+        let mut server = DevServer(child);
+
+        let started = Instant::now();
+        while !reachable(base_url) {
+            if let Some(status) = server.0.try_wait()? {
+                anyhow::bail!(
+                    "Frontend dev server exited ({}), see {}",
+                    status,
+                    log_path.display()
+                );
+            }
+            if started.elapsed() > DEV_SERVER_TIMEOUT {
+                anyhow::bail!(
+                    "Frontend dev server did not answer at {} within {}s, see {}",
+                    base_url,
+                    DEV_SERVER_TIMEOUT.as_secs(),
+                    log_path.display()
+                );
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
+        Ok(server)
+    }
+
+    /// Start the dev server unless `base_url` already answers (only for the
+    /// default URL; one configured as `base_url` in `[section]` must be up)
+    pub fn ensure(
+        root: &Path,
+        base_url: &str,
+        log_path: &Path,
+        section: &str,
+        start: bool,
+    ) -> Result<Option<Self>> {
+        if reachable(base_url) {
+            return Ok(None);
+        }
+        if !start {
+            anyhow::bail!(
+                "{} is not reachable (base_url in [{}] of {})",
+                base_url,
+                section,
+                CONFIG_FILE
+            );
+        }
+        Self::start(root, base_url, log_path).map(Some)
+    }
+}
+
+impl Drop for DevServer {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+pub fn reachable(url: &str) -> bool {
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(3))
+        .build()
+        .and_then(|client| client.get(url).send())
+        .is_ok_and(|response| response.status().is_success())
+}
+
+fn find_browser() -> Option<PathBuf> {
+    let paths = std::env::var_os("PATH").unwrap_or_default();
+    BROWSERS.iter().find_map(|browser| {
+        let path = Path::new(browser);
+        if path.is_absolute() {
+            return path.is_file().then(|| path.to_path_buf());
+        }
+        std::env::split_paths(&paths)
+            .map(|dir| dir.join(browser))
+            .find(|candidate| candidate.is_file())
+    })
+}
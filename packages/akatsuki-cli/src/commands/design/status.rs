@@ -0,0 +1,206 @@
+use anyhow::{bail, Context, Result};
+use colored::*;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::front_matter::{self, DesignMeta};
+use super::index::EXAMPLE_SUFFIX;
+use crate::cli::{DesignStatus, DesignTemplate};
+use crate::utils::get_workspace_dir;
+use crate::utils::output::OutputFormatter;
+
+/// `design status` entry
+#[derive(Debug, Serialize)]
+struct DesignEntry {
+    feature: String,
+    path: PathBuf,
+    /// Status label, also for documents without front matter
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<DesignMeta>,
+    /// Invalid front matter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl DesignEntry {
+    fn new(path: PathBuf, feature: &str, content: &str) -> Self {
+        let (meta, error) = match front_matter::parse(content) {
+            Ok(meta) => (meta, None),
+            Err(e) => (None, Some(format!("{:#}", e))),
+        };
+        Self {
+            feature: feature.to_string(),
+            path,
+            status: front_matter::status_label(content),
+            meta,
+            error,
+        }
+    }
+}
+
+pub fn execute(
+    feature_name: Option<&str>,
+    set: Option<DesignStatus>,
+    output: OutputFormatter,
+) -> Result<()> {
+    let workspace_dir = get_workspace_dir()?;
+    match (feature_name, set) {
+        (Some(feature_name), Some(status)) => set_status(&workspace_dir, feature_name, status),
+        (Some(feature_name), None) => show(&workspace_dir, feature_name, output),
+        (None, _) => list(&workspace_dir, output),
+    }
+}
+
+fn list(workspace_dir: &Path, output: OutputFormatter) -> Result<()> {
+    let mut files: Vec<PathBuf> = fs::read_dir(workspace_dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().ends_with(EXAMPLE_SUFFIX))
+        })
+        .collect();
+    files.sort();
+
+    let entries = files
+        .into_iter()
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            let feature = name.trim_end_matches(EXAMPLE_SUFFIX).to_string();
+            let content = fs::read_to_string(&path)?;
+            Ok(DesignEntry::new(path, &feature, &content))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    output.emit(&entries, |entries| print_list(entries))
+}
+
+fn print_list(entries: &[DesignEntry]) {
+    println!("{}\n", "📐 Design Documents (workspace/)".green().bold());
+    if entries.is_empty() {
+        println!("  No design documents yet");
+        println!("\n💡 Create one: akatsuki design new <feature-name>");
+        return;
+    }
+
+    println!(
+        "  {:<28} {:<9} {:<12} {:<16} {:<10} {}",
+        "FEATURE".bold(),
+        "TEMPLATE".bold(),
+        "STATUS".bold(),
+        "OWNER".bold(),
+        "UPDATED".bold(),
+        "ENTITIES".bold()
+    );
+    for entry in entries {
+        match (&entry.meta, &entry.error) {
+            (Some(meta), _) => println!(
+                "  {:<28} {:<9} {:<12} {:<16} {:<10} {}",
+                entry.feature,
+                format!("{:?}", meta.template).to_lowercase(),
+                colored_status(meta.status),
+                meta.owner,
+                meta.updated,
+                meta.entities.join(", ")
+            ),
+            (None, None) => println!(
+                "  {:<28} {:<9} {:<12} {}",
+                entry.feature,
+                "-",
+                entry.status,
+                "(no front matter)".bright_black()
+            ),
+            (None, Some(error)) => println!("  {:<28} {}", entry.feature, error.red()),
+        }
+    }
+    println!(
+        "\n{}",
+        "💡 akatsuki design status <feature> --set in-progress|completed".cyan()
+    );
+}
+
+fn show(workspace_dir: &Path, feature_name: &str, output: OutputFormatter) -> Result<()> {
+    let (path, content) = read(workspace_dir, feature_name)?;
+    front_matter::parse(&content)?;
+    let entry = DesignEntry::new(path, feature_name, &content);
+    output.emit(&entry, print_entry)
+}
+
+fn print_entry(entry: &DesignEntry) {
+    println!("{} {}\n", "📐".green(), entry.path.display());
+
+    let Some(meta) = &entry.meta else {
+        println!("  Status:   {}", entry.status);
+        println!(
+            "\n{}",
+            "💡 No front matter; `--set <status>` adds it".bright_black()
+        );
+        return;
+    };
+    println!(
+        "  Template: {}",
+        format!("{:?}", meta.template).to_lowercase()
+    );
+    println!("  Status:   {}", colored_status(meta.status));
+    println!("  Owner:    {}", meta.owner);
+    println!("  Entities: {}", meta.entities.join(", "));
+    if let Some(theme) = &meta.theme {
+        println!("  Theme:    {}", theme);
+    }
+    if !meta.tags.is_empty() {
+        println!("  Tags:     {}", meta.tags.join(", "));
+    }
+    println!("  Created:  {}", meta.created);
+    println!("  Updated:  {}", meta.updated);
+}
+
+fn set_status(workspace_dir: &Path, feature_name: &str, status: DesignStatus) -> Result<()> {
+    let (path, content) = read(workspace_dir, feature_name)?;
+    let updated = match front_matter::update(&content, |meta| meta.status = status)? {
+        Some(updated) => updated,
+        None => {
+            // Older document: add front matter instead of failing
+            let mut meta = DesignMeta::new(feature_name, DesignTemplate::Page);
+            meta.status = status;
+            println!("{}", "➕ Added front matter".yellow());
+            front_matter::render(&meta, &content)?
+        }
+    };
+    fs::write(&path, updated).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!(
+        "{} {} → {}",
+        "✅".green(),
+        feature_name,
+        colored_status(status)
+    );
+    if status == DesignStatus::Completed {
+        println!(
+            "💡 Publish it as an example: akatsuki design publish {}",
+            feature_name
+        );
+    }
+    Ok(())
+}
+
+fn read(workspace_dir: &Path, feature_name: &str) -> Result<(PathBuf, String)> {
+    let path = workspace_dir.join(format!("{}{}", feature_name, EXAMPLE_SUFFIX));
+    if !path.exists() {
+        bail!(
+            "Design file not found: {}\n\nTip: akatsuki design status (lists workspace designs)",
+            path.display()
+        );
+    }
+    let content = fs::read_to_string(&path)?;
+    Ok((path, content))
+}
+
+fn colored_status(status: DesignStatus) -> ColoredString {
+    let label = front_matter::label(status);
+    match status {
+        DesignStatus::Draft => label.yellow(),
+        DesignStatus::InProgress => label.cyan(),
+        DesignStatus::Completed => label.green(),
+    }
+}
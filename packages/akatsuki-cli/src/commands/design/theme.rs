@@ -145,7 +145,7 @@ impl Theme {
         for use_case in &self.use_cases {
             md.push_str(&format!("- {}\n", use_case));
         }
-        md.push_str("\n");
+        md.push('\n');
 
         // Color Palette
         md.push_str("## カラーパレット\n\n");
@@ -183,7 +183,7 @@ impl Theme {
 
         // Typography
         md.push_str("## タイポグラフィ\n\n");
-        md.push_str(&format!("**Font Family**:\n"));
+        md.push_str("**Font Family**:\n");
         md.push_str(&format!(
             "- Sans: `{}`\n",
             self.typography
@@ -237,7 +237,7 @@ impl Theme {
 
         // How to use
         md.push_str("## このテーマを使う\n\n");
-        md.push_str(&format!("```bash\n# 新しい設計でこのテーマを参照\nakatsuki design new my-feature\n# 上記Markdownをコピーして設計書の「カラーパレット」セクションに貼り付け\n```\n"));
+        md.push_str("```bash\n# 新しい設計でこのテーマを参照\nakatsuki design new my-feature\n# 上記Markdownをコピーして設計書の「カラーパレット」セクションに貼り付け\n```\n");
 
         md
     }
@@ -251,7 +251,7 @@ impl Theme {
                 md.push_str(&format!("- **{}**: `{}`\n", key, value));
             }
         }
-        md.push_str("\n");
+        md.push('\n');
     }
 }
 
@@ -300,7 +300,7 @@ pub fn show_theme(theme_id: &str, format: &str) -> Result<()> {
             let json = serde_json::to_string_pretty(&theme)?;
             println!("{}", json);
         }
-        "markdown" | _ => {
+        _ => {
             let markdown = theme.to_markdown();
             println!("{}", markdown);
         }
@@ -356,7 +356,7 @@ fn replace_color_theme_section_in_file(content: &str, theme_section: &str) -> St
             let mut result = String::new();
             result.push_str(&content[..start_pos]);
             result.push_str(theme_section);
-            result.push_str("\n");
+            result.push('\n');
             result.push_str(&content[end_pos..]);
             return result;
         }
@@ -3,6 +3,9 @@ use chrono::Local;
 use colored::*;
 use std::fs;
 
+use super::front_matter::{self, DesignMeta};
+use super::index;
+use crate::cli::DesignStatus;
 use crate::utils::{
     confirm_overwrite, extract_markdown_metadata, get_examples_dir, get_workspace_dir,
     input_feature_name, select_design_example, to_title_case,
@@ -20,13 +23,7 @@ pub fn execute() -> Result<()> {
     let files: Vec<_> = entries
         .filter_map(|entry| entry.ok())
         .filter(|entry| {
-            entry.path().is_file()
-                && entry
-                    .path()
-                    .extension()
-                    .and_then(|s| s.to_str())
-                    .map(|s| s == "md")
-                    .unwrap_or(false)
+            entry.path().is_file() && index::is_example(&entry.file_name().to_string_lossy())
         })
         .collect();
 
@@ -97,6 +94,16 @@ pub fn execute() -> Result<()> {
         .collect::<Vec<_>>()
         .join("\n");
 
+    // Front matter: the copy is a new draft owned by the current user
+    let updated_content = front_matter::update(&updated_content, |meta| {
+        let fresh = DesignMeta::new(&new_feature_name, meta.template);
+        meta.feature = fresh.feature;
+        meta.status = DesignStatus::Draft;
+        meta.owner = fresh.owner;
+        meta.created = fresh.created;
+    })?
+    .unwrap_or(updated_content);
+
     fs::write(&output_path, updated_content)?;
 
     println!(
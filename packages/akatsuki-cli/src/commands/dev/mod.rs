@@ -3,6 +3,10 @@ use colored::Colorize;
 use std::process::{Command, Stdio};
 
 use crate::cli::DevTarget;
+use crate::utils::config::{ProjectConfig, CONFIG_FILE};
+use crate::utils::{env_file, find_project_root, platform};
+
+const BACKEND_DIR: &str = "packages/app-backend";
 
 pub struct DevCommand;
 
@@ -22,7 +26,7 @@ impl DevCommand {
     fn run_frontend(&self) -> Result<()> {
         println!("{}", "🚀 Starting frontend development server...".cyan());
 
-        let status = Command::new("npm")
+        let status = platform::command("npm")
             .args(["run", "dev", "--workspace=app-frontend"])
             .status()
             .context("Failed to start frontend dev server")?;
@@ -39,7 +43,8 @@ impl DevCommand {
 
         let status = Command::new("cargo")
             .args(["shuttle", "run"])
-            .current_dir("packages/app-backend")
+            .current_dir(BACKEND_DIR)
+            .envs(backend_env()?)
             .status()
             .context("Failed to start backend dev server")?;
 
@@ -61,7 +66,8 @@ impl DevCommand {
         println!("\n{}", "🦀 Starting backend...".cyan());
         let backend = Command::new("cargo")
             .args(["shuttle", "run"])
-            .current_dir("packages/app-backend")
+            .current_dir(BACKEND_DIR)
+            .envs(backend_env()?)
             .stdout(Stdio::inherit())
             .stderr(Stdio::inherit())
             .spawn()
@@ -69,7 +75,7 @@ impl DevCommand {
 
         // Start frontend in foreground
         println!("{}", "🚀 Starting frontend...".cyan());
-        let frontend_status = Command::new("npm")
+        let frontend_status = platform::command("npm")
             .args(["run", "dev", "--workspace=app-frontend"])
             .status()
             .context("Failed to start frontend dev server")?;
@@ -84,3 +90,23 @@ impl DevCommand {
         Ok(())
     }
 }
+
+/// `[backend]` settings from akatsuki.toml as environment variables, except
+/// those already set in the shell or in packages/app-backend/.env
+fn backend_env() -> Result<Vec<(String, String)>> {
+    let env = ProjectConfig::load()?.backend.env();
+    let in_env_file = env_file::set_keys(&find_project_root().join(BACKEND_DIR).join(".env"));
+    let env: Vec<(String, String)> = env
+        .into_iter()
+        .filter(|(key, _)| std::env::var_os(key).is_none() && !in_env_file.contains(key))
+        .collect();
+
+    if !env.is_empty() {
+        let keys: Vec<&str> = env.iter().map(|(key, _)| key.as_str()).collect();
+        println!(
+            "{}",
+            format!("   Using {} from {}", keys.join(", "), CONFIG_FILE).dimmed()
+        );
+    }
+    Ok(env)
+}
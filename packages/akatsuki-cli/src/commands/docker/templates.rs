@@ -0,0 +1,117 @@
+/// Docker files written by `akatsuki docker init`
+///
+/// The backend image is built with the `standalone` feature (plain axum +
+/// tokio), so neither the Shuttle runtime nor cargo-shuttle is involved.
+pub const BACKEND_DOCKERFILE: &str = r#"# app-backend (generated by akatsuki docker init)
+# Standalone binary (--features standalone): no Shuttle tooling needed.
+FROM rust:1-bookworm AS build
+
+WORKDIR /app
+COPY . .
+RUN cargo build --release --no-default-features --features standalone
+
+FROM debian:bookworm-slim
+RUN apt-get update \
+    && apt-get install -y --no-install-recommends ca-certificates \
+    && rm -rf /var/lib/apt/lists/*
+COPY --from=build /app/target/release/app-backend /usr/local/bin/app-backend
+
+ENV HOST=0.0.0.0 \
+    PORT=8000 \
+    RUST_LOG=info
+EXPOSE 8000
+CMD ["app-backend"]
+"#;
+
+pub const BACKEND_DOCKERIGNORE: &str = r#"target/
+.env
+.env.*
+"#;
+
+/// Built from the repository root so the npm workspace lockfile is available
+pub const FRONTEND_DOCKERFILE: &str = r#"# app-frontend preview (generated by akatsuki docker init)
+# Build context: repository root (npm workspaces)
+FROM node:20-slim
+
+WORKDIR /app
+COPY . .
+RUN npm ci
+
+# Vite inlines VITE_* variables at build time
+ARG VITE_API_BASE_URL=http://localhost:8000
+ARG VITE_SUPABASE_URL=
+ARG VITE_SUPABASE_ANON_KEY=
+ENV VITE_API_BASE_URL=$VITE_API_BASE_URL \
+    VITE_SUPABASE_URL=$VITE_SUPABASE_URL \
+    VITE_SUPABASE_ANON_KEY=$VITE_SUPABASE_ANON_KEY
+RUN npm run build --workspace=app-frontend
+
+EXPOSE 4173
+CMD ["npm", "run", "preview", "--workspace=app-frontend", "--", "--host", "0.0.0.0", "--port", "4173"]
+"#;
+
+pub const ROOT_DOCKERIGNORE: &str = r#"# generated by akatsuki docker init
+.git
+.akatsuki
+**/node_modules
+**/target
+**/dist
+**/.env
+**/.env.*
+"#;
+
+pub const COMPOSE: &str = r#"# Local stack (generated by akatsuki docker init)
+#   akatsuki docker build   # docker compose build
+#   akatsuki docker up      # docker compose up
+#
+# Postgres (pgvector) replaces the Supabase database and the backend uses mock
+# AI providers unless MOCK_PROVIDERS=false, so the stack runs offline.
+# Supabase migrations are not applied (they depend on Supabase's auth schema).
+services:
+  db:
+    image: pgvector/pgvector:pg16
+    environment:
+      POSTGRES_USER: postgres
+      POSTGRES_PASSWORD: postgres
+      POSTGRES_DB: akatsuki
+    ports:
+      - "54329:5432"
+    volumes:
+      - db-data:/var/lib/postgresql/data
+    healthcheck:
+      test: ["CMD-SHELL", "pg_isready -U postgres -d akatsuki"]
+      interval: 5s
+      timeout: 5s
+      retries: 10
+
+  backend:
+    build: ./packages/app-backend
+    env_file:
+      - path: packages/app-backend/.env
+        required: false
+    environment:
+      DATABASE_URL: postgres://postgres:postgres@db:5432/akatsuki
+      MOCK_PROVIDERS: ${MOCK_PROVIDERS:-true}
+      CORS_ALLOWED_ORIGINS: http://localhost:4173
+    ports:
+      - "8000:8000"
+    depends_on:
+      db:
+        condition: service_healthy
+
+  frontend:
+    build:
+      context: .
+      dockerfile: packages/app-frontend/Dockerfile
+      args:
+        VITE_API_BASE_URL: http://localhost:8000
+        VITE_SUPABASE_URL: ${VITE_SUPABASE_URL:-}
+        VITE_SUPABASE_ANON_KEY: ${VITE_SUPABASE_ANON_KEY:-}
+    ports:
+      - "4173:4173"
+    depends_on:
+      - backend
+
+volumes:
+  db-data:
+"#;
@@ -0,0 +1,146 @@
+/**
+ * Docs Lint Cache
+ * Per-file JSDoc presence keyed by mtime + size (.akatsuki/cache/docs.json)
+ *
+ * A file is re-read only when its mtime or size changed. Entries for files
+ * that no longer exist are dropped on save.
+ */
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use crate::utils::platform;
+
+pub const CACHE_PATH: &str = ".akatsuki/cache/docs.json";
+
+/// Bump when the JSDoc detection changes so stale results are discarded
+const CACHE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct CacheEntry {
+    mtime_ns: u64,
+    size: u64,
+    has_jsdoc: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    files: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Default)]
+pub struct DocsCache {
+    files: BTreeMap<String, CacheEntry>,
+    seen: HashSet<String>,
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl DocsCache {
+    /// Load the cache (empty when missing, unreadable or from another version)
+    pub fn load(project_root: &Path) -> Self {
+        let files = fs::read_to_string(project_root.join(CACHE_PATH))
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache| cache.version == CACHE_VERSION)
+            .map(|cache| cache.files)
+            .unwrap_or_default();
+
+        Self {
+            files,
+            ..Self::default()
+        }
+    }
+
+    /// Cached result for `path`, or `check(path)` when it changed since the last run
+    pub fn has_jsdoc<F>(&mut self, project_root: &Path, path: &Path, check: F) -> Result<bool>
+    where
+        F: FnOnce(&Path) -> Result<bool>,
+    {
+        let key = cache_key(project_root, path);
+        let metadata = fs::metadata(path)?;
+        let mtime_ns = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or(0);
+        let size = metadata.len();
+        self.seen.insert(key.clone());
+
+        if let Some(entry) = self.files.get(&key) {
+            if entry.mtime_ns == mtime_ns && entry.size == size {
+                self.hits += 1;
+                return Ok(entry.has_jsdoc);
+            }
+        }
+
+        self.misses += 1;
+        let has_jsdoc = check(path)?;
+        self.files.insert(
+            key,
+            CacheEntry {
+                mtime_ns,
+                size,
+                has_jsdoc,
+            },
+        );
+        Ok(has_jsdoc)
+    }
+
+    /// Write the files seen in this run back to disk
+    pub fn save(mut self, project_root: &Path) -> Result<()> {
+        let seen = std::mem::take(&mut self.seen);
+        self.files.retain(|key, _| seen.contains(key));
+
+        let path = project_root.join(CACHE_PATH);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let cache = CacheFile {
+            version: CACHE_VERSION,
+            files: self.files,
+        };
+        fs::write(&path, serde_json::to_string(&cache)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+fn cache_key(project_root: &Path, path: &Path) -> String {
+    platform::slash_path(path.strip_prefix(project_root).unwrap_or(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_invalidates_on_change() {
+        let root = tempfile::tempdir().unwrap();
+        let file = root.path().join("Button.tsx");
+        fs::write(&file, "/** Button */\nexport const Button = 1;\n").unwrap();
+
+        let mut cache = DocsCache::load(root.path());
+        assert!(cache.has_jsdoc(root.path(), &file, |_| Ok(true)).unwrap());
+        cache.save(root.path()).unwrap();
+
+        // Unchanged: the checker is not called again
+        let mut cache = DocsCache::load(root.path());
+        let cached = cache
+            .has_jsdoc(root.path(), &file, |_| panic!("should be cached"))
+            .unwrap();
+        assert!(cached);
+        assert_eq!((cache.hits, cache.misses), (1, 0));
+
+        // Size changed: re-checked
+        fs::write(&file, "export const Button = 1;\n").unwrap();
+        let mut cache = DocsCache::load(root.path());
+        assert!(!cache.has_jsdoc(root.path(), &file, |_| Ok(false)).unwrap());
+        assert_eq!(cache.misses, 1);
+    }
+}
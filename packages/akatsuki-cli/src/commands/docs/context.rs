@@ -0,0 +1,234 @@
+/**
+ * AI Context Size
+ * Token estimates and trim suggestions for `akatsuki docs context-size`
+ *
+ * Tokens are estimated without a tokenizer: about 4 ASCII characters per
+ * token, one token per other character (Japanese text, emoji). That is close
+ * enough to compare files against a budget. Sections are `##` headings;
+ * a section is stale when it points at paths that no longer exist or has not
+ * been touched for STALE_DAYS.
+ */
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::process::Command;
+
+/// Sections unchanged for this long are trim candidates
+pub const STALE_DAYS: i64 = 90;
+
+/// Suggestions shown per file over budget
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Directories relative path references in context files are resolved against
+const PATH_BASES: [&str; 4] = [
+    "",
+    "packages/app-frontend",
+    "packages/app-frontend/src",
+    "supabase/functions",
+];
+
+pub fn estimate_tokens(text: &str) -> usize {
+    let (ascii, other) = text.chars().fold((0usize, 0usize), |(ascii, other), c| {
+        if c.is_ascii() {
+            (ascii + 1, other)
+        } else {
+            (ascii, other + 1)
+        }
+    });
+    ascii.div_ceil(4) + other
+}
+
+#[derive(Debug)]
+pub struct Section {
+    pub title: String,
+    /// 0-based line range [start, end)
+    pub start: usize,
+    pub end: usize,
+    pub tokens: usize,
+    /// Backticked paths that exist under none of the PATH_BASES
+    pub missing_paths: Vec<String>,
+    /// Days since the newest line of the section was committed
+    pub age_days: Option<i64>,
+}
+
+impl Section {
+    pub fn is_stale(&self) -> bool {
+        !self.missing_paths.is_empty() || self.age_days.is_some_and(|age| age >= STALE_DAYS)
+    }
+
+    /// Why the section is a trim candidate
+    pub fn reasons(&self) -> Vec<String> {
+        let mut reasons = Vec::new();
+        if !self.missing_paths.is_empty() {
+            reasons.push(format!(
+                "{} missing path(s): {}",
+                self.missing_paths.len(),
+                self.missing_paths.join(", ")
+            ));
+        }
+        if let Some(age) = self.age_days.filter(|age| *age >= STALE_DAYS) {
+            reasons.push(format!("unchanged for {} days", age));
+        }
+        reasons
+    }
+}
+
+/// Split markdown at `##` headings (text before the first one is "(preamble)")
+pub fn sections(root: &Path, content: &str) -> Vec<Section> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut starts = vec![(0, "(preamble)".to_string())];
+    let mut in_code = false;
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        } else if !in_code {
+            if let Some(title) = line.strip_prefix("## ") {
+                starts.push((i, title.trim().to_string()));
+            }
+        }
+    }
+
+    let mut sections = Vec::new();
+    for (index, (start, title)) in starts.iter().enumerate() {
+        let end = starts.get(index + 1).map_or(lines.len(), |next| next.0);
+        let text = lines[*start..end].join("\n");
+        if text.trim().is_empty() {
+            continue;
+        }
+        sections.push(Section {
+            title: title.clone(),
+            start: *start,
+            end,
+            tokens: estimate_tokens(&text),
+            missing_paths: missing_paths(root, &text),
+            age_days: None,
+        });
+    }
+    sections
+}
+
+/// Fill in `age_days` from `git blame` (left empty for untracked files)
+pub fn apply_ages(root: &Path, file: &str, sections: &mut [Section]) {
+    let Some(times) = line_commit_times(root, file) else {
+        return;
+    };
+    let now = chrono::Utc::now().timestamp();
+    for section in sections {
+        if let Some(newest) = times.get(section.start..section.end.min(times.len())) {
+            if let Some(newest) = newest.iter().max() {
+                section.age_days = Some((now - newest) / 86_400);
+            }
+        }
+    }
+}
+
+/// Stale sections first (most missing paths, then oldest and largest),
+/// falling back to the largest sections when nothing looks stale
+pub fn trim_candidates(sections: &[Section]) -> Vec<&Section> {
+    let mut stale: Vec<&Section> = sections.iter().filter(|s| s.is_stale()).collect();
+    if stale.is_empty() {
+        let mut largest: Vec<&Section> = sections.iter().collect();
+        largest.sort_by_key(|section| std::cmp::Reverse(section.tokens));
+        largest.truncate(MAX_SUGGESTIONS);
+        return largest;
+    }
+    stale.sort_by_key(|section| {
+        std::cmp::Reverse((
+            section.missing_paths.len(),
+            section.age_days.unwrap_or(0) * section.tokens as i64,
+        ))
+    });
+    stale.truncate(MAX_SUGGESTIONS);
+    stale
+}
+
+fn missing_paths(root: &Path, text: &str) -> Vec<String> {
+    let mut missing = BTreeSet::new();
+    // Fences would shift the pairing of inline code spans
+    let text = text.replace("```", "");
+    for reference in text.split('`').skip(1).step_by(2) {
+        if !looks_like_path(reference) {
+            continue;
+        }
+        let first = reference.split('/').next().unwrap_or_default();
+        let bases: Vec<&&str> = PATH_BASES
+            .iter()
+            .filter(|base| root.join(base).join(first).exists())
+            .collect();
+        // Paths under an unknown top-level directory are not ours to judge
+        if bases.is_empty() {
+            continue;
+        }
+        if !bases
+            .iter()
+            .any(|base| root.join(base).join(reference).exists())
+        {
+            missing.insert(reference.to_string());
+        }
+    }
+    missing.into_iter().collect()
+}
+
+/// `docs/setup.md`, `src/components/ui/` — not routes, globs, placeholders
+/// or local dotfiles such as `.env` that are created per checkout
+fn looks_like_path(reference: &str) -> bool {
+    let Some((_, last)) = reference.trim_end_matches('/').rsplit_once('/') else {
+        return false;
+    };
+    let has_extension = last.contains('.') && !last.ends_with('.');
+    !reference.starts_with(['/', '.', '@', '~'])
+        && !last.starts_with('.')
+        && !reference.contains([' ', '<', '>', '*', '{', ':', '$'])
+        && (has_extension || reference.ends_with('/'))
+}
+
+/// Committer time of every line (`git blame --line-porcelain`)
+fn line_commit_times(root: &Path, file: &str) -> Option<Vec<i64>> {
+    let output = Command::new("git")
+        .args(["blame", "--line-porcelain", "--", file])
+        .current_dir(root)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.strip_prefix("committer-time "))
+            .filter_map(|time| time.parse().ok())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("akatsuki"), 2);
+        assert_eq!(estimate_tokens("akatsuki 開発"), 5);
+    }
+
+    #[test]
+    fn test_sections_and_missing_paths() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("docs")).unwrap();
+        std::fs::write(root.path().join("docs/setup.md"), "").unwrap();
+        std::fs::create_dir_all(root.path().join("packages/app-frontend/src/models")).unwrap();
+
+        let content = "# Agent\nintro\n\
+                       ## Setup\nSee `docs/setup.md` and `docs/removed.md`.\n\
+                       ```\n## not a heading\n```\n\
+                       ## Models\n`models/` `models/Gone.ts` `models/.env` `/admin/wasm` `vendor/lib.rs` `bg-black/60`\n";
+        let sections = sections(root.path(), content);
+
+        let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, ["(preamble)", "Setup", "Models"]);
+        assert_eq!(sections[1].missing_paths, ["docs/removed.md"]);
+        assert_eq!(sections[2].missing_paths, ["models/Gone.ts"]);
+        assert!(!sections[0].is_stale());
+
+        let candidates = trim_candidates(&sections);
+        assert_eq!(candidates.len(), 2);
+    }
+}
@@ -1,16 +1,205 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+use walkdir::WalkDir;
 
 use crate::cli::DocsAction;
+use crate::commands::db::erd;
+use crate::utils::backend::BackendClient;
+use crate::utils::config::{ProjectConfig, CONFIG_FILE};
+use crate::utils::output::OutputFormatter;
+use crate::utils::platform;
+
+mod cache;
+mod context;
+mod routes;
+mod watch;
+
+use cache::DocsCache;
+use routes::{BackendRoute, GeneratedPage, RouteMap};
+
+use crate::commands::check::navigation::AppRoute;
 
 pub struct DocsCommand {
     project_root: PathBuf,
 }
 
+/// Frontend layers checked by `docs lint`
+const LINT_LAYERS: [(&str, &str); 6] = [
+    ("UI Components", "packages/app-frontend/src/components"),
+    ("Models", "packages/app-frontend/src/models"),
+    ("Repositories", "packages/app-frontend/src/repositories"),
+    ("Services", "packages/app-frontend/src/services"),
+    ("Hooks", "packages/app-frontend/src/hooks"),
+    ("Pages", "packages/app-frontend/src/pages"),
+];
+
+/// Layer listed by `docs <layer>` / `docs all`
+struct ListLayer {
+    /// Subcommand name, also the `layer` key of the JSON output
+    key: &'static str,
+    heading: &'static str,
+    dir_name: &'static str,
+    dir: &'static str,
+    /// Singular name for the summary line
+    label: &'static str,
+}
+
+const LIST_LAYERS: [ListLayer; 6] = [
+    ListLayer {
+        key: "components",
+        heading: "📦 UI Components",
+        dir_name: "Components",
+        dir: "packages/app-frontend/src/components",
+        label: "UI Component",
+    },
+    ListLayer {
+        key: "models",
+        heading: "📊 Models",
+        dir_name: "Models",
+        dir: "packages/app-frontend/src/models",
+        label: "Model",
+    },
+    ListLayer {
+        key: "repositories",
+        heading: "🗄️  Repositories",
+        dir_name: "Repositories",
+        dir: "packages/app-frontend/src/repositories",
+        label: "Repository",
+    },
+    ListLayer {
+        key: "services",
+        heading: "⚙️  Services",
+        dir_name: "Services",
+        dir: "packages/app-frontend/src/services",
+        label: "Service",
+    },
+    ListLayer {
+        key: "hooks",
+        heading: "🎣 Custom Hooks",
+        dir_name: "Hooks",
+        dir: "packages/app-frontend/src/hooks",
+        label: "Hook",
+    },
+    ListLayer {
+        key: "pages",
+        heading: "📄 Pages",
+        dir_name: "Pages",
+        dir: "packages/app-frontend/src/pages",
+        label: "Page",
+    },
+];
+
+/// Documented files of one layer
+#[derive(Debug, Serialize)]
+struct LayerDocs {
+    layer: &'static str,
+    /// The layer directory exists
+    found: bool,
+    docs: Vec<ComponentDoc>,
+}
+
+#[derive(Debug, Serialize)]
+struct AllDocs<'a> {
+    search: Option<&'a str>,
+    layers: Vec<LayerDocs>,
+}
+
+/// `docs routes` result (frontend / backend filtered by `--search`)
+#[derive(Debug, Serialize)]
+struct RouteReport<'a> {
+    frontend: Vec<&'a AppRoute>,
+    backend: Vec<&'a BackendRoute>,
+    generated: &'a [GeneratedPage],
+    unregistered_pages: Vec<&'a GeneratedPage>,
+    unmerged_modules: &'a [String],
+    /// Every App.tsx route, to point out path conflicts
+    #[serde(skip)]
+    all_frontend: &'a [AppRoute],
+}
+
+/// `docs lint` result
+#[derive(Debug, Serialize)]
+struct CoverageReport {
+    documented: usize,
+    total: usize,
+    percent: usize,
+    layers: Vec<LayerReport>,
+    cache_hits: usize,
+    cache_misses: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct LayerReport {
+    layer: &'static str,
+    documented: usize,
+    total: usize,
+    percent: usize,
+    /// Paths relative to the project root
+    undocumented: Vec<PathBuf>,
+}
+
+/// `docs context-size` result for one file
+#[derive(Debug, Serialize)]
+struct ContextFile {
+    file: String,
+    /// Estimated tokens
+    tokens: usize,
+    budget: usize,
+    /// Trim candidates are stale sections (otherwise the largest ones)
+    stale: bool,
+    /// Empty when within budget
+    trim: Vec<TrimSuggestion>,
+}
+
+impl ContextFile {
+    fn over_budget(&self) -> bool {
+        self.tokens > self.budget
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TrimSuggestion {
+    section: String,
+    /// 1-based
+    line: usize,
+    tokens: usize,
+    reasons: Vec<String>,
+}
+
+/// JSDoc coverage of one layer
 #[derive(Debug, Clone)]
+pub struct LayerCoverage {
+    pub layer: &'static str,
+    pub documented: Vec<PathBuf>,
+    pub undocumented: Vec<PathBuf>,
+}
+
+impl LayerCoverage {
+    pub fn total(&self) -> usize {
+        self.documented.len() + self.undocumented.len()
+    }
+
+    pub fn percent(&self) -> usize {
+        percent(self.documented.len(), self.total())
+    }
+}
+
+fn percent(documented: usize, total: usize) -> usize {
+    if total > 0 {
+        (documented as f64 / total as f64 * 100.0) as usize
+    } else {
+        0
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 struct ComponentDoc {
+    /// Relative to the project root
+    #[serde(rename = "file")]
     file_path: PathBuf,
     summary: String,
     category: String,
@@ -53,146 +242,116 @@ impl DocsCommand {
         }
     }
 
-    pub fn execute(&self, action: DocsAction, search: Option<&str>) -> Result<()> {
+    pub fn execute(
+        &self,
+        action: DocsAction,
+        search: Option<&str>,
+        output: OutputFormatter,
+    ) -> Result<()> {
         match action {
-            DocsAction::All => self.list_all(search),
-            DocsAction::Components => self.list_components(search),
-            DocsAction::Models => self.list_models(search),
-            DocsAction::Repositories => self.list_repositories(search),
-            DocsAction::Services => self.list_services(search),
-            DocsAction::Hooks => self.list_hooks(search),
-            DocsAction::Pages => self.list_pages(search),
-            DocsAction::Lint => self.lint(),
-            DocsAction::Sync { target, dry_run } => self.sync(&target, dry_run),
-        }
-    }
-
-    fn list_all(&self, search: Option<&str>) -> Result<()> {
-        println!("📚 All Project Documentation");
-        if let Some(keyword) = search {
-            println!("🔍 Searching for: \"{}\"\n", keyword);
-        }
-        println!();
-
-        self.list_components(search)?;
-        println!();
-        self.list_models(search)?;
-        println!();
-        self.list_repositories(search)?;
-        println!();
-        self.list_services(search)?;
-        println!();
-        self.list_hooks(search)?;
-        println!();
-        self.list_pages(search)?;
-
-        Ok(())
-    }
-
-    fn list_components(&self, search: Option<&str>) -> Result<()> {
-        println!("📦 UI Components\n");
-
-        let components_dir = self
-            .project_root
-            .join("packages/app-frontend/src/components");
-        if !components_dir.exists() {
-            println!("❌ Components directory not found: {:?}", components_dir);
-            return Ok(());
-        }
-
-        let docs = self.scan_directory(&components_dir, "component")?;
-        let filtered = self.filter_docs(&docs, search);
-        self.print_docs(&filtered, "UI Component");
-
-        Ok(())
-    }
-
-    fn list_models(&self, search: Option<&str>) -> Result<()> {
-        println!("📊 Models\n");
-
-        let models_dir = self.project_root.join("packages/app-frontend/src/models");
-        if !models_dir.exists() {
-            println!("❌ Models directory not found: {:?}", models_dir);
-            return Ok(());
+            DocsAction::All => self.list_all(search, output),
+            DocsAction::Components => self.list_layer(&LIST_LAYERS[0], search, output),
+            DocsAction::Models => self.list_layer(&LIST_LAYERS[1], search, output),
+            DocsAction::Repositories => self.list_layer(&LIST_LAYERS[2], search, output),
+            DocsAction::Services => self.list_layer(&LIST_LAYERS[3], search, output),
+            DocsAction::Hooks => self.list_layer(&LIST_LAYERS[4], search, output),
+            DocsAction::Pages => self.list_layer(&LIST_LAYERS[5], search, output),
+            DocsAction::Routes => self.list_routes(search, output),
+            DocsAction::Lint { no_cache } => self.lint(no_cache, output),
+            DocsAction::Sync {
+                target,
+                dry_run,
+                watch,
+            } => {
+                let targets = self.sync_targets(target)?;
+                if watch {
+                    self.watch_sync(&targets)
+                } else {
+                    self.sync(&targets, dry_run)
+                }
+            }
+            DocsAction::ContextSize { files, budget } => self.context_size(files, budget, output),
+            DocsAction::Index {
+                paths,
+                url,
+                token,
+                namespace,
+                prune,
+                no_wait,
+                dry_run,
+            } => {
+                let client = BackendClient::new(&url)?.with_token(token);
+                self.index(&paths, &client, &namespace, prune, !no_wait, dry_run)
+            }
         }
-
-        let docs = self.scan_directory(&models_dir, "model")?;
-        let filtered = self.filter_docs(&docs, search);
-        self.print_docs(&filtered, "Model");
-
-        Ok(())
     }
 
-    fn list_repositories(&self, search: Option<&str>) -> Result<()> {
-        println!("🗄️  Repositories\n");
-
-        let repos_dir = self
-            .project_root
-            .join("packages/app-frontend/src/repositories");
-        if !repos_dir.exists() {
-            println!("❌ Repositories directory not found: {:?}", repos_dir);
-            return Ok(());
-        }
+    fn list_all(&self, search: Option<&str>, output: OutputFormatter) -> Result<()> {
+        let all = AllDocs {
+            search,
+            layers: LIST_LAYERS
+                .iter()
+                .map(|layer| self.layer_docs(layer, search))
+                .collect::<Result<_>>()?,
+        };
 
-        let docs = self.scan_directory(&repos_dir, "repository")?;
-        let filtered = self.filter_docs(&docs, search);
-        self.print_docs(&filtered, "Repository");
+        output.emit(&all, |all| {
+            println!("📚 All Project Documentation");
+            if let Some(keyword) = all.search {
+                println!("🔍 Searching for: \"{}\"\n", keyword);
+            }
+            println!();
 
-        Ok(())
+            for (index, (layer, docs)) in LIST_LAYERS.iter().zip(&all.layers).enumerate() {
+                if index > 0 {
+                    println!();
+                }
+                self.print_layer(layer, docs);
+            }
+        })
     }
 
-    fn list_services(&self, search: Option<&str>) -> Result<()> {
-        println!("⚙️  Services\n");
-
-        let services_dir = self.project_root.join("packages/app-frontend/src/services");
-        if !services_dir.exists() {
-            println!("❌ Services directory not found: {:?}", services_dir);
-            return Ok(());
-        }
-
-        let docs = self.scan_directory(&services_dir, "service")?;
-        let filtered = self.filter_docs(&docs, search);
-        self.print_docs(&filtered, "Service");
-
-        Ok(())
+    fn list_layer(
+        &self,
+        layer: &ListLayer,
+        search: Option<&str>,
+        output: OutputFormatter,
+    ) -> Result<()> {
+        let docs = self.layer_docs(layer, search)?;
+        output.emit(&docs, |docs| self.print_layer(layer, docs))
     }
 
-    fn list_hooks(&self, search: Option<&str>) -> Result<()> {
-        println!("🎣 Custom Hooks\n");
-
-        let hooks_dir = self.project_root.join("packages/app-frontend/src/hooks");
-        if !hooks_dir.exists() {
-            println!("❌ Hooks directory not found: {:?}", hooks_dir);
-            return Ok(());
-        }
-
-        let docs = self.scan_directory(&hooks_dir, "hook")?;
-        let filtered = self.filter_docs(&docs, search);
-        self.print_docs(&filtered, "Hook");
-
-        Ok(())
+    fn layer_docs(&self, layer: &ListLayer, search: Option<&str>) -> Result<LayerDocs> {
+        let dir = self.project_root.join(layer.dir);
+        let docs = if dir.exists() {
+            self.filter_docs(&self.scan_directory(&dir)?, search)
+        } else {
+            Vec::new()
+        };
+        Ok(LayerDocs {
+            layer: layer.key,
+            found: dir.exists(),
+            docs,
+        })
     }
 
-    fn list_pages(&self, search: Option<&str>) -> Result<()> {
-        println!("📄 Pages\n");
-
-        let pages_dir = self.project_root.join("packages/app-frontend/src/pages");
-        if !pages_dir.exists() {
-            println!("❌ Pages directory not found: {:?}", pages_dir);
-            return Ok(());
+    fn print_layer(&self, layer: &ListLayer, docs: &LayerDocs) {
+        println!("{}\n", layer.heading);
+        if !docs.found {
+            println!(
+                "❌ {} directory not found: {:?}",
+                layer.dir_name,
+                self.project_root.join(layer.dir)
+            );
+            return;
         }
-
-        let docs = self.scan_directory(&pages_dir, "page")?;
-        let filtered = self.filter_docs(&docs, search);
-        self.print_docs(&filtered, "Page");
-
-        Ok(())
+        self.print_docs(&docs.docs, layer.label);
     }
 
-    fn scan_directory(&self, dir: &Path, doc_type: &str) -> Result<Vec<ComponentDoc>> {
+    fn scan_directory(&self, dir: &Path) -> Result<Vec<ComponentDoc>> {
         let mut docs = Vec::new();
 
-        self.walk_dir(dir, &mut docs, doc_type)?;
+        self.walk_dir(dir, &mut docs)?;
 
         // Sort by category, then by file name
         docs.sort_by(|a, b| {
@@ -204,7 +363,7 @@ impl DocsCommand {
         Ok(docs)
     }
 
-    fn walk_dir(&self, dir: &Path, docs: &mut Vec<ComponentDoc>, doc_type: &str) -> Result<()> {
+    fn walk_dir(&self, dir: &Path, docs: &mut Vec<ComponentDoc>) -> Result<()> {
         if !dir.is_dir() {
             return Ok(());
         }
@@ -214,7 +373,7 @@ impl DocsCommand {
             let path = entry.path();
 
             if path.is_dir() {
-                self.walk_dir(&path, docs, doc_type)?;
+                self.walk_dir(&path, docs)?;
             } else if path.extension().and_then(|s| s.to_str()) == Some("ts")
                 || path.extension().and_then(|s| s.to_str()) == Some("tsx")
                 || path.extension().and_then(|s| s.to_str()) == Some("jsx")
@@ -224,7 +383,7 @@ impl DocsCommand {
                     continue;
                 }
 
-                if let Some(doc) = self.extract_doc(&path, doc_type)? {
+                if let Some(doc) = self.extract_doc(&path)? {
                     docs.push(doc);
                 }
             }
@@ -233,7 +392,7 @@ impl DocsCommand {
         Ok(())
     }
 
-    fn extract_doc(&self, file_path: &Path, _doc_type: &str) -> Result<Option<ComponentDoc>> {
+    fn extract_doc(&self, file_path: &Path) -> Result<Option<ComponentDoc>> {
         let content = fs::read_to_string(file_path)?;
 
         // Extract JSDoc comment (/** ... */)
@@ -261,7 +420,10 @@ impl DocsCommand {
             let category = self.categorize_file(file_path);
 
             Ok(Some(ComponentDoc {
-                file_path: file_path.to_path_buf(),
+                file_path: file_path
+                    .strip_prefix(&self.project_root)
+                    .unwrap_or(file_path)
+                    .to_path_buf(),
                 summary,
                 category,
             }))
@@ -271,7 +433,7 @@ impl DocsCommand {
     }
 
     fn categorize_file(&self, file_path: &Path) -> String {
-        let path_str = file_path.to_string_lossy();
+        let path_str = platform::slash_path(file_path);
 
         if path_str.contains("/layout/") {
             "Layout".to_string()
@@ -312,12 +474,7 @@ impl DocsCommand {
                 current_category = doc.category.clone();
             }
 
-            // Print file path relative to project root
-            let relative_path = doc
-                .file_path
-                .strip_prefix(&self.project_root)
-                .unwrap_or(&doc.file_path);
-            println!("{}", relative_path.display());
+            println!("{}", doc.file_path.display());
 
             // Print summary with indentation
             for line in doc.summary.lines() {
@@ -357,68 +514,183 @@ impl DocsCommand {
         }
     }
 
-    fn lint(&self) -> Result<()> {
-        println!("🔍 Documentation Coverage Report\n");
+    fn list_routes(&self, search: Option<&str>, output: OutputFormatter) -> Result<()> {
+        let map = RouteMap::collect(&self.project_root)?;
+        let matches = |values: &[&str]| match search {
+            Some(keyword) => values
+                .iter()
+                .any(|value| value.to_lowercase().contains(&keyword.to_lowercase())),
+            None => true,
+        };
+        let report = RouteReport {
+            frontend: map
+                .frontend
+                .iter()
+                .filter(|r| matches(&[&r.path, r.element.as_deref().unwrap_or("")]))
+                .collect(),
+            backend: map
+                .backend
+                .iter()
+                .filter(|r| matches(&[&r.path, &r.handler, &r.module]))
+                .collect(),
+            generated: &map.generated,
+            unregistered_pages: map.unregistered_pages(),
+            unmerged_modules: &map.unmerged_modules,
+            all_frontend: &map.frontend,
+        };
 
-        let mut total_files = 0;
-        let mut total_documented = 0;
+        output.emit(&report, Self::print_routes)?;
+        let unregistered = report.unregistered_pages.len() + report.unmerged_modules.len();
+        if unregistered > 0 {
+            return Err(output.failed(format!("{} unregistered route source(s)", unregistered)));
+        }
+        Ok(())
+    }
 
-        // Check each layer
-        let layers = vec![
-            (
-                "UI Components",
-                self.project_root
-                    .join("packages/app-frontend/src/components"),
-            ),
-            (
-                "Models",
-                self.project_root.join("packages/app-frontend/src/models"),
-            ),
-            (
-                "Repositories",
-                self.project_root
-                    .join("packages/app-frontend/src/repositories"),
-            ),
-            (
-                "Services",
-                self.project_root.join("packages/app-frontend/src/services"),
-            ),
-            (
-                "Hooks",
-                self.project_root.join("packages/app-frontend/src/hooks"),
-            ),
-            (
-                "Pages",
-                self.project_root.join("packages/app-frontend/src/pages"),
-            ),
-        ];
+    fn print_routes(report: &RouteReport) {
+        println!("🗺️  Route Map\n");
 
-        for (layer_name, dir) in layers {
-            if !dir.exists() {
-                continue;
+        println!("━━━ Frontend (App.tsx) ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!();
+        let width = report
+            .frontend
+            .iter()
+            .map(|r| r.path.len())
+            .max()
+            .unwrap_or(0);
+        for route in &report.frontend {
+            println!(
+                "  {:<width$}  {}",
+                route.path,
+                route.element.as_deref().unwrap_or("-"),
+                width = width
+            );
+        }
+        println!();
+
+        println!("━━━ Backend (axum) ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!();
+        let width = report
+            .backend
+            .iter()
+            .map(|r| r.path.len())
+            .max()
+            .unwrap_or(0);
+        for route in &report.backend {
+            println!(
+                "  {:<6}  {:<width$}  {} ({})",
+                route.method,
+                route.path,
+                route.handler,
+                route.module,
+                width = width
+            );
+        }
+        println!();
+
+        println!(
+            "Total: {} frontend routes, {} backend routes, {} generated admin pages",
+            report.frontend.len(),
+            report.backend.len(),
+            report.generated.len()
+        );
+
+        if report.unregistered_pages.is_empty() && report.unmerged_modules.is_empty() {
+            println!("\n✅ All generated pages and backend routers are registered");
+            return;
+        }
+
+        println!();
+        for page in &report.unregistered_pages {
+            println!(
+                "❌ {} is generated but not routed in App.tsx",
+                page.component
+            );
+            println!(
+                "   import {{ {} }} from './pages/admin/entities/{}'",
+                page.component, page.component
+            );
+            if let Some(path) = &page.suggested_path {
+                println!(
+                    "   <Route path=\"{}\" element={{<{} />}} />",
+                    path, page.component
+                );
+                if let Some(existing) = report.all_frontend.iter().find(|r| &r.path == path) {
+                    println!(
+                        "   (path already used by {} - pick another path)",
+                        existing.element.as_deref().unwrap_or("another route")
+                    );
+                }
             }
+        }
+        for module in report.unmerged_modules {
+            println!(
+                "❌ {}::router() is not merged in packages/app-backend/src/main.rs",
+                module
+            );
+            println!("   .merge({}::router())", module);
+        }
+    }
 
-            let (documented, undocumented) = self.lint_layer(&dir)?;
-            let total = documented.len() + undocumented.len();
-            let coverage = if total > 0 {
-                (documented.len() as f64 / total as f64 * 100.0) as usize
-            } else {
-                0
-            };
+    fn lint(&self, no_cache: bool, output: OutputFormatter) -> Result<()> {
+        // --no-cache re-reads every file but still refreshes the cache
+        let mut cache = if no_cache {
+            DocsCache::default()
+        } else {
+            DocsCache::load(&self.project_root)
+        };
+
+        let layers: Vec<LayerReport> = self
+            .layer_coverage(&mut cache)?
+            .into_iter()
+            .map(|layer| LayerReport {
+                layer: layer.layer,
+                documented: layer.documented.len(),
+                total: layer.total(),
+                percent: layer.percent(),
+                undocumented: layer
+                    .undocumented
+                    .iter()
+                    .map(|file| {
+                        file.strip_prefix(&self.project_root)
+                            .unwrap_or(file)
+                            .to_path_buf()
+                    })
+                    .collect(),
+            })
+            .collect();
+        let (cache_hits, cache_misses) = (cache.hits, cache.misses);
+        cache.save(&self.project_root)?;
+
+        let documented = layers.iter().map(|layer| layer.documented).sum();
+        let total = layers.iter().map(|layer| layer.total).sum();
+        let report = CoverageReport {
+            documented,
+            total,
+            percent: percent(documented, total),
+            layers,
+            cache_hits,
+            cache_misses,
+        };
+        output.emit(&report, Self::print_coverage)
+    }
 
-            total_files += total;
-            total_documented += documented.len();
+    fn print_coverage(report: &CoverageReport) {
+        println!("🔍 Documentation Coverage Report\n");
 
-            println!("━━━ {} ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", layer_name);
+        for layer in &report.layers {
+            println!("━━━ {} ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━", layer.layer);
             println!();
-            println!("  Coverage: {}/{} ({}%)", documented.len(), total, coverage);
+            println!(
+                "  Coverage: {}/{} ({}%)",
+                layer.documented, layer.total, layer.percent
+            );
             println!();
 
-            if !undocumented.is_empty() {
+            if !layer.undocumented.is_empty() {
                 println!("  ⚠️  Undocumented files:");
-                for file in &undocumented {
-                    let relative_path = file.strip_prefix(&self.project_root).unwrap_or(file);
-                    println!("    • {}", relative_path.display());
+                for file in &layer.undocumented {
+                    println!("    • {}", file.display());
                 }
                 println!();
             } else {
@@ -427,22 +699,21 @@ impl DocsCommand {
             }
         }
 
-        // Overall summary
-        let overall_coverage = if total_files > 0 {
-            (total_documented as f64 / total_files as f64 * 100.0) as usize
-        } else {
-            0
-        };
-
         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         println!();
         println!(
             "📊 Overall Coverage: {}/{} ({}%)",
-            total_documented, total_files, overall_coverage
+            report.documented, report.total, report.percent
+        );
+        println!(
+            "   ({} cached, {} re-read; cache: {})",
+            report.cache_hits,
+            report.cache_misses,
+            cache::CACHE_PATH
         );
         println!();
 
-        if overall_coverage < 100 {
+        if report.percent < 100 {
             println!("💡 Tip: Add JSDoc comments to undocumented files:");
             println!("   /**");
             println!("    * Brief description of the component/module");
@@ -451,15 +722,42 @@ impl DocsCommand {
         } else {
             println!("🎉 Perfect! All files are documented!");
         }
+    }
 
-        Ok(())
+    /// JSDoc coverage of the existing layers (uses and refreshes the lint cache)
+    pub fn coverage(&self) -> Result<Vec<LayerCoverage>> {
+        let mut cache = DocsCache::load(&self.project_root);
+        let layers = self.layer_coverage(&mut cache)?;
+        cache.save(&self.project_root)?;
+        Ok(layers)
     }
 
-    fn lint_layer(&self, dir: &Path) -> Result<(Vec<PathBuf>, Vec<PathBuf>)> {
+    fn layer_coverage(&self, cache: &mut DocsCache) -> Result<Vec<LayerCoverage>> {
+        let mut layers = Vec::new();
+        for (layer, dir) in LINT_LAYERS {
+            let dir = self.project_root.join(dir);
+            if !dir.exists() {
+                continue;
+            }
+            let (documented, undocumented) = self.lint_layer(&dir, cache)?;
+            layers.push(LayerCoverage {
+                layer,
+                documented,
+                undocumented,
+            });
+        }
+        Ok(layers)
+    }
+
+    fn lint_layer(
+        &self,
+        dir: &Path,
+        cache: &mut DocsCache,
+    ) -> Result<(Vec<PathBuf>, Vec<PathBuf>)> {
         let mut documented = Vec::new();
         let mut undocumented = Vec::new();
 
-        self.collect_files(dir, &mut documented, &mut undocumented)?;
+        self.collect_files(dir, cache, &mut documented, &mut undocumented)?;
 
         Ok((documented, undocumented))
     }
@@ -467,6 +765,7 @@ impl DocsCommand {
     fn collect_files(
         &self,
         dir: &Path,
+        cache: &mut DocsCache,
         documented: &mut Vec<PathBuf>,
         undocumented: &mut Vec<PathBuf>,
     ) -> Result<()> {
@@ -479,7 +778,7 @@ impl DocsCommand {
             let path = entry.path();
 
             if path.is_dir() {
-                self.collect_files(&path, documented, undocumented)?;
+                self.collect_files(&path, cache, documented, undocumented)?;
             } else if path.extension().and_then(|s| s.to_str()) == Some("ts")
                 || path.extension().and_then(|s| s.to_str()) == Some("tsx")
                 || path.extension().and_then(|s| s.to_str()) == Some("jsx")
@@ -490,7 +789,8 @@ impl DocsCommand {
                 }
 
                 // Check if file has JSDoc
-                let has_jsdoc = self.has_jsdoc(&path)?;
+                let has_jsdoc =
+                    cache.has_jsdoc(&self.project_root, &path, |path| self.has_jsdoc(path))?;
                 if has_jsdoc {
                     documented.push(path);
                 } else {
@@ -521,7 +821,22 @@ impl DocsCommand {
         }
     }
 
-    fn sync(&self, target: &str, dry_run: bool) -> Result<()> {
+    /// `--target`, else `[docs] sync_targets` in akatsuki.toml
+    fn sync_targets(&self, target: Option<String>) -> Result<Vec<String>> {
+        let targets = match target {
+            Some(target) => vec![target],
+            None => ProjectConfig::load()?.docs.sync_targets,
+        };
+        if targets.is_empty() {
+            anyhow::bail!(
+                "No sync targets: pass --target or set [docs] sync_targets in {}",
+                CONFIG_FILE
+            );
+        }
+        Ok(targets)
+    }
+
+    fn sync(&self, targets: &[String], dry_run: bool) -> Result<()> {
         println!("\n🔍 Scanning project components...");
 
         // 1. Collect statistics
@@ -552,30 +867,200 @@ impl DocsCommand {
         // 2. Generate new Markdown section
         let new_section = self.generate_component_section(&stats)?;
 
-        // 3. Read target file
-        let target_path = self.project_root.join(target);
-        if !target_path.exists() {
-            anyhow::bail!("Target file not found: {}", target);
+        let mut changed = Vec::new();
+        for target in targets {
+            // 3. Read target file
+            let target_path = self.project_root.join(target);
+            if !target_path.exists() {
+                anyhow::bail!("Target file not found: {}", target);
+            }
+
+            let original_content = fs::read_to_string(&target_path)?;
+
+            // 4. Detect and replace section
+            let updated_content = self
+                .replace_section(&original_content, &new_section)
+                .with_context(|| format!("Cannot sync {}", target))?;
+
+            // 5. Show diff or apply changes (unchanged files are not rewritten)
+            if updated_content == original_content {
+                println!("\n✅ {} is already up to date", target);
+                continue;
+            }
+            changed.push(target.as_str());
+            if dry_run {
+                println!("\n📋 Proposed changes to {} (--dry-run):\n", target);
+                self.print_diff(&original_content, &updated_content);
+            } else {
+                println!("\n📝 Updating {}...", target);
+                fs::write(&target_path, updated_content)?;
+                println!("✅ {} updated successfully!", target);
+            }
+        }
+
+        if !changed.is_empty() {
+            if dry_run {
+                println!("\n💡 Run without --dry-run to apply changes.");
+            } else {
+                println!("\n💡 Review changes: git diff {}", changed.join(" "));
+            }
         }
 
-        let original_content = fs::read_to_string(&target_path)?;
+        // 6. Regenerate the ERD when the project keeps one (akatsuki db erd)
+        for path in erd::sync(&self.project_root, dry_run)? {
+            if dry_run {
+                println!("📐 {} is out of date with the migrations", path);
+            } else {
+                println!("📐 {} regenerated from the migrations", path);
+            }
+        }
 
-        // 4. Detect and replace section
-        let updated_content = self.replace_section(&original_content, &new_section)?;
+        Ok(())
+    }
 
-        // 5. Show diff or apply changes
-        if dry_run {
-            println!("\n📋 Proposed changes (--dry-run):\n");
-            self.print_diff(&original_content, &updated_content);
-            println!("\n💡 Run without --dry-run to apply changes.");
+    fn context_size(
+        &self,
+        files: Vec<String>,
+        budget: Option<usize>,
+        output: OutputFormatter,
+    ) -> Result<()> {
+        let config = ProjectConfig::load()?.docs;
+        let explicit = !files.is_empty();
+        let files = if explicit {
+            files
         } else {
-            println!("\n📝 Updating {}...", target);
-            fs::write(&target_path, updated_content)?;
-            println!("✅ {} updated successfully!", target);
-            println!("\n💡 Review changes: git diff {}", target);
+            config.context_files
+        };
+
+        let mut measured = Vec::new();
+        for file in &files {
+            let path = self.project_root.join(file);
+            let content = match fs::read_to_string(&path) {
+                Ok(content) => content,
+                // Configured defaults that the project does not use
+                Err(_) if !explicit => continue,
+                Err(error) => {
+                    return Err(error).with_context(|| format!("Failed to read {}", file))
+                }
+            };
+
+            let tokens = context::estimate_tokens(&content);
+            let limit = budget
+                .or_else(|| config.context_budgets.get(file).copied())
+                .unwrap_or(config.context_budget);
+            let mut measurement = ContextFile {
+                file: file.clone(),
+                tokens,
+                budget: limit,
+                stale: false,
+                trim: Vec::new(),
+            };
+            if measurement.over_budget() {
+                let mut sections = context::sections(&self.project_root, &content);
+                context::apply_ages(&self.project_root, file, &mut sections);
+                let candidates = context::trim_candidates(&sections);
+                measurement.stale = candidates.iter().any(|section| section.is_stale());
+                measurement.trim = candidates
+                    .iter()
+                    .map(|section| TrimSuggestion {
+                        section: section.title.clone(),
+                        line: section.start + 1,
+                        tokens: section.tokens,
+                        reasons: section.reasons(),
+                    })
+                    .collect();
+            }
+            measured.push(measurement);
         }
 
-        Ok(())
+        output.emit(&measured, |measured| {
+            Self::print_context_size(&files, measured)
+        })
+    }
+
+    fn print_context_size(files: &[String], measured: &[ContextFile]) {
+        println!("\n📏 AI Context Size (estimated tokens)\n");
+
+        for measurement in measured {
+            let percent = measurement.tokens * 100 / measurement.budget.max(1);
+            let size = format!("~{}", measurement.tokens);
+
+            if !measurement.over_budget() {
+                println!(
+                    "  ✅ {:<34} {:>8} / {} ({}%)",
+                    measurement.file, size, measurement.budget, percent
+                );
+                continue;
+            }
+            println!(
+                "  ⚠️  {:<33} {:>8} / {} ({}%, over by ~{})",
+                measurement.file,
+                size,
+                measurement.budget,
+                percent,
+                measurement.tokens - measurement.budget
+            );
+
+            println!(
+                "      {}",
+                if measurement.stale {
+                    "Stale sections to trim first:"
+                } else {
+                    "No stale sections found; largest sections:"
+                }
+            );
+            for suggestion in &measurement.trim {
+                println!(
+                    "      - ## {} (line {}, ~{} tokens)",
+                    suggestion.section, suggestion.line, suggestion.tokens
+                );
+                for reason in &suggestion.reasons {
+                    println!("          {}", reason);
+                }
+            }
+            let freed: usize = measurement.trim.iter().map(|s| s.tokens).sum();
+            println!("      These sections total ~{} tokens", freed);
+            println!();
+        }
+
+        if measured.is_empty() {
+            println!("  No context files found ({})", files.join(", "));
+            return;
+        }
+        println!();
+        let over_budget = measured.iter().filter(|m| m.over_budget()).count();
+        if over_budget > 0 {
+            println!(
+                "💡 {} file(s) over budget. Move details into docs/ and link them, or adjust [docs] context_budget(s) in {}",
+                over_budget, CONFIG_FILE
+            );
+        } else {
+            println!("✅ All context files are within budget");
+        }
+    }
+
+    /// Sync once, then again whenever the frontend sources or migrations change
+    fn watch_sync(&self, targets: &[String]) -> Result<()> {
+        let inputs: Vec<String> = watch::WATCHED.iter().map(|dir| dir.to_string()).collect();
+        self.sync(targets, false)?;
+
+        println!(
+            "\n👀 Watching {} (Ctrl+C to stop)",
+            watch::WATCHED.join(", ")
+        );
+        let mut last = watch::fingerprint(&self.project_root, &inputs);
+        loop {
+            last = watch::wait_for_change(&self.project_root, &inputs, &last);
+            println!(
+                "\n🔄 [{}] Change detected, syncing {}",
+                chrono::Local::now().format("%H:%M:%S"),
+                targets.join(", ")
+            );
+            // Keep watching through a broken marker or a half-written file
+            if let Err(error) = self.sync(targets, false) {
+                eprintln!("⚠️  Sync failed: {:#}", error);
+            }
+        }
     }
 
     fn collect_sync_stats(&self) -> Result<SyncStats> {
@@ -609,13 +1094,14 @@ impl DocsCommand {
         ];
 
         let mut stats = SyncStats::default();
+        let mut cache = DocsCache::load(&self.project_root);
 
         for (layer_name, dir) in layers {
             if !dir.exists() {
                 continue;
             }
 
-            let (documented, undocumented) = self.lint_layer(&dir)?;
+            let (documented, undocumented) = self.lint_layer(&dir, &mut cache)?;
             let total = documented.len() + undocumented.len();
             let coverage = if total > 0 {
                 (documented.len() as f64 / total as f64 * 100.0) as usize
@@ -649,6 +1135,7 @@ impl DocsCommand {
             }
         }
 
+        cache.save(&self.project_root)?;
         Ok(stats)
     }
 
@@ -707,6 +1194,127 @@ impl DocsCommand {
         Ok(format!("{}\n{}{}", before, new_section, after))
     }
 
+    fn index(
+        &self,
+        paths: &[String],
+        client: &BackendClient,
+        namespace: &str,
+        prune: bool,
+        wait: bool,
+        dry_run: bool,
+    ) -> Result<()> {
+        println!("\n🔍 Collecting markdown documents...");
+
+        let documents = self.collect_markdown(paths)?;
+        if documents.is_empty() {
+            anyhow::bail!("No markdown files found in: {}", paths.join(", "));
+        }
+
+        for doc in &documents {
+            println!("  {} ({} bytes)", doc.path, doc.content.len());
+        }
+        println!("  Total: {} files", documents.len());
+
+        if dry_run {
+            println!(
+                "\n💡 Run without --dry-run to upload to {}",
+                client.base_url()
+            );
+            return Ok(());
+        }
+
+        println!(
+            "\n📤 Uploading to {} (namespace: {})...",
+            client.base_url(),
+            namespace
+        );
+
+        let mut job: IndexJob = client.post_json(
+            "/api/aigen/docs/index",
+            &IndexRequest {
+                documents: &documents,
+                namespace,
+                prune,
+            },
+        )?;
+        println!("  Job queued: {}", job.id);
+
+        if !wait {
+            println!(
+                "\n💡 Check progress: curl {}/api/jobs/{}",
+                client.base_url(),
+                job.id
+            );
+            return Ok(());
+        }
+
+        while job.status != "completed" && job.status != "failed" {
+            std::thread::sleep(Duration::from_secs(1));
+            job = client.get_json(&format!("/api/jobs/{}", job.id))?;
+            println!("  {} ({}%)", job.status, job.progress);
+        }
+
+        if job.status == "failed" {
+            anyhow::bail!(
+                "Indexing failed: {}",
+                job.error.unwrap_or_else(|| "unknown error".to_string())
+            );
+        }
+
+        let chunks = job
+            .result
+            .as_ref()
+            .and_then(|r| r["chunks"].as_u64())
+            .unwrap_or(0);
+        println!("✅ Indexed {} files ({} chunks)", documents.len(), chunks);
+        println!(
+            "\n💡 Ask questions: POST {}/api/aigen/ask-docs",
+            client.base_url()
+        );
+
+        Ok(())
+    }
+
+    fn collect_markdown(&self, paths: &[String]) -> Result<Vec<IndexDocument>> {
+        let mut documents = Vec::new();
+
+        for path in paths {
+            let full_path = self.project_root.join(path);
+            if !full_path.exists() {
+                println!("  ⚠️  Skipping missing path: {}", path);
+                continue;
+            }
+
+            for entry in WalkDir::new(&full_path)
+                .sort_by_file_name()
+                .into_iter()
+                .filter_map(|e| e.ok())
+            {
+                let file_path = entry.path();
+                if !entry.file_type().is_file()
+                    || file_path.extension().and_then(|e| e.to_str()) != Some("md")
+                {
+                    continue;
+                }
+
+                let content = fs::read_to_string(file_path)
+                    .with_context(|| format!("Failed to read {}", file_path.display()))?;
+                let relative = platform::slash_path(
+                    file_path
+                        .strip_prefix(&self.project_root)
+                        .unwrap_or(file_path),
+                );
+
+                documents.push(IndexDocument {
+                    path: relative,
+                    content,
+                });
+            }
+        }
+
+        Ok(documents)
+    }
+
     fn print_diff(&self, old: &str, new: &str) {
         // Simple line-by-line diff
         let old_lines: Vec<&str> = old.lines().collect();
@@ -733,6 +1341,28 @@ impl DocsCommand {
     }
 }
 
+#[derive(Serialize)]
+struct IndexDocument {
+    path: String,
+    content: String,
+}
+
+#[derive(Serialize)]
+struct IndexRequest<'a> {
+    documents: &'a [IndexDocument],
+    namespace: &'a str,
+    prune: bool,
+}
+
+#[derive(Deserialize)]
+struct IndexJob {
+    id: String,
+    status: String,
+    progress: u8,
+    result: Option<serde_json::Value>,
+    error: Option<String>,
+}
+
 #[derive(Default)]
 struct SyncStats {
     components_count: usize,
@@ -0,0 +1,236 @@
+/**
+ * Route Map
+ * Unified view of frontend routes (App.tsx), generated admin pages and
+ * backend axum routes
+ *
+ * Flags:
+ * - Generated AdminPages (src/pages/admin/entities) never registered in App.tsx
+ * - Backend modules whose router() is never merged in main.rs
+ */
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::commands::check::navigation::{self, AppRoute};
+
+const APP_ROUTER_FILES: [&str; 2] = [
+    "packages/app-frontend/src/App.tsx",
+    "packages/app-frontend/src/App.jsx",
+];
+const GENERATED_PAGES_DIR: &str = "packages/app-frontend/src/pages/admin/entities";
+const BACKEND_SRC: &str = "packages/app-backend/src";
+
+/// Backend route: one row per HTTP method
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BackendRoute {
+    pub method: String,
+    pub path: String,
+    pub handler: String,
+    /// Module file (e.g. jobs.rs)
+    pub module: String,
+}
+
+/// AdminPage generated by `akatsuki api new`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GeneratedPage {
+    pub component: String,
+    /// Route suggested in the generated file header
+    pub suggested_path: Option<String>,
+}
+
+/// Route table for `akatsuki docs routes`
+pub struct RouteMap {
+    pub frontend: Vec<AppRoute>,
+    pub generated: Vec<GeneratedPage>,
+    pub backend: Vec<BackendRoute>,
+    /// Backend modules defining `router()` that main.rs never merges
+    pub unmerged_modules: Vec<String>,
+}
+
+impl RouteMap {
+    pub fn collect(project_root: &Path) -> Result<Self> {
+        let frontend = match APP_ROUTER_FILES
+            .iter()
+            .map(|file| project_root.join(file))
+            .find(|path| path.exists())
+        {
+            Some(path) => navigation::parse_routes(
+                &fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?,
+            ),
+            None => Vec::new(),
+        };
+
+        let mut generated = Vec::new();
+        let pages_dir = project_root.join(GENERATED_PAGES_DIR);
+        if pages_dir.is_dir() {
+            for entry in fs::read_dir(&pages_dir)? {
+                let path = entry?.path();
+                let Some(component) = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.strip_suffix(".tsx"))
+                    .filter(|name| name.ends_with("AdminPage"))
+                else {
+                    continue;
+                };
+                let content = fs::read_to_string(&path)?;
+                generated.push(GeneratedPage {
+                    component: component.to_string(),
+                    suggested_path: navigation::parse_routes(&content)
+                        .into_iter()
+                        .next()
+                        .map(|route| route.path),
+                });
+            }
+        }
+        generated.sort_by(|a, b| a.component.cmp(&b.component));
+
+        let mut backend = Vec::new();
+        let mut router_modules = Vec::new();
+        let mut main_rs = String::new();
+        let src_dir = project_root.join(BACKEND_SRC);
+        if src_dir.is_dir() {
+            let mut files: Vec<_> = fs::read_dir(&src_dir)?
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+                .collect();
+            files.sort();
+
+            for path in files {
+                let module = path.file_name().unwrap().to_string_lossy().to_string();
+                let content = fs::read_to_string(&path)?;
+                if module == "main.rs" {
+                    main_rs = content.clone();
+                } else if content.contains("pub fn router()") {
+                    router_modules.push(module.trim_end_matches(".rs").to_string());
+                }
+                backend.extend(parse_axum_routes(&content, &module));
+            }
+        }
+
+        let unmerged_modules = router_modules
+            .into_iter()
+            .filter(|module| !main_rs.contains(&format!("{}::router()", module)))
+            .collect();
+
+        Ok(Self {
+            frontend,
+            generated,
+            backend,
+            unmerged_modules,
+        })
+    }
+
+    /// Generated pages whose component is not rendered by any App.tsx route
+    pub fn unregistered_pages(&self) -> Vec<&GeneratedPage> {
+        self.generated
+            .iter()
+            .filter(|page| {
+                !self
+                    .frontend
+                    .iter()
+                    .any(|route| route.element.as_deref() == Some(page.component.as_str()))
+            })
+            .collect()
+    }
+}
+
+/// Extract `.route("/path", get(a).post(b))` registrations (one row per method)
+pub fn parse_axum_routes(content: &str, module: &str) -> Vec<BackendRoute> {
+    let method_re = Regex::new(r"\b(get|post|put|patch|delete)\((\w+)\)").unwrap();
+    let mut routes = Vec::new();
+
+    let mut rest = content;
+    while let Some(start) = rest.find(".route(") {
+        rest = &rest[start + ".route(".len()..];
+
+        // Arguments up to the matching closing parenthesis
+        let mut depth = 1;
+        let end = rest
+            .char_indices()
+            .find(|&(_, c)| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+            .map(|(index, _)| index)
+            .unwrap_or(rest.len());
+        let args = &rest[..end];
+
+        let Some(path) = args.split('"').nth(1) else {
+            continue;
+        };
+        for captures in method_re.captures_iter(args) {
+            routes.push(BackendRoute {
+                method: captures[1].to_uppercase(),
+                path: path.to_string(),
+                handler: captures[2].to_string(),
+                module: module.to_string(),
+            });
+        }
+    }
+    routes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_axum_routes() {
+        let content = r#"
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/jobs/:id", get(get_job))
+        .route(
+            "/api/admin/jobs",
+            get(admin_list_jobs).delete(admin_purge_jobs),
+        )
+        .route(
+            "/api/aigen/stt",
+            post(speech_to_text).layer(DefaultBodyLimit::max(MAX_STT_BYTES)),
+        )
+}
+"#;
+        let routes = parse_axum_routes(content, "jobs.rs");
+        let rows: Vec<(&str, &str, &str)> = routes
+            .iter()
+            .map(|r| (r.method.as_str(), r.path.as_str(), r.handler.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("GET", "/api/jobs/:id", "get_job"),
+                ("GET", "/api/admin/jobs", "admin_list_jobs"),
+                ("DELETE", "/api/admin/jobs", "admin_purge_jobs"),
+                ("POST", "/api/aigen/stt", "speech_to_text"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unregistered_pages() {
+        let map = RouteMap {
+            frontend: navigation::parse_routes(
+                r#"<Route path="/admin/products" element={<ProductAdminPage />} />"#,
+            ),
+            generated: ["ProductAdminPage", "OrderAdminPage"]
+                .map(|component| GeneratedPage {
+                    component: component.to_string(),
+                    suggested_path: None,
+                })
+                .to_vec(),
+            backend: Vec::new(),
+            unmerged_modules: Vec::new(),
+        };
+        let unregistered = map.unregistered_pages();
+        assert_eq!(unregistered.len(), 1);
+        assert_eq!(unregistered[0].component, "OrderAdminPage");
+    }
+}
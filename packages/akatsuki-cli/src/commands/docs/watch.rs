@@ -0,0 +1,88 @@
+/**
+ * Docs Watch
+ * Change detection for `akatsuki docs sync --watch`
+ *
+ * Polls the path, mtime and size of every watched file instead of relying on
+ * OS file events, so it behaves the same on every platform and in containers.
+ */
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, UNIX_EPOCH};
+
+use crate::utils::content_hash::{input_files, ContentHasher};
+use crate::utils::platform::slash_path;
+
+/// Trees whose changes affect the synced sections (and the ERD)
+pub const WATCHED: [&str; 2] = ["packages/app-frontend/src", "supabase/migrations"];
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Cheap fingerprint of the files under `inputs` (no contents are read)
+pub fn fingerprint(root: &Path, inputs: &[String]) -> String {
+    let mut hasher = ContentHasher::new();
+    for file in input_files(root, inputs) {
+        let Ok(metadata) = file.metadata() else {
+            continue;
+        };
+        let mtime_ns = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        hasher.write_str(&slash_path(file.strip_prefix(root).unwrap_or(&file)));
+        hasher.write(&mtime_ns.to_le_bytes());
+        hasher.write(&metadata.len().to_le_bytes());
+    }
+    hasher.finish_hex()
+}
+
+/// Block until the fingerprint differs from `last` and then stays the same for
+/// one poll interval (an editor or `git checkout` writes many files at once)
+pub fn wait_for_change(root: &Path, inputs: &[String], last: &str) -> String {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let current = fingerprint(root, inputs);
+        if current == last {
+            continue;
+        }
+
+        let mut settled = current;
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let next = fingerprint(root, inputs);
+            if next == settled {
+                return settled;
+            }
+            settled = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_fingerprint_tracks_files() {
+        let root = tempfile::tempdir().unwrap();
+        let src = root.path().join("packages/app-frontend/src");
+        fs::create_dir_all(src.join("node_modules")).unwrap();
+        fs::write(src.join("App.tsx"), "export {}\n").unwrap();
+        let inputs: Vec<String> = WATCHED.iter().map(|dir| dir.to_string()).collect();
+
+        let initial = fingerprint(root.path(), &inputs);
+        assert_eq!(fingerprint(root.path(), &inputs), initial);
+
+        // Ignored directories do not count
+        fs::write(src.join("node_modules/dep.js"), "x").unwrap();
+        assert_eq!(fingerprint(root.path(), &inputs), initial);
+
+        fs::write(src.join("App.tsx"), "export const a = 1\n").unwrap();
+        let edited = fingerprint(root.path(), &inputs);
+        assert_ne!(edited, initial);
+
+        fs::write(src.join("Page.tsx"), "").unwrap();
+        assert_ne!(fingerprint(root.path(), &inputs), edited);
+    }
+}
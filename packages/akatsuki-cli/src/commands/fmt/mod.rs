@@ -3,7 +3,11 @@ use colored::Colorize;
 use std::process::Command;
 
 use crate::cli::FmtTarget;
-use crate::utils::find_project_root;
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+use crate::utils::{
+    cargo_package_command, find_project_root, CargoWorkspace, ADMIN_CLI_PACKAGE, BACKEND_PACKAGE,
+};
 
 pub struct FmtCommand;
 
@@ -25,9 +29,9 @@ impl FmtCommand {
     fn fmt_frontend(&self) -> Result<()> {
         println!("{}", "🎨 Formatting frontend...".cyan());
 
-        let status = Command::new("npm")
+        let status = platform::command("npm")
             .args(["run", "format", "--workspace=app-frontend"])
-            .status()
+            .timed_status()
             .context("Failed to run npm format for frontend")?;
 
         if !status.success() {
@@ -41,12 +45,8 @@ impl FmtCommand {
     fn fmt_backend(&self) -> Result<()> {
         println!("{}", "🦀 Formatting backend (Rust)...".cyan());
 
-        let project_root = find_project_root();
-        let manifest_path = project_root.join("packages/app-backend/Cargo.toml");
-
-        let status = Command::new("cargo")
-            .args(["fmt", "--manifest-path", manifest_path.to_str().unwrap()])
-            .status()
+        let status = cargo_package_command(&find_project_root(), BACKEND_PACKAGE, "fmt")
+            .timed_status()
             .context("Failed to run cargo fmt for backend")?;
 
         if !status.success() {
@@ -60,9 +60,9 @@ impl FmtCommand {
     fn fmt_cli(&self) -> Result<()> {
         println!("{}", "📟 Formatting CLI (TypeScript)...".cyan());
 
-        let status = Command::new("npm")
+        let status = platform::command("npm")
             .args(["run", "format", "--workspace=app-cli"])
-            .status()
+            .timed_status()
             .context("Failed to run npm format for CLI")?;
 
         if !status.success() {
@@ -76,12 +76,8 @@ impl FmtCommand {
     fn fmt_admin_cli(&self) -> Result<()> {
         println!("{}", "🦀 Formatting admin-cli (Rust)...".cyan());
 
-        let project_root = find_project_root();
-        let manifest_path = project_root.join("packages/akatsuki-cli/Cargo.toml");
-
-        let status = Command::new("cargo")
-            .args(["fmt", "--manifest-path", manifest_path.to_str().unwrap()])
-            .status()
+        let status = cargo_package_command(&find_project_root(), ADMIN_CLI_PACKAGE, "fmt")
+            .timed_status()
             .context("Failed to run cargo fmt for admin-cli")?;
 
         if !status.success() {
@@ -101,13 +97,47 @@ impl FmtCommand {
         self.fmt_cli()?;
         println!();
 
-        self.fmt_backend()?;
-        println!();
+        match CargoWorkspace::detect(&find_project_root()) {
+            Some(workspace) => self.fmt_workspace(&workspace)?,
+            None => {
+                self.fmt_backend()?;
+                println!();
 
-        self.fmt_admin_cli()?;
+                self.fmt_admin_cli()?;
+            }
+        }
         println!();
 
         println!("{}", "✨ All formatted!".green().bold());
         Ok(())
     }
+
+    /// One `cargo fmt --all` for the workspace, plus non-member packages
+    fn fmt_workspace(&self, workspace: &CargoWorkspace) -> Result<()> {
+        println!(
+            "{}",
+            "🦀 Formatting cargo workspace (cargo fmt --all)...".cyan()
+        );
+
+        let status = Command::new("cargo")
+            .args(["fmt", "--all"])
+            .current_dir(&workspace.root)
+            .timed_status()
+            .context("Failed to run cargo fmt for workspace")?;
+
+        if !status.success() {
+            anyhow::bail!("Workspace format failed");
+        }
+        println!("{}", "✅ Workspace formatted!".green());
+
+        if !workspace.contains(BACKEND_PACKAGE) {
+            println!();
+            self.fmt_backend()?;
+        }
+        if !workspace.contains(ADMIN_CLI_PACKAGE) {
+            println!();
+            self.fmt_admin_cli()?;
+        }
+        Ok(())
+    }
 }
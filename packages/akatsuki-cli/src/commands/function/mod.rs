@@ -1,8 +1,11 @@
+mod schedule;
+
 use anyhow::{Context, Result};
 use colored::Colorize;
-use std::process::Command;
 
 use crate::cli::FunctionAction;
+use crate::utils::output::OutputFormatter;
+use crate::utils::{find_project_root, platform};
 
 pub struct FunctionCommand;
 
@@ -11,10 +14,28 @@ impl FunctionCommand {
         Self
     }
 
-    pub fn execute(&self, action: FunctionAction) -> Result<()> {
+    pub fn execute(&self, action: FunctionAction, output: OutputFormatter) -> Result<()> {
         match action {
             FunctionAction::New { name } => self.create_function(&name),
             FunctionAction::Deploy { name } => self.deploy(name.as_deref()),
+            FunctionAction::Schedule {
+                name,
+                cron,
+                job,
+                body,
+                dry_run,
+            } => schedule::schedule(
+                &find_project_root(),
+                &name,
+                &cron,
+                job.as_deref(),
+                &body,
+                dry_run,
+            ),
+            FunctionAction::Schedules => schedule::list(&find_project_root(), output),
+            FunctionAction::Unschedule { job, dry_run } => {
+                schedule::unschedule(&find_project_root(), &job, dry_run)
+            }
         }
     }
 
@@ -24,7 +45,7 @@ impl FunctionCommand {
             format!("⚡ Creating new edge function: {}", name).cyan()
         );
 
-        let status = Command::new("supabase")
+        let status = platform::command("supabase")
             .args(["functions", "new", name])
             .status()
             .context("Failed to create function. Make sure Supabase CLI is installed.")?;
@@ -45,7 +66,7 @@ impl FunctionCommand {
                     format!("🚀 Deploying edge function: {}", func_name).cyan()
                 );
 
-                let status = Command::new("supabase")
+                let status = platform::command("supabase")
                     .args(["functions", "deploy", func_name, "--use-api"])
                     .status()
                     .context("Failed to deploy function. Make sure Supabase CLI is installed.")?;
@@ -62,7 +83,7 @@ impl FunctionCommand {
             None => {
                 println!("{}", "🚀 Deploying all edge functions...".cyan());
 
-                let status = Command::new("supabase")
+                let status = platform::command("supabase")
                     .args(["functions", "deploy", "--use-api"])
                     .status()
                     .context("Failed to deploy functions. Make sure Supabase CLI is installed.")?;
@@ -0,0 +1,459 @@
+/**
+ * Function Schedules
+ * pg_cron jobs that invoke Edge Functions through pg_net, kept as migrations
+ *
+ * - schedule: writes a migration that (re)creates the job
+ * - schedules: jobs left after replaying cron.schedule / cron.unschedule
+ *   in the migrations, in order
+ * - unschedule: writes a migration that removes the job
+ *
+ * The jobs call `app.settings.supabase_url` with the service role key,
+ * like 20251102110035_add_event_processing_cron.sql.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use regex::Regex;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::db::ddl::MIGRATIONS_DIR;
+use crate::utils::output::OutputFormatter;
+
+/// Allowed numbers per cron field: minute, hour, day of month, month, day of week
+const CRON_RANGES: [(&str, u32, u32); 5] = [
+    ("minute", 0, 59),
+    ("hour", 0, 23),
+    ("day of month", 1, 31),
+    ("month", 1, 12),
+    ("day of week", 0, 7),
+];
+
+/// A job as the migrations leave it
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Schedule {
+    pub job: String,
+    pub cron: String,
+    /// Edge Function the job posts to (None for jobs running other SQL)
+    pub function: Option<String>,
+    /// Migration that scheduled it
+    pub migration: String,
+}
+
+pub fn schedule(
+    root: &Path,
+    function: &str,
+    cron: &str,
+    job: Option<&str>,
+    body: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let job = job.unwrap_or(function);
+    check_name("function", function)?;
+    check_name("job", job)?;
+    let cron = cron.split_whitespace().collect::<Vec<_>>().join(" ");
+    check_cron(&cron)?;
+    let body = request_body(body)?;
+
+    println!(
+        "⏰ Schedule {} ({})",
+        function.bright_white(),
+        cron.bright_white()
+    );
+    if !root.join("supabase/functions").join(function).is_dir() {
+        println!(
+            "{}",
+            format!(
+                "⚠️  supabase/functions/{} not found: deploy it before the job runs",
+                function
+            )
+            .yellow()
+        );
+    }
+    if let Some(existing) = schedules(root)?.into_iter().find(|s| s.job == job) {
+        println!(
+            "{}",
+            format!(
+                "↻ Replaces job '{}' ({}) from {}",
+                job, existing.cron, existing.migration
+            )
+            .yellow()
+        );
+    }
+
+    let sql = render_schedule(function, &cron, job, &body);
+    write_migration(root, &format!("schedule_{}", job), &sql, dry_run)?;
+    if !dry_run {
+        println!("\n{}", "🚀 Next steps:".bright_cyan());
+        println!(
+            "  1. Apply the migration: {}",
+            "akatsuki db push".bright_white()
+        );
+        println!(
+            "  2. Deploy the function: {}",
+            format!("akatsuki function deploy {}", function).bright_white()
+        );
+        println!("  Cron times are UTC. The job needs app.settings.supabase_url / service_role_key set on the database.");
+    }
+    Ok(())
+}
+
+pub fn unschedule(root: &Path, job: &str, dry_run: bool) -> Result<()> {
+    check_name("job", job)?;
+    if !schedules(root)?.iter().any(|s| s.job == job) {
+        anyhow::bail!(
+            "No schedule named '{}' in {} (see akatsuki function schedules)",
+            job,
+            MIGRATIONS_DIR
+        );
+    }
+
+    println!("🗑️  Unschedule {}", job.bright_white());
+    let sql = format!(
+        "-- Remove the {job} cron job\n-- Generated by `akatsuki function unschedule`\n\n{}",
+        unschedule_sql(job)
+    );
+    write_migration(root, &format!("unschedule_{}", job), &sql, dry_run)?;
+    if !dry_run {
+        println!(
+            "\n  Apply the migration: {}",
+            "akatsuki db push".bright_white()
+        );
+    }
+    Ok(())
+}
+
+pub fn list(root: &Path, output: OutputFormatter) -> Result<()> {
+    let schedules = schedules(root)?;
+    output.emit(&schedules, |schedules| {
+        println!("⏰ Scheduled jobs (from {})", MIGRATIONS_DIR);
+        println!("{}", "─".repeat(50).bright_black());
+        if schedules.is_empty() {
+            println!(
+                "No schedules yet: {}",
+                "akatsuki function schedule <name> --cron \"0 3 * * *\"".bright_white()
+            );
+            return;
+        }
+        let width = schedules.iter().map(|s| s.job.len()).max().unwrap_or(0);
+        let cron_width = schedules.iter().map(|s| s.cron.len()).max().unwrap_or(0);
+        for schedule in schedules {
+            println!(
+                "  {}  {:cron_width$}  → {}  {}",
+                format!("{:width$}", schedule.job).bright_white(),
+                schedule.cron,
+                schedule.function.as_deref().unwrap_or("(SQL)"),
+                format!("({})", schedule.migration).bright_black(),
+            );
+        }
+    })
+}
+
+/// Jobs left after replaying the migrations in order
+pub fn schedules(root: &Path) -> Result<Vec<Schedule>> {
+    let dir = root.join(MIGRATIONS_DIR);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "sql"))
+        .collect();
+    files.sort();
+
+    let mut schedules: Vec<Schedule> = Vec::new();
+    for file in files {
+        let sql = fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        let migration = file
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        apply(&mut schedules, &sql, &migration);
+    }
+    Ok(schedules)
+}
+
+/// cron.schedule / cron.unschedule calls of one migration, in order
+fn apply(schedules: &mut Vec<Schedule>, sql: &str, migration: &str) {
+    let sql: String = sql
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("--"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let scheduled = Regex::new(
+        r"(?is)cron\.schedule\(\s*'([^']+)'\s*,\s*'([^']+)'\s*,\s*(\$\$.*?\$\$|'(?:[^']|'')*')",
+    )
+    .unwrap();
+    let unscheduled = Regex::new(r"(?i)cron\.unschedule\(\s*'([^']+)'\s*\)").unwrap();
+    let function = Regex::new(r"/functions/v1/([A-Za-z0-9_-]+)").unwrap();
+
+    let mut calls: Vec<(usize, Option<Schedule>, String)> = scheduled
+        .captures_iter(&sql)
+        .map(|caps| {
+            let job = caps[1].to_string();
+            let schedule = Schedule {
+                job: job.clone(),
+                cron: caps[2].to_string(),
+                function: function.captures(&caps[3]).map(|f| f[1].to_string()),
+                migration: migration.to_string(),
+            };
+            (caps.get(0).unwrap().start(), Some(schedule), job)
+        })
+        .collect();
+    calls.extend(
+        unscheduled
+            .captures_iter(&sql)
+            .map(|caps| (caps.get(0).unwrap().start(), None, caps[1].to_string())),
+    );
+    calls.sort_by_key(|(start, _, _)| *start);
+
+    for (_, schedule, job) in calls {
+        schedules.retain(|s| s.job != job);
+        if let Some(schedule) = schedule {
+            schedules.push(schedule);
+        }
+    }
+}
+
+fn render_schedule(function: &str, cron: &str, job: &str, body: &str) -> String {
+    format!(
+        r#"-- Invoke the {function} Edge Function on "{cron}" (UTC)
+-- Generated by `akatsuki function schedule`
+-- Requires app.settings.supabase_url / app.settings.service_role_key
+-- (see 20251102110035_add_event_processing_cron.sql)
+
+CREATE EXTENSION IF NOT EXISTS pg_cron;
+CREATE EXTENSION IF NOT EXISTS pg_net;
+
+-- Replace the job if it already exists
+{unschedule}
+SELECT cron.schedule(
+  '{job}',
+  '{cron}',
+  $$
+  SELECT
+    net.http_post(
+      url := current_setting('app.settings.supabase_url', true) || '/functions/v1/{function}',
+      headers := jsonb_build_object(
+        'Content-Type', 'application/json',
+        'Authorization', 'Bearer ' || current_setting('app.settings.service_role_key', true)
+      ),
+      body := '{body}'::jsonb
+    ) AS request_id;
+  $$
+);
+"#,
+        unschedule = unschedule_sql(job),
+        body = body.replace('\'', "''"),
+    )
+}
+
+/// No-op when the job does not exist
+fn unschedule_sql(job: &str) -> String {
+    format!(
+        "SELECT cron.unschedule('{job}') WHERE EXISTS (\n  SELECT 1 FROM cron.job WHERE jobname = '{job}'\n);\n"
+    )
+}
+
+fn write_migration(root: &Path, name: &str, sql: &str, dry_run: bool) -> Result<()> {
+    let path = root.join(MIGRATIONS_DIR).join(format!(
+        "{}_{}.sql",
+        chrono::Local::now().format("%Y%m%d%H%M%S"),
+        name.replace('-', "_")
+    ));
+    let relative = path
+        .strip_prefix(root)
+        .unwrap_or(&path)
+        .display()
+        .to_string();
+    if dry_run {
+        println!(
+            "\n{}\n",
+            format!("🔍 Dry run - {} would contain:", relative).bright_cyan()
+        );
+        print!("{}", sql);
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(&path, sql).with_context(|| format!("Failed to write {}", path.display()))?;
+    println!("{}", format!("✅ Created {}", relative).green());
+    Ok(())
+}
+
+/// Names end up in SQL literals and the function URL
+fn check_name(kind: &str, name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        anyhow::bail!(
+            "Invalid {} name '{}': use letters, digits, '-' or '_'",
+            kind,
+            name
+        );
+    }
+    Ok(())
+}
+
+/// Five-field cron expressions (numbers, `*`, `,`, `-`, `/`, month and day
+/// names) or pg_cron's "N seconds"
+fn check_cron(cron: &str) -> Result<()> {
+    let seconds = Regex::new(r"^(\d+) seconds?$").unwrap();
+    if let Some(caps) = seconds.captures(cron) {
+        let n: u32 = caps[1].parse().unwrap_or(0);
+        if (1..=59).contains(&n) {
+            return Ok(());
+        }
+        anyhow::bail!("Invalid interval '{}': use 1 to 59 seconds", cron);
+    }
+
+    let fields: Vec<&str> = cron.split(' ').collect();
+    if fields.len() != CRON_RANGES.len() {
+        anyhow::bail!(
+            "Invalid cron expression '{}': expected 5 fields (minute hour day-of-month month day-of-week), e.g. \"0 3 * * *\"",
+            cron
+        );
+    }
+    let field_syntax = Regex::new(r"^[0-9A-Za-z*,/-]+$").unwrap();
+    let number = Regex::new(r"\d+").unwrap();
+    for (field, (name, min, max)) in fields.iter().zip(CRON_RANGES) {
+        if !field_syntax.is_match(field) {
+            anyhow::bail!("Invalid {} field '{}' in '{}'", name, field, cron);
+        }
+        let numbers = field.split('/').next().unwrap_or_default();
+        for value in number.find_iter(numbers) {
+            let value: u32 = value.as_str().parse().unwrap_or(u32::MAX);
+            if value < min || value > max {
+                anyhow::bail!(
+                    "Invalid {} field '{}' in '{}': {} is outside {}-{}",
+                    name,
+                    field,
+                    cron,
+                    value,
+                    min,
+                    max
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Compact JSON for the request body; `$$` would end the job's command
+fn request_body(body: &str) -> Result<String> {
+    let value: serde_json::Value = serde_json::from_str(body)
+        .with_context(|| format!("--body is not valid JSON: {}", body))?;
+    let body = serde_json::to_string(&value)?;
+    if body.contains("$$") {
+        anyhow::bail!("--body must not contain '$$'");
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_cron() {
+        for valid in [
+            "0 3 * * *",
+            "*/15 * * * *",
+            "0 9-17 * * MON-FRI",
+            "30 2 1,15 * 0",
+            "30 seconds",
+        ] {
+            assert!(check_cron(valid).is_ok(), "{}", valid);
+        }
+        for invalid in [
+            "0 3 * *",
+            "60 * * * *",
+            "0 24 * * *",
+            "0 0 0 * *",
+            "0 3 * * *; DROP",
+            "90 seconds",
+        ] {
+            assert!(check_cron(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_schedules_from_migrations() {
+        let mut schedules = Vec::new();
+        apply(
+            &mut schedules,
+            "-- SELECT cron.schedule('commented', '* * * * *', $$ SELECT 1 $$);\n\
+             SELECT cron.unschedule('process-system-events') WHERE EXISTS (\n  SELECT 1 FROM cron.job WHERE jobname = 'process-system-events'\n);\n\
+             SELECT cron.schedule(\n  'process-system-events',\n  '* * * * *',\n  $$\n  SELECT net.http_post(url := current_setting('app.settings.supabase_url', true) || '/functions/v1/process-events') AS request_id;\n  $$\n);\n\
+             SELECT cron.schedule('vacuum-logs', '0 4 * * 0', 'DELETE FROM logs');",
+            "20251102110035_add_event_processing_cron.sql",
+        );
+        let sql = render_schedule(
+            "cleanup",
+            "0 3 * * *",
+            "nightly-cleanup",
+            r#"{"note":"it's"}"#,
+        );
+        assert!(sql.contains("body := '{\"note\":\"it''s\"}'::jsonb"));
+        apply(
+            &mut schedules,
+            &sql,
+            "20260101000000_schedule_nightly_cleanup.sql",
+        );
+        assert_eq!(
+            schedules,
+            [
+                Schedule {
+                    job: "process-system-events".to_string(),
+                    cron: "* * * * *".to_string(),
+                    function: Some("process-events".to_string()),
+                    migration: "20251102110035_add_event_processing_cron.sql".to_string(),
+                },
+                Schedule {
+                    job: "vacuum-logs".to_string(),
+                    cron: "0 4 * * 0".to_string(),
+                    function: None,
+                    migration: "20251102110035_add_event_processing_cron.sql".to_string(),
+                },
+                Schedule {
+                    job: "nightly-cleanup".to_string(),
+                    cron: "0 3 * * *".to_string(),
+                    function: Some("cleanup".to_string()),
+                    migration: "20260101000000_schedule_nightly_cleanup.sql".to_string(),
+                },
+            ]
+        );
+
+        // Rescheduling replaces the job, unscheduling removes it
+        let sql = render_schedule("cleanup", "0 5 * * *", "nightly-cleanup", "{}");
+        apply(
+            &mut schedules,
+            &sql,
+            "20260102000000_schedule_nightly_cleanup.sql",
+        );
+        assert_eq!(schedules.len(), 3);
+        assert_eq!(schedules[2].cron, "0 5 * * *");
+        apply(
+            &mut schedules,
+            &unschedule_sql("vacuum-logs"),
+            "20260103000000_unschedule_vacuum_logs.sql",
+        );
+        let jobs: Vec<&str> = schedules.iter().map(|s| s.job.as_str()).collect();
+        assert_eq!(jobs, ["process-system-events", "nightly-cleanup"]);
+    }
+
+    #[test]
+    fn test_request_body() {
+        assert_eq!(request_body("{ \"a\": 1 }").unwrap(), "{\"a\":1}");
+        assert!(request_body("{a: 1}").is_err());
+        assert!(request_body("{\"a\":\"$$\"}").is_err());
+    }
+}
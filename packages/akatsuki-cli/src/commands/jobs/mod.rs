@@ -0,0 +1,186 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::JobsAction;
+use crate::utils::backend::BackendClient;
+use crate::utils::output::OutputFormatter;
+
+pub struct JobsCommand {
+    client: BackendClient,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Job {
+    id: String,
+    kind: String,
+    status: String,
+    progress: u8,
+    attempts: u32,
+    error: Option<String>,
+    created_at: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct JobList {
+    items: Vec<Job>,
+    page: Option<u32>,
+    total: Option<i64>,
+    has_more: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct PurgeResult {
+    purged: usize,
+}
+
+impl JobsCommand {
+    pub fn new(url: &str, token: Option<String>) -> Result<Self> {
+        if token.is_none() {
+            anyhow::bail!(
+                "Admin token required. Set AKATSUKI_ADMIN_TOKEN or pass --token \
+                 (JWT with app_metadata.role = admin, or the service role key)"
+            );
+        }
+
+        Ok(Self {
+            client: BackendClient::new(url)?.with_token(token),
+        })
+    }
+
+    pub fn execute(&self, action: JobsAction, output: OutputFormatter) -> Result<()> {
+        match action {
+            JobsAction::List {
+                status,
+                kind,
+                sort,
+                page,
+                limit,
+            } => self.list(
+                status.as_deref(),
+                kind.as_deref(),
+                sort.as_deref(),
+                page,
+                limit,
+                output,
+            ),
+            JobsAction::Retry { id } => self.retry(&id),
+            JobsAction::Cancel { id } => self.cancel(&id),
+            JobsAction::Purge {
+                older_than_hours,
+                status,
+            } => self.purge(older_than_hours, status.as_deref()),
+        }
+    }
+
+    fn list(
+        &self,
+        status: Option<&str>,
+        kind: Option<&str>,
+        sort: Option<&str>,
+        page: u32,
+        limit: u32,
+        output: OutputFormatter,
+    ) -> Result<()> {
+        let mut query = vec![format!("page={}", page), format!("limit={}", limit)];
+        if let Some(status) = status {
+            query.push(format!("status={}", status));
+        }
+        if let Some(kind) = kind {
+            query.push(format!("kind={}", kind));
+        }
+        if let Some(sort) = sort {
+            query.push(format!("sort={}", sort));
+        }
+
+        let list: JobList = self
+            .client
+            .get_json(&format!("/api/admin/jobs?{}", query.join("&")))?;
+
+        output.emit(&list, |list| {
+            if list.items.is_empty() {
+                println!("{}", "No jobs found".yellow());
+                return;
+            }
+
+            println!(
+                "\n{:<36}  {:<18}  {:<10}  {:>4}  {:>3}  CREATED",
+                "ID", "KIND", "STATUS", "%", "TRY"
+            );
+            for job in &list.items {
+                println!(
+                    "{:<36}  {:<18}  {:<10}  {:>4}  {:>3}  {}",
+                    job.id,
+                    job.kind,
+                    Self::colored_status(&job.status),
+                    job.progress,
+                    job.attempts,
+                    job.created_at
+                );
+                if let Some(error) = &job.error {
+                    println!("  {} {}", "↳".red(), error.dimmed());
+                }
+            }
+            println!(
+                "\nPage {} ({} of {} jobs)",
+                list.page.unwrap_or(page),
+                list.items.len(),
+                list.total.unwrap_or(list.items.len() as i64)
+            );
+            if list.has_more {
+                println!(
+                    "{}",
+                    format!("💡 Next page: akatsuki jobs list --page {}", page + 1).dimmed()
+                );
+            }
+        })
+    }
+
+    fn retry(&self, id: &str) -> Result<()> {
+        let job: Job = self
+            .client
+            .post_json(&format!("/api/admin/jobs/{}/retry", id), &())?;
+        println!(
+            "{}",
+            format!("🔁 Job {} restarted (attempt {})", job.id, job.attempts + 1).green()
+        );
+        Ok(())
+    }
+
+    fn cancel(&self, id: &str) -> Result<()> {
+        let job: Job = self
+            .client
+            .post_json(&format!("/api/admin/jobs/{}/cancel", id), &())?;
+        println!("{}", format!("🛑 Job {} cancelled", job.id).green());
+        Ok(())
+    }
+
+    fn purge(&self, older_than_hours: u32, status: Option<&str>) -> Result<()> {
+        let mut path = format!("/api/admin/jobs?older_than_hours={}", older_than_hours);
+        if let Some(status) = status {
+            path.push_str(&format!("&status={}", status));
+        }
+
+        let result: PurgeResult = self.client.delete_json(&path)?;
+        println!(
+            "{}",
+            format!(
+                "🧹 Purged {} job(s) older than {}h",
+                result.purged, older_than_hours
+            )
+            .green()
+        );
+        Ok(())
+    }
+
+    fn colored_status(status: &str) -> String {
+        let padded = format!("{:<10}", status);
+        match status {
+            "completed" => padded.green().to_string(),
+            "failed" => padded.red().to_string(),
+            "cancelled" => padded.dimmed().to_string(),
+            "generating" => padded.cyan().to_string(),
+            _ => padded.yellow().to_string(),
+        }
+    }
+}
@@ -4,7 +4,11 @@ use std::process::Command;
 
 use crate::cli::LintTarget;
 use crate::commands::check::navigation;
-use crate::utils::find_project_root;
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+use crate::utils::{
+    cargo_package_command, find_project_root, CargoWorkspace, ADMIN_CLI_PACKAGE, BACKEND_PACKAGE,
+};
 
 pub struct LintCommand;
 
@@ -35,9 +39,9 @@ impl LintCommand {
             args.push("--fix");
         }
 
-        let status = Command::new("npm")
+        let status = platform::command("npm")
             .args(&args)
-            .status()
+            .timed_status()
             .context("Failed to run eslint")?;
 
         if !status.success() {
@@ -57,12 +61,7 @@ impl LintCommand {
     fn lint_backend(&self, fix: bool) -> Result<()> {
         println!("{}", "🦀 Linting backend (cargo clippy)...".cyan());
 
-        let project_root = find_project_root();
-        let mut args = vec![
-            "clippy",
-            "--all-targets",
-            "--all-features",
-        ];
+        let mut args = vec!["--all-targets", "--all-features"];
 
         if fix {
             args.extend(["--fix", "--allow-dirty", "--allow-staged"]);
@@ -70,10 +69,9 @@ impl LintCommand {
 
         args.extend(["--", "-D", "warnings"]);
 
-        let status = Command::new("cargo")
+        let status = cargo_package_command(&find_project_root(), BACKEND_PACKAGE, "clippy")
             .args(&args)
-            .current_dir(project_root.join("packages/app-backend"))
-            .status()
+            .timed_status()
             .context("Failed to run cargo clippy")?;
 
         if !status.success() {
@@ -93,9 +91,9 @@ impl LintCommand {
             args.push("--fix");
         }
 
-        let status = Command::new("npm")
+        let status = platform::command("npm")
             .args(&args)
-            .status()
+            .timed_status()
             .context("Failed to run eslint")?;
 
         if !status.success() {
@@ -109,11 +107,7 @@ impl LintCommand {
     fn lint_admin_cli(&self, fix: bool) -> Result<()> {
         println!("{}", "🦀 Linting admin-cli (cargo clippy)...".cyan());
 
-        let project_root = find_project_root();
-        let mut args = vec![
-            "clippy",
-            "--all-targets",
-        ];
+        let mut args = vec!["--all-targets"];
 
         if fix {
             args.extend(["--fix", "--allow-dirty", "--allow-staged"]);
@@ -121,10 +115,9 @@ impl LintCommand {
 
         args.extend(["--", "-D", "warnings"]);
 
-        let status = Command::new("cargo")
+        let status = cargo_package_command(&find_project_root(), ADMIN_CLI_PACKAGE, "clippy")
             .args(&args)
-            .current_dir(project_root.join("packages/akatsuki-cli"))
-            .status()
+            .timed_status()
             .context("Failed to run cargo clippy")?;
 
         if !status.success() {
@@ -138,9 +131,12 @@ impl LintCommand {
     fn lint_all(&self, fix: bool) -> Result<()> {
         println!(
             "{}",
-            format!("🔍 Running all lints{}...", if fix { " (with --fix)" } else { "" })
-                .cyan()
-                .bold()
+            format!(
+                "🔍 Running all lints{}...",
+                if fix { " (with --fix)" } else { "" }
+            )
+            .cyan()
+            .bold()
         );
 
         self.lint_frontend(fix)?;
@@ -149,13 +145,55 @@ impl LintCommand {
         self.lint_cli(fix)?;
         println!();
 
-        self.lint_backend(fix)?;
-        println!();
+        match CargoWorkspace::detect(&find_project_root()) {
+            Some(workspace) => self.lint_workspace(&workspace, fix)?,
+            None => {
+                self.lint_backend(fix)?;
+                println!();
 
-        self.lint_admin_cli(fix)?;
+                self.lint_admin_cli(fix)?;
+            }
+        }
         println!();
 
         println!("{}", "✨ All lints passed!".green().bold());
         Ok(())
     }
+
+    /// One `cargo clippy --workspace` (shared target dir), plus non-member packages
+    fn lint_workspace(&self, workspace: &CargoWorkspace, fix: bool) -> Result<()> {
+        println!(
+            "{}",
+            "🦀 Linting cargo workspace (cargo clippy --workspace)...".cyan()
+        );
+
+        let mut args = vec!["clippy", "--workspace", "--all-targets", "--all-features"];
+
+        if fix {
+            args.extend(["--fix", "--allow-dirty", "--allow-staged"]);
+        }
+
+        args.extend(["--", "-D", "warnings"]);
+
+        let status = Command::new("cargo")
+            .args(&args)
+            .current_dir(&workspace.root)
+            .timed_status()
+            .context("Failed to run cargo clippy")?;
+
+        if !status.success() {
+            anyhow::bail!("Workspace clippy failed");
+        }
+        println!("{}", "✅ Workspace lint passed!".green());
+
+        if !workspace.contains(BACKEND_PACKAGE) {
+            println!();
+            self.lint_backend(fix)?;
+        }
+        if !workspace.contains(ADMIN_CLI_PACKAGE) {
+            println!();
+            self.lint_admin_cli(fix)?;
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,638 @@
+/**
+ * Mock Command
+ * Local fixtures server for the backend API: canned responses from the `mocks/` directory,
+ * everything else synthesized from the OpenAPI contract
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::utils::find_project_root;
+
+/// Backend OpenAPI contract (relative to the project root)
+pub const DEFAULT_SPEC: &str = "packages/app-backend/openapi.yaml";
+/// Fixture directory (relative to the project root)
+pub const DEFAULT_MOCKS_DIR: &str = "mocks";
+
+const HTTP_METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+/// Nested `$ref`s deeper than this are rendered as `null` (recursive schemas)
+const MAX_SCHEMA_DEPTH: usize = 8;
+/// Request bodies are read and discarded; anything larger is cut off
+const MAX_REQUEST_BYTES: usize = 10 * 1024 * 1024;
+
+/// One canned response. A `mocks/*.json` file holds a single fixture or an array.
+/// `path` may contain `{param}` segments; `{{param}}` in string values of `body`
+/// is replaced with the matched segment.
+#[derive(Debug, Clone, Deserialize)]
+struct Fixture {
+    #[serde(default = "default_method")]
+    method: String,
+    path: String,
+    #[serde(default = "default_status")]
+    status: u16,
+    /// Overrides `--latency` for this fixture
+    latency_ms: Option<u64>,
+    #[serde(default)]
+    body: Value,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    /// File the fixture was loaded from (for request logs)
+    #[serde(skip)]
+    source: String,
+}
+
+fn default_method() -> String {
+    "GET".to_string()
+}
+
+fn default_status() -> u16 {
+    200
+}
+
+/// Success response of one OpenAPI operation
+#[derive(Debug)]
+struct Operation {
+    method: String,
+    path: String,
+    status: u16,
+    body: Option<Value>,
+}
+
+#[derive(Debug, PartialEq)]
+struct MockResponse {
+    status: u16,
+    body: Option<Value>,
+    headers: Vec<(String, String)>,
+    latency: Duration,
+    /// Where the response came from: a fixture file, `openapi`, or `-`
+    source: String,
+}
+
+struct MockServer {
+    fixtures: Vec<Fixture>,
+    operations: Vec<Operation>,
+    latency: Duration,
+}
+
+pub struct MockCommand;
+
+impl MockCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(
+        &self,
+        port: u16,
+        dir: Option<PathBuf>,
+        spec: Option<PathBuf>,
+        latency: u64,
+    ) -> Result<()> {
+        let root = find_project_root();
+        let dir = dir.unwrap_or_else(|| root.join(DEFAULT_MOCKS_DIR));
+        let spec = spec.unwrap_or_else(|| root.join(DEFAULT_SPEC));
+
+        println!("{}", "🎭 Mock API Server".bright_cyan().bold());
+        println!("{}", "─".repeat(50).bright_black());
+
+        let fixtures = if dir.is_dir() {
+            load_fixtures(&dir)?
+        } else {
+            println!(
+                "{}",
+                format!(
+                    "No fixture directory at {} (serving OpenAPI examples only)",
+                    dir.display()
+                )
+                .yellow()
+            );
+            Vec::new()
+        };
+        let spec_source = std::fs::read_to_string(&spec)
+            .with_context(|| format!("Failed to read OpenAPI spec {}", spec.display()))?;
+        let operations = parse_operations(&spec_source)
+            .with_context(|| format!("Invalid OpenAPI spec {}", spec.display()))?;
+
+        println!(
+            "  Spec:      {} ({} operations)",
+            spec.display(),
+            operations.len()
+        );
+        println!("  Fixtures:  {} ({})", dir.display(), fixtures.len());
+        println!("  Latency:   {}ms", latency);
+
+        let server = Arc::new(MockServer {
+            fixtures,
+            operations,
+            latency: Duration::from_millis(latency),
+        });
+        let listener = TcpListener::bind(("127.0.0.1", port))
+            .with_context(|| format!("Failed to bind 127.0.0.1:{}", port))?;
+
+        println!(
+            "\n{} {}",
+            "✅ Listening on".green(),
+            format!("http://localhost:{}", port).bright_white().bold()
+        );
+        println!(
+            "{}",
+            "💡 Point the frontend at it with VITE_API_BASE_URL, Ctrl+C to stop\n".bright_black()
+        );
+
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            let server = Arc::clone(&server);
+            std::thread::spawn(move || {
+                if let Err(e) = server.handle(stream) {
+                    eprintln!("{} {}", "⚠️".yellow(), e);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+impl MockServer {
+    fn handle(&self, stream: TcpStream) -> Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+            return Ok(());
+        };
+        let method = method.to_uppercase();
+        let path = target.split('?').next().unwrap_or(target).to_string();
+
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+        let mut body = vec![0; content_length.min(MAX_REQUEST_BYTES)];
+        reader.read_exact(&mut body)?;
+
+        // CORS preflight from the Vite dev server
+        let response = if method == "OPTIONS" {
+            MockResponse {
+                status: 204,
+                body: None,
+                headers: Vec::new(),
+                latency: Duration::ZERO,
+                source: "-".to_string(),
+            }
+        } else {
+            self.respond(&method, &path)
+        };
+
+        std::thread::sleep(response.latency);
+        write_response(stream, &response)?;
+
+        let status = match response.status {
+            200..=299 => response.status.to_string().green(),
+            400..=499 => response.status.to_string().yellow(),
+            _ => response.status.to_string().red(),
+        };
+        println!(
+            "{:<6} {} → {} {}",
+            method,
+            path,
+            status,
+            format!("({})", response.source).bright_black()
+        );
+        Ok(())
+    }
+
+    /// Fixture with the fewest `{param}` segments wins, then the OpenAPI example
+    fn respond(&self, method: &str, path: &str) -> MockResponse {
+        let fixture = self
+            .fixtures
+            .iter()
+            .filter(|f| f.method.eq_ignore_ascii_case(method))
+            .filter_map(|f| match_path(&f.path, path).map(|params| (f, params)))
+            .min_by_key(|(_, params)| params.len());
+        if let Some((fixture, params)) = fixture {
+            return MockResponse {
+                status: fixture.status,
+                body: Some(substitute(&fixture.body, &params)),
+                headers: fixture
+                    .headers
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+                latency: fixture
+                    .latency_ms
+                    .map(Duration::from_millis)
+                    .unwrap_or(self.latency),
+                source: fixture.source.clone(),
+            };
+        }
+
+        let operation = self
+            .operations
+            .iter()
+            .filter(|op| op.method.eq_ignore_ascii_case(method))
+            .filter_map(|op| match_path(&op.path, path).map(|params| (op, params)))
+            .min_by_key(|(_, params)| params.len());
+        match operation {
+            Some((operation, _)) => MockResponse {
+                status: operation.status,
+                body: operation.body.clone(),
+                headers: Vec::new(),
+                latency: self.latency,
+                source: "openapi".to_string(),
+            },
+            None => MockResponse {
+                status: 404,
+                body: Some(json!({ "error": format!("No mock for {} {}", method, path) })),
+                headers: Vec::new(),
+                latency: self.latency,
+                source: "-".to_string(),
+            },
+        }
+    }
+}
+
+fn write_response(mut stream: TcpStream, response: &MockResponse) -> Result<()> {
+    let body = match &response.body {
+        Some(body) => serde_json::to_vec(body)?,
+        None => Vec::new(),
+    };
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Access-Control-Allow-Headers: *\r\n\
+         Access-Control-Allow-Methods: GET, POST, PUT, PATCH, DELETE, OPTIONS\r\n\
+         Connection: close\r\n",
+        response.status,
+        reason(response.status),
+        body.len()
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(&body)?;
+    stream.flush()?;
+    Ok(())
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        409 => "Conflict",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Mock",
+    }
+}
+
+/// Load every `*.json` file in `dir` (file name order)
+fn load_fixtures(dir: &Path) -> Result<Vec<Fixture>> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+
+    let mut fixtures = Vec::new();
+    for file in files {
+        let name = file
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let content = std::fs::read_to_string(&file)?;
+        let value: Value = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid JSON in {}", file.display()))?;
+        let items = match value {
+            Value::Array(items) => items,
+            other => vec![other],
+        };
+        for item in items {
+            let mut fixture: Fixture = serde_json::from_value(item)
+                .with_context(|| format!("Invalid fixture in {}", file.display()))?;
+            fixture.source = name.clone();
+            fixtures.push(fixture);
+        }
+    }
+    Ok(fixtures)
+}
+
+/// Match `/api/items/{id}` (or `:id`) against a request path, returning the parameters
+fn match_path(template: &str, path: &str) -> Option<BTreeMap<String, String>> {
+    let template: Vec<&str> = template.trim_end_matches('/').split('/').collect();
+    let path: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+    if template.len() != path.len() {
+        return None;
+    }
+
+    let mut params = BTreeMap::new();
+    for (expected, actual) in template.iter().zip(&path) {
+        let name = expected
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .or_else(|| expected.strip_prefix(':'));
+        match name {
+            Some(name) if !actual.is_empty() => {
+                params.insert(name.to_string(), actual.to_string());
+            }
+            _ if expected == actual => {}
+            _ => return None,
+        }
+    }
+    Some(params)
+}
+
+/// Replace `{{param}}` in every string value
+fn substitute(value: &Value, params: &BTreeMap<String, String>) -> Value {
+    match value {
+        Value::String(s) => Value::String(params.iter().fold(s.clone(), |s, (name, param)| {
+            s.replace(&format!("{{{{{}}}}}", name), param)
+        })),
+        Value::Array(items) => Value::Array(items.iter().map(|v| substitute(v, params)).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), substitute(v, params)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Lowest 2xx response of every operation, with a body synthesized from its schema
+fn parse_operations(spec: &str) -> Result<Vec<Operation>> {
+    let root: Value = serde_yaml::from_str(spec)?;
+    let Some(paths) = root.get("paths").and_then(Value::as_object) else {
+        anyhow::bail!("No `paths` section");
+    };
+
+    let mut operations = Vec::new();
+    for (path, item) in paths {
+        for method in HTTP_METHODS {
+            let Some(responses) = item
+                .get(method)
+                .and_then(|op| op.get("responses"))
+                .and_then(Value::as_object)
+            else {
+                continue;
+            };
+            let Some((status, response)) = responses
+                .iter()
+                .filter_map(|(code, response)| {
+                    code.parse::<u16>()
+                        .ok()
+                        .filter(|c| (200..300).contains(c))
+                        .map(|c| (c, response))
+                })
+                .min_by_key(|(code, _)| *code)
+            else {
+                continue;
+            };
+
+            let response = resolve(&root, response);
+            let body = response
+                .pointer("/content/application~1json")
+                .map(|content| match content.get("example") {
+                    Some(example) => example.clone(),
+                    None => content
+                        .get("schema")
+                        .map(|schema| example_for(&root, schema, 0))
+                        .unwrap_or(Value::Null),
+                });
+            operations.push(Operation {
+                method: method.to_uppercase(),
+                path: path.clone(),
+                status,
+                body,
+            });
+        }
+    }
+    Ok(operations)
+}
+
+/// Follow a local `$ref` (`#/components/...`)
+fn resolve<'a>(root: &'a Value, value: &'a Value) -> &'a Value {
+    match value.get("$ref").and_then(Value::as_str) {
+        Some(reference) => reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+            .unwrap_or(&Value::Null),
+        None => value,
+    }
+}
+
+/// Deterministic example value for a JSON schema (`example` / `default` / `enum` win)
+fn example_for(root: &Value, schema: &Value, depth: usize) -> Value {
+    if depth > MAX_SCHEMA_DEPTH {
+        return Value::Null;
+    }
+    let schema = resolve(root, schema);
+    for key in ["example", "default"] {
+        if let Some(value) = schema.get(key) {
+            return value.clone();
+        }
+    }
+    if let Some(first) = schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .and_then(|e| e.first())
+    {
+        return first.clone();
+    }
+    if let Some(variants) = ["oneOf", "anyOf"]
+        .iter()
+        .find_map(|key| schema.get(*key).and_then(Value::as_array))
+    {
+        return variants
+            .first()
+            .map(|v| example_for(root, v, depth + 1))
+            .unwrap_or(Value::Null);
+    }
+    if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+        let mut merged = Map::new();
+        for part in parts {
+            if let Value::Object(map) = example_for(root, part, depth + 1) {
+                merged.extend(map);
+            }
+        }
+        return Value::Object(merged);
+    }
+
+    let kind = schema.get("type").and_then(Value::as_str).unwrap_or(
+        if schema.get("properties").is_some() {
+            "object"
+        } else {
+            ""
+        },
+    );
+    match kind {
+        "object" => Value::Object(
+            schema
+                .get("properties")
+                .and_then(Value::as_object)
+                .map(|properties| {
+                    properties
+                        .iter()
+                        .map(|(name, prop)| (name.clone(), example_for(root, prop, depth + 1)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ),
+        "array" => match schema.get("items") {
+            Some(items) => json!([example_for(root, items, depth + 1)]),
+            None => json!([]),
+        },
+        "string" => json!(match schema.get("format").and_then(Value::as_str) {
+            Some("date-time") => "2025-01-01T00:00:00Z",
+            Some("date") => "2025-01-01",
+            Some("uuid") => "00000000-0000-0000-0000-000000000000",
+            Some("uri") | Some("url") => "https://example.com/mock",
+            Some("email") => "user@example.com",
+            _ => "string",
+        }),
+        "integer" => json!(0),
+        "number" => json!(0.0),
+        "boolean" => json!(false),
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"
+openapi: 3.0.3
+paths:
+  /api/items/{id}:
+    get:
+      responses:
+        '404':
+          description: Not found
+        '200':
+          description: Item
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Item'
+    delete:
+      responses:
+        '204':
+          description: Deleted
+components:
+  schemas:
+    Item:
+      type: object
+      properties:
+        id:
+          type: string
+          format: uuid
+        status:
+          type: string
+          enum: [active, archived]
+        tags:
+          type: array
+          items:
+            type: string
+        count:
+          type: integer
+          example: 3
+"#;
+
+    fn fixture(path: &str, body: Value) -> Fixture {
+        Fixture {
+            method: "GET".to_string(),
+            path: path.to_string(),
+            status: 200,
+            latency_ms: None,
+            body,
+            headers: BTreeMap::new(),
+            source: "items.json".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_match_path() {
+        let params = match_path("/api/items/{id}", "/api/items/42").unwrap();
+        assert_eq!(params.get("id").map(String::as_str), Some("42"));
+        assert!(match_path("/api/items/:id", "/api/items/42/").is_some());
+        assert!(match_path("/api/items/{id}", "/api/items").is_none());
+        assert!(match_path("/api/items", "/api/users").is_none());
+    }
+
+    #[test]
+    fn test_openapi_examples() {
+        let operations = parse_operations(SPEC).unwrap();
+        assert_eq!(operations.len(), 2);
+
+        let get = operations.iter().find(|op| op.method == "GET").unwrap();
+        assert_eq!(get.status, 200);
+        assert_eq!(
+            get.body,
+            Some(json!({
+                "id": "00000000-0000-0000-0000-000000000000",
+                "status": "active",
+                "tags": ["string"],
+                "count": 3
+            }))
+        );
+
+        let delete = operations.iter().find(|op| op.method == "DELETE").unwrap();
+        assert_eq!((delete.status, &delete.body), (204, &None));
+    }
+
+    #[test]
+    fn test_fixtures_take_precedence() {
+        let server = MockServer {
+            fixtures: vec![
+                fixture("/api/items/{id}", json!({ "id": "{{id}}" })),
+                fixture("/api/items/special", json!({ "special": true })),
+            ],
+            operations: parse_operations(SPEC).unwrap(),
+            latency: Duration::from_millis(5),
+        };
+
+        let response = server.respond("GET", "/api/items/7");
+        assert_eq!(response.body, Some(json!({ "id": "7" })));
+        assert_eq!(response.source, "items.json");
+        assert_eq!(response.latency, Duration::from_millis(5));
+
+        let response = server.respond("GET", "/api/items/special");
+        assert_eq!(response.body, Some(json!({ "special": true })));
+
+        let response = server.respond("DELETE", "/api/items/7");
+        assert_eq!(
+            (response.status, response.source.as_str()),
+            (204, "openapi")
+        );
+
+        assert_eq!(server.respond("GET", "/api/unknown").status, 404);
+    }
+}
@@ -1,16 +1,36 @@
 pub mod advice;
+pub mod aigen;
 pub mod api;
+pub mod backend;
+pub mod bench;
+pub mod branch;
 pub mod build;
 pub mod check;
 pub mod db;
+pub mod demo;
 pub mod deploy;
+pub mod deps;
 pub mod design;
 pub mod dev;
+pub mod docker;
 pub mod docs;
 pub mod fmt;
 pub mod function;
+pub mod jobs;
 pub mod lint;
+pub mod mock;
+pub mod plugins;
+pub mod pr;
 pub mod preflight;
+pub mod prompts;
 pub mod release;
+pub mod report;
+pub mod run;
+pub mod self_update;
 pub mod setup;
+pub mod stats;
+pub mod storage;
 pub mod test;
+pub mod ui;
+pub mod users;
+pub mod wasm;
@@ -0,0 +1,210 @@
+/**
+ * Plugin Command
+ * Git-style external subcommands: `akatsuki foo` runs `akatsuki-foo` from PATH
+ *
+ * Plugins receive:
+ * - AKATSUKI_PROJECT_ROOT: project root directory
+ * - AKATSUKI_CONFIG: path to akatsuki.toml (when it exists)
+ * - AKATSUKI_PLUGIN_CONFIG: `[plugins.<name>]` section as JSON (when set)
+ * - AKATSUKI_BIN / AKATSUKI_VERSION: the invoking CLI
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::cli::PluginsAction;
+use crate::utils::config::ProjectConfig;
+use crate::utils::find_project_root;
+use crate::utils::output::OutputFormatter;
+
+pub const PLUGIN_PREFIX: &str = "akatsuki-";
+
+/// Executable `akatsuki-<name>` found on PATH
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Plugin {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+pub struct PluginsCommand;
+
+impl PluginsCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, action: PluginsAction, output: OutputFormatter) -> Result<()> {
+        match action {
+            PluginsAction::List => self.list(output),
+        }
+    }
+
+    fn list(&self, output: OutputFormatter) -> Result<()> {
+        let plugins = discover(std::env::var_os("PATH").as_deref());
+        output.emit(&plugins, |plugins| Self::print_plugins(plugins))
+    }
+
+    fn print_plugins(plugins: &[Plugin]) {
+        println!("{}", "🔌 Plugins".bright_cyan().bold());
+        println!("{}", "─".repeat(50).bright_black());
+
+        if plugins.is_empty() {
+            println!(
+                "\n{}",
+                format!("No {}<name> executables found on PATH", PLUGIN_PREFIX).yellow()
+            );
+            println!(
+                "💡 Any executable named {} becomes {}",
+                format!("{}<name>", PLUGIN_PREFIX).bright_white(),
+                "akatsuki <name>".bright_white()
+            );
+            return;
+        }
+
+        println!();
+        let width = plugins.iter().map(|p| p.name.len()).max().unwrap_or(0);
+        for plugin in plugins {
+            println!(
+                "  {:<width$}  {}",
+                plugin.name.bright_white(),
+                plugin.path.display().to_string().bright_black(),
+                width = width
+            );
+        }
+        println!("\nTotal: {} plugins", plugins.len());
+    }
+}
+
+/// Plugins on `path_var`, first match per name wins (same as command lookup)
+pub fn discover(path_var: Option<&std::ffi::OsStr>) -> Vec<Plugin> {
+    let mut plugins: BTreeMap<String, PathBuf> = BTreeMap::new();
+    let Some(path_var) = path_var else {
+        return Vec::new();
+    };
+
+    for dir in std::env::split_paths(path_var) {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = plugin_name(&path) else {
+                continue;
+            };
+            if is_executable(&path) {
+                plugins.entry(name).or_insert(path);
+            }
+        }
+    }
+
+    plugins
+        .into_iter()
+        .map(|(name, path)| Plugin { name, path })
+        .collect()
+}
+
+/// `akatsuki-foo` / `akatsuki-foo.exe` → `foo`
+fn plugin_name(path: &Path) -> Option<String> {
+    let file_name = path.file_name()?.to_str()?;
+    let name = file_name.strip_prefix(PLUGIN_PREFIX)?;
+    let name = if cfg!(windows) {
+        name.strip_suffix(".exe")?
+    } else {
+        name
+    };
+    (!name.is_empty() && !name.contains('.')).then(|| name.to_string())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Run `akatsuki <name> [args...]` as the `akatsuki-<name>` plugin, exiting with its status
+pub fn run_external(args: Vec<String>) -> Result<()> {
+    let (name, plugin_args) = args.split_first().context("Missing plugin name")?;
+
+    let plugin = discover(std::env::var_os("PATH").as_deref())
+        .into_iter()
+        .find(|plugin| &plugin.name == name)
+        .with_context(|| {
+            format!(
+                "Unknown command '{}' (no {}{} on PATH). Run `akatsuki list` or `akatsuki plugins list`",
+                name, PLUGIN_PREFIX, name
+            )
+        })?;
+
+    let mut command = Command::new(&plugin.path);
+    command
+        .args(plugin_args)
+        .env("AKATSUKI_PROJECT_ROOT", find_project_root())
+        .env("AKATSUKI_VERSION", env!("CARGO_PKG_VERSION"));
+    if let Ok(exe) = std::env::current_exe() {
+        command.env("AKATSUKI_BIN", exe);
+    }
+
+    let config_path = ProjectConfig::path();
+    if config_path.exists() {
+        command.env("AKATSUKI_CONFIG", &config_path);
+        if let Some(section) = ProjectConfig::load()?.plugins.get(name.as_str()) {
+            command.env("AKATSUKI_PLUGIN_CONFIG", serde_json::to_string(section)?);
+        }
+    }
+
+    let status = command
+        .status()
+        .with_context(|| format!("Failed to run {}", plugin.path.display()))?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_name() {
+        assert_eq!(
+            plugin_name(Path::new("/usr/bin/akatsuki-deploy-preview")).as_deref(),
+            Some("deploy-preview")
+        );
+        assert_eq!(plugin_name(Path::new("/usr/bin/akatsuki")), None);
+        assert_eq!(plugin_name(Path::new("/usr/bin/akatsuki-")), None);
+        assert_eq!(plugin_name(Path::new("/usr/bin/akatsuki-foo.sh")), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_discover_first_match_wins() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        for dir in [first.path(), second.path()] {
+            let plugin = dir.join("akatsuki-hello");
+            std::fs::write(&plugin, "#!/bin/sh\n").unwrap();
+            std::fs::set_permissions(&plugin, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        // Not executable: ignored
+        std::fs::write(first.path().join("akatsuki-notes"), "").unwrap();
+
+        let path_var = std::env::join_paths([first.path(), second.path()]).unwrap();
+        let plugins = discover(Some(&path_var));
+        assert_eq!(plugins.len(), 1);
+        assert_eq!(plugins[0].name, "hello");
+        assert_eq!(plugins[0].path, first.path().join("akatsuki-hello"));
+    }
+}
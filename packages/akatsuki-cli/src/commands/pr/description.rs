@@ -0,0 +1,194 @@
+/**
+ * PR Description
+ * Markdown body for `akatsuki pr create`
+ */
+use regex::Regex;
+
+use super::rules::{Finding, Severity, RULES};
+use super::SchemaChange;
+
+pub struct Description<'a> {
+    pub commits: &'a [String],
+    pub files_changed: usize,
+    /// (label, link target) of the design document
+    pub design_doc: Option<(String, String)>,
+    pub schemas: &'a [SchemaChange],
+    /// (file name, summary) of each added migration
+    pub migrations: Vec<(String, String)>,
+    /// `akatsuki advice` situation lines (None when skipped)
+    pub advice: Option<Vec<String>>,
+    pub findings: &'a [Finding],
+}
+
+impl Description<'_> {
+    pub fn render(&self) -> String {
+        let mut md = String::new();
+
+        md.push_str("## Summary\n\n");
+        for commit in self.commits {
+            md.push_str(&format!("- {}\n", commit));
+        }
+        md.push_str(&format!(
+            "\n{} commit(s), {} file(s) changed\n",
+            self.commits.len(),
+            self.files_changed
+        ));
+
+        if let Some((label, target)) = &self.design_doc {
+            md.push_str(&format!("\n## Design\n\n- [{}]({})\n", label, target));
+        }
+
+        if !self.schemas.is_empty() {
+            md.push_str("\n## Schema changes\n\n");
+            for schema in self.schemas {
+                md.push_str(&format!(
+                    "- **{}** (`{}`) from `{}`: {} field(s), operations: {}\n",
+                    schema.info.name,
+                    schema.info.table_name,
+                    schema.path,
+                    schema.info.fields,
+                    schema.info.operations.join(", ")
+                ));
+            }
+        }
+
+        if !self.migrations.is_empty() {
+            md.push_str("\n## Migrations\n\n");
+            for (name, summary) in &self.migrations {
+                md.push_str(&format!("- `{}`: {}\n", name, summary));
+            }
+        }
+
+        if let Some(situation) = &self.advice {
+            md.push_str("\n## Project state (akatsuki advice)\n\n");
+            if situation.is_empty() {
+                md.push_str("- No issues detected\n");
+            }
+            for item in situation {
+                md.push_str(&format!("- {}\n", item));
+            }
+        }
+
+        md.push_str("\n## Checks (akatsuki pr check)\n\n");
+        for rule in RULES {
+            let findings: Vec<&Finding> = self
+                .findings
+                .iter()
+                .filter(|finding| finding.rule == rule)
+                .collect();
+            let mark = if findings
+                .iter()
+                .any(|finding| finding.severity == Severity::Error)
+            {
+                "❌"
+            } else if findings.is_empty() {
+                "✅"
+            } else {
+                "⚠️"
+            };
+            md.push_str(&format!("- {} `{}`\n", mark, rule));
+            for finding in findings {
+                md.push_str(&format!("  - {}\n", finding.message));
+            }
+        }
+
+        md
+    }
+}
+
+/// One-line summary of the statements in a migration
+/// ("creates table public.articles, 4 policies, 1 function")
+pub fn summarize_migration(sql: &str) -> String {
+    let sql = sql.to_lowercase();
+    let names = |pattern: &str| -> Vec<String> {
+        Regex::new(pattern)
+            .unwrap()
+            .captures_iter(&sql)
+            .map(|caps| caps[1].to_string())
+            .collect()
+    };
+    let count = |pattern: &str| Regex::new(pattern).unwrap().find_iter(&sql).count();
+
+    let mut parts = Vec::new();
+    let created = names(r"create table (?:if not exists )?([\w.]+)");
+    if !created.is_empty() {
+        parts.push(format!("creates table {}", created.join(", ")));
+    }
+    let mut altered: Vec<String> = Vec::new();
+    for table in names(r"alter table (?:if exists )?(?:only )?([\w.]+)") {
+        if !created.contains(&table) && !altered.contains(&table) {
+            altered.push(table);
+        }
+    }
+    if !altered.is_empty() {
+        parts.push(format!("alters {}", altered.join(", ")));
+    }
+    let dropped = names(r"drop table (?:if exists )?([\w.]+)");
+    if !dropped.is_empty() {
+        parts.push(format!("drops table {}", dropped.join(", ")));
+    }
+    for (pattern, singular, plural) in [
+        (r"create policy", "policy", "policies"),
+        (r"create (?:or replace )?function", "function", "functions"),
+        (r"create (?:or replace )?trigger", "trigger", "triggers"),
+        (r"create (?:unique )?index", "index", "indexes"),
+    ] {
+        match count(pattern) {
+            0 => {}
+            1 => parts.push(format!("1 {}", singular)),
+            n => parts.push(format!("{} {}", n, plural)),
+        }
+    }
+
+    if parts.is_empty() {
+        "no schema statements detected".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// `https://github.com/<owner>/<repo>` from an `origin` remote URL
+pub fn github_web_url(remote: &str) -> Option<String> {
+    let remote = remote.trim().trim_end_matches('/').trim_end_matches(".git");
+    let path = remote
+        .strip_prefix("git@github.com:")
+        .or_else(|| remote.strip_prefix("ssh://git@github.com/"))
+        .or_else(|| remote.strip_prefix("https://github.com/"))?;
+    Some(format!("https://github.com/{}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_migration() {
+        let sql = "CREATE TABLE IF NOT EXISTS public.articles (id uuid);\n\
+                   ALTER TABLE public.articles ENABLE ROW LEVEL SECURITY;\n\
+                   ALTER TABLE public.users ADD COLUMN bio text;\n\
+                   CREATE POLICY \"a\" ON public.articles;\nCREATE POLICY \"b\" ON public.articles;\n\
+                   CREATE INDEX idx ON public.articles (id);\n\
+                   CREATE OR REPLACE FUNCTION public.touch() RETURNS trigger AS $$ $$;";
+        assert_eq!(
+            summarize_migration(sql),
+            "creates table public.articles, alters public.users, 2 policies, 1 function, 1 index"
+        );
+        assert_eq!(
+            summarize_migration("-- empty"),
+            "no schema statements detected"
+        );
+    }
+
+    #[test]
+    fn test_github_web_url() {
+        assert_eq!(
+            github_web_url("git@github.com:ynishi/akatsuki.git").as_deref(),
+            Some("https://github.com/ynishi/akatsuki")
+        );
+        assert_eq!(
+            github_web_url("https://github.com/ynishi/akatsuki\n").as_deref(),
+            Some("https://github.com/ynishi/akatsuki")
+        );
+        assert_eq!(github_web_url("https://gitlab.com/x/y.git"), None);
+    }
+}
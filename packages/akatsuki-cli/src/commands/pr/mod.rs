@@ -0,0 +1,436 @@
+/**
+ * PR Command
+ * `akatsuki pr create` builds the PR description from the branch and calls `gh pr create`;
+ * `akatsuki pr check` validates migrations and generated code against the base branch
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::cli::PrAction;
+use crate::commands::advice;
+use crate::commands::api::{inspect_schema, SchemaInfo};
+use crate::utils::find_project_root;
+use crate::utils::git::git;
+
+mod description;
+mod rules;
+
+use description::Description;
+use rules::{Finding, PrContext, Severity, RULES};
+
+pub struct PrCommand {
+    project_root: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Deleted,
+    Renamed,
+}
+
+impl ChangeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Modified => "modified",
+            ChangeKind::Deleted => "deleted",
+            ChangeKind::Renamed => "renamed",
+        }
+    }
+}
+
+/// A file changed between the merge base and HEAD (path relative to the repository root)
+#[derive(Debug, Clone)]
+pub struct ChangedFile {
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+/// An entity schema YAML changed on the branch
+pub struct SchemaChange {
+    pub path: String,
+    pub info: SchemaInfo,
+}
+
+struct BranchDiff {
+    base: String,
+    /// `origin/<base>` when available, otherwise the local branch
+    base_ref: String,
+    /// `<sha> <subject>`, oldest first
+    commits: Vec<String>,
+    changes: Vec<ChangedFile>,
+}
+
+impl PrCommand {
+    pub fn new() -> Self {
+        Self {
+            project_root: find_project_root(),
+        }
+    }
+
+    pub fn execute(&self, action: PrAction) -> Result<()> {
+        match action {
+            PrAction::Create {
+                base,
+                title,
+                draft,
+                no_advice,
+                dry_run,
+            } => self.create(base, title, draft, no_advice, dry_run),
+            PrAction::Check { base } => self.check(base),
+        }
+    }
+
+    fn create(
+        &self,
+        base: Option<String>,
+        title: Option<String>,
+        draft: bool,
+        no_advice: bool,
+        dry_run: bool,
+    ) -> Result<()> {
+        let diff = self.branch_diff(base)?;
+        if diff.commits.is_empty() {
+            anyhow::bail!("No commits between {} and HEAD", diff.base_ref);
+        }
+        let branch = git(&self.project_root, &["rev-parse", "--abbrev-ref", "HEAD"])?;
+
+        println!(
+            "{}",
+            format!("📝 Preparing PR: {} → {}", branch, diff.base)
+                .cyan()
+                .bold()
+        );
+
+        let schemas = self.schema_changes(&diff.changes);
+        let findings = self.findings(&diff, &schemas);
+        let advice = if no_advice {
+            None
+        } else {
+            println!("{}", "🔍 Collecting akatsuki advice...".bright_black());
+            Some(advice::current_situation(&self.project_root)?)
+        };
+        let migrations = diff
+            .changes
+            .iter()
+            .filter(|change| rules::is_migration(&change.path) && change.kind == ChangeKind::Added)
+            .map(|change| {
+                let sql = std::fs::read_to_string(self.project_root.join(&change.path))
+                    .unwrap_or_default();
+                let name = change.path.rsplit('/').next().unwrap_or(&change.path);
+                (name.to_string(), description::summarize_migration(&sql))
+            })
+            .collect();
+
+        let body = Description {
+            commits: &diff.commits,
+            files_changed: diff.changes.len(),
+            design_doc: self.design_doc(&branch, &diff.changes),
+            schemas: &schemas,
+            migrations,
+            advice,
+            findings: &findings,
+        }
+        .render();
+        let title = title.unwrap_or_else(|| default_title(&branch, &diff.commits));
+
+        if dry_run {
+            println!();
+            println!("{} {}", "Title:".bold(), title);
+            println!();
+            println!("{}", body);
+            return Ok(());
+        }
+
+        let errors = count(&findings, Severity::Error);
+        if errors > 0 {
+            println!(
+                "{}",
+                format!(
+                    "⚠️  {} PR check error(s) — see the Checks section (akatsuki pr check)",
+                    errors
+                )
+                .yellow()
+            );
+        }
+
+        let mut cmd = Command::new("gh");
+        cmd.args([
+            "pr", "create", "--base", &diff.base, "--title", &title, "--body", &body,
+        ])
+        .current_dir(&self.project_root);
+        if draft {
+            cmd.arg("--draft");
+        }
+        let status = cmd
+            .status()
+            .context("Failed to run gh (install the GitHub CLI: https://cli.github.com)")?;
+        if !status.success() {
+            anyhow::bail!("gh pr create failed");
+        }
+        Ok(())
+    }
+
+    fn check(&self, base: Option<String>) -> Result<()> {
+        let diff = self.branch_diff(base)?;
+        let schemas = self.schema_changes(&diff.changes);
+        let findings = self.findings(&diff, &schemas);
+
+        println!(
+            "{}",
+            format!(
+                "🔍 PR check: {} commit(s), {} file(s) vs {}",
+                diff.commits.len(),
+                diff.changes.len(),
+                diff.base_ref
+            )
+            .cyan()
+            .bold()
+        );
+        println!();
+
+        for rule in RULES {
+            let rule_findings: Vec<&Finding> = findings
+                .iter()
+                .filter(|finding| finding.rule == rule)
+                .collect();
+            if rule_findings.is_empty() {
+                println!("  {} {}", "✅".green(), rule);
+                continue;
+            }
+            for finding in rule_findings {
+                match finding.severity {
+                    Severity::Error => {
+                        println!(
+                            "  {} {}: {}",
+                            "❌".red(),
+                            rule.red().bold(),
+                            finding.message
+                        )
+                    }
+                    Severity::Warning => println!(
+                        "  {} {}: {}",
+                        "⚠️".yellow(),
+                        rule.yellow().bold(),
+                        finding.message
+                    ),
+                }
+            }
+        }
+        println!();
+
+        let errors = count(&findings, Severity::Error);
+        if errors > 0 {
+            anyhow::bail!("PR check failed: {} error(s)", errors);
+        }
+        println!("{}", "✨ PR check passed!".green().bold());
+        Ok(())
+    }
+
+    fn findings(&self, diff: &BranchDiff, schemas: &[SchemaChange]) -> Vec<Finding> {
+        rules::check(&PrContext {
+            project_root: &self.project_root,
+            changes: &diff.changes,
+            schemas,
+            latest_base_migration: self.latest_migration(&diff.base_ref),
+        })
+    }
+
+    fn branch_diff(&self, base: Option<String>) -> Result<BranchDiff> {
+        let root = &self.project_root;
+        let (base, base_ref) = self.resolve_base(base)?;
+        let merge_base = git(root, &["merge-base", &base_ref, "HEAD"])
+            .with_context(|| format!("No common ancestor with {}", base_ref))?;
+        let range = format!("{}..HEAD", merge_base);
+
+        let commits = git(root, &["log", "--reverse", "--format=%h %s", &range])?
+            .lines()
+            .map(str::to_string)
+            .collect();
+        let changes = git(root, &["diff", "--name-status", "-M", &merge_base, "HEAD"])?
+            .lines()
+            .filter_map(parse_name_status)
+            .collect();
+
+        Ok(BranchDiff {
+            base,
+            base_ref,
+            commits,
+            changes,
+        })
+    }
+
+    /// (branch name for `gh --base`, ref to diff against)
+    fn resolve_base(&self, base: Option<String>) -> Result<(String, String)> {
+        let root = &self.project_root;
+        let base = match base {
+            Some(base) => base,
+            None => git(
+                root,
+                &["symbolic-ref", "--short", "refs/remotes/origin/HEAD"],
+            )
+            .ok()
+            .map(|head| head.trim_start_matches("origin/").to_string())
+            .or_else(|| {
+                ["main", "master"]
+                    .into_iter()
+                    .find(|name| git(root, &["rev-parse", "--verify", "--quiet", name]).is_ok())
+                    .map(str::to_string)
+            })
+            .context("Could not determine the base branch (use --base)")?,
+        };
+        let remote = format!("origin/{}", base);
+        let base_ref = if git(root, &["rev-parse", "--verify", "--quiet", &remote]).is_ok() {
+            remote
+        } else {
+            base.clone()
+        };
+        Ok((base, base_ref))
+    }
+
+    fn schema_changes(&self, changes: &[ChangedFile]) -> Vec<SchemaChange> {
+        changes
+            .iter()
+            .filter(|change| change.kind != ChangeKind::Deleted)
+            .filter(|change| change.path.ends_with(".yaml") || change.path.ends_with(".yml"))
+            .filter_map(|change| {
+                let path = self.project_root.join(&change.path);
+                let content = std::fs::read_to_string(&path).ok()?;
+                if !content.contains("tableName:") {
+                    return None;
+                }
+                match inspect_schema(&path) {
+                    Ok(info) => Some(SchemaChange {
+                        path: change.path.clone(),
+                        info,
+                    }),
+                    Err(e) => {
+                        println!(
+                            "{}",
+                            format!("⚠️  Skipping {}: {}", change.path, e).yellow()
+                        );
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn latest_migration(&self, base_ref: &str) -> Option<String> {
+        let root = &self.project_root;
+        git(
+            root,
+            &["ls-tree", "--name-only", base_ref, "supabase/migrations/"],
+        )
+        .ok()?
+        .lines()
+        .filter(|path| path.ends_with(".sql"))
+        .map(|path| path.rsplit('/').next().unwrap_or(path).to_string())
+        .max()
+    }
+
+    /// (label, link) of the design doc: one changed on the branch, or `<branch>-design.md`
+    fn design_doc(&self, branch: &str, changes: &[ChangedFile]) -> Option<(String, String)> {
+        let root = &self.project_root;
+        let slug = branch.rsplit('/').next().unwrap_or(branch);
+        let path = changes
+            .iter()
+            .filter(|change| change.kind != ChangeKind::Deleted)
+            .map(|change| change.path.clone())
+            .find(|path| path.ends_with("-design.md"))
+            .or_else(|| {
+                [
+                    format!("workspace/{}-design.md", slug),
+                    format!("docs/examples/{}-design.md", slug),
+                ]
+                .into_iter()
+                .find(|path| self.project_root.join(path).exists())
+            })?;
+
+        let link = git(root, &["remote", "get-url", "origin"])
+            .ok()
+            .and_then(|remote| description::github_web_url(&remote))
+            .map(|url| format!("{}/blob/{}/{}", url, branch, path))
+            .unwrap_or_else(|| path.clone());
+        Some((path, link))
+    }
+}
+
+/// One line of `git diff --name-status -M` (`R100\told\tnew` keeps the new path)
+fn parse_name_status(line: &str) -> Option<ChangedFile> {
+    let mut parts = line.split('\t');
+    let status = parts.next()?;
+    let path = parts.next_back()?.to_string();
+    let kind = match status.chars().next()? {
+        'A' | 'C' => ChangeKind::Added,
+        'D' => ChangeKind::Deleted,
+        'R' => ChangeKind::Renamed,
+        _ => ChangeKind::Modified,
+    };
+    Some(ChangedFile { path, kind })
+}
+
+fn default_title(branch: &str, commits: &[String]) -> String {
+    if let [commit] = commits {
+        return commit
+            .split_once(' ')
+            .map_or(commit.as_str(), |(_, subject)| subject)
+            .to_string();
+    }
+    let slug = branch
+        .rsplit('/')
+        .next()
+        .unwrap_or(branch)
+        .replace(['-', '_'], " ");
+    let mut chars = slug.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => slug,
+    }
+}
+
+fn count(findings: &[Finding], severity: Severity) -> usize {
+    findings
+        .iter()
+        .filter(|finding| finding.severity == severity)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_name_status() {
+        let renamed = parse_name_status("R087\tsrc/old.ts\tsrc/new.ts").unwrap();
+        assert_eq!(renamed.path, "src/new.ts");
+        assert_eq!(renamed.kind, ChangeKind::Renamed);
+
+        let added = parse_name_status("A\tsupabase/migrations/1_init.sql").unwrap();
+        assert_eq!(added.kind, ChangeKind::Added);
+        assert_eq!(
+            parse_name_status("M\tREADME.md").unwrap().kind,
+            ChangeKind::Modified
+        );
+        assert!(parse_name_status("").is_none());
+    }
+
+    #[test]
+    fn test_default_title() {
+        assert_eq!(
+            default_title("feature/article-tags", &["abc1234 Add tags".to_string()]),
+            "Add tags"
+        );
+        assert_eq!(
+            default_title(
+                "feature/article_tags-v2",
+                &["abc1234 a".to_string(), "def5678 b".to_string()]
+            ),
+            "Article tags v2"
+        );
+    }
+}
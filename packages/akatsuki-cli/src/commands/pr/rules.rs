@@ -0,0 +1,310 @@
+/**
+ * PR Rules
+ * `akatsuki pr check`: migration and generated-code consistency of the branch diff
+ */
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use super::{ChangeKind, ChangedFile, SchemaChange};
+use crate::utils::platform;
+
+const MIGRATIONS_DIR: &str = "supabase/migrations/";
+/// Header written by the HEADLESS API Generator into every generated file
+const GENERATED_MARKER: &str = "Auto-generated by HEADLESS API Generator";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug)]
+pub struct Finding {
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Finding {
+    fn error(rule: &'static str, message: String) -> Self {
+        Self {
+            rule,
+            severity: Severity::Error,
+            message,
+        }
+    }
+
+    fn warning(rule: &'static str, message: String) -> Self {
+        Self {
+            rule,
+            severity: Severity::Warning,
+            message,
+        }
+    }
+}
+
+/// Rule names in the order they are reported
+pub const RULES: [&str; 5] = [
+    "migrations-append-only",
+    "migrations-ordered",
+    "schema-has-migration",
+    "generated-code-current",
+    "generated-code-untouched",
+];
+
+/// Everything the rules look at, gathered from git and the working tree
+pub struct PrContext<'a> {
+    pub project_root: &'a Path,
+    pub changes: &'a [ChangedFile],
+    pub schemas: &'a [SchemaChange],
+    /// Newest migration file name on the base branch
+    pub latest_base_migration: Option<String>,
+}
+
+pub fn check(ctx: &PrContext) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    findings.extend(migrations_append_only(ctx));
+    findings.extend(migrations_ordered(ctx));
+    findings.extend(schema_has_migration(ctx));
+    findings.extend(generated_code_current(ctx));
+    findings.extend(generated_code_untouched(ctx));
+    findings
+}
+
+pub fn is_migration(path: &str) -> bool {
+    path.starts_with(MIGRATIONS_DIR) && path.ends_with(".sql")
+}
+
+/// Migrations already on the base branch may have been applied; edit with a new migration instead
+fn migrations_append_only(ctx: &PrContext) -> Vec<Finding> {
+    ctx.changes
+        .iter()
+        .filter(|change| is_migration(&change.path) && change.kind != ChangeKind::Added)
+        .map(|change| {
+            Finding::error(
+                "migrations-append-only",
+                format!(
+                    "{} was {} — existing migrations must not change, add a new one",
+                    change.path,
+                    change.kind.as_str()
+                ),
+            )
+        })
+        .collect()
+}
+
+/// New migrations must sort after everything on the base branch or they are applied out of order
+fn migrations_ordered(ctx: &PrContext) -> Vec<Finding> {
+    let Some(latest) = &ctx.latest_base_migration else {
+        return Vec::new();
+    };
+    ctx.changes
+        .iter()
+        .filter(|change| is_migration(&change.path) && change.kind == ChangeKind::Added)
+        .filter_map(|change| {
+            let name = file_name(&change.path);
+            (name.as_str() <= latest.as_str()).then(|| {
+                Finding::error(
+                    "migrations-ordered",
+                    format!(
+                        "{} is older than {} on the base branch — regenerate it with a newer timestamp",
+                        name, latest
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// A schema change should come with a migration for its table
+fn schema_has_migration(ctx: &PrContext) -> Vec<Finding> {
+    let migrations: Vec<String> = ctx
+        .changes
+        .iter()
+        .filter(|change| is_migration(&change.path) && change.kind != ChangeKind::Deleted)
+        .filter_map(|change| std::fs::read_to_string(ctx.project_root.join(&change.path)).ok())
+        .map(|sql| sql.to_lowercase())
+        .collect();
+
+    ctx.schemas
+        .iter()
+        .filter(|schema| {
+            let table = schema.info.table_name.to_lowercase();
+            !migrations.iter().any(|sql| mentions_table(sql, &table))
+        })
+        .map(|schema| {
+            Finding::error(
+                "schema-has-migration",
+                format!(
+                    "{} changed but no migration in this branch touches {}",
+                    schema.path, schema.info.table_name
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Generated files must match what the changed schema produces today
+fn generated_code_current(ctx: &PrContext) -> Vec<Finding> {
+    ctx.schemas
+        .iter()
+        .filter_map(|schema| {
+            let stale: Vec<String> = schema
+                .info
+                .generated
+                .iter()
+                .filter(|(path, content)| {
+                    std::fs::read_to_string(path).map_or(true, |current| &current != content)
+                })
+                .map(|(path, _)| relative(ctx.project_root, path))
+                .collect();
+            (!stale.is_empty()).then(|| {
+                Finding::error(
+                    "generated-code-current",
+                    format!(
+                        "out of sync with {}: {} — run: akatsuki api new {} --schema {}",
+                        schema.path,
+                        stale.join(", "),
+                        schema.info.name,
+                        schema.path
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Hand edits to generated files are lost on the next regeneration
+fn generated_code_untouched(ctx: &PrContext) -> Vec<Finding> {
+    let regenerated: BTreeSet<String> = ctx
+        .schemas
+        .iter()
+        .flat_map(|schema| schema.info.generated.iter())
+        .map(|(path, _)| relative(ctx.project_root, path))
+        .collect();
+
+    ctx.changes
+        .iter()
+        .filter(|change| change.kind == ChangeKind::Modified)
+        .filter(|change| !regenerated.contains(&change.path))
+        .filter(|change| {
+            std::fs::read_to_string(ctx.project_root.join(&change.path))
+                .is_ok_and(|content| content.contains(GENERATED_MARKER))
+        })
+        .map(|change| {
+            Finding::warning(
+                "generated-code-untouched",
+                format!(
+                    "{} is generated but was edited without a schema change — the edit is lost on regeneration",
+                    change.path
+                ),
+            )
+        })
+        .collect()
+}
+
+fn mentions_table(sql: &str, table: &str) -> bool {
+    [
+        format!("public.{}", table),
+        format!("table {}", table),
+        format!("table if exists {}", table),
+        format!("table if not exists {}", table),
+    ]
+    .iter()
+    .any(|needle| sql.contains(needle.as_str()))
+}
+
+fn file_name(path: &str) -> String {
+    path.rsplit('/').next().unwrap_or(path).to_string()
+}
+
+fn relative(project_root: &Path, path: &Path) -> String {
+    platform::slash_path(path.strip_prefix(project_root).unwrap_or(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(path: &str, kind: ChangeKind) -> ChangedFile {
+        ChangedFile {
+            path: path.to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_migration_rules() {
+        let root = tempfile::tempdir().unwrap();
+        let changes = vec![
+            change(
+                "supabase/migrations/20250101000000_create_articles.sql",
+                ChangeKind::Modified,
+            ),
+            change(
+                "supabase/migrations/20240101000000_add_tags.sql",
+                ChangeKind::Added,
+            ),
+            change(
+                "supabase/migrations/20260101000000_add_index.sql",
+                ChangeKind::Added,
+            ),
+        ];
+        let ctx = PrContext {
+            project_root: root.path(),
+            changes: &changes,
+            schemas: &[],
+            latest_base_migration: Some("20250101000000_create_articles.sql".to_string()),
+        };
+        let findings = check(&ctx);
+
+        let rules: Vec<&str> = findings.iter().map(|finding| finding.rule).collect();
+        assert_eq!(rules, vec!["migrations-append-only", "migrations-ordered"]);
+        assert!(findings[1]
+            .message
+            .starts_with("20240101000000_add_tags.sql"));
+    }
+
+    #[test]
+    fn test_generated_code_untouched() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("ArticleAdminPage.tsx"),
+            "/**\n * Auto-generated by HEADLESS API Generator\n */\n",
+        )
+        .unwrap();
+        std::fs::write(root.path().join("App.tsx"), "export {}\n").unwrap();
+
+        let changes = vec![
+            change("ArticleAdminPage.tsx", ChangeKind::Modified),
+            change("App.tsx", ChangeKind::Modified),
+        ];
+        let ctx = PrContext {
+            project_root: root.path(),
+            changes: &changes,
+            schemas: &[],
+            latest_base_migration: None,
+        };
+        let findings = check(&ctx);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.starts_with("ArticleAdminPage.tsx"));
+    }
+
+    #[test]
+    fn test_mentions_table() {
+        assert!(mentions_table(
+            "create table if not exists public.articles (",
+            "articles"
+        ));
+        assert!(mentions_table(
+            "alter table articles add column",
+            "articles"
+        ));
+        assert!(!mentions_table(
+            "alter table public.article_tags",
+            "articles"
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+/**
+ * Preflight Result Cache
+ * Last passing input key per target step (.akatsuki/cache/preflight.json)
+ *
+ * The key hashes the target's source tree and the versions of the tools that
+ * check it, so a step is skipped only when neither changed since it passed.
+ */
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::utils::content_hash::ContentHasher;
+use crate::utils::platform;
+
+pub const CACHE_PATH: &str = ".akatsuki/cache/preflight.json";
+
+/// Bump when the key computation or the steps change
+const CACHE_VERSION: u32 = 1;
+
+/// Files and tools a preflight target depends on
+pub struct TargetInputs {
+    pub name: &'static str,
+    /// Files or directories relative to the project root
+    pub paths: &'static [&'static str],
+    /// Commands whose `--version` output is part of the key
+    pub tools: &'static [&'static [&'static str]],
+}
+
+const NODE_TOOLS: &[&[&str]] = &[&["node", "--version"], &["npm", "--version"]];
+const RUST_TOOLS: &[&[&str]] = &[
+    &["rustc", "--version"],
+    &["cargo", "--version"],
+    &["cargo", "clippy", "--version"],
+];
+
+pub const FRONTEND: TargetInputs = TargetInputs {
+    name: "frontend",
+    paths: &["packages/app-frontend", "package.json", "package-lock.json"],
+    tools: NODE_TOOLS,
+};
+
+pub const CLI: TargetInputs = TargetInputs {
+    name: "cli",
+    paths: &["packages/app-cli", "package.json", "package-lock.json"],
+    tools: NODE_TOOLS,
+};
+
+pub const BACKEND: TargetInputs = TargetInputs {
+    name: "backend",
+    paths: &["packages/app-backend", "Cargo.toml", "Cargo.lock"],
+    tools: RUST_TOOLS,
+};
+
+pub const ADMIN_CLI: TargetInputs = TargetInputs {
+    name: "admin-cli",
+    paths: &["packages/akatsuki-cli", "Cargo.toml", "Cargo.lock"],
+    tools: RUST_TOOLS,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    /// `<target>:<step>` → key of the inputs it last passed with
+    results: BTreeMap<String, String>,
+}
+
+pub struct PreflightCache {
+    root: PathBuf,
+    file: CacheFile,
+    force: bool,
+    tool_versions: BTreeMap<String, String>,
+}
+
+impl PreflightCache {
+    /// With `force`, every step runs (results are still recorded)
+    pub fn load(root: &Path, force: bool) -> Self {
+        let file = fs::read_to_string(root.join(CACHE_PATH))
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|file| file.version == CACHE_VERSION)
+            .unwrap_or(CacheFile {
+                version: CACHE_VERSION,
+                results: BTreeMap::new(),
+            });
+
+        Self {
+            root: root.to_path_buf(),
+            file,
+            force,
+            tool_versions: BTreeMap::new(),
+        }
+    }
+
+    /// Key of the target's current inputs
+    pub fn key(&mut self, target: &TargetInputs) -> Result<String> {
+        let mut hasher = ContentHasher::new();
+        for tool in target.tools {
+            hasher.write_str(&self.tool_version(tool));
+        }
+        let paths: Vec<String> = target.paths.iter().map(|p| p.to_string()).collect();
+        hasher.write_files(&self.root, &paths)?;
+        Ok(hasher.finish_hex())
+    }
+
+    pub fn is_fresh(&self, target: &TargetInputs, step: &str, key: &str) -> bool {
+        !self.force
+            && self
+                .file
+                .results
+                .get(&format!("{}:{}", target.name, step))
+                .is_some_and(|cached| cached == key)
+    }
+
+    /// Record a passing step and save the cache
+    pub fn record(&mut self, target: &TargetInputs, step: &str, key: String) -> Result<()> {
+        self.file
+            .results
+            .insert(format!("{}:{}", target.name, step), key);
+
+        let path = self.root.join(CACHE_PATH);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(&self.file)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// `--version` output, run once per command (a missing tool is part of the key too)
+    fn tool_version(&mut self, command: &[&str]) -> String {
+        let name = command.join(" ");
+        self.tool_versions
+            .entry(name)
+            .or_insert_with(|| {
+                platform::command(command[0])
+                    .args(&command[1..])
+                    .output()
+                    .ok()
+                    .filter(|output| output.status.success())
+                    .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+                    .unwrap_or_else(|| "missing".to_string())
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TARGET: TargetInputs = TargetInputs {
+        name: "frontend",
+        paths: &["packages/app-frontend"],
+        tools: &[],
+    };
+
+    #[test]
+    fn test_steps_are_fresh_until_inputs_change() {
+        let root = tempfile::tempdir().unwrap();
+        let src = root.path().join("packages/app-frontend/src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("App.tsx"), "export {}\n").unwrap();
+
+        let mut cache = PreflightCache::load(root.path(), false);
+        let key = cache.key(&TARGET).unwrap();
+        assert!(!cache.is_fresh(&TARGET, "lint", &key));
+        cache.record(&TARGET, "lint", key.clone()).unwrap();
+
+        // Reloaded from disk
+        let mut cache = PreflightCache::load(root.path(), false);
+        assert!(cache.is_fresh(&TARGET, "lint", &key));
+        assert!(!cache.is_fresh(&TARGET, "test", &key));
+
+        fs::write(src.join("App.tsx"), "export const a = 1\n").unwrap();
+        let changed = cache.key(&TARGET).unwrap();
+        assert!(!cache.is_fresh(&TARGET, "lint", &changed));
+
+        let forced = PreflightCache::load(root.path(), true);
+        assert!(!forced.is_fresh(&TARGET, "lint", &key));
+    }
+}
@@ -1,11 +1,52 @@
 use anyhow::Result;
 use colored::Colorize;
+use std::path::Path;
 
 use crate::cli::{CheckTarget, FmtTarget, LintTarget, PreflightTarget, TestTarget};
+use crate::commands::api;
 use crate::commands::check::CheckCommand;
 use crate::commands::fmt::FmtCommand;
 use crate::commands::lint::LintCommand;
 use crate::commands::test::TestCommand;
+use crate::utils::process::TimedCommand;
+use crate::utils::{cargo_package_command, find_project_root, ADMIN_CLI_PACKAGE};
+
+mod cache;
+
+use cache::{PreflightCache, TargetInputs, ADMIN_CLI, BACKEND, CLI, FRONTEND};
+
+/// Cached steps per target, in the order preflight runs them
+const STEPS: [(&TargetInputs, &[&str]); 4] = [
+    (&FRONTEND, &["lint", "check", "test"]),
+    (&CLI, &["lint", "check"]),
+    (&BACKEND, &["lint", "check", "test"]),
+    (&ADMIN_CLI, &["lint", "check", "test"]),
+];
+
+/// Whether a preflight step last passed with the target's current inputs
+#[derive(Debug, Clone)]
+pub struct StepStatus {
+    pub target: &'static str,
+    pub step: &'static str,
+    pub passed: bool,
+}
+
+/// Status of every cached step (`akatsuki ui`); hashes the inputs but runs nothing
+pub fn step_status(root: &Path) -> Result<Vec<StepStatus>> {
+    let mut cache = PreflightCache::load(root, false);
+    let mut status = Vec::new();
+    for (target, steps) in STEPS {
+        let key = cache.key(target)?;
+        for step in steps {
+            status.push(StepStatus {
+                target: target.name,
+                step,
+                passed: cache.is_fresh(target, step, &key),
+            });
+        }
+    }
+    Ok(status)
+}
 
 pub struct PreflightCommand;
 
@@ -14,7 +55,10 @@ impl PreflightCommand {
         Self
     }
 
-    pub fn execute(&self, target: PreflightTarget) -> Result<()> {
+    /// lint / check / test are skipped when the target's inputs are unchanged
+    /// since they last passed, unless `force` is set. With `verify_api`,
+    /// generated API code is checked against its schemas first.
+    pub fn execute(&self, target: PreflightTarget, force: bool, verify_api: bool) -> Result<()> {
         println!(
             "{}",
             "🚦 Running preflight checks (fmt → lint → check → test)..."
@@ -23,16 +67,25 @@ impl PreflightCommand {
         );
         println!();
 
+        let root = find_project_root();
+        if verify_api {
+            println!("{}", "━━━ Generated API Code ━━━".bright_blue().bold());
+            println!();
+            api::verify_generated(&root)?;
+            println!();
+        }
+
+        let mut cache = PreflightCache::load(&root, force);
         match target {
-            PreflightTarget::Frontend => self.preflight_frontend(),
-            PreflightTarget::Backend => self.preflight_backend(),
-            PreflightTarget::Cli => self.preflight_cli(),
-            PreflightTarget::AdminCli => self.preflight_admin_cli(),
-            PreflightTarget::All => self.preflight_all(),
+            PreflightTarget::Frontend => self.preflight_frontend(&mut cache),
+            PreflightTarget::Backend => self.preflight_backend(&mut cache),
+            PreflightTarget::Cli => self.preflight_cli(&mut cache),
+            PreflightTarget::AdminCli => self.preflight_admin_cli(&mut cache),
+            PreflightTarget::All => self.preflight_all(&mut cache),
         }
     }
 
-    fn preflight_frontend(&self) -> Result<()> {
+    fn preflight_frontend(&self, cache: &mut PreflightCache) -> Result<()> {
         println!("{}", "━━━ Frontend Preflight ━━━".bright_blue().bold());
         println!();
 
@@ -43,24 +96,30 @@ impl PreflightCommand {
 
         // 2. Lint
         println!("{}", "2️⃣  Linting...".cyan());
-        LintCommand::new().execute(LintTarget::Frontend, true)?;
+        self.cached_step(cache, &FRONTEND, "lint", || {
+            LintCommand::new().execute(LintTarget::Frontend, true)
+        })?;
         println!();
 
         // 3. Check
         println!("{}", "3️⃣  Type checking...".cyan());
-        CheckCommand::new().execute(CheckTarget::Frontend)?;
+        self.cached_step(cache, &FRONTEND, "check", || {
+            CheckCommand::new().execute(CheckTarget::Frontend)
+        })?;
         println!();
 
         // 4. Test
         println!("{}", "4️⃣  Testing...".cyan());
-        TestCommand::new().execute(TestTarget::Frontend, false, false, false)?;
+        self.cached_step(cache, &FRONTEND, "test", || {
+            TestCommand::new().execute(TestTarget::Frontend, false, false, false, false)
+        })?;
         println!();
 
         println!("{}", "✅ Frontend preflight passed!".green().bold());
         Ok(())
     }
 
-    fn preflight_backend(&self) -> Result<()> {
+    fn preflight_backend(&self, cache: &mut PreflightCache) -> Result<()> {
         println!("{}", "━━━ Backend Preflight ━━━".bright_blue().bold());
         println!();
 
@@ -71,24 +130,30 @@ impl PreflightCommand {
 
         // 2. Lint
         println!("{}", "2️⃣  Linting...".cyan());
-        LintCommand::new().execute(LintTarget::Backend, true)?;
+        self.cached_step(cache, &BACKEND, "lint", || {
+            LintCommand::new().execute(LintTarget::Backend, true)
+        })?;
         println!();
 
         // 3. Check
         println!("{}", "3️⃣  Type checking...".cyan());
-        CheckCommand::new().execute(CheckTarget::Backend)?;
+        self.cached_step(cache, &BACKEND, "check", || {
+            CheckCommand::new().execute(CheckTarget::Backend)
+        })?;
         println!();
 
         // 4. Test
         println!("{}", "4️⃣  Testing...".cyan());
-        TestCommand::new().execute(TestTarget::Backend, false, false, false)?;
+        self.cached_step(cache, &BACKEND, "test", || {
+            TestCommand::new().execute(TestTarget::Backend, false, false, false, false)
+        })?;
         println!();
 
         println!("{}", "✅ Backend preflight passed!".green().bold());
         Ok(())
     }
 
-    fn preflight_cli(&self) -> Result<()> {
+    fn preflight_cli(&self, cache: &mut PreflightCache) -> Result<()> {
         println!("{}", "━━━ CLI Preflight ━━━".bright_blue().bold());
         println!();
 
@@ -99,12 +164,16 @@ impl PreflightCommand {
 
         // 2. Lint
         println!("{}", "2️⃣  Linting...".cyan());
-        LintCommand::new().execute(LintTarget::Cli, true)?;
+        self.cached_step(cache, &CLI, "lint", || {
+            LintCommand::new().execute(LintTarget::Cli, true)
+        })?;
         println!();
 
         // 3. Check
         println!("{}", "3️⃣  Type checking...".cyan());
-        CheckCommand::new().execute(CheckTarget::Cli)?;
+        self.cached_step(cache, &CLI, "check", || {
+            CheckCommand::new().execute(CheckTarget::Cli)
+        })?;
         println!();
 
         // CLI doesn't have tests currently
@@ -115,7 +184,7 @@ impl PreflightCommand {
         Ok(())
     }
 
-    fn preflight_admin_cli(&self) -> Result<()> {
+    fn preflight_admin_cli(&self, cache: &mut PreflightCache) -> Result<()> {
         println!("{}", "━━━ Admin-CLI Preflight ━━━".bright_blue().bold());
         println!();
 
@@ -126,47 +195,51 @@ impl PreflightCommand {
 
         // 2. Lint
         println!("{}", "2️⃣  Linting...".cyan());
-        LintCommand::new().execute(LintTarget::AdminCli, true)?;
+        self.cached_step(cache, &ADMIN_CLI, "lint", || {
+            LintCommand::new().execute(LintTarget::AdminCli, true)
+        })?;
         println!();
 
         // 3. Check
         println!("{}", "3️⃣  Type checking...".cyan());
-        CheckCommand::new().execute(CheckTarget::AdminCli)?;
+        self.cached_step(cache, &ADMIN_CLI, "check", || {
+            CheckCommand::new().execute(CheckTarget::AdminCli)
+        })?;
         println!();
 
-        // 4. Test (cargo test)
+        // 4. Test (cargo test: unit tests + the end-to-end suite in tests/)
         println!("{}", "4️⃣  Testing...".cyan());
-        let project_root = crate::utils::find_project_root();
-        let status = std::process::Command::new("cargo")
-            .args(["test"])
-            .current_dir(project_root.join("packages/akatsuki-cli"))
-            .status()?;
-
-        if !status.success() {
-            anyhow::bail!("admin-cli tests failed");
-        }
-        println!("{}", "✅ admin-cli tests passed!".green());
+        self.cached_step(cache, &ADMIN_CLI, "test", || {
+            let status = cargo_package_command(&find_project_root(), ADMIN_CLI_PACKAGE, "test")
+                .timed_status()?;
+
+            if !status.success() {
+                anyhow::bail!("admin-cli tests failed");
+            }
+            println!("{}", "✅ admin-cli tests passed!".green());
+            Ok(())
+        })?;
         println!();
 
         println!("{}", "✅ Admin-CLI preflight passed!".green().bold());
         Ok(())
     }
 
-    fn preflight_all(&self) -> Result<()> {
+    fn preflight_all(&self, cache: &mut PreflightCache) -> Result<()> {
         // Frontend
-        self.preflight_frontend()?;
+        self.preflight_frontend(cache)?;
         println!();
 
         // CLI
-        self.preflight_cli()?;
+        self.preflight_cli(cache)?;
         println!();
 
         // Backend
-        self.preflight_backend()?;
+        self.preflight_backend(cache)?;
         println!();
 
         // Admin-CLI
-        self.preflight_admin_cli()?;
+        self.preflight_admin_cli(cache)?;
         println!();
 
         println!(
@@ -187,4 +260,28 @@ impl PreflightCommand {
 
         Ok(())
     }
+
+    /// Run `step` unless it already passed with the target's current inputs.
+    /// The key is taken again afterwards since lint --fix may rewrite files.
+    fn cached_step(
+        &self,
+        cache: &mut PreflightCache,
+        target: &TargetInputs,
+        step: &str,
+        run: impl FnOnce() -> Result<()>,
+    ) -> Result<()> {
+        let key = cache.key(target)?;
+        if cache.is_fresh(target, step, &key) {
+            println!(
+                "{}",
+                "   ✓ Inputs unchanged since the last pass (cached, --force to re-run)"
+                    .bright_black()
+            );
+            return Ok(());
+        }
+
+        run()?;
+        let key = cache.key(target)?;
+        cache.record(target, step, key)
+    }
 }
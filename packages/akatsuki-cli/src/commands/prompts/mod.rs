@@ -0,0 +1,343 @@
+/**
+ * Prompts Command
+ * Reusable prompt snippets in workspace/prompts/<name>.md
+ *
+ * A prompt is markdown with optional YAML front matter (`description`,
+ * `defaults` for its variables). `{{name}}` placeholders are filled by the
+ * same engine as the backend's email and experiment prompt templates.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{IsTerminal, Read, Write};
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use crate::cli::{PromptTemplate, PromptsAction};
+use crate::utils::find_project_root;
+use crate::utils::platform;
+
+#[path = "../../../../app-backend/src/template.rs"]
+mod template;
+mod templates;
+
+pub const PROMPTS_DIR: &str = "workspace/prompts";
+
+/// Optional YAML block between `---` lines at the top of a prompt file
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FrontMatter {
+    description: String,
+    /// Values used when a variable is not passed with --var ("" makes it optional)
+    defaults: BTreeMap<String, String>,
+}
+
+#[derive(Debug)]
+struct Prompt {
+    front: FrontMatter,
+    body: String,
+}
+
+impl Prompt {
+    fn parse(content: &str) -> Result<Self> {
+        let Some(rest) = content.strip_prefix("---\n") else {
+            return Ok(Self {
+                front: FrontMatter::default(),
+                body: content.to_string(),
+            });
+        };
+        let (yaml, body) = match rest.split_once("\n---\n") {
+            Some(parts) => parts,
+            None => (
+                rest.strip_suffix("\n---")
+                    .context("Front matter is not closed with ---")?,
+                "",
+            ),
+        };
+        let front = if yaml.trim().is_empty() {
+            FrontMatter::default()
+        } else {
+            serde_yaml::from_str(yaml).context("Invalid front matter")?
+        };
+        Ok(Self {
+            front,
+            body: body.trim_start_matches('\n').to_string(),
+        })
+    }
+
+    /// Placeholder names in the body, sorted
+    fn variables(&self) -> BTreeSet<String> {
+        let mut names = BTreeSet::new();
+        template::render_with(&self.body, |name| {
+            names.insert(name.to_string());
+            None
+        });
+        names
+    }
+
+    /// Variables without a --var value or a default
+    fn missing(&self, values: &BTreeMap<String, String>) -> Vec<String> {
+        self.variables()
+            .into_iter()
+            .filter(|name| !values.contains_key(name) && !self.front.defaults.contains_key(name))
+            .collect()
+    }
+
+    fn render(&self, values: &BTreeMap<String, String>) -> String {
+        template::render_with(&self.body, |name| {
+            values
+                .get(name)
+                .or_else(|| self.front.defaults.get(name))
+                .cloned()
+        })
+    }
+}
+
+pub struct PromptsCommand {
+    dir: PathBuf,
+}
+
+impl PromptsCommand {
+    pub fn new() -> Self {
+        Self {
+            dir: find_project_root().join(PROMPTS_DIR),
+        }
+    }
+
+    pub fn execute(&self, action: PromptsAction) -> Result<()> {
+        match action {
+            PromptsAction::List => self.list(),
+            PromptsAction::New {
+                name,
+                template,
+                force,
+            } => self.new_prompt(&name, template, force),
+            PromptsAction::Render {
+                name,
+                vars,
+                copy,
+                send,
+            } => self.render(&name, &vars, copy, send),
+        }
+    }
+
+    fn list(&self) -> Result<()> {
+        let mut files: Vec<PathBuf> = fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+            .collect();
+        files.sort();
+
+        println!("{}", format!("📝 Prompts ({})", PROMPTS_DIR).cyan().bold());
+        if files.is_empty() {
+            println!("\n  No prompts yet");
+            println!("💡 Create one: akatsuki prompts new <name> --template review");
+            return Ok(());
+        }
+
+        println!();
+        for path in files {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            match fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| Prompt::parse(&content))
+            {
+                Ok(prompt) => {
+                    println!(
+                        "  {:<24} {}",
+                        name.bright_white().bold(),
+                        prompt.front.description
+                    );
+                    let variables: Vec<String> = prompt
+                        .variables()
+                        .into_iter()
+                        .map(|variable| match prompt.front.defaults.get(&variable) {
+                            Some(_) => format!("{}?", variable),
+                            None => variable,
+                        })
+                        .collect();
+                    if !variables.is_empty() {
+                        println!("  {:<24} {}", "", variables.join(" ").bright_black());
+                    }
+                }
+                Err(error) => println!("  {:<24} {}", name.red(), format!("{:#}", error).red()),
+            }
+        }
+        println!();
+        println!(
+            "{}",
+            "💡 akatsuki prompts render <name> --var key=value (? = has a default)".bright_black()
+        );
+        Ok(())
+    }
+
+    fn new_prompt(&self, name: &str, template: PromptTemplate, force: bool) -> Result<()> {
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            anyhow::bail!(
+                "Prompt name must be kebab-case (e.g., code-review): {}",
+                name
+            );
+        }
+
+        let path = self.dir.join(format!("{}.md", name));
+        if path.exists() && !force {
+            anyhow::bail!(
+                "{} already exists (use --force to overwrite)",
+                path.display()
+            );
+        }
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        fs::write(&path, templates::content(template))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        println!("✅ Created {}/{}.md", PROMPTS_DIR, name);
+        println!("💡 Edit it, then: akatsuki prompts render {}", name);
+        Ok(())
+    }
+
+    fn render(&self, name: &str, vars: &[String], copy: bool, send: bool) -> Result<()> {
+        let path = self.dir.join(format!("{}.md", name));
+        let content = fs::read_to_string(&path).with_context(|| {
+            format!(
+                "Prompt not found: {}/{}.md (see: akatsuki prompts list)",
+                PROMPTS_DIR, name
+            )
+        })?;
+        let prompt = Prompt::parse(&content).with_context(|| format!("Invalid prompt {}", name))?;
+
+        let mut values = parse_vars(vars)?;
+        let missing = prompt.missing(&values);
+        if !missing.is_empty() {
+            let reads_stdin = vars.iter().any(|var| var.ends_with("=@-"));
+            if reads_stdin || !std::io::stdin().is_terminal() {
+                anyhow::bail!(
+                    "Missing variable(s): {} (pass --var {}=...)",
+                    missing.join(", "),
+                    missing[0]
+                );
+            }
+            for variable in missing {
+                let value: String = dialoguer::Input::new()
+                    .with_prompt(&variable)
+                    .allow_empty(true)
+                    .interact_text()?;
+                values.insert(variable, value);
+            }
+        }
+
+        let rendered = prompt.render(&values);
+        if copy {
+            platform::copy_to_clipboard(&rendered)?;
+            eprintln!(
+                "📋 Copied {} ({} chars) to the clipboard",
+                name,
+                rendered.chars().count()
+            );
+        }
+        if send {
+            send_to_claude(&rendered)?;
+        }
+        if !copy && !send {
+            print!("{}", rendered);
+            if !rendered.ends_with('\n') {
+                println!();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `key=value`, `key=@file` (file contents) or `key=@-` (stdin)
+fn parse_vars(vars: &[String]) -> Result<BTreeMap<String, String>> {
+    let mut values = BTreeMap::new();
+    for var in vars {
+        let (key, value) = var
+            .split_once('=')
+            .with_context(|| format!("Invalid --var '{}' (expected key=value)", var))?;
+        let value = match value.strip_prefix('@') {
+            Some("-") => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            Some(file) => {
+                fs::read_to_string(file).with_context(|| format!("Failed to read {}", file))?
+            }
+            None => value.to_string(),
+        };
+        values.insert(key.trim().to_string(), value);
+    }
+    Ok(values)
+}
+
+/// Run `claude -p` with the prompt on stdin, streaming the answer
+fn send_to_claude(prompt: &str) -> Result<()> {
+    let mut child = platform::command("claude")
+        .arg("-p")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .context("Failed to execute 'claude' command (required for --send)")?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(prompt.as_bytes())?;
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        anyhow::bail!("Claude command failed with status: {}", status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_variables_and_render() {
+        let prompt = Prompt::parse(
+            "---\ndescription: Review\ndefaults:\n  focus: bugs\n---\nFocus on {{focus}}.\n\n{{ diff }}\n",
+        )
+        .unwrap();
+        assert_eq!(prompt.front.description, "Review");
+        assert_eq!(
+            prompt.variables().into_iter().collect::<Vec<_>>(),
+            ["diff", "focus"]
+        );
+
+        let mut values = BTreeMap::new();
+        assert_eq!(prompt.missing(&values), ["diff"]);
+        values.insert("diff".to_string(), "+ fn main() {}".to_string());
+        assert_eq!(prompt.render(&values), "Focus on bugs.\n\n+ fn main() {}\n");
+
+        let plain = Prompt::parse("Just {{text}}").unwrap();
+        assert!(plain.front.defaults.is_empty());
+        assert!(Prompt::parse("---\nunknown: 1\n---\nbody").is_err());
+    }
+
+    #[test]
+    fn test_builtin_templates_parse() {
+        for template in [
+            PromptTemplate::Blank,
+            PromptTemplate::Review,
+            PromptTemplate::Feature,
+            PromptTemplate::Bugfix,
+        ] {
+            let prompt = Prompt::parse(templates::content(template)).unwrap();
+            assert!(!prompt.front.description.is_empty());
+            assert!(!prompt.variables().is_empty());
+        }
+    }
+}
@@ -0,0 +1,62 @@
+/**
+ * Prompt Templates
+ * Starting points for `akatsuki prompts new --template <name>`
+ */
+use crate::cli::PromptTemplate;
+
+const BLANK: &str = r#"---
+description: Describe what this prompt is for
+defaults: {}
+---
+{{task}}
+"#;
+
+const REVIEW: &str = r#"---
+description: Review a diff for bugs, security issues and missing tests
+defaults:
+  focus: correctness, security and missing tests
+---
+Review the following changes to this project (conventions: AGENT.md).
+Focus on {{focus}}. List concrete issues with file and line, most severe first,
+and say explicitly when you find nothing.
+
+{{diff}}
+"#;
+
+const FEATURE: &str = r#"---
+description: Implement a feature following the project's layered architecture
+defaults:
+  layers: Model → Repository → Service → Hook → Component
+---
+Implement "{{feature}}" in this project.
+
+Requirements:
+{{requirements}}
+
+Follow the architecture in AGENT.md ({{layers}}), reuse existing components
+(`akatsuki docs all`), and add tests for new logic. Run `akatsuki preflight`
+before you finish.
+"#;
+
+const BUGFIX: &str = r#"---
+description: Find the root cause of a bug and fix it with a regression test
+defaults:
+  context: ""
+---
+Fix this bug: {{symptom}}
+
+Reproduction steps / error output:
+{{context}}
+
+Find the root cause before changing code, keep the fix minimal and add a
+regression test that fails without it.
+"#;
+
+pub fn content(template: PromptTemplate) -> &'static str {
+    match template {
+        PromptTemplate::Blank => BLANK,
+        PromptTemplate::Review => REVIEW,
+        PromptTemplate::Feature => FEATURE,
+        PromptTemplate::Bugfix => BUGFIX,
+    }
+}
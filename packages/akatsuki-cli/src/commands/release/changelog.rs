@@ -0,0 +1,63 @@
+//! CHANGELOG.md sections used as release notes
+//!
+//! Sections are `## [X.Y.Z] - YYYY-MM-DD` headings (Keep a Changelog); the
+//! `## [Unreleased]` section is stamped with the version when releasing.
+
+use regex::Regex;
+
+pub const CHANGELOG_PATH: &str = "packages/akatsuki-cli/CHANGELOG.md";
+
+/// Body of the `## [version]` section (None when missing or empty)
+pub fn section(content: &str, version: &str) -> Option<String> {
+    let heading = Regex::new(&format!(r"^## \[?v?{}\]?(\s|$)", regex::escape(version))).ok()?;
+    let body: Vec<&str> = content
+        .lines()
+        .skip_while(|line| !heading.is_match(line))
+        .skip(1)
+        .take_while(|line| !line.starts_with("## "))
+        .collect();
+    let body = body.join("\n").trim().to_string();
+    (!body.is_empty()).then_some(body)
+}
+
+/// Rename a non-empty `## [Unreleased]` section to `## [version] - date` and
+/// start a new empty Unreleased section above it (None when there is nothing
+/// to stamp)
+pub fn stamp_unreleased(content: &str, version: &str, date: &str) -> Option<String> {
+    section(content, "Unreleased")?;
+    let heading = Regex::new(r"(?m)^## \[Unreleased\][^\n]*$").ok()?;
+    Some(
+        heading
+            .replace(
+                content,
+                format!("## [Unreleased]\n\n## [{}] - {}", version, date),
+            )
+            .into_owned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANGELOG: &str = "# Changelog\n\n## [Unreleased]\n\n### Added\n- `akatsuki db plan`\n\n## [0.1.0] - 2025-01-01\n\n- Initial release\n";
+
+    #[test]
+    fn test_sections_and_unreleased_stamp() {
+        assert_eq!(
+            section(CHANGELOG, "0.1.0").as_deref(),
+            Some("- Initial release")
+        );
+        assert_eq!(section(CHANGELOG, "0.2.0"), None);
+
+        let stamped = stamp_unreleased(CHANGELOG, "0.2.0", "2025-02-01").unwrap();
+        assert!(stamped.contains("## [Unreleased]\n\n## [0.2.0] - 2025-02-01\n\n### Added\n"));
+        assert_eq!(section(&stamped, "Unreleased"), None);
+        assert_eq!(
+            section(&stamped, "0.2.0").as_deref(),
+            Some("### Added\n- `akatsuki db plan`")
+        );
+        // Nothing left to stamp
+        assert_eq!(stamp_unreleased(&stamped, "0.3.0", "2025-03-01"), None);
+    }
+}
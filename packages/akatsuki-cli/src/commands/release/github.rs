@@ -0,0 +1,455 @@
+//! GitHub release built by `.github/workflows/release-cli.yml`
+//!
+//! Polls the release of a tag until every platform asset is uploaded, checks
+//! each archive against its `.sha256` file and publishes the release notes.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use super::version::Version;
+use crate::cli::ReleaseChannel;
+
+const API_URL: &str = "https://api.github.com";
+
+/// Ends the part of the release body written by `akatsuki release`
+const NOTES_END: &str = "<!-- akatsuki release notes end -->";
+
+/// Repository the published CLI releases live in
+pub const UPSTREAM_REPO: &str = "ynishi/akatsuki";
+
+/// Archives uploaded by the release workflow (one per build matrix entry)
+pub const PLATFORM_ASSETS: [&str; 4] = [
+    "akatsuki-x86_64-apple-darwin.tar.gz",
+    "akatsuki-aarch64-apple-darwin.tar.gz",
+    "akatsuki-x86_64-unknown-linux-gnu.tar.gz",
+    "akatsuki-x86_64-pc-windows-msvc.zip",
+];
+
+#[derive(Debug, Deserialize)]
+pub struct Release {
+    pub id: u64,
+    pub tag_name: String,
+    pub html_url: String,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub body: Option<String>,
+    pub assets: Vec<Asset>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Asset {
+    pub name: String,
+    /// API URL (downloads with `Accept: application/octet-stream`)
+    pub url: String,
+    pub state: String,
+}
+
+/// Outcome for one platform archive
+#[derive(Debug, PartialEq)]
+pub enum AssetStatus {
+    Missing,
+    MissingChecksum,
+    /// The `.sha256` file does not contain a SHA-256 hex digest
+    InvalidChecksum,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    Verified(String),
+}
+
+impl AssetStatus {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, AssetStatus::Verified(_))
+    }
+}
+
+pub struct GitHubClient {
+    repo: String,
+    token: Option<String>,
+    client: reqwest::blocking::Client,
+}
+
+impl GitHubClient {
+    /// Token from GITHUB_TOKEN / GH_TOKEN or `gh auth token` (anonymous
+    /// requests work for public repositories but are rate limited)
+    pub fn new(repo: &str) -> Result<Self> {
+        let token = ["GITHUB_TOKEN", "GH_TOKEN"]
+            .iter()
+            .find_map(|name| std::env::var(name).ok().filter(|t| !t.is_empty()))
+            .or_else(gh_auth_token);
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(300))
+            .user_agent(concat!("akatsuki-cli/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .context("Failed to build HTTP client")?;
+
+        Ok(Self {
+            repo: repo.to_string(),
+            token,
+            client,
+        })
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    /// None until the workflow has created the release
+    pub fn release_by_tag(&self, tag: &str) -> Result<Option<Release>> {
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                &format!("/repos/{}/releases/tags/{}", self.repo, tag),
+            )
+            .send()
+            .context("Failed to reach the GitHub API")?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = check(response)?;
+        Ok(Some(
+            response
+                .json()
+                .context("Failed to parse the GitHub release")?,
+        ))
+    }
+
+    /// Recent releases, newest first
+    pub fn releases(&self) -> Result<Vec<Release>> {
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                &format!("/repos/{}/releases?per_page=50", self.repo),
+            )
+            .send()
+            .context("Failed to reach the GitHub API")?;
+        check(response)?
+            .json()
+            .context("Failed to parse the GitHub releases")
+    }
+
+    pub fn download(&self, asset: &Asset) -> Result<Vec<u8>> {
+        let request = self
+            .client
+            .get(&asset.url)
+            .header("Accept", "application/octet-stream");
+        let response = self
+            .authorize(request)
+            .send()
+            .with_context(|| format!("Failed to download {}", asset.name))?;
+        Ok(check(response)?.bytes()?.to_vec())
+    }
+
+    /// Publish the release notes and set the prerelease flag
+    pub fn update(&self, release: &Release, body: &str, prerelease: bool) -> Result<()> {
+        let response = self
+            .request(
+                reqwest::Method::PATCH,
+                &format!("/repos/{}/releases/{}", self.repo, release.id),
+            )
+            .json(&serde_json::json!({ "body": body, "prerelease": prerelease }))
+            .send()
+            .context("Failed to reach the GitHub API")?;
+        check(response)?;
+        Ok(())
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::blocking::RequestBuilder {
+        let request = self
+            .client
+            .request(method, format!("{}{}", API_URL, path))
+            .header("Accept", "application/vnd.github+json");
+        self.authorize(request)
+    }
+
+    fn authorize(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> reqwest::blocking::RequestBuilder {
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+}
+
+fn check(response: reqwest::blocking::Response) -> Result<reqwest::blocking::Response> {
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().unwrap_or_default();
+        anyhow::bail!("GitHub API returned {}: {}", status, body.trim());
+    }
+    Ok(response)
+}
+
+fn gh_auth_token() -> Option<String> {
+    let output = Command::new("gh").args(["auth", "token"]).output().ok()?;
+    let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !token.is_empty()).then_some(token)
+}
+
+/// `owner/repo` of the origin remote (GitHub HTTPS or SSH URL)
+pub fn origin_repo(root: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .args(["remote", "get-url", "origin"])
+        .current_dir(root)
+        .output()
+        .ok()?;
+    parse_repo(String::from_utf8_lossy(&output.stdout).trim())
+}
+
+fn parse_repo(url: &str) -> Option<String> {
+    let path = url
+        .strip_prefix("git@github.com:")
+        .or_else(|| url.split_once("github.com/").map(|(_, path)| path))?;
+    let repo = path.trim_end_matches('/').trim_end_matches(".git");
+    (repo.split('/').count() == 2).then(|| repo.to_string())
+}
+
+/// Platform archives that are uploaded (and their checksum files)
+pub fn uploaded_count(release: &Release) -> usize {
+    PLATFORM_ASSETS
+        .iter()
+        .filter(|name| {
+            [name.to_string(), format!("{}.sha256", name)]
+                .iter()
+                .all(|file| find_asset(release, file).is_some())
+        })
+        .count()
+}
+
+/// Check every platform archive against its `.sha256` file
+pub fn verify_assets(
+    client: &GitHubClient,
+    release: &Release,
+) -> Result<Vec<(String, AssetStatus)>> {
+    let mut results = Vec::new();
+    for name in PLATFORM_ASSETS {
+        let (status, _) = check_asset(client, release, name)?;
+        results.push((name.to_string(), status));
+    }
+    Ok(results)
+}
+
+/// Download a platform archive, failing unless it matches its `.sha256` file
+pub fn download_verified(client: &GitHubClient, release: &Release, name: &str) -> Result<Vec<u8>> {
+    match check_asset(client, release, name)? {
+        (AssetStatus::Verified(_), archive) => Ok(archive),
+        (AssetStatus::Missing, _) => {
+            anyhow::bail!("{} has no {} asset", release.tag_name, name)
+        }
+        (AssetStatus::MissingChecksum | AssetStatus::InvalidChecksum, _) => {
+            anyhow::bail!("{} has no usable {}.sha256 file", release.tag_name, name)
+        }
+        (AssetStatus::Mismatch { expected, actual }, _) => anyhow::bail!(
+            "Checksum mismatch for {} (expected {}, got {})",
+            name,
+            expected,
+            actual
+        ),
+    }
+}
+
+/// Status of one archive, with its contents when it was downloaded
+fn check_asset(
+    client: &GitHubClient,
+    release: &Release,
+    name: &str,
+) -> Result<(AssetStatus, Vec<u8>)> {
+    let archive = find_asset(release, name);
+    let checksum = find_asset(release, &format!("{}.sha256", name));
+    let (Some(archive), Some(checksum)) = (archive, checksum) else {
+        let status = if archive.is_none() {
+            AssetStatus::Missing
+        } else {
+            AssetStatus::MissingChecksum
+        };
+        return Ok((status, Vec::new()));
+    };
+
+    let checksum = String::from_utf8_lossy(&client.download(checksum)?).to_string();
+    let Some(expected) = parse_checksum(&checksum) else {
+        return Ok((AssetStatus::InvalidChecksum, Vec::new()));
+    };
+    let content = client.download(archive)?;
+    let actual = format!("{:x}", Sha256::digest(&content));
+    if actual == expected {
+        Ok((AssetStatus::Verified(actual), content))
+    } else {
+        Ok((AssetStatus::Mismatch { expected, actual }, content))
+    }
+}
+
+/// Archive for the platform this binary was built for
+pub fn platform_asset() -> Option<&'static str> {
+    if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+        Some(PLATFORM_ASSETS[1])
+    } else if cfg!(all(target_os = "macos", target_arch = "x86_64")) {
+        Some(PLATFORM_ASSETS[0])
+    } else if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+        Some(PLATFORM_ASSETS[2])
+    } else if cfg!(all(target_os = "windows", target_arch = "x86_64")) {
+        Some(PLATFORM_ASSETS[3])
+    } else {
+        None
+    }
+}
+
+/// Newest CLI release of the channel (stable skips prereleases, beta takes
+/// whichever is newest)
+pub fn latest_release(releases: Vec<Release>, channel: ReleaseChannel) -> Option<Release> {
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .filter_map(|release| Some((Version::parse(&release.tag_name)?, release)))
+        .filter(|(version, release)| {
+            release.tag_name.starts_with("cli-v")
+                && (channel == ReleaseChannel::Beta
+                    || !(release.prerelease || version.is_prerelease()))
+        })
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
+fn find_asset<'a>(release: &'a Release, name: &str) -> Option<&'a Asset> {
+    release
+        .assets
+        .iter()
+        .find(|asset| asset.name == name && asset.state == "uploaded")
+}
+
+/// Digest of a `shasum -a 256` or `certutil -hashfile` output (lowercase hex)
+fn parse_checksum(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let candidates = [
+            line.split_whitespace().next().unwrap_or("").to_string(),
+            line.split_whitespace().collect::<String>(),
+        ];
+        candidates
+            .into_iter()
+            .find(|hex| hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+            .map(|hex| hex.to_lowercase())
+    })
+}
+
+/// Release body: the changelog notes, a checksum table, then whatever the
+/// workflow generated (GitHub's "What's Changed"); re-running replaces the
+/// previously published notes
+pub fn release_body(notes: &str, verified: &[(String, AssetStatus)], existing: &str) -> String {
+    let generated = existing
+        .split_once(NOTES_END)
+        .map_or(existing, |(_, generated)| generated);
+    let mut body = format!(
+        "{}\n\n## Checksums (SHA-256)\n\n| Asset | SHA-256 |\n|---|---|\n",
+        notes.trim()
+    );
+    for (name, status) in verified {
+        if let AssetStatus::Verified(digest) = status {
+            body.push_str(&format!("| `{}` | `{}` |\n", name, digest));
+        }
+    }
+    body.push_str(&format!("\n{}\n", NOTES_END));
+    if !generated.trim().is_empty() {
+        body.push('\n');
+        body.push_str(generated.trim());
+        body.push('\n');
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_repo_and_checksums() {
+        assert_eq!(
+            parse_repo("git@github.com:ynishi/akatsuki.git").as_deref(),
+            Some("ynishi/akatsuki")
+        );
+        assert_eq!(
+            parse_repo("https://github.com/ynishi/akatsuki").as_deref(),
+            Some("ynishi/akatsuki")
+        );
+        assert_eq!(parse_repo("https://gitlab.com/ynishi/akatsuki.git"), None);
+
+        let digest = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+        assert_eq!(
+            parse_checksum(&format!(
+                "{}  akatsuki-x86_64-apple-darwin.tar.gz\n",
+                digest
+            ))
+            .as_deref(),
+            Some(digest)
+        );
+        // certutil (Windows): digest on its own line, uppercase in older versions
+        let certutil = format!(
+            "SHA256 hash of akatsuki-x86_64-pc-windows-msvc.zip:\r\n{}\r\nCertUtil: -hashfile command completed successfully.\r\n",
+            digest.to_uppercase()
+        );
+        assert_eq!(parse_checksum(&certutil).as_deref(), Some(digest));
+        assert_eq!(parse_checksum("not a checksum"), None);
+
+        let verified = [
+            (
+                PLATFORM_ASSETS[0].to_string(),
+                AssetStatus::Verified(digest.to_string()),
+            ),
+            (PLATFORM_ASSETS[1].to_string(), AssetStatus::Missing),
+        ];
+        let body = release_body(
+            "### Added\n- db plan\n",
+            &verified,
+            "## What's Changed\n* x",
+        );
+        assert!(body.starts_with("### Added\n- db plan\n\n## Checksums (SHA-256)\n"));
+        assert!(body.contains(&format!("| `{}` | `{}` |\n", PLATFORM_ASSETS[0], digest)));
+        assert!(!body.contains(PLATFORM_ASSETS[1]));
+        assert!(body.ends_with("## What's Changed\n* x\n"));
+        // Publishing again keeps one copy of the notes
+        assert_eq!(
+            release_body("### Added\n- db plan\n", &verified, &body),
+            body
+        );
+    }
+
+    #[test]
+    fn test_latest_release_per_channel() {
+        let release = |tag: &str, prerelease: bool| Release {
+            id: 1,
+            tag_name: tag.to_string(),
+            html_url: String::new(),
+            draft: false,
+            prerelease,
+            body: None,
+            assets: Vec::new(),
+        };
+        let releases = || {
+            vec![
+                release("app-v9.0.0", false),
+                release("cli-v1.4.0-beta.2", true),
+                release("cli-v1.3.1", false),
+                release("cli-v1.4.0-beta.10", true),
+                release("cli-v1.3.0", false),
+            ]
+        };
+
+        let stable = latest_release(releases(), ReleaseChannel::Stable).unwrap();
+        assert_eq!(stable.tag_name, "cli-v1.3.1");
+        let beta = latest_release(releases(), ReleaseChannel::Beta).unwrap();
+        assert_eq!(beta.tag_name, "cli-v1.4.0-beta.10");
+
+        let mut with_stable = releases();
+        with_stable.push(release("cli-v1.4.0", false));
+        let beta = latest_release(with_stable, ReleaseChannel::Beta).unwrap();
+        assert_eq!(beta.tag_name, "cli-v1.4.0");
+    }
+}
@@ -1,15 +1,34 @@
 //! Release command for CLI versioning and publishing
 //!
-//! Updates Cargo.toml version, creates git tag, and pushes to origin.
+//! Updates Cargo.toml version (and stamps CHANGELOG.md), creates git tag,
+//! pushes to origin, then waits for the GitHub Actions release and verifies
+//! its assets before publishing the release notes.
+//!
+//! Beta releases (`X.Y.Z-beta.N --channel beta`) are published as GitHub
+//! prereleases and only reach `akatsuki self-update --channel beta`.
 
 use anyhow::{Context, Result};
 use colored::*;
 use dialoguer::Confirm;
 use regex::Regex;
 use std::fs;
+use std::path::Path;
 use std::process::Command;
+use std::time::{Duration, Instant};
+
+mod changelog;
+pub mod github;
+pub mod version;
 
+use crate::cli::ReleaseChannel;
 use crate::utils::get_project_root;
+use changelog::CHANGELOG_PATH;
+use github::{AssetStatus, GitHubClient, PLATFORM_ASSETS};
+use version::Version;
+
+/// How often and how long to poll for the workflow-built release
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+const WAIT_TIMEOUT: Duration = Duration::from_secs(30 * 60);
 
 pub struct ReleaseCommand;
 
@@ -18,19 +37,39 @@ impl ReleaseCommand {
         Self
     }
 
-    pub fn execute(&self, version: &str, skip_confirm: bool) -> Result<()> {
+    pub fn execute(
+        &self,
+        version: &str,
+        channel: ReleaseChannel,
+        skip_confirm: bool,
+        verify_only: bool,
+        dry_run: bool,
+    ) -> Result<()> {
         println!("\n{}\n", "🚀 Akatsuki CLI Release".cyan().bold());
 
         // Validate version format
-        let version_re = Regex::new(r"^\d+\.\d+\.\d+$")?;
-        if !version_re.is_match(version) {
-            anyhow::bail!(
-                "Invalid version format: {}. Expected: X.Y.Z (e.g., 1.0.0)",
+        let parsed = Version::parse(version).with_context(|| {
+            format!(
+                "Invalid version format: {}. Expected: X.Y.Z (e.g., 1.0.0) or X.Y.Z-beta.N",
                 version
-            );
+            )
+        })?;
+        match channel {
+            ReleaseChannel::Stable if parsed.is_prerelease() => anyhow::bail!(
+                "{} is a prerelease version. Use --channel beta (or release X.Y.Z)",
+                version
+            ),
+            ReleaseChannel::Beta if !parsed.is_prerelease() => anyhow::bail!(
+                "Beta releases need a prerelease version like {}-beta.1",
+                version
+            ),
+            _ => {}
         }
 
         let root = get_project_root()?;
+        if verify_only {
+            return self.verify_release(&root, &format!("cli-v{}", version), version, channel);
+        }
         let cargo_toml_path = root.join("packages/akatsuki-cli/Cargo.toml");
 
         // Read current version
@@ -43,6 +82,9 @@ impl ReleaseCommand {
         println!("{} Current version: {}", "ℹ".blue(), current_version);
         println!("{} New version: {}", "ℹ".blue(), version.green());
         println!("{} Tag: {}", "ℹ".blue(), format!("cli-v{}", version).yellow());
+        if channel == ReleaseChannel::Beta {
+            println!("{} Channel: {} (GitHub prerelease)", "ℹ".blue(), "beta".yellow());
+        }
         println!();
 
         // Check for uncommitted changes
@@ -57,6 +99,10 @@ impl ReleaseCommand {
             println!();
         }
 
+        if dry_run {
+            return self.print_plan(&root, version, channel);
+        }
+
         // Confirm
         if !skip_confirm {
             let confirm = Confirm::new()
@@ -79,11 +125,25 @@ impl ReleaseCommand {
         fs::write(&cargo_toml_path, new_content)?;
         println!("{} Updated version to {}", "✓".green(), version);
 
+        // Step 1b: Stamp the Unreleased changelog section with the version
+        // (betas keep it Unreleased until the stable release)
+        let changelog_path = root.join(CHANGELOG_PATH);
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        if let Some(stamped) = fs::read_to_string(&changelog_path)
+            .ok()
+            .filter(|_| channel == ReleaseChannel::Stable)
+            .and_then(|content| changelog::stamp_unreleased(&content, version, &today))
+        {
+            fs::write(&changelog_path, stamped)?;
+            println!("{} Moved Unreleased changes to [{}] in CHANGELOG.md", "✓".green(), version);
+        }
+
         // Step 2: Git add and commit
         println!("\n{} Creating release commit...", "▸".magenta());
 
         let status = Command::new("git")
             .args(["add", "packages/akatsuki-cli/Cargo.toml"])
+            .args(changelog_path.exists().then_some(CHANGELOG_PATH))
             .current_dir(&root)
             .status()?;
 
@@ -162,21 +222,203 @@ impl ReleaseCommand {
         }
         println!("{} Pushed commit and tag to origin", "✓".green());
 
+        // Step 5: Wait for the GitHub Actions release and verify it
+        self.verify_release(&root, &tag, version, channel)
+    }
+
+    /// What a release would change, without touching files, git or GitHub
+    fn print_plan(
+        &self,
+        root: &Path,
+        version: &str,
+        channel: ReleaseChannel,
+    ) -> Result<()> {
+        let tag = format!("cli-v{}", version);
+        println!("{}", "Dry run - no changes made. A release would:".cyan().bold());
+        println!("  1. Set version = \"{}\" in packages/akatsuki-cli/Cargo.toml", version);
+        let stamps_changelog = channel == ReleaseChannel::Stable
+            && fs::read_to_string(root.join(CHANGELOG_PATH))
+                .ok()
+                .and_then(|content| changelog::stamp_unreleased(&content, version, "YYYY-MM-DD"))
+                .is_some();
+        if stamps_changelog {
+            println!("     and move Unreleased changes to [{}] in CHANGELOG.md", version);
+        }
+        println!("  2. Commit: chore(akatsuki-cli): Release v{}", version);
+        let tag_exists = Command::new("git")
+            .args(["rev-parse", &tag])
+            .current_dir(root)
+            .output()?
+            .status
+            .success();
+        if tag_exists {
+            println!("  3. Reuse existing tag {}", tag);
+        } else {
+            println!("  3. Create tag {}", tag);
+        }
+        println!("  4. Push the commit and tag to origin");
+        println!("  5. Wait for the GitHub release, verify its assets and publish the notes");
+        Ok(())
+    }
+
+    /// Poll the release of `tag` until every platform asset is uploaded,
+    /// verify the checksums and publish the release notes
+    fn verify_release(
+        &self,
+        root: &Path,
+        tag: &str,
+        version: &str,
+        channel: ReleaseChannel,
+    ) -> Result<()> {
+        println!("\n{} Waiting for the GitHub release...", "▸".magenta());
+        let repo = github::origin_repo(root)
+            .context("Could not determine the GitHub repository from the origin remote")?;
+        let client = GitHubClient::new(&repo)?;
+        if !client.has_token() {
+            println!(
+                "{} No GITHUB_TOKEN / GH_TOKEN (or gh login): using anonymous, rate-limited requests",
+                "⚠".yellow()
+            );
+        }
+
+        let started = Instant::now();
+        let release = loop {
+            let release = client.release_by_tag(tag)?;
+            let uploaded = release.as_ref().map_or(0, github::uploaded_count);
+            match release {
+                Some(release) if uploaded == PLATFORM_ASSETS.len() => break Some(release),
+                release if started.elapsed() >= WAIT_TIMEOUT => break release,
+                _ => {}
+            }
+            println!(
+                "  ⏳ {}/{} platform assets uploaded, checking again in {}s",
+                uploaded,
+                PLATFORM_ASSETS.len(),
+                POLL_INTERVAL.as_secs()
+            );
+            std::thread::sleep(POLL_INTERVAL);
+        };
+
+        let Some(release) = release else {
+            anyhow::bail!(
+                "No GitHub release for {} after {} minutes. Check the workflow: https://github.com/{}/actions\nRe-run the verification with: akatsuki release {} --channel {} --verify-only",
+                tag,
+                WAIT_TIMEOUT.as_secs() / 60,
+                repo,
+                version,
+                channel.as_str()
+            );
+        };
+
+        println!("\n{} Verifying assets...", "▸".magenta());
+        let results = github::verify_assets(&client, &release)?;
+        for (name, status) in &results {
+            match status {
+                AssetStatus::Verified(digest) => {
+                    println!("{} {} ({})", "✓".green(), name, &digest[..12])
+                }
+                AssetStatus::Missing => println!("{} {}: missing", "✗".red(), name),
+                AssetStatus::MissingChecksum => {
+                    println!("{} {}: no .sha256 file", "✗".red(), name)
+                }
+                AssetStatus::InvalidChecksum => {
+                    println!("{} {}: .sha256 file has no SHA-256 digest", "✗".red(), name)
+                }
+                AssetStatus::Mismatch { expected, actual } => println!(
+                    "{} {}: checksum mismatch (expected {}, got {})",
+                    "✗".red(),
+                    name,
+                    expected,
+                    actual
+                ),
+            }
+        }
+        let verified = results.iter().filter(|(_, status)| status.is_ok()).count();
+        if verified != results.len() {
+            anyhow::bail!(
+                "Release {} is incomplete: {}/{} platform assets verified ({})",
+                tag,
+                verified,
+                results.len(),
+                release.html_url
+            );
+        }
+
+        println!("\n{} Publishing release notes...", "▸".magenta());
+        let notes = fs::read_to_string(root.join(CHANGELOG_PATH))
+            .ok()
+            .and_then(|content| {
+                changelog::section(&content, version).or_else(|| {
+                    (channel == ReleaseChannel::Beta)
+                        .then(|| changelog::section(&content, "Unreleased"))
+                        .flatten()
+                })
+            });
+        let notes = match notes {
+            Some(notes) => notes,
+            None => {
+                println!(
+                    "{} No [{}] section in CHANGELOG.md, using the commit subjects",
+                    "ℹ".blue(),
+                    version
+                );
+                commit_notes(root, tag)
+            }
+        };
+        let body = github::release_body(&notes, &results, release.body.as_deref().unwrap_or(""));
+        let prerelease = channel == ReleaseChannel::Beta;
+        client.update(&release, &body, prerelease)?;
+        println!(
+            "{} Release notes published{}",
+            "✓".green(),
+            if prerelease { " (prerelease)" } else { "" }
+        );
+
         // Summary
         println!("\n{}\n", "🎉 Release Complete!".cyan().bold());
         println!("Version: {}", version.green());
         println!("Tag: {}", tag.yellow());
+        println!("Release: {}", release.html_url);
         println!();
-        println!("{}", "Next steps:".bold());
-        println!("1. GitHub Actions will build and create the release");
-        println!("2. Update Formula/akatsuki.rb with new SHA256 hashes");
-        println!("3. Check: https://github.com/ynishi/akatsuki/releases");
+        match channel {
+            ReleaseChannel::Stable => {
+                println!("The workflow updates Formula/akatsuki.rb with the new SHA256 hashes.")
+            }
+            ReleaseChannel::Beta => {
+                println!("Dogfood it with: akatsuki self-update --channel beta")
+            }
+        }
         println!();
 
         Ok(())
     }
 }
 
+/// `- <subject>` for each CLI commit since the previous `cli-v*` tag
+fn commit_notes(root: &Path, tag: &str) -> String {
+    let previous = Command::new("git")
+        .args(["describe", "--tags", "--abbrev=0", "--match", "cli-v*"])
+        .arg(format!("{}^", tag))
+        .current_dir(root)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+    let range = match previous {
+        Some(previous) => format!("{}..{}", previous, tag),
+        None => tag.to_string(),
+    };
+
+    Command::new("git")
+        .args(["log", "--pretty=format:- %s", &range, "--", "packages/akatsuki-cli"])
+        .current_dir(root)
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|notes| !notes.is_empty())
+        .unwrap_or_else(|| format!("Release {}", tag))
+}
+
 /// Extract version from Cargo.toml content
 fn extract_version(content: &str) -> Option<String> {
     let re = Regex::new(r#"^version\s*=\s*"([^"]+)""#).ok()?;
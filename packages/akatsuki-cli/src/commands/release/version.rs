@@ -0,0 +1,101 @@
+//! CLI versions (`X.Y.Z` or `X.Y.Z-<prerelease>`), ordered as SemVer
+
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    core: (u64, u64, u64),
+    /// Dot-separated identifiers after `-` (empty for stable versions)
+    pre: Vec<String>,
+}
+
+impl Version {
+    /// Parses `1.4.0`, `1.4.0-beta.1` or a `cli-v` tag
+    pub fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.strip_prefix("cli-v").unwrap_or(raw);
+        let (core, pre) = match raw.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (raw, None),
+        };
+        let numbers: Vec<u64> = core
+            .split('.')
+            .map(|part| part.parse().ok())
+            .collect::<Option<_>>()?;
+        let [major, minor, patch] = numbers[..] else {
+            return None;
+        };
+        let pre: Vec<String> = match pre {
+            Some(pre) => pre.split('.').map(str::to_string).collect(),
+            None => Vec::new(),
+        };
+        let valid_pre = pre
+            .iter()
+            .all(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'));
+        valid_pre.then_some(Self {
+            core: (major, minor, patch),
+            pre,
+        })
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.core.cmp(&other.core).then_with(|| {
+            match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                // A prerelease comes before its release
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => compare_pre(&self.pre, &other.pre),
+            }
+        })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Numeric identifiers compare as numbers and sort before alphanumeric ones
+fn compare_pre(a: &[String], b: &[String]) -> Ordering {
+    for (x, y) in a.iter().zip(b) {
+        let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => x.cmp(y),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orders_prereleases_before_releases() {
+        let v = |raw: &str| Version::parse(raw).unwrap();
+        assert!(v("1.4.0-beta.1") < v("1.4.0-beta.2"));
+        assert!(v("1.4.0-beta.2") < v("1.4.0-beta.10"));
+        assert!(v("1.4.0-alpha.3") < v("1.4.0-beta.1"));
+        assert!(v("1.4.0-beta.10") < v("1.4.0"));
+        assert!(v("1.3.9") < v("1.4.0-beta.1"));
+        assert_eq!(v("cli-v1.4.0"), v("1.4.0"));
+        assert!(v("1.4.0-rc.1").is_prerelease());
+        assert!(!v("1.4.0").is_prerelease());
+
+        assert!(Version::parse("1.4").is_none());
+        assert!(Version::parse("1.4.0-").is_none());
+        assert!(Version::parse("1.4.0-beta..1").is_none());
+    }
+}
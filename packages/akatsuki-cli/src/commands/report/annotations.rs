@@ -0,0 +1,292 @@
+/**
+ * CI Annotations
+ * Extract file/line diagnostics from tool output (tsc, eslint, rustc/clippy, cargo test, vitest)
+ * and format them as GitHub Actions workflow commands
+ */
+use regex::Regex;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::utils::platform;
+
+/// GitHub shows at most 10 annotations per step; keep a little headroom for the report
+const MAX_PER_STEP: usize = 50;
+
+struct Patterns {
+    ansi: Regex,
+    tsc: Regex,
+    rustc_error: Regex,
+    rustc_location: Regex,
+    eslint_file: Regex,
+    eslint_problem: Regex,
+    panic: Regex,
+    vitest_fail: Regex,
+}
+
+impl Patterns {
+    fn new() -> Self {
+        let re = |pattern: &str| Regex::new(pattern).unwrap();
+        Self {
+            ansi: re(r"\x1b\[[0-9;]*m"),
+            // src/App.tsx(12,5): error TS2322: ...
+            tsc: re(
+                r"^(?P<file>[^\s(][^(]*)\((?P<line>\d+),(?P<col>\d+)\): error (?P<msg>TS\d+: .*)$",
+            ),
+            // error[E0308]: mismatched types
+            rustc_error: re(r"^error(?:\[(?P<code>E\d+)\])?: (?P<msg>.+)$"),
+            //   --> src/main.rs:10:5
+            rustc_location: re(r"^\s*--> (?P<file>[^:\s]+):(?P<line>\d+):(?P<col>\d+)"),
+            // eslint "stylish": absolute file path, then indented problems
+            eslint_file: re(r"^(?P<file>/\S+\.(?:[jt]sx?|mjs|cjs))$"),
+            eslint_problem: re(
+                r"^\s+(?P<line>\d+):(?P<col>\d+)\s+error\s+(?P<msg>.+?)(?:\s{2,}(?P<rule>\S+))?$",
+            ),
+            // thread 'tests::it_works' panicked at src/lib.rs:20:9:
+            panic: re(
+                r"^thread '(?P<test>[^']+)' panicked at (?P<file>[^:\s]+):(?P<line>\d+):(?P<col>\d+):?$",
+            ),
+            //  FAIL  src/models/Article.test.ts > Article > parses dates
+            vitest_fail: re(
+                r"^\s*FAIL\s+(?P<file>\S+\.(?:test|spec)\.[jt]sx?)\s+>\s+(?P<name>.+)$",
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Annotation {
+    /// Path relative to the project root
+    pub file: String,
+    pub line: Option<u32>,
+    pub col: Option<u32>,
+    pub message: String,
+}
+
+/// Diagnostics found in `output` (deduplicated, capped per step)
+pub fn parse(output: &str, project_root: &Path) -> Vec<Annotation> {
+    let patterns = Patterns::new();
+    let output = patterns.ansi.replace_all(output, "");
+    let lines: Vec<&str> = output.lines().collect();
+    let mut annotations: Vec<Annotation> = Vec::new();
+    let mut eslint_file: Option<String> = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        let found = if let Some(caps) = patterns.tsc.captures(line) {
+            Some(annotation(
+                project_root,
+                &caps["file"],
+                Some(&caps["line"]),
+                Some(&caps["col"]),
+                &caps["msg"],
+            ))
+        } else if let Some(caps) = patterns.rustc_error.captures(line) {
+            // The location follows on one of the next lines: `  --> src/main.rs:10:5`
+            lines[i + 1..]
+                .iter()
+                .take(4)
+                .find_map(|next| patterns.rustc_location.captures(next))
+                .map(|location| {
+                    let message = match caps.name("code") {
+                        Some(code) => format!("{}: {}", code.as_str(), &caps["msg"]),
+                        None => caps["msg"].to_string(),
+                    };
+                    annotation(
+                        project_root,
+                        &location["file"],
+                        Some(&location["line"]),
+                        Some(&location["col"]),
+                        &message,
+                    )
+                })
+        } else if let Some(caps) = patterns.eslint_file.captures(line) {
+            eslint_file = Some(caps["file"].to_string());
+            None
+        } else if let (Some(file), Some(caps)) =
+            (&eslint_file, patterns.eslint_problem.captures(line))
+        {
+            let message = match caps.name("rule") {
+                Some(rule) => format!("{} ({})", &caps["msg"], rule.as_str()),
+                None => caps["msg"].to_string(),
+            };
+            Some(annotation(
+                project_root,
+                file,
+                Some(&caps["line"]),
+                Some(&caps["col"]),
+                &message,
+            ))
+        } else if let Some(caps) = patterns.panic.captures(line) {
+            let detail = lines.get(i + 1).map(|next| next.trim()).unwrap_or_default();
+            let message = format!("{} panicked: {}", &caps["test"], detail);
+            Some(annotation(
+                project_root,
+                &caps["file"],
+                Some(&caps["line"]),
+                Some(&caps["col"]),
+                &message,
+            ))
+        } else if let Some(caps) = patterns.vitest_fail.captures(line) {
+            let message = format!("Test failed: {}", caps["name"].trim());
+            Some(annotation(
+                project_root,
+                &caps["file"],
+                None,
+                None,
+                &message,
+            ))
+        } else {
+            if line.trim().is_empty() {
+                eslint_file = None;
+            }
+            None
+        };
+
+        if let Some(found) = found {
+            if !annotations.contains(&found) {
+                annotations.push(found);
+            }
+        }
+        if annotations.len() >= MAX_PER_STEP {
+            break;
+        }
+    }
+
+    annotations
+}
+
+/// `::error file=...,line=...,col=...,title=...::message`
+pub fn workflow_command(title: &str, annotation: &Annotation) -> String {
+    let mut properties = vec![format!("file={}", escape_property(&annotation.file))];
+    if let Some(line) = annotation.line {
+        properties.push(format!("line={}", line));
+    }
+    if let Some(col) = annotation.col {
+        properties.push(format!("col={}", col));
+    }
+    properties.push(format!("title={}", escape_property(title)));
+    format!(
+        "::error {}::{}",
+        properties.join(","),
+        escape_data(&annotation.message)
+    )
+}
+
+/// `::error title=...::message` for failures without a location
+pub fn workflow_error(title: &str, message: &str) -> String {
+    format!(
+        "::error title={}::{}",
+        escape_property(title),
+        escape_data(message)
+    )
+}
+
+fn annotation(
+    project_root: &Path,
+    file: &str,
+    line: Option<&str>,
+    col: Option<&str>,
+    message: &str,
+) -> Annotation {
+    Annotation {
+        file: resolve_path(project_root, file.trim()),
+        line: line.and_then(|value| value.parse().ok()),
+        col: col.and_then(|value| value.parse().ok()),
+        message: message.trim().to_string(),
+    }
+}
+
+/// Tools print paths relative to the package they ran in; annotations need
+/// them relative to the repository root
+fn resolve_path(project_root: &Path, file: &str) -> String {
+    let path = Path::new(file);
+    let relative = if path.is_absolute() {
+        path.strip_prefix(project_root)
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|_| path.to_path_buf())
+    } else if project_root.join(path).exists() {
+        path.to_path_buf()
+    } else {
+        std::fs::read_dir(project_root.join("packages"))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| Path::new("packages").join(entry.file_name()).join(path))
+            .find(|candidate| project_root.join(candidate).exists())
+            .unwrap_or_else(|| path.to_path_buf())
+    };
+    platform::slash_path(&relative)
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tool_output() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("packages/app-backend/src")).unwrap();
+        std::fs::write(root.path().join("packages/app-backend/src/main.rs"), "").unwrap();
+
+        let output = format!(
+            "src/App.tsx(12,5): error TS2322: Type 'string' is not assignable to type 'number'.\n\
+             \x1b[1merror[E0308]\x1b[0m: mismatched types\n  --> src/main.rs:10:5\n   |\n\n\
+             {root}/packages/app-frontend/src/pages/Home.tsx\n  \
+             3:10  error  'x' is assigned a value but never used  no-unused-vars\n\n\
+             thread 'tests::it_works' panicked at src/main.rs:20:9:\nassertion failed\n \
+             FAIL  src/models/Article.test.ts > Article > parses dates\n",
+            root = root.path().display()
+        );
+        let annotations = parse(&output, root.path());
+
+        assert_eq!(annotations.len(), 5);
+        assert_eq!(annotations[0].file, "src/App.tsx");
+        assert_eq!(
+            (annotations[0].line, annotations[0].col),
+            (Some(12), Some(5))
+        );
+        assert_eq!(annotations[1].file, "packages/app-backend/src/main.rs");
+        assert_eq!(annotations[1].message, "E0308: mismatched types");
+        assert_eq!(
+            annotations[2].file,
+            "packages/app-frontend/src/pages/Home.tsx"
+        );
+        assert_eq!(
+            annotations[2].message,
+            "'x' is assigned a value but never used (no-unused-vars)"
+        );
+        assert_eq!(
+            annotations[3].message,
+            "tests::it_works panicked: assertion failed"
+        );
+        assert_eq!(annotations[4].line, None);
+    }
+
+    #[test]
+    fn test_workflow_command() {
+        let annotation = Annotation {
+            file: "src/a,b.ts".to_string(),
+            line: Some(3),
+            col: None,
+            message: "50% done\nnext".to_string(),
+        };
+        assert_eq!(
+            workflow_command("lint", &annotation),
+            "::error file=src/a%2Cb.ts,line=3,title=lint::50%25 done%0Anext"
+        );
+        assert_eq!(
+            workflow_error("db: check", "failed"),
+            "::error title=db%3A check::failed"
+        );
+    }
+}
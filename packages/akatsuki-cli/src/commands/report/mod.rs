@@ -0,0 +1,460 @@
+/**
+ * CI Report
+ * check / lint / test / docs lint / db check を実行し、Markdown + JSON レポートにまとめる
+ *
+ * Each step runs as a separate `akatsuki` process so one failure doesn't stop
+ * the rest. Failures found in the tool output become GitHub Actions
+ * annotations (`::error file=...`) when running under Actions or with --annotations.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::cli::{CiStep, ReportAction};
+use crate::utils::duration::format_duration;
+use crate::utils::find_project_root;
+use crate::utils::output::OutputFormatter;
+use crate::utils::process::TimedCommand;
+
+mod annotations;
+
+use annotations::Annotation;
+
+/// Lines of output kept for failed steps
+const OUTPUT_TAIL_LINES: usize = 40;
+
+pub struct ReportCommand;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum StepStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+#[derive(Debug, Serialize)]
+struct StepResult {
+    name: &'static str,
+    command: String,
+    status: StepStatus,
+    duration_ms: u64,
+    annotations: Vec<Annotation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_tail: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CiReport {
+    generated_at: String,
+    success: bool,
+    duration_ms: u64,
+    steps: Vec<StepResult>,
+}
+
+impl CiStep {
+    const ALL: [CiStep; 5] = [
+        CiStep::Check,
+        CiStep::Lint,
+        CiStep::Test,
+        CiStep::DocsLint,
+        CiStep::DbCheck,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            CiStep::Check => "check",
+            CiStep::Lint => "lint",
+            CiStep::Test => "test",
+            CiStep::DocsLint => "docs-lint",
+            CiStep::DbCheck => "db-check",
+        }
+    }
+
+    /// `akatsuki` arguments for this step
+    fn args(self) -> &'static [&'static str] {
+        match self {
+            CiStep::Check => &["check"],
+            CiStep::Lint => &["lint"],
+            CiStep::Test => &["test"],
+            CiStep::DocsLint => &["docs", "lint"],
+            CiStep::DbCheck => &["db", "check"],
+        }
+    }
+}
+
+impl ReportCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, action: ReportAction, output: OutputFormatter) -> Result<()> {
+        match action {
+            ReportAction::Ci {
+                skip,
+                out_dir,
+                annotations,
+            } => self.ci(&skip, out_dir, annotations, output),
+        }
+    }
+
+    fn ci(
+        &self,
+        skip: &[CiStep],
+        out_dir: PathBuf,
+        annotations: bool,
+        output: OutputFormatter,
+    ) -> Result<()> {
+        let project_root = find_project_root();
+        let exe = std::env::current_exe().context("Failed to locate the akatsuki binary")?;
+        let annotate = annotations || std::env::var("GITHUB_ACTIONS").is_ok_and(|v| v == "true");
+
+        if !output.is_json() {
+            println!("{}", "📋 Generating CI report...".cyan().bold());
+            println!();
+        }
+
+        let started = Instant::now();
+        let steps: Vec<StepResult> = CiStep::ALL
+            .into_iter()
+            .map(|step| {
+                if skip.contains(&step) {
+                    if !output.is_json() {
+                        println!("  {} {}", "⏭️".bright_black(), step.name().bright_black());
+                    }
+                    return Ok(skipped(step));
+                }
+                let result = run_step(&exe, &project_root, step)?;
+                if !output.is_json() {
+                    print_step(&result);
+                }
+                Ok(result)
+            })
+            .collect::<Result<_>>()?;
+
+        let report = CiReport {
+            generated_at: chrono::Utc::now().to_rfc3339(),
+            success: steps.iter().all(|step| step.status != StepStatus::Failed),
+            duration_ms: millis(started.elapsed()),
+            steps,
+        };
+
+        if annotate {
+            for step in &report.steps {
+                for line in workflow_commands(step) {
+                    // Actions reads workflow commands from stderr too; stdout is the JSON document
+                    if output.is_json() {
+                        eprintln!("{}", line);
+                    } else {
+                        println!("{}", line);
+                    }
+                }
+            }
+        }
+
+        let markdown = render_markdown(&report);
+        let out_dir = project_root.join(out_dir);
+        fs::create_dir_all(&out_dir)
+            .with_context(|| format!("Failed to create {}", out_dir.display()))?;
+        let markdown_path = out_dir.join("ci.md");
+        let json_path = out_dir.join("ci.json");
+        fs::write(&markdown_path, &markdown)
+            .with_context(|| format!("Failed to write {}", markdown_path.display()))?;
+        fs::write(&json_path, serde_json::to_string_pretty(&report)?)
+            .with_context(|| format!("Failed to write {}", json_path.display()))?;
+
+        // Shown on the workflow run page
+        if let Ok(summary_path) = std::env::var("GITHUB_STEP_SUMMARY") {
+            append(Path::new(&summary_path), &markdown)?;
+        }
+
+        output.emit(&report, |_| {
+            println!();
+            println!(
+                "{} {}",
+                "📄 Report:".bright_black(),
+                display_path(&project_root, &markdown_path)
+            );
+            println!(
+                "{} {}",
+                "📄 JSON:  ".bright_black(),
+                display_path(&project_root, &json_path)
+            );
+        })?;
+
+        let failed = report
+            .steps
+            .iter()
+            .filter(|step| step.status == StepStatus::Failed)
+            .count();
+        if failed > 0 {
+            return Err(output.failed(format!("{} CI step(s) failed", failed)));
+        }
+        if !output.is_json() {
+            println!("{}", "✨ All CI steps passed!".green().bold());
+        }
+        Ok(())
+    }
+}
+
+fn run_step(exe: &Path, project_root: &Path, step: CiStep) -> Result<StepResult> {
+    let started = Instant::now();
+    let output = Command::new(exe)
+        .args(step.args())
+        .current_dir(project_root)
+        .env("NO_COLOR", "1")
+        .env("CARGO_TERM_COLOR", "never")
+        .env("FORCE_COLOR", "0")
+        // Keep anyhow backtraces out of the captured output
+        .env("RUST_LIB_BACKTRACE", "0")
+        .timed_output()
+        .with_context(|| format!("Failed to run akatsuki {}", step.args().join(" ")))?;
+    let duration = started.elapsed();
+
+    let combined = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let success = output.status.success();
+
+    Ok(StepResult {
+        name: step.name(),
+        command: format!("akatsuki {}", step.args().join(" ")),
+        status: if success {
+            StepStatus::Passed
+        } else {
+            StepStatus::Failed
+        },
+        duration_ms: millis(duration),
+        annotations: if success {
+            Vec::new()
+        } else {
+            annotations::parse(&combined, project_root)
+        },
+        output_tail: (!success).then(|| tail(&combined, OUTPUT_TAIL_LINES)),
+    })
+}
+
+fn skipped(step: CiStep) -> StepResult {
+    StepResult {
+        name: step.name(),
+        command: format!("akatsuki {}", step.args().join(" ")),
+        status: StepStatus::Skipped,
+        duration_ms: 0,
+        annotations: Vec::new(),
+        output_tail: None,
+    }
+}
+
+fn print_step(step: &StepResult) {
+    let duration = format_duration(step.duration_ms).bright_black();
+    match step.status {
+        StepStatus::Passed => println!("  {} {}  {}", "✅".green(), step.name, duration),
+        _ => {
+            println!("  {} {}  {}", "❌".red(), step.name.red().bold(), duration);
+            for annotation in step.annotations.iter().take(5) {
+                println!(
+                    "     {} {}",
+                    location(annotation).bright_white(),
+                    annotation.message
+                );
+            }
+            if step.annotations.len() > 5 {
+                println!(
+                    "     {}",
+                    format!("... and {} more", step.annotations.len() - 5).bright_black()
+                );
+            }
+        }
+    }
+}
+
+fn workflow_commands(step: &StepResult) -> Vec<String> {
+    if step.status != StepStatus::Failed {
+        return Vec::new();
+    }
+    if step.annotations.is_empty() {
+        return vec![annotations::workflow_error(
+            step.name,
+            &format!("{} failed", step.command),
+        )];
+    }
+    step.annotations
+        .iter()
+        .map(|annotation| annotations::workflow_command(step.name, annotation))
+        .collect()
+}
+
+fn render_markdown(report: &CiReport) -> String {
+    let mut md = String::new();
+    md.push_str("# CI Report\n\n");
+    md.push_str(&format!(
+        "{}\n\n",
+        if report.success {
+            badge("ci", "passed", "brightgreen")
+        } else {
+            badge("ci", "failed", "red")
+        }
+    ));
+
+    md.push_str("| Step | Result | Duration | Command |\n");
+    md.push_str("|------|--------|----------|---------|\n");
+    for step in &report.steps {
+        let (message, color) = match step.status {
+            StepStatus::Passed => ("passed", "brightgreen"),
+            StepStatus::Failed => ("failed", "red"),
+            StepStatus::Skipped => ("skipped", "lightgrey"),
+        };
+        let duration = if step.status == StepStatus::Skipped {
+            "-".to_string()
+        } else {
+            format_duration(step.duration_ms)
+        };
+        md.push_str(&format!(
+            "| {} | {} | {} | `{}` |\n",
+            step.name,
+            badge(step.name, message, color),
+            duration,
+            step.command
+        ));
+    }
+    md.push_str(&format!(
+        "\n**Total:** {} · generated {}\n",
+        format_duration(report.duration_ms),
+        report.generated_at
+    ));
+
+    for step in report
+        .steps
+        .iter()
+        .filter(|step| step.status == StepStatus::Failed)
+    {
+        md.push_str(&format!("\n## ❌ {}\n\n", step.name));
+        for annotation in &step.annotations {
+            md.push_str(&format!(
+                "- `{}` {}\n",
+                location(annotation),
+                annotation.message
+            ));
+        }
+        if !step.annotations.is_empty() {
+            md.push('\n');
+        }
+        if let Some(output) = &step.output_tail {
+            md.push_str(&format!(
+                "<details><summary>Output (last {} lines)</summary>\n\n```\n{}\n```\n\n</details>\n",
+                OUTPUT_TAIL_LINES, output
+            ));
+        }
+    }
+
+    md
+}
+
+/// shields.io static badge
+fn badge(label: &str, message: &str, color: &str) -> String {
+    let escape = |text: &str| text.replace('-', "--").replace('_', "__").replace(' ', "_");
+    format!(
+        "![{}: {}](https://img.shields.io/badge/{}-{}-{})",
+        label,
+        message,
+        escape(label),
+        escape(message),
+        color
+    )
+}
+
+fn location(annotation: &Annotation) -> String {
+    match (annotation.line, annotation.col) {
+        (Some(line), Some(col)) => format!("{}:{}:{}", annotation.file, line, col),
+        (Some(line), None) => format!("{}:{}", annotation.file, line),
+        _ => annotation.file.clone(),
+    }
+}
+
+fn tail(output: &str, lines: usize) -> String {
+    let all: Vec<&str> = output.trim_end().lines().collect();
+    all[all.len().saturating_sub(lines)..].join("\n")
+}
+
+fn append(path: &Path, content: &str) -> Result<()> {
+    use std::io::Write;
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", content)?;
+    Ok(())
+}
+
+fn display_path(project_root: &Path, path: &Path) -> String {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+fn millis(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_markdown() {
+        let report = CiReport {
+            generated_at: "2026-01-01T00:00:00+00:00".to_string(),
+            success: false,
+            duration_ms: 75_000,
+            steps: vec![
+                StepResult {
+                    name: "docs-lint",
+                    command: "akatsuki docs lint".to_string(),
+                    status: StepStatus::Passed,
+                    duration_ms: 1_200,
+                    annotations: Vec::new(),
+                    output_tail: None,
+                },
+                StepResult {
+                    name: "lint",
+                    command: "akatsuki lint".to_string(),
+                    status: StepStatus::Failed,
+                    duration_ms: 30_000,
+                    annotations: vec![Annotation {
+                        file: "src/App.tsx".to_string(),
+                        line: Some(3),
+                        col: Some(1),
+                        message: "Unexpected any".to_string(),
+                    }],
+                    output_tail: Some("1 problem".to_string()),
+                },
+                skipped(CiStep::DbCheck),
+            ],
+        };
+        let md = render_markdown(&report);
+
+        assert!(md.contains("![ci: failed](https://img.shields.io/badge/ci-failed-red)"));
+        assert!(md.contains(
+            "| docs-lint | ![docs-lint: passed](https://img.shields.io/badge/docs--lint-passed-brightgreen) | 1.2s |"
+        ));
+        assert!(md.contains("| db-check | ![db-check: skipped]"));
+        assert!(md.contains("**Total:** 1m15s"));
+        assert!(md.contains("## ❌ lint\n\n- `src/App.tsx:3:1` Unexpected any\n\n<details>"));
+        assert!(!md.contains("## ❌ docs-lint"));
+
+        let commands = workflow_commands(&report.steps[1]);
+        assert_eq!(
+            commands,
+            vec!["::error file=src/App.tsx,line=3,col=1,title=lint::Unexpected any"]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+/**
+ * Task Graph
+ * Selection, dependency ordering and --affected filtering for `akatsuki run`
+ */
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::utils::config::TaskConfig;
+
+pub struct TaskGraph<'a> {
+    tasks: &'a BTreeMap<String, TaskConfig>,
+}
+
+impl<'a> TaskGraph<'a> {
+    /// Validates that every `depends_on` entry exists and that there are no cycles
+    pub fn new(tasks: &'a BTreeMap<String, TaskConfig>) -> Result<Self> {
+        for (name, task) in tasks {
+            if task.command.trim().is_empty() {
+                anyhow::bail!("Task '{}' has no command", name);
+            }
+            for dependency in &task.depends_on {
+                if !tasks.contains_key(dependency) {
+                    anyhow::bail!("Task '{}' depends on unknown task '{}'", name, dependency);
+                }
+            }
+        }
+
+        let graph = Self { tasks };
+        let all: Vec<String> = tasks.keys().cloned().collect();
+        graph.execution_order(&all)?;
+        Ok(graph)
+    }
+
+    pub fn task(&self, name: &str) -> &'a TaskConfig {
+        &self.tasks[name]
+    }
+
+    /// `name` itself, or every `<package>:<name>` task (`build` → `frontend:build`, `backend:build`)
+    pub fn select(&self, name: &str) -> Result<Vec<String>> {
+        if self.tasks.contains_key(name) {
+            return Ok(vec![name.to_string()]);
+        }
+
+        let suffix = format!(":{}", name);
+        let selected: Vec<String> = self
+            .tasks
+            .keys()
+            .filter(|task| task.ends_with(&suffix))
+            .cloned()
+            .collect();
+        if selected.is_empty() {
+            anyhow::bail!(
+                "Unknown task '{}' (defined: {})",
+                name,
+                self.tasks.keys().cloned().collect::<Vec<_>>().join(", ")
+            );
+        }
+        Ok(selected)
+    }
+
+    /// `roots` and everything they depend on, dependencies first
+    pub fn execution_order(&self, roots: &[String]) -> Result<Vec<String>> {
+        let mut order = Vec::new();
+        let mut done = BTreeSet::new();
+        let mut path = Vec::new();
+        for root in roots {
+            self.visit(root, &mut path, &mut done, &mut order)?;
+        }
+        Ok(order)
+    }
+
+    fn visit(
+        &self,
+        name: &str,
+        path: &mut Vec<String>,
+        done: &mut BTreeSet<String>,
+        order: &mut Vec<String>,
+    ) -> Result<()> {
+        if done.contains(name) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|task| task == name) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(name.to_string());
+            anyhow::bail!("Task cycle: {}", cycle.join(" -> "));
+        }
+
+        path.push(name.to_string());
+        for dependency in &self.tasks[name].depends_on {
+            self.visit(dependency, path, done, order)?;
+        }
+        path.pop();
+
+        done.insert(name.to_string());
+        order.push(name.to_string());
+        Ok(())
+    }
+
+    /// Tasks with a changed file under one of their inputs, plus every task
+    /// depending on them (directly or transitively)
+    pub fn affected(&self, changed: &[String]) -> BTreeSet<String> {
+        let mut affected: BTreeSet<String> = self
+            .tasks
+            .iter()
+            .filter(|(_, task)| {
+                task.inputs.iter().any(|input| {
+                    let input = input.trim_end_matches('/');
+                    changed.iter().any(|file| {
+                        file == input
+                            || file
+                                .strip_prefix(input)
+                                .is_some_and(|rest| rest.starts_with('/'))
+                    })
+                })
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        // Propagate to dependents until nothing changes
+        loop {
+            let dependents: Vec<String> = self
+                .tasks
+                .iter()
+                .filter(|(name, task)| {
+                    !affected.contains(*name)
+                        && task.depends_on.iter().any(|dep| affected.contains(dep))
+                })
+                .map(|(name, _)| name.clone())
+                .collect();
+            if dependents.is_empty() {
+                return affected;
+            }
+            affected.extend(dependents);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(inputs: &[&str], depends_on: &[&str]) -> TaskConfig {
+        TaskConfig {
+            command: "true".to_string(),
+            inputs: inputs.iter().map(|s| s.to_string()).collect(),
+            depends_on: depends_on.iter().map(|s| s.to_string()).collect(),
+            ..TaskConfig::default()
+        }
+    }
+
+    fn tasks() -> BTreeMap<String, TaskConfig> {
+        BTreeMap::from([
+            ("wasm:build".to_string(), task(&["wasm-modules"], &[])),
+            (
+                "frontend:build".to_string(),
+                task(&["packages/app-frontend/src"], &["wasm:build", "codegen"]),
+            ),
+            ("codegen".to_string(), task(&["supabase/schemas/"], &[])),
+            (
+                "backend:build".to_string(),
+                task(&["packages/app-backend"], &["codegen"]),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_select_and_order() {
+        let tasks = tasks();
+        let graph = TaskGraph::new(&tasks).unwrap();
+
+        let selected = graph.select("build").unwrap();
+        assert_eq!(selected, ["backend:build", "frontend:build", "wasm:build"]);
+        assert_eq!(
+            graph.execution_order(&selected).unwrap(),
+            ["codegen", "backend:build", "wasm:build", "frontend:build"]
+        );
+        assert_eq!(graph.select("codegen").unwrap(), ["codegen"]);
+        assert!(graph.select("deploy").is_err());
+    }
+
+    #[test]
+    fn test_rejects_cycles_and_unknown_dependencies() {
+        let mut tasks = tasks();
+        tasks.get_mut("codegen").unwrap().depends_on = vec!["frontend:build".to_string()];
+        let error = TaskGraph::new(&tasks).err().unwrap().to_string();
+        assert!(
+            error.contains("codegen -> frontend:build -> codegen"),
+            "{}",
+            error
+        );
+
+        let mut tasks = self::tasks();
+        tasks.get_mut("codegen").unwrap().depends_on = vec!["lint".to_string()];
+        assert!(TaskGraph::new(&tasks).is_err());
+    }
+
+    #[test]
+    fn test_affected_propagates_to_dependents() {
+        let tasks = tasks();
+        let graph = TaskGraph::new(&tasks).unwrap();
+
+        let affected = graph.affected(&["supabase/schemas/articles.yaml".to_string()]);
+        assert_eq!(
+            affected.into_iter().collect::<Vec<_>>(),
+            ["backend:build", "codegen", "frontend:build"]
+        );
+
+        let affected = graph.affected(&["wasm-modules/sample-module/src/lib.rs".to_string()]);
+        assert_eq!(
+            affected.into_iter().collect::<Vec<_>>(),
+            ["frontend:build", "wasm:build"]
+        );
+
+        // Prefix match is per path segment
+        assert!(graph
+            .affected(&["packages/app-backend-old/README.md".to_string()])
+            .is_empty());
+    }
+}
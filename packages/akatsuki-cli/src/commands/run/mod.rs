@@ -0,0 +1,294 @@
+/**
+ * Run Command
+ * Task graph from akatsuki.toml `[tasks.<name>]`: dependencies first, cached
+ * on the contents of each task's inputs (.akatsuki/cache/tasks.json)
+ *
+ * `--affected` only runs tasks whose inputs changed since the merge base with
+ * the base branch (plus the tasks depending on them).
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+mod graph;
+
+use crate::utils::config::{ProjectConfig, TaskConfig};
+use crate::utils::content_hash::ContentHasher;
+use crate::utils::find_project_root;
+use crate::utils::git::git;
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+use graph::TaskGraph;
+
+pub const CACHE_PATH: &str = ".akatsuki/cache/tasks.json";
+
+/// Bump when the cache key computation changes
+const CACHE_VERSION: u32 = 1;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TaskCache {
+    version: u32,
+    /// Task name → key of its last successful run
+    keys: BTreeMap<String, String>,
+}
+
+pub struct RunCommand {
+    project_root: PathBuf,
+}
+
+impl RunCommand {
+    pub fn new() -> Self {
+        Self {
+            project_root: find_project_root(),
+        }
+    }
+
+    pub fn execute(
+        &self,
+        task: Option<String>,
+        affected: bool,
+        base: Option<String>,
+        force: bool,
+        dry_run: bool,
+    ) -> Result<()> {
+        let config = ProjectConfig::load()?;
+        let graph = TaskGraph::new(&config.tasks)?;
+
+        let Some(task) = task else {
+            return self.list_tasks(&config.tasks);
+        };
+
+        let order = graph.execution_order(&graph.select(&task)?)?;
+        let affected = if affected {
+            let changed = self.changed_files(base)?;
+            Some(graph.affected(&changed))
+        } else {
+            None
+        };
+
+        println!(
+            "{} {}",
+            "🔗 akatsuki run".bright_cyan().bold(),
+            task.bright_white()
+        );
+        println!("{}", "─".repeat(50).bright_black());
+
+        let mut cache = self.load_cache();
+        let mut keys: BTreeMap<String, String> = BTreeMap::new();
+        let (mut ran, mut cached, mut skipped) = (0, 0, 0);
+
+        for name in &order {
+            let task = graph.task(name);
+            let key = self.cache_key(task, &keys)?;
+            keys.insert(name.clone(), key.clone());
+
+            if affected.as_ref().is_some_and(|set| !set.contains(name)) {
+                println!(
+                    "  {} {} {}",
+                    "⏭".bright_black(),
+                    name,
+                    "(not affected)".bright_black()
+                );
+                skipped += 1;
+                continue;
+            }
+
+            let hit = !force
+                && !task.inputs.is_empty()
+                && cache.keys.get(name) == Some(&key)
+                && task
+                    .outputs
+                    .iter()
+                    .all(|output| self.project_root.join(output).exists());
+            if hit {
+                println!("  {} {} {}", "✓".green(), name, "(cached)".bright_black());
+                cached += 1;
+                continue;
+            }
+
+            if dry_run {
+                println!(
+                    "  {} {} {}",
+                    "•".bright_blue(),
+                    name,
+                    task.command.bright_black()
+                );
+                ran += 1;
+                continue;
+            }
+
+            println!(
+                "\n{} {} {}",
+                "▶".bright_cyan(),
+                name.bright_white(),
+                task.command.bright_black()
+            );
+            let status = platform::shell(&task.command)
+                .current_dir(self.project_root.join(task.cwd.as_deref().unwrap_or(".")))
+                .timed_status()
+                .with_context(|| format!("Failed to start task '{}'", name))?;
+            if !status.success() {
+                cache.keys.remove(name);
+                self.save_cache(&cache)?;
+                anyhow::bail!("Task '{}' failed ({})", name, status);
+            }
+
+            cache.keys.insert(name.clone(), key);
+            self.save_cache(&cache)?;
+            ran += 1;
+        }
+
+        println!("\n{}", "─".repeat(50).bright_black());
+        println!(
+            "{} {} {}, {} cached{}",
+            if dry_run { "🔍" } else { "✅" },
+            ran,
+            if dry_run { "would run" } else { "ran" },
+            cached,
+            if affected.is_some() {
+                format!(", {} not affected", skipped)
+            } else {
+                String::new()
+            }
+        );
+        Ok(())
+    }
+
+    fn list_tasks(&self, tasks: &BTreeMap<String, TaskConfig>) -> Result<()> {
+        if tasks.is_empty() {
+            println!(
+                "{}",
+                "No tasks defined. Add [tasks.<name>] sections to akatsuki.toml.".yellow()
+            );
+            return Ok(());
+        }
+
+        println!("{}", "🔗 Tasks (akatsuki.toml)".bright_cyan().bold());
+        for (name, task) in tasks {
+            println!("  {} {}", name.bright_white(), task.command.bright_black());
+            if !task.depends_on.is_empty() {
+                println!("      depends on: {}", task.depends_on.join(", "));
+            }
+        }
+        Ok(())
+    }
+
+    /// Hash of the command, working directory, input file contents and the
+    /// keys of the task's dependencies (a rebuilt dependency invalidates it)
+    fn cache_key(
+        &self,
+        task: &TaskConfig,
+        dependency_keys: &BTreeMap<String, String>,
+    ) -> Result<String> {
+        let mut hasher = ContentHasher::new();
+        hasher.write_str(&task.command);
+        hasher.write_str(task.cwd.as_deref().unwrap_or("."));
+        for dependency in &task.depends_on {
+            hasher.write_str(dependency);
+            hasher.write_str(&dependency_keys[dependency]);
+        }
+        hasher.write_files(&self.project_root, &task.inputs)?;
+        Ok(hasher.finish_hex())
+    }
+
+    /// Files changed since the merge base with the base branch, including
+    /// uncommitted and untracked files
+    fn changed_files(&self, base: Option<String>) -> Result<Vec<String>> {
+        let root = &self.project_root;
+        let base = match base {
+            Some(base) => base,
+            None => self.default_base()?,
+        };
+        let merge_base = git(root, &["merge-base", &base, "HEAD"])
+            .with_context(|| format!("No common ancestor with {}", base))?;
+
+        let mut changed: BTreeSet<String> = git(root, &["diff", "--name-only", &merge_base])?
+            .lines()
+            .map(str::to_string)
+            .collect();
+        changed.extend(
+            git(root, &["ls-files", "--others", "--exclude-standard"])?
+                .lines()
+                .map(str::to_string),
+        );
+        Ok(changed.into_iter().collect())
+    }
+
+    /// origin/HEAD, then origin/main, main, origin/master, master
+    fn default_base(&self) -> Result<String> {
+        let root = &self.project_root;
+        if let Ok(head) = git(
+            root,
+            &["symbolic-ref", "--short", "refs/remotes/origin/HEAD"],
+        ) {
+            return Ok(head);
+        }
+        ["origin/main", "main", "origin/master", "master"]
+            .into_iter()
+            .find(|name| git(root, &["rev-parse", "--verify", "--quiet", name]).is_ok())
+            .map(str::to_string)
+            .context("Could not determine the base branch (use --base)")
+    }
+
+    fn load_cache(&self) -> TaskCache {
+        fs::read_to_string(self.project_root.join(CACHE_PATH))
+            .ok()
+            .and_then(|content| serde_json::from_str::<TaskCache>(&content).ok())
+            .filter(|cache| cache.version == CACHE_VERSION)
+            .unwrap_or(TaskCache {
+                version: CACHE_VERSION,
+                keys: BTreeMap::new(),
+            })
+    }
+
+    fn save_cache(&self, cache: &TaskCache) -> Result<()> {
+        let path = self.project_root.join(CACHE_PATH);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(cache)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_key_tracks_inputs_and_dependencies() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("src/node_modules")).unwrap();
+        fs::write(root.path().join("src/main.ts"), "export {}\n").unwrap();
+        fs::write(root.path().join("src/node_modules/dep.js"), "1").unwrap();
+
+        let run = RunCommand {
+            project_root: root.path().to_path_buf(),
+        };
+        let task = TaskConfig {
+            command: "npm run build".to_string(),
+            inputs: vec!["src".to_string(), "missing".to_string()],
+            depends_on: vec!["codegen".to_string()],
+            ..TaskConfig::default()
+        };
+        let deps = BTreeMap::from([("codegen".to_string(), "aaaa".to_string())]);
+
+        let key = run.cache_key(&task, &deps).unwrap();
+        assert_eq!(key, run.cache_key(&task, &deps).unwrap());
+
+        // node_modules is not an input
+        fs::write(root.path().join("src/node_modules/dep.js"), "2").unwrap();
+        assert_eq!(key, run.cache_key(&task, &deps).unwrap());
+
+        fs::write(root.path().join("src/main.ts"), "export const a = 1\n").unwrap();
+        let changed = run.cache_key(&task, &deps).unwrap();
+        assert_ne!(key, changed);
+
+        let rebuilt = BTreeMap::from([("codegen".to_string(), "bbbb".to_string())]);
+        assert_ne!(changed, run.cache_key(&task, &rebuilt).unwrap());
+    }
+}
@@ -0,0 +1,268 @@
+/**
+ * Self Update Command
+ * Replaces the running akatsuki binary with a GitHub release build
+ *
+ * - stable channel: newest release
+ * - beta channel: newest release or prerelease (X.Y.Z-beta.N)
+ *
+ * `akatsuki install --from-release` uses the same download to install the
+ * binary without a checkout or Rust toolchain.
+ *
+ * The platform archive is checked against its `.sha256` file before
+ * anything is replaced.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::cli::ReleaseChannel;
+use crate::commands::release::github::{self, GitHubClient, Release, UPSTREAM_REPO};
+use crate::commands::release::version::Version;
+
+/// Binary inside the release archives
+pub const BINARY_NAME: &str = if cfg!(windows) {
+    "akatsuki.exe"
+} else {
+    "akatsuki"
+};
+
+pub struct SelfUpdateCommand;
+
+impl SelfUpdateCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(
+        &self,
+        channel: ReleaseChannel,
+        version: Option<String>,
+        yes: bool,
+    ) -> Result<()> {
+        println!(
+            "{}",
+            format!("🔄 Checking for updates ({} channel)...", channel.as_str()).cyan()
+        );
+        let client = GitHubClient::new(UPSTREAM_REPO)?;
+        let release = resolve_release(&client, channel, version.as_deref())?;
+
+        let current = env!("CARGO_PKG_VERSION");
+        let target = release
+            .tag_name
+            .strip_prefix("cli-v")
+            .unwrap_or(&release.tag_name);
+        let newer = match (Version::parse(current), Version::parse(target)) {
+            (Some(current), Some(target)) => target > current,
+            _ => true,
+        };
+        if version.is_none() && !newer {
+            println!("{}", format!("✅ Already up to date ({})", current).green());
+            return Ok(());
+        }
+
+        println!(
+            "  {} → {}{}",
+            current,
+            target.green(),
+            if release.prerelease {
+                " (prerelease)"
+            } else {
+                ""
+            }
+        );
+        if !yes
+            && !dialoguer::Confirm::new()
+                .with_prompt(format!("Update akatsuki to {}?", target))
+                .default(true)
+                .interact()?
+        {
+            println!("{}", "Cancelled".yellow());
+            return Ok(());
+        }
+
+        let binary = download_binary(&client, &release)?;
+        let exe = std::env::current_exe().context("Could not locate the running binary")?;
+        install_binary(&binary, &exe)?;
+        let _ = fs::remove_dir_all(binary.parent().unwrap_or(&binary));
+
+        println!(
+            "{}",
+            format!("✅ Updated {} to {}", exe.display(), target).green()
+        );
+        Ok(())
+    }
+}
+
+/// `akatsuki install --from-release [VERSION]`: the release binary into
+/// `<prefix>/bin` (default: ~/.cargo/bin)
+pub fn install_from_release(version: Option<&str>, prefix: Option<&Path>) -> Result<()> {
+    let dir = install_dir(
+        prefix,
+        std::env::var_os("CARGO_HOME").map(PathBuf::from),
+        std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(PathBuf::from),
+    )
+    .context("Could not determine the home directory. Pass --prefix.")?;
+
+    println!("\n🔧 Installing akatsuki CLI from GitHub releases...\n");
+    let client = GitHubClient::new(UPSTREAM_REPO)?;
+    let release = resolve_release(&client, ReleaseChannel::Stable, version)?;
+    println!("📦 Release: {}", release.tag_name);
+
+    let binary = download_binary(&client, &release)?;
+    let dest = dir.join(BINARY_NAME);
+    install_binary(&binary, &dest)?;
+    let _ = fs::remove_dir_all(binary.parent().unwrap_or(&binary));
+
+    println!();
+    println!("✅ Installed {}", dest.display());
+    let on_path = std::env::var_os("PATH")
+        .is_some_and(|paths| std::env::split_paths(&paths).any(|path| path == dir));
+    if !on_path {
+        println!(
+            "⚠️  {} is not in your PATH. Add it to use `akatsuki`.",
+            dir.display()
+        );
+    }
+    println!("   Update later with: akatsuki self-update");
+    Ok(())
+}
+
+/// `<prefix>/bin`, else `$CARGO_HOME/bin`, else `~/.cargo/bin`
+fn install_dir(
+    prefix: Option<&Path>,
+    cargo_home: Option<PathBuf>,
+    home: Option<PathBuf>,
+) -> Option<PathBuf> {
+    match prefix {
+        Some(prefix) => Some(prefix.join("bin")),
+        None => cargo_home
+            .or_else(|| home.map(|home| home.join(".cargo")))
+            .map(|dir| dir.join("bin")),
+    }
+}
+
+/// The pinned `version`, or the newest release of the channel
+pub fn resolve_release(
+    client: &GitHubClient,
+    channel: ReleaseChannel,
+    version: Option<&str>,
+) -> Result<Release> {
+    match version {
+        Some(version) => {
+            let tag = format!("cli-v{}", version.trim_start_matches("cli-v"));
+            client
+                .release_by_tag(&tag)?
+                .with_context(|| format!("No GitHub release for {}", tag))
+        }
+        None => github::latest_release(client.releases()?, channel)
+            .with_context(|| format!("No {} release of the CLI found", channel.as_str())),
+    }
+}
+
+/// Download this platform's archive, verify it and extract the binary into a
+/// temporary directory
+pub fn download_binary(client: &GitHubClient, release: &Release) -> Result<PathBuf> {
+    let asset = github::platform_asset().context(
+        "No prebuilt binary for this platform. Build from source with `akatsuki install`.",
+    )?;
+    println!("{}", format!("⬇️  Downloading {}...", asset).cyan());
+    let archive = github::download_verified(client, release, asset)?;
+    println!("  {} SHA-256 verified", "✓".green());
+
+    let dir = std::env::temp_dir().join(format!("akatsuki-update-{}", std::process::id()));
+    fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let archive_path = dir.join(asset);
+    fs::write(&archive_path, archive)?;
+
+    // bsdtar (macOS, Windows 10+) and GNU tar read both .tar.gz and .zip
+    let status = Command::new("tar")
+        .arg("-xf")
+        .arg(&archive_path)
+        .arg("-C")
+        .arg(&dir)
+        .status()
+        .context("Failed to run tar to extract the release archive")?;
+    if !status.success() {
+        anyhow::bail!("Failed to extract {}", asset);
+    }
+
+    let binary = dir.join(BINARY_NAME);
+    if !binary.exists() {
+        anyhow::bail!("{} does not contain {}", asset, BINARY_NAME);
+    }
+    Ok(binary)
+}
+
+/// Copy `binary` to `dest` (executable), replacing it atomically when it
+/// exists. Works for the running executable too.
+pub fn install_binary(binary: &Path, dest: &Path) -> Result<()> {
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let staged = dest.with_extension("new");
+    fs::copy(binary, &staged).with_context(|| format!("Failed to write {}", staged.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))?;
+    }
+    // Windows cannot overwrite a running executable, but can rename it
+    #[cfg(windows)]
+    if dest.exists() {
+        let old = dest.with_extension("old");
+        let _ = fs::remove_file(&old);
+        fs::rename(dest, &old)?;
+    }
+
+    fs::rename(&staged, dest).with_context(|| format!("Failed to replace {}", dest.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_binary_replaces_existing() {
+        let root = tempfile::tempdir().unwrap();
+        let binary = root.path().join("download/akatsuki");
+        fs::create_dir_all(binary.parent().unwrap()).unwrap();
+        fs::write(&binary, "new").unwrap();
+
+        let dest = root.path().join("bin/akatsuki");
+        install_binary(&binary, &dest).unwrap();
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "new");
+
+        fs::write(&binary, "newer").unwrap();
+        install_binary(&binary, &dest).unwrap();
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "newer");
+        assert!(!dest.with_extension("new").exists());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&dest).unwrap().permissions().mode();
+            assert_eq!(mode & 0o111, 0o111);
+        }
+
+        let home = Some(PathBuf::from("/home/dev"));
+        assert_eq!(
+            install_dir(None, None, home.clone()),
+            Some(PathBuf::from("/home/dev/.cargo/bin"))
+        );
+        assert_eq!(
+            install_dir(None, Some(PathBuf::from("/opt/cargo")), home.clone()),
+            Some(PathBuf::from("/opt/cargo/bin"))
+        );
+        assert_eq!(
+            install_dir(Some(Path::new("/usr/local")), None, home),
+            Some(PathBuf::from("/usr/local/bin"))
+        );
+        assert_eq!(install_dir(None, None, None), None);
+    }
+}
@@ -1,138 +1,297 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::*;
+use dialoguer::Confirm;
+use serde::Serialize;
 use std::fs;
-use std::process::Command;
+use std::path::Path;
 
+use crate::utils::env_file::{self, ENV_DIRS};
 use crate::utils::get_project_root;
+use crate::utils::output::OutputFormatter;
+use crate::utils::platform;
+
+/// One group of checks (text output prints it as a block)
+#[derive(Debug, Serialize)]
+struct Section {
+    title: &'static str,
+    checks: Vec<CheckResult>,
+    /// Manual follow-ups (label, command), text output only
+    #[serde(skip)]
+    hints: Vec<(&'static str, &'static str)>,
+}
+
+#[derive(Debug, Serialize)]
+struct CheckResult {
+    /// Stable key for scripts (e.g. `frontend_env`, `supabase_cli`)
+    id: String,
+    name: String,
+    passed: bool,
+    details: String,
+    /// Corrective action offered by `--fix`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fix: Option<Fix>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Fix {
+    /// Copy an example file (paths relative to the project root)
+    CopyFile { from: String, to: String },
+    /// Run a command from the project root
+    Command { program: String, args: Vec<String> },
+    /// Append `KEY=` placeholders to an env example file
+    AppendPlaceholders { file: String, keys: Vec<String> },
+}
+
+impl Fix {
+    fn command(program: &str, args: &[&str]) -> Self {
+        Fix::Command {
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Fix::CopyFile { from, to } => format!("copy {} to {}", from, to),
+            Fix::Command { program, args } => format!("run `{} {}`", program, args.join(" ")),
+            Fix::AppendPlaceholders { file, keys } => {
+                format!("append {} to {}", keys.join(", "), file)
+            }
+        }
+    }
 
-pub fn execute() -> Result<()> {
-    println!("\n{}\n", "🔍 Akatsuki Setup Status".cyan().bold());
+    fn apply(&self, root: &Path) -> Result<()> {
+        match self {
+            Fix::CopyFile { from, to } => {
+                fs::copy(root.join(from), root.join(to))
+                    .with_context(|| format!("Failed to copy {} to {}", from, to))?;
+            }
+            Fix::Command { program, args } => {
+                let status = platform::command(program)
+                    .args(args)
+                    .current_dir(root)
+                    .status()
+                    .with_context(|| format!("Failed to run {}", program))?;
+                if !status.success() {
+                    anyhow::bail!("{} {} failed", program, args.join(" "));
+                }
+            }
+            Fix::AppendPlaceholders { file, keys } => {
+                env_file::append_placeholders(&root.join(file), keys)?;
+            }
+        }
+        Ok(())
+    }
+}
 
-    check_prerequisites();
-    check_env_files()?;
-    check_supabase_link()?;
-    check_migrations()?;
-    check_edge_functions()?;
-    check_secrets();
-    check_backend()?;
-    display_summary()?;
+#[derive(Debug, Serialize)]
+struct Report {
+    /// .env files exist and the Supabase project is linked
+    complete: bool,
+    sections: Vec<Section>,
+}
+
+pub fn execute(output: OutputFormatter, fix: bool) -> Result<()> {
+    let root = get_project_root()?;
+    let mut sections = vec![check_prerequisites()];
+    sections.extend(check_project(&root)?);
+    let report = Report {
+        complete: ["frontend_env", "backend_env", "supabase_link"]
+            .iter()
+            .all(|id| passed(&sections, id)),
+        sections,
+    };
+
+    output.emit(&report, |report| {
+        println!("\n{}\n", "🔍 Akatsuki Setup Status".cyan().bold());
+        for section in &report.sections {
+            display_section(section);
+        }
+        display_summary(report, fix);
+    })?;
 
+    if fix {
+        run_fixes(&root, &report)?;
+    }
     Ok(())
 }
 
-fn check_prerequisites() {
-    println!("{}\n", "📋 Prerequisites".cyan().bold());
+fn check_prerequisites() -> Section {
+    let brew = get_command_output("brew", &["--version"]).is_some();
+    let brew_fix = |formula: &str| brew.then(|| Fix::command("brew", &["install", formula]));
+    let mut checks = Vec::new();
 
     // Node.js
     let node_version = get_command_output("node", &["--version"]);
-    if let Some(version) = &node_version {
-        let major = version
-            .trim_start_matches('v')
-            .split('.')
-            .next()
-            .and_then(|s| s.parse::<u32>().ok())
-            .unwrap_or(0);
-        display_check("Node.js", major >= 20, version);
-    } else {
-        display_check("Node.js", false, "Not found");
+    let node_major = node_version
+        .as_deref()
+        .and_then(|version| version.trim_start_matches('v').split('.').next())
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(0);
+    checks.push(CheckResult {
+        id: "node".to_string(),
+        name: "Node.js".to_string(),
+        passed: node_major >= 20,
+        details: node_version.unwrap_or_else(|| "Not found".to_string()),
+        fix: brew_fix("node"),
+    });
+
+    // Rust / Cargo (rustup: https://rustup.rs)
+    for (id, name, program) in [("rust", "Rust", "rustc"), ("cargo", "Cargo", "cargo")] {
+        let version = get_command_output(program, &["--version"]);
+        checks.push(CheckResult {
+            id: id.to_string(),
+            name: name.to_string(),
+            passed: version.is_some(),
+            details: version.unwrap_or_else(|| "Install from https://rustup.rs".to_string()),
+            fix: None,
+        });
     }
-
-    // Rust
-    let rust_version = get_command_output("rustc", &["--version"]);
-    display_check(
-        "Rust",
-        rust_version.is_some(),
-        rust_version.as_deref().unwrap_or(""),
-    );
-
-    // Cargo
-    let cargo_version = get_command_output("cargo", &["--version"]);
-    display_check(
-        "Cargo",
-        cargo_version.is_some(),
-        cargo_version.as_deref().unwrap_or(""),
-    );
+    let has_cargo = checks.last().is_some_and(|check| check.passed);
 
     // Shuttle CLI
     let shuttle_version = get_command_output("cargo", &["shuttle", "--version"]);
-    display_check(
-        "Shuttle CLI",
-        shuttle_version.is_some(),
-        shuttle_version.as_deref().unwrap_or(""),
-    );
+    checks.push(CheckResult {
+        id: "shuttle_cli".to_string(),
+        name: "Shuttle CLI".to_string(),
+        passed: shuttle_version.is_some(),
+        details: shuttle_version.unwrap_or_default(),
+        fix: has_cargo.then(|| Fix::command("cargo", &["install", "cargo-shuttle"])),
+    });
 
     // Supabase CLI
     let supabase_version = get_command_output("supabase", &["--version"]);
-    display_check(
-        "Supabase CLI",
-        supabase_version.is_some(),
-        supabase_version.as_deref().unwrap_or(""),
-    );
+    checks.push(CheckResult {
+        id: "supabase_cli".to_string(),
+        name: "Supabase CLI".to_string(),
+        passed: supabase_version.is_some(),
+        details: supabase_version.unwrap_or_default(),
+        fix: brew_fix("supabase/tap/supabase"),
+    });
+
+    Section {
+        title: "📋 Prerequisites",
+        checks,
+        hints: Vec::new(),
+    }
 }
 
-fn check_env_files() -> Result<()> {
-    println!("\n{}\n", "📝 Environment Files".cyan().bold());
-
-    let root = get_project_root()?;
+/// Checks of the project files (everything except installed tools)
+fn check_project(root: &Path) -> Result<Vec<Section>> {
+    Ok(vec![
+        check_env_files(root)?,
+        check_supabase_link(root)?,
+        check_migrations(root)?,
+        check_edge_functions(root)?,
+        Section {
+            title: "🔑 Supabase Secrets",
+            checks: Vec::new(),
+            hints: vec![
+                ("To check secrets, run", "supabase secrets list"),
+                (
+                    "Required for AI features",
+                    "OPENAI_API_KEY, ANTHROPIC_API_KEY, GEMINI_API_KEY",
+                ),
+            ],
+        },
+        check_backend(root),
+    ])
+}
 
-    // Frontend .env
-    let frontend_env = root.join("packages/app-frontend/.env");
-    let frontend_exists = frontend_env.exists();
-    display_check(
-        "Frontend .env",
-        frontend_exists,
-        "./packages/app-frontend/.env",
-    );
-
-    if frontend_exists {
-        let content = fs::read_to_string(&frontend_env)?;
-        let has_url = content.contains("VITE_SUPABASE_URL=");
-        let has_key = content.contains("VITE_SUPABASE_ANON_KEY=");
-        display_check("  - VITE_SUPABASE_URL", has_url, "");
-        display_check("  - VITE_SUPABASE_ANON_KEY", has_key, "");
-    }
+fn check_env_files(root: &Path) -> Result<Section> {
+    let mut checks = Vec::new();
+    for ((id, name, keys), dir) in [
+        (
+            "frontend_env",
+            "Frontend .env",
+            &["VITE_SUPABASE_URL", "VITE_SUPABASE_ANON_KEY"][..],
+        ),
+        ("backend_env", "Backend .env", &["DATABASE_URL"][..]),
+    ]
+    .into_iter()
+    .zip(ENV_DIRS)
+    {
+        let env = format!("{}/.env", dir);
+        let example = format!("{}/.env.example", dir);
+        let exists = root.join(&env).exists();
+        checks.push(CheckResult {
+            id: id.to_string(),
+            name: name.to_string(),
+            passed: exists,
+            details: format!("./{}", env),
+            fix: (!exists && root.join(&example).exists()).then(|| Fix::CopyFile {
+                from: example,
+                to: env.clone(),
+            }),
+        });
+
+        if exists {
+            let content = fs::read_to_string(root.join(&env))?;
+            for key in keys {
+                checks.push(CheckResult {
+                    id: format!("{}.{}", id, key),
+                    name: format!("  - {}", key),
+                    passed: content.contains(&format!("{}=", key)),
+                    details: String::new(),
+                    fix: None,
+                });
+            }
+        }
 
-    // Backend .env
-    let backend_env = root.join("packages/app-backend/.env");
-    let backend_exists = backend_env.exists();
-    display_check(
-        "Backend .env",
-        backend_exists,
-        "./packages/app-backend/.env",
-    );
-
-    if backend_exists {
-        let content = fs::read_to_string(&backend_env)?;
-        let has_db = content.contains("DATABASE_URL=");
-        display_check("  - DATABASE_URL", has_db, "");
+        // Keys added to one file but not the other
+        if let Some(drift) = env_file::env_drift(&root.join(dir))? {
+            checks.push(CheckResult {
+                id: format!("{}.example_sync", id),
+                name: "  - Keys match .env.example".to_string(),
+                passed: drift.is_empty(),
+                details: drift.describe(),
+                fix: (!drift.missing_in_example.is_empty()).then(|| Fix::AppendPlaceholders {
+                    file: format!("{}/.env.example", dir),
+                    keys: drift.missing_in_example.clone(),
+                }),
+            });
+        }
     }
 
-    Ok(())
+    Ok(Section {
+        title: "📝 Environment Files",
+        checks,
+        hints: Vec::new(),
+    })
 }
 
-fn check_supabase_link() -> Result<()> {
-    println!("\n{}\n", "🔗 Supabase Link".cyan().bold());
-
-    let root = get_project_root()?;
+fn check_supabase_link(root: &Path) -> Result<Section> {
     let project_ref_path = root.join("supabase/.temp/project-ref");
-
-    if project_ref_path.exists() {
-        let project_ref = fs::read_to_string(&project_ref_path)?;
-        display_check("Supabase Project Linked", true, &project_ref.trim());
+    let check = if project_ref_path.exists() {
+        CheckResult {
+            id: "supabase_link".to_string(),
+            name: "Supabase Project Linked".to_string(),
+            passed: true,
+            details: fs::read_to_string(&project_ref_path)?.trim().to_string(),
+            fix: None,
+        }
     } else {
-        display_check("Supabase Project Linked", false, "Run: supabase link");
-    }
+        CheckResult {
+            id: "supabase_link".to_string(),
+            name: "Supabase Project Linked".to_string(),
+            passed: false,
+            details: "Run: supabase link".to_string(),
+            fix: Some(Fix::command("supabase", &["link"])),
+        }
+    };
 
-    Ok(())
+    Ok(Section {
+        title: "🔗 Supabase Link",
+        checks: vec![check],
+        hints: Vec::new(),
+    })
 }
 
-fn check_migrations() -> Result<()> {
-    println!("\n{}\n", "🗄️  Database Migrations".cyan().bold());
-
-    let root = get_project_root()?;
+fn check_migrations(root: &Path) -> Result<Section> {
     let migrations_dir = root.join("supabase/migrations");
 
-    if migrations_dir.exists() {
+    let (passed, details) = if migrations_dir.exists() {
         let count = fs::read_dir(&migrations_dir)?
             .filter_map(|entry| entry.ok())
             .filter(|entry| {
@@ -144,29 +303,30 @@ fn check_migrations() -> Result<()> {
                     .unwrap_or(false)
             })
             .count();
-
-        display_check("Migration Files", count > 0, &format!("{} files", count));
+        (count > 0, format!("{} files", count))
     } else {
-        display_check("Migration Files", false, "No migrations directory");
-    }
-
-    println!(
-        "\n  {} To verify applied migrations, run: {}",
-        "ℹ".yellow(),
-        "supabase db diff".cyan()
-    );
+        (false, "No migrations directory".to_string())
+    };
 
-    Ok(())
+    Ok(Section {
+        title: "🗄️  Database Migrations",
+        checks: vec![CheckResult {
+            id: "migrations".to_string(),
+            name: "Migration Files".to_string(),
+            passed,
+            details,
+            fix: None,
+        }],
+        hints: vec![("To verify applied migrations, run", "supabase db diff")],
+    })
 }
 
-fn check_edge_functions() -> Result<()> {
-    println!("\n{}\n", "⚡ Edge Functions".cyan().bold());
-
-    let root = get_project_root()?;
+fn check_edge_functions(root: &Path) -> Result<Section> {
     let functions_dir = root.join("supabase/functions");
+    let mut checks = Vec::new();
 
     if functions_dir.exists() {
-        let functions: Vec<_> = fs::read_dir(&functions_dir)?
+        let mut functions: Vec<_> = fs::read_dir(&functions_dir)?
             .filter_map(|entry| entry.ok())
             .filter(|entry| {
                 entry.path().is_dir()
@@ -177,76 +337,81 @@ fn check_edge_functions() -> Result<()> {
                         .unwrap_or(false)
             })
             .collect();
-
-        display_check(
-            "Edge Functions",
-            !functions.is_empty(),
-            &format!("{} functions", functions.len()),
-        );
-
+        functions.sort_by_key(|entry| entry.file_name());
+
+        checks.push(CheckResult {
+            id: "edge_functions".to_string(),
+            name: "Edge Functions".to_string(),
+            passed: !functions.is_empty(),
+            details: format!("{} functions", functions.len()),
+            fix: None,
+        });
         for entry in functions {
-            let fn_name = entry.file_name();
-            let index_path = entry.path().join("index.ts");
-            let exists = index_path.exists();
-            let icon = if exists { "✓".green() } else { "✗".red() };
-            println!("    - {} {}", icon, fn_name.to_string_lossy());
+            let fn_name = entry.file_name().to_string_lossy().to_string();
+            checks.push(CheckResult {
+                id: format!("edge_function.{}", fn_name),
+                name: format!("  - {}", fn_name),
+                passed: entry.path().join("index.ts").exists(),
+                details: "index.ts".to_string(),
+                fix: None,
+            });
         }
     } else {
-        display_check("Edge Functions", false, "No functions directory");
+        checks.push(CheckResult {
+            id: "edge_functions".to_string(),
+            name: "Edge Functions".to_string(),
+            passed: false,
+            details: "No functions directory".to_string(),
+            fix: None,
+        });
     }
 
-    println!(
-        "\n  {} To deploy, run: {}",
-        "ℹ".yellow(),
-        "npm run supabase:function:deploy".cyan()
-    );
-
-    Ok(())
+    Ok(Section {
+        title: "⚡ Edge Functions",
+        checks,
+        hints: vec![("To deploy, run", "npm run supabase:function:deploy")],
+    })
 }
 
-fn check_secrets() {
-    println!("\n{}\n", "🔑 Supabase Secrets".cyan().bold());
-
-    println!(
-        "  {} To check secrets, run: {}",
-        "ℹ".yellow(),
-        "supabase secrets list".cyan()
-    );
-    println!("\n  Required for AI features:");
-    println!("    - OPENAI_API_KEY");
-    println!("    - ANTHROPIC_API_KEY");
-    println!("    - GEMINI_API_KEY");
+fn check_backend(root: &Path) -> Section {
+    Section {
+        title: "🦀 Backend (Rust)",
+        checks: vec![CheckResult {
+            id: "backend_cargo_toml".to_string(),
+            name: "Cargo.toml".to_string(),
+            passed: root.join("packages/app-backend/Cargo.toml").exists(),
+            details: String::new(),
+            fix: None,
+        }],
+        hints: vec![("To verify compilation, run", "npm run check:backend")],
+    }
 }
 
-fn check_backend() -> Result<()> {
-    println!("\n{}\n", "🦀 Backend (Rust)".cyan().bold());
-
-    let root = get_project_root()?;
-    let cargo_toml = root.join("packages/app-backend/Cargo.toml");
-
-    display_check("Cargo.toml", cargo_toml.exists(), "");
-
-    println!(
-        "\n  {} To verify compilation, run: {}",
-        "ℹ".yellow(),
-        "npm run check:backend".cyan()
-    );
-
-    Ok(())
+fn passed(sections: &[Section], id: &str) -> bool {
+    sections
+        .iter()
+        .flat_map(|section| &section.checks)
+        .any(|check| check.id == id && check.passed)
 }
 
-fn display_summary() -> Result<()> {
-    println!("\n{}\n", "📊 Summary".cyan().bold());
-
-    let root = get_project_root()?;
-
-    let frontend_env_exists = root.join("packages/app-frontend/.env").exists();
-    let backend_env_exists = root.join("packages/app-backend/.env").exists();
-    let project_ref_exists = root.join("supabase/.temp/project-ref").exists();
+fn display_section(section: &Section) {
+    println!("{}\n", section.title.cyan().bold());
+    for check in &section.checks {
+        display_check(&check.name, check.passed, &check.details);
+    }
+    for (i, (label, command)) in section.hints.iter().enumerate() {
+        if i > 0 || !section.checks.is_empty() {
+            println!();
+        }
+        println!("  {} {}: {}", "ℹ".yellow(), label, command.cyan());
+    }
+    println!();
+}
 
-    let setup_complete = frontend_env_exists && backend_env_exists && project_ref_exists;
+fn display_summary(report: &Report, fix: bool) {
+    println!("{}\n", "📊 Summary".cyan().bold());
 
-    if setup_complete {
+    if report.complete {
         println!("  {} Basic setup is complete!", "✓".green());
         println!("\n  {}", "Next Steps:".bold());
         println!(
@@ -270,15 +435,71 @@ fn display_summary() -> Result<()> {
         );
     }
 
+    let fixable = failed_fixes(report).count();
+    if fixable > 0 && !fix {
+        println!(
+            "  {} {} failed check(s) can be fixed automatically: {}",
+            "ℹ".yellow(),
+            fixable,
+            "akatsuki setup check --fix".cyan()
+        );
+    }
+
     println!();
+}
+
+fn failed_fixes(report: &Report) -> impl Iterator<Item = (&CheckResult, &Fix)> {
+    report
+        .sections
+        .iter()
+        .flat_map(|section| &section.checks)
+        .filter(|check| !check.passed)
+        .filter_map(|check| check.fix.as_ref().map(|fix| (check, fix)))
+}
+
+/// Offer the corrective action of each failed check
+fn run_fixes(root: &Path, report: &Report) -> Result<()> {
+    let fixes: Vec<_> = failed_fixes(report).collect();
+    if fixes.is_empty() {
+        println!("{}", "Nothing to fix automatically.".bright_black());
+        return Ok(());
+    }
+
+    println!("{}\n", "🔧 Fixes".cyan().bold());
+    let mut applied = 0;
+    for (check, fix) in fixes {
+        let confirmed = Confirm::new()
+            .with_prompt(format!("{}: {}?", check.name.trim(), fix.describe()))
+            .default(true)
+            .interact()?;
+        if !confirmed {
+            println!("  {} Skipped", "⏭".bright_black());
+            continue;
+        }
+        match fix.apply(root) {
+            Ok(()) => {
+                applied += 1;
+                println!("  {} {}", "✓".green(), fix.describe());
+            }
+            Err(e) => println!("  {} {}", "✗".red(), e),
+        }
+    }
 
+    println!();
+    if applied > 0 {
+        println!(
+            "  {} Re-run {} to see the new status",
+            "ℹ".yellow(),
+            "akatsuki setup check".cyan()
+        );
+    }
     Ok(())
 }
 
 // Helper functions
 
 fn get_command_output(cmd: &str, args: &[&str]) -> Option<String> {
-    Command::new(cmd)
+    platform::command(cmd)
         .args(args)
         .output()
         .ok()
@@ -307,3 +528,44 @@ fn display_check(label: &str, passed: bool, details: &str) {
     };
     println!("  {} {}: {}{}", icon, label, status, details_str);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_env_is_fixed_from_example() {
+        let root = tempfile::tempdir().unwrap();
+        let frontend = root.path().join("packages/app-frontend");
+        fs::create_dir_all(&frontend).unwrap();
+        fs::write(frontend.join(".env.example"), "VITE_SUPABASE_URL=\n").unwrap();
+
+        let sections = check_project(root.path()).unwrap();
+        let checks: Vec<&CheckResult> = sections.iter().flat_map(|s| &s.checks).collect();
+        let frontend_env = checks.iter().find(|c| c.id == "frontend_env").unwrap();
+        assert!(!frontend_env.passed);
+        let fix = frontend_env.fix.clone().unwrap();
+        assert_eq!(
+            fix,
+            Fix::CopyFile {
+                from: "packages/app-frontend/.env.example".to_string(),
+                to: "packages/app-frontend/.env".to_string(),
+            }
+        );
+        // No example to copy from
+        let backend_env = checks.iter().find(|c| c.id == "backend_env").unwrap();
+        assert_eq!(backend_env.fix, None);
+        let link = checks.iter().find(|c| c.id == "supabase_link").unwrap();
+        assert_eq!(link.fix, Some(Fix::command("supabase", &["link"])));
+
+        fix.apply(root.path()).unwrap();
+        let sections = check_project(root.path()).unwrap();
+        assert!(passed(&sections, "frontend_env"));
+        assert!(passed(&sections, "frontend_env.VITE_SUPABASE_URL"));
+        assert!(!passed(&sections, "frontend_env.VITE_SUPABASE_ANON_KEY"));
+
+        let json = serde_json::to_value(&sections[0]).unwrap();
+        assert_eq!(json["checks"][0]["id"], "frontend_env");
+        assert!(json.get("hints").is_none());
+    }
+}
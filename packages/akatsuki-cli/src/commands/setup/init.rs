@@ -13,6 +13,7 @@ use std::path::Path;
 use std::process::Command;
 
 use crate::utils::get_project_root;
+use crate::utils::platform;
 
 /// Project setup information collected during wizard
 struct ProjectInfo {
@@ -164,8 +165,15 @@ fn setup_project_name() -> Result<ProjectInfo> {
         package["name"] = json!(project_name);
         package["description"] = json!(final_description);
 
-        fs::write(&package_json_path, serde_json::to_string_pretty(&package)? + "\n")?;
-        println!("{} Updated package.json: name = \"{}\"", "✓".green(), project_name);
+        fs::write(
+            &package_json_path,
+            serde_json::to_string_pretty(&package)? + "\n",
+        )?;
+        println!(
+            "{} Updated package.json: name = \"{}\"",
+            "✓".green(),
+            project_name
+        );
         println!(
             "{} Updated package.json: description = \"{}\"",
             "✓".green(),
@@ -174,7 +182,10 @@ fn setup_project_name() -> Result<ProjectInfo> {
     }
 
     // Update README.md
-    println!("{} Updating README.md with project information...", "▸".magenta());
+    println!(
+        "{} Updating README.md with project information...",
+        "▸".magenta()
+    );
     let readme_path = root.join("README.md");
     if readme_path.exists() {
         let mut readme = fs::read_to_string(&readme_path)?;
@@ -320,7 +331,9 @@ fn check_prerequisites() -> Result<()> {
 
     if !all_passed {
         println!();
-        anyhow::bail!("Some prerequisites are missing. Please install them and run this command again.");
+        anyhow::bail!(
+            "Some prerequisites are missing. Please install them and run this command again."
+        );
     }
 
     println!();
@@ -464,7 +477,10 @@ SUPABASE_ANON_KEY={}
 // =============================================================================
 
 fn link_supabase_project(project_ref: &str) -> Result<()> {
-    println!("\n{}\n", "🔗 Step 4: Linking Supabase Project".cyan().bold());
+    println!(
+        "\n{}\n",
+        "🔗 Step 4: Linking Supabase Project".cyan().bold()
+    );
 
     let confirm = Confirm::new()
         .with_prompt(format!("Link to Supabase project: {}?", project_ref))
@@ -487,7 +503,7 @@ fn link_supabase_project(project_ref: &str) -> Result<()> {
     );
 
     let root = get_project_root()?;
-    let status = Command::new("supabase")
+    let status = platform::command("supabase")
         .args(["link", "--project-ref", project_ref])
         .current_dir(&root)
         .status()?;
@@ -532,7 +548,7 @@ fn apply_migrations() -> Result<()> {
     println!("{} Running: supabase db push", "▸".magenta());
 
     let root = get_project_root()?;
-    let status = Command::new("supabase")
+    let status = platform::command("supabase")
         .args(["db", "push"])
         .current_dir(&root)
         .status()?;
@@ -541,10 +557,7 @@ fn apply_migrations() -> Result<()> {
         println!("{} Database migrations applied successfully!", "✓".green());
     } else {
         println!("{} Failed to apply migrations", "✗".red());
-        println!(
-            "{} You can run manually: npm run supabase:push",
-            "ℹ".blue()
-        );
+        println!("{} You can run manually: npm run supabase:push", "ℹ".blue());
     }
 
     Ok(())
@@ -561,10 +574,16 @@ fn deploy_edge_functions() -> Result<()> {
     );
 
     println!("{} Edge Functions:", "ℹ".blue());
-    println!("{}   ai-chat - LLM API (OpenAI/Anthropic/Gemini)", "▸".magenta());
+    println!(
+        "{}   ai-chat - LLM API (OpenAI/Anthropic/Gemini)",
+        "▸".magenta()
+    );
     println!("{}   generate-image - Image generation", "▸".magenta());
     println!("{}   upload-file - File upload", "▸".magenta());
-    println!("{}   create-signed-url - Signed URL generation", "▸".magenta());
+    println!(
+        "{}   create-signed-url - Signed URL generation",
+        "▸".magenta()
+    );
     println!("{}   slack-notify - Slack notifications", "▸".magenta());
     println!("{}   send-email - Email sending (Resend)", "▸".magenta());
     println!();
@@ -585,7 +604,7 @@ fn deploy_edge_functions() -> Result<()> {
     println!("{} Running: supabase functions deploy", "▸".magenta());
 
     let root = get_project_root()?;
-    let status = Command::new("supabase")
+    let status = platform::command("supabase")
         .args(["functions", "deploy"])
         .current_dir(&root)
         .status()?;
@@ -636,10 +655,7 @@ fn guide_secrets_setup() {
 // =============================================================================
 
 fn verify_backend() -> Result<()> {
-    println!(
-        "\n{}\n",
-        "🔍 Step 8: Verifying Backend Setup".cyan().bold()
-    );
+    println!("\n{}\n", "🔍 Step 8: Verifying Backend Setup".cyan().bold());
 
     let confirm = Confirm::new()
         .with_prompt("Run backend compile check (cargo check)?")
@@ -671,10 +687,7 @@ fn verify_backend() -> Result<()> {
         println!("{} Backend compiles successfully!", "✓".green());
     } else {
         println!("{} Backend compilation failed", "✗".red());
-        println!(
-            "{} Check your .env file and dependencies",
-            "ℹ".blue()
-        );
+        println!("{} Check your .env file and dependencies", "ℹ".blue());
     }
 
     Ok(())
@@ -753,26 +766,28 @@ fn setup_claude_code_hooks() -> Result<()> {
 
     // Detect platform and suggest appropriate command
     let (sound_command, sound_name) = if cfg!(target_os = "macos") {
-        (
-            "afplay /System/Library/Sounds/Glass.aiff",
-            "Glass (macOS)",
-        )
+        ("afplay /System/Library/Sounds/Glass.aiff", "Glass (macOS)")
     } else if cfg!(target_os = "linux") {
         (
             "paplay /usr/share/sounds/freedesktop/stereo/complete.oga",
             "complete.oga (Linux)",
         )
     } else if cfg!(target_os = "windows") {
-        ("[console]::beep(800,300)", "System beep (Windows)")
+        // Hooks run in Git Bash on Windows, so PowerShell is invoked explicitly
+        (
+            "powershell -NoProfile -Command [console]::beep(800,300)",
+            "System beep (Windows)",
+        )
     } else {
-        println!(
-            "{} Unknown platform. Skipping hooks setup.",
-            "⚠".yellow()
-        );
+        println!("{} Unknown platform. Skipping hooks setup.", "⚠".yellow());
         return Ok(());
     };
 
-    println!("{} Recommended notification sound: {}", "▸".magenta(), sound_name);
+    println!(
+        "{} Recommended notification sound: {}",
+        "▸".magenta(),
+        sound_name
+    );
     println!();
 
     let confirm_sound = Confirm::new()
@@ -797,7 +812,10 @@ fn setup_claude_code_hooks() -> Result<()> {
     });
 
     // Write settings
-    fs::write(&settings_path, serde_json::to_string_pretty(&settings)? + "\n")?;
+    fs::write(
+        &settings_path,
+        serde_json::to_string_pretty(&settings)? + "\n",
+    )?;
     println!(
         "{} Added notification hook to .claude/settings.local.json",
         "✓".green()
@@ -913,7 +931,7 @@ fn display_summary(info: &ProjectInfo) {
 // =============================================================================
 
 fn get_command_output(cmd: &str, args: &[&str]) -> Option<String> {
-    Command::new(cmd)
+    platform::command(cmd)
         .args(args)
         .output()
         .ok()
@@ -929,7 +947,10 @@ fn get_command_output(cmd: &str, args: &[&str]) -> Option<String> {
 }
 
 fn run_command(cmd: &str, args: &[&str], dir: &Path) -> Result<()> {
-    let status = Command::new(cmd).args(args).current_dir(dir).status()?;
+    let status = platform::command(cmd)
+        .args(args)
+        .current_dir(dir)
+        .status()?;
 
     if !status.success() {
         anyhow::bail!("Command failed: {} {:?}", cmd, args);
@@ -4,6 +4,7 @@ mod init;
 use anyhow::Result;
 
 use crate::cli::SetupAction;
+use crate::utils::output::{OutputFormat, OutputFormatter};
 
 pub struct SetupCommand;
 
@@ -12,9 +13,17 @@ impl SetupCommand {
         Self
     }
 
-    pub fn execute(&self, action: SetupAction) -> Result<()> {
+    pub fn execute(&self, action: SetupAction, output: OutputFormatter) -> Result<()> {
         match action {
-            SetupAction::Check => check::execute(),
+            SetupAction::Check { json, fix } => {
+                // `--json` predates the global `--output json`
+                let output = if json {
+                    OutputFormatter::new(OutputFormat::Json)
+                } else {
+                    output
+                };
+                check::execute(output, fix)
+            }
             SetupAction::Init => init::execute(),
         }
     }
@@ -0,0 +1,205 @@
+/**
+ * Stats Command
+ * Summarizes the local telemetry log: which commands dominate workflow time
+ */
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::utils::config::CONFIG_FILE;
+use crate::utils::duration::format_duration;
+use crate::utils::output::OutputFormatter;
+use crate::utils::telemetry::{self, TelemetryEvent, TELEMETRY_LOG};
+
+/// Aggregated runs of one command
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct CommandStats {
+    runs: usize,
+    failures: usize,
+    total_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct CommandUsage {
+    command: String,
+    #[serde(flatten)]
+    stats: CommandStats,
+    /// Percentage of the total time
+    share: f64,
+}
+
+/// `stats` result
+#[derive(Debug, Serialize)]
+struct StatsReport {
+    /// By total time (descending)
+    commands: Vec<CommandUsage>,
+    runs: usize,
+    total_ms: u64,
+    /// Oldest event included
+    since: Option<DateTime<Utc>>,
+}
+
+pub struct StatsCommand;
+
+impl StatsCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, days: Option<u32>, output: OutputFormatter) -> Result<()> {
+        let mut events = telemetry::read_events()?;
+        if let Some(days) = days {
+            let since = Utc::now() - chrono::Duration::days(days as i64);
+            events.retain(|event| event.timestamp >= since);
+        }
+
+        let stats = aggregate(&events);
+        let total_ms: u64 = stats.iter().map(|(_, s)| s.total_ms).sum();
+        let report = StatsReport {
+            commands: stats
+                .into_iter()
+                .map(|(command, stats)| CommandUsage {
+                    share: share(stats.total_ms, total_ms),
+                    command,
+                    stats,
+                })
+                .collect(),
+            runs: events.len(),
+            total_ms,
+            since: events.iter().map(|e| e.timestamp).min(),
+        };
+        output.emit(&report, print)
+    }
+}
+
+fn print(report: &StatsReport) {
+    println!("{}", "📈 Command Stats".bright_cyan().bold());
+    println!("{}", "─".repeat(50).bright_black());
+
+    let Some(since) = report.since else {
+        println!("\n{}", format!("No events in {}", TELEMETRY_LOG).yellow());
+        println!(
+            "💡 Telemetry is opt-in: set {} in {} or export {}",
+            "[telemetry] enabled = true".bright_white(),
+            CONFIG_FILE,
+            "AKATSUKI_TELEMETRY=1".bright_white()
+        );
+        return;
+    };
+
+    let width = report
+        .commands
+        .iter()
+        .map(|usage| usage.command.len())
+        .max()
+        .unwrap_or(0)
+        .max("COMMAND".len());
+
+    println!(
+        "\n{}",
+        format!(
+            "{:<width$}  {:>5}  {:>10}  {:>9}  {:>6}  {:>6}",
+            "COMMAND",
+            "RUNS",
+            "TOTAL",
+            "AVG",
+            "FAIL",
+            "SHARE",
+            width = width
+        )
+        .bold()
+    );
+    for usage in &report.commands {
+        let stat = &usage.stats;
+        let failure_rate = stat.failures as f64 / stat.runs as f64 * 100.0;
+        let failure = format!("{:>5.0}%", failure_rate);
+        println!(
+            "{:<width$}  {:>5}  {:>10}  {:>9}  {}  {:>5.1}%",
+            usage.command,
+            stat.runs,
+            format_duration(stat.total_ms),
+            format_duration(stat.total_ms / stat.runs as u64),
+            if stat.failures > 0 {
+                failure.red()
+            } else {
+                failure.normal()
+            },
+            usage.share,
+            width = width
+        );
+    }
+
+    println!(
+        "\n{} {} runs, {} total since {}",
+        "📊".bright_blue(),
+        report.runs,
+        format_duration(report.total_ms),
+        since.format("%Y-%m-%d")
+    );
+}
+
+/// Per-command totals, sorted by total time (descending)
+fn aggregate(events: &[TelemetryEvent]) -> Vec<(String, CommandStats)> {
+    let mut by_command: BTreeMap<&str, CommandStats> = BTreeMap::new();
+    for event in events {
+        let stats = by_command.entry(&event.command).or_default();
+        stats.runs += 1;
+        stats.total_ms += event.duration_ms;
+        if !event.success {
+            stats.failures += 1;
+        }
+    }
+
+    let mut stats: Vec<(String, CommandStats)> = by_command
+        .into_iter()
+        .map(|(name, stats)| (name.to_string(), stats))
+        .collect();
+    stats.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.total_ms));
+    stats
+}
+
+fn share(part: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64 * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(command: &str, duration_ms: u64, success: bool) -> TelemetryEvent {
+        TelemetryEvent {
+            timestamp: chrono::Utc::now(),
+            command: command.to_string(),
+            duration_ms,
+            success,
+            version: "0.1.0".to_string(),
+            os: "linux".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let events = vec![
+            event("check", 40_000, true),
+            event("dev", 1_000, true),
+            event("check", 50_000, false),
+        ];
+        let stats = aggregate(&events);
+        assert_eq!(stats[0].0, "check");
+        assert_eq!(
+            stats[0].1,
+            CommandStats {
+                runs: 2,
+                failures: 1,
+                total_ms: 90_000
+            }
+        );
+        assert_eq!(stats[1].0, "dev");
+    }
+}
@@ -0,0 +1,529 @@
+/**
+ * Storage Command
+ * Supabase Storage from the command line, with the service role key
+ *
+ * - ls: objects under bucket[/prefix] (`-r` for every level)
+ * - upload: files, directories (their contents) and globs such as
+ *   'assets/**/*.png' (quoted, so the shell leaves them alone); the
+ *   layout below the directory / glob base is kept under the destination
+ * - download: one object, or everything under a prefix
+ * - sign: a time-limited URL for a private object
+ *
+ * Uploads and downloads run `--jobs` transfers at a time.
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use regex::Regex;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use walkdir::WalkDir;
+
+use crate::cli::StorageAction;
+use crate::utils::find_project_root;
+use crate::utils::output::OutputFormatter;
+use crate::utils::platform::slash_path;
+use crate::utils::supabase::{StorageObject, SupabaseAdmin};
+
+/// Created by Supabase Studio to keep empty folders
+const FOLDER_PLACEHOLDER: &str = ".emptyFolderPlaceholder";
+const CONTENT_TYPES: [(&str, &str); 20] = [
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("avif", "image/avif"),
+    ("svg", "image/svg+xml"),
+    ("ico", "image/x-icon"),
+    ("pdf", "application/pdf"),
+    ("json", "application/json"),
+    ("txt", "text/plain"),
+    ("md", "text/markdown"),
+    ("csv", "text/csv"),
+    ("html", "text/html"),
+    ("css", "text/css"),
+    ("js", "text/javascript"),
+    ("mp3", "audio/mpeg"),
+    ("wav", "audio/wav"),
+    ("mp4", "video/mp4"),
+    ("webm", "video/webm"),
+];
+
+/// `ls` row
+#[derive(Debug, Serialize)]
+struct Entry {
+    /// Path in the bucket (folders end with `/`)
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated_at: Option<String>,
+}
+
+/// `sign` result
+#[derive(Debug, Serialize)]
+struct Signed {
+    url: String,
+    expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// One upload: local file → object path in the bucket
+#[derive(Debug, PartialEq)]
+struct Upload {
+    file: PathBuf,
+    object: String,
+}
+
+pub struct StorageCommand;
+
+impl StorageCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, action: StorageAction, output: OutputFormatter) -> Result<()> {
+        let admin = SupabaseAdmin::from_env(&find_project_root())?;
+        match action {
+            StorageAction::Ls { target, recursive } => ls(&admin, &target, recursive, output),
+            StorageAction::Upload {
+                target,
+                sources,
+                jobs,
+            } => upload(&admin, &target, &sources, jobs),
+            StorageAction::Download { target, dest, jobs } => {
+                download(&admin, &target, dest.as_deref(), jobs)
+            }
+            StorageAction::Sign { target, expires_in } => sign(&admin, &target, expires_in, output),
+        }
+    }
+}
+
+fn ls(admin: &SupabaseAdmin, target: &str, recursive: bool, output: OutputFormatter) -> Result<()> {
+    let (bucket, prefix) = parse_target(target)?;
+    let entries: Vec<Entry> = if recursive {
+        list_files(admin, bucket, prefix)?
+            .into_iter()
+            .map(|(path, object)| Entry {
+                path,
+                size: object.size(),
+                updated_at: object.updated_at,
+            })
+            .collect()
+    } else {
+        admin
+            .list_objects(bucket, prefix)?
+            .into_iter()
+            .filter(|object| object.name != FOLDER_PLACEHOLDER)
+            .map(|object| Entry {
+                path: join(prefix, &object.name) + if object.is_folder() { "/" } else { "" },
+                size: object.size(),
+                updated_at: object.updated_at.clone(),
+            })
+            .collect()
+    };
+
+    output.emit(&entries, |entries| {
+        if entries.is_empty() {
+            println!("{}", format!("No objects under {}", target).yellow());
+            return;
+        }
+        for entry in entries {
+            match entry.size {
+                Some(size) => println!(
+                    "{:>12}  {:<24}  {}",
+                    format_size(size),
+                    entry.updated_at.as_deref().unwrap_or("-"),
+                    entry.path
+                ),
+                None => println!("{:>12}  {:<24}  {}", "-", "", entry.path.bright_blue()),
+            }
+        }
+        let folders = entries.iter().filter(|e| e.path.ends_with('/')).count();
+        let bytes: u64 = entries.iter().filter_map(|e| e.size).sum();
+        print!("\n{} object(s)", entries.len() - folders);
+        if folders > 0 {
+            print!(", {} folder(s)", folders);
+        }
+        println!(", {}", format_size(bytes));
+    })
+}
+
+fn upload(admin: &SupabaseAdmin, target: &str, sources: &[String], jobs: usize) -> Result<()> {
+    let (bucket, prefix) = parse_target(target)?;
+    let mut uploads = Vec::new();
+    for source in sources {
+        let found = expand(source, prefix)?;
+        if found.is_empty() {
+            anyhow::bail!("No files match {}", source);
+        }
+        uploads.extend(found);
+    }
+
+    println!(
+        "{}",
+        format!(
+            "📤 Uploading {} file(s) to {} ({} at a time)",
+            uploads.len(),
+            bucket,
+            jobs
+        )
+        .cyan()
+    );
+    let failed = parallel(&uploads, jobs, |upload| {
+        let bytes = fs::read(&upload.file)
+            .with_context(|| format!("Failed to read {}", upload.file.display()))?;
+        let size = bytes.len() as u64;
+        admin.upload(bucket, &upload.object, bytes, content_type(&upload.object))?;
+        Ok(format!(
+            "{} → {}/{} ({})",
+            upload.file.display(),
+            bucket,
+            upload.object,
+            format_size(size)
+        ))
+    });
+    finish("uploaded", uploads.len(), failed)
+}
+
+fn download(admin: &SupabaseAdmin, target: &str, dest: Option<&Path>, jobs: usize) -> Result<()> {
+    let (bucket, path) = parse_target(target)?;
+    let files = list_files(admin, bucket, path)?;
+
+    // Not a prefix: a single object
+    if files.is_empty() {
+        if path.is_empty() {
+            anyhow::bail!("Bucket {} is empty", bucket);
+        }
+        let name = path.rsplit('/').next().unwrap_or(path);
+        let file = match dest {
+            Some(dest) if dest.is_dir() => dest.join(name),
+            Some(dest) => dest.to_path_buf(),
+            None => PathBuf::from(name),
+        };
+        let bytes = admin
+            .download(bucket, path)
+            .with_context(|| format!("Failed to download {}", target))?;
+        write_file(&file, &bytes)?;
+        println!(
+            "{} {} → {} ({})",
+            "✓".green(),
+            target,
+            file.display(),
+            format_size(bytes.len() as u64)
+        );
+        return Ok(());
+    }
+
+    let dest = dest.unwrap_or(Path::new("."));
+    let downloads: Vec<(String, PathBuf)> = files
+        .into_iter()
+        .map(|(object, _)| {
+            let relative = object
+                .strip_prefix(path)
+                .unwrap_or(&object)
+                .trim_start_matches('/');
+            let file = dest.join(relative);
+            (object, file)
+        })
+        .collect();
+    println!(
+        "{}",
+        format!(
+            "📥 Downloading {} object(s) to {} ({} at a time)",
+            downloads.len(),
+            dest.display(),
+            jobs
+        )
+        .cyan()
+    );
+    let failed = parallel(&downloads, jobs, |(object, file)| {
+        let bytes = admin.download(bucket, object)?;
+        write_file(file, &bytes)?;
+        Ok(format!(
+            "{}/{} → {} ({})",
+            bucket,
+            object,
+            file.display(),
+            format_size(bytes.len() as u64)
+        ))
+    });
+    finish("downloaded", downloads.len(), failed)
+}
+
+fn sign(
+    admin: &SupabaseAdmin,
+    target: &str,
+    expires_in: u64,
+    output: OutputFormatter,
+) -> Result<()> {
+    let (bucket, path) = parse_target(target)?;
+    if path.is_empty() {
+        anyhow::bail!("Give an object path: {}/<path>", bucket);
+    }
+    let signed = Signed {
+        url: admin.sign_url(bucket, path, expires_in)?,
+        expires_at: chrono::Utc::now() + chrono::Duration::seconds(expires_in as i64),
+    };
+    output.emit(&signed, |signed| {
+        println!("{}", signed.url);
+        eprintln!(
+            "{}",
+            format!("Expires at {}", signed.expires_at.to_rfc3339()).bright_black()
+        );
+    })
+}
+
+/// `bucket/some/path` → ("bucket", "some/path")
+fn parse_target(target: &str) -> Result<(&str, &str)> {
+    let target = target.trim_matches('/');
+    let (bucket, path) = target.split_once('/').unwrap_or((target, ""));
+    if bucket.is_empty() {
+        anyhow::bail!("Expected <bucket>[/<path>], got '{}'", target);
+    }
+    Ok((bucket, path.trim_matches('/')))
+}
+
+fn join(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", prefix, name)
+    }
+}
+
+/// Every object under `prefix` with its full path, depth first
+fn list_files(
+    admin: &SupabaseAdmin,
+    bucket: &str,
+    prefix: &str,
+) -> Result<Vec<(String, StorageObject)>> {
+    let mut files = Vec::new();
+    let mut folders = vec![prefix.to_string()];
+    while let Some(folder) = folders.pop() {
+        for object in admin.list_objects(bucket, &folder)? {
+            let path = join(&folder, &object.name);
+            if object.is_folder() {
+                folders.push(path);
+            } else if object.name != FOLDER_PLACEHOLDER {
+                files.push((path, object));
+            }
+        }
+    }
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(files)
+}
+
+/// Local files for one `upload` source and their object paths below `prefix`
+fn expand(source: &str, prefix: &str) -> Result<Vec<Upload>> {
+    let path = Path::new(source);
+    if path.is_file() {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        return Ok(vec![Upload {
+            file: path.to_path_buf(),
+            object: join(prefix, &name),
+        }]);
+    }
+
+    // A directory uploads everything below it; a glob whatever matches below
+    // its literal leading directories
+    let (base, pattern) = if path.is_dir() {
+        (path.to_path_buf(), None)
+    } else if source.contains(['*', '?', '[']) {
+        let components: Vec<&str> = source.split(['/', '\\']).collect();
+        let literal = components
+            .iter()
+            .take_while(|c| !c.contains(['*', '?', '[']))
+            .count();
+        let base = match components[..literal].join("/") {
+            base if base.is_empty() && source.starts_with(['/', '\\']) => PathBuf::from("/"),
+            base if base.is_empty() => PathBuf::from("."),
+            base => PathBuf::from(base),
+        };
+        (base, Some(glob_regex(&components[literal..].join("/"))?))
+    } else {
+        anyhow::bail!("{} not found", source);
+    };
+
+    let mut uploads: Vec<Upload> = WalkDir::new(&base)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let relative = slash_path(entry.path().strip_prefix(&base).ok()?);
+            if pattern
+                .as_ref()
+                .is_some_and(|pattern| !pattern.is_match(&relative))
+            {
+                return None;
+            }
+            Some(Upload {
+                object: join(prefix, &relative),
+                file: entry.into_path(),
+            })
+        })
+        .collect();
+    uploads.sort_by(|a, b| a.object.cmp(&b.object));
+    Ok(uploads)
+}
+
+/// `*` and `?` stay within one path segment, `**/` spans any number of them
+fn glob_regex(glob: &str) -> Result<Regex> {
+    let mut regex = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '[' => {
+                let class: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                regex.push('[');
+                regex.push_str(&class.replacen('!', "^", 1));
+                regex.push(']');
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    Regex::new(&regex).with_context(|| format!("Invalid glob '{}'", glob))
+}
+
+/// Run `transfer` over `items` on `jobs` threads, printing each result;
+/// returns the number of failures
+fn parallel<T: Sync>(
+    items: &[T],
+    jobs: usize,
+    transfer: impl Fn(&T) -> Result<String> + Sync,
+) -> usize {
+    let next = AtomicUsize::new(0);
+    let failed = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(item) = items.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    match transfer(item) {
+                        Ok(line) => println!("  {} {}", "✓".green(), line),
+                        Err(e) => {
+                            failed.fetch_add(1, Ordering::Relaxed);
+                            eprintln!("  {} {:#}", "✗".red(), e);
+                        }
+                    }
+                }
+            });
+        }
+    });
+    failed.into_inner()
+}
+
+fn finish(verb: &str, total: usize, failed: usize) -> Result<()> {
+    if failed > 0 {
+        anyhow::bail!("{} of {} file(s) failed", failed, total);
+    }
+    println!("{}", format!("✅ {} file(s) {}", total, verb).green());
+    Ok(())
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, bytes).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn content_type(path: &str) -> &'static str {
+    let extension = path.rsplit('.').next().unwrap_or("").to_lowercase();
+    CONTENT_TYPES
+        .iter()
+        .find(|(ext, _)| *ext == extension)
+        .map_or("application/octet-stream", |(_, content_type)| content_type)
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        0..1_000 => format!("{} B", bytes),
+        1_000..1_000_000 => format!("{:.1} kB", bytes as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1} MB", bytes as f64 / 1e6),
+        _ => format!("{:.1} GB", bytes as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_target() {
+        assert_eq!(parse_target("assets").unwrap(), ("assets", ""));
+        assert_eq!(
+            parse_target("assets/icons/logo.png").unwrap(),
+            ("assets", "icons/logo.png")
+        );
+        assert_eq!(parse_target("/assets/icons/").unwrap(), ("assets", "icons"));
+        assert!(parse_target("/").is_err());
+    }
+
+    #[test]
+    fn test_glob_regex() {
+        let glob = glob_regex("**/*.png").unwrap();
+        assert!(glob.is_match("logo.png"));
+        assert!(glob.is_match("icons/small/logo.png"));
+        assert!(!glob.is_match("logo.png.bak"));
+
+        let glob = glob_regex("icons/?-[ab].svg").unwrap();
+        assert!(glob.is_match("icons/1-a.svg"));
+        assert!(!glob.is_match("icons/sub/1-a.svg"));
+        assert!(!glob.is_match("icons/1-c.svg"));
+        assert!(!glob_regex("*.txt").unwrap().is_match("docs/a.txt"));
+    }
+
+    #[test]
+    fn test_expand_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        for file in [
+            "assets/logo.png",
+            "assets/icons/a.png",
+            "assets/icons/b.svg",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "x").unwrap();
+        }
+        let objects = |source: &str, prefix: &str| -> Vec<String> {
+            let source = format!("{}/{}", slash_path(root), source);
+            expand(&source, prefix)
+                .unwrap()
+                .into_iter()
+                .map(|upload| upload.object)
+                .collect()
+        };
+
+        assert_eq!(objects("assets/logo.png", "public"), ["public/logo.png"]);
+        assert_eq!(
+            objects("assets", ""),
+            ["icons/a.png", "icons/b.svg", "logo.png"]
+        );
+        assert_eq!(
+            objects("assets/**/*.png", "img"),
+            ["img/icons/a.png", "img/logo.png"]
+        );
+        assert_eq!(objects("assets/icons/*.svg", "img"), ["img/b.svg"]);
+        assert!(expand(&format!("{}/missing.png", slash_path(root)), "").is_err());
+
+        assert_eq!(content_type("img/logo.PNG"), "image/png");
+        assert_eq!(content_type("data.bin"), "application/octet-stream");
+    }
+}
@@ -3,6 +3,10 @@ use colored::Colorize;
 use std::process::Command;
 
 use crate::cli::TestTarget;
+use crate::utils::platform;
+use crate::utils::process::TimedCommand;
+
+mod visual;
 
 pub struct TestCommand;
 
@@ -11,11 +15,19 @@ impl TestCommand {
         Self
     }
 
-    pub fn execute(&self, target: TestTarget, watch: bool, ui: bool, coverage: bool) -> Result<()> {
+    pub fn execute(
+        &self,
+        target: TestTarget,
+        watch: bool,
+        ui: bool,
+        coverage: bool,
+        update: bool,
+    ) -> Result<()> {
         match target {
             TestTarget::Frontend => self.test_frontend(watch, ui, coverage),
             TestTarget::Backend => self.test_backend(),
             TestTarget::All => self.test_all(watch, ui, coverage),
+            TestTarget::Visual => visual::execute(update),
         }
     }
 
@@ -38,10 +50,10 @@ impl TestCommand {
             args.push("test:run");
         }
 
-        let status = Command::new("npm")
+        let status = platform::command("npm")
             .args(&args)
             .current_dir("packages/app-frontend")
-            .status()
+            .timed_status()
             .context("Failed to run npm test")?;
 
         if !status.success() {
@@ -60,7 +72,7 @@ impl TestCommand {
         let status = Command::new("cargo")
             .args(["test"])
             .current_dir("packages/app-backend")
-            .status()
+            .timed_status()
             .context("Failed to run cargo test")?;
 
         if !status.success() {
@@ -0,0 +1,457 @@
+/**
+ * Visual Regression Tests
+ * Screenshots of the `[visual]` routes in akatsuki.toml compared pixel by
+ * pixel with the committed baselines
+ *
+ * The Vite dev server is started for the run when nothing answers at the
+ * default URL. Baseline, current and diff images plus report.html are written
+ * to .akatsuki/visual/ (a self-contained CI artifact).
+ */
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use crate::commands::design::screenshot::{self, Capture, DevServer};
+use crate::utils::config::{ProjectConfig, VisualConfig, CONFIG_FILE};
+use crate::utils::find_project_root;
+
+const OUTPUT_DIR: &str = ".akatsuki/visual";
+const REPORT_FILE: &str = "report.html";
+const DEV_SERVER_LOG: &str = "dev-server.log";
+
+/// Decoded RGBA8 image
+struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+enum Outcome {
+    /// Within the threshold
+    Passed {
+        diff_percent: f64,
+    },
+    Failed {
+        diff_percent: f64,
+    },
+    SizeChanged {
+        baseline: (u32, u32),
+        current: (u32, u32),
+    },
+    /// No baseline yet: the screenshot became the baseline
+    New,
+    /// Baseline replaced by `--update`
+    Updated,
+}
+
+impl Outcome {
+    fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Failed { .. } | Outcome::SizeChanged { .. })
+    }
+
+    fn diff_percent(&self) -> Option<f64> {
+        match self {
+            Outcome::Passed { diff_percent } | Outcome::Failed { diff_percent } => {
+                Some(*diff_percent)
+            }
+            _ => None,
+        }
+    }
+
+    fn summary(&self) -> String {
+        match self {
+            Outcome::Passed { diff_percent } => format!("passed ({:.3}% differ)", diff_percent),
+            Outcome::Failed { diff_percent } => format!("failed ({:.3}% differ)", diff_percent),
+            Outcome::SizeChanged { baseline, current } => format!(
+                "size changed ({}x{} → {}x{})",
+                baseline.0, baseline.1, current.0, current.1
+            ),
+            Outcome::New => "new baseline".to_string(),
+            Outcome::Updated => "baseline updated".to_string(),
+        }
+    }
+}
+
+struct RouteResult {
+    route: String,
+    /// Image file name shared by the baseline, current and diff directories
+    file: String,
+    outcome: Outcome,
+}
+
+pub fn execute(update: bool) -> Result<()> {
+    println!("{}", "📸 Running visual regression tests...".cyan());
+
+    let root = find_project_root();
+    let config = ProjectConfig::load()?.visual;
+    if config.routes.is_empty() {
+        anyhow::bail!(
+            "No routes to capture: set routes in [visual] of {}",
+            CONFIG_FILE
+        );
+    }
+    let mut files = Vec::new();
+    for route in &config.routes {
+        if !route.starts_with('/') {
+            anyhow::bail!("Visual route must start with / (got {})", route);
+        }
+        let file = file_name(route);
+        if files.contains(&file) {
+            anyhow::bail!("Visual routes map to the same image {}: {}", file, route);
+        }
+        files.push(file);
+    }
+
+    // Images of the previous run must not mix into the report
+    let output = root.join(OUTPUT_DIR);
+    if output.exists() {
+        fs::remove_dir_all(&output)
+            .with_context(|| format!("Failed to clear {}", output.display()))?;
+    }
+    for dir in ["baseline", "current", "diff"] {
+        fs::create_dir_all(output.join(dir))?;
+    }
+
+    let base_url = config
+        .base_url
+        .clone()
+        .unwrap_or_else(|| screenshot::DEFAULT_BASE_URL.to_string());
+    let server = DevServer::ensure(
+        &root,
+        &base_url,
+        &output.join(DEV_SERVER_LOG),
+        "visual",
+        config.base_url.is_none(),
+    )?;
+
+    let mut results = Vec::new();
+    for (route, file) in config.routes.iter().zip(files) {
+        let url = format!("{}{}", base_url.trim_end_matches('/'), route);
+        let current = output.join("current").join(&file);
+        match screenshot::capture(&url, &current)? {
+            Capture::Saved => {}
+            Capture::NoBrowser => {
+                anyhow::bail!("No headless Chrome / Chromium / Edge found to take screenshots")
+            }
+            Capture::Unreachable => anyhow::bail!("{} did not respond with 2xx", url),
+        }
+
+        let outcome = compare(&root, &config, &file, update)
+            .with_context(|| format!("Failed to compare {}", route))?;
+        let mark = match &outcome {
+            outcome if outcome.is_failure() => "✗".red(),
+            Outcome::Passed { .. } => "✓".green(),
+            _ => "+".bright_blue(),
+        };
+        println!("  {} {:<30} {}", mark, route, outcome.summary());
+        results.push(RouteResult {
+            route: route.clone(),
+            file,
+            outcome,
+        });
+    }
+    drop(server);
+
+    let report = output.join(REPORT_FILE);
+    fs::write(&report, render_report(&results, &config))
+        .with_context(|| format!("Failed to write {}", report.display()))?;
+
+    let failed = results.iter().filter(|r| r.outcome.is_failure()).count();
+    let written = results
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::New | Outcome::Updated))
+        .count();
+    println!(
+        "\n   Report: {}",
+        report.display().to_string().bright_white()
+    );
+    if written > 0 {
+        println!(
+            "{}",
+            format!(
+                "   💡 {} baseline(s) written to {}, commit them",
+                written, config.baseline_dir
+            )
+            .yellow()
+        );
+    }
+    if failed > 0 {
+        anyhow::bail!(
+            "{} of {} routes changed visually (accept with: akatsuki test visual --update)",
+            failed,
+            results.len()
+        );
+    }
+
+    println!("{}", "✅ Visual tests passed!".green());
+    Ok(())
+}
+
+/// Compare `<output>/current/<file>` with its baseline, writing the report
+/// copies and the diff image
+fn compare(root: &Path, config: &VisualConfig, file: &str, update: bool) -> Result<Outcome> {
+    let output = root.join(OUTPUT_DIR);
+    let current_path = output.join("current").join(file);
+    let baseline_dir = root.join(&config.baseline_dir);
+    let baseline_path = baseline_dir.join(file);
+
+    if update || !baseline_path.is_file() {
+        let existed = baseline_path.is_file();
+        fs::create_dir_all(&baseline_dir)?;
+        fs::copy(&current_path, &baseline_path)?;
+        return Ok(if existed {
+            Outcome::Updated
+        } else {
+            Outcome::New
+        });
+    }
+
+    fs::copy(&baseline_path, output.join("baseline").join(file))?;
+    let baseline = read_png(&baseline_path)?;
+    let current = read_png(&current_path)?;
+    if (baseline.width, baseline.height) != (current.width, current.height) {
+        return Ok(Outcome::SizeChanged {
+            baseline: (baseline.width, baseline.height),
+            current: (current.width, current.height),
+        });
+    }
+
+    let (changed, diff_image) = diff(&baseline, &current, config.color_tolerance);
+    if changed > 0 {
+        write_png(&output.join("diff").join(file), &diff_image)?;
+    }
+    let diff_percent = changed as f64 * 100.0 / (baseline.width as f64 * baseline.height as f64);
+    Ok(if diff_percent > config.threshold {
+        Outcome::Failed { diff_percent }
+    } else {
+        Outcome::Passed { diff_percent }
+    })
+}
+
+/// Number of pixels with a channel differing by more than `tolerance`, and
+/// the baseline faded to light gray with those pixels in red
+fn diff(baseline: &Image, current: &Image, tolerance: u8) -> (usize, Image) {
+    let mut changed = 0;
+    let mut pixels = Vec::with_capacity(baseline.pixels.len());
+    for (old, new) in baseline
+        .pixels
+        .chunks_exact(4)
+        .zip(current.pixels.chunks_exact(4))
+    {
+        if old.iter().zip(new).any(|(a, b)| a.abs_diff(*b) > tolerance) {
+            changed += 1;
+            pixels.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            let luma = (old[0] as u32 * 299 + old[1] as u32 * 587 + old[2] as u32 * 114) / 1000;
+            let faded = (255 - (255 - luma) / 4) as u8;
+            pixels.extend_from_slice(&[faded, faded, faded, 255]);
+        }
+    }
+    (
+        changed,
+        Image {
+            width: baseline.width,
+            height: baseline.height,
+            pixels,
+        },
+    )
+}
+
+/// `/` → `index.png`, `/admin/users?tab=roles` → `admin-users-tab-roles.png`
+fn file_name(route: &str) -> String {
+    let slug: String = route
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let slug = slug
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        "index.png".to_string()
+    } else {
+        format!("{}.png", slug)
+    }
+}
+
+fn read_png(path: &Path) -> Result<Image> {
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut decoder = png::Decoder::new(BufReader::new(file));
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder
+        .read_info()
+        .with_context(|| format!("{} is not a PNG", path.display()))?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let frame = reader
+        .next_frame(&mut buffer)
+        .with_context(|| format!("Failed to decode {}", path.display()))?;
+    buffer.truncate(frame.buffer_size());
+
+    let pixels = match frame.color_type {
+        png::ColorType::Rgba => buffer,
+        png::ColorType::Rgb => buffer
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buffer
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        png::ColorType::Grayscale => buffer.iter().flat_map(|&v| [v, v, v, 255]).collect(),
+        png::ColorType::Indexed => {
+            anyhow::bail!("Unexpected indexed colors in {}", path.display())
+        }
+    };
+    Ok(Image {
+        width: frame.width,
+        height: frame.height,
+        pixels,
+    })
+}
+
+fn write_png(path: &Path, image: &Image) -> Result<()> {
+    let file = File::create(path).with_context(|| format!("Failed to write {}", path.display()))?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), image.width, image.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&image.pixels)?;
+    Ok(())
+}
+
+fn render_report(results: &[RouteResult], config: &VisualConfig) -> String {
+    let failed = results.iter().filter(|r| r.outcome.is_failure()).count();
+    let mut html = format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Visual regression report</title>
+<style>
+body {{ font-family: system-ui, sans-serif; margin: 2rem; color: #1f2937; }}
+section {{ border-top: 1px solid #e5e7eb; padding: 1rem 0; }}
+h2 {{ font-size: 1.1rem; margin: 0 0 .25rem; }}
+.status {{ font-weight: 600; }}
+.failed {{ color: #dc2626; }}
+.passed {{ color: #16a34a; }}
+.baseline {{ color: #2563eb; }}
+.images {{ display: flex; gap: 1rem; margin-top: .75rem; }}
+figure {{ margin: 0; flex: 1; }}
+figure img {{ width: 100%; border: 1px solid #d1d5db; }}
+figcaption {{ font-size: .85rem; color: #6b7280; }}
+</style>
+</head>
+<body>
+<h1>Visual regression report</h1>
+<p>{} route(s), {} failed. Threshold {}% of pixels, color tolerance {}.</p>
+"#,
+        results.len(),
+        failed,
+        config.threshold,
+        config.color_tolerance
+    );
+
+    for result in results {
+        let class = match result.outcome {
+            ref outcome if outcome.is_failure() => "failed",
+            Outcome::Passed { .. } => "passed",
+            _ => "baseline",
+        };
+        html.push_str(&format!(
+            "<section>\n<h2>{}</h2>\n<div class=\"status {}\">{}</div>\n<div class=\"images\">\n",
+            escape_html(&result.route),
+            class,
+            escape_html(&result.outcome.summary())
+        ));
+        for (dir, caption) in [
+            ("baseline", "Baseline"),
+            ("current", "Current"),
+            ("diff", "Diff"),
+        ] {
+            let exists = match dir {
+                "baseline" => !matches!(result.outcome, Outcome::New | Outcome::Updated),
+                "diff" => result.outcome.diff_percent().is_some_and(|d| d > 0.0),
+                _ => true,
+            };
+            if exists {
+                html.push_str(&format!(
+                    "<figure><img src=\"{}/{}\" alt=\"{}\"><figcaption>{}</figcaption></figure>\n",
+                    dir, result.file, caption, caption
+                ));
+            }
+        }
+        html.push_str("</div>\n</section>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(width: u32, height: u32, rgba: [u8; 4]) -> Image {
+        Image {
+            width,
+            height,
+            pixels: rgba.repeat((width * height) as usize),
+        }
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name("/"), "index.png");
+        assert_eq!(file_name("/admin/Users"), "admin-users.png");
+        assert_eq!(
+            file_name("/admin/users?tab=roles"),
+            "admin-users-tab-roles.png"
+        );
+    }
+
+    #[test]
+    fn test_diff_counts_pixels_beyond_tolerance() {
+        let baseline = solid(4, 4, [100, 100, 100, 255]);
+        let mut current = solid(4, 4, [100, 100, 100, 255]);
+        // Anti-aliasing noise within the tolerance
+        current.pixels[0] = 110;
+        // Real changes
+        current.pixels[4] = 200;
+        current.pixels[8 + 2] = 0;
+
+        let (changed, image) = diff(&baseline, &current, 16);
+        assert_eq!(changed, 2);
+        assert_eq!(&image.pixels[4..8], [255, 0, 0, 255]);
+        assert_ne!(&image.pixels[0..4], [255, 0, 0, 255]);
+
+        let (changed, _) = diff(&baseline, &current, 0);
+        assert_eq!(changed, 3);
+    }
+
+    #[test]
+    fn test_png_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("image.png");
+        let image = solid(3, 2, [10, 20, 30, 255]);
+        write_png(&path, &image).unwrap();
+
+        let decoded = read_png(&path).unwrap();
+        assert_eq!((decoded.width, decoded.height), (3, 2));
+        assert_eq!(decoded.pixels, image.pixels);
+    }
+}
@@ -0,0 +1,137 @@
+/**
+ * Dashboard Data
+ * Project state shown in the `akatsuki ui` panels
+ *
+ * Everything here only reads: preflight results come from the preflight
+ * cache, docs coverage from the docs lint cache and migrations from git.
+ */
+use anyhow::Result;
+use std::net::{SocketAddr, TcpStream};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::commands::db::ddl::MIGRATIONS_DIR;
+use crate::commands::docs::{DocsCommand, LayerCoverage};
+use crate::commands::preflight::{self, StepStatus};
+
+/// Dev servers shown in the Servers panel
+pub struct DevServer {
+    pub name: &'static str,
+    pub port: u16,
+    pub program: &'static str,
+    pub args: &'static [&'static str],
+    /// Working directory relative to the project root
+    pub dir: &'static str,
+}
+
+pub const DEV_SERVERS: [DevServer; 2] = [
+    DevServer {
+        name: "frontend",
+        port: 5173,
+        program: "npm",
+        args: &["run", "dev", "--workspace=app-frontend"],
+        dir: "",
+    },
+    DevServer {
+        name: "backend",
+        port: 8000,
+        program: "cargo",
+        args: &["shuttle", "run"],
+        dir: "packages/app-backend",
+    },
+];
+
+const PROBE_TIMEOUT: Duration = Duration::from_millis(150);
+
+/// Whether something accepts connections on localhost:`port`
+pub fn port_open(port: u16) -> bool {
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    TcpStream::connect_timeout(&addr, PROBE_TIMEOUT).is_ok()
+}
+
+#[derive(Debug, Default)]
+pub struct Migrations {
+    pub total: usize,
+    pub latest: Option<String>,
+    /// New or changed migrations not committed yet (most likely not applied elsewhere)
+    pub pending: Vec<String>,
+}
+
+/// Panels refreshed together in the background
+pub struct Snapshot {
+    pub preflight: Result<Vec<StepStatus>>,
+    pub migrations: Result<Migrations>,
+    pub docs: Result<Vec<LayerCoverage>>,
+}
+
+pub fn collect(root: &Path) -> Snapshot {
+    Snapshot {
+        preflight: preflight::step_status(root),
+        migrations: migrations(root),
+        docs: DocsCommand::new().coverage(),
+    }
+}
+
+fn migrations(root: &Path) -> Result<Migrations> {
+    let dir = root.join(MIGRATIONS_DIR);
+    let mut names: Vec<String> = std::fs::read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .filter(|name| name.ends_with(".sql"))
+        .collect();
+    names.sort();
+
+    let output = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=all", "--"])
+        .arg(MIGRATIONS_DIR)
+        .current_dir(root)
+        .output()?;
+
+    Ok(Migrations {
+        total: names.len(),
+        latest: names.pop(),
+        pending: pending_migrations(&String::from_utf8_lossy(&output.stdout)),
+    })
+}
+
+/// Migration file names in `git status --porcelain` output
+fn pending_migrations(porcelain: &str) -> Vec<String> {
+    porcelain
+        .lines()
+        .filter(|line| !line.starts_with(" D") && !line.starts_with("D "))
+        .filter_map(|line| line.get(3..))
+        // Renames: `R  old.sql -> new.sql`
+        .map(|path| path.rsplit(" -> ").next().unwrap_or(path))
+        .filter(|path| path.starts_with(MIGRATIONS_DIR) && path.ends_with(".sql"))
+        .filter_map(|path| path.rsplit('/').next())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_migrations() {
+        let porcelain = [
+            "?? supabase/migrations/20250102000000_add_tags.sql",
+            " M supabase/migrations/20250101000000_create_articles.sql",
+            " D supabase/migrations/20240101000000_old.sql",
+            "?? supabase/migrations/README.md",
+            "R  supabase/migrations/a.sql -> supabase/migrations/20250103000000_b.sql",
+        ]
+        .join("\n");
+        assert_eq!(
+            pending_migrations(&porcelain),
+            vec![
+                "20250102000000_add_tags.sql",
+                "20250101000000_create_articles.sql",
+                "20250103000000_b.sql",
+            ]
+        );
+    }
+}
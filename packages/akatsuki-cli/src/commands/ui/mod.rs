@@ -0,0 +1,305 @@
+/**
+ * UI Command
+ * Terminal dashboard for the VibeCoding loop: dev servers, check/lint/test
+ * results, pending migrations, docs coverage and advice in one screen
+ *
+ * Keys run the corresponding akatsuki command in the restored terminal and
+ * come back to the dashboard afterwards. Dev servers started here log to
+ * .akatsuki/logs/dev-<name>.log and are stopped when the dashboard exits.
+ */
+use anyhow::{Context, Result};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::DefaultTerminal;
+use std::fs::{self, File};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+mod dashboard;
+mod view;
+
+use crate::commands::advice;
+use crate::utils::find_project_root;
+use crate::utils::platform;
+use dashboard::{DevServer, Snapshot, DEV_SERVERS};
+
+const LOG_DIR: &str = ".akatsuki/logs";
+const TICK: Duration = Duration::from_millis(250);
+const PROBE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Keys that run an akatsuki command: (key, label, arguments)
+const ACTIONS: [(char, &str, &[&str]); 5] = [
+    ('c', "check", &["check"]),
+    ('l', "lint", &["lint"]),
+    ('t', "test", &["test"]),
+    ('m', "db check", &["db", "check"]),
+    ('d', "docs lint", &["docs", "lint"]),
+];
+
+/// Result of a command run from the dashboard in this session
+struct Run {
+    label: &'static str,
+    success: bool,
+    elapsed: Duration,
+    finished_at: chrono::DateTime<chrono::Local>,
+}
+
+struct ServerState {
+    server: &'static DevServer,
+    up: bool,
+    /// Started from the dashboard (a server started elsewhere is only probed)
+    child: Option<Child>,
+}
+
+/// Results of the background work
+enum Update {
+    Snapshot(Box<Snapshot>),
+    Advice(Result<Vec<String>>),
+}
+
+struct App {
+    root: PathBuf,
+    servers: Vec<ServerState>,
+    snapshot: Option<Snapshot>,
+    advice: Option<Result<Vec<String>>>,
+    runs: Vec<Run>,
+    refreshing: bool,
+    analyzing: bool,
+    message: Option<String>,
+    updates: (Sender<Update>, Receiver<Update>),
+    last_probe: Instant,
+}
+
+pub struct UiCommand;
+
+impl UiCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self) -> Result<()> {
+        let mut app = App::new(find_project_root());
+        app.probe_servers();
+        app.refresh();
+
+        let mut terminal = ratatui::init();
+        let result = app.run(&mut terminal);
+        ratatui::restore();
+        app.stop_servers();
+        result
+    }
+}
+
+impl App {
+    fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            servers: DEV_SERVERS
+                .iter()
+                .map(|server| ServerState {
+                    server,
+                    up: false,
+                    child: None,
+                })
+                .collect(),
+            snapshot: None,
+            advice: None,
+            runs: Vec::new(),
+            refreshing: false,
+            analyzing: false,
+            message: None,
+            updates: mpsc::channel(),
+            last_probe: Instant::now(),
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        loop {
+            while let Ok(update) = self.updates.1.try_recv() {
+                match update {
+                    Update::Snapshot(snapshot) => {
+                        self.snapshot = Some(*snapshot);
+                        self.refreshing = false;
+                    }
+                    Update::Advice(advice) => {
+                        self.advice = Some(advice);
+                        self.analyzing = false;
+                    }
+                }
+            }
+
+            terminal.draw(|frame| view::render(frame, self))?;
+
+            if event::poll(TICK)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    let quit = matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+                        || (key.code == KeyCode::Char('c')
+                            && key.modifiers.contains(KeyModifiers::CONTROL));
+                    if quit {
+                        return Ok(());
+                    }
+                    if let KeyCode::Char(key) = key.code {
+                        self.handle_key(key, terminal)?;
+                    }
+                }
+            }
+
+            if self.last_probe.elapsed() >= PROBE_INTERVAL {
+                self.probe_servers();
+            }
+        }
+    }
+
+    fn handle_key(&mut self, key: char, terminal: &mut DefaultTerminal) -> Result<()> {
+        if let Some((_, label, args)) = ACTIONS.iter().find(|(action, _, _)| *action == key) {
+            ratatui::restore();
+            let result = self.run_command(label, args);
+            *terminal = ratatui::init();
+            terminal.clear()?;
+            result?;
+            self.refresh();
+            return Ok(());
+        }
+
+        match key {
+            'r' => self.refresh(),
+            'a' => self.analyze(),
+            'F' => self.toggle_server(0)?,
+            'B' => self.toggle_server(1)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Run `akatsuki <args>` in the normal terminal and wait for Enter
+    fn run_command(&mut self, label: &'static str, args: &[&str]) -> Result<()> {
+        println!("▶ akatsuki {}\n", args.join(" "));
+
+        let exe = std::env::current_exe().context("Failed to locate the akatsuki binary")?;
+        let started = Instant::now();
+        let status = Command::new(exe)
+            .args(args)
+            .current_dir(&self.root)
+            .status()
+            .with_context(|| format!("Failed to run akatsuki {}", args.join(" ")))?;
+
+        self.runs.retain(|run| run.label != label);
+        self.runs.push(Run {
+            label,
+            success: status.success(),
+            elapsed: started.elapsed(),
+            finished_at: chrono::Local::now(),
+        });
+
+        print!("\nPress Enter to return to the dashboard...");
+        io::stdout().flush()?;
+        io::stdin().lock().read_line(&mut String::new())?;
+        Ok(())
+    }
+
+    /// Reload the snapshot panels in the background
+    fn refresh(&mut self) {
+        if self.refreshing {
+            return;
+        }
+        self.refreshing = true;
+        let sender = self.updates.0.clone();
+        let root = self.root.clone();
+        thread::spawn(move || {
+            let snapshot = dashboard::collect(&root);
+            let _ = sender.send(Update::Snapshot(Box::new(snapshot)));
+        });
+    }
+
+    /// Run the advice rules in the background (they shell out to git, npm and cargo)
+    fn analyze(&mut self) {
+        if self.analyzing {
+            return;
+        }
+        self.analyzing = true;
+        let sender = self.updates.0.clone();
+        let root = self.root.clone();
+        thread::spawn(move || {
+            let _ = sender.send(Update::Advice(advice::current_situation(&root)));
+        });
+    }
+
+    fn probe_servers(&mut self) {
+        for state in &mut self.servers {
+            // A child that exited leaves its port closed; forget it
+            if let Some(child) = &mut state.child {
+                if let Ok(Some(status)) = child.try_wait() {
+                    self.message = Some(format!(
+                        "{} dev server exited ({}), see {}",
+                        state.server.name,
+                        status,
+                        log_path(state.server).display()
+                    ));
+                    state.child = None;
+                }
+            }
+            state.up = dashboard::port_open(state.server.port);
+        }
+        self.last_probe = Instant::now();
+    }
+
+    fn toggle_server(&mut self, index: usize) -> Result<()> {
+        let root = self.root.clone();
+        let state = &mut self.servers[index];
+        let name = state.server.name;
+
+        if let Some(mut child) = state.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+            self.message = Some(format!("Stopped {} dev server", name));
+        } else if state.up {
+            self.message = Some(format!(
+                "{} is already running on :{} (not started here)",
+                name, state.server.port
+            ));
+        } else {
+            state.child = Some(start_server(&root, state.server)?);
+            self.message = Some(format!(
+                "Starting {} dev server, logging to {}",
+                name,
+                log_path(state.server).display()
+            ));
+        }
+        self.probe_servers();
+        Ok(())
+    }
+
+    fn stop_servers(&mut self) {
+        for state in &mut self.servers {
+            if let Some(mut child) = state.child.take() {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+        }
+    }
+}
+
+fn log_path(server: &DevServer) -> PathBuf {
+    Path::new(LOG_DIR).join(format!("dev-{}.log", server.name))
+}
+
+fn start_server(root: &Path, server: &DevServer) -> Result<Child> {
+    fs::create_dir_all(root.join(LOG_DIR))
+        .with_context(|| format!("Failed to create {}", LOG_DIR))?;
+    let log = File::create(root.join(log_path(server)))?;
+
+    platform::command(server.program)
+        .args(server.args)
+        .current_dir(root.join(server.dir))
+        .stdin(Stdio::null())
+        .stdout(log.try_clone()?)
+        .stderr(log)
+        .spawn()
+        .with_context(|| format!("Failed to start the {} dev server", server.name))
+}
@@ -0,0 +1,285 @@
+/**
+ * Dashboard View
+ * Panel layout and rendering of `akatsuki ui`
+ */
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::{App, ACTIONS};
+
+pub(super) fn render(frame: &mut Frame, app: &App) {
+    let [title, top, middle, bottom, help] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(7),
+        Constraint::Min(7),
+        Constraint::Min(5),
+        Constraint::Length(2),
+    ])
+    .areas(frame.area());
+    let [servers, checks] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(top);
+    let [migrations, docs] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(middle);
+
+    let mut heading = vec![
+        " 🌅 Akatsuki ".bold(),
+        app.root.display().to_string().dark_gray(),
+    ];
+    if app.refreshing {
+        heading.push("  refreshing…".yellow());
+    }
+    frame.render_widget(Line::from(heading), title);
+
+    render_servers(frame, app, servers);
+    render_checks(frame, app, checks);
+    render_migrations(frame, app, migrations);
+    render_docs(frame, app, docs);
+    render_advice(frame, app, bottom);
+    render_help(frame, app, help);
+}
+
+fn panel(title: &str) -> Block<'_> {
+    Block::bordered().title(format!(" {} ", title).bold())
+}
+
+fn status_span(passed: bool) -> Span<'static> {
+    if passed {
+        "✓".green()
+    } else {
+        "✗".red()
+    }
+}
+
+fn error_line(error: &anyhow::Error) -> Line<'static> {
+    Line::from(format!("Failed: {:#}", error).red())
+}
+
+fn loading_line() -> Line<'static> {
+    Line::from("Loading…".dark_gray())
+}
+
+fn render_servers(frame: &mut Frame, app: &App, area: Rect) {
+    let lines: Vec<Line> = app
+        .servers
+        .iter()
+        .map(|state| {
+            let status = match (state.up, state.child.is_some()) {
+                (true, _) => "running".green(),
+                (false, true) => "starting…".yellow(),
+                (false, false) => "stopped".dark_gray(),
+            };
+            Line::from(vec![
+                format!("{:<9}", state.server.name).bold(),
+                format!(":{:<6}", state.server.port).into(),
+                status,
+                if state.child.is_some() {
+                    " (started here)".dark_gray()
+                } else {
+                    "".into()
+                },
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(panel("Servers")), area);
+}
+
+fn render_checks(frame: &mut Frame, app: &App, area: Rect) {
+    let mut lines = Vec::new();
+    match app.snapshot.as_ref().map(|snapshot| &snapshot.preflight) {
+        None => lines.push(loading_line()),
+        Some(Err(error)) => lines.push(error_line(error)),
+        Some(Ok(steps)) => {
+            let mut targets: Vec<&str> = steps.iter().map(|step| step.target).collect();
+            targets.dedup();
+            for target in targets {
+                let mut spans = vec![format!("{:<10}", target).bold()];
+                for step in steps.iter().filter(|step| step.target == target) {
+                    spans.push(" ".into());
+                    spans.push(if step.passed {
+                        "✓".green()
+                    } else {
+                        "·".dark_gray()
+                    });
+                    spans.push(format!(" {:<6}", step.step).into());
+                }
+                lines.push(Line::from(spans));
+            }
+            lines.push(Line::from(
+                "✓ passed with the current inputs (preflight cache)".dark_gray(),
+            ));
+        }
+    }
+
+    if !app.runs.is_empty() {
+        lines.push(Line::default());
+        for run in app.runs.iter().rev() {
+            lines.push(Line::from(vec![
+                status_span(run.success),
+                format!(" {:<10}", run.label).into(),
+                format!(
+                    "{}  {:.1}s",
+                    run.finished_at.format("%H:%M:%S"),
+                    run.elapsed.as_secs_f64()
+                )
+                .dark_gray(),
+            ]));
+        }
+    }
+    frame.render_widget(Paragraph::new(lines).block(panel("Checks")), area);
+}
+
+fn render_migrations(frame: &mut Frame, app: &App, area: Rect) {
+    let lines = match app.snapshot.as_ref().map(|snapshot| &snapshot.migrations) {
+        None => vec![loading_line()],
+        Some(Err(error)) => vec![error_line(error)],
+        Some(Ok(migrations)) => {
+            let mut lines = vec![
+                Line::from(format!("{} migration(s)", migrations.total)),
+                Line::from(vec![
+                    "latest  ".dark_gray(),
+                    migrations.latest.as_deref().unwrap_or("-").into(),
+                ]),
+            ];
+            if migrations.pending.is_empty() {
+                lines.push(Line::from("No uncommitted migrations".green()));
+            } else {
+                lines.push(Line::from(
+                    format!("{} uncommitted:", migrations.pending.len()).yellow(),
+                ));
+                for name in &migrations.pending {
+                    lines.push(Line::from(format!("  {}", name)));
+                }
+            }
+            lines
+        }
+    };
+    frame.render_widget(Paragraph::new(lines).block(panel("Migrations")), area);
+}
+
+fn render_docs(frame: &mut Frame, app: &App, area: Rect) {
+    let lines = match app.snapshot.as_ref().map(|snapshot| &snapshot.docs) {
+        None => vec![loading_line()],
+        Some(Err(error)) => vec![error_line(error)],
+        Some(Ok(layers)) if layers.is_empty() => {
+            vec![Line::from("No frontend layers found".dark_gray())]
+        }
+        Some(Ok(layers)) => layers
+            .iter()
+            .map(|layer| {
+                let percent = layer.percent();
+                let color = match percent {
+                    80.. => Color::Green,
+                    50..=79 => Color::Yellow,
+                    _ => Color::Red,
+                };
+                let filled = percent / 10;
+                Line::from(vec![
+                    format!("{:<14}", layer.layer).into(),
+                    Span::styled("█".repeat(filled), Style::default().fg(color)),
+                    "░".repeat(10 - filled).dark_gray(),
+                    format!(
+                        " {:>3}% ({}/{})",
+                        percent,
+                        layer.documented.len(),
+                        layer.total()
+                    )
+                    .into(),
+                ])
+            })
+            .collect(),
+    };
+    frame.render_widget(Paragraph::new(lines).block(panel("Docs coverage")), area);
+}
+
+fn render_advice(frame: &mut Frame, app: &App, area: Rect) {
+    let lines = match &app.advice {
+        _ if app.analyzing => vec![Line::from("Analyzing…".yellow())],
+        None => vec![Line::from("Press a to analyze the project".dark_gray())],
+        Some(Err(error)) => vec![error_line(error)],
+        Some(Ok(situation)) if situation.is_empty() => {
+            vec![Line::from("No issues detected".green())]
+        }
+        Some(Ok(situation)) => situation
+            .iter()
+            .map(|item| Line::from(vec!["- ".into(), item.clone().yellow()]))
+            .collect(),
+    };
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(panel("Advice")),
+        area,
+    );
+}
+
+fn render_help(frame: &mut Frame, app: &App, area: Rect) {
+    let mut keys: Vec<Span> = Vec::new();
+    let bindings = ACTIONS
+        .iter()
+        .map(|(key, label, _)| (key.to_string(), *label))
+        .chain([
+            ("a".to_string(), "advice"),
+            ("F/B".to_string(), "dev servers"),
+            ("r".to_string(), "refresh"),
+            ("q".to_string(), "quit"),
+        ]);
+    for (key, label) in bindings {
+        keys.push(format!(" {} ", key).black().on_cyan());
+        keys.push(format!(" {}  ", label).into());
+    }
+
+    let message = app.message.as_deref().unwrap_or_default();
+    frame.render_widget(
+        Paragraph::new(vec![Line::from(keys), Line::from(message.dark_gray())]),
+        area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::ui::dashboard::{Migrations, Snapshot};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_render_panels() {
+        let mut app = App::new(PathBuf::from("/work/app"));
+        app.snapshot = Some(Snapshot {
+            preflight: Ok(Vec::new()),
+            migrations: Ok(Migrations {
+                total: 2,
+                latest: Some("20250102000000_add_tags.sql".to_string()),
+                pending: vec!["20250102000000_add_tags.sql".to_string()],
+            }),
+            docs: Ok(Vec::new()),
+        });
+        app.advice = Some(Ok(vec!["1 uncommitted migration".to_string()]));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        for expected in [
+            "Servers",
+            "stopped",
+            "Checks",
+            "1 uncommitted:",
+            "No frontend layers found",
+            "- 1 uncommitted migration",
+            "docs lint",
+        ] {
+            assert!(screen.contains(expected), "missing {:?}", expected);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+/**
+ * Users Audit Log
+ * Changes made by `akatsuki users`, one JSON line each
+ * (.akatsuki/audit/users.jsonl), failed attempts included
+ */
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+pub const AUDIT_LOG: &str = ".akatsuki/audit/users.jsonl";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// git user.email, else $USER
+    pub actor: String,
+    /// Supabase project URL
+    pub project: String,
+    /// invite | set-role | disable | enable
+    pub action: String,
+    pub user_id: Option<String>,
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub details: Value,
+    pub success: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+pub fn append(project_root: &Path, entry: &AuditEntry) -> Result<()> {
+    let path = project_root.join(AUDIT_LOG);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    Ok(())
+}
+
+pub fn actor() -> String {
+    Command::new("git")
+        .args(["config", "user.email"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|email| !email.is_empty())
+        .or_else(|| std::env::var("USER").ok())
+        .or_else(|| std::env::var("USERNAME").ok())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_entries() {
+        let root = tempfile::tempdir().unwrap();
+        let entry = |action: &str, error: Option<&str>| AuditEntry {
+            timestamp: chrono::Utc::now(),
+            actor: "admin@example.com".to_string(),
+            project: "https://abc.supabase.co".to_string(),
+            action: action.to_string(),
+            user_id: Some("u-1".to_string()),
+            email: Some("user@example.com".to_string()),
+            details: serde_json::json!({ "from": null, "to": "admin" }),
+            success: error.is_none(),
+            error: error.map(str::to_string),
+        };
+        append(root.path(), &entry("set-role", None)).unwrap();
+        append(
+            root.path(),
+            &entry("disable", Some("Supabase returned 500")),
+        )
+        .unwrap();
+
+        let content = std::fs::read_to_string(root.path().join(AUDIT_LOG)).unwrap();
+        let entries: Vec<AuditEntry> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].details["to"], "admin");
+        assert!(!entries[1].success);
+        assert!(!content.lines().next().unwrap().contains("\"error\""));
+    }
+}
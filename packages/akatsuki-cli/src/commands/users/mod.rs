@@ -0,0 +1,402 @@
+/**
+ * Users Command
+ * Supabase Auth user administration with the service role key
+ *
+ * - list: users with role (app_metadata.role), status and last sign-in
+ * - invite / set-role / disable: confirmed before running (`-y` skips) and
+ *   recorded in .akatsuki/audit/users.jsonl, failed attempts included
+ */
+mod audit;
+
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::path::Path;
+
+use crate::cli::UsersAction;
+use crate::utils::find_project_root;
+use crate::utils::output::OutputFormatter;
+use crate::utils::supabase::{self, AuthUser, SupabaseAdmin};
+use audit::AuditEntry;
+
+/// `ban_duration` used for disabled users (~100 years)
+const BAN_DURATION: &str = "876000h";
+/// Role that manages every row in the generated admin RLS policies
+const ADMIN_ROLE: &str = "admin";
+
+pub struct UsersCommand;
+
+impl UsersCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, action: UsersAction, output: OutputFormatter) -> Result<()> {
+        let root = find_project_root();
+        let admin = SupabaseAdmin::from_env(&root)?;
+        match action {
+            UsersAction::List { role, disabled } => list(&admin, role.as_deref(), disabled, output),
+            UsersAction::Invite {
+                email,
+                role,
+                redirect_to,
+                yes,
+            } => invite(&root, &admin, &email, role, redirect_to, yes),
+            UsersAction::SetRole {
+                user,
+                role,
+                clear: _,
+                yes,
+            } => set_role(&root, &admin, &user, role, yes),
+            UsersAction::Disable { user, enable, yes } => {
+                set_disabled(&root, &admin, &user, !enable, yes)
+            }
+        }
+    }
+}
+
+fn list(
+    admin: &SupabaseAdmin,
+    role: Option<&str>,
+    disabled: bool,
+    output: OutputFormatter,
+) -> Result<()> {
+    let now = chrono::Utc::now();
+    let users: Vec<AuthUser> = admin
+        .list_users()?
+        .into_iter()
+        .filter(|user| role.is_none() || user.role() == role)
+        .filter(|user| !disabled || user.is_disabled(now))
+        .collect();
+
+    output.emit(&users, |users| {
+        if users.is_empty() {
+            println!("{}", "No users found".yellow());
+            return;
+        }
+        println!(
+            "\n{:<36}  {:<32}  {:<10}  {:<11}  LAST SIGN-IN",
+            "ID", "EMAIL", "ROLE", "STATUS"
+        );
+        for user in users {
+            let status = format!("{:<11}", status(user, now));
+            let status = match status.trim_end() {
+                "active" => status.green(),
+                "disabled" => status.red(),
+                _ => status.yellow(),
+            };
+            println!(
+                "{:<36}  {:<32}  {:<10}  {}  {}",
+                user.id,
+                user.email.as_deref().unwrap_or("-"),
+                user.role().unwrap_or("-"),
+                status,
+                user.last_sign_in_at.as_deref().unwrap_or("never")
+            );
+        }
+        println!("\n{} users", users.len());
+    })
+}
+
+fn invite(
+    root: &Path,
+    admin: &SupabaseAdmin,
+    email: &str,
+    role: Option<String>,
+    redirect_to: Option<String>,
+    yes: bool,
+) -> Result<()> {
+    if !email.contains('@') {
+        anyhow::bail!("Invalid email address '{}'", email);
+    }
+    if let Some(role) = &role {
+        validate_role(role)?;
+    }
+    let prompt = match &role {
+        Some(role) => format!("Invite {} as {}?", email, role),
+        None => format!("Invite {}?", email),
+    };
+    if !confirm(root, admin, &prompt, yes)? {
+        println!("{}", "Cancelled".yellow());
+        return Ok(());
+    }
+
+    let result = admin
+        .invite_user(email, redirect_to.as_deref())
+        .and_then(|user| match &role {
+            Some(role) => admin.update_user(&user.id, &json!({ "app_metadata": { "role": role } })),
+            None => Ok(user),
+        });
+    record(
+        root,
+        admin,
+        "invite",
+        result.as_ref().ok().map(|user| user.id.as_str()),
+        email,
+        json!({ "role": role, "redirect_to": redirect_to }),
+        &result,
+    );
+    result?;
+    println!("{} Invitation sent to {}", "✓".green(), email);
+    Ok(())
+}
+
+fn set_role(
+    root: &Path,
+    admin: &SupabaseAdmin,
+    query: &str,
+    role: Option<String>,
+    yes: bool,
+) -> Result<()> {
+    if let Some(role) = &role {
+        validate_role(role)?;
+    }
+    let users = admin.list_users()?;
+    let user = find_user(&users, query)?;
+    let email = user.email.as_deref().unwrap_or(&user.id);
+    let previous = user.role().map(str::to_string);
+    if previous == role {
+        println!(
+            "{} already has role {}",
+            email,
+            role.as_deref().unwrap_or("(none)")
+        );
+        return Ok(());
+    }
+
+    if previous.as_deref() == Some(ADMIN_ROLE) && admin_count(&users) == 1 {
+        println!(
+            "{}",
+            format!("⚠️  {} is the only {} user", email, ADMIN_ROLE).yellow()
+        );
+    }
+    let prompt = format!(
+        "Change the role of {} from {} to {}?",
+        email,
+        previous.as_deref().unwrap_or("(none)"),
+        role.as_deref().unwrap_or("(none)")
+    );
+    if !confirm(root, admin, &prompt, yes)? {
+        println!("{}", "Cancelled".yellow());
+        return Ok(());
+    }
+
+    // A null role removes the key (app_metadata is merged)
+    let result = admin.update_user(&user.id, &json!({ "app_metadata": { "role": role } }));
+    record(
+        root,
+        admin,
+        "set-role",
+        Some(&user.id),
+        email,
+        json!({ "from": previous, "to": role }),
+        &result,
+    );
+    result?;
+    println!(
+        "{} {} now has role {}",
+        "✓".green(),
+        email,
+        role.as_deref().unwrap_or("(none)")
+    );
+    Ok(())
+}
+
+fn set_disabled(
+    root: &Path,
+    admin: &SupabaseAdmin,
+    query: &str,
+    disable: bool,
+    yes: bool,
+) -> Result<()> {
+    let users = admin.list_users()?;
+    let user = find_user(&users, query)?;
+    let email = user.email.as_deref().unwrap_or(&user.id);
+    if user.is_disabled(chrono::Utc::now()) == disable {
+        println!(
+            "{} is already {}",
+            email,
+            if disable { "disabled" } else { "enabled" }
+        );
+        return Ok(());
+    }
+
+    if disable && user.role() == Some(ADMIN_ROLE) && admin_count(&users) == 1 {
+        println!(
+            "{}",
+            format!("⚠️  {} is the only {} user", email, ADMIN_ROLE).yellow()
+        );
+    }
+    let prompt = if disable {
+        format!("Disable sign-in for {}?", email)
+    } else {
+        format!("Enable sign-in for {}?", email)
+    };
+    if !confirm(root, admin, &prompt, yes)? {
+        println!("{}", "Cancelled".yellow());
+        return Ok(());
+    }
+
+    let ban_duration = if disable { BAN_DURATION } else { "none" };
+    let result = admin.update_user(&user.id, &json!({ "ban_duration": ban_duration }));
+    record(
+        root,
+        admin,
+        if disable { "disable" } else { "enable" },
+        Some(&user.id),
+        email,
+        Value::Null,
+        &result,
+    );
+    result?;
+    println!(
+        "{} {} {}",
+        "✓".green(),
+        email,
+        if disable { "disabled" } else { "enabled" }
+    );
+    Ok(())
+}
+
+/// User by ID or (case-insensitive) email
+fn find_user<'a>(users: &'a [AuthUser], query: &str) -> Result<&'a AuthUser> {
+    users
+        .iter()
+        .find(|user| {
+            user.id == query
+                || user
+                    .email
+                    .as_deref()
+                    .is_some_and(|email| email.eq_ignore_ascii_case(query))
+        })
+        .ok_or_else(|| anyhow::anyhow!("No user with email or ID '{}'", query))
+}
+
+fn status(user: &AuthUser, now: chrono::DateTime<chrono::Utc>) -> &'static str {
+    if user.is_disabled(now) {
+        "disabled"
+    } else if user.email_confirmed_at.is_some() {
+        "active"
+    } else if user.invited_at.is_some() {
+        "invited"
+    } else {
+        "unconfirmed"
+    }
+}
+
+fn admin_count(users: &[AuthUser]) -> usize {
+    let now = chrono::Utc::now();
+    users
+        .iter()
+        .filter(|user| user.role() == Some(ADMIN_ROLE) && !user.is_disabled(now))
+        .count()
+}
+
+fn validate_role(role: &str) -> Result<()> {
+    let valid = role.starts_with(|c: char| c.is_ascii_lowercase())
+        && role
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+    if !valid {
+        anyhow::bail!(
+            "Invalid role '{}' (use lowercase letters, digits, - and _)",
+            role
+        );
+    }
+    Ok(())
+}
+
+/// Ask before every change; production projects get an extra warning
+fn confirm(root: &Path, admin: &SupabaseAdmin, prompt: &str, yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    if let Some(production) = supabase::production_ref(root, admin.url()) {
+        println!(
+            "{}",
+            format!(
+                "⚠️  SUPABASE_URL points at the production project ({})",
+                production
+            )
+            .yellow()
+            .bold()
+        );
+    }
+    Ok(dialoguer::Confirm::new()
+        .with_prompt(prompt)
+        .default(false)
+        .interact()?)
+}
+
+/// Append to the audit log; a failed write is reported but does not undo the change
+fn record<T>(
+    root: &Path,
+    admin: &SupabaseAdmin,
+    action: &str,
+    user_id: Option<&str>,
+    email: &str,
+    details: Value,
+    result: &Result<T>,
+) {
+    let entry = AuditEntry {
+        timestamp: chrono::Utc::now(),
+        actor: audit::actor(),
+        project: admin.url().to_string(),
+        action: action.to_string(),
+        user_id: user_id.map(str::to_string),
+        email: Some(email.to_string()),
+        details,
+        success: result.is_ok(),
+        error: result.as_ref().err().map(|e| format!("{:#}", e)),
+    };
+    if let Err(e) = audit::append(root, &entry) {
+        eprintln!(
+            "{}",
+            format!("⚠️  Could not write {}: {:#}", audit::AUDIT_LOG, e).yellow()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str, email: &str, metadata: Value) -> AuthUser {
+        serde_json::from_value(json!({ "id": id, "email": email, "app_metadata": metadata }))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_find_user() {
+        let users = vec![
+            user("u-1", "Alice@example.com", json!({ "role": "admin" })),
+            user("u-2", "bob@example.com", json!({})),
+        ];
+        assert_eq!(find_user(&users, "alice@example.com").unwrap().id, "u-1");
+        assert_eq!(
+            find_user(&users, "u-2").unwrap().email.as_deref(),
+            Some("bob@example.com")
+        );
+        assert!(find_user(&users, "carol@example.com").is_err());
+        assert_eq!(admin_count(&users), 1);
+    }
+
+    #[test]
+    fn test_user_status() {
+        let now = chrono::Utc::now();
+        let mut user = user("u-1", "a@example.com", json!({}));
+        assert_eq!(status(&user, now), "unconfirmed");
+        user.invited_at = Some("2026-01-01T00:00:00Z".to_string());
+        assert_eq!(status(&user, now), "invited");
+        user.email_confirmed_at = Some("2026-01-02T00:00:00Z".to_string());
+        assert_eq!(status(&user, now), "active");
+        // Expired bans no longer count
+        user.banned_until = Some("2020-01-01T00:00:00Z".to_string());
+        assert_eq!(status(&user, now), "active");
+        user.banned_until = Some("2126-01-01T00:00:00Z".to_string());
+        assert_eq!(status(&user, now), "disabled");
+
+        assert!(validate_role("content_editor").is_ok());
+        assert!(validate_role("Admin").is_err());
+    }
+}
@@ -0,0 +1,151 @@
+/**
+ * TypeScript Loader Generator
+ * Generates packages/app-frontend/src/wasm/index.ts from the built wasm modules
+ */
+use std::fmt::Write;
+
+use super::registry::{ModuleTypings, WasmModule};
+use crate::commands::api::templates::pascal_case;
+
+pub const LOADER_PATH: &str = "packages/app-frontend/src/wasm/index.ts";
+
+/// Relative path from the loader to the repository root
+const LOADER_TO_ROOT: &str = "../../../..";
+
+/// Render the loader for built modules and their typings
+pub fn render(modules: &[(WasmModule, ModuleTypings)]) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "/**\n * WASM Module Loader\n * Auto-generated by `akatsuki wasm loader` - DO NOT EDIT\n *\n \
+         * Each module is imported lazily (code-split) and initialized once.\n \
+         * Typings are derived from the wasm-bindgen `.d.ts` in wasm-modules/<name>/pkg.\n */\n",
+    );
+
+    for (module, typings) in modules {
+        if typings.types.is_empty() {
+            continue;
+        }
+        let _ = writeln!(
+            out,
+            "\nimport type {{ {} }} from '{}/wasm-modules/{}/pkg/{}'",
+            typings.types.join(", "),
+            LOADER_TO_ROOT,
+            module.name,
+            module.crate_name()
+        );
+    }
+
+    for (module, typings) in modules {
+        let type_name = pascal_case(&module.name);
+        let _ = write!(
+            out,
+            "\n// ============================================================\n\
+             // {} v{}\n\
+             // ============================================================\n\n",
+            module.name, module.version
+        );
+
+        if !typings.types.is_empty() {
+            let _ = writeln!(out, "export type {{ {} }}\n", typings.types.join(", "));
+        }
+
+        let _ = writeln!(out, "export interface {}Exports {{", type_name);
+        for function in &typings.functions {
+            if let Some(doc) = &function.doc {
+                for line in doc.lines() {
+                    let indent = if line.starts_with('*') { "   " } else { "  " };
+                    let _ = writeln!(out, "{}{}", indent, line);
+                }
+            }
+            let _ = writeln!(out, "  {}{}", function.name, function.signature);
+        }
+        if !typings.types.is_empty() {
+            let _ = writeln!(
+                out,
+                "  /** Exported classes and enums (constructors / values) */"
+            );
+            for name in &typings.types {
+                let _ = writeln!(out, "  {}: typeof {}", name, name);
+            }
+        }
+        out.push_str("}\n\n");
+
+        let _ = write!(
+            out,
+            "let {var}Promise: Promise<{ty}Exports> | null = null\n\n\
+             /** Lazily import and initialize {name} (cached after the first call) */\n\
+             export function load{ty}(): Promise<{ty}Exports> {{\n  \
+             {var}Promise ??= import('{root}/wasm-modules/{name}/pkg/{krate}.js').then(async (mod) => {{\n    \
+             await mod.default()\n    \
+             return mod as unknown as {ty}Exports\n  \
+             }})\n  \
+             return {var}Promise\n\
+             }}\n",
+            var = lower_first(&type_name),
+            ty = type_name,
+            name = module.name,
+            root = LOADER_TO_ROOT,
+            krate = module.crate_name(),
+        );
+    }
+
+    out.push_str("\n/** All built wasm modules by name */\nexport const wasmModules = {\n");
+    for (module, _) in modules {
+        let _ = writeln!(
+            out,
+            "  '{}': load{},",
+            module.name,
+            pascal_case(&module.name)
+        );
+    }
+    out.push_str("} as const\n\nexport type WasmModuleName = keyof typeof wasmModules\n");
+
+    out
+}
+
+fn lower_first(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::registry::ExportedFunction;
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_render() {
+        let module = WasmModule {
+            name: "sample-module".to_string(),
+            version: "1.0.0".to_string(),
+            dir: PathBuf::from("wasm-modules/sample-module"),
+        };
+        let typings = ModuleTypings {
+            functions: vec![ExportedFunction {
+                name: "sum_array".to_string(),
+                signature: "(input: Int32Array): number".to_string(),
+                doc: Some("/**\n* Array sum\n*/".to_string()),
+            }],
+            types: vec!["RgbaImage".to_string()],
+        };
+
+        let loader = render(&[(module, typings)]);
+        assert!(loader.contains(
+            "import type { RgbaImage } from '../../../../wasm-modules/sample-module/pkg/sample_module'"
+        ));
+        assert!(loader.contains("export interface SampleModuleExports {"));
+        assert!(loader
+            .contains("  /**\n   * Array sum\n   */\n  sum_array(input: Int32Array): number\n"));
+        assert!(loader.contains("  RgbaImage: typeof RgbaImage\n"));
+        assert!(
+            loader.contains("export function loadSampleModule(): Promise<SampleModuleExports> {")
+        );
+        assert!(loader
+            .contains("import('../../../../wasm-modules/sample-module/pkg/sample_module.js')"));
+        assert!(loader.contains("  'sample-module': loadSampleModule,\n"));
+    }
+}
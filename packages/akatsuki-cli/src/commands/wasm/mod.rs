@@ -0,0 +1,166 @@
+/**
+ * WASM Module Command
+ * Registry of wasm-modules/ crates and TypeScript loader generation
+ */
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+use std::fs;
+
+use crate::cli::WasmAction;
+use crate::utils::find_project_root;
+use crate::utils::output::OutputFormatter;
+
+mod loader;
+mod registry;
+
+use loader::LOADER_PATH;
+use registry::{ModuleTypings, WasmModule, WASM_MODULES_DIR};
+
+pub struct WasmCommand;
+
+/// `wasm list` entry
+#[derive(Serialize)]
+struct ModuleStatus {
+    name: String,
+    version: String,
+    /// Exported functions and types (`None` until built)
+    typings: Option<ModuleTypings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build_hint: Option<String>,
+}
+
+impl WasmCommand {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, action: WasmAction, output: OutputFormatter) -> Result<()> {
+        match action {
+            WasmAction::List => self.list(output),
+            WasmAction::Loader { check } => self.generate_loader(check),
+        }
+    }
+
+    fn build_hint(module: &WasmModule) -> String {
+        format!(
+            "wasm-pack build --target web {}/{}",
+            WASM_MODULES_DIR, module.name
+        )
+    }
+
+    fn list(&self, output: OutputFormatter) -> Result<()> {
+        let modules = registry::discover(&find_project_root())?
+            .into_iter()
+            .map(|module| {
+                let built = module.is_built();
+                Ok(ModuleStatus {
+                    typings: built.then(|| module.typings()).transpose()?,
+                    build_hint: (!built).then(|| Self::build_hint(&module)),
+                    name: module.name,
+                    version: module.version,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        output.emit(&modules, |modules| Self::print_modules(modules))
+    }
+
+    fn print_modules(modules: &[ModuleStatus]) {
+        println!("{}", "🧩 WASM Modules".bright_cyan().bold());
+        println!("{}", "─".repeat(50).bright_black());
+
+        if modules.is_empty() {
+            println!(
+                "\n{}",
+                format!("No modules in {}/", WASM_MODULES_DIR).yellow()
+            );
+            return;
+        }
+
+        for module in modules {
+            println!(
+                "\n{} {} {}",
+                "📦".bright_blue(),
+                module.name.bright_white().bold(),
+                format!("v{}", module.version).bright_black()
+            );
+
+            let Some(typings) = &module.typings else {
+                println!("   {} not built", "✗".red());
+                if let Some(hint) = &module.build_hint {
+                    println!("   {} {}", "→".bright_blue(), hint);
+                }
+                continue;
+            };
+            println!(
+                "   {} built ({} functions, {} types)",
+                "✓".green(),
+                typings.functions.len(),
+                typings.types.len()
+            );
+            for function in &typings.functions {
+                println!(
+                    "     {} {}{}",
+                    "•".bright_blue(),
+                    function.name,
+                    function.signature.bright_black()
+                );
+            }
+        }
+
+        println!(
+            "\n{} Regenerate the frontend loader: {}",
+            "💡".bright_yellow(),
+            "akatsuki wasm loader".bright_white()
+        );
+    }
+
+    fn generate_loader(&self, check: bool) -> Result<()> {
+        println!("{}", "🧩 WASM Loader Generator".bright_cyan().bold());
+        println!("{}", "─".repeat(50).bright_black());
+
+        let root = find_project_root();
+        let mut built = Vec::new();
+        for module in registry::discover(&root)? {
+            if module.is_built() {
+                let typings = module.typings()?;
+                println!("  {} {}", "✓".green(), module.name);
+                built.push((module, typings));
+            } else {
+                println!(
+                    "  {} {} skipped (not built: {})",
+                    "⚠".yellow(),
+                    module.name,
+                    Self::build_hint(&module)
+                );
+            }
+        }
+
+        let content = loader::render(&built);
+        let path = root.join(LOADER_PATH);
+
+        if check {
+            let current = fs::read_to_string(&path).unwrap_or_default();
+            if current != content {
+                anyhow::bail!("{} is out of date. Run `akatsuki wasm loader`", LOADER_PATH);
+            }
+            println!("\n{}", "✅ Loader is up to date".green().bold());
+            return Ok(());
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+
+        println!(
+            "\n{} {}",
+            "✅ Generated".green().bold(),
+            LOADER_PATH.bright_white()
+        );
+        println!("\n{}", "📌 Usage:".bright_cyan());
+        println!("  import {{ loadSampleModule }} from '@/wasm'");
+        println!("  const wasm = await loadSampleModule()");
+        Ok(())
+    }
+}
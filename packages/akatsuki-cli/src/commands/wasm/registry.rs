@@ -0,0 +1,231 @@
+/**
+ * WASM Module Registry
+ * Discovers crates under wasm-modules/ and their wasm-pack output (pkg/)
+ */
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const WASM_MODULES_DIR: &str = "wasm-modules";
+
+/// Exported function parsed from the wasm-bindgen `.d.ts`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportedFunction {
+    pub name: String,
+    /// `(params): ReturnType`
+    pub signature: String,
+    /// JSDoc block emitted by wasm-bindgen from `///` comments
+    pub doc: Option<String>,
+}
+
+/// Exported typings of a built module
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ModuleTypings {
+    pub functions: Vec<ExportedFunction>,
+    /// Exported classes and enums (referenced by function signatures)
+    pub types: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WasmModule {
+    /// Directory name under wasm-modules/ (e.g. sample-module)
+    pub name: String,
+    pub version: String,
+    pub dir: PathBuf,
+}
+
+impl WasmModule {
+    /// wasm-pack output name (`sample-module` -> `sample_module`)
+    pub fn crate_name(&self) -> String {
+        self.name.replace('-', "_")
+    }
+
+    pub fn pkg_dir(&self) -> PathBuf {
+        self.dir.join("pkg")
+    }
+
+    pub fn typings_path(&self) -> PathBuf {
+        self.pkg_dir().join(format!("{}.d.ts", self.crate_name()))
+    }
+
+    pub fn is_built(&self) -> bool {
+        self.typings_path().exists()
+    }
+
+    pub fn typings(&self) -> Result<ModuleTypings> {
+        let path = self.typings_path();
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Ok(parse_typings(&content))
+    }
+}
+
+/// All crates in `<root>/wasm-modules/*/Cargo.toml`, sorted by name
+pub fn discover(root: &Path) -> Result<Vec<WasmModule>> {
+    let modules_dir = root.join(WASM_MODULES_DIR);
+    if !modules_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut modules = Vec::new();
+    for entry in fs::read_dir(&modules_dir)? {
+        let dir = entry?.path();
+        let manifest = dir.join("Cargo.toml");
+        if !manifest.exists() {
+            continue;
+        }
+
+        let content = fs::read_to_string(&manifest)
+            .with_context(|| format!("Failed to read {}", manifest.display()))?;
+        let (name, version) = parse_package(&content)
+            .with_context(|| format!("No [package] name in {}", manifest.display()))?;
+        modules.push(WasmModule { name, version, dir });
+    }
+
+    modules.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(modules)
+}
+
+/// `name` and `version` from the `[package]` table of a Cargo.toml
+fn parse_package(manifest: &str) -> Option<(String, String)> {
+    let mut in_package = false;
+    let mut name = None;
+    let mut version = String::from("0.0.0");
+
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+            continue;
+        }
+        if !in_package {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim().trim_matches('"').to_string();
+            match key.trim() {
+                "name" => name = Some(value),
+                "version" => version = value,
+                _ => {}
+            }
+        }
+    }
+
+    name.map(|name| (name, version))
+}
+
+/// Parse `export function` / `export class` / `export enum` declarations from wasm-bindgen typings
+pub fn parse_typings(content: &str) -> ModuleTypings {
+    let mut typings = ModuleTypings::default();
+    let mut doc: Vec<&str> = Vec::new();
+    let mut in_doc = false;
+    let mut depth = 0usize;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        // Skip class/enum/interface bodies
+        if depth > 0 {
+            depth += trimmed.matches('{').count();
+            depth -= trimmed.matches('}').count().min(depth);
+            continue;
+        }
+
+        if trimmed.starts_with("/**") {
+            in_doc = true;
+            doc.clear();
+        }
+        if in_doc {
+            doc.push(trimmed);
+            in_doc = !trimmed.ends_with("*/");
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix("export function ") {
+            if let Some(paren) = rest.find('(') {
+                let name = rest[..paren].trim().to_string();
+                if name != "initSync" {
+                    typings.functions.push(ExportedFunction {
+                        name,
+                        signature: rest[paren..].trim_end_matches(';').to_string(),
+                        doc: (!doc.is_empty()).then(|| doc.join("\n")),
+                    });
+                }
+            }
+        } else if let Some(rest) = trimmed
+            .strip_prefix("export class ")
+            .or_else(|| trimmed.strip_prefix("export enum "))
+        {
+            let name: String = rest
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            typings.types.push(name);
+        }
+
+        if trimmed.starts_with("export ") {
+            depth = trimmed
+                .matches('{')
+                .count()
+                .saturating_sub(trimmed.matches('}').count());
+        }
+        doc.clear();
+    }
+
+    typings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPINGS: &str = r#"/* tslint:disable */
+/* eslint-disable */
+/**
+ * Rotate 90° clockwise
+ */
+export function rotate90(rgba: Uint8Array, width: number, height: number): RgbaImage;
+export function sum_array(input: Int32Array): number;
+export enum Transform {
+  Rotate90 = 0,
+  Rotate180 = 1,
+}
+export class RgbaImage {
+  private constructor();
+  free(): void;
+  readonly width: number;
+}
+export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
+export function initSync(module: { module: SyncInitInput } | SyncInitInput): InitOutput;
+export default function __wbg_init (module_or_path?: { module_or_path: InitInput | Promise<InitInput> } | InitInput | Promise<InitInput>): Promise<InitOutput>;
+"#;
+
+    #[test]
+    fn test_parse_typings() {
+        let typings = parse_typings(TYPINGS);
+        assert_eq!(typings.types, vec!["Transform", "RgbaImage"]);
+        assert_eq!(typings.functions.len(), 2);
+
+        let rotate = &typings.functions[0];
+        assert_eq!(rotate.name, "rotate90");
+        assert_eq!(
+            rotate.signature,
+            "(rgba: Uint8Array, width: number, height: number): RgbaImage"
+        );
+        assert_eq!(
+            rotate.doc.as_deref(),
+            Some("/**\n* Rotate 90° clockwise\n*/")
+        );
+        assert_eq!(typings.functions[1].doc, None);
+    }
+
+    #[test]
+    fn test_parse_package() {
+        let manifest = "[package]\nname = \"sample-module\"\nversion = \"1.0.0\"\n\n[dependencies]\nname = \"ignored\"\n";
+        assert_eq!(
+            parse_package(manifest),
+            Some(("sample-module".to_string(), "1.0.0".to_string()))
+        );
+        assert_eq!(parse_package("[dependencies]\n"), None);
+    }
+}
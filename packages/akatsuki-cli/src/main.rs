@@ -4,10 +4,27 @@ mod error;
 mod utils;
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches};
 use cli::Cli;
+use std::time::Instant;
 
 fn main() -> Result<()> {
-    let cli = Cli::parse();
-    cli.run()
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+
+    let output = cli.output();
+    let started = Instant::now();
+    let result = cli.run();
+    utils::telemetry::record(
+        &utils::telemetry::command_name(&matches),
+        started.elapsed(),
+        result.is_ok(),
+    );
+    if let Err(err) = &result {
+        if output.is_json() {
+            output.print_error(err);
+            std::process::exit(1);
+        }
+    }
+    result
 }
@@ -0,0 +1,139 @@
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Default URL of the local backend (`cargo shuttle run`)
+pub const DEFAULT_BACKEND_URL: &str = "http://localhost:8000";
+
+/// Response of an untyped call (`akatsuki backend call`)
+pub struct RawResponse {
+    pub status: reqwest::StatusCode,
+    pub content_type: Option<String>,
+    pub request_id: Option<String>,
+    pub body: Vec<u8>,
+    /// Time until the full body was received
+    pub elapsed: Duration,
+}
+
+/// Minimal blocking HTTP client for the app-backend API
+pub struct BackendClient {
+    base_url: String,
+    token: Option<String>,
+    client: reqwest::blocking::Client,
+}
+
+impl BackendClient {
+    pub fn new(base_url: &str) -> Result<Self> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(120))
+            .build()
+            .context("Failed to build HTTP client")?;
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token: None,
+            client,
+        })
+    }
+
+    /// Send `Authorization: Bearer <token>` with every request
+    pub fn with_token(mut self, token: Option<String>) -> Self {
+        self.token = token;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn get_json<Res: DeserializeOwned>(&self, path: &str) -> Result<Res> {
+        self.send(self.request(reqwest::Method::GET, path))
+    }
+
+    pub fn post_json<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &Req,
+    ) -> Result<Res> {
+        self.send(self.request(reqwest::Method::POST, path).json(body))
+    }
+
+    pub fn delete_json<Res: DeserializeOwned>(&self, path: &str) -> Result<Res> {
+        self.send(self.request(reqwest::Method::DELETE, path))
+    }
+
+    /// Send a request without interpreting the response status or body
+    pub fn send_raw(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<RawResponse> {
+        let mut request = self.request(method, path);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        let started = Instant::now();
+        let response = request
+            .send()
+            .with_context(|| format!("Failed to reach backend at {}", self.base_url))?;
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let status = response.status();
+        let content_type = header("content-type");
+        let request_id = header("x-request-id");
+        let body = response
+            .bytes()
+            .context("Failed to read backend response")?
+            .to_vec();
+
+        Ok(RawResponse {
+            status,
+            content_type,
+            request_id,
+            body,
+            elapsed: started.elapsed(),
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::blocking::RequestBuilder {
+        let request = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path));
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    fn send<Res: DeserializeOwned>(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> Result<Res> {
+        let response = request
+            .send()
+            .with_context(|| format!("Failed to reach backend at {}", self.base_url))?;
+        Self::parse(response)
+    }
+
+    fn parse<Res: DeserializeOwned>(response: reqwest::blocking::Response) -> Result<Res> {
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            anyhow::bail!("Backend returned {}: check AKATSUKI_ADMIN_TOKEN", status);
+        }
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            anyhow::bail!("Backend returned {}: {}", status, body);
+        }
+        response
+            .json()
+            .context("Failed to parse backend response as JSON")
+    }
+}
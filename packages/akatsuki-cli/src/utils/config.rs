@@ -0,0 +1,503 @@
+/**
+ * Project configuration
+ * akatsuki.toml（プロジェクトルート）の読み込み
+ */
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use super::find_project_root;
+
+pub const CONFIG_FILE: &str = "akatsuki.toml";
+
+/// Contents of akatsuki.toml (every section is optional)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    pub a11y: A11yConfig,
+    pub advice: AdviceConfig,
+    pub backend: BackendConfig,
+    pub branch: BranchConfig,
+    pub budgets: BudgetConfig,
+    pub demo: DemoConfig,
+    pub docs: DocsConfig,
+    pub licenses: LicenseConfig,
+    /// `[plugins.<name>]`: free-form settings passed to `akatsuki-<name>` plugins
+    pub plugins: BTreeMap<String, toml::Value>,
+    /// `[tasks.<name>]`: task graph for `akatsuki run`
+    pub tasks: BTreeMap<String, TaskConfig>,
+    pub telemetry: TelemetryConfig,
+    pub visual: VisualConfig,
+}
+
+/// `[a11y]`: axe-core audit for `akatsuki check a11y`
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct A11yConfig {
+    /// Frontend routes to audit (path and optional query string)
+    pub routes: Vec<String>,
+    /// Also audit the admin pages wired into App.tsx by `api new --auto-wire`
+    pub admin_pages: bool,
+    /// Frontend URL (default: the Vite dev server, started when not running)
+    pub base_url: Option<String>,
+    /// Lowest impact that fails the check: minor | moderate | serious | critical
+    pub fail_on: String,
+    /// Violations at or above `fail_on` tolerated before failing
+    pub max_violations: usize,
+}
+
+impl Default for A11yConfig {
+    fn default() -> Self {
+        Self {
+            routes: vec!["/".to_string()],
+            admin_pages: true,
+            base_url: None,
+            fail_on: "serious".to_string(),
+            max_violations: 0,
+        }
+    }
+}
+
+/// `[advice]`: settings for `akatsuki advice`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AdviceConfig {
+    pub claude: AdviceClaudeConfig,
+    pub report: AdviceReportConfig,
+}
+
+/// `[advice.claude]`: how `claude -p` is run for AI answers
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AdviceClaudeConfig {
+    /// Model alias or name passed as `--model` (claude's default when unset)
+    pub model: Option<String>,
+    /// Passed as `--max-turns`
+    pub max_turns: Option<u32>,
+    /// Kill claude when it has not answered after this many seconds
+    pub timeout_secs: u64,
+}
+
+impl Default for AdviceClaudeConfig {
+    fn default() -> Self {
+        Self {
+            model: None,
+            max_turns: None,
+            timeout_secs: 600,
+        }
+    }
+}
+
+/// `[advice.report]`: Slack / Discord digest sent by `akatsuki advice report`
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AdviceReportConfig {
+    /// Message title (defaults to "<project> health report")
+    pub title: Option<String>,
+    /// Environment variable holding the Slack incoming webhook URL
+    pub slack_webhook_env: String,
+    /// Environment variable holding the Discord webhook URL
+    pub discord_webhook_env: String,
+    /// Append the AI answer (same as `--ai`)
+    pub include_ai: bool,
+    /// Include missing-test / coverage detections
+    pub enable_test_coverage: bool,
+}
+
+impl Default for AdviceReportConfig {
+    fn default() -> Self {
+        Self {
+            title: None,
+            slack_webhook_env: "SLACK_WEBHOOK_URL".to_string(),
+            discord_webhook_env: "DISCORD_WEBHOOK_URL".to_string(),
+            include_ai: false,
+            enable_test_coverage: false,
+        }
+    }
+}
+
+/// `[backend]`: app-backend settings exported by `akatsuki dev`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BackendConfig {
+    pub providers: BackendProvidersConfig,
+}
+
+/// `[backend.providers.<llm|image|speech>]`: concurrency limits per provider
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BackendProvidersConfig {
+    pub llm: ProviderLimitConfig,
+    pub image: ProviderLimitConfig,
+    pub speech: ProviderLimitConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProviderLimitConfig {
+    /// Calls in flight at once (0 = unlimited)
+    pub concurrency: Option<usize>,
+    /// Callers waiting for a slot before the backend answers 503
+    pub queue_size: Option<usize>,
+}
+
+impl BackendConfig {
+    /// Environment variables the backend reads these settings from
+    pub fn env(&self) -> Vec<(String, String)> {
+        let providers = &self.providers;
+        let mut env = Vec::new();
+        for (prefix, limit) in [
+            ("LLM", &providers.llm),
+            ("IMAGE", &providers.image),
+            ("SPEECH", &providers.speech),
+        ] {
+            if let Some(concurrency) = limit.concurrency {
+                env.push((format!("{}_CONCURRENCY", prefix), concurrency.to_string()));
+            }
+            if let Some(queue_size) = limit.queue_size {
+                env.push((format!("{}_QUEUE_SIZE", prefix), queue_size.to_string()));
+            }
+        }
+        env
+    }
+}
+
+/// `[branch]`: per-worktree environments prepared by `akatsuki branch setup`
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BranchConfig {
+    /// Files copied from the main worktree (relative to the project root)
+    pub env_files: Vec<String>,
+    /// Create / reuse a Supabase preview branch named after the git branch
+    pub supabase_branching: bool,
+    /// Fixed Supabase project refs per git branch (take precedence over branching)
+    pub projects: BTreeMap<String, String>,
+    /// Push migrations and supabase/seed.sql to the linked project
+    pub seed: bool,
+}
+
+impl Default for BranchConfig {
+    fn default() -> Self {
+        Self {
+            env_files: ["packages/app-frontend/.env", "packages/app-backend/.env"]
+                .map(String::from)
+                .to_vec(),
+            supabase_branching: false,
+            projects: BTreeMap::new(),
+            seed: true,
+        }
+    }
+}
+
+/// `[demo]`: showcase data created by `akatsuki demo seed`
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DemoConfig {
+    pub email: String,
+    /// Generated (and printed) when unset
+    pub password: Option<String>,
+    /// Records per entity
+    pub records: usize,
+    /// Storage bucket for the sample images (created public when missing)
+    pub bucket: String,
+    /// Images to upload instead of generated placeholders (relative to the project root)
+    pub images_dir: Option<String>,
+}
+
+impl Default for DemoConfig {
+    fn default() -> Self {
+        Self {
+            email: "demo@example.com".to_string(),
+            password: None,
+            records: 10,
+            bucket: "demo".to_string(),
+            images_dir: None,
+        }
+    }
+}
+
+/// `[docs]`: settings for `akatsuki docs`
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DocsConfig {
+    /// Files whose SYNC:COMPONENTS section `akatsuki docs sync` regenerates
+    pub sync_targets: Vec<String>,
+    /// AI context files measured by `akatsuki docs context-size` (missing ones are skipped)
+    pub context_files: Vec<String>,
+    /// Estimated token budget per context file
+    pub context_budget: usize,
+    /// Per-file budgets overriding `context_budget`
+    pub context_budgets: BTreeMap<String, usize>,
+}
+
+impl Default for DocsConfig {
+    fn default() -> Self {
+        Self {
+            sync_targets: vec!["AGENT.md".to_string()],
+            context_files: [
+                "AGENT.md",
+                "AGENT-mini.md",
+                "CLAUDE.md",
+                ".cursorrules",
+                ".github/copilot-instructions.md",
+            ]
+            .map(String::from)
+            .to_vec(),
+            context_budget: 20_000,
+            context_budgets: BTreeMap::new(),
+        }
+    }
+}
+
+/// `[tasks.<name>]`: one node of the `akatsuki run` task graph.
+/// Names are `<package>:<task>` by convention so `akatsuki run build` selects every `*:build`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TaskConfig {
+    /// Shell command (`sh -c`, `cmd /C` on Windows)
+    pub command: String,
+    /// Working directory relative to the project root (default: the root)
+    pub cwd: Option<String>,
+    /// Files / directories (relative to the root) whose contents key the cache;
+    /// a task without inputs always runs
+    pub inputs: Vec<String>,
+    /// Paths the task produces; a cache hit also requires all of them to exist
+    pub outputs: Vec<String>,
+    /// Tasks that must succeed first
+    pub depends_on: Vec<String>,
+}
+
+/// `[telemetry]`: opt-in usage recording (see utils::telemetry)
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetryConfig {
+    pub enabled: bool,
+    /// Also POST each event as JSON to this URL (best effort, 2s timeout)
+    pub endpoint: Option<String>,
+}
+
+/// `[visual]`: screenshot regression tests for `akatsuki test visual`
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VisualConfig {
+    /// Frontend routes to capture (path and optional query string)
+    pub routes: Vec<String>,
+    /// Frontend URL (default: the Vite dev server, started when not running)
+    pub base_url: Option<String>,
+    /// Committed baseline PNGs (relative to the project root)
+    pub baseline_dir: String,
+    /// Maximum share of differing pixels per route, in percent
+    pub threshold: f64,
+    /// Per-channel difference (0-255) ignored as anti-aliasing noise
+    pub color_tolerance: u8,
+}
+
+impl Default for VisualConfig {
+    fn default() -> Self {
+        Self {
+            routes: vec!["/".to_string()],
+            base_url: None,
+            baseline_dir: "packages/app-frontend/visual-baselines".to_string(),
+            threshold: 0.1,
+            color_tolerance: 16,
+        }
+    }
+}
+
+/// `[budgets]`: frontend bundle size and build time limits for `akatsuki check budget`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BudgetConfig {
+    /// Sum of all emitted JS/CSS assets (e.g. "800 kB")
+    pub total: Option<String>,
+    /// Wall-clock time of the production build
+    pub build_seconds: Option<f64>,
+    /// Per-chunk limits keyed by chunk name without the content hash (e.g. "index.js")
+    pub chunks: BTreeMap<String, String>,
+}
+
+/// `[licenses]`: license policy for `akatsuki deps licenses`
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LicenseConfig {
+    /// Disallowed SPDX identifiers; `GPL-3.0` also matches `GPL-3.0-only` / `GPL-3.0-or-later`
+    pub deny: Vec<String>,
+    /// Packages exempt from the denylist (reviewed exceptions)
+    pub allow_packages: Vec<String>,
+}
+
+impl Default for LicenseConfig {
+    fn default() -> Self {
+        Self {
+            deny: ["AGPL-3.0", "GPL-2.0", "GPL-3.0", "SSPL-1.0"]
+                .map(String::from)
+                .to_vec(),
+            allow_packages: Vec::new(),
+        }
+    }
+}
+
+impl ProjectConfig {
+    /// Load akatsuki.toml from the project root (defaults when the file does not exist)
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Invalid {}", CONFIG_FILE))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Path of akatsuki.toml in the project root (may not exist)
+    pub fn path() -> PathBuf {
+        find_project_root().join(CONFIG_FILE)
+    }
+}
+
+/// Parse a human-readable size ("250 kB", "1.5MB", "900 B", or a plain byte count)
+pub fn parse_size(value: &str) -> Result<u64> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number
+        .parse()
+        .with_context(|| format!("Invalid size '{}'", value))?;
+
+    // Vite reports sizes in decimal units (1 kB = 1000 B)
+    let multiplier = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "kb" => 1_000.0,
+        "mb" => 1_000_000.0,
+        _ => anyhow::bail!("Unknown size unit in '{}' (use B, kB or MB)", value),
+    };
+    Ok((number * multiplier).round() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = ProjectConfig::parse(
+            r#"
+[budgets]
+total = "800 kB"
+build_seconds = 60
+
+[budgets.chunks]
+"index.js" = "300 kB"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.budgets.total.as_deref(), Some("800 kB"));
+        assert_eq!(config.budgets.build_seconds, Some(60.0));
+        assert_eq!(config.budgets.chunks["index.js"], "300 kB");
+
+        assert!(config.licenses.deny.contains(&"GPL-3.0".to_string()));
+
+        let config = ProjectConfig::parse("[plugins.deploy-preview]\nregion = \"tokyo\"").unwrap();
+        assert_eq!(
+            config.plugins["deploy-preview"]["region"].as_str(),
+            Some("tokyo")
+        );
+
+        let config = ProjectConfig::parse(
+            "[advice.report]\nslack_webhook_env = \"HEALTH_SLACK_URL\"\ninclude_ai = true",
+        )
+        .unwrap();
+        assert_eq!(config.advice.report.slack_webhook_env, "HEALTH_SLACK_URL");
+        assert_eq!(
+            config.advice.report.discord_webhook_env,
+            "DISCORD_WEBHOOK_URL"
+        );
+        assert!(config.advice.report.include_ai);
+
+        let config = ProjectConfig::parse(
+            "[branch]\nsupabase_branching = true\n\n[branch.projects]\n\"feature/billing\" = \"abcdefghijklmnop\"",
+        )
+        .unwrap();
+        assert!(config.branch.supabase_branching);
+        assert!(config.branch.seed);
+        assert_eq!(config.branch.env_files.len(), 2);
+        assert_eq!(
+            config.branch.projects["feature/billing"],
+            "abcdefghijklmnop"
+        );
+
+        let config = ProjectConfig::parse(
+            "[tasks.\"frontend:build\"]\ncommand = \"npm run build\"\ndepends_on = [\"wasm:build\"]",
+        )
+        .unwrap();
+        assert_eq!(config.tasks["frontend:build"].command, "npm run build");
+        assert_eq!(config.tasks["frontend:build"].depends_on, ["wasm:build"]);
+        assert!(config.tasks["frontend:build"].inputs.is_empty());
+
+        let config =
+            ProjectConfig::parse("[visual]\nroutes = [\"/\", \"/login\"]\nthreshold = 0.5")
+                .unwrap();
+        assert_eq!(config.visual.routes, ["/", "/login"]);
+        assert_eq!(config.visual.threshold, 0.5);
+        assert_eq!(config.visual.color_tolerance, 16);
+
+        let config = ProjectConfig::parse("[a11y]\nfail_on = \"critical\"").unwrap();
+        assert_eq!(config.a11y.fail_on, "critical");
+        assert_eq!(config.a11y.routes, ["/"]);
+        assert!(config.a11y.admin_pages);
+
+        let config = ProjectConfig::parse("[demo]\nrecords = 3\nimages_dir = \"demo/images\"").unwrap();
+        assert_eq!(config.demo.records, 3);
+        assert_eq!(config.demo.bucket, "demo");
+        assert_eq!(config.demo.images_dir.as_deref(), Some("demo/images"));
+        assert!(config.demo.password.is_none());
+
+        let config =
+            ProjectConfig::parse("[docs]\nsync_targets = [\"AGENT.md\", \"AGENT-mini.md\"]")
+                .unwrap();
+        assert_eq!(config.docs.sync_targets, ["AGENT.md", "AGENT-mini.md"]);
+        assert_eq!(
+            ProjectConfig::parse("").unwrap().docs.sync_targets,
+            ["AGENT.md"]
+        );
+        let config = ProjectConfig::parse(
+            "[docs]\ncontext_budget = 8000\n[docs.context_budgets]\n\"AGENT-mini.md\" = 2000",
+        )
+        .unwrap();
+        assert_eq!(config.docs.context_budget, 8000);
+        assert_eq!(config.docs.context_budgets["AGENT-mini.md"], 2000);
+        assert!(config.docs.context_files.contains(&"CLAUDE.md".to_string()));
+
+        let config = ProjectConfig::parse(
+            "[backend.providers.llm]\nconcurrency = 2\n[backend.providers.image]\nqueue_size = 10",
+        )
+        .unwrap();
+        assert_eq!(
+            config.backend.env(),
+            [
+                ("LLM_CONCURRENCY".to_string(), "2".to_string()),
+                ("IMAGE_QUEUE_SIZE".to_string(), "10".to_string()),
+            ]
+        );
+        assert!(ProjectConfig::parse("[backend.providers.video]\nconcurrency = 1").is_err());
+
+        assert!(ProjectConfig::parse("").unwrap().budgets.chunks.is_empty());
+        assert!(ProjectConfig::parse("[budget]\ntotal = 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("250 kB").unwrap(), 250_000);
+        assert_eq!(parse_size("1.5MB").unwrap(), 1_500_000);
+        assert_eq!(parse_size("900").unwrap(), 900);
+        assert!(parse_size("10 GiB").is_err());
+    }
+}
@@ -0,0 +1,72 @@
+/**
+ * Content Hash
+ * Stable hashing of file trees for the caches under .akatsuki/cache/
+ * (`akatsuki run` task keys, preflight results)
+ */
+use anyhow::{Context, Result};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use super::platform::slash_path;
+
+/// Directories never hashed when they appear under an input directory
+pub const IGNORED_DIRS: [&str; 5] = ["node_modules", "target", ".git", "dist", "coverage"];
+
+/// FNV-1a (64-bit): stable across Rust versions, unlike `DefaultHasher`
+pub struct ContentHasher(u64);
+
+impl ContentHasher {
+    pub fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Length-prefixed so ("ab", "c") and ("a", "bc") hash differently
+    pub fn write_str(&mut self, value: &str) {
+        self.write(&(value.len() as u64).to_le_bytes());
+        self.write(value.as_bytes());
+    }
+
+    /// Relative path and contents of every file under `inputs` (files or
+    /// directories relative to `root`)
+    pub fn write_files(&mut self, root: &Path, inputs: &[String]) -> Result<()> {
+        for file in input_files(root, inputs) {
+            let relative = slash_path(file.strip_prefix(root).unwrap_or(&file));
+            self.write_str(&relative);
+            let content =
+                fs::read(&file).with_context(|| format!("Failed to read {}", file.display()))?;
+            self.write(&content);
+        }
+        Ok(())
+    }
+
+    pub fn finish_hex(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+/// Every file under `inputs`, sorted (missing inputs are skipped)
+pub fn input_files(root: &Path, inputs: &[String]) -> BTreeSet<PathBuf> {
+    inputs
+        .iter()
+        .flat_map(|input| {
+            WalkDir::new(root.join(input))
+                .into_iter()
+                .filter_entry(|entry| {
+                    !(entry.file_type().is_dir()
+                        && IGNORED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref()))
+                })
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_type().is_file())
+                .map(|entry| entry.into_path())
+        })
+        .collect()
+}
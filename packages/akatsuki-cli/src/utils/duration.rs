@@ -0,0 +1,21 @@
+/// Compact elapsed time for command summaries (`akatsuki stats`, `report ci`):
+/// `420ms`, `42.0s`, `1m28s`
+pub fn format_duration(ms: u64) -> String {
+    match ms {
+        0..=999 => format!("{}ms", ms),
+        1_000..=59_999 => format!("{:.1}s", ms as f64 / 1000.0),
+        _ => format!("{}m{:02}s", ms / 60_000, (ms % 60_000) / 1000),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(420), "420ms");
+        assert_eq!(format_duration(42_000), "42.0s");
+        assert_eq!(format_duration(88_000), "1m28s");
+    }
+}
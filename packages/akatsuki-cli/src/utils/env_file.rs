@@ -0,0 +1,134 @@
+//! `.env` / `.env.example` key comparison
+
+use anyhow::{Context, Result};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// Packages whose `.env` is documented by a sibling `.env.example`
+pub const ENV_DIRS: [&str; 2] = ["packages/app-frontend", "packages/app-backend"];
+
+/// Keys present in only one of `.env` / `.env.example`
+#[derive(Debug, Default, PartialEq)]
+pub struct EnvDrift {
+    /// Set in `.env` but not documented in the example
+    pub missing_in_example: Vec<String>,
+    /// Required by the example but not set in `.env`
+    pub missing_in_env: Vec<String>,
+}
+
+impl EnvDrift {
+    pub fn is_empty(&self) -> bool {
+        self.missing_in_example.is_empty() && self.missing_in_env.is_empty()
+    }
+
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.missing_in_example.is_empty() {
+            parts.push(format!(
+                "undocumented in .env.example: {}",
+                self.missing_in_example.join(", ")
+            ));
+        }
+        if !self.missing_in_env.is_empty() {
+            parts.push(format!(
+                "not set in .env: {}",
+                self.missing_in_env.join(", ")
+            ));
+        }
+        parts.join("; ")
+    }
+}
+
+/// Compares `<dir>/.env` with `<dir>/.env.example` (None unless both exist)
+pub fn env_drift(dir: &Path) -> Result<Option<EnvDrift>> {
+    let (env_path, example_path) = (dir.join(".env"), dir.join(".env.example"));
+    if !env_path.exists() || !example_path.exists() {
+        return Ok(None);
+    }
+    let env = fs::read_to_string(&env_path)
+        .with_context(|| format!("Failed to read {}", env_path.display()))?;
+    let example = fs::read_to_string(&example_path)
+        .with_context(|| format!("Failed to read {}", example_path.display()))?;
+    Ok(Some(compare(&env, &example)))
+}
+
+fn compare(env: &str, example: &str) -> EnvDrift {
+    let set = keys(env, false);
+    // Commented-out keys (`# PORT=8000`) document optional settings
+    let documented = keys(example, true);
+    let required = keys(example, false);
+    EnvDrift {
+        missing_in_example: set.difference(&documented).cloned().collect(),
+        missing_in_env: required.difference(&set).cloned().collect(),
+    }
+}
+
+/// Keys of `KEY=value` lines, optionally including commented-out ones
+fn keys(content: &str, include_commented: bool) -> BTreeSet<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter_map(|line| match line.strip_prefix('#') {
+            Some(rest) if include_commented => Some(rest.trim()),
+            Some(_) => None,
+            None => Some(line),
+        })
+        .filter_map(|line| line.strip_prefix("export ").unwrap_or(line).split_once('='))
+        .map(|(key, _)| key.trim())
+        .filter(|key| is_env_key(key))
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_env_key(key: &str) -> bool {
+    key.starts_with(|c: char| c.is_ascii_uppercase() || c == '_')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Keys set in an env file (empty when it does not exist)
+pub fn set_keys(path: &Path) -> BTreeSet<String> {
+    fs::read_to_string(path)
+        .map(|content| keys(&content, false))
+        .unwrap_or_default()
+}
+
+/// Appends empty `KEY=` placeholders to an example file
+pub fn append_placeholders(example_path: &Path, keys: &[String]) -> Result<()> {
+    let mut content = fs::read_to_string(example_path)
+        .with_context(|| format!("Failed to read {}", example_path.display()))?;
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str("\n# Added by akatsuki setup check --fix (document these keys)\n");
+    for key in keys {
+        content.push_str(&format!("{}=\n", key));
+    }
+    fs::write(example_path, content)
+        .with_context(|| format!("Failed to write {}", example_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_env_with_example() {
+        let example =
+            "# Supabase\nDATABASE_URL=postgresql://...\nSUPABASE_URL=https://x.supabase.co\n\
+                       # PORT=8000\n# Optional: see https://example.com/a=b\n";
+        let env =
+            "DATABASE_URL=postgresql://local\nexport PORT=3000\nSTRIPE_KEY=sk_test\n# OLD_KEY=1\n";
+
+        assert_eq!(
+            compare(env, example),
+            EnvDrift {
+                missing_in_example: vec!["STRIPE_KEY".to_string()],
+                missing_in_env: vec!["SUPABASE_URL".to_string()],
+            }
+        );
+        assert!(compare(example, example).is_empty());
+    }
+}
@@ -2,6 +2,8 @@ use anyhow::Result;
 use std::fs;
 use std::path::PathBuf;
 
+use crate::commands::design::front_matter;
+
 /// Get project root directory (searches for Cargo.toml or package.json)
 pub fn get_project_root() -> Result<PathBuf> {
     let current_dir = std::env::current_dir()?;
@@ -91,6 +93,12 @@ pub fn extract_markdown_metadata(content: &str) -> MarkdownMetadata {
         .map(|m| m.as_str().to_string())
         .unwrap_or_else(|| "Unknown".to_string());
 
+    // Front matter (design docs created by `design new`) takes precedence
+    let (created, status) = match front_matter::parse(content) {
+        Ok(Some(meta)) => (meta.created, front_matter::label(meta.status).to_string()),
+        _ => (created, status),
+    };
+
     let description = desc_regex
         .captures(content)
         .and_then(|cap| cap.get(1))
@@ -0,0 +1,24 @@
+/**
+ * Git
+ * Runs git in the project and returns its output
+ */
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+/// `git <args>` in `root`, returning trimmed stdout (fails with git's stderr)
+pub fn git(root: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
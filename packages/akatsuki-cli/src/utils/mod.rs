@@ -1,6 +1,17 @@
+pub mod backend;
+pub mod config;
+pub mod content_hash;
+pub mod duration;
+pub mod env_file;
 pub mod file;
+pub mod git;
+pub mod output;
+pub mod platform;
+pub mod process;
 pub mod project;
 pub mod prompt;
+pub mod supabase;
+pub mod telemetry;
 pub mod template;
 
 pub use file::*;
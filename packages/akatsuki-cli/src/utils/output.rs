@@ -0,0 +1,108 @@
+/**
+ * Output Formatter
+ * Global `--output text|json` flag for scripts and AI tooling
+ *
+ * Commands with structured results build a Serialize value and pass it to
+ * `emit` along with their text renderer. In JSON mode stdout carries exactly
+ * one JSON document: the result, or `{"error": ...}` when the command fails.
+ */
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Colored text for people
+    #[default]
+    Text,
+    /// One JSON document on stdout
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OutputFormatter {
+    format: OutputFormat,
+}
+
+/// Failure whose details are already part of the emitted JSON result;
+/// `main` exits non-zero without printing another document
+#[derive(Debug)]
+pub struct AlreadyReported(String);
+
+impl fmt::Display for AlreadyReported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for AlreadyReported {}
+
+#[derive(Serialize)]
+struct ErrorOutput {
+    error: String,
+    /// Underlying errors, outermost first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    causes: Vec<String>,
+}
+
+impl OutputFormatter {
+    pub fn new(format: OutputFormat) -> Self {
+        Self { format }
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.format == OutputFormat::Json
+    }
+
+    /// Print `value` as JSON, or call `text` to render it for people
+    pub fn emit<T: Serialize + ?Sized>(&self, value: &T, text: impl FnOnce(&T)) -> Result<()> {
+        match self.format {
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(value)?),
+            OutputFormat::Text => text(value),
+        }
+        Ok(())
+    }
+
+    /// Error for a failed check whose result was already emitted
+    pub fn failed(&self, message: String) -> anyhow::Error {
+        match self.format {
+            OutputFormat::Json => AlreadyReported(message).into(),
+            OutputFormat::Text => anyhow::anyhow!(message),
+        }
+    }
+
+    /// Print the error of a failed command as JSON
+    pub fn print_error(&self, err: &anyhow::Error) {
+        if err.downcast_ref::<AlreadyReported>().is_some() {
+            return;
+        }
+        let output = ErrorOutput {
+            error: err.to_string(),
+            causes: err.chain().skip(1).map(|cause| cause.to_string()).collect(),
+        };
+        if let Ok(json) = serde_json::to_string_pretty(&output) {
+            println!("{}", json);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_is_already_reported_only_in_json_mode() {
+        let json = OutputFormatter::new(OutputFormat::Json);
+        let text = OutputFormatter::default();
+
+        assert!(json
+            .failed("1 schema file(s) failed validation".to_string())
+            .downcast_ref::<AlreadyReported>()
+            .is_some());
+        assert!(text
+            .failed("1 schema file(s) failed validation".to_string())
+            .downcast_ref::<AlreadyReported>()
+            .is_none());
+    }
+}
@@ -0,0 +1,171 @@
+/**
+ * Platform
+ * Windows 対応: npm.cmd / supabase.exe などの解決、パス区切りの正規化、ファイル探索
+ *
+ * `Command::new("npm")` only finds `npm.exe` on Windows, while npm, npx and
+ * npm-installed CLIs (supabase, claude) are `.cmd` shims there.
+ */
+use anyhow::Result;
+use std::ffi::OsStr;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use walkdir::WalkDir;
+
+use super::content_hash::IGNORED_DIRS;
+
+/// Default `PATHEXT` when the variable is unset
+const DEFAULT_PATHEXT: &str = ".COM;.EXE;.BAT;.CMD";
+
+/// `Command::new` that also resolves `.cmd` / `.bat` shims on Windows
+pub fn command(program: &str) -> Command {
+    Command::new(resolve_program(program))
+}
+
+/// Full path of `program` on Windows (searching PATH with PATHEXT); the name itself elsewhere
+pub fn resolve_program(program: &str) -> PathBuf {
+    if !cfg!(windows) {
+        return PathBuf::from(program);
+    }
+    let pathext = std::env::var("PATHEXT").unwrap_or_else(|_| DEFAULT_PATHEXT.to_string());
+    std::env::var_os("PATH")
+        .and_then(|path| find_in_path(program, &path, &pathext))
+        .unwrap_or_else(|| PathBuf::from(program))
+}
+
+fn find_in_path(program: &str, path: &OsStr, pathext: &str) -> Option<PathBuf> {
+    // Explicit paths and names with an extension are used as-is
+    if Path::new(program).extension().is_some() || program.contains(['/', '\\']) {
+        return None;
+    }
+    std::env::split_paths(path).find_map(|dir| {
+        pathext
+            .split(';')
+            .filter(|ext| !ext.is_empty())
+            .map(|ext| dir.join(format!("{}{}", program, ext.to_ascii_lowercase())))
+            .find(|candidate| candidate.is_file())
+    })
+}
+
+/// `command` run by the platform shell (`cmd /C` on Windows, `sh -c` elsewhere)
+pub fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+/// Clipboard commands in order of preference (text on stdin)
+const CLIPBOARD_COMMANDS: &[&[&str]] = if cfg!(target_os = "macos") {
+    &[&["pbcopy"]]
+} else if cfg!(windows) {
+    &[&["clip"]]
+} else {
+    &[
+        &["wl-copy"],
+        &["xclip", "-selection", "clipboard"],
+        &["xsel", "--clipboard", "--input"],
+    ]
+};
+
+/// Copy `text` with the first clipboard command that is installed
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    for candidate in CLIPBOARD_COMMANDS {
+        let Ok(mut child) = command(candidate[0])
+            .args(&candidate[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+        if child.wait()?.success() {
+            return Ok(());
+        }
+    }
+    let tried: Vec<&str> = CLIPBOARD_COMMANDS.iter().map(|c| c[0]).collect();
+    anyhow::bail!(
+        "No clipboard command available (tried {})",
+        tried.join(", ")
+    )
+}
+
+/// Path with `/` separators (for display, matching and cache keys)
+pub fn slash_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Files under `dir` (at most `max_depth` levels deep) whose name matches,
+/// skipping [`IGNORED_DIRS`]. Replaces `find`, which Windows doesn't have.
+pub fn find_files(dir: &Path, max_depth: usize, matches: impl Fn(&str) -> bool) -> Vec<PathBuf> {
+    WalkDir::new(dir)
+        .max_depth(max_depth)
+        .into_iter()
+        .filter_entry(|entry| {
+            !(entry.file_type().is_dir()
+                && IGNORED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref()))
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| matches(&entry.file_name().to_string_lossy()))
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_find_in_path_resolves_shims() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        fs::write(second.path().join("npm.cmd"), "").unwrap();
+        fs::write(first.path().join("supabase.exe"), "").unwrap();
+        let path = std::env::join_paths([first.path(), second.path()]).unwrap();
+
+        assert_eq!(
+            find_in_path("npm", &path, ".EXE;.CMD"),
+            Some(second.path().join("npm.cmd"))
+        );
+        assert_eq!(
+            find_in_path("supabase", &path, ".EXE;.CMD"),
+            Some(first.path().join("supabase.exe"))
+        );
+        assert_eq!(find_in_path("npm", &path, ".EXE"), None);
+        assert_eq!(find_in_path("npm.cmd", &path, ".CMD"), None);
+    }
+
+    #[test]
+    fn test_find_files_skips_ignored_dirs() {
+        let root = tempfile::tempdir().unwrap();
+        for file in [
+            "a-design.md",
+            "docs/b-design.md",
+            "node_modules/c-design.md",
+        ] {
+            let path = root.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        let is_design = |name: &str| name.ends_with("-design.md");
+
+        let mut found: Vec<String> = find_files(root.path(), usize::MAX, is_design)
+            .iter()
+            .map(|path| slash_path(path.strip_prefix(root.path()).unwrap()))
+            .collect();
+        found.sort();
+        assert_eq!(found, ["a-design.md", "docs/b-design.md"]);
+        assert_eq!(find_files(root.path(), 1, is_design).len(), 1);
+    }
+}
@@ -0,0 +1,291 @@
+/**
+ * External Process Timing
+ * 外部コマンド (npm / cargo など) の実行時間とピークメモリを記録
+ *
+ * Every invocation made through [`TimedCommand`] is recorded for the current
+ * CLI run; `--profile` on check / preflight / build prints the breakdown.
+ */
+use anyhow::Result;
+use colored::Colorize;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// One finished external command
+#[derive(Debug, Clone)]
+pub struct ProcessStats {
+    /// Short description, e.g. "cargo check (app-backend)"
+    pub label: String,
+    pub duration: Duration,
+    /// Peak resident set size of the process tree (unix only)
+    pub peak_rss_kb: Option<u64>,
+    pub success: bool,
+}
+
+static RECORDED: Mutex<Vec<ProcessStats>> = Mutex::new(Vec::new());
+
+/// `status()` / `output()` replacements that record timing and memory
+pub trait TimedCommand {
+    fn timed_status(&mut self) -> io::Result<ExitStatus>;
+    /// Like [`Command::output`]: stdin is null, stdout/stderr are captured
+    fn timed_output(&mut self) -> io::Result<Output>;
+}
+
+impl TimedCommand for Command {
+    fn timed_status(&mut self) -> io::Result<ExitStatus> {
+        let label = label(self);
+        let started = Instant::now();
+        let child = self.spawn()?;
+        let (status, peak_rss_kb) = wait(child)?;
+        record(label, started.elapsed(), peak_rss_kb, status.success());
+        Ok(status)
+    }
+
+    fn timed_output(&mut self) -> io::Result<Output> {
+        let label = label(self);
+        let started = Instant::now();
+        let mut child = self
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // Drain both pipes before waiting so a chatty child can't block
+        let mut stderr_pipe = child.stderr.take();
+        let stderr_reader = std::thread::spawn(move || {
+            let mut buffer = Vec::new();
+            if let Some(pipe) = stderr_pipe.as_mut() {
+                let _ = pipe.read_to_end(&mut buffer);
+            }
+            buffer
+        });
+        let mut stdout = Vec::new();
+        if let Some(mut pipe) = child.stdout.take() {
+            pipe.read_to_end(&mut stdout)?;
+        }
+        let stderr = stderr_reader.join().unwrap_or_default();
+
+        let (status, peak_rss_kb) = wait(child)?;
+        record(label, started.elapsed(), peak_rss_kb, status.success());
+        Ok(Output {
+            status,
+            stdout,
+            stderr,
+        })
+    }
+}
+
+/// Commands recorded so far in this run, in execution order
+pub fn recorded() -> Vec<ProcessStats> {
+    RECORDED
+        .lock()
+        .map(|stats| stats.clone())
+        .unwrap_or_default()
+}
+
+/// Run `f`, then print the subprocess breakdown when `profile` is set
+/// (also on failure, which is often when the numbers matter most)
+pub fn profiled<F>(profile: bool, f: F) -> Result<()>
+where
+    F: FnOnce() -> Result<()>,
+{
+    let started = Instant::now();
+    let result = f();
+    if profile {
+        print_profile(&recorded(), started.elapsed());
+    }
+    result
+}
+
+fn print_profile(stats: &[ProcessStats], wall: Duration) {
+    println!();
+    println!("{}", "⏱️  Profile".bright_cyan().bold());
+    println!("{}", "─".repeat(50).bright_black());
+
+    if stats.is_empty() {
+        println!("{}", "  No external commands were run".bright_black());
+        return;
+    }
+
+    let mut sorted = stats.to_vec();
+    sorted.sort_by_key(|stat| std::cmp::Reverse(stat.duration));
+    let width = sorted
+        .iter()
+        .map(|stat| stat.label.len())
+        .max()
+        .unwrap_or(0)
+        .max("akatsuki (in-process)".len());
+
+    for stat in &sorted {
+        let line = format!(
+            "  {:<width$}  {:>8}  {:>4.0}%  {:>9}",
+            stat.label,
+            format_seconds(stat.duration),
+            share(stat.duration, wall),
+            stat.peak_rss_kb.map(format_memory).unwrap_or_default(),
+            width = width
+        );
+        if stat.success {
+            println!("{}", line);
+        } else {
+            println!("{}  {}", line.red(), "failed".red());
+        }
+    }
+
+    let external: Duration = stats.iter().map(|stat| stat.duration).sum();
+    println!("{}", "─".repeat(50).bright_black());
+    println!(
+        "  {:<width$}  {:>8}  {:>4.0}%",
+        "akatsuki (in-process)",
+        format_seconds(wall.saturating_sub(external)),
+        share(wall.saturating_sub(external), wall),
+        width = width
+    );
+    println!(
+        "  {:<width$}  {:>8}",
+        "total".bold(),
+        format_seconds(wall).bold(),
+        width = width
+    );
+}
+
+fn record(label: String, duration: Duration, peak_rss_kb: Option<u64>, success: bool) {
+    if let Ok(mut stats) = RECORDED.lock() {
+        stats.push(ProcessStats {
+            label,
+            duration,
+            peak_rss_kb,
+            success,
+        });
+    }
+}
+
+/// "npm run typecheck --workspace=app-frontend" → "npm typecheck (app-frontend)"
+fn label(command: &Command) -> String {
+    // file_stem: `npm.cmd` on Windows is labelled `npm`
+    let program = Path::new(command.get_program())
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let args: Vec<String> = command
+        .get_args()
+        .map(|arg| arg.to_string_lossy().to_string())
+        .collect();
+
+    let subcommand = args
+        .iter()
+        .find(|arg| !arg.starts_with('-') && arg.as_str() != "run")
+        .filter(|arg| {
+            arg.chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, ':' | '-' | '_'))
+        });
+    let context = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("--workspace=").map(str::to_string))
+        .or_else(|| {
+            command
+                .get_current_dir()
+                .and_then(Path::file_name)
+                .map(|name| name.to_string_lossy().to_string())
+        });
+
+    let mut label = program;
+    if let Some(subcommand) = subcommand {
+        label = format!("{} {}", label, subcommand);
+    }
+    if let Some(context) = context {
+        label = format!("{} ({})", label, context);
+    }
+    label
+}
+
+/// Reap the child, collecting its peak RSS via wait4 where available
+#[cfg(unix)]
+fn wait(child: Child) -> io::Result<(ExitStatus, Option<u64>)> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let mut status = 0;
+    // SAFETY: rusage is plain old data; wait4 fills it for our own child pid
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    loop {
+        let pid = unsafe { libc::wait4(child.id() as libc::pid_t, &mut status, 0, &mut usage) };
+        if pid != -1 {
+            break;
+        }
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+
+    // ru_maxrss is kilobytes on Linux but bytes on macOS
+    let max_rss = usage.ru_maxrss.max(0) as u64;
+    let peak_rss_kb = if cfg!(target_os = "macos") {
+        max_rss / 1024
+    } else {
+        max_rss
+    };
+    Ok((ExitStatus::from_raw(status), Some(peak_rss_kb)))
+}
+
+#[cfg(not(unix))]
+fn wait(mut child: Child) -> io::Result<(ExitStatus, Option<u64>)> {
+    Ok((child.wait()?, None))
+}
+
+fn share(part: Duration, total: Duration) -> f64 {
+    if total.is_zero() {
+        0.0
+    } else {
+        part.as_secs_f64() / total.as_secs_f64() * 100.0
+    }
+}
+
+fn format_seconds(duration: Duration) -> String {
+    format!("{:.1}s", duration.as_secs_f64())
+}
+
+fn format_memory(kb: u64) -> String {
+    if kb >= 1024 * 1024 {
+        format!("{:.1} GB", kb as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{} MB", kb / 1024)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label() {
+        let mut npm = Command::new("npm");
+        npm.args(["run", "typecheck", "--workspace=app-frontend"]);
+        assert_eq!(label(&npm), "npm typecheck (app-frontend)");
+
+        let mut cargo = Command::new("cargo");
+        cargo
+            .args(["clippy", "--", "-D", "warnings"])
+            .current_dir("packages/app-backend");
+        assert_eq!(label(&cargo), "cargo clippy (app-backend)");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_timed_output_records_stats() {
+        let output = Command::new("sh")
+            .args(["-c", "echo out; echo err >&2; exit 3"])
+            .timed_output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(output.stdout, b"out\n");
+        assert_eq!(output.stderr, b"err\n");
+
+        let stats = recorded();
+        let stat = stats.iter().find(|stat| stat.label == "sh").unwrap();
+        assert!(!stat.success);
+        assert!(stat.peak_rss_kb.is_some_and(|kb| kb > 0));
+    }
+}
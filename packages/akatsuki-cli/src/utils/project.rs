@@ -2,7 +2,8 @@
  * Project utilities
  * プロジェクトルート検出など
  */
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
 /// Find Akatsuki project root directory
 ///
@@ -40,10 +41,110 @@ pub fn find_project_root() -> PathBuf {
     }
 }
 
+/// Rust packages handled by fmt / lint / check
+pub const BACKEND_PACKAGE: &str = "packages/app-backend";
+pub const ADMIN_CLI_PACKAGE: &str = "packages/akatsuki-cli";
+
+/// Cargo workspace declared in the project root's Cargo.toml
+#[derive(Debug, Clone, PartialEq)]
+pub struct CargoWorkspace {
+    pub root: PathBuf,
+    /// `members` entries (exact paths or `dir/*` globs)
+    pub members: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl CargoWorkspace {
+    /// `None` when the project root has no Cargo.toml with a `[workspace]` table
+    pub fn detect(project_root: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(project_root.join("Cargo.toml")).ok()?;
+        Self::parse(project_root, &content)
+    }
+
+    pub fn parse(project_root: &Path, content: &str) -> Option<Self> {
+        let manifest: toml::Value = content.parse().ok()?;
+        let workspace = manifest.get("workspace")?;
+        let strings = |key: &str| -> Vec<String> {
+            workspace
+                .get(key)
+                .and_then(|value| value.as_array())
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|item| item.as_str())
+                        .map(|item| item.trim_end_matches('/').to_string())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Some(Self {
+            root: project_root.to_path_buf(),
+            members: strings("members"),
+            exclude: strings("exclude"),
+        })
+    }
+
+    /// Whether `package_dir` (relative to the root, e.g. "packages/app-backend") is a member
+    pub fn contains(&self, package_dir: &str) -> bool {
+        let matches = |pattern: &String| match pattern.strip_suffix("/*") {
+            Some(parent) => Path::new(package_dir).parent() == Some(Path::new(parent)),
+            None => pattern == package_dir,
+        };
+        self.members.iter().any(matches) && !self.exclude.iter().any(matches)
+    }
+}
+
+/// `[package] name` of the crate in `dir`
+pub fn cargo_package_name(dir: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    let manifest: toml::Value = content.parse().ok()?;
+    manifest
+        .get("package")?
+        .get("name")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// `cargo <subcommand>` for one package
+///
+/// Workspace members run from the workspace root with `-p <name>` so every
+/// package shares one target dir; otherwise cargo runs in the package directory.
+pub fn cargo_package_command(project_root: &Path, package_dir: &str, subcommand: &str) -> Command {
+    let mut command = Command::new("cargo");
+    command.arg(subcommand);
+
+    let package_path = project_root.join(package_dir);
+    let workspace_package = CargoWorkspace::detect(project_root)
+        .filter(|workspace| workspace.contains(package_dir))
+        .and_then(|_| cargo_package_name(&package_path));
+    match workspace_package {
+        Some(name) => command.args(["-p", &name]).current_dir(project_root),
+        None => command.current_dir(package_path),
+    };
+    command
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cargo_workspace_members() {
+        let root = Path::new("/repo");
+        let workspace = CargoWorkspace::parse(
+            root,
+            "[workspace]\nmembers = [\"packages/app-backend\", \"wasm-modules/*\"]\nexclude = [\"wasm-modules/legacy\"]\n",
+        )
+        .unwrap();
+        assert!(workspace.contains("packages/app-backend"));
+        assert!(workspace.contains("wasm-modules/sample-module"));
+        assert!(!workspace.contains("wasm-modules/legacy"));
+        assert!(!workspace.contains("packages/akatsuki-cli"));
+
+        assert!(CargoWorkspace::parse(root, "[package]\nname = \"x\"\n").is_none());
+    }
+
     #[test]
     fn test_find_project_root() {
         let root = find_project_root();
@@ -0,0 +1,424 @@
+/**
+ * Supabase Admin Client
+ * Auth admin API, PostgREST and Storage calls made with the service role
+ * key (bypasses RLS; used by `akatsuki demo`, `akatsuki users` and
+ * `akatsuki storage`)
+ *
+ * SUPABASE_URL / SUPABASE_SERVICE_ROLE_KEY come from the environment, else
+ * from packages/app-backend/.env.
+ */
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::commands::db::branch::{parent_project_ref, parse_env, project_ref_from_url};
+use crate::commands::db::console::BACKEND_ENV;
+
+/// Page size of the admin user list
+const USERS_PER_PAGE: usize = 200;
+/// Page size of the Storage object list
+const OBJECTS_PER_PAGE: usize = 1000;
+
+pub struct SupabaseAdmin {
+    client: reqwest::blocking::Client,
+    url: String,
+    key: String,
+}
+
+/// Auth user as returned by the admin API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthUser {
+    pub id: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub last_sign_in_at: Option<String>,
+    #[serde(default)]
+    pub invited_at: Option<String>,
+    #[serde(default)]
+    pub email_confirmed_at: Option<String>,
+    #[serde(default)]
+    pub banned_until: Option<String>,
+    #[serde(default)]
+    pub app_metadata: Value,
+}
+
+impl AuthUser {
+    /// `app_metadata.role` (checked by the admin RLS policies and `is_admin()`)
+    pub fn role(&self) -> Option<&str> {
+        self.app_metadata["role"].as_str()
+    }
+
+    /// Banned until a time after `now`
+    pub fn is_disabled(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.banned_until
+            .as_deref()
+            .and_then(|until| chrono::DateTime::parse_from_rfc3339(until).ok())
+            .is_some_and(|until| until > now)
+    }
+}
+
+/// Object (or folder) in a Storage list, named relative to the listed prefix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageObject {
+    pub name: String,
+    /// `None` for folders
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// `size`, `mimetype`, ... (`None` for folders)
+    #[serde(default)]
+    pub metadata: Option<Value>,
+}
+
+impl StorageObject {
+    pub fn is_folder(&self) -> bool {
+        self.id.is_none()
+    }
+
+    pub fn size(&self) -> Option<u64> {
+        self.metadata.as_ref()?.get("size")?.as_u64()
+    }
+}
+
+#[derive(Deserialize)]
+struct SignedUrl {
+    #[serde(rename = "signedURL")]
+    signed_url: String,
+}
+
+#[derive(Deserialize)]
+struct UserPage {
+    users: Vec<AuthUser>,
+}
+
+#[derive(Deserialize)]
+struct InsertedRow {
+    id: Value,
+}
+
+impl SupabaseAdmin {
+    pub fn new(url: &str, key: &str) -> Result<Self> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()?;
+        Ok(Self {
+            client,
+            url: url.trim_end_matches('/').to_string(),
+            key: key.to_string(),
+        })
+    }
+
+    /// Client for SUPABASE_URL with SUPABASE_SERVICE_ROLE_KEY
+    pub fn from_env(project_root: &Path) -> Result<Self> {
+        let env = fs::read_to_string(project_root.join(BACKEND_ENV))
+            .map(|content| parse_env(&content))
+            .unwrap_or_default();
+        let read = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .or_else(|| env.get(name).cloned())
+                .filter(|value| !value.is_empty())
+                .with_context(|| {
+                    format!("{} not found in the environment or {}", name, BACKEND_ENV)
+                })
+        };
+        Self::new(&read("SUPABASE_URL")?, &read("SUPABASE_SERVICE_ROLE_KEY")?)
+    }
+
+    /// Project URL without a trailing slash
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Every user, oldest first
+    pub fn list_users(&self) -> Result<Vec<AuthUser>> {
+        let mut users = Vec::new();
+        for page in 1.. {
+            let response = self
+                .request(
+                    reqwest::Method::GET,
+                    &format!(
+                        "/auth/v1/admin/users?page={}&per_page={}",
+                        page, USERS_PER_PAGE
+                    ),
+                )
+                .send()
+                .context("Failed to reach Supabase Auth")?;
+            let batch: UserPage = check(response)?.json()?;
+            let done = batch.users.len() < USERS_PER_PAGE;
+            users.extend(batch.users);
+            if done {
+                break;
+            }
+        }
+        Ok(users)
+    }
+
+    /// Send an invitation email (the user sets a password from the link)
+    pub fn invite_user(&self, email: &str, redirect_to: Option<&str>) -> Result<AuthUser> {
+        let mut request = self.request(reqwest::Method::POST, "/auth/v1/invite");
+        if let Some(redirect_to) = redirect_to {
+            request = request.query(&[("redirect_to", redirect_to)]);
+        }
+        let response = request
+            .json(&json!({ "email": email }))
+            .send()
+            .context("Failed to reach Supabase Auth")?;
+        Ok(check(response)?.json()?)
+    }
+
+    /// Admin update (`app_metadata` is merged; null values remove keys)
+    pub fn update_user(&self, id: &str, changes: &Value) -> Result<AuthUser> {
+        let response = self
+            .request(
+                reqwest::Method::PUT,
+                &format!("/auth/v1/admin/users/{}", id),
+            )
+            .json(changes)
+            .send()
+            .context("Failed to reach Supabase Auth")?;
+        Ok(check(response)?.json()?)
+    }
+
+    /// Confirmed user (no email is sent); returns its id
+    pub fn create_user(&self, email: &str, password: &str) -> Result<String> {
+        let response = self
+            .request(reqwest::Method::POST, "/auth/v1/admin/users")
+            .json(&json!({
+                "email": email,
+                "password": password,
+                "email_confirm": true,
+                "user_metadata": { "demo": true },
+            }))
+            .send()
+            .context("Failed to reach Supabase Auth")?;
+        let user: AuthUser = check(response)?.json()?;
+        Ok(user.id)
+    }
+
+    /// Already deleted users are fine
+    pub fn delete_user(&self, id: &str) -> Result<()> {
+        let response = self
+            .request(
+                reqwest::Method::DELETE,
+                &format!("/auth/v1/admin/users/{}", id),
+            )
+            .send()
+            .context("Failed to reach Supabase Auth")?;
+        if response.status() != reqwest::StatusCode::NOT_FOUND {
+            check(response)?;
+        }
+        Ok(())
+    }
+
+    /// Insert rows and return their ids
+    pub fn insert(&self, table: &str, rows: &[Value]) -> Result<Vec<String>> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/rest/v1/{}?select=id", table),
+            )
+            .header("Prefer", "return=representation")
+            .json(rows)
+            .send()
+            .context("Failed to reach PostgREST")?;
+        let inserted: Vec<InsertedRow> = check(response)?.json()?;
+        Ok(inserted
+            .into_iter()
+            .map(|row| match row.id {
+                Value::String(id) => id,
+                id => id.to_string(),
+            })
+            .collect())
+    }
+
+    pub fn delete_rows(&self, table: &str, ids: &[String]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let response = self
+            .request(
+                reqwest::Method::DELETE,
+                &format!("/rest/v1/{}?id=in.({})", table, ids.join(",")),
+            )
+            .send()
+            .context("Failed to reach PostgREST")?;
+        check(response)?;
+        Ok(())
+    }
+
+    /// Create a public bucket unless it exists
+    pub fn ensure_bucket(&self, bucket: &str) -> Result<()> {
+        let response = self
+            .request(reqwest::Method::POST, "/storage/v1/bucket")
+            .json(&json!({ "id": bucket, "name": bucket, "public": true }))
+            .send()
+            .context("Failed to reach Supabase Storage")?;
+        let status = response.status();
+        if status.is_success() || status == reqwest::StatusCode::CONFLICT {
+            return Ok(());
+        }
+        // Storage answers 400 with statusCode "409" for existing buckets
+        let body = response.text().unwrap_or_default();
+        if body.contains("409") || body.contains("already exists") {
+            return Ok(());
+        }
+        anyhow::bail!("Supabase returned {}: {}", status, body.trim());
+    }
+
+    pub fn upload(
+        &self,
+        bucket: &str,
+        path: &str,
+        bytes: Vec<u8>,
+        content_type: &str,
+    ) -> Result<()> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/storage/v1/object/{}", object_path(bucket, path)),
+            )
+            .header("Content-Type", content_type)
+            .header("x-upsert", "true")
+            .body(bytes)
+            .send()
+            .context("Failed to reach Supabase Storage")?;
+        check(response)?;
+        Ok(())
+    }
+
+    pub fn public_url(&self, bucket: &str, path: &str) -> String {
+        format!("{}/storage/v1/object/public/{}/{}", self.url, bucket, path)
+    }
+
+    /// Objects and folders directly under `prefix` (`""` for the bucket root)
+    pub fn list_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<StorageObject>> {
+        let mut objects = Vec::new();
+        loop {
+            let response = self
+                .request(
+                    reqwest::Method::POST,
+                    &format!("/storage/v1/object/list/{}", bucket),
+                )
+                .json(&json!({
+                    "prefix": prefix,
+                    "limit": OBJECTS_PER_PAGE,
+                    "offset": objects.len(),
+                    "sortBy": { "column": "name", "order": "asc" },
+                }))
+                .send()
+                .context("Failed to reach Supabase Storage")?;
+            let page: Vec<StorageObject> = check(response)?.json()?;
+            let done = page.len() < OBJECTS_PER_PAGE;
+            objects.extend(page);
+            if done {
+                break;
+            }
+        }
+        Ok(objects)
+    }
+
+    pub fn download(&self, bucket: &str, path: &str) -> Result<Vec<u8>> {
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                &format!("/storage/v1/object/{}", object_path(bucket, path)),
+            )
+            .send()
+            .context("Failed to reach Supabase Storage")?;
+        Ok(check(response)?.bytes()?.to_vec())
+    }
+
+    /// URL that reads a (private) object without a key until it expires
+    pub fn sign_url(&self, bucket: &str, path: &str, expires_in: u64) -> Result<String> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/storage/v1/object/sign/{}", object_path(bucket, path)),
+            )
+            .json(&json!({ "expiresIn": expires_in }))
+            .send()
+            .context("Failed to reach Supabase Storage")?;
+        let signed: SignedUrl = check(response)?.json()?;
+        Ok(format!("{}/storage/v1{}", self.url, signed.signed_url))
+    }
+
+    pub fn remove_objects(&self, bucket: &str, paths: &[String]) -> Result<()> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+        let response = self
+            .request(
+                reqwest::Method::DELETE,
+                &format!("/storage/v1/object/{}", bucket),
+            )
+            .json(&json!({ "prefixes": paths }))
+            .send()
+            .context("Failed to reach Supabase Storage")?;
+        check(response)?;
+        Ok(())
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::blocking::RequestBuilder {
+        self.client
+            .request(method, format!("{}{}", self.url, path))
+            .header("apikey", &self.key)
+            .bearer_auth(&self.key)
+    }
+}
+
+fn check(response: reqwest::blocking::Response) -> Result<reqwest::blocking::Response> {
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().unwrap_or_default();
+        anyhow::bail!("Supabase returned {}: {}", status, body.trim());
+    }
+    Ok(response)
+}
+
+/// `bucket/path` with each segment percent-encoded (spaces, `#`, `?`, ...)
+fn object_path(bucket: &str, path: &str) -> String {
+    let mut encoded = format!("{}/", bucket);
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// Project ref of `url` when it is the parent (production) project
+pub fn production_ref(project_root: &Path, url: &str) -> Option<String> {
+    let production = parent_project_ref(project_root)?;
+    (project_ref_from_url(url)? == production).then_some(production)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_path_encoding() {
+        assert_eq!(
+            object_path("assets", "icons/logo-v2.png"),
+            "assets/icons/logo-v2.png"
+        );
+        assert_eq!(
+            object_path("assets", "my file#1?.png"),
+            "assets/my%20file%231%3F.png"
+        );
+        assert_eq!(
+            object_path("assets", "写真.jpg"),
+            "assets/%E5%86%99%E7%9C%9F.jpg"
+        );
+    }
+}
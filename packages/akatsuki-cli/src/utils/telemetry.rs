@@ -0,0 +1,151 @@
+/**
+ * Telemetry (opt-in)
+ * コマンド名・所要時間・成否をローカル (.akatsuki/telemetry.jsonl) に記録
+ *
+ * Enabled by `[telemetry] enabled = true` in akatsuki.toml or AKATSUKI_TELEMETRY=1.
+ * Only subcommand names are recorded (no arguments, paths or user data).
+ * DO_NOT_TRACK=1 always disables it.
+ */
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use super::config::ProjectConfig;
+use super::find_project_root;
+
+pub const TELEMETRY_LOG: &str = ".akatsuki/telemetry.jsonl";
+
+/// One command invocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetryEvent {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Subcommand chain, e.g. "db push" or "check"
+    pub command: String,
+    pub duration_ms: u64,
+    pub success: bool,
+    pub version: String,
+    pub os: String,
+}
+
+pub fn log_path() -> PathBuf {
+    find_project_root().join(TELEMETRY_LOG)
+}
+
+/// Subcommand chain from parsed arguments (values are never included)
+pub fn command_name(matches: &clap::ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub)) = current.subcommand() {
+        names.push(name.to_string());
+        current = sub;
+    }
+    names.join(" ")
+}
+
+/// Whether telemetry is on: DO_NOT_TRACK > AKATSUKI_TELEMETRY > akatsuki.toml
+fn enabled(config_enabled: bool) -> bool {
+    let flag = |key: &str| {
+        std::env::var(key)
+            .ok()
+            .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+    };
+    if flag("DO_NOT_TRACK") == Some(true) {
+        return false;
+    }
+    flag("AKATSUKI_TELEMETRY").unwrap_or(config_enabled)
+}
+
+/// Record a finished command. Never fails the command itself.
+pub fn record(command: &str, duration: Duration, success: bool) {
+    if command.is_empty() {
+        return;
+    }
+    let Ok(config) = ProjectConfig::load() else {
+        return;
+    };
+    if !enabled(config.telemetry.enabled) {
+        return;
+    }
+
+    let event = TelemetryEvent {
+        timestamp: chrono::Utc::now(),
+        command: command.to_string(),
+        duration_ms: duration.as_millis() as u64,
+        success,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        os: std::env::consts::OS.to_string(),
+    };
+
+    let _ = append(&event);
+    if let Some(endpoint) = &config.telemetry.endpoint {
+        let _ = post(endpoint, &event);
+    }
+}
+
+fn append(event: &TelemetryEvent) -> Result<()> {
+    let path = log_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+    writeln!(file, "{}", serde_json::to_string(event)?)?;
+    Ok(())
+}
+
+fn post(endpoint: &str, event: &TelemetryEvent) -> Result<()> {
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(2))
+        .build()?
+        .post(endpoint)
+        .json(event)
+        .send()?
+        .error_for_status()?;
+    Ok(())
+}
+
+/// Events from the local log (malformed lines are skipped)
+pub fn read_events() -> Result<Vec<TelemetryEvent>> {
+    let path = log_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(parse_events(&content))
+}
+
+pub fn parse_events(content: &str) -> Vec<TelemetryEvent> {
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Arg, Command};
+
+    #[test]
+    fn test_command_name() {
+        let cli = Command::new("akatsuki")
+            .subcommand(Command::new("db").subcommand(Command::new("push").arg(Arg::new("name"))));
+        let matches = cli.get_matches_from(["akatsuki", "db", "push", "secret-value"]);
+        assert_eq!(command_name(&matches), "db push");
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let content = r#"{"timestamp":"2025-12-01T00:00:00Z","command":"check","duration_ms":42000,"success":true,"version":"0.1.0","os":"linux"}
+not json
+"#;
+        let events = parse_events(content);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].command, "check");
+    }
+}
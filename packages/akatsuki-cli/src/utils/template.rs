@@ -1,22 +1,33 @@
 use anyhow::Result;
-use chrono::Local;
+
+use crate::cli::DesignTemplate;
 
 const DESIGN_TEMPLATE: &str = include_str!("../../../../docs/templates/design-template.md");
+const API_DESIGN_TEMPLATE: &str = include_str!("../../../../docs/templates/design-api-template.md");
+const WORKFLOW_DESIGN_TEMPLATE: &str =
+    include_str!("../../../../docs/templates/design-workflow-template.md");
+
+fn design_template(template: DesignTemplate) -> &'static str {
+    match template {
+        DesignTemplate::Page => DESIGN_TEMPLATE,
+        DesignTemplate::Api => API_DESIGN_TEMPLATE,
+        DesignTemplate::Workflow => WORKFLOW_DESIGN_TEMPLATE,
+    }
+}
 
-pub fn process_template(feature_name: &str) -> String {
+pub fn process_template(feature_name: &str, template: DesignTemplate) -> String {
     let title = crate::utils::to_title_case(feature_name);
-    let today = Local::now().format("%Y-%m-%d").to_string();
-
-    DESIGN_TEMPLATE
-        .replace("[Feature Name]", &title)
-        .replace("[Date]", &today)
+    design_template(template).replace("[Feature Name]", &title)
 }
 
-pub fn process_template_with_theme(feature_name: &str, theme_id: &str) -> Result<String> {
+pub fn process_template_with_theme(
+    feature_name: &str,
+    theme_id: &str,
+    template: DesignTemplate,
+) -> Result<String> {
     use crate::commands::design::theme::Theme;
 
     let title = crate::utils::to_title_case(feature_name);
-    let today = Local::now().format("%Y-%m-%d").to_string();
 
     // Load theme
     let theme = Theme::load(theme_id)?;
@@ -25,9 +36,7 @@ pub fn process_template_with_theme(feature_name: &str, theme_id: &str) -> Result
     let theme_section = generate_theme_section(&theme);
 
     // Replace placeholders
-    let mut content = DESIGN_TEMPLATE
-        .replace("[Feature Name]", &title)
-        .replace("[Date]", &today);
+    let mut content = design_template(template).replace("[Feature Name]", &title);
 
     // Replace Color Theme section
     content = replace_color_theme_section(&content, &theme_section);
@@ -44,7 +53,7 @@ pub fn generate_theme_section_for_insertion(
 fn generate_theme_section(theme: &crate::commands::design::theme::Theme) -> String {
     let mut section = String::new();
 
-    section.push_str(&format!("### Color Theme\n\n"));
+    section.push_str("### Color Theme\n\n");
     section.push_str(&format!(
         "**Selected Theme:** `{}` - {}\n\n",
         theme.id, theme.name
@@ -156,7 +165,7 @@ fn replace_color_theme_section(content: &str, theme_section: &str) -> String {
             let mut result = String::new();
             result.push_str(&content[..start_pos]);
             result.push_str(theme_section);
-            result.push_str("\n");
+            result.push('\n');
             result.push_str(&content[end_pos..]);
             return result;
         }
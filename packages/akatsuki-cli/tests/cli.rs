@@ -0,0 +1,400 @@
+//! End-to-end tests: run the akatsuki binary against a fake monorepo
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+const ARTICLE_SCHEMA: &str = r#"name: Article
+tableName: articles
+fields:
+  - name: id
+    dbName: id
+    type: uuid
+    primaryKey: true
+  - name: title
+    dbName: title
+    type: string
+    required: true
+operations:
+  - type: list
+  - type: get
+  - type: create
+rls:
+  - action: SELECT
+    name: "Anyone can view articles"
+    using: "true"
+"#;
+
+/// Temporary project: npm workspaces root + git repo with one commit
+struct Project {
+    dir: TempDir,
+}
+
+impl Project {
+    fn new() -> Self {
+        let project = Self {
+            dir: tempfile::tempdir().unwrap(),
+        };
+        project.write(
+            "package.json",
+            r#"{ "name": "fixture", "private": true, "workspaces": ["packages/*"] }"#,
+        );
+        project.git(&["init", "-q"]);
+        project.git(&["add", "-A"]);
+        project.git(&["commit", "-q", "-m", "Initial commit"]);
+        project
+    }
+
+    fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    fn write(&self, relative: &str, content: &str) -> &Self {
+        let path = self.path().join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+        self
+    }
+
+    fn read(&self, relative: &str) -> String {
+        fs::read_to_string(self.path().join(relative)).unwrap()
+    }
+
+    fn git(&self, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(self.path())
+            .env("GIT_AUTHOR_NAME", "Test")
+            .env("GIT_AUTHOR_EMAIL", "test@example.com")
+            .env("GIT_COMMITTER_NAME", "Test")
+            .env("GIT_COMMITTER_EMAIL", "test@example.com")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    fn commit_all(&self) {
+        self.git(&["add", "-A"]);
+        self.git(&["commit", "-q", "-m", "Fixture"]);
+    }
+
+    fn akatsuki(&self, args: &[&str]) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_akatsuki"));
+        command
+            .args(args)
+            .current_dir(self.path())
+            .env("NO_COLOR", "1")
+            .env("DO_NOT_TRACK", "1");
+        command
+    }
+}
+
+#[test]
+fn test_docs_components_lists_jsdoc_summaries() {
+    let project = Project::new();
+    project.write(
+        "packages/app-frontend/src/components/ui/Button.tsx",
+        "/**\n * Primary action button\n */\nexport function Button() {}\n",
+    );
+    project.write(
+        "packages/app-frontend/src/components/ui/Plain.tsx",
+        "export function Plain() {}\n",
+    );
+
+    project
+        .akatsuki(&["docs", "components"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Button.tsx"))
+        .stdout(predicate::str::contains("Primary action button"))
+        .stdout(predicate::str::contains("Plain.tsx").not());
+}
+
+#[test]
+fn test_docs_lint_reports_undocumented_files() {
+    let project = Project::new();
+    project.write(
+        "packages/app-frontend/src/models/Article.ts",
+        "export class Article {}\n",
+    );
+
+    project
+        .akatsuki(&["docs", "lint"])
+        .assert()
+        .stdout(predicate::str::contains("Article.ts"));
+}
+
+#[test]
+fn test_docs_sync_updates_configured_targets() {
+    let project = Project::new();
+    let section = "# Agent\n<!-- SYNC:COMPONENTS:START -->\nstale\n<!-- SYNC:COMPONENTS:END -->\n";
+    project
+        .write("AGENT.md", section)
+        .write("AGENT-mini.md", section)
+        .write(
+            "akatsuki.toml",
+            "[docs]\nsync_targets = [\"AGENT.md\", \"AGENT-mini.md\"]\n",
+        )
+        .write(
+            "packages/app-frontend/src/models/Article.ts",
+            "/** Article */\nexport class Article {}\n",
+        );
+
+    project.akatsuki(&["docs", "sync"]).assert().success();
+    for target in ["AGENT.md", "AGENT-mini.md"] {
+        let content = project.read(target);
+        assert!(!content.contains("stale"), "{} not synced", target);
+        assert!(content.contains("Models: 1クラス（100%ドキュメント化）"));
+    }
+
+    project
+        .akatsuki(&["docs", "sync"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "AGENT-mini.md is already up to date",
+        ));
+}
+
+#[test]
+fn test_api_check_validates_schemas() {
+    let project = Project::new();
+    project.write("schemas/article.yaml", ARTICLE_SCHEMA);
+    project.write("schemas/broken.yaml", "name: Broken\nfields: [\n");
+
+    project
+        .akatsuki(&["api", "check", "schemas/article.yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("article.yaml"));
+    project
+        .akatsuki(&["api", "check", "schemas/broken.yaml"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_output_json_prints_one_document() {
+    let project = Project::new();
+    project.write("schemas/article.yaml", ARTICLE_SCHEMA);
+    project.write("schemas/broken.yaml", "name: Broken\nfields: [\n");
+    let json = |args: &[&str]| -> (bool, serde_json::Value) {
+        let output = project.akatsuki(args).output().unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        (
+            output.status.success(),
+            serde_json::from_str(&stdout).unwrap(),
+        )
+    };
+
+    let (success, report) = json(&[
+        "api",
+        "check",
+        "schemas/article.yaml",
+        "schemas/broken.yaml",
+        "--output",
+        "json",
+    ]);
+    assert!(!success);
+    assert_eq!(report["valid"], 1);
+    assert_eq!(report["invalid"], 1);
+    assert_eq!(report["files"][0]["entity"]["table_name"], "articles");
+    assert!(report["files"][1]["error"].is_string());
+
+    let (success, stats) = json(&["--output", "json", "stats"]);
+    assert!(success);
+    assert_eq!(stats["runs"], 0);
+    assert_eq!(stats["commands"], serde_json::json!([]));
+
+    let (success, error) = json(&["--output", "json", "ui"]);
+    assert!(!success);
+    assert!(error["error"].as_str().unwrap().contains("no JSON output"));
+}
+
+#[test]
+fn test_api_new_dry_run_writes_nothing() {
+    let project = Project::new();
+    project.write("schemas/article.yaml", ARTICLE_SCHEMA);
+    project.commit_all();
+
+    project
+        .akatsuki(&[
+            "api",
+            "new",
+            "Article",
+            "--schema",
+            "schemas/article.yaml",
+            "--dry-run",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Dry run - files that would be generated",
+        ))
+        .stdout(predicate::str::contains(
+            "Migration (Table + RLS + Indexes)",
+        ));
+    assert_eq!(project.git(&["status", "--porcelain"]), "");
+}
+
+#[test]
+fn test_api_new_keeps_edited_files() {
+    let project = Project::new();
+    project.write("schemas/article.yaml", ARTICLE_SCHEMA);
+    let new_article = ["api", "new", "Article", "--schema", "schemas/article.yaml"];
+    project.akatsuki(&new_article).assert().success();
+    project.commit_all();
+
+    let model = "packages/app-frontend/src/models/Article.ts";
+    let edited = project.read(model) + "// hand-written helper\n";
+    project.write(model, &edited);
+
+    // Without a terminal the edited file is kept and the new version lands next to it
+    project
+        .akatsuki(&new_article)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "was edited since it was generated",
+        ))
+        .stdout(predicate::str::contains("table migration exists"));
+    assert_eq!(project.read(model), edited);
+    let status = project.git(&["status", "--porcelain"]);
+    assert!(status.contains("Article.ts.new"));
+    assert!(!status.contains("_create_articles_table.sql"));
+
+    let mut forced = new_article.to_vec();
+    forced.push("--force");
+    project.akatsuki(&forced).assert().success();
+    assert!(!project.read(model).contains("hand-written helper"));
+}
+
+#[test]
+fn test_api_delete_removes_generated_files() {
+    let project = Project::new();
+    project.write("schemas/article.yaml", ARTICLE_SCHEMA);
+    project
+        .akatsuki(&["api", "new", "Article", "--schema", "schemas/article.yaml"])
+        .assert()
+        .success();
+    project.commit_all();
+
+    project
+        .akatsuki(&["api", "delete", "Article", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "supabase/functions/articles-crud/",
+        ))
+        .stdout(predicate::str::contains("_drop_articles_table.sql"));
+    assert_eq!(project.git(&["status", "--porcelain"]), "");
+
+    project
+        .akatsuki(&["api", "delete", "Article", "--force"])
+        .assert()
+        .success();
+    assert!(!project
+        .path()
+        .join("supabase/functions/articles-crud")
+        .exists());
+    assert!(!project
+        .path()
+        .join("packages/app-frontend/src/models/Article.ts")
+        .exists());
+    let status = project.git(&["status", "--porcelain"]);
+    assert!(status.contains("_drop_articles_table.sql"));
+    assert!(!status.contains("_create_articles_table.sql"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_db_check_lists_migrations() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let project = Project::new();
+    project.write(
+        "supabase/migrations/20240101000000_create_articles.sql",
+        "CREATE TABLE articles (id uuid PRIMARY KEY);\n",
+    );
+    // Stand-in for `supabase migration list` (no linked project needed)
+    project.write(
+        "bin/supabase",
+        "#!/bin/sh\necho '   Local          | Remote         | Time (UTC)'\n",
+    );
+    let fake = project.path().join("bin/supabase");
+    fs::set_permissions(&fake, fs::Permissions::from_mode(0o755)).unwrap();
+    let path = format!(
+        "{}:{}",
+        project.path().join("bin").display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+
+    project
+        .akatsuki(&["db", "check"])
+        .env("PATH", path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Found 1 migration file(s)"))
+        .stdout(predicate::str::contains(
+            "20240101000000_create_articles.sql",
+        ))
+        .stdout(predicate::str::contains("CREATE TABLE articles"));
+}
+
+#[test]
+fn test_advice_rule_flags_uncommitted_migration() {
+    let project = Project::new();
+    project.write(
+        "supabase/migrations/20240101000000_create_articles.sql",
+        "CREATE TABLE articles (id uuid PRIMARY KEY);\n",
+    );
+    project.commit_all();
+    project.write(
+        "supabase/migrations/20240102000000_add_title.sql",
+        "ALTER TABLE articles ADD COLUMN title text;\n",
+    );
+
+    project
+        .akatsuki(&["advice", "rule"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "New uncommitted migration file(s): 1 (latest: 20240102000000_add_title.sql)",
+        ))
+        .stdout(predicate::str::contains("akatsuki db push"));
+}
+
+#[test]
+fn test_release_dry_run_changes_nothing() {
+    let project = Project::new();
+    let cargo_toml = "[package]\nname = \"akatsuki-cli\"\nversion = \"1.2.0\"\n";
+    project.write("packages/akatsuki-cli/Cargo.toml", cargo_toml);
+    project.write(
+        "packages/akatsuki-cli/CHANGELOG.md",
+        "# Changelog\n\n## [Unreleased]\n\n- New command\n",
+    );
+    project.commit_all();
+
+    project
+        .akatsuki(&["release", "1.3.0", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Current version: 1.2.0"))
+        .stdout(predicate::str::contains("Create tag cli-v1.3.0"))
+        .stdout(predicate::str::contains(
+            "move Unreleased changes to [1.3.0]",
+        ));
+    assert_eq!(project.read("packages/akatsuki-cli/Cargo.toml"), cargo_toml);
+    assert_eq!(project.git(&["status", "--porcelain"]), "");
+    assert_eq!(project.git(&["tag"]), "");
+
+    project
+        .akatsuki(&["release", "1.3.0-beta.1", "--dry-run"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--channel beta"));
+}
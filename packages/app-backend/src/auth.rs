@@ -0,0 +1,198 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts, StatusCode},
+};
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use std::convert::Infallible;
+use uuid::Uuid;
+
+use crate::state::AppState;
+
+// ========================================
+// Claims
+// ========================================
+
+/// Subset of the Supabase access token claims used by the backend
+#[derive(Debug, Clone, Deserialize)]
+pub struct Claims {
+    /// User id (absent for service role keys)
+    pub sub: Option<String>,
+    /// Postgres role: "authenticated", "anon" or "service_role"
+    pub role: Option<String>,
+    #[serde(default)]
+    pub app_metadata: serde_json::Value,
+}
+
+impl Claims {
+    /// Admin role claim (`app_metadata.role = 'admin'`, same check as `is_admin()` in SQL)
+    /// or the service role key
+    pub fn is_admin(&self) -> bool {
+        self.app_metadata["role"].as_str() == Some("admin")
+            || self.role.as_deref() == Some("service_role")
+    }
+}
+
+/// Verify a Supabase-issued JWT (HS256, audience not enforced)
+pub fn verify_token(token: &str, secret: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+    let mut validation = Validation::new(Algorithm::HS256);
+    validation.validate_aud = false;
+
+    decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &validation,
+    )
+    .map(|data| data.claims)
+}
+
+// ========================================
+// Extractors
+// ========================================
+
+/// Authenticated caller (valid `Authorization: Bearer <jwt>`)
+#[derive(Debug, Clone)]
+pub struct AuthUser {
+    pub id: Option<Uuid>,
+    pub claims: Claims,
+}
+
+impl AuthUser {
+    /// User id, or 401 for tokens without one (service role keys)
+    pub fn require_id(&self) -> Result<Uuid, StatusCode> {
+        self.id.ok_or(StatusCode::UNAUTHORIZED)
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for AuthUser {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let secret = state.config.auth.jwt_secret.as_deref().ok_or_else(|| {
+            tracing::error!("SUPABASE_JWT_SECRET is not set, rejecting authenticated request");
+            StatusCode::SERVICE_UNAVAILABLE
+        })?;
+
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+
+        let claims = verify_token(token, secret).map_err(|e| {
+            tracing::warn!("Rejected token: {}", e);
+            StatusCode::UNAUTHORIZED
+        })?;
+
+        Ok(AuthUser {
+            id: claims.sub.as_deref().and_then(|s| Uuid::parse_str(s).ok()),
+            claims,
+        })
+    }
+}
+
+/// Caller of a route that also accepts anonymous requests
+/// (`None` without a token; an invalid token is treated as anonymous)
+#[derive(Debug, Clone)]
+pub struct OptionalUser(pub Option<AuthUser>);
+
+impl OptionalUser {
+    pub fn id(&self) -> Option<Uuid> {
+        self.0.as_ref().and_then(|user| user.id)
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for OptionalUser {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        if state.config.auth.jwt_secret.is_none()
+            || !parts.headers.contains_key(header::AUTHORIZATION)
+        {
+            return Ok(OptionalUser(None));
+        }
+        Ok(OptionalUser(
+            AuthUser::from_request_parts(parts, state).await.ok(),
+        ))
+    }
+}
+
+/// Authenticated caller with the admin role claim
+#[derive(Debug, Clone)]
+pub struct AdminUser(pub AuthUser);
+
+#[async_trait]
+impl FromRequestParts<AppState> for AdminUser {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let user = AuthUser::from_request_parts(parts, state).await?;
+        if !user.claims.is_admin() {
+            return Err(StatusCode::FORBIDDEN);
+        }
+        Ok(AdminUser(user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+
+    fn token(claims: serde_json::Value) -> String {
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_admin_claim() {
+        let claims = verify_token(
+            &token(serde_json::json!({
+                "sub": "4a1b6c9e-5d2f-4e8a-9b7c-1d2e3f4a5b6c",
+                "role": "authenticated",
+                "app_metadata": { "role": "admin" },
+                "exp": 4_102_444_800u64,
+            })),
+            "secret",
+        )
+        .unwrap();
+        assert!(claims.is_admin());
+    }
+
+    #[test]
+    fn test_regular_user_is_not_admin() {
+        let claims = verify_token(
+            &token(serde_json::json!({
+                "sub": "4a1b6c9e-5d2f-4e8a-9b7c-1d2e3f4a5b6c",
+                "role": "authenticated",
+                "exp": 4_102_444_800u64,
+            })),
+            "secret",
+        )
+        .unwrap();
+        assert!(!claims.is_admin());
+    }
+
+    #[test]
+    fn test_wrong_secret_is_rejected() {
+        let signed = token(serde_json::json!({ "role": "service_role", "exp": 4_102_444_800u64 }));
+        assert!(verify_token(&signed, "other").is_err());
+    }
+}
@@ -0,0 +1,383 @@
+/// Application configuration loaded from environment variables
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    /// `APP_ENV=production` enables fail-closed defaults
+    pub production: bool,
+    pub auth: AuthConfig,
+    pub cors: CorsConfig,
+    pub llm: LlmConfig,
+    pub llm_cache: LlmCacheConfig,
+    pub conversation: ConversationConfig,
+    pub speech: SpeechConfig,
+    pub image: ImageConfig,
+    pub storage: StorageConfig,
+    pub retention: RetentionConfig,
+    pub logging: LoggingConfig,
+    pub idempotency: IdempotencyConfig,
+    pub tasks: TasksConfig,
+    pub email: EmailConfig,
+    pub slack: SlackConfig,
+    pub share: ShareConfig,
+    pub mock: MockConfig,
+    pub limits: ProviderLimitsConfig,
+}
+
+/// Authentication settings
+#[derive(Debug, Clone)]
+pub struct AuthConfig {
+    /// Supabase JWT secret used to verify access tokens (protected routes fail without it)
+    pub jwt_secret: Option<String>,
+}
+
+/// CORS settings
+#[derive(Debug, Clone)]
+pub struct CorsConfig {
+    /// Allowed origins: exact (`https://app.example.com`) or wildcard
+    /// subdomain (`https://*.example.com`)
+    pub allowed_origins: Vec<String>,
+}
+
+/// LLM provider settings
+#[derive(Debug, Clone)]
+pub struct LlmConfig {
+    /// OpenAI-compatible API key (placeholder provider is used when unset)
+    pub api_key: Option<String>,
+    /// Base URL of the OpenAI-compatible API
+    pub base_url: String,
+    /// Model used when a request does not specify one
+    pub default_model: String,
+    /// Embedding model (must match the pgvector column dimensions)
+    pub embedding_model: String,
+    pub embedding_dimensions: usize,
+    /// Consecutive provider failures that open the circuit breaker (0 disables it)
+    pub breaker_threshold: u32,
+    /// How long calls are rejected once the breaker opens
+    pub breaker_cooldown_secs: u64,
+}
+
+/// agent-execute response cache settings
+#[derive(Debug, Clone)]
+pub struct LlmCacheConfig {
+    pub enabled: bool,
+    /// How long a cached completion is served
+    pub ttl_secs: i64,
+    /// Also serve completions of similar prompts (embeds every cache miss)
+    pub semantic: bool,
+    /// Minimum cosine similarity for a semantic hit
+    pub similarity_threshold: f64,
+}
+
+/// Conversation history settings
+#[derive(Debug, Clone)]
+pub struct ConversationConfig {
+    /// Approximate token budget for history sent to the provider
+    pub context_token_budget: usize,
+    /// Number of most recent messages that are never summarized away
+    pub keep_recent_messages: usize,
+}
+
+/// Text-to-speech / speech-to-text settings
+#[derive(Debug, Clone)]
+pub struct SpeechConfig {
+    /// Speech provider: "openai" (default, uses the LLM API key) or "elevenlabs"
+    pub provider: String,
+    pub elevenlabs_api_key: Option<String>,
+    pub elevenlabs_base_url: String,
+    /// Overrides for the provider's default models and voice
+    pub tts_model: Option<String>,
+    pub tts_voice: Option<String>,
+    pub stt_model: Option<String>,
+}
+
+/// Image post-processing (upscale / background removal) settings
+#[derive(Debug, Clone)]
+pub struct ImageConfig {
+    /// Image provider: "local" (default, upscale only) or "stability"
+    pub provider: String,
+    pub stability_api_key: Option<String>,
+    pub stability_base_url: String,
+    /// Maximum size of a downloaded source image
+    pub max_source_bytes: usize,
+}
+
+/// Supabase Storage settings for generated assets
+#[derive(Debug, Clone)]
+pub struct StorageConfig {
+    pub supabase_url: Option<String>,
+    /// Service role key (bypasses RLS, never expose to clients)
+    pub service_role_key: Option<String>,
+    pub bucket: String,
+    /// Lifetime of signed URLs returned to clients
+    pub signed_url_ttl_secs: u64,
+}
+
+/// Request logging settings
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    /// Fraction of successful requests to log (0.0 - 1.0); server errors are always logged
+    pub sample_rate: f64,
+    /// Include (redacted) JSON request bodies, which may contain prompts
+    pub include_bodies: bool,
+    pub max_body_bytes: usize,
+}
+
+/// `Idempotency-Key` replay settings
+#[derive(Debug, Clone)]
+pub struct IdempotencyConfig {
+    /// How long a stored response is replayed
+    pub ttl_secs: u64,
+    /// Larger request bodies are rejected (413), larger responses not stored
+    pub max_body_bytes: usize,
+}
+
+/// Scheduled maintenance tasks (cron expressions are UTC)
+#[derive(Debug, Clone)]
+pub struct TasksConfig {
+    /// Run the in-process scheduler (disable when several replicas share the database)
+    pub enabled: bool,
+    pub expire_links_cron: String,
+    pub aggregate_usage_cron: String,
+    pub retry_jobs_cron: String,
+    pub quota_warnings_cron: String,
+    pub purge_llm_cache_cron: String,
+    pub purge_assets_cron: String,
+    /// A running job without progress for this long is considered stalled
+    pub job_stall_minutes: i64,
+    /// Stalled jobs are restarted until they have run this many times
+    pub job_max_attempts: u32,
+    /// At startup, unfinished jobs of a previous process last updated this
+    /// long ago are resumed (or marked failed)
+    pub job_resume_after_minutes: i64,
+}
+
+/// Notification emails sent through Resend
+#[derive(Debug, Clone)]
+pub struct EmailConfig {
+    /// Resend API key (notification emails are disabled when unset)
+    pub resend_api_key: Option<String>,
+    pub resend_base_url: String,
+    /// Sender address (its domain must be verified in Resend)
+    pub from: String,
+    /// Jobs that ran at least this long email their owner when they finish
+    pub job_notify_min_secs: i64,
+    /// Share of the monthly request limit (percent) that triggers a quota warning
+    pub quota_warning_percent: i32,
+}
+
+/// Slack alerts for backend operational events
+#[derive(Debug, Clone)]
+pub struct SlackConfig {
+    /// Incoming webhook URL (alerts are disabled when unset)
+    pub webhook_url: Option<String>,
+    /// Lowest severity posted: "info", "warning" or "critical"
+    pub min_severity: String,
+    /// Failed jobs within the window that count as a spike (0 disables the alert)
+    pub job_failure_threshold: usize,
+    pub job_failure_window_secs: i64,
+}
+
+/// Public share link settings
+#[derive(Debug, Clone)]
+pub struct ShareConfig {
+    /// Public origin of this backend, used to build absolute share URLs
+    /// (`/share/:token` paths are returned when unset)
+    pub base_url: Option<String>,
+    pub default_ttl_hours: i64,
+    /// Longest lifetime a share link can be created with
+    pub max_ttl_hours: i64,
+}
+
+/// Offline development mode (`MOCK_PROVIDERS=1`): placeholder images and canned
+/// agent responses instead of the real providers. Ignored in production.
+#[derive(Debug, Clone)]
+pub struct MockConfig {
+    pub enabled: bool,
+    /// JSON array of `{"match": "...", "response": "..."}` checked before the built-in responses
+    pub responses_file: Option<String>,
+    /// Artificial delay added to every mock chat completion
+    pub latency_ms: u64,
+}
+
+/// How long stored assets are kept, per asset class (0 = forever). Expired
+/// and user-deleted assets stay restorable for `grace_days` before the
+/// `purge-assets` task deletes them.
+#[derive(Debug, Clone)]
+pub struct RetentionConfig {
+    /// Audio uploaded for transcription (`store=true`)
+    pub temp_upload_hours: i64,
+    /// Generated images and stored TTS audio
+    pub generation_days: i64,
+    /// Assets with an active share link
+    pub shared_days: i64,
+    pub grace_days: i64,
+}
+
+/// Concurrent calls and queued waiters allowed per provider
+#[derive(Debug, Clone, Copy)]
+pub struct ProviderLimit {
+    /// Calls in flight at once (0 = unlimited)
+    pub concurrency: usize,
+    /// Callers waiting for a slot before new ones are rejected with 503
+    pub queue_size: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProviderLimitsConfig {
+    pub llm: ProviderLimit,
+    pub image: ProviderLimit,
+    pub speech: ProviderLimit,
+}
+
+impl AppConfig {
+    pub fn from_env() -> Self {
+        Self {
+            production: env_or("APP_ENV", "development") == "production",
+            auth: AuthConfig {
+                jwt_secret: env_opt("SUPABASE_JWT_SECRET"),
+            },
+            cors: CorsConfig {
+                allowed_origins: env_list("CORS_ALLOWED_ORIGINS"),
+            },
+            llm: LlmConfig {
+                api_key: env_opt("OPENAI_API_KEY"),
+                base_url: env_or("LLM_BASE_URL", "https://api.openai.com/v1"),
+                default_model: env_or("LLM_DEFAULT_MODEL", "gpt-4o-mini"),
+                embedding_model: env_or("EMBEDDING_MODEL", "text-embedding-3-small"),
+                embedding_dimensions: env_parse("EMBEDDING_DIMENSIONS", 1536),
+                breaker_threshold: env_parse("LLM_BREAKER_THRESHOLD", 5),
+                breaker_cooldown_secs: env_parse("LLM_BREAKER_COOLDOWN_SECS", 30),
+            },
+            llm_cache: LlmCacheConfig {
+                enabled: env_parse("LLM_CACHE_ENABLED", true),
+                ttl_secs: env_parse("LLM_CACHE_TTL_SECS", 86400),
+                semantic: env_parse("LLM_CACHE_SEMANTIC", false),
+                similarity_threshold: env_parse("LLM_CACHE_SIMILARITY", 0.95),
+            },
+            conversation: ConversationConfig {
+                context_token_budget: env_parse("CONVERSATION_CONTEXT_TOKENS", 6000),
+                keep_recent_messages: env_parse("CONVERSATION_KEEP_RECENT", 8),
+            },
+            speech: SpeechConfig {
+                provider: env_or("SPEECH_PROVIDER", "openai"),
+                elevenlabs_api_key: env_opt("ELEVENLABS_API_KEY"),
+                elevenlabs_base_url: env_or("ELEVENLABS_BASE_URL", "https://api.elevenlabs.io"),
+                tts_model: env_opt("TTS_MODEL"),
+                tts_voice: env_opt("TTS_VOICE"),
+                stt_model: env_opt("STT_MODEL"),
+            },
+            image: ImageConfig {
+                provider: env_or("IMAGE_PROVIDER", "local"),
+                stability_api_key: env_opt("STABILITY_API_KEY"),
+                stability_base_url: env_or("STABILITY_BASE_URL", "https://api.stability.ai"),
+                max_source_bytes: env_parse("IMAGE_MAX_SOURCE_BYTES", 20 * 1024 * 1024),
+            },
+            storage: StorageConfig {
+                supabase_url: env_opt("SUPABASE_URL"),
+                service_role_key: env_opt("SUPABASE_SERVICE_ROLE_KEY"),
+                bucket: env_or("AIGEN_STORAGE_BUCKET", "aigen_outputs"),
+                signed_url_ttl_secs: env_parse("STORAGE_SIGNED_URL_TTL", 3600),
+            },
+            retention: RetentionConfig {
+                temp_upload_hours: env_parse("RETENTION_TEMP_UPLOAD_HOURS", 24),
+                generation_days: env_parse("RETENTION_GENERATION_DAYS", 0),
+                shared_days: env_parse("RETENTION_SHARED_DAYS", 0),
+                grace_days: env_parse("RETENTION_GRACE_DAYS", 7),
+            },
+            logging: LoggingConfig {
+                sample_rate: env_parse("REQUEST_LOG_SAMPLE_RATE", 1.0),
+                include_bodies: env_parse("REQUEST_LOG_BODIES", false),
+                max_body_bytes: env_parse("REQUEST_LOG_MAX_BODY_BYTES", 4096),
+            },
+            idempotency: IdempotencyConfig {
+                ttl_secs: env_parse("IDEMPOTENCY_TTL_SECS", 24 * 60 * 60),
+                max_body_bytes: env_parse("IDEMPOTENCY_MAX_BODY_BYTES", 1024 * 1024),
+            },
+            tasks: TasksConfig {
+                enabled: env_parse("TASKS_ENABLED", true),
+                expire_links_cron: env_or("TASK_EXPIRE_LINKS_CRON", "*/15 * * * *"),
+                aggregate_usage_cron: env_or("TASK_AGGREGATE_USAGE_CRON", "5 * * * *"),
+                retry_jobs_cron: env_or("TASK_RETRY_JOBS_CRON", "*/5 * * * *"),
+                quota_warnings_cron: env_or("TASK_QUOTA_WARNINGS_CRON", "0 * * * *"),
+                purge_llm_cache_cron: env_or("TASK_PURGE_LLM_CACHE_CRON", "30 * * * *"),
+                purge_assets_cron: env_or("TASK_PURGE_ASSETS_CRON", "45 * * * *"),
+                job_stall_minutes: env_parse("JOB_STALL_MINUTES", 15),
+                job_max_attempts: env_parse("JOB_MAX_ATTEMPTS", 3),
+                job_resume_after_minutes: env_parse("JOB_RESUME_AFTER_MINUTES", 0),
+            },
+            email: EmailConfig {
+                resend_api_key: env_opt("RESEND_API_KEY"),
+                resend_base_url: env_or("RESEND_BASE_URL", "https://api.resend.com"),
+                from: env_or("EMAIL_FROM", "noreply@example.com"),
+                job_notify_min_secs: env_parse("EMAIL_JOB_NOTIFY_MIN_SECS", 60),
+                quota_warning_percent: env_parse("EMAIL_QUOTA_WARNING_PERCENT", 80),
+            },
+            slack: SlackConfig {
+                webhook_url: env_opt("SLACK_WEBHOOK_URL"),
+                min_severity: env_or("SLACK_MIN_SEVERITY", "info"),
+                job_failure_threshold: env_parse("SLACK_JOB_FAILURE_THRESHOLD", 5),
+                job_failure_window_secs: env_parse("SLACK_JOB_FAILURE_WINDOW_SECS", 300),
+            },
+            share: ShareConfig {
+                base_url: env_opt("SHARE_BASE_URL"),
+                default_ttl_hours: env_parse("SHARE_DEFAULT_TTL_HOURS", 168),
+                max_ttl_hours: env_parse("SHARE_MAX_TTL_HOURS", 720),
+            },
+            mock: MockConfig {
+                enabled: env_flag("MOCK_PROVIDERS"),
+                responses_file: env_opt("MOCK_RESPONSES_FILE"),
+                latency_ms: env_parse("MOCK_LATENCY_MS", 0),
+            },
+            limits: ProviderLimitsConfig {
+                llm: ProviderLimit {
+                    concurrency: env_parse("LLM_CONCURRENCY", 8),
+                    queue_size: env_parse("LLM_QUEUE_SIZE", 100),
+                },
+                image: ProviderLimit {
+                    concurrency: env_parse("IMAGE_CONCURRENCY", 4),
+                    queue_size: env_parse("IMAGE_QUEUE_SIZE", 50),
+                },
+                speech: ProviderLimit {
+                    concurrency: env_parse("SPEECH_CONCURRENCY", 4),
+                    queue_size: env_parse("SPEECH_QUEUE_SIZE", 50),
+                },
+            },
+        }
+    }
+
+    /// Whether mock providers are in use (never in production)
+    pub fn mock_providers(&self) -> bool {
+        self.mock.enabled && !self.production
+    }
+}
+
+fn env_opt(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|v| !v.is_empty())
+}
+
+fn env_or(key: &str, default: &str) -> String {
+    env_opt(key).unwrap_or_else(|| default.to_string())
+}
+
+/// Comma-separated list (empty when unset)
+fn env_list(key: &str) -> Vec<String> {
+    env_opt(key)
+        .map(|v| {
+            v.split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `1`, `true` or `yes` (case-insensitive)
+fn env_flag(key: &str) -> bool {
+    env_opt(key).is_some_and(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str, default: T) -> T {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
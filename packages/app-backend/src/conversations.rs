@@ -0,0 +1,594 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::error::internal_error;
+use crate::llm::{estimate_tokens, ChatMessage, ChatRequest, ChatRole, LlmProvider};
+use crate::pagination::{Cursor, Paginated, Pagination, SortBy, SortFields};
+use crate::state::AppState;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct Conversation {
+    pub id: Uuid,
+    pub title: Option<String>,
+    pub system_prompt: Option<String>,
+    pub model: Option<String>,
+    pub summary: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct StoredMessage {
+    pub id: Uuid,
+    pub conversation_id: Uuid,
+    pub role: String,
+    pub content: String,
+    pub token_estimate: i32,
+    pub summarized: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Sortable columns for the conversation list
+struct ConversationSort;
+
+impl SortFields for ConversationSort {
+    const ALLOWED: &'static [&'static str] = &["created_at", "updated_at", "title"];
+    const DEFAULT: &'static str = "updated_at";
+    const DEFAULT_DESCENDING: bool = true;
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateConversationRequest {
+    title: Option<String>,
+    system_prompt: Option<String>,
+    model: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateConversationRequest {
+    title: Option<String>,
+    system_prompt: Option<String>,
+    model: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ConversationDetailResponse {
+    conversation: Conversation,
+    messages: Vec<StoredMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PostMessageRequest {
+    content: String,
+    model: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PostMessageResponse {
+    message: StoredMessage,
+    model_used: String,
+    tokens_used: Option<u32>,
+    /// Number of older messages folded into the conversation summary by this turn
+    summarized_messages: usize,
+}
+
+// ========================================
+// Context Window Management
+// ========================================
+
+/// Decide how many of the oldest messages must be summarized away.
+///
+/// Walks from the newest message backwards, always keeping `keep_recent`
+/// messages and then as many older ones as fit in `budget` tokens.
+/// Returns the number of leading messages that no longer fit.
+pub fn summarize_split(token_counts: &[usize], budget: usize, keep_recent: usize) -> usize {
+    let total: usize = token_counts.iter().sum();
+    if total <= budget {
+        return 0;
+    }
+
+    let mut kept_tokens = 0;
+    let mut split = token_counts.len();
+
+    for (index, tokens) in token_counts.iter().enumerate().rev() {
+        let kept_count = token_counts.len() - index;
+        if kept_count <= keep_recent || kept_tokens + tokens <= budget {
+            kept_tokens += tokens;
+            split = index;
+        } else {
+            break;
+        }
+    }
+
+    split
+}
+
+/// Build the provider message list from the system prompt, summary, and active history
+fn build_context(conversation: &Conversation, history: &[StoredMessage]) -> Vec<ChatMessage> {
+    let mut messages = Vec::with_capacity(history.len() + 2);
+
+    if let Some(system_prompt) = &conversation.system_prompt {
+        messages.push(ChatMessage::new(ChatRole::System, system_prompt.clone()));
+    }
+
+    if let Some(summary) = &conversation.summary {
+        messages.push(ChatMessage::new(
+            ChatRole::System,
+            format!("Summary of the earlier conversation:\n{}", summary),
+        ));
+    }
+
+    messages.extend(history.iter().filter_map(|m| {
+        ChatRole::parse(&m.role).map(|role| ChatMessage::new(role, m.content.clone()))
+    }));
+
+    messages
+}
+
+/// Ask the provider to fold old messages into the running summary
+async fn summarize(
+    llm: &dyn LlmProvider,
+    previous_summary: Option<&str>,
+    messages: &[StoredMessage],
+) -> Option<String> {
+    let mut transcript = String::new();
+    if let Some(summary) = previous_summary {
+        transcript.push_str(&format!("Previous summary:\n{}\n\n", summary));
+    }
+    for message in messages {
+        transcript.push_str(&format!("{}: {}\n", message.role, message.content));
+    }
+
+    let request = ChatRequest {
+        model: None,
+        messages: vec![
+            ChatMessage::new(
+                ChatRole::System,
+                "Summarize the conversation below into a concise summary that preserves facts, decisions, and open questions.",
+            ),
+            ChatMessage::new(ChatRole::User, transcript),
+        ],
+    };
+
+    match llm.chat(request).await {
+        Ok(completion) => Some(completion.content),
+        Err(e) => {
+            tracing::warn!(
+                "Conversation summarization failed, truncating instead: {}",
+                e
+            );
+            None
+        }
+    }
+}
+
+// ========================================
+// Repository
+// ========================================
+
+/// Messages of conversation `$1` owned by user `$2` (same check as the RLS policy)
+const OWNED_MESSAGES: &str = "conversation_id = $1
+         AND EXISTS (SELECT 1 FROM agent_conversations c WHERE c.id = $1 AND c.user_id = $2)";
+
+/// The conversation, if it exists and belongs to `user_id`
+async fn find_conversation(
+    db: &PgPool,
+    id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<Conversation>, sqlx::Error> {
+    sqlx::query_as::<_, Conversation>(
+        "SELECT id, title, system_prompt, model, summary, created_at, updated_at
+         FROM agent_conversations WHERE id = $1 AND user_id = $2",
+    )
+    .bind(id)
+    .bind(user_id)
+    .fetch_optional(db)
+    .await
+}
+
+async fn list_messages(
+    db: &PgPool,
+    conversation_id: Uuid,
+    user_id: Uuid,
+    active_only: bool,
+) -> Result<Vec<StoredMessage>, sqlx::Error> {
+    sqlx::query_as::<_, StoredMessage>(&format!(
+        "SELECT id, conversation_id, role, content, token_estimate, summarized, created_at
+         FROM agent_messages
+         WHERE {} AND ($3 = false OR summarized = false)
+         ORDER BY created_at ASC",
+        OWNED_MESSAGES
+    ))
+    .bind(conversation_id)
+    .bind(user_id)
+    .bind(active_only)
+    .fetch_all(db)
+    .await
+}
+
+/// One page of a conversation's messages, oldest first
+async fn page_messages(
+    db: &PgPool,
+    conversation_id: Uuid,
+    user_id: Uuid,
+    pagination: &Pagination,
+) -> Result<Paginated<StoredMessage>, sqlx::Error> {
+    if let Some(cursor) = pagination.cursor {
+        let rows = sqlx::query_as::<_, StoredMessage>(&format!(
+            "SELECT id, conversation_id, role, content, token_estimate, summarized, created_at
+             FROM agent_messages
+             WHERE {} AND (created_at, id) > ($3, $4)
+             ORDER BY created_at ASC, id ASC
+             LIMIT $5",
+            OWNED_MESSAGES
+        ))
+        .bind(conversation_id)
+        .bind(user_id)
+        .bind(cursor.created_at)
+        .bind(cursor.id)
+        .bind(pagination.limit as i64 + 1)
+        .fetch_all(db)
+        .await?;
+
+        return Ok(Paginated::from_cursor(rows, pagination.limit, |m| Cursor {
+            created_at: m.created_at,
+            id: m.id,
+        }));
+    }
+
+    let total: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM agent_messages WHERE {}",
+        OWNED_MESSAGES
+    ))
+    .bind(conversation_id)
+    .bind(user_id)
+    .fetch_one(db)
+    .await?;
+    let rows = sqlx::query_as::<_, StoredMessage>(&format!(
+        "SELECT id, conversation_id, role, content, token_estimate, summarized, created_at
+         FROM agent_messages
+         WHERE {}
+         ORDER BY created_at ASC, id ASC
+         LIMIT $3 OFFSET $4",
+        OWNED_MESSAGES
+    ))
+    .bind(conversation_id)
+    .bind(user_id)
+    .bind(pagination.limit as i64)
+    .bind(pagination.offset())
+    .fetch_all(db)
+    .await?;
+
+    Ok(Paginated::from_offset(rows, pagination, total))
+}
+
+/// `None` when the conversation does not belong to `user_id`
+async fn insert_message(
+    db: &PgPool,
+    conversation_id: Uuid,
+    user_id: Uuid,
+    role: ChatRole,
+    content: &str,
+) -> Result<Option<StoredMessage>, sqlx::Error> {
+    sqlx::query_as::<_, StoredMessage>(
+        "INSERT INTO agent_messages (conversation_id, role, content, token_estimate)
+         SELECT id, $3, $4, $5 FROM agent_conversations WHERE id = $1 AND user_id = $2
+         RETURNING id, conversation_id, role, content, token_estimate, summarized, created_at",
+    )
+    .bind(conversation_id)
+    .bind(user_id)
+    .bind(role.as_str())
+    .bind(content)
+    .bind(estimate_tokens(content) as i32)
+    .fetch_optional(db)
+    .await
+}
+
+/// Mark messages as folded into the summary and store the new summary atomically
+async fn apply_summary(
+    db: &PgPool,
+    conversation_id: Uuid,
+    user_id: Uuid,
+    summary: Option<&str>,
+    message_ids: &[Uuid],
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query(&format!(
+        "UPDATE agent_messages SET summarized = true WHERE {} AND id = ANY($3)",
+        OWNED_MESSAGES
+    ))
+    .bind(conversation_id)
+    .bind(user_id)
+    .bind(message_ids)
+    .execute(&mut *tx)
+    .await?;
+
+    if let Some(summary) = summary {
+        sqlx::query("UPDATE agent_conversations SET summary = $3 WHERE id = $1 AND user_id = $2")
+            .bind(conversation_id)
+            .bind(user_id)
+            .bind(summary)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// The caller's conversations, most recently updated first by default
+async fn list_conversations(
+    State(state): State<AppState>,
+    user: AuthUser,
+    pagination: Pagination,
+    sort: SortBy<ConversationSort>,
+) -> Result<Json<Paginated<Conversation>>, StatusCode> {
+    let user_id = user.require_id()?;
+    let total: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM agent_conversations WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&state.db)
+            .await
+            .map_err(internal_error)?;
+
+    let conversations = sqlx::query_as::<_, Conversation>(&format!(
+        "SELECT id, title, system_prompt, model, summary, created_at, updated_at
+         FROM agent_conversations
+         WHERE user_id = $1
+         ORDER BY {}, id
+         LIMIT $2 OFFSET $3",
+        sort.order_by()
+    ))
+    .bind(user_id)
+    .bind(pagination.limit as i64)
+    .bind(pagination.offset())
+    .fetch_all(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    Ok(Json(Paginated::from_offset(
+        conversations,
+        &pagination,
+        total,
+    )))
+}
+
+async fn create_conversation(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<CreateConversationRequest>,
+) -> Result<(StatusCode, Json<Conversation>), StatusCode> {
+    let user_id = user.require_id()?;
+    let conversation = sqlx::query_as::<_, Conversation>(
+        "INSERT INTO agent_conversations (user_id, title, system_prompt, model)
+         VALUES ($1, $2, $3, $4)
+         RETURNING id, title, system_prompt, model, summary, created_at, updated_at",
+    )
+    .bind(user_id)
+    .bind(payload.title)
+    .bind(payload.system_prompt)
+    .bind(payload.model)
+    .fetch_one(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    Ok((StatusCode::CREATED, Json(conversation)))
+}
+
+/// Conversations of other users are reported as not found
+async fn get_conversation(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ConversationDetailResponse>, StatusCode> {
+    let user_id = user.require_id()?;
+    let conversation = find_conversation(&state.db, id, user_id)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let messages = list_messages(&state.db, id, user_id, false)
+        .await
+        .map_err(internal_error)?;
+
+    Ok(Json(ConversationDetailResponse {
+        conversation,
+        messages,
+    }))
+}
+
+async fn update_conversation(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateConversationRequest>,
+) -> Result<Json<Conversation>, StatusCode> {
+    let user_id = user.require_id()?;
+    sqlx::query_as::<_, Conversation>(
+        "UPDATE agent_conversations
+         SET title = COALESCE($3, title),
+             system_prompt = COALESCE($4, system_prompt),
+             model = COALESCE($5, model)
+         WHERE id = $1 AND user_id = $2
+         RETURNING id, title, system_prompt, model, summary, created_at, updated_at",
+    )
+    .bind(id)
+    .bind(user_id)
+    .bind(payload.title)
+    .bind(payload.system_prompt)
+    .bind(payload.model)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(internal_error)?
+    .map(Json)
+    .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn get_messages(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+    pagination: Pagination,
+) -> Result<Json<Paginated<StoredMessage>>, StatusCode> {
+    let user_id = user.require_id()?;
+    find_conversation(&state.db, id, user_id)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    page_messages(&state.db, id, user_id, &pagination)
+        .await
+        .map(Json)
+        .map_err(internal_error)
+}
+
+async fn post_message(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<PostMessageRequest>,
+) -> Result<Json<PostMessageResponse>, StatusCode> {
+    let user_id = user.require_id()?;
+    if payload.content.trim().is_empty() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let mut conversation = find_conversation(&state.db, id, user_id)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    insert_message(&state.db, id, user_id, ChatRole::User, &payload.content)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let mut history = list_messages(&state.db, id, user_id, true)
+        .await
+        .map_err(internal_error)?;
+
+    // Fold the oldest messages into the summary once history outgrows the budget
+    let token_counts: Vec<usize> = history
+        .iter()
+        .map(|m| m.token_estimate.max(0) as usize)
+        .collect();
+    let split = summarize_split(
+        &token_counts,
+        state.config.conversation.context_token_budget,
+        state.config.conversation.keep_recent_messages,
+    );
+
+    if split > 0 {
+        let folded: Vec<StoredMessage> = history.drain(..split).collect();
+        let summary = summarize(state.llm.as_ref(), conversation.summary.as_deref(), &folded).await;
+        let folded_ids: Vec<Uuid> = folded.iter().map(|m| m.id).collect();
+
+        apply_summary(&state.db, id, user_id, summary.as_deref(), &folded_ids)
+            .await
+            .map_err(internal_error)?;
+
+        if summary.is_some() {
+            conversation.summary = summary;
+        }
+        tracing::info!(
+            "Conversation {}: folded {} message(s) into summary",
+            id,
+            folded.len()
+        );
+    }
+
+    let completion = state
+        .llm
+        .chat(ChatRequest {
+            model: payload.model.or_else(|| conversation.model.clone()),
+            messages: build_context(&conversation, &history),
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!("Conversation {}: provider call failed: {}", id, e);
+            e.status()
+        })?;
+
+    let message = insert_message(
+        &state.db,
+        id,
+        user_id,
+        ChatRole::Assistant,
+        &completion.content,
+    )
+    .await
+    .map_err(internal_error)?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    sqlx::query("UPDATE agent_conversations SET updated_at = NOW() WHERE id = $1 AND user_id = $2")
+        .bind(id)
+        .bind(user_id)
+        .execute(&state.db)
+        .await
+        .map_err(internal_error)?;
+
+    Ok(Json(PostMessageResponse {
+        message,
+        model_used: completion.model_used,
+        tokens_used: completion.tokens_used,
+        summarized_messages: split,
+    }))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/aigen/conversations",
+            get(list_conversations).post(create_conversation),
+        )
+        .route(
+            "/api/aigen/conversations/:id",
+            get(get_conversation).patch(update_conversation),
+        )
+        .route(
+            "/api/aigen/conversations/:id/messages",
+            get(get_messages).post(post_message),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_split_within_budget() {
+        assert_eq!(summarize_split(&[100, 200, 300], 1000, 2), 0);
+    }
+
+    #[test]
+    fn test_summarize_split_over_budget() {
+        // Newest two (300 + 300) fit, the third (400) does not
+        assert_eq!(summarize_split(&[400, 400, 300, 300], 700, 1), 2);
+    }
+
+    #[test]
+    fn test_summarize_split_keeps_recent_even_over_budget() {
+        assert_eq!(summarize_split(&[500, 500, 500], 100, 2), 1);
+    }
+}
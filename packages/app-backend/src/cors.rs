@@ -0,0 +1,133 @@
+use axum::http::{HeaderName, HeaderValue};
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+
+use crate::config::CorsConfig;
+
+// ========================================
+// Origin Matching
+// ========================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum OriginPattern {
+    /// `https://app.example.com`
+    Exact(String),
+    /// `https://*.example.com` (any subdomain, not the apex)
+    Subdomain { scheme: String, suffix: String },
+}
+
+/// Allowlist of CORS origins (exact or wildcard subdomain)
+#[derive(Debug, Clone, Default)]
+pub struct OriginAllowlist {
+    patterns: Vec<OriginPattern>,
+}
+
+impl OriginAllowlist {
+    pub fn new<S: AsRef<str>>(origins: &[S]) -> Self {
+        let patterns = origins
+            .iter()
+            .map(|origin| {
+                origin
+                    .as_ref()
+                    .trim()
+                    .trim_end_matches('/')
+                    .to_ascii_lowercase()
+            })
+            .filter(|origin| !origin.is_empty())
+            .map(|origin| match origin.split_once("://*.") {
+                Some((scheme, suffix)) => OriginPattern::Subdomain {
+                    scheme: scheme.to_string(),
+                    suffix: suffix.to_string(),
+                },
+                None => OriginPattern::Exact(origin),
+            })
+            .collect();
+
+        Self { patterns }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn allows(&self, origin: &str) -> bool {
+        let origin = origin.to_ascii_lowercase();
+
+        self.patterns.iter().any(|pattern| match pattern {
+            OriginPattern::Exact(exact) => *exact == origin,
+            OriginPattern::Subdomain { scheme, suffix } => origin
+                .strip_prefix(scheme.as_str())
+                .and_then(|rest| rest.strip_prefix("://"))
+                .and_then(|host| host.strip_suffix(suffix.as_str()))
+                .is_some_and(|sub| {
+                    sub.len() > 1
+                        && sub.ends_with('.')
+                        && sub
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+                }),
+        })
+    }
+}
+
+// ========================================
+// Layer
+// ========================================
+
+/// Build the CORS layer from configuration.
+///
+/// Without an allowlist, development allows any origin while production
+/// fails closed (no cross-origin requests are allowed).
+pub fn cors_layer(config: &CorsConfig, production: bool) -> CorsLayer {
+    let layer = CorsLayer::new()
+        .allow_methods(Any)
+        .allow_headers(Any)
+        .expose_headers([
+            HeaderName::from_static("x-request-id"),
+            HeaderName::from_static("idempotent-replayed"),
+        ]);
+
+    if config.allowed_origins.iter().any(|origin| origin == "*") {
+        tracing::warn!("CORS_ALLOWED_ORIGINS contains '*', allowing any origin");
+        return layer.allow_origin(Any);
+    }
+
+    let allowlist = OriginAllowlist::new(&config.allowed_origins);
+    if allowlist.is_empty() {
+        if production {
+            tracing::warn!(
+                "CORS_ALLOWED_ORIGINS is empty in production, cross-origin requests are blocked"
+            );
+            return layer.allow_origin(AllowOrigin::predicate(|_, _| false));
+        }
+        return layer.allow_origin(Any);
+    }
+
+    layer.allow_origin(AllowOrigin::predicate(move |origin: &HeaderValue, _| {
+        origin.to_str().is_ok_and(|o| allowlist.allows(o))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_origin() {
+        let allowlist = OriginAllowlist::new(&["https://app.example.com/"]);
+        assert!(allowlist.allows("https://app.example.com"));
+        assert!(allowlist.allows("https://APP.example.com"));
+        assert!(!allowlist.allows("http://app.example.com"));
+        assert!(!allowlist.allows("https://app.example.com:8443"));
+    }
+
+    #[test]
+    fn test_wildcard_subdomain() {
+        let allowlist = OriginAllowlist::new(&["https://*.example.com"]);
+        assert!(allowlist.allows("https://preview-42.example.com"));
+        assert!(allowlist.allows("https://a.b.example.com"));
+        assert!(!allowlist.allows("https://example.com"));
+        assert!(!allowlist.allows("https://evilexample.com"));
+        assert!(!allowlist.allows("https://example.com.evil.io"));
+        assert!(!allowlist.allows("http://app.example.com"));
+    }
+}
@@ -2,7 +2,6 @@ use sqlx::{postgres::PgPoolOptions, PgPool};
 use std::time::Duration;
 
 /// Initialize Supabase (PostgreSQL) connection pool
-#[allow(dead_code)]
 pub async fn init_db_pool() -> Result<PgPool, sqlx::Error> {
     let database_url = std::env::var("DATABASE_URL")
         .expect("DATABASE_URL must be set in environment variables");
@@ -0,0 +1,533 @@
+use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::fmt;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use crate::auth::AdminUser;
+use crate::config::EmailConfig;
+use crate::error::internal_error;
+use crate::jobs::{Job, JobStatus};
+use crate::state::AppState;
+use crate::template;
+
+// ========================================
+// Models
+// ========================================
+
+/// Values for `{{name}}` placeholders
+pub type Variables = HashMap<String, String>;
+
+/// Row of `email_templates`
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct EmailTemplate {
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub html: String,
+    pub text: Option<String>,
+}
+
+/// A templated email to one recipient
+pub struct Notification {
+    pub template: String,
+    pub to: String,
+    pub user_id: Option<Uuid>,
+    pub variables: Variables,
+    /// Not sent again once a delivery with this key has been recorded
+    pub dedupe_key: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum EmailError {
+    Http(reqwest::Error),
+    Api(String),
+    Db(sqlx::Error),
+    TemplateNotFound(String),
+}
+
+impl fmt::Display for EmailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmailError::Http(err) => write!(f, "Email request failed: {}", err),
+            EmailError::Api(msg) => write!(f, "Resend API error: {}", msg),
+            EmailError::Db(err) => write!(f, "Email database error: {}", err),
+            EmailError::TemplateNotFound(key) => write!(f, "Email template '{}' not found", key),
+        }
+    }
+}
+
+impl std::error::Error for EmailError {}
+
+impl From<reqwest::Error> for EmailError {
+    fn from(err: reqwest::Error) -> Self {
+        EmailError::Http(err)
+    }
+}
+
+impl From<sqlx::Error> for EmailError {
+    fn from(err: sqlx::Error) -> Self {
+        EmailError::Db(err)
+    }
+}
+
+impl From<EmailError> for StatusCode {
+    fn from(err: EmailError) -> Self {
+        match err {
+            EmailError::TemplateNotFound(_) => StatusCode::NOT_FOUND,
+            EmailError::Http(_) | EmailError::Api(_) => {
+                tracing::error!("{}", err);
+                StatusCode::BAD_GATEWAY
+            }
+            EmailError::Db(err) => internal_error(err),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SendResponse {
+    id: String,
+}
+
+// ========================================
+// Templates
+// ========================================
+
+impl EmailTemplate {
+    /// Fill `{{name}}` placeholders; values are HTML-escaped in the HTML body
+    /// and unknown placeholders render as empty strings
+    pub fn render(&self, variables: &Variables) -> RenderedEmail {
+        RenderedEmail {
+            subject: substitute(&self.subject, variables, false),
+            html: substitute(&self.html_body, variables, true),
+            text: self
+                .text_body
+                .as_deref()
+                .map(|body| substitute(body, variables, false)),
+        }
+    }
+}
+
+fn substitute(template: &str, variables: &Variables, escape: bool) -> String {
+    template::render_with(template, |name| {
+        let value = variables.get(name).map_or("", String::as_str);
+        Some(if escape {
+            escape_html(value)
+        } else {
+            value.to_string()
+        })
+    })
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+pub async fn load_template(db: &PgPool, key: &str) -> Result<EmailTemplate, EmailError> {
+    sqlx::query_as::<_, EmailTemplate>(
+        "SELECT subject, html_body, text_body FROM email_templates WHERE key = $1",
+    )
+    .bind(key)
+    .fetch_optional(db)
+    .await?
+    .ok_or_else(|| EmailError::TemplateNotFound(key.to_string()))
+}
+
+// ========================================
+// Resend Client
+// ========================================
+
+/// Sends notification emails through the Resend API
+#[derive(Clone)]
+pub struct Mailer {
+    client: reqwest::Client,
+    base_url: String,
+    api_key: String,
+    from: String,
+}
+
+impl Mailer {
+    /// Returns `None` when the Resend API key is not configured
+    pub fn from_config(config: &EmailConfig) -> Option<Self> {
+        let Some(api_key) = &config.resend_api_key else {
+            tracing::warn!("RESEND_API_KEY not set, notification emails are disabled");
+            return None;
+        };
+
+        Some(Self {
+            client: reqwest::Client::new(),
+            base_url: config.resend_base_url.trim_end_matches('/').to_string(),
+            api_key: api_key.clone(),
+            from: config.from.clone(),
+        })
+    }
+
+    /// Send a rendered email and return the Resend email id
+    pub async fn send(&self, to: &str, email: &RenderedEmail) -> Result<String, EmailError> {
+        let response = self
+            .client
+            .post(format!("{}/emails", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&serde_json::json!({
+                "from": self.from,
+                "to": [to],
+                "subject": email.subject,
+                "html": email.html,
+                "text": email.text,
+            }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(EmailError::Api(format!("{}: {}", status, body)));
+        }
+
+        let body: SendResponse = response.json().await?;
+        Ok(body.id)
+    }
+
+    /// Render the notification's template, send it and record the delivery.
+    ///
+    /// Returns `Ok(None)` when the dedupe key was already sent. Failed
+    /// deliveries are recorded without the key so they are retried.
+    pub async fn notify(
+        &self,
+        db: &PgPool,
+        notification: &Notification,
+    ) -> Result<Option<String>, EmailError> {
+        if let Some(key) = &notification.dedupe_key {
+            let sent: bool = sqlx::query_scalar(
+                "SELECT EXISTS (SELECT 1 FROM email_deliveries WHERE dedupe_key = $1)",
+            )
+            .bind(key)
+            .fetch_one(db)
+            .await?;
+            if sent {
+                return Ok(None);
+            }
+        }
+
+        let email = load_template(db, &notification.template)
+            .await?
+            .render(&notification.variables);
+        let result = self.send(&notification.to, &email).await;
+
+        let (status, provider_id, error) = match &result {
+            Ok(id) => ("sent", Some(id.clone()), None),
+            Err(err) => ("failed", None, Some(err.to_string())),
+        };
+        let recorded = sqlx::query(
+            "INSERT INTO email_deliveries
+               (template_key, recipient, user_id, dedupe_key, status, provider_id, error)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             ON CONFLICT (dedupe_key) DO NOTHING",
+        )
+        .bind(&notification.template)
+        .bind(&notification.to)
+        .bind(notification.user_id)
+        .bind(notification.dedupe_key.clone().filter(|_| result.is_ok()))
+        .bind(status)
+        .bind(provider_id)
+        .bind(error)
+        .execute(db)
+        .await;
+        if let Err(e) = recorded {
+            tracing::warn!("Failed to record email delivery: {}", e);
+        }
+
+        result.map(Some)
+    }
+}
+
+// ========================================
+// Notifications
+// ========================================
+
+/// Email job owners when a long-running job completes or fails
+pub fn spawn_job_notifier(state: AppState) {
+    let Some(mailer) = state.email.clone() else {
+        return;
+    };
+    let min_duration = Duration::seconds(state.config.email.job_notify_min_secs);
+    let mut finished = state.jobs.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            let job = match finished.recv().await {
+                Ok(job) => job,
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Job notifier skipped {} finished job(s)", skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            let (Some(owner), Some((template, variables))) =
+                (job.owner, job_variables(&job, min_duration))
+            else {
+                continue;
+            };
+
+            let email = match user_email(&state.db, owner).await {
+                Ok(Some(email)) => email,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!("Failed to look up email of user {}: {}", owner, e);
+                    continue;
+                }
+            };
+            let notification = Notification {
+                template: template.to_string(),
+                to: email,
+                user_id: Some(owner),
+                variables,
+                dedupe_key: Some(format!("{}:{}:{}", template, job.id, job.attempts)),
+            };
+            if let Err(e) = mailer.notify(&state.db, &notification).await {
+                tracing::warn!(
+                    "Failed to notify user {} about job {}: {}",
+                    owner,
+                    job.id,
+                    e
+                );
+            }
+        }
+    });
+}
+
+/// Template and variables for a finished job (`None` for short or cancelled jobs)
+fn job_variables(job: &Job, min_duration: Duration) -> Option<(&'static str, Variables)> {
+    let template = match job.status {
+        JobStatus::Completed => "job_completed",
+        JobStatus::Failed => "job_failed",
+        _ => return None,
+    };
+    let duration = job.updated_at - job.created_at;
+    if duration < min_duration {
+        return None;
+    }
+
+    let mut variables = Variables::from([
+        ("kind".to_string(), job.kind.clone()),
+        ("job_id".to_string(), job.id.to_string()),
+        ("duration".to_string(), format_duration(duration)),
+    ]);
+    if let Some(error) = &job.error {
+        variables.insert("error".to_string(), error.clone());
+    }
+    Some((template, variables))
+}
+
+/// "1h 05m", "3m 20s", "45s"
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.num_seconds().max(0);
+    match (secs / 3600, secs % 3600 / 60, secs % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m {:02}s", m, s),
+        (h, m, _) => format!("{}h {:02}m", h, m),
+    }
+}
+
+async fn user_email(db: &PgPool, user_id: Uuid) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar::<_, Option<String>>("SELECT email FROM auth.users WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+        .map(Option::flatten)
+}
+
+#[derive(sqlx::FromRow)]
+struct QuotaUsage {
+    user_id: Uuid,
+    email: String,
+    plan_type: String,
+    current_month: String,
+    requests_used: i32,
+    monthly_request_limit: i32,
+}
+
+/// Warn users who used `EMAIL_QUOTA_WARNING_PERCENT` of this month's
+/// request limit (once per user and month)
+pub async fn send_quota_warnings(state: AppState) -> Result<String, String> {
+    let Some(mailer) = state.email.clone() else {
+        return Ok("skipped, RESEND_API_KEY is not set".to_string());
+    };
+
+    let usages = sqlx::query_as::<_, QuotaUsage>(
+        "SELECT q.user_id, u.email, q.plan_type, q.current_month,
+                q.requests_used, q.monthly_request_limit
+         FROM user_quotas q
+         JOIN auth.users u ON u.id = q.user_id
+         WHERE q.current_month = TO_CHAR(NOW(), 'YYYY-MM')
+           AND q.monthly_request_limit > 0
+           AND u.email IS NOT NULL
+           AND q.requests_used * 100 >= q.monthly_request_limit * $1",
+    )
+    .bind(state.config.email.quota_warning_percent)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let (mut sent, mut failed) = (0, 0);
+    for usage in usages {
+        let percent = usage.requests_used as i64 * 100 / usage.monthly_request_limit as i64;
+        let notification = Notification {
+            template: "quota_warning".to_string(),
+            to: usage.email,
+            user_id: Some(usage.user_id),
+            variables: Variables::from([
+                ("requests_used".to_string(), usage.requests_used.to_string()),
+                (
+                    "request_limit".to_string(),
+                    usage.monthly_request_limit.to_string(),
+                ),
+                ("percent".to_string(), percent.to_string()),
+                ("plan".to_string(), usage.plan_type),
+                ("month".to_string(), usage.current_month.clone()),
+            ]),
+            dedupe_key: Some(format!(
+                "quota_warning:{}:{}",
+                usage.user_id, usage.current_month
+            )),
+        };
+        match mailer.notify(&state.db, &notification).await {
+            Ok(Some(_)) => sent += 1,
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!("Quota warning to user {} failed: {}", usage.user_id, e);
+                failed += 1;
+            }
+        }
+    }
+
+    Ok(format!("sent {} quota warning(s), {} failed", sent, failed))
+}
+
+// ========================================
+// Admin Handlers
+// ========================================
+
+#[derive(Debug, Deserialize)]
+struct TestSendRequest {
+    to: String,
+    /// `email_templates.key`
+    template: String,
+    #[serde(default)]
+    variables: Variables,
+}
+
+#[derive(Debug, Serialize)]
+struct TestSendResponse {
+    /// Resend email id
+    id: String,
+    subject: String,
+}
+
+async fn admin_test_send(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+    Json(payload): Json<TestSendRequest>,
+) -> Result<Json<TestSendResponse>, StatusCode> {
+    if !payload.to.contains('@') {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let mailer = state
+        .email
+        .as_ref()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+
+    let email = load_template(&state.db, &payload.template)
+        .await?
+        .render(&payload.variables);
+    let id = mailer.send(&payload.to, &email).await?;
+    tracing::info!(
+        "Admin {:?} sent test email '{}' to {}",
+        admin.id,
+        payload.template,
+        payload.to
+    );
+
+    Ok(Json(TestSendResponse {
+        id,
+        subject: email.subject,
+    }))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new().route("/api/admin/email/test", post(admin_test_send))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_render_template() {
+        let template = EmailTemplate {
+            subject: "Your {{kind}} job failed".to_string(),
+            html_body: "<p>{{ error }}</p>{{missing}}".to_string(),
+            text_body: Some("{{kind}}: {{error}} {{unclosed".to_string()),
+        };
+        let variables = Variables::from([
+            ("kind".to_string(), "upscale".to_string()),
+            ("error".to_string(), "<timeout> & retry".to_string()),
+        ]);
+
+        assert_eq!(
+            template.render(&variables),
+            RenderedEmail {
+                subject: "Your upscale job failed".to_string(),
+                html: "<p>&lt;timeout&gt; &amp; retry</p>".to_string(),
+                text: Some("upscale: <timeout> & retry {{unclosed".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_job_variables() {
+        let now = Utc::now();
+        let mut job = Job {
+            id: Uuid::new_v4(),
+            kind: "docs-index".to_string(),
+            owner: Some(Uuid::new_v4()),
+            status: JobStatus::Completed,
+            progress: 100,
+            attempts: 1,
+            result: None,
+            error: None,
+            created_at: now - Duration::seconds(200),
+            updated_at: now,
+        };
+
+        let (template, variables) = job_variables(&job, Duration::seconds(60)).unwrap();
+        assert_eq!(template, "job_completed");
+        assert_eq!(variables["duration"], "3m 20s");
+
+        // Short jobs and cancelled jobs are not notified
+        assert!(job_variables(&job, Duration::minutes(5)).is_none());
+        job.status = JobStatus::Cancelled;
+        assert!(job_variables(&job, Duration::seconds(60)).is_none());
+
+        assert_eq!(format_duration(Duration::seconds(45)), "45s");
+        assert_eq!(format_duration(Duration::minutes(65)), "1h 05m");
+    }
+}
@@ -0,0 +1,250 @@
+use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::{AdminUser, AuthUser};
+use crate::error::internal_error;
+use crate::llm::{EmbeddingRequest, LlmProvider};
+use crate::state::AppState;
+
+const DEFAULT_NAMESPACE: &str = "default";
+const DEFAULT_SEARCH_LIMIT: i64 = 5;
+const MAX_SEARCH_LIMIT: i64 = 50;
+const MAX_EMBED_INPUTS: usize = 100;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Deserialize)]
+struct EmbedRequest {
+    texts: Vec<String>,
+    namespace: Option<String>,
+    metadata: Option<serde_json::Value>,
+    /// Return the raw vectors in the response (default: false)
+    #[serde(default)]
+    return_embeddings: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct EmbedResponse {
+    ids: Vec<Uuid>,
+    namespace: String,
+    model_used: String,
+    dimensions: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embeddings: Option<Vec<Vec<f32>>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchRequest {
+    query: String,
+    namespace: Option<String>,
+    limit: Option<i64>,
+    /// Minimum cosine similarity (0.0 - 1.0)
+    min_similarity: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct SearchResult {
+    pub id: Uuid,
+    pub namespace: String,
+    pub content: String,
+    pub metadata: serde_json::Value,
+    pub similarity: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct SearchResponse {
+    results: Vec<SearchResult>,
+}
+
+// ========================================
+// Vector Helpers
+// ========================================
+
+/// Render a vector in pgvector's text input format (`[0.1,0.2,...]`)
+pub fn to_pgvector(values: &[f32]) -> String {
+    let parts: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    format!("[{}]", parts.join(","))
+}
+
+/// Embed `texts` and store them in the `embeddings` table, returning the new row ids
+pub async fn embed_and_store(
+    db: &PgPool,
+    llm: &dyn LlmProvider,
+    namespace: &str,
+    texts: &[String],
+    metadata: &[serde_json::Value],
+) -> Result<(Vec<Uuid>, Vec<Vec<f32>>, String), StatusCode> {
+    let response = llm
+        .embed(EmbeddingRequest {
+            model: None,
+            inputs: texts.to_vec(),
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!("Embedding request failed: {}", e);
+            e.status()
+        })?;
+
+    let mut tx = db.begin().await.map_err(internal_error)?;
+    let mut ids = Vec::with_capacity(texts.len());
+
+    for (index, (text, embedding)) in texts.iter().zip(&response.embeddings).enumerate() {
+        let row_metadata = metadata
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| serde_json::json!({}));
+
+        let id: Uuid = sqlx::query_scalar(
+            "INSERT INTO embeddings (namespace, content, metadata, model, embedding)
+             VALUES ($1, $2, $3, $4, $5::vector)
+             RETURNING id",
+        )
+        .bind(namespace)
+        .bind(text)
+        .bind(row_metadata)
+        .bind(&response.model_used)
+        .bind(to_pgvector(embedding))
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(internal_error)?;
+        ids.push(id);
+    }
+
+    tx.commit().await.map_err(internal_error)?;
+
+    Ok((ids, response.embeddings, response.model_used))
+}
+
+/// Cosine-similarity search over stored embeddings
+pub async fn search_similar(
+    db: &PgPool,
+    llm: &dyn LlmProvider,
+    query: &str,
+    namespace: Option<&str>,
+    limit: i64,
+    min_similarity: f64,
+) -> Result<Vec<SearchResult>, StatusCode> {
+    let response = llm
+        .embed(EmbeddingRequest {
+            model: None,
+            inputs: vec![query.to_string()],
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!("Query embedding failed: {}", e);
+            e.status()
+        })?;
+    let query_vector = response
+        .embeddings
+        .first()
+        .map(|v| to_pgvector(v))
+        .ok_or(StatusCode::BAD_GATEWAY)?;
+
+    sqlx::query_as::<_, SearchResult>(
+        "SELECT id, namespace, content, metadata,
+                (1 - (embedding <=> $1::vector))::float8 AS similarity
+         FROM embeddings
+         WHERE ($2::text IS NULL OR namespace = $2)
+           AND 1 - (embedding <=> $1::vector) >= $4
+         ORDER BY embedding <=> $1::vector
+         LIMIT $3",
+    )
+    .bind(query_vector)
+    .bind(namespace)
+    .bind(limit.clamp(1, MAX_SEARCH_LIMIT))
+    .bind(min_similarity)
+    .fetch_all(db)
+    .await
+    .map_err(internal_error)
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// Admin only: writes to the shared `embeddings` table and spends provider credits
+async fn create_embeddings(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Json(payload): Json<EmbedRequest>,
+) -> Result<(StatusCode, Json<EmbedResponse>), StatusCode> {
+    if payload.texts.is_empty()
+        || payload.texts.len() > MAX_EMBED_INPUTS
+        || payload.texts.iter().any(|t| t.trim().is_empty())
+    {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let namespace = payload
+        .namespace
+        .unwrap_or_else(|| DEFAULT_NAMESPACE.to_string());
+    let metadata =
+        vec![payload.metadata.unwrap_or_else(|| serde_json::json!({})); payload.texts.len()];
+
+    let (ids, embeddings, model_used) = embed_and_store(
+        &state.db,
+        state.llm.as_ref(),
+        &namespace,
+        &payload.texts,
+        &metadata,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(EmbedResponse {
+            ids,
+            namespace,
+            model_used,
+            dimensions: embeddings.first().map(Vec::len).unwrap_or(0),
+            embeddings: payload.return_embeddings.then_some(embeddings),
+        }),
+    ))
+}
+
+async fn search(
+    State(state): State<AppState>,
+    _user: AuthUser,
+    Json(payload): Json<SearchRequest>,
+) -> Result<Json<SearchResponse>, StatusCode> {
+    if payload.query.trim().is_empty() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let results = search_similar(
+        &state.db,
+        state.llm.as_ref(),
+        &payload.query,
+        payload.namespace.as_deref(),
+        payload.limit.unwrap_or(DEFAULT_SEARCH_LIMIT),
+        payload.min_similarity.unwrap_or(0.0),
+    )
+    .await?;
+
+    Ok(Json(SearchResponse { results }))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/aigen/embeddings", post(create_embeddings))
+        .route("/api/aigen/search", post(search))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_pgvector() {
+        assert_eq!(to_pgvector(&[0.5, -1.0, 2.25]), "[0.5,-1,2.25]");
+        assert_eq!(to_pgvector(&[]), "[]");
+    }
+}
@@ -0,0 +1,8 @@
+use axum::http::StatusCode;
+use std::fmt::Display;
+
+/// Log an unexpected error and map it to 500 Internal Server Error
+pub fn internal_error<E: Display>(err: E) -> StatusCode {
+    tracing::error!("Internal error: {}", err);
+    StatusCode::INTERNAL_SERVER_ERROR
+}
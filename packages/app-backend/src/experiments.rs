@@ -0,0 +1,492 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashSet;
+use std::time::Instant;
+use uuid::Uuid;
+
+use crate::auth::AdminUser;
+use crate::error::internal_error;
+use crate::feedback;
+use crate::state::AppState;
+use crate::template;
+
+pub const MIN_VARIANTS: usize = 2;
+pub const MAX_VARIANTS: usize = 10;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct Experiment {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    /// draft | running | stopped
+    pub status: String,
+    #[sqlx(skip)]
+    pub variants: Vec<Variant>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Prompt/model overrides applied to requests assigned to this variant
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Variant {
+    #[serde(default = "Uuid::nil", skip_deserializing)]
+    pub id: Uuid,
+    pub key: String,
+    #[serde(default = "default_weight")]
+    pub weight: i32,
+    pub model: Option<String>,
+    pub system_prompt: Option<String>,
+    /// `{{input}}` is replaced with the request's prompt/task
+    pub prompt_template: Option<String>,
+}
+
+fn default_weight() -> i32 {
+    1
+}
+
+impl Variant {
+    /// Wrap the caller's input with the variant's template
+    pub fn apply_prompt(&self, input: &str) -> String {
+        match &self.prompt_template {
+            Some(prompt_template) => template::render_with(prompt_template, |name| {
+                (name == "input").then(|| input.to_string())
+            }),
+            None => input.to_string(),
+        }
+    }
+}
+
+/// Variant chosen for a request; send feedback with `result_id` as the `job_id`
+#[derive(Debug, Clone, Serialize)]
+pub struct ExperimentAssignment {
+    pub experiment_id: Uuid,
+    pub variant: String,
+    pub result_id: Uuid,
+}
+
+/// Per-variant comparison returned by the analytics endpoint
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct VariantStats {
+    pub variant: String,
+    pub model: Option<String>,
+    pub weight: i32,
+    pub exposures: i64,
+    pub users: i64,
+    pub failures: i64,
+    pub avg_latency_ms: Option<f64>,
+    pub avg_tokens: Option<f64>,
+    /// Feedback from `POST /api/aigen/feedback`
+    #[sqlx(skip)]
+    pub ratings: i64,
+    #[sqlx(skip)]
+    pub positive: i64,
+    /// `positive / ratings` (None until the first rating)
+    #[sqlx(skip)]
+    pub positive_rate: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateExperimentRequest {
+    name: String,
+    description: Option<String>,
+    #[serde(default = "default_status")]
+    status: String,
+    variants: Vec<Variant>,
+}
+
+fn default_status() -> String {
+    "running".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateExperimentRequest {
+    status: String,
+}
+
+const STATUSES: &[&str] = &["draft", "running", "stopped"];
+
+const EXPERIMENT_COLUMNS: &str = "id, name, description, status, created_at, updated_at";
+const VARIANT_COLUMNS: &str = "id, key, weight, model, system_prompt, prompt_template";
+
+// ========================================
+// Assignment
+// ========================================
+
+/// FNV-1a: stable across processes and releases, unlike `DefaultHasher`
+fn stable_hash(value: &str) -> u64 {
+    value.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Pick a variant by weight; the same subject always gets the same variant
+pub fn pick_variant<'a>(
+    experiment_id: Uuid,
+    subject: &str,
+    variants: &'a [Variant],
+) -> Option<&'a Variant> {
+    let total: u64 = variants.iter().map(|v| v.weight.max(0) as u64).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut bucket = stable_hash(&format!("{}:{}", experiment_id, subject)) % total;
+    variants.iter().find(|v| {
+        let weight = v.weight.max(0) as u64;
+        if bucket < weight {
+            return true;
+        }
+        bucket -= weight;
+        false
+    })
+}
+
+/// Variant for the caller, or `None` when the experiment is not running.
+/// Anonymous callers get a random (per-request) assignment.
+pub async fn assign(
+    db: &PgPool,
+    experiment_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<Option<Variant>, StatusCode> {
+    let status: Option<String> = sqlx::query_scalar("SELECT status FROM experiments WHERE id = $1")
+        .bind(experiment_id)
+        .fetch_optional(db)
+        .await
+        .map_err(internal_error)?;
+    match status.as_deref() {
+        None => return Err(StatusCode::NOT_FOUND),
+        Some("running") => {}
+        Some(_) => return Ok(None),
+    }
+
+    let variants = load_variants(db, experiment_id)
+        .await
+        .map_err(internal_error)?;
+    let subject = user_id.unwrap_or_else(Uuid::new_v4).to_string();
+    Ok(pick_variant(experiment_id, &subject, &variants).cloned())
+}
+
+/// Outcome of one experiment-assigned generation
+pub struct ExperimentRun<'a> {
+    pub experiment_id: Uuid,
+    pub variant: &'a Variant,
+    pub user_id: Option<Uuid>,
+    pub endpoint: &'static str,
+    pub started: Instant,
+    pub succeeded: bool,
+    pub tokens_used: Option<u32>,
+}
+
+/// Store the outcome so it shows up in the analytics (failures are only logged)
+pub async fn record_result(db: &PgPool, run: ExperimentRun<'_>) -> Option<ExperimentAssignment> {
+    let result: Result<Uuid, sqlx::Error> = sqlx::query_scalar(
+        "INSERT INTO experiment_results
+           (experiment_id, variant_id, user_id, endpoint, succeeded, latency_ms, tokens_used)
+         VALUES ($1, $2, $3, $4, $5, $6, $7)
+         RETURNING id",
+    )
+    .bind(run.experiment_id)
+    .bind(run.variant.id)
+    .bind(run.user_id)
+    .bind(run.endpoint)
+    .bind(run.succeeded)
+    .bind(run.started.elapsed().as_millis() as i32)
+    .bind(run.tokens_used.map(|t| t as i32))
+    .fetch_one(db)
+    .await;
+
+    match result {
+        Ok(result_id) => Some(ExperimentAssignment {
+            experiment_id: run.experiment_id,
+            variant: run.variant.key.clone(),
+            result_id,
+        }),
+        Err(e) => {
+            tracing::warn!("Failed to record experiment result: {}", e);
+            None
+        }
+    }
+}
+
+// ========================================
+// Helpers
+// ========================================
+
+async fn load_variants(db: &PgPool, experiment_id: Uuid) -> Result<Vec<Variant>, sqlx::Error> {
+    sqlx::query_as::<_, Variant>(&format!(
+        "SELECT {} FROM experiment_variants WHERE experiment_id = $1 ORDER BY key",
+        VARIANT_COLUMNS
+    ))
+    .bind(experiment_id)
+    .fetch_all(db)
+    .await
+}
+
+async fn find_experiment(db: &PgPool, id: Uuid) -> Result<Option<Experiment>, sqlx::Error> {
+    let experiment = sqlx::query_as::<_, Experiment>(&format!(
+        "SELECT {} FROM experiments WHERE id = $1",
+        EXPERIMENT_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(db)
+    .await?;
+    let Some(mut experiment) = experiment else {
+        return Ok(None);
+    };
+    experiment.variants = load_variants(db, id).await?;
+    Ok(Some(experiment))
+}
+
+/// Between MIN_VARIANTS and MAX_VARIANTS variants with unique, non-empty keys and positive weights
+fn validate_variants(variants: &[Variant]) -> bool {
+    let mut keys = HashSet::new();
+    (MIN_VARIANTS..=MAX_VARIANTS).contains(&variants.len())
+        && variants.iter().all(|v| {
+            let key = v.key.trim();
+            !key.is_empty() && key.len() <= 50 && v.weight > 0 && keys.insert(key.to_string())
+        })
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+async fn admin_create_experiment(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateExperimentRequest>,
+) -> Result<(StatusCode, Json<Experiment>), StatusCode> {
+    let name = payload.name.trim();
+    if name.is_empty()
+        || !STATUSES.contains(&payload.status.as_str())
+        || !validate_variants(&payload.variants)
+    {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    tracing::info!("Admin {:?} creating experiment {}", admin.id, name);
+
+    let mut tx = state.db.begin().await.map_err(internal_error)?;
+    let id: Uuid = sqlx::query_scalar(
+        "INSERT INTO experiments (name, description, status, created_by)
+         VALUES ($1, $2, $3, $4)
+         ON CONFLICT (name) DO NOTHING
+         RETURNING id",
+    )
+    .bind(name)
+    .bind(&payload.description)
+    .bind(&payload.status)
+    .bind(admin.id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(internal_error)?
+    .ok_or(StatusCode::CONFLICT)?;
+
+    for variant in &payload.variants {
+        sqlx::query(
+            "INSERT INTO experiment_variants
+               (experiment_id, key, weight, model, system_prompt, prompt_template)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(id)
+        .bind(variant.key.trim())
+        .bind(variant.weight)
+        .bind(&variant.model)
+        .bind(&variant.system_prompt)
+        .bind(&variant.prompt_template)
+        .execute(&mut *tx)
+        .await
+        .map_err(internal_error)?;
+    }
+    tx.commit().await.map_err(internal_error)?;
+
+    let experiment = find_experiment(&state.db, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok((StatusCode::CREATED, Json(experiment)))
+}
+
+async fn admin_list_experiments(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Experiment>>, StatusCode> {
+    let mut experiments = sqlx::query_as::<_, Experiment>(&format!(
+        "SELECT {} FROM experiments ORDER BY created_at DESC",
+        EXPERIMENT_COLUMNS
+    ))
+    .fetch_all(&state.db)
+    .await
+    .map_err(internal_error)?;
+    for experiment in &mut experiments {
+        experiment.variants = load_variants(&state.db, experiment.id)
+            .await
+            .map_err(internal_error)?;
+    }
+    Ok(Json(experiments))
+}
+
+async fn admin_get_experiment(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Experiment>, StatusCode> {
+    find_experiment(&state.db, id)
+        .await
+        .map_err(internal_error)?
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Start, pause (draft) or stop an experiment
+async fn admin_update_experiment(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateExperimentRequest>,
+) -> Result<Json<Experiment>, StatusCode> {
+    if !STATUSES.contains(&payload.status.as_str()) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    tracing::info!(
+        "Admin {:?} setting experiment {} to {}",
+        admin.id,
+        id,
+        payload.status
+    );
+
+    sqlx::query("UPDATE experiments SET status = $2 WHERE id = $1")
+        .bind(id)
+        .bind(&payload.status)
+        .execute(&state.db)
+        .await
+        .map_err(internal_error)?;
+    admin_get_experiment(AdminUser(admin), State(state), Path(id)).await
+}
+
+/// Compare variants: exposures, failures, latency, tokens and feedback
+async fn admin_experiment_results(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<VariantStats>>, StatusCode> {
+    let mut stats = sqlx::query_as::<_, VariantStats>(
+        "SELECT v.key AS variant, v.model, v.weight,
+           COUNT(r.id) AS exposures,
+           COUNT(DISTINCT r.user_id) AS users,
+           COUNT(r.id) FILTER (WHERE NOT r.succeeded) AS failures,
+           AVG(r.latency_ms)::float8 AS avg_latency_ms,
+           AVG(r.tokens_used)::float8 AS avg_tokens
+         FROM experiment_variants v
+         LEFT JOIN experiment_results r ON r.variant_id = v.id
+         WHERE v.experiment_id = $1
+         GROUP BY v.id
+         ORDER BY v.key",
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(internal_error)?;
+    if stats.is_empty() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let ratings = feedback::counts_by_variant(&state.db, id)
+        .await
+        .map_err(internal_error)?;
+    for variant in &mut stats {
+        if let Some(counts) = ratings.iter().find(|c| c.group == variant.variant) {
+            variant.ratings = counts.total;
+            variant.positive = counts.up;
+            variant.positive_rate = counts.positive_rate;
+        }
+    }
+    Ok(Json(stats))
+}
+
+// ========================================
+// Router Setup
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/admin/experiments",
+            get(admin_list_experiments).post(admin_create_experiment),
+        )
+        .route(
+            "/api/admin/experiments/:id",
+            get(admin_get_experiment).patch(admin_update_experiment),
+        )
+        .route(
+            "/api/admin/experiments/:id/results",
+            get(admin_experiment_results),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(key: &str, weight: i32) -> Variant {
+        Variant {
+            id: Uuid::nil(),
+            key: key.to_string(),
+            weight,
+            model: None,
+            system_prompt: None,
+            prompt_template: None,
+        }
+    }
+
+    #[test]
+    fn test_pick_variant_is_deterministic_and_weighted() {
+        let experiment = Uuid::new_v4();
+        let variants = vec![variant("control", 3), variant("b", 1)];
+
+        let first = pick_variant(experiment, "user-1", &variants).unwrap();
+        for _ in 0..10 {
+            assert_eq!(
+                pick_variant(experiment, "user-1", &variants).unwrap().key,
+                first.key
+            );
+        }
+
+        let control = (0..4000)
+            .filter(|i| {
+                pick_variant(experiment, &format!("user-{}", i), &variants)
+                    .unwrap()
+                    .key
+                    == "control"
+            })
+            .count();
+        assert!((2700..3300).contains(&control), "control got {}", control);
+
+        assert!(pick_variant(experiment, "user-1", &[]).is_none());
+    }
+
+    #[test]
+    fn test_validate_variants_and_template() {
+        assert!(validate_variants(&[variant("a", 1), variant("b", 2)]));
+        assert!(!validate_variants(&[variant("a", 1)]));
+        assert!(!validate_variants(&[variant("a", 1), variant("a", 1)]));
+        assert!(!validate_variants(&[variant("a", 1), variant("b", 0)]));
+
+        let mut templated = variant("a", 1);
+        assert_eq!(templated.apply_prompt("a cat"), "a cat");
+        templated.prompt_template = Some("Watercolor painting of {{input}}".to_string());
+        assert_eq!(
+            templated.apply_prompt("a cat"),
+            "Watercolor painting of a cat"
+        );
+    }
+}
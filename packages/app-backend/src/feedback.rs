@@ -0,0 +1,320 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::{AdminUser, AuthUser};
+use crate::error::internal_error;
+use crate::gallery::normalize_tags;
+use crate::jobs::JobStatus;
+use crate::state::AppState;
+
+pub const MAX_COMMENT_LENGTH: usize = 2000;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Rating {
+    Up,
+    Down,
+}
+
+impl Rating {
+    fn value(self) -> i16 {
+        match self {
+            Rating::Up => 1,
+            Rating::Down => -1,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FeedbackRequest {
+    /// Background job id, or `experiment.result_id` of an agent-execute / text-to-image response
+    job_id: Uuid,
+    rating: Rating,
+    #[serde(default)]
+    tags: Vec<String>,
+    comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct Feedback {
+    pub id: Uuid,
+    pub job_id: Uuid,
+    pub kind: String,
+    pub rating: i16,
+    pub tags: Vec<String>,
+    pub comment: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Thumbs up/down counts for one group (job kind, experiment variant, ...)
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct FeedbackCounts {
+    pub group: String,
+    pub total: i64,
+    pub up: i64,
+    pub down: i64,
+    /// `up / total`
+    #[sqlx(skip)]
+    pub positive_rate: Option<f64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct TagCount {
+    pub tag: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedbackStats {
+    pub since: DateTime<Utc>,
+    pub total: i64,
+    pub up: i64,
+    pub down: i64,
+    pub positive_rate: Option<f64>,
+    pub by_kind: Vec<FeedbackCounts>,
+    pub top_tags: Vec<TagCount>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatsQuery {
+    kind: Option<String>,
+    /// Look-back window in days
+    #[serde(default = "default_days")]
+    days: i64,
+}
+
+fn default_days() -> i64 {
+    30
+}
+
+/// What a feedback `job_id` refers to
+struct Target {
+    kind: String,
+    experiment_result_id: Option<Uuid>,
+}
+
+// ========================================
+// Aggregates
+// ========================================
+
+fn with_rates(mut counts: Vec<FeedbackCounts>) -> Vec<FeedbackCounts> {
+    for group in &mut counts {
+        group.positive_rate = positive_rate(group.up, group.total);
+    }
+    counts
+}
+
+fn positive_rate(up: i64, total: i64) -> Option<f64> {
+    (total > 0).then(|| up as f64 / total as f64)
+}
+
+/// Feedback per job kind since `since` (optionally a single kind)
+pub async fn counts_by_kind(
+    db: &PgPool,
+    since: DateTime<Utc>,
+    kind: Option<&str>,
+) -> Result<Vec<FeedbackCounts>, sqlx::Error> {
+    let counts = sqlx::query_as::<_, FeedbackCounts>(
+        "SELECT kind AS \"group\", COUNT(*) AS total,
+           COUNT(*) FILTER (WHERE rating = 1) AS up,
+           COUNT(*) FILTER (WHERE rating = -1) AS down
+         FROM generation_feedback
+         WHERE created_at >= $1 AND ($2::text IS NULL OR kind = $2)
+         GROUP BY kind
+         ORDER BY total DESC",
+    )
+    .bind(since)
+    .bind(kind)
+    .fetch_all(db)
+    .await?;
+    Ok(with_rates(counts))
+}
+
+/// Feedback per variant key of an experiment
+pub async fn counts_by_variant(
+    db: &PgPool,
+    experiment_id: Uuid,
+) -> Result<Vec<FeedbackCounts>, sqlx::Error> {
+    let counts = sqlx::query_as::<_, FeedbackCounts>(
+        "SELECT v.key AS \"group\", COUNT(*) AS total,
+           COUNT(*) FILTER (WHERE fb.rating = 1) AS up,
+           COUNT(*) FILTER (WHERE fb.rating = -1) AS down
+         FROM generation_feedback fb
+         JOIN experiment_results r ON r.id = fb.experiment_result_id
+         JOIN experiment_variants v ON v.id = r.variant_id
+         WHERE r.experiment_id = $1
+         GROUP BY v.key",
+    )
+    .bind(experiment_id)
+    .fetch_all(db)
+    .await?;
+    Ok(with_rates(counts))
+}
+
+// ========================================
+// Helpers
+// ========================================
+
+/// Resolve `job_id` to a finished job or an experiment result of the caller
+async fn find_target(state: &AppState, user_id: Uuid, job_id: Uuid) -> Result<Target, StatusCode> {
+    if let Some(job) = state.jobs.get(job_id).await {
+        if job.owner.is_some_and(|owner| owner != user_id) {
+            return Err(StatusCode::NOT_FOUND);
+        }
+        if !matches!(job.status, JobStatus::Completed | JobStatus::Failed) {
+            return Err(StatusCode::CONFLICT);
+        }
+        return Ok(Target {
+            kind: job.kind,
+            experiment_result_id: None,
+        });
+    }
+
+    let endpoint: Option<String> = sqlx::query_scalar(
+        "SELECT endpoint FROM experiment_results WHERE id = $1 AND user_id = $2",
+    )
+    .bind(job_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    endpoint
+        .map(|kind| Target {
+            kind,
+            experiment_result_id: Some(job_id),
+        })
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// Record (or replace) the caller's feedback on a generation
+async fn submit_feedback(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<FeedbackRequest>,
+) -> Result<Json<Feedback>, StatusCode> {
+    let user_id = user.require_id()?;
+    let tags = normalize_tags(&payload.tags).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    let comment = payload
+        .comment
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty());
+    if comment
+        .as_ref()
+        .is_some_and(|c| c.chars().count() > MAX_COMMENT_LENGTH)
+    {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let target = find_target(&state, user_id, payload.job_id).await?;
+
+    let feedback = sqlx::query_as::<_, Feedback>(
+        "INSERT INTO generation_feedback
+           (job_id, kind, experiment_result_id, user_id, rating, tags, comment)
+         VALUES ($1, $2, $3, $4, $5, $6, $7)
+         ON CONFLICT (user_id, job_id) DO UPDATE
+           SET rating = EXCLUDED.rating, tags = EXCLUDED.tags, comment = EXCLUDED.comment
+         RETURNING id, job_id, kind, rating, tags, comment, updated_at",
+    )
+    .bind(payload.job_id)
+    .bind(&target.kind)
+    .bind(target.experiment_result_id)
+    .bind(user_id)
+    .bind(payload.rating.value())
+    .bind(&tags)
+    .bind(comment)
+    .fetch_one(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    Ok(Json(feedback))
+}
+
+/// Overall and per-kind feedback with the most used tags
+async fn admin_feedback_stats(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+    Query(query): Query<StatsQuery>,
+) -> Result<Json<FeedbackStats>, StatusCode> {
+    if !(1..=365).contains(&query.days) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let since = Utc::now() - Duration::days(query.days);
+    let kind = query.kind.as_deref();
+
+    let by_kind = counts_by_kind(&state.db, since, kind)
+        .await
+        .map_err(internal_error)?;
+    let top_tags = sqlx::query_as::<_, TagCount>(
+        "SELECT tag, COUNT(*) AS count
+         FROM generation_feedback, unnest(tags) AS tag
+         WHERE created_at >= $1 AND ($2::text IS NULL OR kind = $2)
+         GROUP BY tag
+         ORDER BY count DESC, tag
+         LIMIT 20",
+    )
+    .bind(since)
+    .bind(kind)
+    .fetch_all(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    let total = by_kind.iter().map(|k| k.total).sum();
+    let up = by_kind.iter().map(|k| k.up).sum();
+    Ok(Json(FeedbackStats {
+        since,
+        total,
+        up,
+        down: by_kind.iter().map(|k| k.down).sum(),
+        positive_rate: positive_rate(up, total),
+        by_kind,
+        top_tags,
+    }))
+}
+
+// ========================================
+// Router Setup
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/aigen/feedback", post(submit_feedback))
+        .route("/api/admin/aigen/feedback/stats", get(admin_feedback_stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rating_and_rates() {
+        let rating: Rating = serde_json::from_str("\"down\"").unwrap();
+        assert_eq!(rating.value(), -1);
+        assert!(serde_json::from_str::<Rating>("\"meh\"").is_err());
+
+        let counts = with_rates(vec![FeedbackCounts {
+            group: "upscale".to_string(),
+            total: 4,
+            up: 3,
+            down: 1,
+            positive_rate: None,
+        }]);
+        assert_eq!(counts[0].positive_rate, Some(0.75));
+        assert_eq!(positive_rate(0, 0), None);
+    }
+}
@@ -0,0 +1,377 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, put},
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::error::internal_error;
+use crate::pagination::{Paginated, Pagination, SortBy, SortFields};
+use crate::retention;
+use crate::state::AppState;
+use crate::storage::StoredObject;
+
+pub const MAX_TAGS: usize = 20;
+pub const MAX_TAG_LENGTH: usize = 50;
+
+// ========================================
+// Models
+// ========================================
+
+/// Generated image (`files` row with `metadata.type = 'generated_image'`)
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct GalleryImage {
+    pub id: Uuid,
+    pub bucket_name: String,
+    pub storage_path: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub file_size: i64,
+    pub is_public: bool,
+    /// Permanent URL for files in public buckets
+    #[sqlx(skip)]
+    pub public_url: Option<String>,
+    pub prompt: Option<String>,
+    pub revised_prompt: Option<String>,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    /// Generation mode (text-to-image, variation, edit, upscale, remove-background, ...)
+    pub mode: Option<String>,
+    pub metadata: serde_json::Value,
+    pub tags: Vec<String>,
+    pub favorite: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct TagCount {
+    pub tag: String,
+    pub count: i64,
+}
+
+/// Sortable columns for the gallery
+struct GallerySort;
+
+impl SortFields for GallerySort {
+    const ALLOWED: &'static [&'static str] = &["created_at", "file_name", "file_size"];
+    const DEFAULT: &'static str = "created_at";
+    const DEFAULT_DESCENDING: bool = true;
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GalleryFilter {
+    /// Only images with this tag
+    tag: Option<String>,
+    /// Only favorites
+    #[serde(default)]
+    favorite: bool,
+    /// Case-insensitive search over the prompt and revised prompt
+    q: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SetTagsRequest {
+    tags: Vec<String>,
+}
+
+const IMAGE_COLUMNS: &str = "f.id, f.bucket_name, f.storage_path, f.file_name, f.mime_type,
+    f.file_size, f.is_public,
+    f.metadata->>'prompt' AS prompt, f.metadata->>'revised_prompt' AS revised_prompt,
+    f.metadata->>'provider' AS provider, f.metadata->>'model' AS model,
+    f.metadata->>'mode' AS mode, COALESCE(f.metadata, '{}'::jsonb) AS metadata,
+    ARRAY(SELECT t.tag FROM gallery_tags t WHERE t.file_id = f.id ORDER BY t.tag) AS tags,
+    EXISTS (SELECT 1 FROM gallery_favorites fav WHERE fav.file_id = f.id AND fav.user_id = $1)
+      AS favorite,
+    f.created_at";
+
+const GENERATED_BY_USER: &str = "f.owner_id = $1 AND f.status = 'active'
+    AND f.metadata->>'type' = 'generated_image'";
+
+// ========================================
+// Helpers
+// ========================================
+
+/// Trim, lowercase and de-duplicate tags (`None` when a tag is too long or there are too many)
+pub fn normalize_tags(tags: &[String]) -> Option<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() || normalized.contains(&tag) {
+            continue;
+        }
+        if tag.chars().count() > MAX_TAG_LENGTH {
+            return None;
+        }
+        normalized.push(tag);
+    }
+    (normalized.len() <= MAX_TAGS).then_some(normalized)
+}
+
+/// `ILIKE` pattern matching `query` literally anywhere in the text
+fn contains_pattern(query: &str) -> String {
+    let escaped = query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+fn public_url(supabase_url: Option<&str>, image: &GalleryImage) -> Option<String> {
+    let base = supabase_url?.trim_end_matches('/');
+    image.is_public.then(|| {
+        format!(
+            "{}/storage/v1/object/public/{}/{}",
+            base, image.bucket_name, image.storage_path
+        )
+    })
+}
+
+pub async fn find_image(
+    db: &PgPool,
+    user_id: Uuid,
+    id: Uuid,
+) -> Result<Option<GalleryImage>, sqlx::Error> {
+    sqlx::query_as::<_, GalleryImage>(&format!(
+        "SELECT {} FROM files f WHERE {} AND f.id = $2",
+        IMAGE_COLUMNS, GENERATED_BY_USER
+    ))
+    .bind(user_id)
+    .bind(id)
+    .fetch_optional(db)
+    .await
+}
+
+/// Add a `files` row for an image produced by a backend job so it shows up in the gallery
+pub async fn record_generated_image(
+    db: &PgPool,
+    owner: Uuid,
+    stored: &StoredObject,
+    metadata: serde_json::Value,
+) -> Result<Uuid, sqlx::Error> {
+    retention::record_asset(db, owner, stored, retention::GENERATED_IMAGE, metadata).await
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// The caller's generated images, newest first by default
+async fn list_images(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Query(filter): Query<GalleryFilter>,
+    pagination: Pagination,
+    sort: SortBy<GallerySort>,
+) -> Result<Json<Paginated<GalleryImage>>, StatusCode> {
+    let user_id = user.require_id()?;
+    let tag = filter.tag.map(|tag| tag.trim().to_lowercase());
+    let pattern = filter
+        .q
+        .as_deref()
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+        .map(contains_pattern);
+
+    let conditions = format!(
+        "{}
+         AND ($2::text IS NULL
+              OR EXISTS (SELECT 1 FROM gallery_tags t WHERE t.file_id = f.id AND t.tag = $2))
+         AND (NOT $3
+              OR EXISTS (SELECT 1 FROM gallery_favorites fav
+                         WHERE fav.file_id = f.id AND fav.user_id = $1))
+         AND ($4::text IS NULL
+              OR f.metadata->>'prompt' ILIKE $4 OR f.metadata->>'revised_prompt' ILIKE $4)",
+        GENERATED_BY_USER
+    );
+
+    let total: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM files f WHERE {}",
+        conditions
+    ))
+    .bind(user_id)
+    .bind(&tag)
+    .bind(filter.favorite)
+    .bind(&pattern)
+    .fetch_one(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    let mut images = sqlx::query_as::<_, GalleryImage>(&format!(
+        "SELECT {} FROM files f WHERE {}
+         ORDER BY {}, id
+         LIMIT $5 OFFSET $6",
+        IMAGE_COLUMNS,
+        conditions,
+        sort.order_by()
+    ))
+    .bind(user_id)
+    .bind(&tag)
+    .bind(filter.favorite)
+    .bind(&pattern)
+    .bind(pagination.limit as i64)
+    .bind(pagination.offset())
+    .fetch_all(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    let supabase_url = state.config.storage.supabase_url.as_deref();
+    for image in &mut images {
+        image.public_url = public_url(supabase_url, image);
+    }
+
+    Ok(Json(Paginated::from_offset(images, &pagination, total)))
+}
+
+async fn get_image(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<GalleryImage>, StatusCode> {
+    let mut image = find_image(&state.db, user.require_id()?, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    image.public_url = public_url(state.config.storage.supabase_url.as_deref(), &image);
+    Ok(Json(image))
+}
+
+/// Replace the tags of an image
+async fn set_tags(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<SetTagsRequest>,
+) -> Result<Json<GalleryImage>, StatusCode> {
+    let user_id = user.require_id()?;
+    let tags = normalize_tags(&payload.tags).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    find_image(&state.db, user_id, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let mut tx = state.db.begin().await.map_err(internal_error)?;
+    sqlx::query("DELETE FROM gallery_tags WHERE file_id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(internal_error)?;
+    sqlx::query("INSERT INTO gallery_tags (file_id, tag) SELECT $1, unnest($2::text[])")
+        .bind(id)
+        .bind(&tags)
+        .execute(&mut *tx)
+        .await
+        .map_err(internal_error)?;
+    tx.commit().await.map_err(internal_error)?;
+
+    get_image(user, State(state), Path(id)).await
+}
+
+async fn add_favorite(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let user_id = user.require_id()?;
+    find_image(&state.db, user_id, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    sqlx::query(
+        "INSERT INTO gallery_favorites (user_id, file_id) VALUES ($1, $2)
+         ON CONFLICT DO NOTHING",
+    )
+    .bind(user_id)
+    .bind(id)
+    .execute(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn remove_favorite(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    sqlx::query("DELETE FROM gallery_favorites WHERE user_id = $1 AND file_id = $2")
+        .bind(user.require_id()?)
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(internal_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Tags used on the caller's images with their counts
+async fn list_tags(
+    user: AuthUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TagCount>>, StatusCode> {
+    sqlx::query_as::<_, TagCount>(&format!(
+        "SELECT t.tag, COUNT(*) AS count
+         FROM gallery_tags t
+         JOIN files f ON f.id = t.file_id
+         WHERE {}
+         GROUP BY t.tag
+         ORDER BY count DESC, t.tag",
+        GENERATED_BY_USER
+    ))
+    .bind(user.require_id()?)
+    .fetch_all(&state.db)
+    .await
+    .map(Json)
+    .map_err(internal_error)
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/gallery/images", get(list_images))
+        .route("/api/gallery/images/:id", get(get_image))
+        .route("/api/gallery/images/:id/tags", put(set_tags))
+        .route(
+            "/api/gallery/images/:id/favorite",
+            put(add_favorite).delete(remove_favorite),
+        )
+        .route("/api/gallery/tags", get(list_tags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_tags() {
+        let tags = vec![
+            " Landscape ".to_string(),
+            "landscape".to_string(),
+            "".to_string(),
+            "night".to_string(),
+        ];
+        assert_eq!(
+            normalize_tags(&tags),
+            Some(vec!["landscape".to_string(), "night".to_string()])
+        );
+
+        assert_eq!(normalize_tags(&["x".repeat(MAX_TAG_LENGTH + 1)]), None);
+        let many: Vec<String> = (0..=MAX_TAGS).map(|i| format!("tag{}", i)).collect();
+        assert_eq!(normalize_tags(&many), None);
+    }
+
+    #[test]
+    fn test_contains_pattern_escapes_wildcards() {
+        assert_eq!(contains_pattern("cat"), "%cat%");
+        assert_eq!(contains_pattern("100%_done"), "%100\\%\\_done%");
+    }
+}
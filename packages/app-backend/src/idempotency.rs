@@ -0,0 +1,316 @@
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    extract::{Request, State},
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+use crate::state::AppState;
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+const REPLAYED_HEADER: &str = "idempotent-replayed";
+const MAX_KEY_LENGTH: usize = 255;
+
+// ========================================
+// Store (in-memory)
+// ========================================
+
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub status: StatusCode,
+    pub content_type: Option<HeaderValue>,
+    pub body: Bytes,
+}
+
+#[derive(Debug, Clone)]
+enum EntryState {
+    InFlight,
+    Done(CachedResponse),
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    fingerprint: u64,
+    state: EntryState,
+    expires_at: Instant,
+}
+
+/// Outcome of claiming an idempotency key
+#[derive(Debug)]
+pub enum Claim {
+    /// First use: run the handler and `complete` (or `release`) the key
+    Started,
+    /// A response was already stored for this key
+    Replay(CachedResponse),
+    /// The original request is still being processed
+    InFlight,
+    /// The key was reused with a different request
+    Mismatch,
+}
+
+/// Stored responses keyed by `Idempotency-Key`, expiring after a TTL
+#[derive(Clone)]
+pub struct IdempotencyStore {
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+    ttl: Duration,
+}
+
+impl IdempotencyStore {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            entries: Arc::default(),
+            ttl,
+        }
+    }
+
+    pub async fn claim(&self, key: &str, fingerprint: u64) -> Claim {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().await;
+        entries.retain(|_, entry| entry.expires_at > now);
+
+        match entries.get(key) {
+            Some(entry) if entry.fingerprint != fingerprint => Claim::Mismatch,
+            Some(Entry {
+                state: EntryState::Done(response),
+                ..
+            }) => Claim::Replay(response.clone()),
+            Some(_) => Claim::InFlight,
+            None => {
+                entries.insert(
+                    key.to_string(),
+                    Entry {
+                        fingerprint,
+                        state: EntryState::InFlight,
+                        expires_at: now + self.ttl,
+                    },
+                );
+                Claim::Started
+            }
+        }
+    }
+
+    pub async fn complete(&self, key: &str, response: CachedResponse) {
+        if let Some(entry) = self.entries.lock().await.get_mut(key) {
+            entry.state = EntryState::Done(response);
+        }
+    }
+
+    /// Forget a key so the request can be retried (used for 5xx / uncacheable responses)
+    pub async fn release(&self, key: &str) {
+        self.entries.lock().await.remove(key);
+    }
+}
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Keys are scoped per caller so one client cannot replay another's response
+fn scoped_key(headers: &HeaderMap, key: &str) -> String {
+    let caller = headers
+        .get(header::AUTHORIZATION)
+        .map(|v| hash_of(v.as_bytes()))
+        .unwrap_or_default();
+    format!("{:x}:{}", caller, key)
+}
+
+// ========================================
+// Middleware
+// ========================================
+
+/// Replay stored responses for repeated `Idempotency-Key` requests.
+///
+/// Applies to POST/PUT/PATCH requests carrying the header. Request bodies
+/// larger than the body limit are rejected with 413; responses that are 5xx,
+/// streamed, or larger than the limit are not stored.
+pub async fn idempotency(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    if !matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::PATCH
+    ) {
+        return next.run(request).await;
+    }
+    let Some(key) = request
+        .headers()
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+    else {
+        return next.run(request).await;
+    };
+    if key.is_empty() || key.len() > MAX_KEY_LENGTH {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let max_bytes = state.config.idempotency.max_body_bytes;
+    let scoped = scoped_key(request.headers(), &key);
+
+    let (request, fingerprint) = match fingerprint(request, max_bytes).await {
+        Ok(fingerprinted) => fingerprinted,
+        Err(status) => return status.into_response(),
+    };
+
+    let store = &state.idempotency;
+    match store.claim(&scoped, fingerprint).await {
+        Claim::Started => {}
+        Claim::Replay(cached) => return replay(cached),
+        Claim::InFlight => return StatusCode::CONFLICT.into_response(),
+        Claim::Mismatch => return StatusCode::UNPROCESSABLE_ENTITY.into_response(),
+    }
+
+    let response = next.run(request).await;
+    let cacheable = !response.status().is_server_error()
+        && response
+            .body()
+            .size_hint()
+            .exact()
+            .is_some_and(|len| len as usize <= max_bytes);
+    if !cacheable {
+        store.release(&scoped).await;
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, max_bytes).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            store.release(&scoped).await;
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    store
+        .complete(
+            &scoped,
+            CachedResponse {
+                status: parts.status,
+                content_type: parts.headers.get(header::CONTENT_TYPE).cloned(),
+                body: bytes.clone(),
+            },
+        )
+        .await;
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Fingerprint method + path + body so a reused key with a different request
+/// is rejected. The body is buffered whatever its size hint says (chunked and
+/// multipart uploads have none); one that can't be read within the limit is 413.
+async fn fingerprint(request: Request, max_bytes: usize) -> Result<(Request, u64), StatusCode> {
+    let (parts, body) = request.into_parts();
+    let bytes = axum::body::to_bytes(body, max_bytes)
+        .await
+        .map_err(|_| StatusCode::PAYLOAD_TOO_LARGE)?;
+    let fingerprint = hash_of(&(parts.method.as_str(), parts.uri.path(), &bytes[..]));
+    Ok((Request::from_parts(parts, Body::from(bytes)), fingerprint))
+}
+
+fn replay(cached: CachedResponse) -> Response {
+    let mut response = (cached.status, cached.body).into_response();
+    if let Some(content_type) = cached.content_type {
+        response
+            .headers_mut()
+            .insert(header::CONTENT_TYPE, content_type);
+    }
+    response
+        .headers_mut()
+        .insert(REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached(body: &'static str) -> CachedResponse {
+        CachedResponse {
+            status: StatusCode::ACCEPTED,
+            content_type: None,
+            body: Bytes::from_static(body.as_bytes()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_claim_replays_completed_response() {
+        let store = IdempotencyStore::new(Duration::from_secs(60));
+        assert!(matches!(store.claim("k", 1).await, Claim::Started));
+        assert!(matches!(store.claim("k", 1).await, Claim::InFlight));
+
+        store.complete("k", cached("{}")).await;
+        match store.claim("k", 1).await {
+            Claim::Replay(response) => assert_eq!(response.status, StatusCode::ACCEPTED),
+            other => panic!("expected replay, got {:?}", other),
+        }
+        assert!(matches!(store.claim("k", 2).await, Claim::Mismatch));
+    }
+
+    #[tokio::test]
+    async fn test_release_and_expiry() {
+        let store = IdempotencyStore::new(Duration::from_secs(60));
+        store.claim("k", 1).await;
+        store.release("k").await;
+        assert!(matches!(store.claim("k", 1).await, Claim::Started));
+
+        let expiring = IdempotencyStore::new(Duration::ZERO);
+        expiring.claim("k", 1).await;
+        expiring.complete("k", cached("{}")).await;
+        assert!(matches!(expiring.claim("k", 1).await, Claim::Started));
+    }
+
+    /// Chunked body (no size hint upper bound)
+    fn chunked(chunks: &[&'static str]) -> Request {
+        let stream = futures::stream::iter(
+            chunks
+                .iter()
+                .map(|chunk| Ok::<_, std::io::Error>(Bytes::from_static(chunk.as_bytes())))
+                .collect::<Vec<_>>(),
+        );
+        axum::http::Request::post("/api/aigen/upscale")
+            .header(IDEMPOTENCY_KEY_HEADER, "k")
+            .body(Body::from_stream(stream))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_chunked_bodies_are_fingerprinted() {
+        let first = chunked(&["{\"image_url\": ", "\"a.png\"}"]);
+        let second = chunked(&["{\"image_url\": ", "\"b.png\"}"]);
+        assert_eq!(first.body().size_hint().upper(), None);
+
+        let (request, first) = fingerprint(first, 1024).await.unwrap();
+        let body = axum::body::to_bytes(request.into_body(), 1024)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"{\"image_url\": \"a.png\"}");
+        let (_, second) = fingerprint(second, 1024).await.unwrap();
+        assert_ne!(first, second);
+
+        let store = IdempotencyStore::new(Duration::from_secs(60));
+        assert!(matches!(store.claim("k", first).await, Claim::Started));
+        assert!(matches!(store.claim("k", second).await, Claim::Mismatch));
+
+        assert!(matches!(
+            fingerprint(chunked(&["0123456789", "0123456789"]), 16).await,
+            Err(StatusCode::PAYLOAD_TOO_LARGE)
+        ));
+    }
+
+    #[test]
+    fn test_keys_are_scoped_by_caller() {
+        let mut alice = HeaderMap::new();
+        alice.insert(header::AUTHORIZATION, "Bearer a".parse().unwrap());
+        let mut bob = HeaderMap::new();
+        bob.insert(header::AUTHORIZATION, "Bearer b".parse().unwrap());
+
+        assert_ne!(scoped_key(&alice, "k"), scoped_key(&bob, "k"));
+    }
+}
@@ -0,0 +1,938 @@
+use async_trait::async_trait;
+use axum::{body::Bytes, extract::State, http::StatusCode, routing::post, Json, Router};
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use uuid::Uuid;
+
+use crate::auth::{AuthUser, OptionalUser};
+use crate::config::ImageConfig;
+use crate::error::internal_error;
+use crate::gallery::{find_image, record_generated_image};
+use crate::jobs::{self, spawn_resumable_job, Job, JobHandle, JobRunner, JobStore};
+use crate::llm::{ensure_success, LlmError};
+use crate::state::AppState;
+use crate::storage::{object_path, StorageClient, StoredObject};
+
+/// Largest edge (in pixels) an upscaled image may have
+const MAX_OUTPUT_EDGE: u32 = 8192;
+
+/// Text-to-image size when the request has none
+pub const DEFAULT_IMAGE_SIZE: u32 = 1024;
+
+/// Most images one text-to-image or variations request may produce
+pub const MAX_NUM_IMAGES: u32 = 4;
+
+/// Largest seed the providers accept (Stability AI: 0 ..= 4294967294)
+const MAX_SEED: u32 = u32::MAX - 1;
+
+const DEFAULT_VARIATION_STRENGTH: f32 = 0.35;
+
+/// Aspect ratios (width, height) the Stability AI generate endpoint accepts
+const ASPECT_RATIOS: [(u32, u32); 9] = [
+    (21, 9),
+    (16, 9),
+    (3, 2),
+    (5, 4),
+    (1, 1),
+    (4, 5),
+    (2, 3),
+    (9, 16),
+    (9, 21),
+];
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Clone)]
+pub struct SourceImage {
+    pub data: Bytes,
+    pub content_type: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    pub data: Bytes,
+    pub content_type: String,
+    pub extension: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpscaleRequest {
+    /// Publicly reachable URL of the source image (e.g. a signed Storage URL)
+    image_url: String,
+    /// Upscale factor: 2 (default) or 4
+    scale: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoveBackgroundRequest {
+    image_url: String,
+}
+
+/// One output of a multi-image request
+#[derive(Debug, Clone, Serialize)]
+pub struct GeneratedImage {
+    pub url: String,
+    /// Pass back as `seed` (with `num_images: 1`) to reproduce the image
+    pub seed: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct VariationsRequest {
+    /// Gallery image (`files.id`) to vary
+    file_id: Uuid,
+    /// Number of variations (1-4, default 1)
+    num_images: Option<u32>,
+    /// Prompt guiding the variations (default: the prompt of the source image)
+    prompt: Option<String>,
+    /// How far the variations may drift from the source (0.0-1.0)
+    strength: Option<f32>,
+    /// Seed of the first variation; the others use the following seeds
+    seed: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VariationOptions {
+    pub prompt: String,
+    pub strength: f32,
+    pub seed: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct InpaintOptions {
+    pub prompt: String,
+    pub seed: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenerateOptions {
+    pub prompt: String,
+    /// Requested size (providers with fixed sizes use the closest aspect ratio)
+    pub width: u32,
+    pub height: u32,
+    pub seed: u32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(tag = "operation", rename_all = "kebab-case")]
+enum Operation {
+    Upscale { scale: u32 },
+    RemoveBackground,
+}
+
+impl Operation {
+    fn kind(&self) -> &'static str {
+        match self {
+            Operation::Upscale { .. } => "upscale",
+            Operation::RemoveBackground => "remove-background",
+        }
+    }
+}
+
+// ========================================
+// Provider Abstraction
+// ========================================
+
+#[async_trait]
+pub trait ImageProvider: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn upscale(&self, image: SourceImage, scale: u32) -> Result<ProcessedImage, LlmError>;
+
+    async fn remove_background(&self, image: SourceImage) -> Result<ProcessedImage, LlmError>;
+
+    /// Image from a text prompt
+    async fn generate(&self, options: &GenerateOptions) -> Result<ProcessedImage, LlmError>;
+
+    async fn variation(
+        &self,
+        image: SourceImage,
+        options: &VariationOptions,
+    ) -> Result<ProcessedImage, LlmError>;
+
+    /// Repaint the white area of `mask` (a binarized PNG the size of `image`)
+    async fn inpaint(
+        &self,
+        image: SourceImage,
+        mask: Bytes,
+        options: &InpaintOptions,
+    ) -> Result<ProcessedImage, LlmError>;
+}
+
+/// Build the image provider selected by `IMAGE_PROVIDER`.
+///
+/// Falls back to the local provider (upscale only) when Stability AI is
+/// selected without an API key.
+pub fn image_provider_from_config(config: &ImageConfig) -> Box<dyn ImageProvider> {
+    match (config.provider.as_str(), &config.stability_api_key) {
+        ("stability", Some(api_key)) => Box::new(StabilityProvider {
+            client: reqwest::Client::new(),
+            api_key: api_key.clone(),
+            base_url: config.stability_base_url.trim_end_matches('/').to_string(),
+        }),
+        ("stability", None) => {
+            tracing::warn!("STABILITY_API_KEY is not set, using local image provider");
+            Box::new(LocalImageProvider)
+        }
+        _ => Box::new(LocalImageProvider),
+    }
+}
+
+/// Seeds for `num_images` outputs (default 1): consecutive from `seed`, or from
+/// a random one. `None` when `num_images` is 0 or above `MAX_NUM_IMAGES`.
+pub fn seeds(seed: Option<u32>, num_images: Option<u32>) -> Option<Vec<u32>> {
+    let count = num_images.unwrap_or(1);
+    if !(1..=MAX_NUM_IMAGES).contains(&count) {
+        return None;
+    }
+    let first = seed.unwrap_or_else(random_seed);
+    let modulus = MAX_SEED as u64 + 1;
+    Some(
+        (0..count as u64)
+            .map(|i| ((first as u64 + i) % modulus) as u32)
+            .collect(),
+    )
+}
+
+pub fn random_seed() -> u32 {
+    (Uuid::new_v4().as_u128() % MAX_SEED as u128) as u32
+}
+
+/// Supported aspect ratio (`"16:9"`) closest to `width` x `height`
+fn aspect_ratio(width: u32, height: u32) -> String {
+    let target = (width.max(1) as f64 / height.max(1) as f64).ln();
+    let distance = |&(w, h): &(u32, u32)| ((w as f64 / h as f64).ln() - target).abs();
+    let (w, h) = ASPECT_RATIOS
+        .into_iter()
+        .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+        .unwrap_or((1, 1));
+    format!("{}:{}", w, h)
+}
+
+/// Resize an image by `scale` (Lanczos3) and encode it as PNG
+pub fn resize_png(data: &[u8], scale: f32) -> Result<Vec<u8>, String> {
+    let image = image::load_from_memory(data).map_err(|e| e.to_string())?;
+    let width = (image.width() as f32 * scale).round() as u32;
+    let height = (image.height() as f32 * scale).round() as u32;
+    if width.max(height) > MAX_OUTPUT_EDGE {
+        return Err(format!(
+            "Output size {}x{} exceeds the {}px limit",
+            width, height, MAX_OUTPUT_EDGE
+        ));
+    }
+
+    let resized = image.resize_exact(width, height, image::imageops::FilterType::Lanczos3);
+    let mut output = Cursor::new(Vec::new());
+    resized
+        .write_to(&mut output, image::ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok(output.into_inner())
+}
+
+async fn resize_png_blocking(data: Bytes, scale: f32) -> Result<ProcessedImage, LlmError> {
+    let png = tokio::task::spawn_blocking(move || resize_png(&data, scale))
+        .await
+        .map_err(|e| LlmError::Provider(e.to_string()))?
+        .map_err(LlmError::Provider)?;
+
+    Ok(ProcessedImage {
+        data: Bytes::from(png),
+        content_type: "image/png".to_string(),
+        extension: "png",
+    })
+}
+
+// ========================================
+// Local Provider
+// ========================================
+
+/// Lanczos resampling on the server; no background removal
+pub struct LocalImageProvider;
+
+#[async_trait]
+impl ImageProvider for LocalImageProvider {
+    fn name(&self) -> &str {
+        "local"
+    }
+
+    async fn upscale(&self, image: SourceImage, scale: u32) -> Result<ProcessedImage, LlmError> {
+        resize_png_blocking(image.data, scale as f32).await
+    }
+
+    async fn remove_background(&self, _image: SourceImage) -> Result<ProcessedImage, LlmError> {
+        Err(LlmError::Provider(
+            "Background removal requires IMAGE_PROVIDER=stability".to_string(),
+        ))
+    }
+
+    async fn generate(&self, _options: &GenerateOptions) -> Result<ProcessedImage, LlmError> {
+        Err(LlmError::Provider(
+            "Text-to-image requires IMAGE_PROVIDER=stability".to_string(),
+        ))
+    }
+
+    async fn variation(
+        &self,
+        _image: SourceImage,
+        _options: &VariationOptions,
+    ) -> Result<ProcessedImage, LlmError> {
+        Err(LlmError::Provider(
+            "Variations require IMAGE_PROVIDER=stability".to_string(),
+        ))
+    }
+
+    async fn inpaint(
+        &self,
+        _image: SourceImage,
+        _mask: Bytes,
+        _options: &InpaintOptions,
+    ) -> Result<ProcessedImage, LlmError> {
+        Err(LlmError::Provider(
+            "Inpainting requires IMAGE_PROVIDER=stability".to_string(),
+        ))
+    }
+}
+
+// ========================================
+// Stability AI Provider
+// ========================================
+
+pub struct StabilityProvider {
+    client: reqwest::Client,
+    api_key: String,
+    base_url: String,
+}
+
+impl StabilityProvider {
+    async fn edit(&self, path: &str, image: SourceImage) -> Result<Bytes, LlmError> {
+        self.post_image(path, vec![("image", image)], Vec::new())
+            .await
+    }
+
+    /// POST `images` and `fields` as multipart form data and return the PNG result
+    async fn post_image(
+        &self,
+        path: &str,
+        images: Vec<(&'static str, SourceImage)>,
+        fields: Vec<(&'static str, String)>,
+    ) -> Result<Bytes, LlmError> {
+        let mut form = reqwest::multipart::Form::new().text("output_format", "png");
+        for (name, image) in images {
+            let part = reqwest::multipart::Part::bytes(image.data.to_vec())
+                .file_name(name)
+                .mime_str(&image.content_type)?;
+            form = form.part(name, part);
+        }
+        let form = fields
+            .into_iter()
+            .fold(form, |form, (name, value)| form.text(name, value));
+
+        let response = self
+            .client
+            .post(format!("{}{}", self.base_url, path))
+            .bearer_auth(&self.api_key)
+            .header(reqwest::header::ACCEPT, "image/*")
+            .multipart(form)
+            .send()
+            .await?;
+
+        Ok(ensure_success(response).await?.bytes().await?)
+    }
+}
+
+#[async_trait]
+impl ImageProvider for StabilityProvider {
+    fn name(&self) -> &str {
+        "stability"
+    }
+
+    async fn upscale(&self, image: SourceImage, scale: u32) -> Result<ProcessedImage, LlmError> {
+        // The fast upscaler is fixed at 4x; smaller factors are resampled down locally
+        let upscaled = self
+            .edit("/v2beta/stable-image/upscale/fast", image)
+            .await?;
+        resize_png_blocking(upscaled, scale as f32 / 4.0).await
+    }
+
+    async fn remove_background(&self, image: SourceImage) -> Result<ProcessedImage, LlmError> {
+        let data = self
+            .edit("/v2beta/stable-image/edit/remove-background", image)
+            .await?;
+
+        Ok(ProcessedImage {
+            data,
+            content_type: "image/png".to_string(),
+            extension: "png",
+        })
+    }
+
+    async fn generate(&self, options: &GenerateOptions) -> Result<ProcessedImage, LlmError> {
+        let fields = vec![
+            ("prompt", options.prompt.clone()),
+            ("aspect_ratio", aspect_ratio(options.width, options.height)),
+            ("seed", options.seed.to_string()),
+        ];
+        let data = self
+            .post_image("/v2beta/stable-image/generate/sd3", Vec::new(), fields)
+            .await?;
+
+        Ok(ProcessedImage {
+            data,
+            content_type: "image/png".to_string(),
+            extension: "png",
+        })
+    }
+
+    async fn variation(
+        &self,
+        image: SourceImage,
+        options: &VariationOptions,
+    ) -> Result<ProcessedImage, LlmError> {
+        let fields = vec![
+            ("mode", "image-to-image".to_string()),
+            ("prompt", options.prompt.clone()),
+            ("strength", options.strength.to_string()),
+            ("seed", options.seed.to_string()),
+        ];
+        let data = self
+            .post_image(
+                "/v2beta/stable-image/generate/sd3",
+                vec![("image", image)],
+                fields,
+            )
+            .await?;
+
+        Ok(ProcessedImage {
+            data,
+            content_type: "image/png".to_string(),
+            extension: "png",
+        })
+    }
+    async fn inpaint(
+        &self,
+        image: SourceImage,
+        mask: Bytes,
+        options: &InpaintOptions,
+    ) -> Result<ProcessedImage, LlmError> {
+        let mask = SourceImage {
+            data: mask,
+            content_type: "image/png".to_string(),
+        };
+        let fields = vec![
+            ("prompt", options.prompt.clone()),
+            ("seed", options.seed.to_string()),
+        ];
+        let data = self
+            .post_image(
+                "/v2beta/stable-image/edit/inpaint",
+                vec![("image", image), ("mask", mask)],
+                fields,
+            )
+            .await?;
+
+        Ok(ProcessedImage {
+            data,
+            content_type: "image/png".to_string(),
+            extension: "png",
+        })
+    }
+}
+
+// ========================================
+// Job
+// ========================================
+
+pub async fn fetch_source(url: &str, max_bytes: usize) -> Result<SourceImage, String> {
+    if !(url.starts_with("https://") || url.starts_with("http://")) {
+        return Err("image_url must be an http(s) URL".to_string());
+    }
+
+    let response = reqwest::get(url)
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Failed to download source image: {}", e))?;
+
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    if !content_type.starts_with("image/") {
+        return Err(format!("Source is not an image ({})", content_type));
+    }
+
+    let data = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to download source image: {}", e))?;
+    if data.len() > max_bytes {
+        return Err(format!("Source image exceeds {} bytes", max_bytes));
+    }
+
+    Ok(SourceImage { data, content_type })
+}
+
+async fn process_image(
+    state: AppState,
+    storage: StorageClient,
+    image_url: String,
+    operation: Operation,
+    owner: Option<Uuid>,
+    handle: JobHandle,
+) -> Result<serde_json::Value, String> {
+    let provider = state.images.clone();
+    let source = fetch_source(&image_url, state.config.image.max_source_bytes).await?;
+    handle.set_progress(20).await;
+
+    let processed = match operation {
+        Operation::Upscale { scale } => provider.upscale(source, scale).await,
+        Operation::RemoveBackground => provider.remove_background(source).await,
+    }
+    .map_err(|e| e.to_string())?;
+    handle.set_progress(80).await;
+
+    let metadata = serde_json::json!({
+        "mode": operation.kind(),
+        "provider": provider.name(),
+        "source_image": image_url,
+        "job_id": handle.id,
+    });
+    let (stored, file_id) = store_output(
+        &state,
+        &storage,
+        operation.kind(),
+        processed,
+        owner,
+        metadata,
+    )
+    .await?;
+
+    Ok(serde_json::json!({
+        "provider": provider.name(),
+        "storage": stored,
+        "file_id": file_id,
+    }))
+}
+
+/// Upload a job output; outputs of signed-in users are also listed in their gallery
+pub async fn store_output(
+    state: &AppState,
+    storage: &StorageClient,
+    kind: &str,
+    processed: ProcessedImage,
+    owner: Option<Uuid>,
+    metadata: serde_json::Value,
+) -> Result<(StoredObject, Option<Uuid>), String> {
+    let stored = storage
+        .upload(
+            &object_path(kind, processed.extension),
+            processed.data,
+            &processed.content_type,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let file_id = match owner {
+        Some(owner) => record_generated_image(&state.db, owner, &stored, metadata)
+            .await
+            .map_err(|e| tracing::warn!("Failed to record {} output: {}", kind, e))
+            .ok(),
+        None => None,
+    };
+    Ok((stored, file_id))
+}
+
+/// Input of an upscale / remove-background job (stored to resume it after a restart)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ImageJob {
+    image_url: String,
+    #[serde(flatten)]
+    operation: Operation,
+}
+
+/// Source image and settings of a variations job (re-used when the job is retried)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct VariationJob {
+    owner: Uuid,
+    file_id: Uuid,
+    bucket: String,
+    path: String,
+    prompt: String,
+    strength: f32,
+    seeds: Vec<u32>,
+}
+
+/// Prompt and settings of a text-to-image job (re-used when the job is retried)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextToImageJob {
+    pub prompt: String,
+    pub model: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub seeds: Vec<u32>,
+}
+
+async fn process_text_to_image(
+    state: AppState,
+    storage: StorageClient,
+    job: TextToImageJob,
+    owner: Option<Uuid>,
+    handle: JobHandle,
+) -> Result<serde_json::Value, String> {
+    let provider = state.images.clone();
+
+    let mut images = Vec::new();
+    for (index, &seed) in job.seeds.iter().enumerate() {
+        let options = GenerateOptions {
+            prompt: job.prompt.clone(),
+            width: job.width,
+            height: job.height,
+            seed,
+        };
+        let processed = provider
+            .generate(&options)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let metadata = serde_json::json!({
+            "mode": "text-to-image",
+            "provider": provider.name(),
+            "model": job.model,
+            "prompt": job.prompt,
+            "seed": seed,
+            "job_id": handle.id,
+        });
+        let (stored, file_id) = store_output(
+            &state,
+            &storage,
+            "text-to-image",
+            processed,
+            owner,
+            metadata,
+        )
+        .await?;
+        images.push(serde_json::json!({
+            "seed": seed,
+            "storage": stored,
+            "file_id": file_id,
+        }));
+
+        let done = (index + 1) as u32 * 100 / job.seeds.len() as u32;
+        handle.set_progress(done as u8).await;
+    }
+
+    Ok(serde_json::json!({
+        "provider": provider.name(),
+        "images": images,
+    }))
+}
+
+async fn process_variations(
+    state: AppState,
+    storage: StorageClient,
+    job: VariationJob,
+    handle: JobHandle,
+) -> Result<serde_json::Value, String> {
+    let provider = state.images.clone();
+    let source_url = storage
+        .signed_url_in(&job.bucket, &job.path)
+        .await
+        .map_err(|e| e.to_string())?;
+    let source = fetch_source(&source_url, state.config.image.max_source_bytes).await?;
+    handle.set_progress(10).await;
+
+    let mut images = Vec::new();
+    for (index, &seed) in job.seeds.iter().enumerate() {
+        let options = VariationOptions {
+            prompt: job.prompt.clone(),
+            strength: job.strength,
+            seed,
+        };
+        let processed = provider
+            .variation(source.clone(), &options)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let metadata = serde_json::json!({
+            "mode": "variation",
+            "provider": provider.name(),
+            "prompt": job.prompt,
+            "seed": seed,
+            "strength": job.strength,
+            "source_file_id": job.file_id,
+            "job_id": handle.id,
+        });
+        let (stored, file_id) = store_output(
+            &state,
+            &storage,
+            "variation",
+            processed,
+            Some(job.owner),
+            metadata,
+        )
+        .await?;
+        images.push(serde_json::json!({
+            "seed": seed,
+            "storage": stored,
+            "file_id": file_id,
+        }));
+
+        let done = (index + 1) as u32 * 90 / job.seeds.len() as u32;
+        handle.set_progress(10 + done as u8).await;
+    }
+
+    Ok(serde_json::json!({
+        "provider": provider.name(),
+        "source_file_id": job.file_id,
+        "images": images,
+    }))
+}
+
+fn image_runner(
+    state: &AppState,
+    storage: StorageClient,
+    job: ImageJob,
+    owner: Option<Uuid>,
+) -> JobRunner {
+    let state = state.clone();
+    jobs::runner(move |handle| {
+        process_image(
+            state.clone(),
+            storage.clone(),
+            job.image_url.clone(),
+            job.operation,
+            owner,
+            handle,
+        )
+    })
+}
+
+fn text_to_image_runner(
+    state: &AppState,
+    storage: StorageClient,
+    job: TextToImageJob,
+    owner: Option<Uuid>,
+) -> JobRunner {
+    let state = state.clone();
+    jobs::runner(move |handle| {
+        process_text_to_image(state.clone(), storage.clone(), job.clone(), owner, handle)
+    })
+}
+
+fn variation_runner(state: &AppState, storage: StorageClient, job: VariationJob) -> JobRunner {
+    let state = state.clone();
+    jobs::runner(move |handle| {
+        process_variations(state.clone(), storage.clone(), job.clone(), handle)
+    })
+}
+
+async fn enqueue(
+    state: &AppState,
+    owner: Option<Uuid>,
+    image_url: String,
+    operation: Operation,
+) -> Result<(StatusCode, Json<Job>), StatusCode> {
+    if image_url.trim().is_empty() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let storage = state
+        .storage
+        .clone()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+
+    let job = ImageJob {
+        image_url,
+        operation,
+    };
+    let input = serde_json::to_value(&job).map_err(internal_error)?;
+    let runner = image_runner(state, storage, job, owner);
+    let job = spawn_resumable_job(&state.jobs, operation.kind(), owner, input, runner).await;
+
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+/// Generate each seed of `job` in the background, storing the images like
+/// the other job outputs (`None` when storage is not configured)
+pub async fn enqueue_text_to_image(
+    state: &AppState,
+    owner: Option<Uuid>,
+    job: TextToImageJob,
+) -> Result<Option<Job>, StatusCode> {
+    let Some(storage) = state.storage.clone() else {
+        return Ok(None);
+    };
+    let input = serde_json::to_value(&job).map_err(internal_error)?;
+    let runner = text_to_image_runner(state, storage, job, owner);
+    Ok(Some(
+        spawn_resumable_job(&state.jobs, "text-to-image", owner, input, runner).await,
+    ))
+}
+
+/// Rebuild image jobs interrupted by a restart from their stored input
+pub async fn register_resumers(store: &JobStore) {
+    for operation in [Operation::Upscale { scale: 2 }, Operation::RemoveBackground] {
+        store
+            .register_resumer(operation.kind(), |state, owner, input| {
+                let job: ImageJob = serde_json::from_value(input)
+                    .map_err(|e| format!("invalid job input: {}", e))?;
+                Ok(image_runner(state, job_storage(state)?, job, owner))
+            })
+            .await;
+    }
+    store
+        .register_resumer("text-to-image", |state, owner, input| {
+            let job: TextToImageJob =
+                serde_json::from_value(input).map_err(|e| format!("invalid job input: {}", e))?;
+            Ok(text_to_image_runner(state, job_storage(state)?, job, owner))
+        })
+        .await;
+    store
+        .register_resumer("variation", |state, _owner, input| {
+            let job: VariationJob =
+                serde_json::from_value(input).map_err(|e| format!("invalid job input: {}", e))?;
+            Ok(variation_runner(state, job_storage(state)?, job))
+        })
+        .await;
+}
+
+/// Storage for a resumed job (configuration may have changed since it started)
+pub fn job_storage(state: &AppState) -> Result<StorageClient, String> {
+    state
+        .storage
+        .clone()
+        .ok_or_else(|| "storage is not configured".to_string())
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+async fn upscale(
+    user: OptionalUser,
+    State(state): State<AppState>,
+    Json(payload): Json<UpscaleRequest>,
+) -> Result<(StatusCode, Json<Job>), StatusCode> {
+    let scale = payload.scale.unwrap_or(2);
+    if scale != 2 && scale != 4 {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    enqueue(
+        &state,
+        user.id(),
+        payload.image_url,
+        Operation::Upscale { scale },
+    )
+    .await
+}
+
+async fn remove_background(
+    user: OptionalUser,
+    State(state): State<AppState>,
+    Json(payload): Json<RemoveBackgroundRequest>,
+) -> Result<(StatusCode, Json<Job>), StatusCode> {
+    enqueue(
+        &state,
+        user.id(),
+        payload.image_url,
+        Operation::RemoveBackground,
+    )
+    .await
+}
+
+/// N variations of a gallery image, stored and added to the gallery like other outputs
+async fn variations(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<VariationsRequest>,
+) -> Result<(StatusCode, Json<Job>), StatusCode> {
+    let owner = user.require_id()?;
+    let seeds = seeds(payload.seed, payload.num_images).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    let strength = payload.strength.unwrap_or(DEFAULT_VARIATION_STRENGTH);
+    if !(0.0..=1.0).contains(&strength) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let storage = state
+        .storage
+        .clone()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    let source = find_image(&state.db, owner, payload.file_id)
+        .await
+        .map_err(internal_error)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let job = VariationJob {
+        owner,
+        file_id: source.id,
+        bucket: source.bucket_name,
+        path: source.storage_path,
+        prompt: payload
+            .prompt
+            .filter(|prompt| !prompt.trim().is_empty())
+            .or(source.prompt)
+            .unwrap_or_else(|| "A variation of this image".to_string()),
+        strength,
+        seeds,
+    };
+    let input = serde_json::to_value(&job).map_err(internal_error)?;
+    let runner = variation_runner(&state, storage, job);
+    let job = spawn_resumable_job(&state.jobs, "variation", Some(owner), input, runner).await;
+
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/aigen/upscale", post(upscale))
+        .route("/api/aigen/remove-background", post(remove_background))
+        .route("/api/aigen/variations", post(variations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_png(width: u32, height: u32) -> Vec<u8> {
+        let image = image::RgbaImage::from_pixel(width, height, image::Rgba([255, 0, 0, 255]));
+        let mut output = Cursor::new(Vec::new());
+        image
+            .write_to(&mut output, image::ImageFormat::Png)
+            .unwrap();
+        output.into_inner()
+    }
+
+    #[test]
+    fn test_resize_png_scales_dimensions() {
+        let resized = resize_png(&sample_png(8, 4), 2.0).unwrap();
+        let image = image::load_from_memory(&resized).unwrap();
+        assert_eq!((image.width(), image.height()), (16, 8));
+    }
+
+    #[test]
+    fn test_resize_png_rejects_oversized_output() {
+        assert!(resize_png(&sample_png(4096, 1), 4.0).is_err());
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        assert_eq!(aspect_ratio(1024, 1024), "1:1");
+        assert_eq!(aspect_ratio(1920, 1080), "16:9");
+        assert_eq!(aspect_ratio(512, 768), "2:3");
+        assert_eq!(aspect_ratio(0, 0), "1:1");
+    }
+
+    #[test]
+    fn test_seeds() {
+        assert_eq!(seeds(Some(7), Some(3)), Some(vec![7, 8, 9]));
+        assert_eq!(seeds(Some(MAX_SEED), Some(2)), Some(vec![MAX_SEED, 0]));
+        assert_eq!(seeds(None, None).map(|s| s.len()), Some(1));
+        assert_eq!(seeds(None, Some(0)), None);
+        assert_eq!(seeds(None, Some(MAX_NUM_IMAGES + 1)), None);
+    }
+}
@@ -0,0 +1,484 @@
+use axum::{
+    body::Bytes,
+    extract::{DefaultBodyLimit, FromRequest, Multipart, Request, State},
+    http::{header, StatusCode},
+    routing::post,
+    Json, Router,
+};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::Cursor;
+use uuid::Uuid;
+
+use crate::auth::OptionalUser;
+use crate::images::{
+    fetch_source, job_storage, random_seed, store_output, InpaintOptions, SourceImage,
+};
+use crate::jobs::{self, spawn_resumable_job, Job, JobHandle, JobRunner, JobStore};
+use crate::state::AppState;
+use crate::storage::StorageClient;
+
+/// Request body limit: source and mask, base64-encoded in JSON
+const MAX_INPAINT_BODY_BYTES: usize = 50 * 1024 * 1024;
+
+/// Mask pixels at or above this luminance (and opacity) are repainted
+const MASK_THRESHOLD: u8 = 128;
+
+// ========================================
+// Models
+// ========================================
+
+/// JSON body; the same fields are accepted as `multipart/form-data`
+/// (`image` / `mask` as files or base64 text)
+#[derive(Debug, Deserialize)]
+struct InpaintRequest {
+    /// Publicly reachable URL of the source image
+    image_url: Option<String>,
+    /// Source image as base64 or a `data:image/...;base64,` URL (instead of `image_url`)
+    image: Option<String>,
+    /// Mask as base64 or a data URL; white marks the area to repaint
+    mask: String,
+    /// What to paint into the masked area
+    prompt: String,
+    seed: Option<u32>,
+}
+
+/// Validated request, kept by the job so it can be retried
+#[derive(Debug, Clone)]
+struct InpaintJob {
+    /// `None` for resumed jobs, which download `image_url` again
+    source: Option<SourceImage>,
+    /// Binarized PNG the size of the source
+    mask: Bytes,
+    image_url: Option<String>,
+    options: InpaintOptions,
+}
+
+/// Job input stored to resume it after a restart. Uploaded sources are not
+/// stored, so only jobs started from an `image_url` can resume.
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredInpaint {
+    image_url: Option<String>,
+    /// Base64 of the binarized mask
+    mask: String,
+    prompt: String,
+    seed: u32,
+}
+
+impl InpaintJob {
+    /// `from_url`: the source was downloaded from `image_url` (not uploaded)
+    fn to_stored(&self, from_url: bool) -> StoredInpaint {
+        StoredInpaint {
+            image_url: self.image_url.clone().filter(|_| from_url),
+            mask: base64::engine::general_purpose::STANDARD.encode(&self.mask),
+            prompt: self.options.prompt.clone(),
+            seed: self.options.seed,
+        }
+    }
+
+    fn from_stored(stored: StoredInpaint) -> Result<Self, String> {
+        let mask = base64::engine::general_purpose::STANDARD
+            .decode(&stored.mask)
+            .map_err(|e| format!("invalid stored mask: {}", e))?;
+        Ok(Self {
+            source: None,
+            mask: Bytes::from(mask),
+            image_url: Some(
+                stored
+                    .image_url
+                    .ok_or("the uploaded source image is not kept across restarts")?,
+            ),
+            options: InpaintOptions {
+                prompt: stored.prompt,
+                seed: stored.seed,
+            },
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MaskError {
+    Decode(String),
+    SizeMismatch {
+        image: (u32, u32),
+        mask: (u32, u32),
+    },
+    /// No pixel is white, so there is nothing to repaint
+    Empty,
+}
+
+impl fmt::Display for MaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaskError::Decode(err) => write!(f, "Failed to decode image: {}", err),
+            MaskError::SizeMismatch { image, mask } => write!(
+                f,
+                "Mask is {}x{} but the image is {}x{}",
+                mask.0, mask.1, image.0, image.1
+            ),
+            MaskError::Empty => write!(f, "Mask has no white (repaint) area"),
+        }
+    }
+}
+
+// ========================================
+// Mask Preprocessing
+// ========================================
+
+/// Binarize `mask` into a black/white PNG after checking it matches the size
+/// of `image`. A pixel is repainted (white) when it is bright and opaque;
+/// anti-aliased brush edges and transparent backgrounds become black.
+pub fn prepare_mask(image: &[u8], mask: &[u8]) -> Result<Vec<u8>, MaskError> {
+    let decode =
+        |data: &[u8]| image::load_from_memory(data).map_err(|e| MaskError::Decode(e.to_string()));
+    let image = decode(image)?;
+    let mask = decode(mask)?.to_luma_alpha8();
+    if (image.width(), image.height()) != mask.dimensions() {
+        return Err(MaskError::SizeMismatch {
+            image: (image.width(), image.height()),
+            mask: mask.dimensions(),
+        });
+    }
+
+    let binary = image::GrayImage::from_fn(mask.width(), mask.height(), |x, y| {
+        let [luma, alpha] = mask.get_pixel(x, y).0;
+        let paint = luma >= MASK_THRESHOLD && alpha >= MASK_THRESHOLD;
+        image::Luma([if paint { 255 } else { 0 }])
+    });
+    if binary.pixels().all(|pixel| pixel[0] == 0) {
+        return Err(MaskError::Empty);
+    }
+
+    let mut output = Cursor::new(Vec::new());
+    binary
+        .write_to(&mut output, image::ImageFormat::Png)
+        .map_err(|e| MaskError::Decode(e.to_string()))?;
+    Ok(output.into_inner())
+}
+
+/// Decode base64 or a `data:<type>;base64,` URL; the content type is sniffed
+/// when the data URL does not name an image type
+fn decode_base64_image(value: &str) -> Option<SourceImage> {
+    let (declared, encoded) = match value.trim().strip_prefix("data:") {
+        Some(rest) => {
+            let (meta, encoded) = rest.split_once(',')?;
+            (meta.strip_suffix(";base64").map(str::to_string), encoded)
+        }
+        None => (None, value.trim()),
+    };
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(encoded.trim())
+        .ok()?;
+    let content_type = declared
+        .filter(|content_type| content_type.starts_with("image/"))
+        .or_else(|| {
+            image::guess_format(&data)
+                .ok()
+                .map(|format| format.to_mime_type().to_string())
+        })?;
+    Some(SourceImage {
+        data: Bytes::from(data),
+        content_type,
+    })
+}
+
+fn unprocessable(reason: impl fmt::Display) -> StatusCode {
+    tracing::info!("Inpaint request rejected: {}", reason);
+    StatusCode::UNPROCESSABLE_ENTITY
+}
+
+// ========================================
+// Request Parsing
+// ========================================
+
+/// Multipart form with the fields of `InpaintRequest`; `image` and `mask` may
+/// be file uploads or base64 text
+async fn read_form(
+    mut multipart: Multipart,
+) -> Result<(InpaintRequest, Option<SourceImage>, Option<Bytes>), StatusCode> {
+    let mut request = InpaintRequest {
+        image_url: None,
+        image: None,
+        mask: String::new(),
+        prompt: String::new(),
+        seed: None,
+    };
+    let mut image_file = None;
+    let mut mask_file = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+    {
+        let name = field.name().unwrap_or_default().to_string();
+        let is_file = field.file_name().is_some();
+        let content_type = field.content_type().map(str::to_string);
+        let data = field.bytes().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+        let text = || String::from_utf8_lossy(&data).trim().to_string();
+        match name.as_str() {
+            "image" if is_file => {
+                image_file = Some(SourceImage {
+                    content_type: content_type
+                        .filter(|content_type| content_type.starts_with("image/"))
+                        .ok_or_else(|| unprocessable("image upload is not an image"))?,
+                    data: data.clone(),
+                })
+            }
+            "image" => request.image = Some(text()),
+            "mask" if is_file => mask_file = Some(data.clone()),
+            "mask" => request.mask = text(),
+            "image_url" => request.image_url = Some(text()).filter(|url| !url.is_empty()),
+            "prompt" => request.prompt = text(),
+            "seed" => {
+                request.seed = Some(
+                    text()
+                        .parse()
+                        .map_err(|_| unprocessable("seed is not a number"))?,
+                )
+            }
+            _ => {}
+        }
+    }
+
+    Ok((request, image_file, mask_file))
+}
+
+async fn read_request(
+    state: &AppState,
+    request: Request,
+) -> Result<(InpaintRequest, Option<SourceImage>, Option<Bytes>), StatusCode> {
+    let is_multipart = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("multipart/form-data"));
+
+    if is_multipart {
+        let multipart = Multipart::from_request(request, state)
+            .await
+            .map_err(|_| StatusCode::BAD_REQUEST)?;
+        read_form(multipart).await
+    } else {
+        let Json(payload) = Json::<InpaintRequest>::from_request(request, state)
+            .await
+            .map_err(|rejection| rejection.status())?;
+        Ok((payload, None, None))
+    }
+}
+
+// ========================================
+// Job
+// ========================================
+
+async fn process_inpaint(
+    state: AppState,
+    storage: StorageClient,
+    job: InpaintJob,
+    owner: Option<Uuid>,
+    handle: JobHandle,
+) -> Result<serde_json::Value, String> {
+    let provider = state.images.clone();
+    let source = match (&job.source, &job.image_url) {
+        (Some(source), _) => source.clone(),
+        (None, Some(url)) => fetch_source(url, state.config.image.max_source_bytes).await?,
+        (None, None) => return Err("The job has no source image".to_string()),
+    };
+    handle.set_progress(20).await;
+
+    let processed = provider
+        .inpaint(source, job.mask.clone(), &job.options)
+        .await
+        .map_err(|e| e.to_string())?;
+    handle.set_progress(80).await;
+
+    let metadata = serde_json::json!({
+        "mode": "inpaint",
+        "provider": provider.name(),
+        "prompt": job.options.prompt,
+        "seed": job.options.seed,
+        "source_image": job.image_url,
+        "job_id": handle.id,
+    });
+    let (stored, file_id) =
+        store_output(&state, &storage, "inpaint", processed, owner, metadata).await?;
+
+    Ok(serde_json::json!({
+        "provider": provider.name(),
+        "seed": job.options.seed,
+        "storage": stored,
+        "file_id": file_id,
+    }))
+}
+
+fn inpaint_runner(
+    state: &AppState,
+    storage: StorageClient,
+    job: InpaintJob,
+    owner: Option<Uuid>,
+) -> JobRunner {
+    let state = state.clone();
+    jobs::runner(move |handle| {
+        process_inpaint(state.clone(), storage.clone(), job.clone(), owner, handle)
+    })
+}
+
+/// Resume inpaint jobs started from an `image_url` after a restart
+pub async fn register_resumers(store: &JobStore) {
+    store
+        .register_resumer("inpaint", |state, owner, input| {
+            let stored: StoredInpaint =
+                serde_json::from_value(input).map_err(|e| format!("invalid job input: {}", e))?;
+            let job = InpaintJob::from_stored(stored)?;
+            Ok(inpaint_runner(state, job_storage(state)?, job, owner))
+        })
+        .await;
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// Repaint the masked area of an image (JSON or multipart), as a background job
+async fn inpaint(
+    user: OptionalUser,
+    State(state): State<AppState>,
+    request: Request,
+) -> Result<(StatusCode, Json<Job>), StatusCode> {
+    let (payload, image_file, mask_file) = read_request(&state, request).await?;
+    if payload.prompt.trim().is_empty() {
+        return Err(unprocessable("prompt is required"));
+    }
+    let storage = state
+        .storage
+        .clone()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    let max_bytes = state.config.image.max_source_bytes;
+
+    let from_url = image_file.is_none() && payload.image.is_none();
+    let source = match (image_file, payload.image.as_deref(), &payload.image_url) {
+        (Some(file), _, _) => file,
+        (None, Some(encoded), _) => {
+            decode_base64_image(encoded).ok_or_else(|| unprocessable("image is not base64"))?
+        }
+        (None, None, Some(url)) => fetch_source(url, max_bytes).await.map_err(unprocessable)?,
+        (None, None, None) => return Err(unprocessable("image or image_url is required")),
+    };
+    let mask = match mask_file {
+        Some(data) => data,
+        None => {
+            decode_base64_image(&payload.mask)
+                .ok_or_else(|| unprocessable("mask is missing or not base64"))?
+                .data
+        }
+    };
+    if source.data.len() > max_bytes || mask.len() > max_bytes {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    let source_data = source.data.clone();
+    let mask = tokio::task::spawn_blocking(move || prepare_mask(&source_data, &mask))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(unprocessable)?;
+
+    let owner = user.id();
+    let job = InpaintJob {
+        source: Some(source),
+        mask: Bytes::from(mask),
+        image_url: payload.image_url,
+        options: InpaintOptions {
+            prompt: payload.prompt,
+            seed: payload.seed.unwrap_or_else(random_seed),
+        },
+    };
+    let input = serde_json::to_value(job.to_stored(from_url))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let runner = inpaint_runner(&state, storage, job, owner);
+    let job = spawn_resumable_job(&state.jobs, "inpaint", owner, input, runner).await;
+
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(
+        "/api/aigen/inpaint",
+        post(inpaint).layer(DefaultBodyLimit::max(MAX_INPAINT_BODY_BYTES)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(image: image::DynamicImage) -> Vec<u8> {
+        let mut output = Cursor::new(Vec::new());
+        image
+            .write_to(&mut output, image::ImageFormat::Png)
+            .unwrap();
+        output.into_inner()
+    }
+
+    fn gray(width: u32, height: u32, paint: impl Fn(u32, u32) -> [u8; 2]) -> Vec<u8> {
+        png(image::DynamicImage::ImageLumaA8(
+            image::GrayAlphaImage::from_fn(width, height, |x, y| image::LumaA(paint(x, y))),
+        ))
+    }
+
+    #[test]
+    fn test_prepare_mask_binarizes() {
+        let source = gray(4, 2, |_, _| [0, 255]);
+        // Bright opaque, soft edge, bright but transparent, black
+        let mask = gray(4, 2, |x, _| match x {
+            0 => [255, 255],
+            1 => [100, 255],
+            2 => [255, 0],
+            _ => [0, 255],
+        });
+
+        let binary = image::load_from_memory(&prepare_mask(&source, &mask).unwrap())
+            .unwrap()
+            .to_luma8();
+        let row: Vec<u8> = (0..4).map(|x| binary.get_pixel(x, 1)[0]).collect();
+        assert_eq!(row, [255, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_prepare_mask_rejects_bad_masks() {
+        let source = gray(4, 2, |_, _| [0, 255]);
+        assert_eq!(
+            prepare_mask(&source, &gray(2, 2, |_, _| [255, 255])),
+            Err(MaskError::SizeMismatch {
+                image: (4, 2),
+                mask: (2, 2)
+            })
+        );
+        assert_eq!(
+            prepare_mask(&source, &gray(4, 2, |_, _| [0, 255])),
+            Err(MaskError::Empty)
+        );
+        assert!(matches!(
+            prepare_mask(&source, b"not an image"),
+            Err(MaskError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_base64_image() {
+        let data = gray(1, 1, |_, _| [255, 255]);
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+
+        let plain = decode_base64_image(&encoded).unwrap();
+        assert_eq!(plain.content_type, "image/png");
+        assert_eq!(plain.data.as_ref(), data.as_slice());
+
+        let url = decode_base64_image(&format!("data:image/webp;base64,{}", encoded)).unwrap();
+        assert_eq!(url.content_type, "image/webp");
+        assert!(decode_base64_image("not base64!").is_none());
+    }
+}
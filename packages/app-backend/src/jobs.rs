@@ -0,0 +1,965 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::AbortHandle;
+use tracing::Instrument;
+use uuid::Uuid;
+
+use crate::auth::{AdminUser, OptionalUser};
+use crate::limits;
+use crate::pagination::{Paginated, Pagination, SortBy, SortFields};
+use crate::state::AppState;
+use crate::telemetry;
+
+// ========================================
+// Job Models
+// ========================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Queued,
+    Generating,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Queued => "queued",
+            JobStatus::Generating => "generating",
+            JobStatus::Completed => "completed",
+            JobStatus::Failed => "failed",
+            JobStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled
+        )
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            JobStatus::Queued,
+            JobStatus::Generating,
+            JobStatus::Completed,
+            JobStatus::Failed,
+            JobStatus::Cancelled,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == value)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Job {
+    pub id: Uuid,
+    pub kind: String,
+    /// User who started the job (notified by email when it finishes)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Uuid>,
+    pub status: JobStatus,
+    /// Progress percentage (0-100)
+    pub progress: u8,
+    /// Number of times the job has been started
+    pub attempts: u32,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Filters for listing jobs
+#[derive(Debug, Default, Deserialize)]
+pub struct JobFilter {
+    pub status: Option<JobStatus>,
+    pub kind: Option<String>,
+}
+
+/// Sortable fields for the admin job list
+pub struct JobSort;
+
+impl SortFields for JobSort {
+    const ALLOWED: &'static [&'static str] = &["created_at", "updated_at", "kind", "status"];
+    const DEFAULT: &'static str = "created_at";
+    const DEFAULT_DESCENDING: bool = true;
+}
+
+#[derive(Debug, Deserialize)]
+struct PurgeQuery {
+    /// Remove finished jobs last updated more than this many hours ago
+    older_than_hours: Option<i64>,
+    /// Only purge jobs with this status (default: any finished status)
+    status: Option<JobStatus>,
+}
+
+#[derive(Debug, Serialize)]
+struct PurgeResponse {
+    purged: usize,
+}
+
+/// `background_jobs` row
+#[derive(Debug, sqlx::FromRow)]
+struct JobRow {
+    id: Uuid,
+    kind: String,
+    owner_id: Option<Uuid>,
+    status: String,
+    progress: i16,
+    attempts: i32,
+    input: Option<serde_json::Value>,
+    result: Option<serde_json::Value>,
+    error: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+const JOB_COLUMNS: &str =
+    "id, kind, owner_id, status, progress, attempts, input, result, error, created_at, updated_at";
+
+impl JobRow {
+    fn into_job(self) -> (Job, Option<serde_json::Value>) {
+        let job = Job {
+            id: self.id,
+            kind: self.kind,
+            owner: self.owner_id,
+            // Unknown values only come from manual edits; treat them as failed
+            status: JobStatus::parse(&self.status).unwrap_or(JobStatus::Failed),
+            progress: self.progress.clamp(0, 100) as u8,
+            attempts: self.attempts.max(0) as u32,
+            result: self.result,
+            error: self.error,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        };
+        (job, self.input)
+    }
+}
+
+// ========================================
+// Job Store
+// ========================================
+
+type JobFuture = Pin<Box<dyn Future<Output = Result<serde_json::Value, String>> + Send>>;
+
+/// Re-runnable job body (kept so failed jobs can be retried)
+pub type JobRunner = Arc<dyn Fn(JobHandle) -> JobFuture + Send + Sync>;
+
+/// Rebuilds the body of a `kind` of job from its stored input (and owner) after
+/// a restart; the error is recorded as the reason the job failed
+pub type Resumer = Arc<
+    dyn Fn(&AppState, Option<Uuid>, serde_json::Value) -> Result<JobRunner, String> + Send + Sync,
+>;
+
+struct JobEntry {
+    job: Job,
+    runner: JobRunner,
+    task: Option<AbortHandle>,
+}
+
+/// Registry of background jobs: running jobs live in memory, and every state
+/// transition is written to `background_jobs` when a database is attached so
+/// jobs survive a restart
+#[derive(Clone)]
+pub struct JobStore {
+    jobs: Arc<RwLock<HashMap<Uuid, JobEntry>>>,
+    /// Jobs whose body completed or failed
+    finished: broadcast::Sender<Job>,
+    db: Option<PgPool>,
+    resumers: Arc<RwLock<HashMap<String, Resumer>>>,
+}
+
+impl Default for JobStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error returned by admin operations on a job
+#[derive(Debug, PartialEq, Eq)]
+pub enum JobActionError {
+    NotFound,
+    /// The job is not in a state that allows the action
+    InvalidState(JobStatus),
+}
+
+impl From<JobActionError> for StatusCode {
+    fn from(err: JobActionError) -> Self {
+        match err {
+            JobActionError::NotFound => StatusCode::NOT_FOUND,
+            JobActionError::InvalidState(_) => StatusCode::CONFLICT,
+        }
+    }
+}
+
+impl JobStore {
+    /// In-memory only (jobs are lost on restart)
+    pub fn new() -> Self {
+        Self {
+            jobs: Arc::default(),
+            finished: broadcast::channel(64).0,
+            db: None,
+            resumers: Arc::default(),
+        }
+    }
+
+    /// Jobs persisted in `background_jobs`
+    pub fn with_db(db: PgPool) -> Self {
+        Self {
+            db: Some(db),
+            ..Self::new()
+        }
+    }
+
+    /// How to resume `kind` jobs interrupted by a restart (see `resume_orphaned`)
+    pub async fn register_resumer<F>(&self, kind: &str, resume: F)
+    where
+        F: Fn(&AppState, Option<Uuid>, serde_json::Value) -> Result<JobRunner, String>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resumers
+            .write()
+            .await
+            .insert(kind.to_string(), Arc::new(resume));
+    }
+
+    /// Receive jobs as they complete or fail (cancelled jobs are not sent)
+    pub fn subscribe(&self) -> broadcast::Receiver<Job> {
+        self.finished.subscribe()
+    }
+
+    async fn insert(
+        &self,
+        kind: &str,
+        owner: Option<Uuid>,
+        input: Option<serde_json::Value>,
+        runner: JobRunner,
+    ) -> Job {
+        let now = Utc::now();
+        let job = Job {
+            id: Uuid::new_v4(),
+            kind: kind.to_string(),
+            owner,
+            status: JobStatus::Queued,
+            progress: 0,
+            attempts: 0,
+            result: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        };
+        self.jobs.write().await.insert(
+            job.id,
+            JobEntry {
+                job: job.clone(),
+                runner,
+                task: None,
+            },
+        );
+        self.persist(&job, input.as_ref()).await;
+        job
+    }
+
+    /// Write `job` to `background_jobs` (`input` is only stored on insert).
+    /// Failures are logged: the in-memory job keeps running either way.
+    async fn persist(&self, job: &Job, input: Option<&serde_json::Value>) {
+        let Some(db) = &self.db else {
+            return;
+        };
+        let result = sqlx::query(
+            "INSERT INTO background_jobs
+               (id, kind, owner_id, status, progress, attempts, input, result, error,
+                created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+             ON CONFLICT (id) DO UPDATE SET
+               status = EXCLUDED.status, progress = EXCLUDED.progress,
+               attempts = EXCLUDED.attempts, result = EXCLUDED.result,
+               error = EXCLUDED.error, updated_at = EXCLUDED.updated_at
+             WHERE background_jobs.updated_at <= EXCLUDED.updated_at",
+        )
+        .bind(job.id)
+        .bind(&job.kind)
+        .bind(job.owner)
+        .bind(job.status.as_str())
+        .bind(job.progress as i16)
+        .bind(job.attempts as i32)
+        .bind(input)
+        .bind(&job.result)
+        .bind(&job.error)
+        .bind(job.created_at)
+        .bind(job.updated_at)
+        .execute(db)
+        .await;
+        if let Err(e) = result {
+            tracing::warn!("Failed to persist job {}: {}", job.id, e);
+        }
+    }
+
+    /// A job of this process, or one from `background_jobs` (e.g. finished
+    /// before a restart)
+    pub async fn get(&self, id: Uuid) -> Option<Job> {
+        if let Some(entry) = self.jobs.read().await.get(&id) {
+            return Some(entry.job.clone());
+        }
+        let db = self.db.as_ref()?;
+        sqlx::query_as::<_, JobRow>(&format!(
+            "SELECT {} FROM background_jobs WHERE id = $1",
+            JOB_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| tracing::warn!("Failed to load job {}: {}", id, e))
+        .ok()
+        .flatten()
+        .map(|row| row.into_job().0)
+    }
+
+    /// One page of jobs matching `filter`
+    pub async fn list(
+        &self,
+        filter: &JobFilter,
+        pagination: &Pagination,
+        sort: &SortBy<JobSort>,
+    ) -> Paginated<Job> {
+        if let Some(db) = &self.db {
+            match Self::list_persisted(db, filter, pagination, sort).await {
+                Ok(page) => return page,
+                Err(e) => tracing::warn!(
+                    "Failed to list persisted jobs, showing this process only: {}",
+                    e
+                ),
+            }
+        }
+
+        let mut jobs: Vec<Job> = self
+            .jobs
+            .read()
+            .await
+            .values()
+            .map(|entry| &entry.job)
+            .filter(|job| filter.status.is_none_or(|s| job.status == s))
+            .filter(|job| filter.kind.as_deref().is_none_or(|k| job.kind == k))
+            .cloned()
+            .collect();
+
+        jobs.sort_by(|a, b| {
+            let ordering = match sort.field {
+                "updated_at" => a.updated_at.cmp(&b.updated_at),
+                "kind" => a.kind.cmp(&b.kind),
+                "status" => a.status.as_str().cmp(b.status.as_str()),
+                _ => a.created_at.cmp(&b.created_at),
+            };
+            if sort.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        let total = jobs.len() as i64;
+        let page = jobs
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit as usize)
+            .collect();
+        Paginated::from_offset(page, pagination, total)
+    }
+
+    async fn list_persisted(
+        db: &PgPool,
+        filter: &JobFilter,
+        pagination: &Pagination,
+        sort: &SortBy<JobSort>,
+    ) -> Result<Paginated<Job>, sqlx::Error> {
+        let conditions = "($1::text IS NULL OR status = $1) AND ($2::text IS NULL OR kind = $2)";
+        let status = filter.status.map(|status| status.as_str());
+
+        let total: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM background_jobs WHERE {}",
+            conditions
+        ))
+        .bind(status)
+        .bind(&filter.kind)
+        .fetch_one(db)
+        .await?;
+
+        let rows = sqlx::query_as::<_, JobRow>(&format!(
+            "SELECT {} FROM background_jobs WHERE {}
+             ORDER BY {}, id
+             LIMIT $3 OFFSET $4",
+            JOB_COLUMNS,
+            conditions,
+            sort.order_by()
+        ))
+        .bind(status)
+        .bind(&filter.kind)
+        .bind(pagination.limit as i64)
+        .bind(pagination.offset())
+        .fetch_all(db)
+        .await?;
+
+        let jobs = rows.into_iter().map(|row| row.into_job().0).collect();
+        Ok(Paginated::from_offset(jobs, pagination, total))
+    }
+
+    async fn update<F: FnOnce(&mut Job)>(&self, id: Uuid, apply: F) {
+        let job = {
+            let mut jobs = self.jobs.write().await;
+            let Some(entry) = jobs.get_mut(&id) else {
+                return;
+            };
+            apply(&mut entry.job);
+            entry.job.updated_at = Utc::now();
+            entry.job.clone()
+        };
+        self.persist(&job, None).await;
+    }
+
+    /// Run the job's body in the background
+    async fn start(&self, id: Uuid) {
+        let mut jobs = self.jobs.write().await;
+        let Some(entry) = jobs.get_mut(&id) else {
+            return;
+        };
+
+        let handle = JobHandle {
+            id,
+            store: self.clone(),
+        };
+        let work = (entry.runner)(handle);
+        let store = self.clone();
+        let span = telemetry::job_span(id, &entry.job.kind, entry.job.attempts + 1);
+
+        let task = tokio::spawn(async move {
+            store
+                .update(id, |job| {
+                    job.status = JobStatus::Generating;
+                    job.attempts += 1;
+                })
+                .await;
+
+            let started = Instant::now();
+            let outcome = limits::batch(work).instrument(span.clone()).await;
+            store
+                .update(id, |job| {
+                    // A cancelled job keeps its status even if the body finished
+                    if job.status == JobStatus::Cancelled {
+                        return;
+                    }
+                    match outcome {
+                        Ok(result) => {
+                            job.status = JobStatus::Completed;
+                            job.progress = 100;
+                            job.result = Some(result);
+                        }
+                        Err(error) => {
+                            tracing::error!("Job {} failed: {}", id, error);
+                            job.status = JobStatus::Failed;
+                            job.error = Some(error);
+                        }
+                    }
+                })
+                .await;
+
+            if let Some(job) = store.get(id).await {
+                telemetry::job_finished(&span, &job.kind, job.status.as_str(), started.elapsed());
+                if matches!(job.status, JobStatus::Completed | JobStatus::Failed) {
+                    // No receivers is fine
+                    let _ = store.finished.send(job);
+                }
+            }
+        });
+
+        entry.task = Some(task.abort_handle());
+    }
+
+    /// Re-run a failed or cancelled job of this process
+    pub async fn retry(&self, id: Uuid) -> Result<Job, JobActionError> {
+        let job = {
+            let mut jobs = self.jobs.write().await;
+            let entry = jobs.get_mut(&id).ok_or(JobActionError::NotFound)?;
+            if !matches!(entry.job.status, JobStatus::Failed | JobStatus::Cancelled) {
+                return Err(JobActionError::InvalidState(entry.job.status));
+            }
+
+            entry.job.status = JobStatus::Queued;
+            entry.job.progress = 0;
+            entry.job.result = None;
+            entry.job.error = None;
+            entry.job.updated_at = Utc::now();
+            entry.job.clone()
+        };
+        self.persist(&job, None).await;
+
+        self.start(id).await;
+        self.get(id).await.ok_or(JobActionError::NotFound)
+    }
+
+    /// Cancel a job that has not finished, aborting it if it is running
+    pub async fn cancel(&self, id: Uuid) -> Result<Job, JobActionError> {
+        let job = {
+            let mut jobs = self.jobs.write().await;
+            let entry = jobs.get_mut(&id).ok_or(JobActionError::NotFound)?;
+            if entry.job.status.is_finished() {
+                return Err(JobActionError::InvalidState(entry.job.status));
+            }
+
+            if let Some(task) = entry.task.take() {
+                task.abort();
+            }
+            entry.job.status = JobStatus::Cancelled;
+            entry.job.updated_at = Utc::now();
+            entry.job.clone()
+        };
+        self.persist(&job, None).await;
+        Ok(job)
+    }
+
+    /// Restart running jobs that have not reported progress since `cutoff`.
+    ///
+    /// Jobs that already ran `max_attempts` times are marked failed instead.
+    /// Returns (restarted, failed).
+    pub async fn retry_stalled(&self, cutoff: DateTime<Utc>, max_attempts: u32) -> (usize, usize) {
+        let mut restart = Vec::new();
+        let mut failed = 0;
+        let mut changed = Vec::new();
+        {
+            let mut jobs = self.jobs.write().await;
+            for (id, entry) in jobs.iter_mut() {
+                let job = &mut entry.job;
+                if job.status != JobStatus::Generating || job.updated_at >= cutoff {
+                    continue;
+                }
+                if let Some(task) = entry.task.take() {
+                    task.abort();
+                }
+                job.updated_at = Utc::now();
+                if job.attempts >= max_attempts {
+                    tracing::warn!(
+                        "Job {} stalled after {} attempt(s), giving up",
+                        id,
+                        job.attempts
+                    );
+                    job.status = JobStatus::Failed;
+                    job.error = Some(format!("Stalled after {} attempt(s)", job.attempts));
+                    failed += 1;
+                } else {
+                    tracing::warn!("Job {} stalled, restarting", id);
+                    job.status = JobStatus::Queued;
+                    job.progress = 0;
+                    restart.push(*id);
+                }
+                changed.push(job.clone());
+            }
+        }
+
+        for job in &changed {
+            self.persist(job, None).await;
+        }
+        for id in &restart {
+            self.start(*id).await;
+        }
+        (restart.len(), failed)
+    }
+
+    /// Remove finished jobs last updated before `cutoff`
+    pub async fn purge(&self, cutoff: DateTime<Utc>, status: Option<JobStatus>) -> usize {
+        let purged = {
+            let mut jobs = self.jobs.write().await;
+            let before = jobs.len();
+            jobs.retain(|_, entry| {
+                let job = &entry.job;
+                let purgeable = job.status.is_finished()
+                    && status.is_none_or(|s| job.status == s)
+                    && job.updated_at < cutoff;
+                !purgeable
+            });
+            before - jobs.len()
+        };
+
+        // The table also holds jobs finished before the last restart
+        let Some(db) = &self.db else {
+            return purged;
+        };
+        let finished: Vec<&str> = match status {
+            Some(status) => vec![status.as_str()],
+            None => vec!["completed", "failed", "cancelled"],
+        };
+        match sqlx::query("DELETE FROM background_jobs WHERE status = ANY($1) AND updated_at < $2")
+            .bind(&finished)
+            .bind(cutoff)
+            .execute(db)
+            .await
+        {
+            Ok(result) => purged.max(result.rows_affected() as usize),
+            Err(e) => {
+                tracing::warn!("Failed to purge persisted jobs: {}", e);
+                purged
+            }
+        }
+    }
+
+    /// Pick up jobs a previous process left `queued` or `generating` (last
+    /// updated before `cutoff`): jobs with a registered resumer and stored input
+    /// are queued again, the others are marked failed with the reason.
+    /// Returns (resumed, failed).
+    pub async fn resume_orphaned(
+        &self,
+        state: &AppState,
+        cutoff: DateTime<Utc>,
+        max_attempts: u32,
+    ) -> Result<(usize, usize), sqlx::Error> {
+        let Some(db) = &self.db else {
+            return Ok((0, 0));
+        };
+        let rows = sqlx::query_as::<_, JobRow>(&format!(
+            "SELECT {} FROM background_jobs
+             WHERE status IN ('queued', 'generating') AND updated_at < $1
+             ORDER BY created_at",
+            JOB_COLUMNS
+        ))
+        .bind(cutoff)
+        .fetch_all(db)
+        .await?;
+
+        let (mut resumed, mut failed) = (0, 0);
+        for row in rows {
+            // Jobs of this process are handled by `retry_stalled`
+            if self.jobs.read().await.contains_key(&row.id) {
+                continue;
+            }
+            let (mut job, input) = row.into_job();
+            let runner = if job.attempts >= max_attempts {
+                Err(format!("gave up after {} attempt(s)", job.attempts))
+            } else {
+                self.resume_runner(state, &job, input).await
+            };
+            job.updated_at = Utc::now();
+
+            match runner {
+                Ok(runner) => {
+                    tracing::info!("Resuming {} job {}", job.kind, job.id);
+                    job.status = JobStatus::Queued;
+                    job.progress = 0;
+                    self.persist(&job, None).await;
+                    self.jobs.write().await.insert(
+                        job.id,
+                        JobEntry {
+                            job: job.clone(),
+                            runner,
+                            task: None,
+                        },
+                    );
+                    self.start(job.id).await;
+                    resumed += 1;
+                }
+                Err(reason) => {
+                    tracing::warn!("Job {} interrupted by a restart: {}", job.id, reason);
+                    job.status = JobStatus::Failed;
+                    job.error = Some(format!("Interrupted by a restart: {}", reason));
+                    self.persist(&job, None).await;
+                    // No receivers is fine
+                    let _ = self.finished.send(job);
+                    failed += 1;
+                }
+            }
+        }
+        Ok((resumed, failed))
+    }
+
+    async fn resume_runner(
+        &self,
+        state: &AppState,
+        job: &Job,
+        input: Option<serde_json::Value>,
+    ) -> Result<JobRunner, String> {
+        let resumer = self
+            .resumers
+            .read()
+            .await
+            .get(&job.kind)
+            .cloned()
+            .ok_or_else(|| format!("{} jobs cannot be resumed; start it again", job.kind))?;
+        let input = input.ok_or("no input was stored for the job")?;
+        resumer(state, job.owner, input)
+    }
+}
+
+/// Handle given to a running job for progress reporting
+#[derive(Clone)]
+pub struct JobHandle {
+    pub id: Uuid,
+    store: JobStore,
+}
+
+impl JobHandle {
+    pub async fn set_progress(&self, progress: u8) {
+        tracing::debug!(job_id = %self.id, progress, "job progress");
+        self.store
+            .update(self.id, |job| job.progress = progress.min(100))
+            .await;
+    }
+}
+
+/// Register a job and run `work` in the background.
+///
+/// The job is marked `completed` with the returned value, or `failed`
+/// with the error message. `work` may be called again when an admin
+/// retries the job, so it must not consume its captures. `owner` is
+/// the user to notify when a long-running job finishes.
+pub async fn spawn_job<F, Fut>(store: &JobStore, kind: &str, owner: Option<Uuid>, work: F) -> Job
+where
+    F: Fn(JobHandle) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<serde_json::Value, String>> + Send + 'static,
+{
+    let job = store.insert(kind, owner, None, runner(work)).await;
+    store.start(job.id).await;
+    job
+}
+
+/// Like `spawn_job`, storing `input` with the job so the resumer registered
+/// for `kind` can rebuild `runner` after a restart
+pub async fn spawn_resumable_job(
+    store: &JobStore,
+    kind: &str,
+    owner: Option<Uuid>,
+    input: serde_json::Value,
+    runner: JobRunner,
+) -> Job {
+    let job = store.insert(kind, owner, Some(input), runner).await;
+    store.start(job.id).await;
+    job
+}
+
+/// Box a job body (see `spawn_job` for the requirements on `work`)
+pub fn runner<F, Fut>(work: F) -> JobRunner
+where
+    F: Fn(JobHandle) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<serde_json::Value, String>> + Send + 'static,
+{
+    Arc::new(move |handle| Box::pin(work(handle)))
+}
+
+/// Resume jobs interrupted by the last shutdown or crash (run once at startup)
+pub async fn resume_interrupted(state: &AppState) {
+    let config = &state.config.tasks;
+    let cutoff = Utc::now() - Duration::minutes(config.job_resume_after_minutes);
+    match state
+        .jobs
+        .resume_orphaned(state, cutoff, config.job_max_attempts)
+        .await
+    {
+        Ok((0, 0)) => {}
+        Ok((resumed, failed)) => tracing::info!(
+            "Interrupted jobs: resumed {}, marked {} failed",
+            resumed,
+            failed
+        ),
+        Err(e) => tracing::error!("Failed to load interrupted jobs: {}", e),
+    }
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// Jobs started by a signed-in user are only visible to them (and admins);
+/// other callers get 404. Anonymous jobs are readable by id.
+async fn get_job(
+    user: OptionalUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Job>, StatusCode> {
+    let job = state.jobs.get(id).await.ok_or(StatusCode::NOT_FOUND)?;
+
+    let is_admin = user.0.as_ref().is_some_and(|u| u.claims.is_admin());
+    if job.owner.is_some() && job.owner != user.id() && !is_admin {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    Ok(Json(job))
+}
+
+// ========================================
+// Admin Handlers
+// ========================================
+
+async fn admin_list_jobs(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+    Query(filter): Query<JobFilter>,
+    pagination: Pagination,
+    sort: SortBy<JobSort>,
+) -> Json<Paginated<Job>> {
+    Json(state.jobs.list(&filter, &pagination, &sort).await)
+}
+
+async fn admin_retry_job(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Job>, StatusCode> {
+    tracing::info!("Admin {:?} retrying job {}", admin.id, id);
+    Ok(Json(state.jobs.retry(id).await?))
+}
+
+async fn admin_cancel_job(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Job>, StatusCode> {
+    tracing::info!("Admin {:?} cancelling job {}", admin.id, id);
+    Ok(Json(state.jobs.cancel(id).await?))
+}
+
+async fn admin_purge_jobs(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+    Query(query): Query<PurgeQuery>,
+) -> Result<Json<PurgeResponse>, StatusCode> {
+    if query.status.is_some_and(|s| !s.is_finished()) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let cutoff = Utc::now() - Duration::hours(query.older_than_hours.unwrap_or(24).max(0));
+    let purged = state.jobs.purge(cutoff, query.status).await;
+    tracing::info!("Admin {:?} purged {} job(s)", admin.id, purged);
+
+    Ok(Json(PurgeResponse { purged }))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/jobs/:id", get(get_job))
+        .route(
+            "/api/admin/jobs",
+            get(admin_list_jobs).delete(admin_purge_jobs),
+        )
+        .route("/api/admin/jobs/:id/retry", post(admin_retry_job))
+        .route("/api/admin/jobs/:id/cancel", post(admin_cancel_job))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn wait_finished(store: &JobStore, id: Uuid) -> Job {
+        for _ in 0..100 {
+            let job = store.get(id).await.unwrap();
+            if job.status.is_finished() {
+                return job;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        panic!("job {} did not finish", id);
+    }
+
+    #[test]
+    fn test_status_parse_round_trip() {
+        for status in [
+            JobStatus::Queued,
+            JobStatus::Generating,
+            JobStatus::Cancelled,
+        ] {
+            assert_eq!(JobStatus::parse(status.as_str()), Some(status));
+        }
+        assert_eq!(JobStatus::parse("running"), None);
+    }
+
+    #[tokio::test]
+    async fn test_retry_failed_job() {
+        let store = JobStore::new();
+        let mut finished = store.subscribe();
+        let job = spawn_job(&store, "test", None, |_| async { Err("boom".to_string()) }).await;
+
+        let failed = wait_finished(&store, job.id).await;
+        assert_eq!(failed.status, JobStatus::Failed);
+        assert_eq!(failed.error.as_deref(), Some("boom"));
+        assert_eq!(finished.recv().await.unwrap().id, job.id);
+
+        store.retry(job.id).await.unwrap();
+        let retried = wait_finished(&store, job.id).await;
+        assert_eq!(retried.attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_and_purge() {
+        let store = JobStore::new();
+        let job = spawn_job(&store, "slow", None, |_| async {
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+            Ok(serde_json::Value::Null)
+        })
+        .await;
+
+        let cancelled = store.cancel(job.id).await.unwrap();
+        assert_eq!(cancelled.status, JobStatus::Cancelled);
+        assert_eq!(
+            store.cancel(job.id).await.unwrap_err(),
+            JobActionError::InvalidState(JobStatus::Cancelled)
+        );
+
+        let filter = JobFilter {
+            status: Some(JobStatus::Cancelled),
+            ..Default::default()
+        };
+        let page = store
+            .list(
+                &filter,
+                &Pagination::default(),
+                &SortBy::parse(None).unwrap(),
+            )
+            .await;
+        assert_eq!(page.total, Some(1));
+
+        assert_eq!(store.purge(Utc::now() + Duration::hours(1), None).await, 1);
+        assert!(store.get(job.id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_retry_stalled() {
+        let store = JobStore::new();
+        let job = spawn_job(&store, "stuck", None, |_| async {
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+            Ok(serde_json::Value::Null)
+        })
+        .await;
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        // Progress was reported recently: not stalled
+        let cutoff = Utc::now() - Duration::minutes(15);
+        assert_eq!(store.retry_stalled(cutoff, 2).await, (0, 0));
+
+        let future = Utc::now() + Duration::hours(1);
+        assert_eq!(store.retry_stalled(future, 2).await, (1, 0));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        assert_eq!(store.get(job.id).await.unwrap().attempts, 2);
+
+        assert_eq!(store.retry_stalled(future, 2).await, (0, 1));
+        let failed = store.get(job.id).await.unwrap();
+        assert_eq!(failed.status, JobStatus::Failed);
+        assert_eq!(failed.error.as_deref(), Some("Stalled after 2 attempt(s)"));
+    }
+}
@@ -0,0 +1,572 @@
+use async_trait::async_trait;
+use axum::{body::Bytes, extract::State, routing::get, Json, Router};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::sync::oneshot;
+
+use crate::auth::AdminUser;
+use crate::config::{ProviderLimit, ProviderLimitsConfig};
+use crate::images::{
+    GenerateOptions, ImageProvider, InpaintOptions, ProcessedImage, SourceImage, VariationOptions,
+};
+use crate::llm::{
+    ChatCompletion, ChatRequest, EmbeddingRequest, EmbeddingResponse, LlmError, LlmProvider,
+};
+use crate::speech::{
+    AudioStream, SpeechProvider, SynthesisRequest, Transcription, TranscriptionRequest,
+};
+use crate::state::AppState;
+use crate::telemetry;
+
+// ========================================
+// Priority
+// ========================================
+
+/// Waiting interactive calls always get the next free slot before batch ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    /// Request handlers (the default)
+    Interactive,
+    /// Background jobs and scheduled tasks
+    Batch,
+}
+
+tokio::task_local! {
+    static PRIORITY: Priority;
+}
+
+/// Run `work` with its provider calls queued as batch work
+pub async fn batch<F: Future>(work: F) -> F::Output {
+    PRIORITY.scope(Priority::Batch, work).await
+}
+
+fn current_priority() -> Priority {
+    PRIORITY
+        .try_with(|priority| *priority)
+        .unwrap_or(Priority::Interactive)
+}
+
+// ========================================
+// Limiter
+// ========================================
+
+struct Waiter {
+    id: u64,
+    grant: oneshot::Sender<()>,
+}
+
+#[derive(Default)]
+struct Queue {
+    running: usize,
+    next_id: u64,
+    interactive: VecDeque<Waiter>,
+    batch: VecDeque<Waiter>,
+}
+
+impl Queue {
+    fn waiting(&mut self, priority: Priority) -> &mut VecDeque<Waiter> {
+        match priority {
+            Priority::Interactive => &mut self.interactive,
+            Priority::Batch => &mut self.batch,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct TierMetrics {
+    started: AtomicU64,
+    rejected: AtomicU64,
+    wait_ms_total: AtomicU64,
+    wait_ms_max: AtomicU64,
+}
+
+/// Counters of one priority tier since startup
+#[derive(Debug, Serialize)]
+pub struct TierStats {
+    pub queued: usize,
+    pub started: u64,
+    /// Turned away because the queue was full
+    pub rejected: u64,
+    /// Average time spent waiting for a slot
+    pub avg_wait_ms: Option<u64>,
+    pub max_wait_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LimiterStats {
+    pub concurrency: usize,
+    pub queue_size: usize,
+    pub running: usize,
+    pub interactive: TierStats,
+    pub batch: TierStats,
+}
+
+/// Caps the calls in flight to one provider. Callers beyond the limit wait
+/// in a queue per priority; once `queue_size` callers wait, new ones fail
+/// with `LlmError::Overloaded`.
+pub struct ProviderLimiter {
+    provider: &'static str,
+    limit: ProviderLimit,
+    queue: Mutex<Queue>,
+    interactive: TierMetrics,
+    batch: TierMetrics,
+}
+
+/// Slot held for the duration of one provider call
+pub struct Permit<'a> {
+    limiter: Option<&'a ProviderLimiter>,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if let Some(limiter) = self.limiter {
+            limiter.release();
+        }
+    }
+}
+
+/// Queued caller; leaves the queue (or hands back a slot granted meanwhile)
+/// when the caller gives up, e.g. because the client disconnected
+struct Waiting<'a> {
+    limiter: &'a ProviderLimiter,
+    id: u64,
+    priority: Priority,
+    granted: bool,
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        if self.granted {
+            return;
+        }
+        let dequeued = {
+            let mut queue = self.limiter.queue.lock().unwrap();
+            let waiting = queue.waiting(self.priority);
+            match waiting.iter().position(|waiter| waiter.id == self.id) {
+                Some(index) => waiting.remove(index).is_some(),
+                None => false,
+            }
+        };
+        // Not queued any more: the slot was granted after we stopped waiting
+        if !dequeued {
+            self.limiter.release();
+        }
+    }
+}
+
+impl ProviderLimiter {
+    pub fn new(provider: &'static str, limit: ProviderLimit) -> Self {
+        Self {
+            provider,
+            limit,
+            queue: Mutex::new(Queue::default()),
+            interactive: TierMetrics::default(),
+            batch: TierMetrics::default(),
+        }
+    }
+
+    fn metrics(&self, priority: Priority) -> &TierMetrics {
+        match priority {
+            Priority::Interactive => &self.interactive,
+            Priority::Batch => &self.batch,
+        }
+    }
+
+    /// Wait for a free slot at the priority of the current task
+    pub async fn acquire(&self) -> Result<Permit<'_>, LlmError> {
+        self.acquire_as(current_priority()).await
+    }
+
+    async fn acquire_as(&self, priority: Priority) -> Result<Permit<'_>, LlmError> {
+        if self.limit.concurrency == 0 {
+            return Ok(Permit { limiter: None });
+        }
+        let metrics = self.metrics(priority);
+        let started = Instant::now();
+
+        let (id, granted) = {
+            let mut queue = self.queue.lock().unwrap();
+            if queue.running < self.limit.concurrency {
+                queue.running += 1;
+                metrics.started.fetch_add(1, Ordering::Relaxed);
+                return Ok(Permit {
+                    limiter: Some(self),
+                });
+            }
+            if queue.interactive.len() + queue.batch.len() >= self.limit.queue_size {
+                metrics.rejected.fetch_add(1, Ordering::Relaxed);
+                return Err(LlmError::Overloaded(format!(
+                    "{} queue is full ({} waiting)",
+                    self.provider, self.limit.queue_size
+                )));
+            }
+            let id = queue.next_id;
+            queue.next_id += 1;
+            let (grant, granted) = oneshot::channel();
+            queue.waiting(priority).push_back(Waiter { id, grant });
+            (id, granted)
+        };
+
+        let mut waiting = Waiting {
+            limiter: self,
+            id,
+            priority,
+            granted: false,
+        };
+        // The sender is only dropped after a grant was sent, or with the limiter
+        let _ = granted.await;
+        waiting.granted = true;
+
+        let waited = started.elapsed().as_millis() as u64;
+        metrics.started.fetch_add(1, Ordering::Relaxed);
+        metrics.wait_ms_total.fetch_add(waited, Ordering::Relaxed);
+        metrics.wait_ms_max.fetch_max(waited, Ordering::Relaxed);
+        Ok(Permit {
+            limiter: Some(self),
+        })
+    }
+
+    /// Hand the slot to the next waiter (interactive first) or free it
+    fn release(&self) {
+        let mut queue = self.queue.lock().unwrap();
+        // Sent under the lock, so a waiter still in the queue is still listening
+        match queue
+            .interactive
+            .pop_front()
+            .or_else(|| queue.batch.pop_front())
+        {
+            Some(waiter) => {
+                let _ = waiter.grant.send(());
+            }
+            None => queue.running -= 1,
+        }
+    }
+
+    pub fn stats(&self) -> LimiterStats {
+        let queue = self.queue.lock().unwrap();
+        let tier = |metrics: &TierMetrics, queued: usize| {
+            let started = metrics.started.load(Ordering::Relaxed);
+            let wait_ms_total = metrics.wait_ms_total.load(Ordering::Relaxed);
+            TierStats {
+                queued,
+                started,
+                rejected: metrics.rejected.load(Ordering::Relaxed),
+                avg_wait_ms: (started > 0).then(|| wait_ms_total / started),
+                max_wait_ms: metrics.wait_ms_max.load(Ordering::Relaxed),
+            }
+        };
+
+        LimiterStats {
+            concurrency: self.limit.concurrency,
+            queue_size: self.limit.queue_size,
+            running: queue.running,
+            interactive: tier(&self.interactive, queue.interactive.len()),
+            batch: tier(&self.batch, queue.batch.len()),
+        }
+    }
+}
+
+/// One limiter per provider kind, shared by the provider wrappers and the
+/// metrics endpoint
+#[derive(Clone)]
+pub struct ProviderLimits {
+    pub llm: Arc<ProviderLimiter>,
+    pub image: Arc<ProviderLimiter>,
+    pub speech: Arc<ProviderLimiter>,
+}
+
+impl ProviderLimits {
+    pub fn from_config(config: &ProviderLimitsConfig) -> Self {
+        Self {
+            llm: Arc::new(ProviderLimiter::new("llm", config.llm)),
+            image: Arc::new(ProviderLimiter::new("image", config.image)),
+            speech: Arc::new(ProviderLimiter::new("speech", config.speech)),
+        }
+    }
+}
+
+// ========================================
+// Provider Wrappers
+// ========================================
+
+struct LimitedLlmProvider {
+    inner: Box<dyn LlmProvider>,
+    limiter: Arc<ProviderLimiter>,
+}
+
+/// Run every call of `provider` through `limiter`, traced as a `provider.call` span
+pub fn limit_llm(
+    provider: Box<dyn LlmProvider>,
+    limiter: Arc<ProviderLimiter>,
+) -> Box<dyn LlmProvider> {
+    Box::new(LimitedLlmProvider {
+        inner: provider,
+        limiter,
+    })
+}
+
+#[async_trait]
+impl LlmProvider for LimitedLlmProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn chat(&self, request: ChatRequest) -> Result<ChatCompletion, LlmError> {
+        telemetry::provider_call("llm", self.name(), "chat", async {
+            let _permit = self.limiter.acquire().await?;
+            self.inner.chat(request).await
+        })
+        .await
+    }
+
+    async fn embed(&self, request: EmbeddingRequest) -> Result<EmbeddingResponse, LlmError> {
+        telemetry::provider_call("llm", self.name(), "embed", async {
+            let _permit = self.limiter.acquire().await?;
+            self.inner.embed(request).await
+        })
+        .await
+    }
+}
+
+struct LimitedImageProvider {
+    inner: Box<dyn ImageProvider>,
+    limiter: Arc<ProviderLimiter>,
+}
+
+pub fn limit_images(
+    provider: Box<dyn ImageProvider>,
+    limiter: Arc<ProviderLimiter>,
+) -> Box<dyn ImageProvider> {
+    Box::new(LimitedImageProvider {
+        inner: provider,
+        limiter,
+    })
+}
+
+#[async_trait]
+impl ImageProvider for LimitedImageProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn upscale(&self, image: SourceImage, scale: u32) -> Result<ProcessedImage, LlmError> {
+        telemetry::provider_call("image", self.name(), "upscale", async {
+            let _permit = self.limiter.acquire().await?;
+            self.inner.upscale(image, scale).await
+        })
+        .await
+    }
+
+    async fn remove_background(&self, image: SourceImage) -> Result<ProcessedImage, LlmError> {
+        telemetry::provider_call("image", self.name(), "remove_background", async {
+            let _permit = self.limiter.acquire().await?;
+            self.inner.remove_background(image).await
+        })
+        .await
+    }
+
+    async fn generate(&self, options: &GenerateOptions) -> Result<ProcessedImage, LlmError> {
+        telemetry::provider_call("image", self.name(), "generate", async {
+            let _permit = self.limiter.acquire().await?;
+            self.inner.generate(options).await
+        })
+        .await
+    }
+
+    async fn variation(
+        &self,
+        image: SourceImage,
+        options: &VariationOptions,
+    ) -> Result<ProcessedImage, LlmError> {
+        telemetry::provider_call("image", self.name(), "variation", async {
+            let _permit = self.limiter.acquire().await?;
+            self.inner.variation(image, options).await
+        })
+        .await
+    }
+
+    async fn inpaint(
+        &self,
+        image: SourceImage,
+        mask: Bytes,
+        options: &InpaintOptions,
+    ) -> Result<ProcessedImage, LlmError> {
+        telemetry::provider_call("image", self.name(), "inpaint", async {
+            let _permit = self.limiter.acquire().await?;
+            self.inner.inpaint(image, mask, options).await
+        })
+        .await
+    }
+}
+
+struct LimitedSpeechProvider {
+    inner: Box<dyn SpeechProvider>,
+    limiter: Arc<ProviderLimiter>,
+}
+
+pub fn limit_speech(
+    provider: Box<dyn SpeechProvider>,
+    limiter: Arc<ProviderLimiter>,
+) -> Box<dyn SpeechProvider> {
+    Box::new(LimitedSpeechProvider {
+        inner: provider,
+        limiter,
+    })
+}
+
+#[async_trait]
+impl SpeechProvider for LimitedSpeechProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    /// The slot is held until the provider responds, not while the audio streams
+    async fn synthesize(&self, request: SynthesisRequest) -> Result<AudioStream, LlmError> {
+        telemetry::provider_call("speech", self.name(), "synthesize", async {
+            let _permit = self.limiter.acquire().await?;
+            self.inner.synthesize(request).await
+        })
+        .await
+    }
+
+    async fn transcribe(&self, request: TranscriptionRequest) -> Result<Transcription, LlmError> {
+        telemetry::provider_call("speech", self.name(), "transcribe", async {
+            let _permit = self.limiter.acquire().await?;
+            self.inner.transcribe(request).await
+        })
+        .await
+    }
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+#[derive(Debug, Serialize)]
+struct ProviderMetrics {
+    llm: LimiterStats,
+    image: LimiterStats,
+    speech: LimiterStats,
+}
+
+/// Concurrency and queue metrics per provider
+async fn admin_metrics(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+) -> Json<serde_json::Value> {
+    let limits = &state.limits;
+    Json(serde_json::json!({
+        "providers": ProviderMetrics {
+            llm: limits.llm.stats(),
+            image: limits.image.stats(),
+            speech: limits.speech.stats(),
+        }
+    }))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new().route("/api/admin/metrics", get(admin_metrics))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn limiter(concurrency: usize, queue_size: usize) -> Arc<ProviderLimiter> {
+        Arc::new(ProviderLimiter::new(
+            "test",
+            ProviderLimit {
+                concurrency,
+                queue_size,
+            },
+        ))
+    }
+
+    /// Queue a call that records `label` once it gets a slot
+    fn spawn_waiter(
+        limiter: &Arc<ProviderLimiter>,
+        priority: Priority,
+        label: &'static str,
+        order: &Arc<Mutex<Vec<&'static str>>>,
+    ) -> tokio::task::JoinHandle<()> {
+        let limiter = limiter.clone();
+        let order = order.clone();
+        tokio::spawn(async move {
+            let _permit = limiter.acquire_as(priority).await.unwrap();
+            order.lock().unwrap().push(label);
+        })
+    }
+
+    async fn settle() {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    #[tokio::test]
+    async fn test_interactive_calls_go_before_batch() {
+        let limiter = limiter(1, 10);
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let held = limiter.acquire_as(Priority::Batch).await.unwrap();
+
+        let batch = spawn_waiter(&limiter, Priority::Batch, "batch", &order);
+        settle().await;
+        let interactive = spawn_waiter(&limiter, Priority::Interactive, "interactive", &order);
+        settle().await;
+        assert_eq!(limiter.stats().batch.queued, 1);
+        assert_eq!(limiter.stats().interactive.queued, 1);
+
+        drop(held);
+        batch.await.unwrap();
+        interactive.await.unwrap();
+        assert_eq!(*order.lock().unwrap(), ["interactive", "batch"]);
+        assert_eq!(limiter.stats().running, 0);
+    }
+
+    #[tokio::test]
+    async fn test_full_queue_rejects() {
+        let limiter = limiter(1, 1);
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let held = limiter.acquire_as(Priority::Interactive).await.unwrap();
+        let queued = spawn_waiter(&limiter, Priority::Batch, "batch", &order);
+        settle().await;
+
+        let rejected = limiter.acquire_as(Priority::Interactive).await;
+        assert!(matches!(rejected, Err(LlmError::Overloaded(_))));
+        assert_eq!(limiter.stats().interactive.rejected, 1);
+
+        drop(held);
+        queued.await.unwrap();
+        assert_eq!(limiter.stats().batch.started, 1);
+    }
+
+    #[tokio::test]
+    async fn test_abandoned_waiter_leaves_the_queue() {
+        let limiter = limiter(1, 10);
+        let held = limiter.acquire_as(Priority::Interactive).await.unwrap();
+        let abandoned = tokio::time::timeout(
+            Duration::from_millis(10),
+            limiter.acquire_as(Priority::Batch),
+        )
+        .await;
+        assert!(abandoned.is_err());
+        assert_eq!(limiter.stats().batch.queued, 0);
+
+        drop(held);
+        assert_eq!(limiter.stats().running, 0);
+        assert!(limiter.acquire_as(Priority::Batch).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_batch_scope_sets_priority() {
+        assert_eq!(current_priority(), Priority::Interactive);
+        assert_eq!(batch(async { current_priority() }).await, Priority::Batch);
+    }
+}
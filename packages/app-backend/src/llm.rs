@@ -0,0 +1,577 @@
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::config::LlmConfig;
+use crate::slack::{Severity, SlackEvent, SlackNotifier};
+
+// ========================================
+// Chat Models
+// ========================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatRole {
+    System,
+    User,
+    Assistant,
+}
+
+impl ChatRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChatRole::System => "system",
+            ChatRole::User => "user",
+            ChatRole::Assistant => "assistant",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "system" => Some(ChatRole::System),
+            "user" => Some(ChatRole::User),
+            "assistant" => Some(ChatRole::Assistant),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+impl ChatMessage {
+    pub fn new(role: ChatRole, content: impl Into<String>) -> Self {
+        Self {
+            role,
+            content: content.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatRequest {
+    pub model: Option<String>,
+    pub messages: Vec<ChatMessage>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatCompletion {
+    pub content: String,
+    pub model_used: String,
+    pub tokens_used: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmbeddingRequest {
+    pub model: Option<String>,
+    pub inputs: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmbeddingResponse {
+    pub embeddings: Vec<Vec<f32>>,
+    pub model_used: String,
+}
+
+#[derive(Debug)]
+pub enum LlmError {
+    Http(reqwest::Error),
+    Provider(String),
+    /// Too many calls are queued for the provider; retry later
+    Overloaded(String),
+}
+
+impl fmt::Display for LlmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LlmError::Http(err) => write!(f, "LLM request failed: {}", err),
+            LlmError::Provider(msg) => write!(f, "LLM provider error: {}", msg),
+            LlmError::Overloaded(msg) => write!(f, "Provider overloaded: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LlmError {}
+
+impl LlmError {
+    /// Response status for a failed provider call: 503 when the call was
+    /// turned away by the concurrency limit, 502 otherwise
+    pub fn status(&self) -> StatusCode {
+        match self {
+            LlmError::Overloaded(_) => StatusCode::SERVICE_UNAVAILABLE,
+            _ => StatusCode::BAD_GATEWAY,
+        }
+    }
+}
+
+impl From<reqwest::Error> for LlmError {
+    fn from(err: reqwest::Error) -> Self {
+        LlmError::Http(err)
+    }
+}
+
+/// Rough token estimate (~4 characters per token) used for context budgeting
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+// ========================================
+// Provider Abstraction
+// ========================================
+
+#[async_trait]
+pub trait LlmProvider: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn chat(&self, request: ChatRequest) -> Result<ChatCompletion, LlmError>;
+
+    async fn embed(&self, request: EmbeddingRequest) -> Result<EmbeddingResponse, LlmError>;
+}
+
+/// Build the provider from configuration.
+///
+/// Falls back to the placeholder provider when no API key is configured,
+/// so the API keeps responding during local development.
+pub fn provider_from_config(config: &LlmConfig) -> Box<dyn LlmProvider> {
+    match &config.api_key {
+        Some(api_key) => Box::new(OpenAiProvider::new(api_key.clone(), config)),
+        None => {
+            tracing::warn!("OPENAI_API_KEY is not set, using placeholder LLM provider");
+            Box::new(PlaceholderProvider::new(config))
+        }
+    }
+}
+
+// ========================================
+// OpenAI-compatible Provider
+// ========================================
+
+pub struct OpenAiProvider {
+    client: reqwest::Client,
+    api_key: String,
+    base_url: String,
+    default_model: String,
+    embedding_model: String,
+    embedding_dimensions: usize,
+}
+
+#[derive(Serialize)]
+struct OpenAiChatRequest<'a> {
+    model: &'a str,
+    messages: &'a [ChatMessage],
+}
+
+#[derive(Deserialize)]
+struct OpenAiChatResponse {
+    model: String,
+    choices: Vec<OpenAiChoice>,
+    usage: Option<OpenAiUsage>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiChoice {
+    message: ChatMessage,
+}
+
+#[derive(Deserialize)]
+struct OpenAiUsage {
+    total_tokens: u32,
+}
+
+#[derive(Serialize)]
+struct OpenAiEmbeddingRequest<'a> {
+    model: &'a str,
+    input: &'a [String],
+    dimensions: usize,
+}
+
+#[derive(Deserialize)]
+struct OpenAiEmbeddingResponse {
+    model: String,
+    data: Vec<OpenAiEmbedding>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiEmbedding {
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+impl OpenAiProvider {
+    pub fn new(api_key: String, config: &LlmConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            api_key,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            default_model: config.default_model.clone(),
+            embedding_model: config.embedding_model.clone(),
+            embedding_dimensions: config.embedding_dimensions,
+        }
+    }
+
+    async fn post_json<Req: Serialize, Res: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &Req,
+    ) -> Result<Res, LlmError> {
+        let response = self
+            .client
+            .post(format!("{}{}", self.base_url, path))
+            .bearer_auth(&self.api_key)
+            .json(body)
+            .send()
+            .await?;
+
+        Ok(ensure_success(response).await?.json().await?)
+    }
+}
+
+/// Turn a non-2xx provider response into `LlmError::Provider` with the response body
+pub async fn ensure_success(response: reqwest::Response) -> Result<reqwest::Response, LlmError> {
+    if response.status().is_success() {
+        return Ok(response);
+    }
+
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    Err(LlmError::Provider(format!("{}: {}", status, body)))
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiProvider {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    async fn chat(&self, request: ChatRequest) -> Result<ChatCompletion, LlmError> {
+        let model = request.model.as_deref().unwrap_or(&self.default_model);
+
+        let body: OpenAiChatResponse = self
+            .post_json(
+                "/chat/completions",
+                &OpenAiChatRequest {
+                    model,
+                    messages: &request.messages,
+                },
+            )
+            .await?;
+        let content = body
+            .choices
+            .into_iter()
+            .next()
+            .map(|choice| choice.message.content)
+            .ok_or_else(|| LlmError::Provider("Empty choices in response".to_string()))?;
+
+        Ok(ChatCompletion {
+            content,
+            model_used: body.model,
+            tokens_used: body.usage.map(|u| u.total_tokens),
+        })
+    }
+
+    async fn embed(&self, request: EmbeddingRequest) -> Result<EmbeddingResponse, LlmError> {
+        let model = request.model.as_deref().unwrap_or(&self.embedding_model);
+
+        let mut body: OpenAiEmbeddingResponse = self
+            .post_json(
+                "/embeddings",
+                &OpenAiEmbeddingRequest {
+                    model,
+                    input: &request.inputs,
+                    dimensions: self.embedding_dimensions,
+                },
+            )
+            .await?;
+
+        if body.data.len() != request.inputs.len() {
+            return Err(LlmError::Provider(format!(
+                "Expected {} embeddings, got {}",
+                request.inputs.len(),
+                body.data.len()
+            )));
+        }
+
+        body.data.sort_by_key(|e| e.index);
+        Ok(EmbeddingResponse {
+            embeddings: body.data.into_iter().map(|e| e.embedding).collect(),
+            model_used: body.model,
+        })
+    }
+}
+
+// ========================================
+// Placeholder Provider (no API key)
+// ========================================
+
+pub struct PlaceholderProvider {
+    default_model: String,
+    embedding_dimensions: usize,
+}
+
+impl PlaceholderProvider {
+    pub fn new(config: &LlmConfig) -> Self {
+        Self {
+            default_model: config.default_model.clone(),
+            embedding_dimensions: config.embedding_dimensions,
+        }
+    }
+}
+
+/// Deterministic bag-of-words embedding so local semantic search still ranks sensibly
+pub fn hashed_embedding(text: &str, dimensions: usize) -> Vec<f32> {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut vector = vec![0.0f32; dimensions.max(1)];
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        let mut hasher = DefaultHasher::new();
+        word.to_lowercase().hash(&mut hasher);
+        let slot = (hasher.finish() % vector.len() as u64) as usize;
+        vector[slot] += 1.0;
+    }
+
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+    vector
+}
+
+#[async_trait]
+impl LlmProvider for PlaceholderProvider {
+    fn name(&self) -> &str {
+        "placeholder"
+    }
+
+    async fn chat(&self, request: ChatRequest) -> Result<ChatCompletion, LlmError> {
+        let last_user = request
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role == ChatRole::User)
+            .map(|m| m.content.as_str())
+            .unwrap_or_default();
+
+        let tokens: usize = request
+            .messages
+            .iter()
+            .map(|m| estimate_tokens(&m.content))
+            .sum();
+
+        Ok(ChatCompletion {
+            content: format!("Task '{}' executed successfully (placeholder)", last_user),
+            model_used: request.model.unwrap_or_else(|| self.default_model.clone()),
+            tokens_used: Some(tokens as u32),
+        })
+    }
+
+    async fn embed(&self, request: EmbeddingRequest) -> Result<EmbeddingResponse, LlmError> {
+        Ok(EmbeddingResponse {
+            embeddings: request
+                .inputs
+                .iter()
+                .map(|text| hashed_embedding(text, self.embedding_dimensions))
+                .collect(),
+            model_used: "placeholder-embedding".to_string(),
+        })
+    }
+}
+
+// ========================================
+// Circuit Breaker
+// ========================================
+
+/// Transition reported by `CircuitBreaker::record`
+#[derive(Debug, PartialEq, Eq)]
+enum BreakerEvent {
+    Tripped { failures: u32 },
+    Recovered,
+}
+
+/// Opens after `threshold` consecutive failures and rejects calls for
+/// `cooldown`; afterwards calls go through again, and the next failure
+/// re-opens it until one succeeds
+#[derive(Debug)]
+struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            consecutive_failures: 0,
+            open_until: None,
+        }
+    }
+
+    /// Remaining cooldown when calls are currently rejected
+    fn rejecting(&self, now: Instant) -> Option<Duration> {
+        self.open_until
+            .filter(|until| *until > now)
+            .map(|until| until - now)
+    }
+
+    fn record(&mut self, success: bool, now: Instant) -> Option<BreakerEvent> {
+        if success {
+            self.consecutive_failures = 0;
+            return self.open_until.take().map(|_| BreakerEvent::Recovered);
+        }
+
+        self.consecutive_failures += 1;
+        if self.consecutive_failures < self.threshold {
+            return None;
+        }
+        let was_open = self.open_until.is_some();
+        self.open_until = Some(now + self.cooldown);
+        (!was_open).then_some(BreakerEvent::Tripped {
+            failures: self.consecutive_failures,
+        })
+    }
+}
+
+/// Provider wrapper that stops calling a failing provider for a while
+/// and reports trips / recoveries to Slack
+pub struct CircuitBreakerProvider {
+    inner: Box<dyn LlmProvider>,
+    breaker: Mutex<CircuitBreaker>,
+    slack: Option<SlackNotifier>,
+}
+
+/// Wrap `provider` in a circuit breaker (`LLM_BREAKER_THRESHOLD=0` disables it)
+pub fn with_circuit_breaker(
+    provider: Box<dyn LlmProvider>,
+    config: &LlmConfig,
+    slack: Option<SlackNotifier>,
+) -> Box<dyn LlmProvider> {
+    if config.breaker_threshold == 0 {
+        return provider;
+    }
+    Box::new(CircuitBreakerProvider {
+        inner: provider,
+        breaker: Mutex::new(CircuitBreaker::new(
+            config.breaker_threshold,
+            Duration::from_secs(config.breaker_cooldown_secs),
+        )),
+        slack,
+    })
+}
+
+impl CircuitBreakerProvider {
+    fn check(&self) -> Result<(), LlmError> {
+        let breaker = self.breaker.lock().unwrap();
+        match breaker.rejecting(Instant::now()) {
+            Some(remaining) => Err(LlmError::Provider(format!(
+                "circuit open after repeated failures, retry in {}s",
+                remaining.as_secs() + 1
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn record<T>(&self, result: &Result<T, LlmError>) {
+        let (event, cooldown) = {
+            let mut breaker = self.breaker.lock().unwrap();
+            (
+                breaker.record(result.is_ok(), Instant::now()),
+                breaker.cooldown,
+            )
+        };
+
+        let slack_event = match event {
+            Some(BreakerEvent::Tripped { failures }) => {
+                tracing::error!(
+                    "LLM provider {} circuit opened after {} failures",
+                    self.inner.name(),
+                    failures
+                );
+                SlackEvent::new(Severity::Critical, "LLM provider circuit opened")
+                    .field("provider", self.inner.name())
+                    .field("consecutive failures", failures)
+                    .field("cooldown", format!("{}s", cooldown.as_secs()))
+                    .text(
+                        result
+                            .as_ref()
+                            .err()
+                            .map(ToString::to_string)
+                            .unwrap_or_default(),
+                    )
+            }
+            Some(BreakerEvent::Recovered) => {
+                tracing::info!("LLM provider {} circuit closed", self.inner.name());
+                SlackEvent::new(Severity::Info, "LLM provider recovered")
+                    .field("provider", self.inner.name())
+            }
+            None => return,
+        };
+        if let Some(slack) = &self.slack {
+            slack.notify(slack_event);
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for CircuitBreakerProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn chat(&self, request: ChatRequest) -> Result<ChatCompletion, LlmError> {
+        self.check()?;
+        let result = self.inner.chat(request).await;
+        self.record(&result);
+        result
+    }
+
+    async fn embed(&self, request: EmbeddingRequest) -> Result<EmbeddingResponse, LlmError> {
+        self.check()?;
+        let result = self.inner.embed(request).await;
+        self.record(&result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_breaker() {
+        let start = Instant::now();
+        let cooldown = Duration::from_secs(30);
+        let mut breaker = CircuitBreaker::new(3, cooldown);
+
+        assert_eq!(breaker.record(false, start), None);
+        assert_eq!(breaker.record(false, start), None);
+        assert_eq!(
+            breaker.record(false, start),
+            Some(BreakerEvent::Tripped { failures: 3 })
+        );
+        assert!(breaker.rejecting(start + Duration::from_secs(10)).is_some());
+
+        // After the cooldown a failing trial re-opens without a new trip event
+        let later = start + cooldown;
+        assert_eq!(breaker.rejecting(later), None);
+        assert_eq!(breaker.record(false, later), None);
+        assert!(breaker.rejecting(later + Duration::from_secs(1)).is_some());
+
+        assert_eq!(
+            breaker.record(true, later + cooldown),
+            Some(BreakerEvent::Recovered)
+        );
+        assert_eq!(breaker.record(true, later + cooldown), None);
+    }
+}
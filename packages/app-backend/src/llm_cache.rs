@@ -0,0 +1,383 @@
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
+use chrono::{Duration, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::auth::AdminUser;
+use crate::config::LlmCacheConfig;
+use crate::embeddings::to_pgvector;
+use crate::error::internal_error;
+use crate::llm::{ChatCompletion, ChatMessage, ChatRequest, EmbeddingRequest};
+use crate::state::AppState;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheHit {
+    Exact,
+    Semantic,
+}
+
+/// Identity of a request in the cache
+pub struct CacheKey {
+    /// Canonical model + all messages
+    request: String,
+    /// Canonical model + all messages but the last one
+    scope: String,
+    /// Last message (embedded for semantic lookup)
+    prompt: String,
+    embedding: Option<Vec<f32>>,
+}
+
+impl CacheKey {
+    pub fn new(request: &ChatRequest) -> Self {
+        let canonical = |messages: &[ChatMessage]| {
+            let messages: Vec<(&str, &str)> = messages
+                .iter()
+                .map(|m| (m.role.as_str(), m.content.as_str()))
+                .collect();
+            serde_json::json!({ "model": request.model, "messages": messages }).to_string()
+        };
+        let (last, context) = match request.messages.split_last() {
+            Some((last, context)) => (last.content.clone(), context),
+            None => (String::new(), &request.messages[..]),
+        };
+
+        Self {
+            request: canonical(&request.messages),
+            scope: canonical(context),
+            prompt: last,
+            embedding: None,
+        }
+    }
+}
+
+/// Result of a lookup: the cached completion, or the key to store the fresh one under
+pub enum Lookup {
+    Hit(ChatCompletion, CacheHit),
+    Miss(CacheKey),
+}
+
+#[derive(Debug, Default)]
+struct Metrics {
+    exact_hits: AtomicU64,
+    semantic_hits: AtomicU64,
+    misses: AtomicU64,
+    bypassed: AtomicU64,
+    errors: AtomicU64,
+    /// Tokens the cached completions cost originally
+    tokens_saved: AtomicU64,
+}
+
+/// Counters since startup plus the current number of stored entries
+#[derive(Debug, Serialize)]
+pub struct CacheStats {
+    pub semantic: bool,
+    pub ttl_secs: i64,
+    pub exact_hits: u64,
+    pub semantic_hits: u64,
+    pub misses: u64,
+    pub bypassed: u64,
+    pub errors: u64,
+    /// Hits / (hits + misses)
+    pub hit_rate: Option<f64>,
+    pub tokens_saved: u64,
+    pub entries: i64,
+}
+
+#[derive(sqlx::FromRow)]
+struct CachedRow {
+    content: String,
+    model_used: String,
+    tokens_used: Option<i32>,
+}
+
+impl From<CachedRow> for ChatCompletion {
+    fn from(row: CachedRow) -> Self {
+        ChatCompletion {
+            content: row.content,
+            model_used: row.model_used,
+            tokens_used: row.tokens_used.map(|t| t as u32),
+        }
+    }
+}
+
+// ========================================
+// Cache
+// ========================================
+
+/// Response cache for agent-execute, stored in `llm_cache`
+#[derive(Clone)]
+pub struct LlmCache {
+    config: LlmCacheConfig,
+    metrics: Arc<Metrics>,
+}
+
+impl LlmCache {
+    /// Returns `None` when `LLM_CACHE_ENABLED=false`
+    pub fn from_config(config: &LlmCacheConfig) -> Option<Self> {
+        if !config.enabled {
+            tracing::info!("LLM response cache disabled (LLM_CACHE_ENABLED=false)");
+            return None;
+        }
+        Some(Self {
+            config: config.clone(),
+            metrics: Arc::new(Metrics::default()),
+        })
+    }
+
+    /// The request skipped the cache (`no_cache`)
+    pub fn record_bypass(&self) {
+        self.metrics.bypassed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Exact match first, then (when enabled) the most similar prompt in the same scope.
+    /// Cache errors are logged and count as a miss.
+    pub async fn lookup(&self, state: &AppState, request: &ChatRequest) -> Lookup {
+        let mut key = CacheKey::new(request);
+
+        match self.exact(&state.db, &key).await {
+            Ok(Some(completion)) => return self.hit(completion, CacheHit::Exact),
+            Ok(None) => {}
+            Err(e) => self.error("exact lookup", e),
+        }
+
+        if self.config.semantic && !key.prompt.trim().is_empty() {
+            key.embedding = self.embed(state, &key.prompt).await;
+            if let Some(embedding) = &key.embedding {
+                match self.similar(&state.db, &key, embedding).await {
+                    Ok(Some(completion)) => return self.hit(completion, CacheHit::Semantic),
+                    Ok(None) => {}
+                    Err(e) => self.error("semantic lookup", e),
+                }
+            }
+        }
+
+        self.metrics.misses.fetch_add(1, Ordering::Relaxed);
+        Lookup::Miss(key)
+    }
+
+    /// Store a fresh completion under the key returned by a missed lookup
+    pub async fn store(&self, db: &PgPool, key: CacheKey, completion: &ChatCompletion) {
+        let result = sqlx::query(
+            "INSERT INTO llm_cache
+               (request_hash, request, scope_hash, prompt, model_used, content, tokens_used,
+                embedding, expires_at)
+             VALUES (md5($1), $1, md5($2), $3, $4, $5, $6, $7::vector, $8)
+             ON CONFLICT (request_hash) DO UPDATE SET
+               model_used = EXCLUDED.model_used,
+               content = EXCLUDED.content,
+               tokens_used = EXCLUDED.tokens_used,
+               embedding = COALESCE(EXCLUDED.embedding, llm_cache.embedding),
+               expires_at = EXCLUDED.expires_at",
+        )
+        .bind(&key.request)
+        .bind(&key.scope)
+        .bind(&key.prompt)
+        .bind(&completion.model_used)
+        .bind(&completion.content)
+        .bind(completion.tokens_used.map(|t| t as i32))
+        .bind(key.embedding.as_deref().map(to_pgvector))
+        .bind(Utc::now() + Duration::seconds(self.config.ttl_secs))
+        .execute(db)
+        .await;
+
+        if let Err(e) = result {
+            self.error("store", e);
+        }
+    }
+
+    pub async fn stats(&self, db: &PgPool) -> Result<CacheStats, sqlx::Error> {
+        let entries: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM llm_cache WHERE expires_at > NOW()")
+                .fetch_one(db)
+                .await?;
+        let load = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+        let exact_hits = load(&self.metrics.exact_hits);
+        let semantic_hits = load(&self.metrics.semantic_hits);
+        let misses = load(&self.metrics.misses);
+
+        Ok(CacheStats {
+            semantic: self.config.semantic,
+            ttl_secs: self.config.ttl_secs,
+            exact_hits,
+            semantic_hits,
+            misses,
+            bypassed: load(&self.metrics.bypassed),
+            errors: load(&self.metrics.errors),
+            hit_rate: hit_rate(exact_hits + semantic_hits, misses),
+            tokens_saved: load(&self.metrics.tokens_saved),
+            entries,
+        })
+    }
+
+    async fn exact(&self, db: &PgPool, key: &CacheKey) -> Result<Option<CachedRow>, sqlx::Error> {
+        sqlx::query_as::<_, CachedRow>(
+            "UPDATE llm_cache SET hit_count = hit_count + 1, last_hit_at = NOW()
+             WHERE request_hash = md5($1) AND request = $1 AND expires_at > NOW()
+             RETURNING content, model_used, tokens_used",
+        )
+        .bind(&key.request)
+        .fetch_optional(db)
+        .await
+    }
+
+    async fn similar(
+        &self,
+        db: &PgPool,
+        key: &CacheKey,
+        embedding: &[f32],
+    ) -> Result<Option<CachedRow>, sqlx::Error> {
+        sqlx::query_as::<_, CachedRow>(
+            "UPDATE llm_cache SET hit_count = hit_count + 1, last_hit_at = NOW()
+             WHERE id = (
+               SELECT id FROM llm_cache
+               WHERE scope_hash = md5($1) AND embedding IS NOT NULL AND expires_at > NOW()
+                 AND 1 - (embedding <=> $2::vector) >= $3
+               ORDER BY embedding <=> $2::vector
+               LIMIT 1
+             )
+             RETURNING content, model_used, tokens_used",
+        )
+        .bind(&key.scope)
+        .bind(to_pgvector(embedding))
+        .bind(self.config.similarity_threshold)
+        .fetch_optional(db)
+        .await
+    }
+
+    async fn embed(&self, state: &AppState, prompt: &str) -> Option<Vec<f32>> {
+        let response = state
+            .llm
+            .embed(EmbeddingRequest {
+                model: None,
+                inputs: vec![prompt.to_string()],
+            })
+            .await;
+        match response {
+            Ok(response) => response.embeddings.into_iter().next(),
+            Err(e) => {
+                self.error("prompt embedding", e);
+                None
+            }
+        }
+    }
+
+    fn hit(&self, row: CachedRow, kind: CacheHit) -> Lookup {
+        let counter = match kind {
+            CacheHit::Exact => &self.metrics.exact_hits,
+            CacheHit::Semantic => &self.metrics.semantic_hits,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        let completion = ChatCompletion::from(row);
+        self.metrics.tokens_saved.fetch_add(
+            completion.tokens_used.unwrap_or(0) as u64,
+            Ordering::Relaxed,
+        );
+        Lookup::Hit(completion, kind)
+    }
+
+    fn error(&self, operation: &str, err: impl std::fmt::Display) {
+        self.metrics.errors.fetch_add(1, Ordering::Relaxed);
+        tracing::warn!("LLM cache {} failed: {}", operation, err);
+    }
+}
+
+fn hit_rate(hits: u64, misses: u64) -> Option<f64> {
+    let total = hits + misses;
+    (total > 0).then(|| hits as f64 / total as f64)
+}
+
+/// Scheduled task: delete expired entries
+pub async fn purge_expired(state: AppState) -> Result<String, String> {
+    let result = sqlx::query("DELETE FROM llm_cache WHERE expires_at < NOW()")
+        .execute(&state.db)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(format!("purged {} cache entries", result.rows_affected()))
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+async fn admin_cache_stats(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+) -> Result<Json<CacheStats>, StatusCode> {
+    let cache = state.llm_cache.as_ref().ok_or(StatusCode::NOT_FOUND)?;
+    cache
+        .stats(&state.db)
+        .await
+        .map(Json)
+        .map_err(internal_error)
+}
+
+/// Drop every cached completion (e.g. after changing prompts or models)
+async fn admin_clear_cache(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
+    tracing::info!("Admin {:?} clearing the LLM cache", admin.id);
+    let result = sqlx::query("DELETE FROM llm_cache")
+        .execute(&state.db)
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(
+        serde_json::json!({ "purged": result.rows_affected() }),
+    ))
+}
+
+// ========================================
+// Router Setup
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(
+        "/api/admin/llm/cache",
+        get(admin_cache_stats).delete(admin_clear_cache),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::ChatRole;
+
+    fn request(system: &str, task: &str) -> ChatRequest {
+        ChatRequest {
+            model: Some("gpt-4o-mini".to_string()),
+            messages: vec![
+                ChatMessage::new(ChatRole::System, system),
+                ChatMessage::new(ChatRole::User, task),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_cache_key() {
+        let a = CacheKey::new(&request("Be brief", "Summarize Rust"));
+        let b = CacheKey::new(&request("Be brief", "Summarize Rust"));
+        let other_task = CacheKey::new(&request("Be brief", "Summarize Go"));
+        let other_system = CacheKey::new(&request("Be verbose", "Summarize Rust"));
+
+        assert_eq!(a.request, b.request);
+        assert_eq!(a.prompt, "Summarize Rust");
+        // Same context: candidates for a semantic hit
+        assert_ne!(a.request, other_task.request);
+        assert_eq!(a.scope, other_task.scope);
+        // Different system prompt: never shares an entry
+        assert_ne!(a.scope, other_system.scope);
+    }
+
+    #[test]
+    fn test_hit_rate() {
+        assert_eq!(hit_rate(0, 0), None);
+        assert_eq!(hit_rate(3, 1), Some(0.75));
+    }
+}
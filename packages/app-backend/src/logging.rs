@@ -0,0 +1,235 @@
+use axum::{
+    body::Body,
+    extract::{MatchedPath, Request, State},
+    http::{header, HeaderMap, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use std::collections::BTreeMap;
+use std::time::Instant;
+use uuid::Uuid;
+
+use crate::state::AppState;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Header names that are always redacted
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "apikey",
+];
+
+/// Substrings marking header names, query parameters and JSON keys as secrets
+const SENSITIVE_MARKERS: &[&str] = &["key", "token", "secret", "password", "auth"];
+
+// ========================================
+// Redaction
+// ========================================
+
+fn is_sensitive_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SENSITIVE_HEADERS.contains(&name.as_str())
+        || SENSITIVE_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Header map for logging with credentials replaced by `[REDACTED]`
+pub fn redact_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_sensitive_name(name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                value.to_str().unwrap_or("<binary>").to_string()
+            };
+            (name.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+/// Query string with secret-looking parameter values redacted
+pub fn redact_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((name, _)) if is_sensitive_name(name) => format!("{}={}", name, REDACTED),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Recursively redact secret-looking keys in a JSON body
+pub fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive_name(key) {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// Deterministic sampling decision derived from the request id
+pub fn should_sample(request_id: Uuid, rate: f64) -> bool {
+    if rate >= 1.0 {
+        return true;
+    }
+    (request_id.as_u128() % 10_000) as f64 / 10_000.0 < rate
+}
+
+// ========================================
+// Middleware
+// ========================================
+
+/// Log a structured summary of every sampled request.
+///
+/// Bodies (prompts etc.) are only logged when `REQUEST_LOG_BODIES=true`;
+/// server errors are always logged regardless of sampling.
+pub async fn log_requests(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let config = &state.config.logging;
+    let request_id = Uuid::new_v4();
+    let started = Instant::now();
+
+    let method = request.method().clone();
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+    let query = request.uri().query().map(redact_query);
+    let headers = redact_headers(request.headers());
+
+    let (request, body) = if config.include_bodies {
+        capture_json_body(request, config.max_body_bytes).await
+    } else {
+        (request, None)
+    };
+
+    let mut response = next.run(request).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id.to_string()) {
+        response.headers_mut().insert("x-request-id", value);
+    }
+
+    let status = response.status();
+    if !status.is_server_error() && !should_sample(request_id, config.sample_rate) {
+        return response;
+    }
+
+    let latency_ms = started.elapsed().as_millis() as u64;
+    let response_bytes = response
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+
+    if status.is_server_error() {
+        tracing::error!(
+            target: "http",
+            %request_id, %method, %path, ?query, status = status.as_u16(), latency_ms,
+            ?response_bytes, ?headers, ?body,
+            "request failed"
+        );
+    } else {
+        tracing::info!(
+            target: "http",
+            %request_id, %method, %path, ?query, status = status.as_u16(), latency_ms,
+            ?response_bytes, ?headers, ?body,
+            "request completed"
+        );
+    }
+
+    response
+}
+
+/// Buffer a small JSON body for logging and rebuild the request
+async fn capture_json_body(
+    request: Request,
+    max_bytes: usize,
+) -> (Request, Option<serde_json::Value>) {
+    let is_json = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    let length = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+
+    // Only buffer bodies with a known, small size so streaming uploads are untouched
+    if !is_json || length.is_none_or(|len| len > max_bytes) {
+        return (request, None);
+    }
+
+    let (parts, body) = request.into_parts();
+    let bytes = match axum::body::to_bytes(body, max_bytes).await {
+        Ok(bytes) => bytes,
+        Err(_) => return (Request::from_parts(parts, Body::empty()), None),
+    };
+
+    let logged = serde_json::from_slice::<serde_json::Value>(&bytes)
+        .ok()
+        .map(|mut value| {
+            redact_json(&mut value);
+            value
+        });
+
+    (Request::from_parts(parts, Body::from(bytes)), logged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", "Bearer secret".parse().unwrap());
+        headers.insert("x-api-key", "sk-123".parse().unwrap());
+        headers.insert("content-type", "application/json".parse().unwrap());
+
+        let redacted = redact_headers(&headers);
+        assert_eq!(redacted["authorization"], REDACTED);
+        assert_eq!(redacted["x-api-key"], REDACTED);
+        assert_eq!(redacted["content-type"], "application/json");
+    }
+
+    #[test]
+    fn test_redact_query() {
+        assert_eq!(
+            redact_query("limit=10&access_token=abc"),
+            "limit=10&access_token=[REDACTED]"
+        );
+    }
+
+    #[test]
+    fn test_redact_json_nested() {
+        let mut value = serde_json::json!({
+            "prompt": "a cat",
+            "provider": { "api_key": "sk-123" },
+            "items": [{ "password": "hunter2" }]
+        });
+        redact_json(&mut value);
+        assert_eq!(value["prompt"], "a cat");
+        assert_eq!(value["provider"]["api_key"], REDACTED);
+        assert_eq!(value["items"][0]["password"], REDACTED);
+    }
+
+    #[test]
+    fn test_should_sample_bounds() {
+        let id = Uuid::new_v4();
+        assert!(should_sample(id, 1.0));
+        assert!(!should_sample(id, 0.0));
+    }
+}
@@ -1,14 +1,57 @@
+mod auth;
+mod config;
+mod conversations;
+mod cors;
 mod db;
+mod email;
+mod embeddings;
+mod error;
+mod experiments;
+mod feedback;
+mod gallery;
+mod idempotency;
+mod images;
+mod inpaint;
+mod jobs;
+mod limits;
+mod llm;
+mod llm_cache;
+mod logging;
+mod mock;
+mod pagination;
+mod rag;
+mod retention;
+mod shares;
+mod slack;
+mod speech;
+mod state;
+mod storage;
+mod tasks;
+mod telemetry;
+mod template;
 
 use axum::{
+    extract::State,
+    middleware,
     routing::{get, post},
     Router,
     Json,
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     response::IntoResponse,
 };
 use serde::{Deserialize, Serialize};
-use tower_http::cors::{CorsLayer, Any};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use auth::OptionalUser;
+use config::AppConfig;
+use experiments::{ExperimentAssignment, ExperimentRun};
+use images::{GeneratedImage, TextToImageJob};
+use jobs::Job;
+use llm::{ChatMessage, ChatRequest, ChatRole};
+use llm_cache::{CacheHit, Lookup};
+use state::AppState;
 
 // ========================================
 // AIGen API Models
@@ -20,12 +63,28 @@ struct TextToImageRequest {
     model: Option<String>,
     width: Option<u32>,
     height: Option<u32>,
+    /// Number of images (1-4, default 1)
+    num_images: Option<u32>,
+    /// Seed of the first image; the others use the following seeds (random when omitted)
+    seed: Option<u32>,
+    /// Let a running experiment pick the prompt template / model
+    experiment_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
 struct TextToImageResponse {
-    image_url: String,
+    /// URL of the first image (same as `images[0].url`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image_url: Option<String>,
+    /// Placeholder images (mock mode without storage)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    images: Vec<GeneratedImage>,
+    /// Background job generating and storing the images (`result.images[]`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job: Option<Job>,
     model_used: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    experiment: Option<ExperimentAssignment>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -47,6 +106,11 @@ struct AgentExecuteRequest {
     task: String,
     model: Option<String>,
     system_prompt: Option<String>,
+    /// Let a running experiment pick the prompt / model
+    experiment_id: Option<Uuid>,
+    /// Skip the response cache (neither read nor written)
+    #[serde(default)]
+    no_cache: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -54,6 +118,11 @@ struct AgentExecuteResponse {
     result: String,
     model_used: String,
     tokens_used: Option<u32>,
+    /// Served from the response cache ("exact" or "semantic")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cache: Option<CacheHit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    experiment: Option<ExperimentAssignment>,
 }
 
 // ========================================
@@ -72,46 +141,204 @@ async fn health_check() -> impl IntoResponse {
 // ========================================
 
 /// Text-to-Image endpoint
+///
+/// The images are generated by a background job and stored like the other
+/// image outputs (202 with `job`). In mock mode without storage, placeholder
+/// URLs are returned directly.
 async fn text_to_image(
-    Json(payload): Json<TextToImageRequest>,
-) -> Result<Json<TextToImageResponse>, StatusCode> {
-    tracing::info!("Text-to-Image request: {:?}", payload);
+    State(state): State<AppState>,
+    user: OptionalUser,
+    headers: HeaderMap,
+    Json(mut payload): Json<TextToImageRequest>,
+) -> Result<(StatusCode, Json<TextToImageResponse>), StatusCode> {
+    let started = Instant::now();
+    let seeds =
+        images::seeds(payload.seed, payload.num_images).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    let variant = match payload.experiment_id {
+        Some(id) => experiments::assign(&state.db, id, user.id()).await?,
+        None => None,
+    };
+    if let Some(variant) = &variant {
+        payload.prompt = variant.apply_prompt(&payload.prompt);
+        payload.model = variant.model.clone().or(payload.model);
+    }
+    tracing::info!(
+        "Text-to-Image request (model: {:?}, images: {})",
+        payload.model,
+        seeds.len()
+    );
 
-    // TODO: Implement actual image generation logic
-    // For now, return a placeholder response
+    let job = TextToImageJob {
+        prompt: payload.prompt.clone(),
+        model: payload.model.clone(),
+        width: payload.width.unwrap_or(images::DEFAULT_IMAGE_SIZE),
+        height: payload.height.unwrap_or(images::DEFAULT_IMAGE_SIZE),
+        seeds: seeds.clone(),
+    };
+    let job = images::enqueue_text_to_image(&state, user.id(), job).await?;
+    let images: Vec<GeneratedImage> = match &job {
+        Some(_) => Vec::new(),
+        None if state.config.mock_providers() => {
+            let size = |value: Option<u32>| {
+                value
+                    .unwrap_or(mock::DEFAULT_IMAGE_SIZE)
+                    .clamp(16, mock::MAX_IMAGE_SIZE)
+            };
+            seeds
+                .into_iter()
+                .map(|seed| {
+                    // The seed leads the text (long prompts are cut), so each image gets its own color
+                    let text = format!("#{} {}", seed, payload.prompt);
+                    let url = mock::placeholder_url(
+                        &headers,
+                        size(payload.width),
+                        size(payload.height),
+                        &text,
+                    );
+                    GeneratedImage { url, seed }
+                })
+                .collect()
+        }
+        // Generated images need somewhere to be stored
+        None => return Err(StatusCode::SERVICE_UNAVAILABLE),
+    };
 
-    Ok(Json(TextToImageResponse {
-        image_url: "https://placeholder.example.com/generated-image.png".to_string(),
-        model_used: payload.model.unwrap_or_else(|| "default-model".to_string()),
-    }))
+    let experiment = match (payload.experiment_id, &variant) {
+        (Some(experiment_id), Some(variant)) => {
+            experiments::record_result(
+                &state.db,
+                ExperimentRun {
+                    experiment_id,
+                    variant,
+                    user_id: user.id(),
+                    endpoint: "text-to-image",
+                    started,
+                    succeeded: true,
+                    tokens_used: None,
+                },
+            )
+            .await
+        }
+        _ => None,
+    };
+
+    let status = if job.is_some() {
+        StatusCode::ACCEPTED
+    } else {
+        StatusCode::OK
+    };
+    Ok((
+        status,
+        Json(TextToImageResponse {
+            image_url: images.first().map(|image| image.url.clone()),
+            images,
+            job,
+            model_used: payload.model.unwrap_or_else(|| "default-model".to_string()),
+            experiment,
+        }),
+    ))
 }
 
 /// Image-to-Image endpoint
 async fn image_to_image(
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(payload): Json<ImageToImageRequest>,
 ) -> Result<Json<ImageToImageResponse>, StatusCode> {
-    tracing::info!("Image-to-Image request: {:?}", payload);
+    tracing::info!("Image-to-Image request (model: {:?})", payload.model);
 
     // TODO: Implement actual img2img logic
+    let image_url = if state.config.mock_providers() {
+        let size = mock::DEFAULT_IMAGE_SIZE;
+        mock::placeholder_url(&headers, size, size, &payload.prompt)
+    } else {
+        "https://placeholder.example.com/transformed-image.png".to_string()
+    };
 
     Ok(Json(ImageToImageResponse {
-        image_url: "https://placeholder.example.com/transformed-image.png".to_string(),
+        image_url,
         model_used: payload.model.unwrap_or_else(|| "default-model".to_string()),
     }))
 }
 
-/// Agent execution endpoint
+/// Agent execution endpoint (single turn, see conversations for multi-turn)
 async fn agent_execute(
-    Json(payload): Json<AgentExecuteRequest>,
+    State(state): State<AppState>,
+    user: OptionalUser,
+    Json(mut payload): Json<AgentExecuteRequest>,
 ) -> Result<Json<AgentExecuteResponse>, StatusCode> {
-    tracing::info!("Agent execute request: {:?}", payload);
+    let started = Instant::now();
+    let variant = match payload.experiment_id {
+        Some(id) => experiments::assign(&state.db, id, user.id()).await?,
+        None => None,
+    };
+    if let Some(variant) = &variant {
+        payload.task = variant.apply_prompt(&payload.task);
+        payload.model = variant.model.clone().or(payload.model);
+        payload.system_prompt = variant.system_prompt.clone().or(payload.system_prompt);
+    }
+    tracing::info!("Agent execute request (model: {:?})", payload.model);
+
+    let mut messages = Vec::new();
+    if let Some(system_prompt) = payload.system_prompt {
+        messages.push(ChatMessage::new(ChatRole::System, system_prompt));
+    }
+    messages.push(ChatMessage::new(ChatRole::User, payload.task));
+    let request = ChatRequest {
+        model: payload.model,
+        messages,
+    };
+
+    let lookup = match &state.llm_cache {
+        Some(cache) if payload.no_cache => {
+            cache.record_bypass();
+            None
+        }
+        Some(cache) => Some(cache.lookup(&state, &request).await),
+        None => None,
+    };
+    let (completion, cache) = match lookup {
+        Some(Lookup::Hit(completion, hit)) => (Ok(completion), Some(hit)),
+        Some(Lookup::Miss(key)) => {
+            let completion = state.llm.chat(request).await;
+            if let (Ok(completion), Some(cache)) = (&completion, &state.llm_cache) {
+                cache.store(&state.db, key, completion).await;
+            }
+            (completion, None)
+        }
+        None => (state.llm.chat(request).await, None),
+    };
+
+    let experiment = match (payload.experiment_id, &variant) {
+        (Some(experiment_id), Some(variant)) => {
+            experiments::record_result(
+                &state.db,
+                ExperimentRun {
+                    experiment_id,
+                    variant,
+                    user_id: user.id(),
+                    endpoint: "agent-execute",
+                    started,
+                    succeeded: completion.is_ok(),
+                    tokens_used: completion.as_ref().ok().and_then(|c| c.tokens_used),
+                },
+            )
+            .await
+        }
+        _ => None,
+    };
 
-    // TODO: Implement actual LLM agent execution with LLM_TOOLKIT
+    let completion = completion.map_err(|e| {
+        tracing::error!("Agent execute failed: {}", e);
+        e.status()
+    })?;
 
     Ok(Json(AgentExecuteResponse {
-        result: format!("Task '{}' executed successfully (placeholder)", payload.task),
-        model_used: payload.model.unwrap_or_else(|| "default-llm-model".to_string()),
-        tokens_used: Some(150),
+        result: completion.content,
+        model_used: completion.model_used,
+        tokens_used: completion.tokens_used,
+        cache,
+        experiment,
     }))
 }
 
@@ -119,35 +346,179 @@ async fn agent_execute(
 // Router Setup
 // ========================================
 
-fn create_router() -> Router {
+fn create_router(state: AppState) -> Router {
     Router::new()
         .route("/health", get(health_check))
         .route("/api/aigen/text-to-image", post(text_to_image))
         .route("/api/aigen/image-to-image", post(image_to_image))
         .route("/api/aigen/agent-execute", post(agent_execute))
-        .layer(
-            CorsLayer::new()
-                .allow_origin(Any)
-                .allow_methods(Any)
-                .allow_headers(Any),
-        )
+        .merge(conversations::router())
+        .merge(embeddings::router())
+        .merge(rag::router())
+        .merge(speech::router())
+        .merge(images::router())
+        .merge(inpaint::router())
+        .merge(gallery::router())
+        .merge(shares::router())
+        .merge(experiments::router())
+        .merge(feedback::router())
+        .merge(llm_cache::router())
+        .merge(limits::router())
+        .merge(retention::router())
+        .merge(mock::router())
+        .merge(jobs::router())
+        .merge(tasks::router())
+        .merge(email::router())
+        .layer(middleware::from_fn_with_state(state.clone(), idempotency::idempotency))
+        .layer(middleware::from_fn_with_state(state.clone(), logging::log_requests))
+        .layer(cors::cors_layer(&state.config.cors, state.config.production))
+        .layer(middleware::from_fn(telemetry::trace_requests))
+        .with_state(state)
 }
 
+// ========================================
+// Startup (shared by both entry points)
+// ========================================
+
+/// Config from the environment (.env), providers, background tasks and the router
+async fn init_app() -> Result<Router, sqlx::Error> {
+    dotenvy::dotenv().ok();
+    let config = AppConfig::from_env();
+
+    let db = db::init_db_pool().await?;
+    let slack = slack::SlackNotifier::from_config(&config.slack, config.production);
+    if config.mock.enabled && config.production {
+        tracing::error!("MOCK_PROVIDERS is ignored in production");
+    }
+    let (llm, images): (Box<dyn llm::LlmProvider>, Box<dyn images::ImageProvider>) =
+        if config.mock_providers() {
+            tracing::warn!("Mock providers enabled: no external AI APIs will be called");
+            (
+                Box::new(mock::MockLlmProvider::new(&config)),
+                Box::new(mock::MockImageProvider),
+            )
+        } else {
+            (
+                llm::provider_from_config(&config.llm),
+                images::image_provider_from_config(&config.image),
+            )
+        };
+    tracing::info!("LLM provider: {}", llm.name());
+    let llm = llm::with_circuit_breaker(llm, &config.llm, slack.clone());
+    let speech = speech::speech_provider_from_config(&config);
+    tracing::info!("Image provider: {}", images.name());
+    let limits = limits::ProviderLimits::from_config(&config.limits);
+    telemetry::observe_limits(&limits);
+    let llm = limits::limit_llm(llm, limits.llm.clone());
+    let images = limits::limit_images(images, limits.image.clone());
+    let speech = speech.map(|speech| limits::limit_speech(speech, limits.speech.clone()));
+    let storage = storage::StorageClient::from_config(&config.storage);
+    let email = email::Mailer::from_config(&config.email);
+    let llm_cache = llm_cache::LlmCache::from_config(&config.llm_cache);
+
+    let state = AppState {
+        db: db.clone(),
+        llm: Arc::from(llm),
+        llm_cache,
+        speech: speech.map(Arc::from),
+        images: Arc::from(images),
+        limits,
+        storage,
+        email,
+        slack,
+        jobs: jobs::JobStore::with_db(db.clone()),
+        idempotency: idempotency::IdempotencyStore::new(Duration::from_secs(
+            config.idempotency.ttl_secs,
+        )),
+        tasks: tasks::Scheduler::new(),
+        config: Arc::new(config),
+    };
+
+    email::spawn_job_notifier(state.clone());
+    slack::spawn_job_failure_monitor(&state);
+    slack::spawn_lifecycle_notices(&state);
+
+    images::register_resumers(&state.jobs).await;
+    inpaint::register_resumers(&state.jobs).await;
+    jobs::resume_interrupted(&state).await;
+
+    // Registered even when disabled so /api/admin/tasks can list and run them
+    tasks::register_default_tasks(&state.tasks, &state.config.tasks).await;
+    if state.config.tasks.enabled {
+        state.tasks.start(state.clone());
+    } else {
+        tracing::info!("Task scheduler disabled (TASKS_ENABLED=false)");
+    }
+
+    Ok(create_router(state))
+}
+
+#[cfg(not(any(feature = "shuttle", feature = "standalone")))]
+compile_error!("Enable the `shuttle` (default) or `standalone` feature");
+
 // ========================================
 // Shuttle Entry Point
 // ========================================
 
+#[cfg(all(feature = "shuttle", not(feature = "standalone")))]
 #[shuttle_runtime::main]
 async fn main() -> shuttle_axum::ShuttleAxum {
-    // Initialize tracing
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "info".into()),
-        )
-        .init();
-
-    let router = create_router();
+    telemetry::init();
+    let router = init_app()
+        .await
+        .map_err(shuttle_runtime::CustomError::new)?;
 
     Ok(router.into())
 }
+
+// ========================================
+// Standalone Entry Point
+// ========================================
+//
+// Plain axum + tokio for container hosts and integration tests:
+//   cargo run --no-default-features --features standalone
+// Listens on HOST:PORT (default 0.0.0.0:8000).
+
+#[cfg(feature = "standalone")]
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    telemetry::init();
+    let router = init_app().await?;
+
+    let host = std::env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
+    let port = std::env::var("PORT").unwrap_or_else(|_| "8000".to_string());
+    let listener = tokio::net::TcpListener::bind(format!("{}:{}", host, port)).await?;
+    tracing::info!("Listening on http://{}", listener.local_addr()?);
+
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async {
+            shutdown_signal().await;
+            tracing::info!("Shutting down");
+        })
+        .await?;
+    telemetry::shutdown();
+    Ok(())
+}
+
+/// Ctrl+C, or SIGTERM from the container runtime (also awaited by the
+/// Slack lifecycle notices)
+pub(crate) async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c().await.ok();
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        if let Ok(mut signal) =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+        {
+            signal.recv().await;
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
@@ -0,0 +1,559 @@
+use async_trait::async_trait;
+use axum::{
+    body::Bytes,
+    extract::{Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+use serde::Deserialize;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io::Cursor;
+use std::time::Duration;
+
+use crate::config::AppConfig;
+use crate::images::{
+    resize_png, GenerateOptions, ImageProvider, InpaintOptions, ProcessedImage, SourceImage,
+    VariationOptions,
+};
+use crate::llm::{
+    estimate_tokens, hashed_embedding, ChatCompletion, ChatRequest, ChatRole, EmbeddingRequest,
+    EmbeddingResponse, LlmError, LlmProvider,
+};
+use crate::state::AppState;
+
+pub const DEFAULT_IMAGE_SIZE: u32 = 512;
+pub const MAX_IMAGE_SIZE: u32 = 2048;
+/// Longest text drawn on a placeholder image
+const MAX_IMAGE_TEXT: usize = 120;
+
+// ========================================
+// Canned Responses
+// ========================================
+
+/// `response` is returned when the last user message contains `match` (case-insensitive).
+/// `{{task}}`, `{{model}}` and `{{system}}` are replaced with the request values.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CannedResponse {
+    #[serde(rename = "match")]
+    pub pattern: String,
+    pub response: String,
+}
+
+fn canned(pattern: &str, response: &str) -> CannedResponse {
+    CannedResponse {
+        pattern: pattern.to_string(),
+        response: response.to_string(),
+    }
+}
+
+fn builtin_responses() -> Vec<CannedResponse> {
+    vec![
+        canned("json", "{\"result\": \"mock\", \"task\": \"{{task}}\"}"),
+        canned(
+            "summar",
+            "Summary (mock): {{task}}\n\n- Key point one\n- Key point two\n- Key point three",
+        ),
+        canned("translate", "[translated by {{model}}] {{task}}"),
+        canned(
+            "",
+            "Mock response from {{model}} for: {{task}}\n\nThis text is canned; set MOCK_PROVIDERS=0 to call the real provider.",
+        ),
+    ]
+}
+
+/// Canned responses from `MOCK_RESPONSES_FILE` (checked first), then the built-in ones
+fn load_responses(path: Option<&str>) -> Vec<CannedResponse> {
+    let mut responses = Vec::new();
+    if let Some(path) = path {
+        match std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                serde_json::from_str::<Vec<CannedResponse>>(&json).map_err(|e| e.to_string())
+            }) {
+            Ok(custom) => responses = custom,
+            Err(e) => tracing::warn!("Ignoring MOCK_RESPONSES_FILE {}: {}", path, e),
+        }
+    }
+    responses.extend(builtin_responses());
+    responses
+}
+
+fn render(template: &str, task: &str, model: &str, system: &str) -> String {
+    template
+        .replace("{{task}}", task)
+        .replace("{{model}}", model)
+        .replace("{{system}}", system)
+}
+
+// ========================================
+// Mock LLM Provider
+// ========================================
+
+/// Canned, parametrized chat responses and hashed embeddings (no API key needed)
+pub struct MockLlmProvider {
+    responses: Vec<CannedResponse>,
+    latency: Duration,
+    default_model: String,
+    embedding_dimensions: usize,
+}
+
+impl MockLlmProvider {
+    pub fn new(config: &AppConfig) -> Self {
+        Self {
+            responses: load_responses(config.mock.responses_file.as_deref()),
+            latency: Duration::from_millis(config.mock.latency_ms),
+            default_model: config.llm.default_model.clone(),
+            embedding_dimensions: config.llm.embedding_dimensions,
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for MockLlmProvider {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    async fn chat(&self, request: ChatRequest) -> Result<ChatCompletion, LlmError> {
+        tokio::time::sleep(self.latency).await;
+
+        let find = |role: ChatRole| {
+            request
+                .messages
+                .iter()
+                .rev()
+                .find(|m| m.role == role)
+                .map(|m| m.content.as_str())
+                .unwrap_or_default()
+        };
+        let task = find(ChatRole::User);
+        let model = request.model.as_deref().unwrap_or(&self.default_model);
+        let lowered = task.to_lowercase();
+        let template = self
+            .responses
+            .iter()
+            .find(|r| lowered.contains(&r.pattern.to_lowercase()))
+            .map(|r| r.response.as_str())
+            .unwrap_or("{{task}}");
+        let content = render(template, task, model, find(ChatRole::System));
+
+        let tokens: usize = request
+            .messages
+            .iter()
+            .map(|m| estimate_tokens(&m.content))
+            .sum::<usize>()
+            + estimate_tokens(&content);
+
+        Ok(ChatCompletion {
+            content,
+            model_used: model.to_string(),
+            tokens_used: Some(tokens as u32),
+        })
+    }
+
+    async fn embed(&self, request: EmbeddingRequest) -> Result<EmbeddingResponse, LlmError> {
+        Ok(EmbeddingResponse {
+            embeddings: request
+                .inputs
+                .iter()
+                .map(|text| hashed_embedding(text, self.embedding_dimensions))
+                .collect(),
+            model_used: "mock-embedding".to_string(),
+        })
+    }
+}
+
+// ========================================
+// Mock Image Provider
+// ========================================
+
+/// Local upscaling; background removal returns the source unchanged (as PNG),
+/// generated images are placeholders led by the seed, variations are the
+/// source with its hue rotated by the seed and inpainting fills the masked
+/// area with a color derived from the prompt
+pub struct MockImageProvider;
+
+#[async_trait]
+impl ImageProvider for MockImageProvider {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    async fn upscale(&self, image: SourceImage, scale: u32) -> Result<ProcessedImage, LlmError> {
+        let png = tokio::task::spawn_blocking(move || resize_png(&image.data, scale as f32))
+            .await
+            .map_err(|e| LlmError::Provider(e.to_string()))?
+            .map_err(LlmError::Provider)?;
+        Ok(png_image(png))
+    }
+
+    async fn remove_background(&self, image: SourceImage) -> Result<ProcessedImage, LlmError> {
+        let png = tokio::task::spawn_blocking(move || resize_png(&image.data, 1.0))
+            .await
+            .map_err(|e| LlmError::Provider(e.to_string()))?
+            .map_err(LlmError::Provider)?;
+        Ok(png_image(png))
+    }
+
+    async fn generate(&self, options: &GenerateOptions) -> Result<ProcessedImage, LlmError> {
+        let size = |value: u32| value.clamp(16, MAX_IMAGE_SIZE);
+        let (width, height) = (size(options.width), size(options.height));
+        // The seed leads the text (long prompts are cut), so each image gets its own color
+        let text = format!("#{} {}", options.seed, options.prompt);
+        let png = tokio::task::spawn_blocking(move || placeholder_png(width, height, &text))
+            .await
+            .map_err(|e| LlmError::Provider(e.to_string()))?
+            .map_err(LlmError::Provider)?;
+        Ok(png_image(png))
+    }
+
+    async fn variation(
+        &self,
+        image: SourceImage,
+        options: &VariationOptions,
+    ) -> Result<ProcessedImage, LlmError> {
+        let degrees = (options.seed % 360) as i32;
+        let png = tokio::task::spawn_blocking(move || rotate_hue_png(&image.data, degrees))
+            .await
+            .map_err(|e| LlmError::Provider(e.to_string()))?
+            .map_err(LlmError::Provider)?;
+        Ok(png_image(png))
+    }
+
+    async fn inpaint(
+        &self,
+        image: SourceImage,
+        mask: Bytes,
+        options: &InpaintOptions,
+    ) -> Result<ProcessedImage, LlmError> {
+        let color = text_color(&options.prompt);
+        let png = tokio::task::spawn_blocking(move || fill_mask_png(&image.data, &mask, color))
+            .await
+            .map_err(|e| LlmError::Provider(e.to_string()))?
+            .map_err(LlmError::Provider)?;
+        Ok(png_image(png))
+    }
+}
+
+/// `source` with the white pixels of `mask` painted `color`
+fn fill_mask_png(source: &[u8], mask: &[u8], color: image::Rgb<u8>) -> Result<Vec<u8>, String> {
+    let mut canvas = image::load_from_memory(source)
+        .map_err(|e| e.to_string())?
+        .to_rgba8();
+    let mask = image::load_from_memory(mask)
+        .map_err(|e| e.to_string())?
+        .to_luma8();
+    for (x, y, pixel) in canvas.enumerate_pixels_mut() {
+        if mask.get_pixel_checked(x, y).is_some_and(|m| m[0] > 127) {
+            let [r, g, b] = color.0;
+            *pixel = image::Rgba([r, g, b, 255]);
+        }
+    }
+
+    let mut output = Cursor::new(Vec::new());
+    canvas
+        .write_to(&mut output, image::ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok(output.into_inner())
+}
+
+fn rotate_hue_png(data: &[u8], degrees: i32) -> Result<Vec<u8>, String> {
+    let image = image::load_from_memory(data).map_err(|e| e.to_string())?;
+    let mut output = Cursor::new(Vec::new());
+    image
+        .huerotate(degrees)
+        .write_to(&mut output, image::ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok(output.into_inner())
+}
+
+fn png_image(png: Vec<u8>) -> ProcessedImage {
+    ProcessedImage {
+        data: Bytes::from(png),
+        content_type: "image/png".to_string(),
+        extension: "png",
+    }
+}
+
+// ========================================
+// Placeholder Images
+// ========================================
+
+/// 5x7 bitmap glyph (low 5 bits of each row); lowercase is drawn as uppercase
+fn glyph(c: char) -> [u8; 7] {
+    match c.to_ascii_uppercase() {
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1E],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        ' ' => [0; 7],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        '!' => [0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '/' => [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00],
+        _ => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04],
+    }
+}
+
+/// Color derived from `text` (same text, same color)
+fn text_color(text: &str) -> image::Rgb<u8> {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    let [r, g, b, ..] = hasher.finish().to_le_bytes();
+    image::Rgb([r, g, b])
+}
+
+/// Greedy word wrap to `width` characters (long words are split)
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+        let word: String = word.into_iter().collect();
+        if line.is_empty() {
+            line = word;
+        } else if line.chars().count() + 1 + word.chars().count() <= width {
+            line.push(' ');
+            line.push_str(&word);
+        } else {
+            lines.push(std::mem::replace(&mut line, word));
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Solid background (derived from the text, so the same prompt gives the same color)
+/// with the text and the image size drawn on top, encoded as PNG
+pub fn placeholder_png(width: u32, height: u32, text: &str) -> Result<Vec<u8>, String> {
+    let background = text_color(text);
+    let [r, g, b] = background.0;
+    let luminance = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
+    let foreground = if luminance > 140.0 {
+        image::Rgb([0, 0, 0])
+    } else {
+        image::Rgb([255, 255, 255])
+    };
+    let mut canvas = image::RgbImage::from_pixel(width, height, background);
+
+    // Each glyph cell is 6x9 font pixels (including spacing)
+    let scale = (width / 160).clamp(1, 8);
+    let columns = ((width.saturating_sub(2 * 8 * scale)) / (6 * scale)).max(1) as usize;
+    let rows = (height / (9 * scale)).max(1) as usize;
+    let text: String = text.chars().take(MAX_IMAGE_TEXT).collect();
+    let mut lines = wrap(&text, columns);
+    lines.truncate(rows.saturating_sub(2).max(1));
+    lines.push(String::new());
+    lines.push(format!("{}x{}", width, height));
+
+    let block_height = lines.len() as u32 * 9 * scale;
+    let top = height.saturating_sub(block_height) / 2;
+    for (row, line) in lines.iter().enumerate() {
+        let line_width = line.chars().count() as u32 * 6 * scale;
+        let left = width.saturating_sub(line_width) / 2;
+        let y0 = top + row as u32 * 9 * scale;
+        for (column, c) in line.chars().enumerate() {
+            let x0 = left + column as u32 * 6 * scale;
+            for (gy, bits) in glyph(c).iter().enumerate() {
+                for gx in 0..5u32 {
+                    if bits & (0x10 >> gx) == 0 {
+                        continue;
+                    }
+                    for dy in 0..scale {
+                        for dx in 0..scale {
+                            let (x, y) = (x0 + gx * scale + dx, y0 + gy as u32 * scale + dy);
+                            if x < width && y < height {
+                                canvas.put_pixel(x, y, foreground);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut output = Cursor::new(Vec::new());
+    canvas
+        .write_to(&mut output, image::ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok(output.into_inner())
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// URL of a placeholder image served by this backend (`/api/mock/images`)
+pub fn placeholder_url(headers: &HeaderMap, width: u32, height: u32, text: &str) -> String {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    let origin = match header(header::HOST.as_str()) {
+        Some(host) => format!(
+            "{}://{}",
+            header("x-forwarded-proto").unwrap_or("http"),
+            host
+        ),
+        None => String::new(),
+    };
+    let text: String = text.chars().take(MAX_IMAGE_TEXT).collect();
+    format!(
+        "{}/api/mock/images/placeholder.png?w={}&h={}&text={}",
+        origin,
+        width,
+        height,
+        percent_encode(&text)
+    )
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+#[derive(Debug, Deserialize)]
+struct PlaceholderQuery {
+    w: Option<u32>,
+    h: Option<u32>,
+    #[serde(default)]
+    text: String,
+}
+
+/// Render a placeholder PNG (only in mock mode)
+async fn placeholder_image(
+    State(state): State<AppState>,
+    Query(query): Query<PlaceholderQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    if !state.config.mock_providers() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let size = |value: Option<u32>| value.unwrap_or(DEFAULT_IMAGE_SIZE);
+    let (width, height) = (size(query.w), size(query.h));
+    if !(16..=MAX_IMAGE_SIZE).contains(&width) || !(16..=MAX_IMAGE_SIZE).contains(&height) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let png = tokio::task::spawn_blocking(move || placeholder_png(width, height, &query.text))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(|e| {
+            tracing::error!("Failed to render placeholder image: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "image/png"),
+            (header::CACHE_CONTROL, "public, max-age=86400"),
+        ],
+        png,
+    ))
+}
+
+// ========================================
+// Router Setup
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new().route("/api/mock/images/placeholder.png", get(placeholder_image))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::ChatMessage;
+
+    #[test]
+    fn test_placeholder_png() {
+        let png = placeholder_png(320, 200, "A beautiful sunset over the ocean").unwrap();
+        let image = image::load_from_memory(&png).unwrap();
+        assert_eq!((image.width(), image.height()), (320, 200));
+
+        // Same text, same image
+        assert_eq!(
+            png,
+            placeholder_png(320, 200, "A beautiful sunset over the ocean").unwrap()
+        );
+        assert_eq!(
+            wrap("a bb ccc dddddd", 4),
+            vec!["a bb", "ccc", "dddd", "dd"]
+        );
+        assert_eq!(percent_encode("a b&c"), "a%20b%26c");
+    }
+
+    #[tokio::test]
+    async fn test_mock_chat_uses_canned_responses() {
+        let provider = MockLlmProvider {
+            responses: load_responses(None),
+            latency: Duration::ZERO,
+            default_model: "gpt-4o-mini".to_string(),
+            embedding_dimensions: 8,
+        };
+        let request = |task: &str| ChatRequest {
+            model: None,
+            messages: vec![ChatMessage::new(ChatRole::User, task)],
+        };
+
+        let summary = provider.chat(request("Summarize this")).await.unwrap();
+        assert!(summary
+            .content
+            .starts_with("Summary (mock): Summarize this"));
+        assert_eq!(summary.model_used, "gpt-4o-mini");
+
+        let fallback = provider.chat(request("Hello")).await.unwrap();
+        assert!(fallback
+            .content
+            .contains("Mock response from gpt-4o-mini for: Hello"));
+    }
+}
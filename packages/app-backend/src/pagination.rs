@@ -0,0 +1,293 @@
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query},
+    http::{request::Parts, StatusCode},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use uuid::Uuid;
+
+pub const DEFAULT_PAGE_LIMIT: u32 = 20;
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+// ========================================
+// Pagination
+// ========================================
+
+#[derive(Debug, Deserialize)]
+struct PageQuery {
+    page: Option<u32>,
+    limit: Option<u32>,
+    cursor: Option<String>,
+}
+
+/// Keyset position `(created_at, id)` of the last item on a page
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        format!(
+            "{}_{}",
+            self.created_at.timestamp_micros(),
+            self.id.simple()
+        )
+    }
+
+    pub fn decode(value: &str) -> Option<Self> {
+        let (micros, id) = value.split_once('_')?;
+        Some(Self {
+            created_at: DateTime::from_timestamp_micros(micros.parse().ok()?)?,
+            id: Uuid::parse_str(id).ok()?,
+        })
+    }
+}
+
+/// `?page=&limit=&cursor=` query extractor.
+///
+/// `page` is 1-based. When `cursor` is present, endpoints that support
+/// keyset pagination use it instead of `page`.
+#[derive(Debug, Clone)]
+pub struct Pagination {
+    pub page: u32,
+    pub limit: u32,
+    pub cursor: Option<Cursor>,
+}
+
+impl Pagination {
+    pub fn offset(&self) -> i64 {
+        (self.page as i64 - 1) * self.limit as i64
+    }
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            limit: DEFAULT_PAGE_LIMIT,
+            cursor: None,
+        }
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Pagination {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(query) = Query::<PageQuery>::from_request_parts(parts, state)
+            .await
+            .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+        let cursor = match query.cursor.as_deref() {
+            Some(value) => Some(Cursor::decode(value).ok_or(StatusCode::BAD_REQUEST)?),
+            None => None,
+        };
+
+        Ok(Pagination {
+            page: query.page.unwrap_or(1).max(1),
+            limit: query
+                .limit
+                .unwrap_or(DEFAULT_PAGE_LIMIT)
+                .clamp(1, MAX_PAGE_LIMIT),
+            cursor,
+        })
+    }
+}
+
+// ========================================
+// Sorting
+// ========================================
+
+/// Whitelist of sortable columns for an endpoint
+pub trait SortFields {
+    const ALLOWED: &'static [&'static str];
+    const DEFAULT: &'static str;
+    const DEFAULT_DESCENDING: bool;
+}
+
+#[derive(Debug, Deserialize)]
+struct SortQuery {
+    sort: Option<String>,
+}
+
+/// `?sort=field` (ascending) or `?sort=-field` (descending), validated against `T::ALLOWED`
+#[derive(Debug, Clone)]
+pub struct SortBy<T> {
+    pub field: &'static str,
+    pub descending: bool,
+    _fields: PhantomData<T>,
+}
+
+impl<T: SortFields> SortBy<T> {
+    pub fn parse(value: Option<&str>) -> Option<Self> {
+        let Some(value) = value.filter(|v| !v.is_empty()) else {
+            return Some(Self {
+                field: T::DEFAULT,
+                descending: T::DEFAULT_DESCENDING,
+                _fields: PhantomData,
+            });
+        };
+
+        let (name, descending) = match value.strip_prefix('-') {
+            Some(name) => (name, true),
+            None => (value, false),
+        };
+        let field = T::ALLOWED.iter().find(|allowed| **allowed == name)?;
+
+        Some(Self {
+            field,
+            descending,
+            _fields: PhantomData,
+        })
+    }
+
+    /// `ORDER BY` fragment; safe to interpolate because `field` comes from the whitelist
+    pub fn order_by(&self) -> String {
+        format!(
+            "{} {}",
+            self.field,
+            if self.descending { "DESC" } else { "ASC" }
+        )
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync, T: SortFields> FromRequestParts<S> for SortBy<T> {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(query) = Query::<SortQuery>::from_request_parts(parts, state)
+            .await
+            .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+        SortBy::parse(query.sort.as_deref()).ok_or(StatusCode::BAD_REQUEST)
+    }
+}
+
+// ========================================
+// Response Envelope
+// ========================================
+
+/// Shared envelope for list endpoints
+#[derive(Debug, Serialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    /// Current page (offset pagination only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    pub limit: u32,
+    /// Total matching items (offset pagination only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    /// Pass as `?cursor=` to fetch the next page (keyset pagination only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+impl<T> Paginated<T> {
+    pub fn from_offset(items: Vec<T>, pagination: &Pagination, total: i64) -> Self {
+        Self {
+            has_more: pagination.offset() + (items.len() as i64) < total,
+            items,
+            page: Some(pagination.page),
+            limit: pagination.limit,
+            total: Some(total),
+            next_cursor: None,
+        }
+    }
+
+    /// Build a keyset page from up to `limit + 1` fetched rows
+    pub fn from_cursor(mut items: Vec<T>, limit: u32, cursor_of: impl Fn(&T) -> Cursor) -> Self {
+        let has_more = items.len() > limit as usize;
+        items.truncate(limit as usize);
+
+        Self {
+            next_cursor: has_more
+                .then(|| items.last().map(|item| cursor_of(item).encode()))
+                .flatten(),
+            items,
+            page: None,
+            limit,
+            total: None,
+            has_more,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestSort;
+
+    impl SortFields for TestSort {
+        const ALLOWED: &'static [&'static str] = &["created_at", "title"];
+        const DEFAULT: &'static str = "created_at";
+        const DEFAULT_DESCENDING: bool = true;
+    }
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let cursor = Cursor {
+            created_at: DateTime::from_timestamp_micros(1_733_000_000_123_456).unwrap(),
+            id: Uuid::new_v4(),
+        };
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor));
+        assert_eq!(Cursor::decode("not-a-cursor"), None);
+    }
+
+    #[test]
+    fn test_sort_by_whitelist() {
+        let sort = SortBy::<TestSort>::parse(Some("-title")).unwrap();
+        assert_eq!(sort.order_by(), "title DESC");
+        assert_eq!(
+            SortBy::<TestSort>::parse(None).unwrap().order_by(),
+            "created_at DESC"
+        );
+        assert!(SortBy::<TestSort>::parse(Some("id; DROP TABLE x")).is_none());
+    }
+
+    #[test]
+    fn test_paginated_from_cursor() {
+        let now = Utc::now();
+        let ids: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let page = Paginated::from_cursor(ids.clone(), 2, |id| Cursor {
+            created_at: now,
+            id: *id,
+        });
+        assert_eq!(page.items.len(), 2);
+        assert!(page.has_more);
+        assert_eq!(
+            Cursor::decode(page.next_cursor.as_deref().unwrap())
+                .unwrap()
+                .id,
+            ids[1]
+        );
+    }
+
+    #[test]
+    fn test_paginated_from_offset() {
+        let pagination = Pagination {
+            page: 2,
+            limit: 10,
+            cursor: None,
+        };
+        let page = Paginated::from_offset(vec![0; 10], &pagination, 25);
+        assert!(page.has_more);
+        let last = Paginated::from_offset(
+            vec![0; 5],
+            &Pagination {
+                page: 3,
+                ..pagination
+            },
+            25,
+        );
+        assert!(!last.has_more);
+    }
+}
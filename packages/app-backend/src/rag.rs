@@ -0,0 +1,427 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    routing::post,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use crate::auth::AdminUser;
+use crate::embeddings::{embed_and_store, search_similar, SearchResult};
+use crate::error::internal_error;
+use crate::jobs::{spawn_job, Job, JobHandle};
+use crate::llm::{ChatMessage, ChatRequest, ChatRole, LlmProvider};
+use crate::state::AppState;
+
+const DOCS_NAMESPACE: &str = "project-docs";
+const MAX_CHUNK_CHARS: usize = 1500;
+const EMBED_BATCH_SIZE: usize = 50;
+const DEFAULT_ASK_LIMIT: i64 = 5;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SourceDocument {
+    /// Project-relative path (used for citations and re-indexing)
+    pub path: String,
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct IndexDocsRequest {
+    documents: Vec<SourceDocument>,
+    namespace: Option<String>,
+    /// Remove indexed documents that are not part of this request
+    #[serde(default)]
+    prune: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeleteIndexQuery {
+    namespace: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AskDocsRequest {
+    question: String,
+    namespace: Option<String>,
+    limit: Option<i64>,
+    model: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Citation {
+    index: usize,
+    path: String,
+    heading: Option<String>,
+    similarity: f64,
+    excerpt: String,
+    /// Whether the answer actually references this source
+    cited: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct AskDocsResponse {
+    answer: String,
+    citations: Vec<Citation>,
+    model_used: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocChunk {
+    pub heading: Option<String>,
+    pub text: String,
+}
+
+// ========================================
+// Chunking
+// ========================================
+
+/// Split markdown into heading-scoped chunks of at most `max_chars` characters.
+///
+/// Sections are split at headings (outside code fences); oversized sections
+/// are further split at paragraph boundaries, then hard-wrapped.
+pub fn chunk_markdown(content: &str, max_chars: usize) -> Vec<DocChunk> {
+    let mut sections: Vec<(Option<String>, String)> = vec![(None, String::new())];
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+
+        if !in_fence && line.starts_with('#') {
+            let heading = line.trim_start_matches('#').trim().to_string();
+            sections.push((Some(heading), String::new()));
+        }
+
+        let body = &mut sections.last_mut().expect("sections is never empty").1;
+        body.push_str(line);
+        body.push('\n');
+    }
+
+    let mut chunks = Vec::new();
+    for (heading, body) in sections {
+        for text in split_section(&body, max_chars) {
+            chunks.push(DocChunk {
+                heading: heading.clone(),
+                text,
+            });
+        }
+    }
+    chunks
+}
+
+fn split_section(body: &str, max_chars: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+
+    for paragraph in body.split("\n\n") {
+        let paragraph = paragraph.trim();
+        if paragraph.is_empty() {
+            continue;
+        }
+
+        if !current.is_empty()
+            && current.chars().count() + paragraph.chars().count() + 2 > max_chars
+        {
+            parts.push(std::mem::take(&mut current));
+        }
+
+        if paragraph.chars().count() > max_chars {
+            let chars: Vec<char> = paragraph.chars().collect();
+            for piece in chars.chunks(max_chars) {
+                parts.push(piece.iter().collect());
+            }
+            continue;
+        }
+
+        if !current.is_empty() {
+            current.push_str("\n\n");
+        }
+        current.push_str(paragraph);
+    }
+
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+// ========================================
+// Indexing Job
+// ========================================
+
+async fn index_documents(
+    db: PgPool,
+    llm: Arc<dyn LlmProvider>,
+    namespace: String,
+    documents: Vec<SourceDocument>,
+    prune: bool,
+    handle: JobHandle,
+) -> Result<serde_json::Value, String> {
+    let mut total_chunks = 0;
+
+    for (doc_index, document) in documents.iter().enumerate() {
+        sqlx::query("DELETE FROM embeddings WHERE namespace = $1 AND metadata->>'path' = $2")
+            .bind(&namespace)
+            .bind(&document.path)
+            .execute(&db)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let chunks = chunk_markdown(&document.content, MAX_CHUNK_CHARS);
+        for (batch_index, batch) in chunks.chunks(EMBED_BATCH_SIZE).enumerate() {
+            let texts: Vec<String> = batch.iter().map(|c| c.text.clone()).collect();
+            let metadata: Vec<serde_json::Value> = batch
+                .iter()
+                .enumerate()
+                .map(|(i, chunk)| {
+                    serde_json::json!({
+                        "path": document.path,
+                        "heading": chunk.heading,
+                        "chunk_index": batch_index * EMBED_BATCH_SIZE + i,
+                    })
+                })
+                .collect();
+
+            embed_and_store(&db, llm.as_ref(), &namespace, &texts, &metadata)
+                .await
+                .map_err(|status| format!("Failed to embed {}: {}", document.path, status))?;
+        }
+
+        total_chunks += chunks.len();
+        handle
+            .set_progress(((doc_index + 1) * 100 / documents.len()) as u8)
+            .await;
+    }
+
+    let mut pruned = 0;
+    if prune {
+        let paths: Vec<String> = documents.iter().map(|d| d.path.clone()).collect();
+        pruned = sqlx::query(
+            "DELETE FROM embeddings WHERE namespace = $1 AND NOT (metadata->>'path' = ANY($2))",
+        )
+        .bind(&namespace)
+        .bind(&paths)
+        .execute(&db)
+        .await
+        .map_err(|e| e.to_string())?
+        .rows_affected();
+    }
+
+    tracing::info!(
+        "Indexed {} document(s), {} chunk(s) into '{}'",
+        documents.len(),
+        total_chunks,
+        namespace
+    );
+
+    Ok(serde_json::json!({
+        "namespace": namespace,
+        "documents": documents.len(),
+        "chunks": total_chunks,
+        "pruned_chunks": pruned,
+    }))
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// Admin only: with `prune`, removes every document of the namespace that is
+/// not part of the request
+async fn index_docs(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+    Json(payload): Json<IndexDocsRequest>,
+) -> Result<(StatusCode, Json<Job>), StatusCode> {
+    if payload.documents.is_empty() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let namespace = payload
+        .namespace
+        .unwrap_or_else(|| DOCS_NAMESPACE.to_string());
+    let db = state.db.clone();
+    let llm = state.llm.clone();
+    let documents = payload.documents;
+    let prune = payload.prune;
+
+    let job = spawn_job(&state.jobs, "docs-index", admin.id, move |handle| {
+        index_documents(
+            db.clone(),
+            llm.clone(),
+            namespace.clone(),
+            documents.clone(),
+            prune,
+            handle,
+        )
+    })
+    .await;
+
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+fn build_ask_prompt(question: &str, sources: &[SearchResult]) -> Vec<ChatMessage> {
+    let mut excerpts = String::new();
+    for (index, source) in sources.iter().enumerate() {
+        let path = source.metadata["path"].as_str().unwrap_or("unknown");
+        let heading = source.metadata["heading"].as_str().unwrap_or("");
+        excerpts.push_str(&format!(
+            "[{}] {} § {}\n{}\n\n",
+            index + 1,
+            path,
+            heading,
+            source.content
+        ));
+    }
+
+    vec![
+        ChatMessage::new(
+            ChatRole::System,
+            "You answer questions about this project using only the documentation excerpts provided. \
+             Cite the excerpts you rely on inline as [n]. \
+             If the excerpts do not contain the answer, say that the documentation does not cover it.",
+        ),
+        ChatMessage::new(
+            ChatRole::User,
+            format!("Documentation excerpts:\n\n{}Question: {}", excerpts, question),
+        ),
+    ]
+}
+
+async fn ask_docs(
+    State(state): State<AppState>,
+    Json(payload): Json<AskDocsRequest>,
+) -> Result<Json<AskDocsResponse>, StatusCode> {
+    if payload.question.trim().is_empty() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let namespace = payload
+        .namespace
+        .unwrap_or_else(|| DOCS_NAMESPACE.to_string());
+    let sources = search_similar(
+        &state.db,
+        state.llm.as_ref(),
+        &payload.question,
+        Some(&namespace),
+        payload.limit.unwrap_or(DEFAULT_ASK_LIMIT),
+        0.0,
+    )
+    .await?;
+
+    if sources.is_empty() {
+        return Ok(Json(AskDocsResponse {
+            answer: "No indexed documentation matched this question.".to_string(),
+            citations: Vec::new(),
+            model_used: None,
+        }));
+    }
+
+    let completion = state
+        .llm
+        .chat(ChatRequest {
+            model: payload.model,
+            messages: build_ask_prompt(&payload.question, &sources),
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!("ask-docs provider call failed: {}", e);
+            e.status()
+        })?;
+
+    let citations = sources
+        .into_iter()
+        .enumerate()
+        .map(|(i, source)| Citation {
+            index: i + 1,
+            path: source.metadata["path"]
+                .as_str()
+                .unwrap_or("unknown")
+                .to_string(),
+            heading: source.metadata["heading"].as_str().map(str::to_string),
+            similarity: source.similarity,
+            excerpt: source.content.chars().take(280).collect(),
+            cited: completion.content.contains(&format!("[{}]", i + 1)),
+        })
+        .collect();
+
+    Ok(Json(AskDocsResponse {
+        answer: completion.content,
+        citations,
+        model_used: Some(completion.model_used),
+    }))
+}
+
+/// Admin only: `DELETE /api/aigen/docs/index?namespace=<name>`
+async fn delete_docs_index(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+    Query(query): Query<DeleteIndexQuery>,
+) -> Result<StatusCode, StatusCode> {
+    let namespace = query.namespace.as_deref().unwrap_or(DOCS_NAMESPACE);
+
+    let deleted = sqlx::query("DELETE FROM embeddings WHERE namespace = $1")
+        .bind(namespace)
+        .execute(&state.db)
+        .await
+        .map_err(internal_error)?
+        .rows_affected();
+    tracing::info!(
+        "Admin {:?} deleted {} chunk(s) from namespace {}",
+        admin.id,
+        deleted,
+        namespace
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/aigen/docs/index",
+            post(index_docs).delete(delete_docs_index),
+        )
+        .route("/api/aigen/ask-docs", post(ask_docs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_markdown_splits_by_heading() {
+        let chunks = chunk_markdown("# Intro\nHello\n\n## Setup\nRun it\n", 1000);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].heading.as_deref(), Some("Intro"));
+        assert_eq!(chunks[1].heading.as_deref(), Some("Setup"));
+        assert!(chunks[1].text.contains("Run it"));
+    }
+
+    #[test]
+    fn test_chunk_markdown_ignores_headings_in_code_fences() {
+        let chunks = chunk_markdown("# Shell\n```bash\n# comment\nls\n```\n", 1000);
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].text.contains("# comment"));
+    }
+
+    #[test]
+    fn test_chunk_markdown_respects_max_chars() {
+        let paragraph = "a".repeat(250);
+        let content = format!("# Long\n{}\n\n{}\n\n{}\n", paragraph, paragraph, paragraph);
+        let chunks = chunk_markdown(&content, 300);
+        assert!(chunks.len() >= 3);
+        assert!(chunks.iter().all(|c| c.text.chars().count() <= 300));
+    }
+}
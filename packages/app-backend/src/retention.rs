@@ -0,0 +1,370 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::config::RetentionConfig;
+use crate::error::internal_error;
+use crate::state::AppState;
+use crate::storage::StoredObject;
+
+/// `files.metadata.type` of the objects the backend stores
+pub const GENERATED_IMAGE: &str = "generated_image";
+pub const TTS_AUDIO: &str = "tts_audio";
+pub const STT_UPLOAD: &str = "stt_upload";
+
+/// Retention class of a `files` row `f`; NULL for files the backend did not
+/// store (e.g. uploads from the frontend), which never expire. An active
+/// share link moves any asset into `shared`.
+const CLASS_EXPR: &str = "CASE
+      WHEN COALESCE(f.metadata->>'type', '') NOT IN ('generated_image', 'tts_audio', 'stt_upload')
+        THEN NULL
+      WHEN EXISTS (SELECT 1 FROM asset_shares s WHERE s.file_id = f.id
+                   AND s.revoked_at IS NULL AND s.expires_at > NOW())
+        THEN 'shared'
+      WHEN f.metadata->>'type' = 'stt_upload' THEN 'temp_upload'
+      ELSE 'generation'
+    END";
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetClass {
+    TempUpload,
+    Generation,
+    Shared,
+}
+
+impl AssetClass {
+    const ALL: [AssetClass; 3] = [
+        AssetClass::TempUpload,
+        AssetClass::Generation,
+        AssetClass::Shared,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AssetClass::TempUpload => "temp_upload",
+            AssetClass::Generation => "generation",
+            AssetClass::Shared => "shared",
+        }
+    }
+}
+
+/// How long assets of `class` are kept (`None` = forever)
+fn ttl(config: &RetentionConfig, class: AssetClass) -> Option<Duration> {
+    let ttl = match class {
+        AssetClass::TempUpload => Duration::hours(config.temp_upload_hours),
+        AssetClass::Generation => Duration::days(config.generation_days),
+        AssetClass::Shared => Duration::days(config.shared_days),
+    };
+    (ttl > Duration::zero()).then_some(ttl)
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UsageTotals {
+    pub files: i64,
+    pub bytes: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClassUsage {
+    pub class: AssetClass,
+    #[serde(flatten)]
+    pub usage: UsageTotals,
+    /// Age at which assets of this class expire (`null` = kept forever)
+    pub retention_hours: Option<i64>,
+}
+
+/// Storage used by one user, by retention class
+#[derive(Debug, Serialize)]
+pub struct StorageUsage {
+    /// Everything below, including assets pending deletion
+    pub total: UsageTotals,
+    pub classes: Vec<ClassUsage>,
+    /// Files the backend did not store, which never expire
+    pub other: UsageTotals,
+    /// Deleted or expired assets that can still be restored
+    pub pending_deletion: UsageTotals,
+    pub grace_days: i64,
+}
+
+#[derive(sqlx::FromRow)]
+struct UsageRow {
+    class: String,
+    files: i64,
+    bytes: i64,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct DeletedAsset {
+    pub id: Uuid,
+    pub deleted_at: DateTime<Utc>,
+    /// Restorable until then
+    #[sqlx(skip)]
+    pub purge_after: Option<DateTime<Utc>>,
+}
+
+// ========================================
+// Assets
+// ========================================
+
+/// Add a `files` row for an object stored for `owner`, so it shows up in the
+/// storage usage and expires with its class
+pub async fn record_asset(
+    db: &PgPool,
+    owner: Uuid,
+    stored: &StoredObject,
+    asset_type: &str,
+    metadata: serde_json::Value,
+) -> Result<Uuid, sqlx::Error> {
+    let mut metadata = metadata;
+    metadata["type"] = serde_json::json!(asset_type);
+    let file_name = stored
+        .path
+        .rsplit('/')
+        .next()
+        .unwrap_or(&stored.path)
+        .to_string();
+
+    sqlx::query_scalar(
+        "INSERT INTO files
+           (owner_id, storage_path, bucket_name, file_name, file_size, mime_type, is_public, metadata)
+         VALUES ($1, $2, $3, $4, $5, $6, false, $7)
+         RETURNING id",
+    )
+    .bind(owner)
+    .bind(&stored.path)
+    .bind(&stored.bucket)
+    .bind(file_name)
+    .bind(stored.size as i64)
+    .bind(&stored.content_type)
+    .bind(metadata)
+    .fetch_one(db)
+    .await
+}
+
+/// Task: soft-delete assets past the retention of their class, then delete
+/// assets whose grace period is over (the `files` delete trigger removes the
+/// storage object)
+pub async fn purge_assets(state: AppState) -> Result<String, String> {
+    let config = &state.config.retention;
+    let now = Utc::now();
+
+    let mut expired = 0;
+    for class in AssetClass::ALL {
+        let Some(ttl) = ttl(config, class) else {
+            continue;
+        };
+        let result = sqlx::query(&format!(
+            "UPDATE files f SET status = 'deleting', deleted_at = NOW()
+             WHERE f.status = 'active' AND f.created_at < $1 AND ({}) = $2",
+            CLASS_EXPR
+        ))
+        .bind(now - ttl)
+        .bind(class.as_str())
+        .execute(&state.db)
+        .await
+        .map_err(|e| e.to_string())?;
+        expired += result.rows_affected();
+    }
+
+    let purged = sqlx::query("DELETE FROM files WHERE status = 'deleting' AND deleted_at < $1")
+        .bind(now - Duration::days(config.grace_days))
+        .execute(&state.db)
+        .await
+        .map_err(|e| e.to_string())?
+        .rows_affected();
+
+    Ok(format!(
+        "expired {} asset(s), purged {} asset(s)",
+        expired, purged
+    ))
+}
+
+fn storage_usage(config: &RetentionConfig, rows: Vec<UsageRow>) -> StorageUsage {
+    let mut usage = StorageUsage {
+        total: UsageTotals::default(),
+        classes: AssetClass::ALL
+            .into_iter()
+            .map(|class| ClassUsage {
+                class,
+                usage: UsageTotals::default(),
+                retention_hours: ttl(config, class).map(|ttl| ttl.num_hours()),
+            })
+            .collect(),
+        other: UsageTotals::default(),
+        pending_deletion: UsageTotals::default(),
+        grace_days: config.grace_days,
+    };
+
+    for row in rows {
+        usage.total.files += row.files;
+        usage.total.bytes += row.bytes;
+        let totals = match row.class.as_str() {
+            "pending_deletion" => &mut usage.pending_deletion,
+            "other" => &mut usage.other,
+            class => match usage.classes.iter_mut().find(|c| c.class.as_str() == class) {
+                Some(entry) => &mut entry.usage,
+                None => continue,
+            },
+        };
+        totals.files += row.files;
+        totals.bytes += row.bytes;
+    }
+    usage
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// Bytes and files the caller stores, by retention class
+async fn get_storage_usage(
+    user: AuthUser,
+    State(state): State<AppState>,
+) -> Result<Json<StorageUsage>, StatusCode> {
+    let rows = sqlx::query_as::<_, UsageRow>(&format!(
+        "SELECT CASE WHEN f.status = 'deleting' THEN 'pending_deletion'
+                     ELSE COALESCE({}, 'other') END AS class,
+                COUNT(*) AS files, COALESCE(SUM(f.file_size), 0)::BIGINT AS bytes
+         FROM files f
+         WHERE f.owner_id = $1 AND f.status IN ('active', 'deleting')
+         GROUP BY 1",
+        CLASS_EXPR
+    ))
+    .bind(user.require_id()?)
+    .fetch_all(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    Ok(Json(storage_usage(&state.config.retention, rows)))
+}
+
+/// Soft-delete one of the caller's files; it can be restored until the grace period ends
+async fn delete_asset(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<DeletedAsset>, StatusCode> {
+    let mut deleted = sqlx::query_as::<_, DeletedAsset>(
+        "UPDATE files SET status = 'deleting', deleted_at = NOW()
+         WHERE id = $1 AND owner_id = $2 AND status = 'active'
+         RETURNING id, deleted_at",
+    )
+    .bind(id)
+    .bind(user.require_id()?)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(internal_error)?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    deleted.purge_after =
+        Some(deleted.deleted_at + Duration::days(state.config.retention.grace_days));
+    Ok(Json(deleted))
+}
+
+/// Undo a deletion (or expiry) during the grace period
+async fn restore_asset(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let result = sqlx::query(
+        "UPDATE files SET status = 'active', deleted_at = NULL
+         WHERE id = $1 AND owner_id = $2 AND status = 'deleting' AND deleted_at > $3",
+    )
+    .bind(id)
+    .bind(user.require_id()?)
+    .bind(Utc::now() - Duration::days(state.config.retention.grace_days))
+    .execute(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/usage/storage", get(get_storage_usage))
+        .route("/api/assets/:id", delete(delete_asset))
+        .route("/api/assets/:id/restore", post(restore_asset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RetentionConfig {
+        RetentionConfig {
+            temp_upload_hours: 24,
+            generation_days: 0,
+            shared_days: 30,
+            grace_days: 7,
+        }
+    }
+
+    #[test]
+    fn test_ttl_per_class() {
+        let config = config();
+        assert_eq!(
+            ttl(&config, AssetClass::TempUpload),
+            Some(Duration::hours(24))
+        );
+        assert_eq!(ttl(&config, AssetClass::Generation), None);
+        assert_eq!(ttl(&config, AssetClass::Shared), Some(Duration::days(30)));
+    }
+
+    #[test]
+    fn test_storage_usage_groups_rows() {
+        let row = |class: &str, files, bytes| UsageRow {
+            class: class.to_string(),
+            files,
+            bytes,
+        };
+        let usage = storage_usage(
+            &config(),
+            vec![
+                row("generation", 3, 3000),
+                row("temp_upload", 1, 500),
+                row("pending_deletion", 2, 200),
+                row("other", 1, 10),
+            ],
+        );
+
+        assert_eq!(usage.total.files, 7);
+        assert_eq!(usage.total.bytes, 3710);
+        assert_eq!(usage.pending_deletion.bytes, 200);
+        assert_eq!(usage.other.files, 1);
+        let classes: Vec<(AssetClass, i64, Option<i64>)> = usage
+            .classes
+            .iter()
+            .map(|c| (c.class, c.usage.bytes, c.retention_hours))
+            .collect();
+        assert_eq!(
+            classes,
+            [
+                (AssetClass::TempUpload, 500, Some(24)),
+                (AssetClass::Generation, 3000, None),
+                (AssetClass::Shared, 0, Some(720)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Redirect, Response},
+    routing::{delete, get, post},
+    Json, Router,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::config::ShareConfig;
+use crate::error::internal_error;
+use crate::state::AppState;
+
+// ========================================
+// Models
+// ========================================
+
+/// Public share link for a `files` row
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct Share {
+    pub id: Uuid,
+    pub file_id: Uuid,
+    pub token: String,
+    /// Absolute URL when `SHARE_BASE_URL` is set, otherwise `/share/:token`
+    #[sqlx(skip)]
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub access_count: i32,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// What anonymous visitors of a share link see
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct SharedAsset {
+    #[serde(skip)]
+    pub bucket_name: String,
+    #[serde(skip)]
+    pub storage_path: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub file_size: i64,
+    pub prompt: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub access_count: i32,
+    /// Time-limited download URL
+    #[sqlx(skip)]
+    pub signed_url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CreateShareRequest {
+    /// Link lifetime (defaults to `SHARE_DEFAULT_TTL_HOURS`, capped at `SHARE_MAX_TTL_HOURS`)
+    expires_in_hours: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ShareQuery {
+    /// `json` returns the metadata instead of redirecting
+    format: Option<String>,
+}
+
+const SHARE_COLUMNS: &str =
+    "id, file_id, token, expires_at, revoked_at, access_count, last_accessed_at, created_at";
+
+// ========================================
+// Helpers
+// ========================================
+
+/// Random 32-character token (122 bits of entropy)
+fn generate_token() -> String {
+    Uuid::new_v4().simple().to_string()
+}
+
+/// Tokens are always alphanumeric, so anything else is rejected before hitting the database
+fn is_valid_token(token: &str) -> bool {
+    (32..=64).contains(&token.len()) && token.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Validate the requested lifetime against the configured default/maximum
+fn share_ttl(config: &ShareConfig, requested: Option<i64>) -> Option<Duration> {
+    let hours = requested.unwrap_or(config.default_ttl_hours);
+    (1..=config.max_ttl_hours)
+        .contains(&hours)
+        .then(|| Duration::hours(hours))
+}
+
+fn share_url(config: &ShareConfig, token: &str) -> String {
+    match &config.base_url {
+        Some(base) => format!("{}/share/{}", base.trim_end_matches('/'), token),
+        None => format!("/share/{}", token),
+    }
+}
+
+fn wants_json(headers: &HeaderMap, query: &ShareQuery) -> bool {
+    if let Some(format) = &query.format {
+        return format == "json";
+    }
+    headers
+        .get(header::ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|accept| accept.contains("application/json"))
+}
+
+/// Ensure the asset exists and belongs to the caller
+async fn ensure_owner(state: &AppState, user_id: Uuid, file_id: Uuid) -> Result<(), StatusCode> {
+    let owned: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM files WHERE id = $1 AND owner_id = $2 AND status = 'active'",
+    )
+    .bind(file_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    owned.map(|_| ()).ok_or(StatusCode::NOT_FOUND)
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// Create an expiring public link for one of the caller's assets
+async fn create_share(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(file_id): Path<Uuid>,
+    payload: Option<Json<CreateShareRequest>>,
+) -> Result<(StatusCode, Json<Share>), StatusCode> {
+    let user_id = user.require_id()?;
+    let config = &state.config.share;
+    let payload = payload.map(|Json(p)| p).unwrap_or_default();
+    let ttl =
+        share_ttl(config, payload.expires_in_hours).ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    ensure_owner(&state, user_id, file_id).await?;
+
+    let mut share = sqlx::query_as::<_, Share>(&format!(
+        "INSERT INTO asset_shares (file_id, token, created_by, expires_at)
+         VALUES ($1, $2, $3, $4)
+         RETURNING {}",
+        SHARE_COLUMNS
+    ))
+    .bind(file_id)
+    .bind(generate_token())
+    .bind(user_id)
+    .bind(Utc::now() + ttl)
+    .fetch_one(&state.db)
+    .await
+    .map_err(internal_error)?;
+    share.url = share_url(config, &share.token);
+
+    tracing::info!(
+        "User {} shared file {} until {}",
+        user_id,
+        file_id,
+        share.expires_at
+    );
+    Ok((StatusCode::CREATED, Json(share)))
+}
+
+/// Share links of an asset (including expired and revoked ones), newest first
+async fn list_shares(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(file_id): Path<Uuid>,
+) -> Result<Json<Vec<Share>>, StatusCode> {
+    let user_id = user.require_id()?;
+    ensure_owner(&state, user_id, file_id).await?;
+
+    let mut shares = sqlx::query_as::<_, Share>(&format!(
+        "SELECT {} FROM asset_shares
+         WHERE file_id = $1 AND created_by = $2
+         ORDER BY created_at DESC",
+        SHARE_COLUMNS
+    ))
+    .bind(file_id)
+    .bind(user_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(internal_error)?;
+    for share in &mut shares {
+        share.url = share_url(&state.config.share, &share.token);
+    }
+
+    Ok(Json(shares))
+}
+
+/// Revoke a share link (it stops resolving immediately)
+async fn revoke_share(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path((file_id, share_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, StatusCode> {
+    let user_id = user.require_id()?;
+
+    let result = sqlx::query(
+        "UPDATE asset_shares SET revoked_at = COALESCE(revoked_at, NOW())
+         WHERE id = $1 AND file_id = $2 AND created_by = $3",
+    )
+    .bind(share_id)
+    .bind(file_id)
+    .bind(user_id)
+    .execute(&state.db)
+    .await
+    .map_err(internal_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Public: redirect to a signed download URL, or return the asset metadata
+/// (`?format=json` or `Accept: application/json`). Every resolution counts as an access.
+async fn resolve_share(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    Query(query): Query<ShareQuery>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    // Unknown, expired and revoked links are indistinguishable to visitors
+    if !is_valid_token(&token) {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let storage = state
+        .storage
+        .clone()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+
+    let mut asset = sqlx::query_as::<_, SharedAsset>(
+        "UPDATE asset_shares s
+         SET access_count = s.access_count + 1, last_accessed_at = NOW()
+         FROM files f
+         WHERE s.token = $1 AND s.revoked_at IS NULL AND s.expires_at > NOW()
+           AND f.id = s.file_id AND f.status = 'active'
+         RETURNING f.bucket_name, f.storage_path, f.file_name, f.mime_type, f.file_size,
+           f.metadata->>'prompt' AS prompt, f.created_at, s.expires_at, s.access_count",
+    )
+    .bind(&token)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(internal_error)?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    let signed_url = storage
+        .signed_url_in(&asset.bucket_name, &asset.storage_path)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to sign shared asset: {}", e);
+            StatusCode::BAD_GATEWAY
+        })?;
+
+    if wants_json(&headers, &query) {
+        asset.signed_url = Some(signed_url);
+        return Ok(Json(asset).into_response());
+    }
+    Ok(Redirect::temporary(&signed_url).into_response())
+}
+
+// ========================================
+// Router Setup
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/assets/:id/share", post(create_share))
+        .route("/api/assets/:id/shares", get(list_shares))
+        .route("/api/assets/:id/shares/:share_id", delete(revoke_share))
+        .route("/share/:token", get(resolve_share))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ShareConfig {
+        ShareConfig {
+            base_url: Some("https://api.example.com/".to_string()),
+            default_ttl_hours: 168,
+            max_ttl_hours: 720,
+        }
+    }
+
+    #[test]
+    fn test_generated_tokens_are_valid() {
+        let token = generate_token();
+        assert!(is_valid_token(&token));
+        assert_ne!(token, generate_token());
+
+        assert!(!is_valid_token("short"));
+        assert!(!is_valid_token(&format!("{}-", &token[..31])));
+        assert_eq!(
+            share_url(&config(), "abc"),
+            "https://api.example.com/share/abc"
+        );
+    }
+
+    #[test]
+    fn test_share_ttl() {
+        let config = config();
+        assert_eq!(share_ttl(&config, None), Some(Duration::hours(168)));
+        assert_eq!(share_ttl(&config, Some(1)), Some(Duration::hours(1)));
+        assert_eq!(share_ttl(&config, Some(720)), Some(Duration::hours(720)));
+        assert_eq!(share_ttl(&config, Some(721)), None);
+        assert_eq!(share_ttl(&config, Some(0)), None);
+    }
+}
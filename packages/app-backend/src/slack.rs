@@ -0,0 +1,337 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::config::SlackConfig;
+use crate::jobs::JobStatus;
+use crate::state::AppState;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    fn color(&self) -> &'static str {
+        match self {
+            Severity::Info => "good",
+            Severity::Warning => "warning",
+            Severity::Critical => "danger",
+        }
+    }
+
+    fn emoji(&self) -> &'static str {
+        match self {
+            Severity::Info => ":information_source:",
+            Severity::Warning => ":warning:",
+            Severity::Critical => ":rotating_light:",
+        }
+    }
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "info" => Ok(Severity::Info),
+            "warning" | "warn" => Ok(Severity::Warning),
+            "critical" => Ok(Severity::Critical),
+            other => Err(format!("unknown severity '{}'", other)),
+        }
+    }
+}
+
+/// Operational event posted to Slack
+#[derive(Debug, Clone)]
+pub struct SlackEvent {
+    pub severity: Severity,
+    pub title: String,
+    pub text: Option<String>,
+    pub fields: Vec<(String, String)>,
+}
+
+impl SlackEvent {
+    pub fn new(severity: Severity, title: impl Into<String>) -> Self {
+        Self {
+            severity,
+            title: title.into(),
+            text: None,
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn field(mut self, title: impl Into<String>, value: impl ToString) -> Self {
+        self.fields.push((title.into(), value.to_string()));
+        self
+    }
+}
+
+#[derive(Debug)]
+pub enum SlackError {
+    Http(reqwest::Error),
+    Api(String),
+}
+
+impl fmt::Display for SlackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlackError::Http(err) => write!(f, "Slack request failed: {}", err),
+            SlackError::Api(msg) => write!(f, "Slack webhook error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SlackError {}
+
+impl From<reqwest::Error> for SlackError {
+    fn from(err: reqwest::Error) -> Self {
+        SlackError::Http(err)
+    }
+}
+
+// ========================================
+// Slack Webhook Client
+// ========================================
+
+/// Posts backend-originated events to a Slack incoming webhook
+#[derive(Clone)]
+pub struct SlackNotifier {
+    client: reqwest::Client,
+    webhook_url: String,
+    min_severity: Severity,
+    /// "production" / "development", prefixed to every message
+    environment: &'static str,
+}
+
+impl SlackNotifier {
+    /// Returns `None` when no webhook URL is configured
+    pub fn from_config(config: &SlackConfig, production: bool) -> Option<Self> {
+        let webhook_url = config.webhook_url.clone()?;
+        let min_severity = config.min_severity.parse().unwrap_or_else(|e| {
+            tracing::warn!("SLACK_MIN_SEVERITY: {}, using info", e);
+            Severity::Info
+        });
+
+        Some(Self {
+            client: reqwest::Client::new(),
+            webhook_url,
+            min_severity,
+            environment: if production {
+                "production"
+            } else {
+                "development"
+            },
+        })
+    }
+
+    /// Post the event in the background (events below the minimum severity are dropped)
+    pub fn notify(&self, event: SlackEvent) {
+        if event.severity < self.min_severity {
+            return;
+        }
+        let notifier = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = notifier.send(&event).await {
+                tracing::warn!("Failed to post '{}' to Slack: {}", event.title, e);
+            }
+        });
+    }
+
+    /// Post the event and wait for Slack to accept it
+    pub async fn send(&self, event: &SlackEvent) -> Result<(), SlackError> {
+        if event.severity < self.min_severity {
+            return Ok(());
+        }
+
+        let response = self
+            .client
+            .post(&self.webhook_url)
+            .json(&self.payload(event))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(SlackError::Api(format!("{}: {}", status, body)));
+        }
+        Ok(())
+    }
+
+    /// Same message shape as the slack-notify edge function
+    fn payload(&self, event: &SlackEvent) -> serde_json::Value {
+        let fields: Vec<serde_json::Value> = event
+            .fields
+            .iter()
+            .map(|(title, value)| {
+                serde_json::json!({ "title": title, "value": value, "short": value.len() < 40 })
+            })
+            .collect();
+
+        serde_json::json!({
+            "username": "Akatsuki Backend",
+            "icon_emoji": ":robot_face:",
+            "text": format!(
+                "{} [{}] {}",
+                event.severity.emoji(),
+                self.environment,
+                event.title
+            ),
+            "attachments": [{
+                "color": event.severity.color(),
+                "text": event.text,
+                "fields": fields,
+            }],
+        })
+    }
+}
+
+// ========================================
+// Event Sources
+// ========================================
+
+/// Announce startup and (best effort) shutdown of this instance
+pub fn spawn_lifecycle_notices(state: &AppState) {
+    let Some(slack) = state.slack.clone() else {
+        return;
+    };
+    slack.notify(
+        SlackEvent::new(Severity::Info, "Backend started")
+            .field("version", env!("CARGO_PKG_VERSION"))
+            .field("llm provider", state.llm.name()),
+    );
+
+    tokio::spawn(async move {
+        crate::shutdown_signal().await;
+        let event = SlackEvent::new(Severity::Info, "Backend shutting down");
+        // The runtime exits shortly after the signal, so don't wait long
+        match tokio::time::timeout(std::time::Duration::from_secs(3), slack.send(&event)).await {
+            Ok(Err(e)) => tracing::warn!("Failed to post shutdown notice: {}", e),
+            Err(_) => tracing::warn!("Timed out posting shutdown notice"),
+            Ok(Ok(())) => {}
+        }
+    });
+}
+
+/// Alert when `SLACK_JOB_FAILURE_THRESHOLD` jobs fail within the window
+pub fn spawn_job_failure_monitor(state: &AppState) {
+    let Some(slack) = state.slack.clone() else {
+        return;
+    };
+    let config = &state.config.slack;
+    if config.job_failure_threshold == 0 {
+        return;
+    }
+    let mut window = FailureWindow::new(
+        Duration::seconds(config.job_failure_window_secs),
+        config.job_failure_threshold,
+    );
+    let mut finished = state.jobs.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            let job = match finished.recv().await {
+                Ok(job) => job,
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            };
+            if job.status != JobStatus::Failed {
+                continue;
+            }
+            if let Some(failures) = window.record(job.updated_at) {
+                slack.notify(
+                    SlackEvent::new(Severity::Warning, "Background job failures spiking")
+                        .field("failures", failures)
+                        .field("window", format!("{}s", window.window.num_seconds()))
+                        .field("last job", format!("{} ({})", job.kind, job.id))
+                        .text(job.error.unwrap_or_default()),
+                );
+            }
+        }
+    });
+}
+
+/// Sliding-window count of failed jobs
+struct FailureWindow {
+    window: Duration,
+    threshold: usize,
+    failures: VecDeque<DateTime<Utc>>,
+    /// Already alerted for the current spike
+    alerted: bool,
+}
+
+impl FailureWindow {
+    fn new(window: Duration, threshold: usize) -> Self {
+        Self {
+            window,
+            threshold,
+            failures: VecDeque::new(),
+            alerted: false,
+        }
+    }
+
+    /// Record a failure; returns the count when a spike starts
+    fn record(&mut self, at: DateTime<Utc>) -> Option<usize> {
+        self.failures.push_back(at);
+        while self
+            .failures
+            .front()
+            .is_some_and(|first| *first <= at - self.window)
+        {
+            self.failures.pop_front();
+        }
+
+        if self.failures.len() < self.threshold {
+            self.alerted = false;
+            return None;
+        }
+        if self.alerted {
+            return None;
+        }
+        self.alerted = true;
+        Some(self.failures.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_order() {
+        assert_eq!("WARN".parse::<Severity>(), Ok(Severity::Warning));
+        assert!("loud".parse::<Severity>().is_err());
+        assert!(Severity::Critical > Severity::Warning);
+        assert!(Severity::Info < Severity::Warning);
+    }
+
+    #[test]
+    fn test_failure_window() {
+        let start = Utc::now();
+        let mut window = FailureWindow::new(Duration::minutes(5), 3);
+
+        assert_eq!(window.record(start), None);
+        assert_eq!(window.record(start + Duration::minutes(1)), None);
+        assert_eq!(window.record(start + Duration::minutes(2)), Some(3));
+        // Same spike: alerted once
+        assert_eq!(window.record(start + Duration::minutes(3)), None);
+
+        // The earlier failures age out, then a new spike alerts again
+        assert_eq!(window.record(start + Duration::minutes(20)), None);
+        assert_eq!(window.record(start + Duration::minutes(21)), None);
+        assert_eq!(window.record(start + Duration::minutes(22)), Some(3));
+    }
+}
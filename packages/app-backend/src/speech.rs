@@ -0,0 +1,587 @@
+use async_trait::async_trait;
+use axum::{
+    body::{Body, Bytes},
+    extract::{DefaultBodyLimit, Multipart, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use futures::{Stream, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use uuid::Uuid;
+
+use crate::auth::OptionalUser;
+use crate::config::AppConfig;
+use crate::error::internal_error;
+use crate::llm::{ensure_success, LlmError};
+use crate::retention;
+use crate::state::AppState;
+use crate::storage::{object_path, StoredObject};
+
+/// OpenAI's TTS input limit, applied to every provider
+const MAX_TTS_CHARS: usize = 4096;
+/// Upload limit for speech-to-text audio files
+const MAX_STT_BYTES: usize = 25 * 1024 * 1024;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioFormat {
+    #[default]
+    Mp3,
+    Opus,
+    Aac,
+    Flac,
+    Wav,
+    Pcm,
+}
+
+impl AudioFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioFormat::Mp3 => "mp3",
+            AudioFormat::Opus => "opus",
+            AudioFormat::Aac => "aac",
+            AudioFormat::Flac => "flac",
+            AudioFormat::Wav => "wav",
+            AudioFormat::Pcm => "pcm",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            AudioFormat::Mp3 => "audio/mpeg",
+            AudioFormat::Opus => "audio/ogg",
+            AudioFormat::Aac => "audio/aac",
+            AudioFormat::Flac => "audio/flac",
+            AudioFormat::Wav => "audio/wav",
+            AudioFormat::Pcm => "audio/pcm",
+        }
+    }
+}
+
+/// Audio bytes streamed from the provider as they are generated
+pub type AudioStream = Pin<Box<dyn Stream<Item = Result<Bytes, reqwest::Error>> + Send>>;
+
+#[derive(Debug, Clone)]
+pub struct SynthesisRequest {
+    pub text: String,
+    pub voice: Option<String>,
+    pub model: Option<String>,
+    pub format: AudioFormat,
+}
+
+#[derive(Debug, Clone)]
+pub struct TranscriptionRequest {
+    pub audio: Bytes,
+    pub filename: String,
+    pub content_type: String,
+    pub language: Option<String>,
+    pub model: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Transcription {
+    pub text: String,
+    pub language: Option<String>,
+    pub model_used: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TtsRequest {
+    text: String,
+    voice: Option<String>,
+    model: Option<String>,
+    #[serde(default)]
+    format: AudioFormat,
+    /// Store the audio in Supabase Storage and return JSON instead of streaming
+    #[serde(default)]
+    store: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct TtsStoredResponse {
+    provider: String,
+    storage: StoredObject,
+    /// `files` row of signed-in users' audio (subject to retention)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize)]
+struct SttResponse {
+    provider: String,
+    #[serde(flatten)]
+    transcription: Transcription,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage: Option<StoredObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_id: Option<Uuid>,
+}
+
+// ========================================
+// Provider Abstraction
+// ========================================
+
+#[async_trait]
+pub trait SpeechProvider: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn synthesize(&self, request: SynthesisRequest) -> Result<AudioStream, LlmError>;
+
+    async fn transcribe(&self, request: TranscriptionRequest) -> Result<Transcription, LlmError>;
+}
+
+/// Build the speech provider selected by `SPEECH_PROVIDER`.
+///
+/// Returns `None` when the provider's API key is missing; the speech
+/// endpoints then respond with 503.
+pub fn speech_provider_from_config(config: &AppConfig) -> Option<Box<dyn SpeechProvider>> {
+    let speech = &config.speech;
+
+    match speech.provider.as_str() {
+        "openai" => match &config.llm.api_key {
+            Some(api_key) => Some(Box::new(OpenAiSpeechProvider {
+                client: reqwest::Client::new(),
+                api_key: api_key.clone(),
+                base_url: config.llm.base_url.trim_end_matches('/').to_string(),
+                tts_model: speech
+                    .tts_model
+                    .clone()
+                    .unwrap_or_else(|| "tts-1".to_string()),
+                tts_voice: speech
+                    .tts_voice
+                    .clone()
+                    .unwrap_or_else(|| "alloy".to_string()),
+                stt_model: speech
+                    .stt_model
+                    .clone()
+                    .unwrap_or_else(|| "whisper-1".to_string()),
+            })),
+            None => {
+                tracing::warn!("OPENAI_API_KEY is not set, speech endpoints are disabled");
+                None
+            }
+        },
+        "elevenlabs" => match &speech.elevenlabs_api_key {
+            Some(api_key) => Some(Box::new(ElevenLabsProvider {
+                client: reqwest::Client::new(),
+                api_key: api_key.clone(),
+                base_url: speech.elevenlabs_base_url.trim_end_matches('/').to_string(),
+                tts_model: speech
+                    .tts_model
+                    .clone()
+                    .unwrap_or_else(|| "eleven_multilingual_v2".to_string()),
+                tts_voice: speech
+                    .tts_voice
+                    .clone()
+                    .unwrap_or_else(|| "21m00Tcm4TlvDq8ikWAM".to_string()),
+                stt_model: speech
+                    .stt_model
+                    .clone()
+                    .unwrap_or_else(|| "scribe_v1".to_string()),
+            })),
+            None => {
+                tracing::warn!("ELEVENLABS_API_KEY is not set, speech endpoints are disabled");
+                None
+            }
+        },
+        other => {
+            tracing::warn!(
+                "Unknown SPEECH_PROVIDER '{}', speech endpoints are disabled",
+                other
+            );
+            None
+        }
+    }
+}
+
+fn audio_part(request: &TranscriptionRequest) -> Result<reqwest::multipart::Part, LlmError> {
+    reqwest::multipart::Part::bytes(request.audio.to_vec())
+        .file_name(request.filename.clone())
+        .mime_str(&request.content_type)
+        .map_err(LlmError::from)
+}
+
+// ========================================
+// OpenAI Provider
+// ========================================
+
+pub struct OpenAiSpeechProvider {
+    client: reqwest::Client,
+    api_key: String,
+    base_url: String,
+    tts_model: String,
+    tts_voice: String,
+    stt_model: String,
+}
+
+#[derive(Serialize)]
+struct OpenAiSpeechRequest<'a> {
+    model: &'a str,
+    input: &'a str,
+    voice: &'a str,
+    response_format: &'a str,
+}
+
+#[derive(Deserialize)]
+struct OpenAiTranscriptionResponse {
+    text: String,
+}
+
+#[async_trait]
+impl SpeechProvider for OpenAiSpeechProvider {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    async fn synthesize(&self, request: SynthesisRequest) -> Result<AudioStream, LlmError> {
+        let response = self
+            .client
+            .post(format!("{}/audio/speech", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&OpenAiSpeechRequest {
+                model: request.model.as_deref().unwrap_or(&self.tts_model),
+                input: &request.text,
+                voice: request.voice.as_deref().unwrap_or(&self.tts_voice),
+                response_format: request.format.as_str(),
+            })
+            .send()
+            .await?;
+
+        Ok(Box::pin(ensure_success(response).await?.bytes_stream()))
+    }
+
+    async fn transcribe(&self, request: TranscriptionRequest) -> Result<Transcription, LlmError> {
+        let model = request
+            .model
+            .clone()
+            .unwrap_or_else(|| self.stt_model.clone());
+        let mut form = reqwest::multipart::Form::new()
+            .part("file", audio_part(&request)?)
+            .text("model", model.clone());
+        if let Some(language) = &request.language {
+            form = form.text("language", language.clone());
+        }
+
+        let response = self
+            .client
+            .post(format!("{}/audio/transcriptions", self.base_url))
+            .bearer_auth(&self.api_key)
+            .multipart(form)
+            .send()
+            .await?;
+        let body: OpenAiTranscriptionResponse = ensure_success(response).await?.json().await?;
+
+        Ok(Transcription {
+            text: body.text,
+            language: request.language,
+            model_used: model,
+        })
+    }
+}
+
+// ========================================
+// ElevenLabs Provider
+// ========================================
+
+pub struct ElevenLabsProvider {
+    client: reqwest::Client,
+    api_key: String,
+    base_url: String,
+    tts_model: String,
+    tts_voice: String,
+    stt_model: String,
+}
+
+#[derive(Serialize)]
+struct ElevenLabsSpeechRequest<'a> {
+    text: &'a str,
+    model_id: &'a str,
+}
+
+#[derive(Deserialize)]
+struct ElevenLabsTranscriptionResponse {
+    text: String,
+    language_code: Option<String>,
+}
+
+/// ElevenLabs `output_format` for an audio format (not every format is supported)
+fn elevenlabs_output_format(format: AudioFormat) -> Option<&'static str> {
+    match format {
+        AudioFormat::Mp3 => Some("mp3_44100_128"),
+        AudioFormat::Opus => Some("opus_48000_128"),
+        AudioFormat::Pcm => Some("pcm_44100"),
+        AudioFormat::Aac | AudioFormat::Flac | AudioFormat::Wav => None,
+    }
+}
+
+#[async_trait]
+impl SpeechProvider for ElevenLabsProvider {
+    fn name(&self) -> &str {
+        "elevenlabs"
+    }
+
+    async fn synthesize(&self, request: SynthesisRequest) -> Result<AudioStream, LlmError> {
+        let output_format = elevenlabs_output_format(request.format).ok_or_else(|| {
+            LlmError::Provider(format!(
+                "ElevenLabs does not support '{}' output",
+                request.format.as_str()
+            ))
+        })?;
+        let voice = request.voice.as_deref().unwrap_or(&self.tts_voice);
+
+        let response = self
+            .client
+            .post(format!(
+                "{}/v1/text-to-speech/{}/stream",
+                self.base_url, voice
+            ))
+            .query(&[("output_format", output_format)])
+            .header("xi-api-key", &self.api_key)
+            .json(&ElevenLabsSpeechRequest {
+                text: &request.text,
+                model_id: request.model.as_deref().unwrap_or(&self.tts_model),
+            })
+            .send()
+            .await?;
+
+        Ok(Box::pin(ensure_success(response).await?.bytes_stream()))
+    }
+
+    async fn transcribe(&self, request: TranscriptionRequest) -> Result<Transcription, LlmError> {
+        let model = request
+            .model
+            .clone()
+            .unwrap_or_else(|| self.stt_model.clone());
+        let mut form = reqwest::multipart::Form::new()
+            .part("file", audio_part(&request)?)
+            .text("model_id", model.clone());
+        if let Some(language) = &request.language {
+            form = form.text("language_code", language.clone());
+        }
+
+        let response = self
+            .client
+            .post(format!("{}/v1/speech-to-text", self.base_url))
+            .header("xi-api-key", &self.api_key)
+            .multipart(form)
+            .send()
+            .await?;
+        let body: ElevenLabsTranscriptionResponse = ensure_success(response).await?.json().await?;
+
+        Ok(Transcription {
+            text: body.text,
+            language: body.language_code.or(request.language),
+            model_used: model,
+        })
+    }
+}
+
+// ========================================
+// Handlers
+// ========================================
+
+/// Track audio stored for a signed-in user so it expires with its retention class
+async fn record_stored(
+    state: &AppState,
+    owner: Option<Uuid>,
+    stored: &StoredObject,
+    asset_type: &str,
+) -> Option<Uuid> {
+    retention::record_asset(&state.db, owner?, stored, asset_type, serde_json::json!({}))
+        .await
+        .map_err(|e| tracing::warn!("Failed to record {}: {}", asset_type, e))
+        .ok()
+}
+
+fn provider_error(err: LlmError) -> StatusCode {
+    tracing::error!("Speech provider call failed: {}", err);
+    err.status()
+}
+
+/// Text-to-speech: streams audio by default, or stores it when `store` is set
+async fn text_to_speech(
+    user: OptionalUser,
+    State(state): State<AppState>,
+    Json(payload): Json<TtsRequest>,
+) -> Result<Response, StatusCode> {
+    let speech = state
+        .speech
+        .clone()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    if payload.text.trim().is_empty() || payload.text.chars().count() > MAX_TTS_CHARS {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    if payload.store && state.storage.is_none() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    let format = payload.format;
+    let stream = speech
+        .synthesize(SynthesisRequest {
+            text: payload.text,
+            voice: payload.voice,
+            model: payload.model,
+            format,
+        })
+        .await
+        .map_err(provider_error)?;
+
+    let Some(storage) = state.storage.as_ref().filter(|_| payload.store) else {
+        return Ok((
+            [(header::CONTENT_TYPE, format.content_type())],
+            Body::from_stream(stream),
+        )
+            .into_response());
+    };
+
+    let chunks: Vec<Bytes> = stream.try_collect().await.map_err(|e| {
+        tracing::error!("Reading synthesized audio failed: {}", e);
+        StatusCode::BAD_GATEWAY
+    })?;
+    let stored = storage
+        .upload(
+            &object_path("tts", format.as_str()),
+            Bytes::from(chunks.concat()),
+            format.content_type(),
+        )
+        .await
+        .map_err(internal_error)?;
+    let file_id = record_stored(&state, user.id(), &stored, retention::TTS_AUDIO).await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(TtsStoredResponse {
+            provider: speech.name().to_string(),
+            storage: stored,
+            file_id,
+        }),
+    )
+        .into_response())
+}
+
+/// Speech-to-text: multipart upload with `file` and optional `language`, `model`, `store`
+async fn speech_to_text(
+    user: OptionalUser,
+    State(state): State<AppState>,
+    mut multipart: Multipart,
+) -> Result<Json<SttResponse>, StatusCode> {
+    let speech = state
+        .speech
+        .clone()
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+
+    let mut audio = None;
+    let mut language = None;
+    let mut model = None;
+    let mut store = false;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+    {
+        match field.name().unwrap_or_default() {
+            "file" => {
+                let filename = field.file_name().unwrap_or("audio").to_string();
+                let content_type = field
+                    .content_type()
+                    .unwrap_or("application/octet-stream")
+                    .to_string();
+                let data = field.bytes().await.map_err(|_| StatusCode::BAD_REQUEST)?;
+                audio = Some((data, filename, content_type));
+            }
+            "language" => language = field.text().await.ok().filter(|v| !v.is_empty()),
+            "model" => model = field.text().await.ok().filter(|v| !v.is_empty()),
+            "store" => store = field.text().await.map(|v| v == "true").unwrap_or(false),
+            _ => {}
+        }
+    }
+
+    let (data, filename, content_type) = audio.ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    if data.is_empty() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    if store && state.storage.is_none() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    let transcription = speech
+        .transcribe(TranscriptionRequest {
+            audio: data.clone(),
+            filename: filename.clone(),
+            content_type: content_type.clone(),
+            language,
+            model,
+        })
+        .await
+        .map_err(provider_error)?;
+
+    let storage = match state.storage.as_ref().filter(|_| store) {
+        Some(storage) => {
+            let extension = filename
+                .rsplit_once('.')
+                .map(|(_, ext)| ext)
+                .unwrap_or("bin");
+            Some(
+                storage
+                    .upload(&object_path("stt", extension), data, &content_type)
+                    .await
+                    .map_err(internal_error)?,
+            )
+        }
+        None => None,
+    };
+    let file_id = match &storage {
+        Some(stored) => record_stored(&state, user.id(), stored, retention::STT_UPLOAD).await,
+        None => None,
+    };
+
+    Ok(Json(SttResponse {
+        provider: speech.name().to_string(),
+        transcription,
+        storage,
+        file_id,
+    }))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/aigen/tts", post(text_to_speech))
+        .route(
+            "/api/aigen/stt",
+            post(speech_to_text).layer(DefaultBodyLimit::max(MAX_STT_BYTES)),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audio_format_defaults_to_mp3() {
+        let request: TtsRequest = serde_json::from_str(r#"{"text":"hello"}"#).unwrap();
+        assert_eq!(request.format, AudioFormat::Mp3);
+        assert_eq!(request.format.content_type(), "audio/mpeg");
+        assert!(!request.store);
+    }
+
+    #[test]
+    fn test_elevenlabs_output_format() {
+        assert_eq!(
+            elevenlabs_output_format(AudioFormat::Mp3),
+            Some("mp3_44100_128")
+        );
+        assert_eq!(elevenlabs_output_format(AudioFormat::Wav), None);
+    }
+}
@@ -0,0 +1,35 @@
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use crate::config::AppConfig;
+use crate::email::Mailer;
+use crate::idempotency::IdempotencyStore;
+use crate::images::ImageProvider;
+use crate::jobs::JobStore;
+use crate::limits::ProviderLimits;
+use crate::llm::LlmProvider;
+use crate::llm_cache::LlmCache;
+use crate::slack::SlackNotifier;
+use crate::speech::SpeechProvider;
+use crate::storage::StorageClient;
+use crate::tasks::Scheduler;
+
+/// Shared application state passed to all handlers
+#[derive(Clone)]
+pub struct AppState {
+    pub db: PgPool,
+    pub llm: Arc<dyn LlmProvider>,
+    /// agent-execute response cache (`None` when disabled)
+    pub llm_cache: Option<LlmCache>,
+    pub speech: Option<Arc<dyn SpeechProvider>>,
+    pub images: Arc<dyn ImageProvider>,
+    /// Per-provider concurrency limits the providers above are wrapped in
+    pub limits: ProviderLimits,
+    pub storage: Option<StorageClient>,
+    pub email: Option<Mailer>,
+    pub slack: Option<SlackNotifier>,
+    pub jobs: JobStore,
+    pub idempotency: IdempotencyStore,
+    pub tasks: Scheduler,
+    pub config: Arc<AppConfig>,
+}
@@ -0,0 +1,176 @@
+use axum::body::Bytes;
+use chrono::{Datelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+use crate::config::StorageConfig;
+
+// ========================================
+// Models
+// ========================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredObject {
+    pub bucket: String,
+    pub path: String,
+    pub content_type: String,
+    pub size: usize,
+    /// Time-limited URL for downloading the object
+    pub signed_url: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum StorageError {
+    Http(reqwest::Error),
+    Api(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::Http(err) => write!(f, "Storage request failed: {}", err),
+            StorageError::Api(msg) => write!(f, "Storage API error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+impl From<reqwest::Error> for StorageError {
+    fn from(err: reqwest::Error) -> Self {
+        StorageError::Http(err)
+    }
+}
+
+#[derive(Deserialize)]
+struct SignedUrlResponse {
+    #[serde(rename = "signedURL")]
+    signed_url: String,
+}
+
+/// Build a unique object path: `{prefix}/{yyyy}/{mm}/{uuid}.{extension}`
+pub fn object_path(prefix: &str, extension: &str) -> String {
+    let now = Utc::now();
+    format!(
+        "{}/{:04}/{:02}/{}.{}",
+        prefix.trim_matches('/'),
+        now.year(),
+        now.month(),
+        Uuid::new_v4(),
+        extension
+    )
+}
+
+// ========================================
+// Supabase Storage Client
+// ========================================
+
+/// Uploads generated assets to Supabase Storage using the service role key
+#[derive(Clone)]
+pub struct StorageClient {
+    client: reqwest::Client,
+    base_url: String,
+    service_role_key: String,
+    bucket: String,
+    signed_url_ttl_secs: u64,
+}
+
+impl StorageClient {
+    /// Returns `None` when the Supabase URL or service role key is not configured
+    pub fn from_config(config: &StorageConfig) -> Option<Self> {
+        let (Some(url), Some(key)) = (&config.supabase_url, &config.service_role_key) else {
+            tracing::warn!(
+                "SUPABASE_URL / SUPABASE_SERVICE_ROLE_KEY not set, generated assets will not be stored"
+            );
+            return None;
+        };
+
+        Some(Self {
+            client: reqwest::Client::new(),
+            base_url: format!("{}/storage/v1", url.trim_end_matches('/')),
+            service_role_key: key.clone(),
+            bucket: config.bucket.clone(),
+            signed_url_ttl_secs: config.signed_url_ttl_secs,
+        })
+    }
+
+    /// Upload `data` to `path` in the configured bucket and return a signed URL
+    pub async fn upload(
+        &self,
+        path: &str,
+        data: Bytes,
+        content_type: &str,
+    ) -> Result<StoredObject, StorageError> {
+        let size = data.len();
+        let response = self
+            .client
+            .post(format!("{}/object/{}/{}", self.base_url, self.bucket, path))
+            .bearer_auth(&self.service_role_key)
+            .header("apikey", &self.service_role_key)
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .header("x-upsert", "true")
+            .body(data)
+            .send()
+            .await?;
+        Self::ensure_success(response).await?;
+
+        let signed_url = self.signed_url(path).await?;
+
+        Ok(StoredObject {
+            bucket: self.bucket.clone(),
+            path: path.to_string(),
+            content_type: content_type.to_string(),
+            size,
+            signed_url: Some(signed_url),
+        })
+    }
+
+    /// Create a time-limited download URL for an object in the configured bucket
+    pub async fn signed_url(&self, path: &str) -> Result<String, StorageError> {
+        self.signed_url_in(&self.bucket, path).await
+    }
+
+    /// Create a time-limited download URL for an object in any bucket
+    pub async fn signed_url_in(&self, bucket: &str, path: &str) -> Result<String, StorageError> {
+        let response = self
+            .client
+            .post(format!("{}/object/sign/{}/{}", self.base_url, bucket, path))
+            .bearer_auth(&self.service_role_key)
+            .header("apikey", &self.service_role_key)
+            .json(&serde_json::json!({ "expiresIn": self.signed_url_ttl_secs }))
+            .send()
+            .await?;
+
+        let body: SignedUrlResponse = Self::ensure_success(response).await?.json().await?;
+        Ok(format!("{}{}", self.base_url, body.signed_url))
+    }
+
+    async fn ensure_success(
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response, StorageError> {
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        Err(StorageError::Api(format!("{}: {}", status, body)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_path_layout() {
+        let path = object_path("/tts/", "mp3");
+        let parts: Vec<&str> = path.split('/').collect();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[0], "tts");
+        assert_eq!(parts[1].len(), 4);
+        assert_eq!(parts[2].len(), 2);
+        assert!(parts[3].ends_with(".mp3"));
+    }
+}
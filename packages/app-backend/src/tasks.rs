@@ -0,0 +1,589 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::{DateTime, Datelike, Duration, DurationRound, Timelike, Utc};
+use serde::Serialize;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::auth::AdminUser;
+use crate::config::TasksConfig;
+use crate::limits;
+use crate::state::AppState;
+
+// ========================================
+// Cron Expressions
+// ========================================
+
+/// Five-field cron expression (`minute hour day-of-month month day-of-week`, UTC)
+///
+/// Fields accept `*`, numbers, ranges (`1-5`), lists (`0,30`) and steps
+/// (`*/15`, `0-30/10`). Day-of-week is 0-6 (Sunday = 0, 7 is also Sunday).
+#[derive(Debug, Clone)]
+pub struct CronSchedule {
+    expr: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Day-of-month / day-of-week restricted (cron ORs them when both are)
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(format!(
+                "expected 5 fields, got {}: '{}'",
+                fields.len(),
+                expr
+            ));
+        };
+
+        let mut weekdays = parse_field(weekday, 0, 7)?;
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            expr: fields.join(" "),
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days: parse_field(day, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            weekdays,
+            days_restricted: day != "*",
+            weekdays_restricted: weekday != "*",
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.expr
+    }
+
+    fn matches_day(&self, time: &DateTime<Utc>) -> bool {
+        let day = bit(self.days, time.day());
+        let weekday = bit(self.weekdays, time.weekday().num_days_from_sunday());
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day || weekday,
+            (true, false) => day,
+            (false, true) => weekday,
+            (false, false) => true,
+        }
+    }
+
+    /// First matching minute strictly after `after`
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut time = after.duration_trunc(Duration::minutes(1)).ok()? + Duration::minutes(1);
+        // Every valid expression matches within 4 years (Feb 29)
+        let limit = time + Duration::days(4 * 366);
+
+        while time < limit {
+            if !bit(self.months, time.month()) || !self.matches_day(&time) {
+                time = time.duration_trunc(Duration::days(1)).ok()? + Duration::days(1);
+                continue;
+            }
+            if !bit(self.hours, time.hour()) {
+                time = time.duration_trunc(Duration::hours(1)).ok()? + Duration::hours(1);
+                continue;
+            }
+            if !bit(self.minutes, time.minute()) {
+                time += Duration::minutes(1);
+                continue;
+            }
+            return Some(time);
+        }
+        None
+    }
+}
+
+fn bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, String> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (
+                range,
+                step.parse::<u32>()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or_else(|| format!("invalid step in '{}'", part))?,
+            ),
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start, part)?, parse_value(end, part)?)
+        } else {
+            let value = parse_value(range, part)?;
+            // `5/10` means "from 5 to the end, every 10"
+            (value, if part.contains('/') { max } else { value })
+        };
+        if start < min || end > max || start > end {
+            return Err(format!("'{}' is outside {}-{}", part, min, max));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(value: &str, part: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value in '{}'", part))
+}
+
+// ========================================
+// Scheduler
+// ========================================
+
+type TaskFuture = Pin<Box<dyn Future<Output = Result<String, String>> + Send>>;
+
+/// Task body: returns a short summary ("purged 3 link(s)") or an error
+type TaskRunner = Arc<dyn Fn(AppState) -> TaskFuture + Send + Sync>;
+
+/// Last-run status of a scheduled task
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskStatus {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub schedule: String,
+    pub running: bool,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_started_at: Option<DateTime<Utc>>,
+    pub last_finished_at: Option<DateTime<Utc>>,
+    pub last_duration_ms: Option<i64>,
+    pub last_success: Option<bool>,
+    /// Summary or error message of the last run
+    pub last_message: Option<String>,
+    pub run_count: u64,
+    pub failure_count: u64,
+}
+
+struct TaskEntry {
+    schedule: CronSchedule,
+    runner: TaskRunner,
+    status: TaskStatus,
+}
+
+/// Error returned when triggering a task by name
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskActionError {
+    NotFound,
+    AlreadyRunning,
+}
+
+impl From<TaskActionError> for StatusCode {
+    fn from(err: TaskActionError) -> Self {
+        match err {
+            TaskActionError::NotFound => StatusCode::NOT_FOUND,
+            TaskActionError::AlreadyRunning => StatusCode::CONFLICT,
+        }
+    }
+}
+
+/// In-process cron scheduler (one instance per backend process)
+#[derive(Clone, Default)]
+pub struct Scheduler {
+    tasks: Arc<RwLock<Vec<TaskEntry>>>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a task; `run` is called on every matching minute
+    pub async fn register<F, Fut>(
+        &self,
+        name: &'static str,
+        description: &'static str,
+        schedule: CronSchedule,
+        run: F,
+    ) where
+        F: Fn(AppState) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, String>> + Send + 'static,
+    {
+        let runner: TaskRunner = Arc::new(move |state| Box::pin(run(state)));
+        let status = TaskStatus {
+            name,
+            description,
+            schedule: schedule.as_str().to_string(),
+            running: false,
+            next_run_at: schedule.next_after(Utc::now()),
+            last_started_at: None,
+            last_finished_at: None,
+            last_duration_ms: None,
+            last_success: None,
+            last_message: None,
+            run_count: 0,
+            failure_count: 0,
+        };
+        self.tasks.write().await.push(TaskEntry {
+            schedule,
+            runner,
+            status,
+        });
+    }
+
+    pub async fn list(&self) -> Vec<TaskStatus> {
+        self.tasks
+            .read()
+            .await
+            .iter()
+            .map(|entry| entry.status.clone())
+            .collect()
+    }
+
+    /// Check for due tasks once a minute until the process exits
+    pub fn start(&self, state: AppState) {
+        let scheduler = self.clone();
+        tokio::spawn(async move {
+            loop {
+                // Wake up just after the next minute boundary
+                let now = Utc::now();
+                let wait = 60 - now.second() as u64;
+                tokio::time::sleep(std::time::Duration::from_secs(wait)).await;
+                scheduler.run_due(&state, Utc::now()).await;
+            }
+        });
+    }
+
+    async fn run_due(&self, state: &AppState, now: DateTime<Utc>) {
+        let due: Vec<&'static str> = self
+            .tasks
+            .read()
+            .await
+            .iter()
+            .filter(|entry| entry.status.next_run_at.is_some_and(|next| next <= now))
+            .map(|entry| entry.status.name)
+            .collect();
+
+        for name in due {
+            match self.trigger(name, state).await {
+                Ok(_) => {}
+                Err(TaskActionError::AlreadyRunning) => {
+                    tracing::warn!("Task {} is still running, skipping this run", name);
+                    self.update(name, |entry| {
+                        entry.status.next_run_at = entry.schedule.next_after(now);
+                    })
+                    .await;
+                }
+                Err(TaskActionError::NotFound) => {}
+            }
+        }
+    }
+
+    /// Start a task now (in the background) regardless of its schedule
+    pub async fn trigger(
+        &self,
+        name: &str,
+        state: &AppState,
+    ) -> Result<TaskStatus, TaskActionError> {
+        let (name, runner, status) = {
+            let mut tasks = self.tasks.write().await;
+            let entry = tasks
+                .iter_mut()
+                .find(|entry| entry.status.name == name)
+                .ok_or(TaskActionError::NotFound)?;
+            if entry.status.running {
+                return Err(TaskActionError::AlreadyRunning);
+            }
+
+            let now = Utc::now();
+            entry.status.running = true;
+            entry.status.last_started_at = Some(now);
+            entry.status.next_run_at = entry.schedule.next_after(now);
+            (
+                entry.status.name,
+                entry.runner.clone(),
+                entry.status.clone(),
+            )
+        };
+
+        let scheduler = self.clone();
+        let work = runner(state.clone());
+        tokio::spawn(async move {
+            let started = Utc::now();
+            // A panicking task must not stay "running" forever
+            let outcome = tokio::spawn(limits::batch(work))
+                .await
+                .unwrap_or_else(|e| Err(format!("task panicked: {}", e)));
+
+            match &outcome {
+                Ok(summary) => tracing::info!("Task {} finished: {}", name, summary),
+                Err(error) => tracing::error!("Task {} failed: {}", name, error),
+            }
+            scheduler
+                .update(name, |entry| {
+                    let finished = Utc::now();
+                    let status = &mut entry.status;
+                    status.running = false;
+                    status.last_finished_at = Some(finished);
+                    status.last_duration_ms = Some((finished - started).num_milliseconds());
+                    status.last_success = Some(outcome.is_ok());
+                    status.run_count += 1;
+                    if outcome.is_err() {
+                        status.failure_count += 1;
+                    }
+                    status.last_message = Some(match outcome {
+                        Ok(summary) => summary,
+                        Err(error) => error,
+                    });
+                })
+                .await;
+        });
+
+        Ok(status)
+    }
+
+    async fn update<F: FnOnce(&mut TaskEntry)>(&self, name: &str, apply: F) {
+        if let Some(entry) = self
+            .tasks
+            .write()
+            .await
+            .iter_mut()
+            .find(|entry| entry.status.name == name)
+        {
+            apply(entry);
+        }
+    }
+}
+
+// ========================================
+// Registered Tasks
+// ========================================
+
+/// Register the built-in maintenance tasks (invalid cron overrides disable the task)
+pub async fn register_default_tasks(scheduler: &Scheduler, config: &TasksConfig) {
+    let schedule = |name: &str, expr: &str| match CronSchedule::parse(expr) {
+        Ok(schedule) => Some(schedule),
+        Err(e) => {
+            tracing::error!("Task {} disabled, invalid schedule: {}", name, e);
+            None
+        }
+    };
+
+    if let Some(cron) = schedule("expire-shared-links", &config.expire_links_cron) {
+        scheduler
+            .register(
+                "expire-shared-links",
+                "Deactivate url_aliases (shared links) past expires_at",
+                cron,
+                expire_shared_links,
+            )
+            .await;
+    }
+
+    if let Some(cron) = schedule("aggregate-usage", &config.aggregate_usage_cron) {
+        scheduler
+            .register(
+                "aggregate-usage",
+                "Roll llm_call_logs up into usage_daily_stats (today and yesterday)",
+                cron,
+                aggregate_usage,
+            )
+            .await;
+    }
+
+    if let Some(cron) = schedule("retry-stalled-jobs", &config.retry_jobs_cron) {
+        let stall = Duration::minutes(config.job_stall_minutes);
+        let max_attempts = config.job_max_attempts;
+        scheduler
+            .register(
+                "retry-stalled-jobs",
+                "Restart background jobs that stopped reporting progress",
+                cron,
+                move |state: AppState| async move {
+                    let cutoff = Utc::now() - stall;
+                    let (restarted, failed) = state.jobs.retry_stalled(cutoff, max_attempts).await;
+                    // Jobs left behind by other instances or a crash
+                    let (resumed, orphans_failed) = state
+                        .jobs
+                        .resume_orphaned(&state, cutoff, max_attempts)
+                        .await
+                        .map_err(|e| format!("Failed to load orphaned jobs: {}", e))?;
+                    Ok(format!(
+                        "restarted {} job(s), failed {} job(s), resumed {} orphaned job(s)",
+                        restarted,
+                        failed + orphans_failed,
+                        resumed
+                    ))
+                },
+            )
+            .await;
+    }
+
+    if let Some(cron) = schedule("quota-warnings", &config.quota_warnings_cron) {
+        scheduler
+            .register(
+                "quota-warnings",
+                "Email users who used EMAIL_QUOTA_WARNING_PERCENT of their monthly quota",
+                cron,
+                crate::email::send_quota_warnings,
+            )
+            .await;
+    }
+
+    if let Some(cron) = schedule("purge-llm-cache", &config.purge_llm_cache_cron) {
+        scheduler
+            .register(
+                "purge-llm-cache",
+                "Delete expired llm_cache entries",
+                cron,
+                crate::llm_cache::purge_expired,
+            )
+            .await;
+    }
+
+    if let Some(cron) = schedule("purge-assets", &config.purge_assets_cron) {
+        scheduler
+            .register(
+                "purge-assets",
+                "Soft-delete assets past their RETENTION_* period, delete those past the grace period",
+                cron,
+                crate::retention::purge_assets,
+            )
+            .await;
+    }
+}
+
+async fn expire_shared_links(state: AppState) -> Result<String, String> {
+    let result = sqlx::query(
+        "UPDATE url_aliases SET is_active = false
+         WHERE is_active = true AND expires_at IS NOT NULL AND expires_at < now()",
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(format!("deactivated {} link(s)", result.rows_affected()))
+}
+
+async fn aggregate_usage(state: AppState) -> Result<String, String> {
+    let result = sqlx::query(
+        "INSERT INTO usage_daily_stats
+           (day, provider, model_id, request_type, calls, failures, input_tokens, output_tokens, users, updated_at)
+         SELECT created_at::date, provider, model_id, request_type,
+                COUNT(*), COUNT(*) FILTER (WHERE NOT success),
+                COALESCE(SUM(input_tokens), 0), COALESCE(SUM(output_tokens), 0),
+                COUNT(DISTINCT user_id), now()
+         FROM llm_call_logs
+         WHERE created_at >= current_date - 1
+         GROUP BY 1, 2, 3, 4
+         ON CONFLICT (day, provider, model_id, request_type) DO UPDATE SET
+           calls = EXCLUDED.calls,
+           failures = EXCLUDED.failures,
+           input_tokens = EXCLUDED.input_tokens,
+           output_tokens = EXCLUDED.output_tokens,
+           users = EXCLUDED.users,
+           updated_at = EXCLUDED.updated_at",
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(format!("updated {} daily row(s)", result.rows_affected()))
+}
+
+// ========================================
+// Admin Handlers
+// ========================================
+
+async fn admin_list_tasks(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+) -> Json<Vec<TaskStatus>> {
+    Json(state.tasks.list().await)
+}
+
+async fn admin_run_task(
+    AdminUser(admin): AdminUser,
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<(StatusCode, Json<TaskStatus>), StatusCode> {
+    tracing::info!("Admin {:?} running task {}", admin.id, name);
+    let status = state.tasks.trigger(&name, &state).await?;
+    Ok((StatusCode::ACCEPTED, Json(status)))
+}
+
+// ========================================
+// Router
+// ========================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/admin/tasks", get(admin_list_tasks))
+        .route("/api/admin/tasks/:name/run", post(admin_run_task))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_cron_next_after() {
+        let every_15 = CronSchedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(
+            every_15.next_after(at(2025, 1, 1, 10, 7)),
+            Some(at(2025, 1, 1, 10, 15))
+        );
+        assert_eq!(
+            every_15.next_after(at(2025, 1, 1, 10, 45)),
+            Some(at(2025, 1, 1, 11, 0))
+        );
+
+        // 03:30 on weekdays; 2025-01-04 is a Saturday
+        let weekdays = CronSchedule::parse("30 3 * * 1-5").unwrap();
+        assert_eq!(
+            weekdays.next_after(at(2025, 1, 3, 4, 0)),
+            Some(at(2025, 1, 6, 3, 30))
+        );
+
+        // Day-of-month and day-of-week are ORed when both are restricted
+        let either = CronSchedule::parse("0 0 1 * 0").unwrap();
+        assert_eq!(
+            either.next_after(at(2025, 1, 2, 0, 0)),
+            Some(at(2025, 1, 5, 0, 0))
+        );
+
+        let leap = CronSchedule::parse("0 12 29 2 *").unwrap();
+        assert_eq!(
+            leap.next_after(at(2025, 3, 1, 0, 0)),
+            Some(at(2028, 2, 29, 12, 0))
+        );
+        assert_eq!(
+            CronSchedule::parse("0  0 * * 7").unwrap().as_str(),
+            "0 0 * * 7"
+        );
+    }
+
+    #[test]
+    fn test_cron_parse_errors() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("5-1 * * * *").is_err());
+        assert!(CronSchedule::parse("a * * * *").is_err());
+        assert!(CronSchedule::parse("0,30 9-17/2 * 1,7 *").is_ok());
+    }
+}
@@ -0,0 +1,592 @@
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::{field, Instrument, Span};
+use uuid::Uuid;
+
+use crate::limits::ProviderLimits;
+use crate::llm::LlmError;
+
+// ========================================
+// Export Settings
+// ========================================
+
+/// OTLP encoding (`OTEL_EXPORTER_OTLP_PROTOCOL`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Protobuf,
+    Json,
+}
+
+/// What to export, from the standard `OTEL_*` variables
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "otel"), allow(dead_code))]
+struct ExportSettings {
+    traces: bool,
+    metrics: bool,
+    encoding: Encoding,
+    /// `OTEL_EXPORTER_OTLP_PROTOCOL=grpc`, which falls back to HTTP
+    grpc_requested: bool,
+}
+
+/// Export is on once an OTLP endpoint is set (`OTEL_EXPORTER_OTLP_ENDPOINT`
+/// or the per-signal `OTEL_EXPORTER_OTLP_{TRACES,METRICS}_ENDPOINT`), unless
+/// `OTEL_SDK_DISABLED=true`; `OTEL_{TRACES,METRICS}_EXPORTER=none` turns one
+/// signal off
+fn export_settings(var: impl Fn(&str) -> Option<String>) -> Option<ExportSettings> {
+    if var("OTEL_SDK_DISABLED").is_some_and(|v| v.eq_ignore_ascii_case("true")) {
+        return None;
+    }
+    let enabled = |signal: &str| {
+        let endpoint = var("OTEL_EXPORTER_OTLP_ENDPOINT")
+            .or_else(|| var(&format!("OTEL_EXPORTER_OTLP_{}_ENDPOINT", signal)));
+        let exporter = var(&format!("OTEL_{}_EXPORTER", signal));
+        endpoint.is_some() && exporter.is_none_or(|v| !v.eq_ignore_ascii_case("none"))
+    };
+    let traces = enabled("TRACES");
+    let metrics = enabled("METRICS");
+    if !traces && !metrics {
+        return None;
+    }
+
+    let protocol = var("OTEL_EXPORTER_OTLP_PROTOCOL");
+    Some(ExportSettings {
+        traces,
+        metrics,
+        encoding: match protocol.as_deref() {
+            Some("http/json") => Encoding::Json,
+            _ => Encoding::Protobuf,
+        },
+        grpc_requested: protocol.as_deref() == Some("grpc"),
+    })
+}
+
+fn env_var(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|v| !v.is_empty())
+}
+
+fn env_filter() -> tracing_subscriber::EnvFilter {
+    tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into())
+}
+
+// ========================================
+// Startup / Shutdown
+// ========================================
+
+/// Install the log subscriber and, in `otel` builds with an OTLP endpoint
+/// configured, the trace and metric exporters
+pub fn init() {
+    // OTEL_* may come from .env
+    dotenvy::dotenv().ok();
+
+    let Some(settings) = export_settings(env_var) else {
+        tracing_subscriber::fmt()
+            .with_env_filter(env_filter())
+            .init();
+        return;
+    };
+
+    #[cfg(feature = "otel")]
+    otel::init(&settings);
+
+    #[cfg(not(feature = "otel"))]
+    {
+        tracing_subscriber::fmt()
+            .with_env_filter(env_filter())
+            .init();
+        tracing::warn!(
+            "An OTLP endpoint is set, but the backend was built without the `otel` feature \
+             (traces: {}, metrics: {}); nothing is exported",
+            settings.traces,
+            settings.metrics
+        );
+    }
+}
+
+/// Flush pending spans and metrics (on graceful shutdown)
+pub fn shutdown() {
+    #[cfg(feature = "otel")]
+    otel::shutdown();
+}
+
+/// Report provider concurrency (running calls, queued calls per priority) as gauges
+pub fn observe_limits(limits: &ProviderLimits) {
+    #[cfg(feature = "otel")]
+    otel::observe_limits(limits);
+
+    #[cfg(not(feature = "otel"))]
+    let _ = limits;
+}
+
+// ========================================
+// Spans
+// ========================================
+
+/// Metrics recorded next to the spans (only exported by `otel` builds)
+#[derive(Debug, Clone, Copy)]
+enum Metric {
+    /// `http.server.request.duration`
+    HttpRequest,
+    /// `provider.call.duration`
+    ProviderCall,
+    /// `job.duration`
+    JobRun,
+}
+
+#[cfg(feature = "otel")]
+use otel::record;
+
+#[cfg(not(feature = "otel"))]
+fn record(_metric: Metric, _elapsed: Duration, _attributes: Vec<(&'static str, String)>) {}
+
+/// Middleware: server span per request, continuing the caller's
+/// `traceparent`, plus the request duration metric
+pub async fn trace_requests(request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string());
+    let name = match &route {
+        Some(route) => format!("{} {}", method, route),
+        None => method.clone(),
+    };
+
+    let span = tracing::info_span!(
+        "http.request",
+        otel.name = %name,
+        otel.kind = "server",
+        otel.status_code = field::Empty,
+        http.request.method = %method,
+        http.route = route.as_deref(),
+        http.response.status_code = field::Empty,
+    );
+    #[cfg(feature = "otel")]
+    otel::continue_trace(&span, request.headers());
+
+    let started = Instant::now();
+    let response = next.run(request).instrument(span.clone()).await;
+
+    let status = response.status();
+    span.record("http.response.status_code", status.as_u16());
+    if status.is_server_error() {
+        span.record("otel.status_code", "ERROR");
+    }
+
+    let mut attributes = vec![
+        ("http.request.method", method),
+        ("http.response.status_code", status.as_u16().to_string()),
+    ];
+    if let Some(route) = route {
+        attributes.push(("http.route", route));
+    }
+    record(Metric::HttpRequest, started.elapsed(), attributes);
+
+    response
+}
+
+/// Client span and duration metric for one AI provider call, including the
+/// wait for a concurrency slot
+pub async fn provider_call<T>(
+    kind: &'static str,
+    provider: &str,
+    operation: &'static str,
+    call: impl Future<Output = Result<T, LlmError>>,
+) -> Result<T, LlmError> {
+    let span = tracing::info_span!(
+        "provider.call",
+        otel.name = %format!("{} {}", kind, operation),
+        otel.kind = "client",
+        otel.status_code = field::Empty,
+        otel.status_message = field::Empty,
+        provider.kind = kind,
+        provider.name = provider,
+        provider.operation = operation,
+    );
+
+    let started = Instant::now();
+    let result = call.instrument(span.clone()).await;
+
+    let outcome = match &result {
+        Ok(_) => "ok",
+        Err(LlmError::Overloaded(_)) => "overloaded",
+        Err(_) => "error",
+    };
+    if let Err(e) = &result {
+        span.record("otel.status_code", "ERROR");
+        span.record("otel.status_message", e.to_string());
+    }
+    record(
+        Metric::ProviderCall,
+        started.elapsed(),
+        vec![
+            ("provider.kind", kind.to_string()),
+            ("provider.name", provider.to_string()),
+            ("provider.operation", operation.to_string()),
+            ("outcome", outcome.to_string()),
+        ],
+    );
+
+    result
+}
+
+/// Span for one run of a background job. It starts a trace of its own,
+/// linked to the request (or task) that started the job.
+pub fn job_span(id: Uuid, kind: &str, attempt: u32) -> Span {
+    let span = tracing::info_span!(
+        parent: None,
+        "job.run",
+        otel.name = %format!("job {}", kind),
+        otel.status_code = field::Empty,
+        job.id = %id,
+        job.kind = kind,
+        job.attempt = attempt,
+        job.status = field::Empty,
+    );
+    span.follows_from(Span::current());
+    span
+}
+
+/// Record the final status of a job run and its duration metric
+pub fn job_finished(span: &Span, kind: &str, status: &str, elapsed: Duration) {
+    span.record("job.status", status);
+    if status == "failed" {
+        span.record("otel.status_code", "ERROR");
+    }
+    record(
+        Metric::JobRun,
+        elapsed,
+        vec![
+            ("job.kind", kind.to_string()),
+            ("job.status", status.to_string()),
+        ],
+    );
+}
+
+// ========================================
+// OpenTelemetry (`otel` feature)
+// ========================================
+
+#[cfg(feature = "otel")]
+mod otel {
+    use axum::http::HeaderMap;
+    use opentelemetry::{
+        global, metrics::Histogram, propagation::Extractor, trace::TracerProvider as _, KeyValue,
+    };
+    use opentelemetry_otlp::{MetricExporter, Protocol, SpanExporter, WithExportConfig};
+    use opentelemetry_sdk::{
+        metrics::SdkMeterProvider, propagation::TraceContextPropagator, trace::SdkTracerProvider,
+        Resource,
+    };
+    use std::sync::{LazyLock, OnceLock};
+    use std::time::Duration;
+    use tracing::Span;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+    use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
+
+    use super::{env_filter, env_var, Encoding, ExportSettings, Metric};
+    use crate::limits::{LimiterStats, ProviderLimits};
+
+    /// `service.name` unless `OTEL_SERVICE_NAME` / `OTEL_RESOURCE_ATTRIBUTES` set one
+    const SERVICE_NAME: &str = "app-backend";
+
+    /// Exported besides the spans themselves: sqlx statements and job
+    /// progress become events of the request / job span
+    const EXPORT_FILTER: &str = "info,sqlx::query=debug,app_backend::jobs=debug";
+
+    struct Providers {
+        tracer: Option<SdkTracerProvider>,
+        meter: Option<SdkMeterProvider>,
+    }
+
+    static PROVIDERS: OnceLock<Providers> = OnceLock::new();
+
+    pub(super) fn init(settings: &ExportSettings) {
+        let resource = resource();
+        let protocol = match settings.encoding {
+            Encoding::Protobuf => Protocol::HttpBinary,
+            Encoding::Json => Protocol::HttpJson,
+        };
+        let mut errors = Vec::new();
+
+        let tracer = if settings.traces {
+            match SpanExporter::builder()
+                .with_http()
+                .with_protocol(protocol)
+                .build()
+            {
+                Ok(exporter) => Some(
+                    SdkTracerProvider::builder()
+                        .with_batch_exporter(exporter)
+                        .with_resource(resource.clone())
+                        .build(),
+                ),
+                Err(e) => {
+                    errors.push(format!("traces: {}", e));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let meter = if settings.metrics {
+            match MetricExporter::builder()
+                .with_http()
+                .with_protocol(protocol)
+                .build()
+            {
+                Ok(exporter) => Some(
+                    SdkMeterProvider::builder()
+                        .with_periodic_exporter(exporter)
+                        .with_resource(resource)
+                        .build(),
+                ),
+                Err(e) => {
+                    errors.push(format!("metrics: {}", e));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let trace_layer = tracer.as_ref().map(|provider| {
+            tracing_opentelemetry::layer()
+                .with_tracer(provider.tracer(SERVICE_NAME))
+                .with_filter(EnvFilter::new(EXPORT_FILTER))
+        });
+        tracing_subscriber::registry()
+            .with(tracing_subscriber::fmt::layer().with_filter(env_filter()))
+            .with(trace_layer)
+            .init();
+
+        if let Some(provider) = &tracer {
+            global::set_tracer_provider(provider.clone());
+            global::set_text_map_propagator(TraceContextPropagator::new());
+        }
+        if let Some(provider) = &meter {
+            global::set_meter_provider(provider.clone());
+        }
+
+        if settings.grpc_requested {
+            tracing::warn!("OTLP over gRPC is not supported; exporting over HTTP (http/protobuf)");
+        }
+        for error in errors {
+            tracing::error!("OpenTelemetry exporter disabled ({})", error);
+        }
+        tracing::info!(
+            "OpenTelemetry export enabled (traces: {}, metrics: {})",
+            tracer.is_some(),
+            meter.is_some()
+        );
+
+        let _ = PROVIDERS.set(Providers { tracer, meter });
+    }
+
+    /// `OTEL_SERVICE_NAME` / `OTEL_RESOURCE_ATTRIBUTES` are read by the
+    /// resource builder; the crate name is the fallback service name
+    fn resource() -> Resource {
+        let named = env_var("OTEL_SERVICE_NAME").is_some()
+            || env_var("OTEL_RESOURCE_ATTRIBUTES").is_some_and(|v| v.contains("service.name="));
+        let builder = Resource::builder();
+        if named {
+            builder.build()
+        } else {
+            builder.with_service_name(SERVICE_NAME).build()
+        }
+    }
+
+    pub(super) fn shutdown() {
+        let Some(providers) = PROVIDERS.get() else {
+            return;
+        };
+        if let Some(provider) = &providers.tracer {
+            if let Err(e) = provider.shutdown() {
+                tracing::warn!("Failed to flush spans: {}", e);
+            }
+        }
+        if let Some(provider) = &providers.meter {
+            if let Err(e) = provider.shutdown() {
+                tracing::warn!("Failed to flush metrics: {}", e);
+            }
+        }
+    }
+
+    struct HeaderExtractor<'a>(&'a HeaderMap);
+
+    impl Extractor for HeaderExtractor<'_> {
+        fn get(&self, key: &str) -> Option<&str> {
+            self.0.get(key).and_then(|v| v.to_str().ok())
+        }
+
+        fn keys(&self) -> Vec<&str> {
+            self.0.keys().map(|k| k.as_str()).collect()
+        }
+    }
+
+    /// Make `span` a child of the `traceparent` sent by the caller, if any
+    pub(super) fn continue_trace(span: &Span, headers: &HeaderMap) {
+        if !headers.contains_key("traceparent") {
+            return;
+        }
+        let parent = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(headers))
+        });
+        let _ = span.set_parent(parent);
+    }
+
+    struct Instruments {
+        http_request: Histogram<f64>,
+        provider_call: Histogram<f64>,
+        job_run: Histogram<f64>,
+    }
+
+    /// Created on first use, after `init` installed the meter provider
+    static INSTRUMENTS: LazyLock<Instruments> = LazyLock::new(|| {
+        let meter = global::meter(SERVICE_NAME);
+        let seconds = |name: &'static str, description: &'static str| {
+            meter
+                .f64_histogram(name)
+                .with_unit("s")
+                .with_description(description)
+                .build()
+        };
+        Instruments {
+            http_request: seconds("http.server.request.duration", "Duration of HTTP requests"),
+            provider_call: seconds(
+                "provider.call.duration",
+                "Duration of AI provider calls, including the wait for a slot",
+            ),
+            job_run: seconds("job.duration", "Duration of background job runs"),
+        }
+    });
+
+    pub(super) fn record(
+        metric: Metric,
+        elapsed: Duration,
+        attributes: Vec<(&'static str, String)>,
+    ) {
+        let histogram = match metric {
+            Metric::HttpRequest => &INSTRUMENTS.http_request,
+            Metric::ProviderCall => &INSTRUMENTS.provider_call,
+            Metric::JobRun => &INSTRUMENTS.job_run,
+        };
+        let attributes: Vec<KeyValue> = attributes
+            .into_iter()
+            .map(|(key, value)| KeyValue::new(key, value))
+            .collect();
+        histogram.record(elapsed.as_secs_f64(), &attributes);
+    }
+
+    pub(super) fn observe_limits(limits: &ProviderLimits) {
+        let meter = global::meter(SERVICE_NAME);
+        let limiters = [
+            ("llm", limits.llm.clone()),
+            ("image", limits.image.clone()),
+            ("speech", limits.speech.clone()),
+        ];
+        let stats = move || {
+            limiters
+                .iter()
+                .map(|(kind, limiter)| (*kind, limiter.stats()))
+                .collect::<Vec<(&str, LimiterStats)>>()
+        };
+
+        let running = stats.clone();
+        meter
+            .u64_observable_gauge("provider.calls.running")
+            .with_description("AI provider calls in flight")
+            .with_callback(move |observer| {
+                for (kind, stats) in running() {
+                    observer.observe(
+                        stats.running as u64,
+                        &[KeyValue::new("provider.kind", kind)],
+                    );
+                }
+            })
+            .build();
+        meter
+            .u64_observable_gauge("provider.calls.queued")
+            .with_description("AI provider calls waiting for a slot")
+            .with_callback(move |observer| {
+                for (kind, stats) in stats() {
+                    for (priority, tier) in
+                        [("interactive", &stats.interactive), ("batch", &stats.batch)]
+                    {
+                        observer.observe(
+                            tier.queued as u64,
+                            &[
+                                KeyValue::new("provider.kind", kind),
+                                KeyValue::new("priority", priority),
+                            ],
+                        );
+                    }
+                }
+            })
+            .build();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn settings(vars: &[(&str, &str)]) -> Option<ExportSettings> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        export_settings(|key| vars.get(key).cloned())
+    }
+
+    #[test]
+    fn test_export_needs_an_endpoint() {
+        assert_eq!(settings(&[]), None);
+        assert_eq!(
+            settings(&[
+                ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4318"),
+                ("OTEL_SDK_DISABLED", "true"),
+            ]),
+            None
+        );
+        assert_eq!(
+            settings(&[("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4318")]),
+            Some(ExportSettings {
+                traces: true,
+                metrics: true,
+                encoding: Encoding::Protobuf,
+                grpc_requested: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_export_per_signal() {
+        let traces_only = settings(&[
+            (
+                "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+                "http://localhost:4318/v1/traces",
+            ),
+            ("OTEL_EXPORTER_OTLP_PROTOCOL", "http/json"),
+        ])
+        .unwrap();
+        assert!(traces_only.traces && !traces_only.metrics);
+        assert_eq!(traces_only.encoding, Encoding::Json);
+
+        let no_metrics = settings(&[
+            ("OTEL_EXPORTER_OTLP_ENDPOINT", "https://api.honeycomb.io"),
+            ("OTEL_METRICS_EXPORTER", "none"),
+            ("OTEL_EXPORTER_OTLP_PROTOCOL", "grpc"),
+        ])
+        .unwrap();
+        assert!(no_metrics.traces && !no_metrics.metrics);
+        assert!(no_metrics.grpc_requested);
+        assert_eq!(no_metrics.encoding, Encoding::Protobuf);
+    }
+}
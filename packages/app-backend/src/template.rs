@@ -0,0 +1,40 @@
+// `{{name}}` placeholder interpolation shared by email templates, experiment
+// prompt templates and the akatsuki CLI prompt library (`akatsuki prompts`
+// includes this file with `#[path]`). Keep it free of crate dependencies.
+
+/// Replace every `{{ name }}` with `lookup(name)`; placeholders the lookup
+/// returns `None` for, and an unclosed `{{`, are left as written
+pub fn render_with<F>(template: &str, mut lookup: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let end = start + len + 4;
+        out.push_str(&rest[..start]);
+        match lookup(rest[start + 2..start + 2 + len].trim()) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[start..end]),
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_with() {
+        let rendered = render_with("Hi {{ name }}, {{missing}} {{unclosed", |name| {
+            (name == "name").then(|| "Ada".to_string())
+        });
+        assert_eq!(rendered, "Hi Ada, {{missing}} {{unclosed");
+    }
+}
@@ -67,6 +67,23 @@ export class SupabaseAuth {
     return this.login(email, password)
   }
 
+  /**
+   * Exchange a refresh token for a new session (expired access token)
+   */
+  async refresh(refreshToken: string): Promise<Session> {
+    const { data, error } = await this.client.auth.refreshSession({ refresh_token: refreshToken })
+
+    if (error) {
+      throw new Error(`Session refresh failed: ${error.message}`)
+    }
+
+    if (!data.session) {
+      throw new Error('Session refresh failed: No session returned')
+    }
+
+    return data.session
+  }
+
   /**
    * Get current session
    */
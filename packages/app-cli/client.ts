@@ -26,6 +26,24 @@ interface AkatsukiResponse<T = unknown> {
   error?: {
     message: string
     code?: string
+    details?: unknown
+  }
+}
+
+/**
+ * Error thrown by invoke()
+ * status is the HTTP status when the function answered; network failures
+ * have no status and code 'NETWORK_ERROR'
+ */
+export class AkatsukiError extends Error {
+  constructor(
+    message: string,
+    public status?: number,
+    public code?: string,
+    public details?: unknown
+  ) {
+    super(message)
+    this.name = 'AkatsukiError'
   }
 }
 
@@ -43,37 +61,45 @@ export class AkatsukiClient {
    * Login and set session
    */
   async login(email: string, password: string): Promise<Session> {
-    this.session = await this.auth.login(email, password)
+    const session = this.setSession(await this.auth.login(email, password))
 
-    // Update client with auth header
-    this.client = createClient(supabaseUrl!, supabaseAnonKey!, {
-      global: {
-        headers: {
-          Authorization: `Bearer ${this.session.access_token}`
-        }
-      }
-    })
-
-    console.log(`✅ Logged in as: ${this.session.user.email}`)
-    return this.session
+    console.log(`✅ Logged in as: ${session.user.email}`)
+    return session
   }
 
   /**
    * Interactive login
    */
   async loginInteractive(): Promise<Session> {
-    this.session = await this.auth.loginInteractive()
+    const session = this.setSession(await this.auth.loginInteractive())
 
+    console.log(`✅ Logged in as: ${session.user.email}`)
+    return session
+  }
+
+  /**
+   * Refresh the access token (called by generated clients on 401)
+   */
+  async refreshSession(): Promise<Session> {
+    if (!this.session) {
+      throw new Error('Not authenticated. Please call login() first.')
+    }
+    return this.setSession(await this.auth.refresh(this.session.refresh_token))
+  }
+
+  /**
+   * Store the session and rebuild the client with its auth header
+   */
+  private setSession(session: Session): Session {
+    this.session = session
     this.client = createClient(supabaseUrl!, supabaseAnonKey!, {
       global: {
         headers: {
-          Authorization: `Bearer ${this.session.access_token}`
+          Authorization: `Bearer ${session.access_token}`
         }
       }
     })
-
-    console.log(`✅ Logged in as: ${this.session.user.email}`)
-    return this.session
+    return session
   }
 
   /**
@@ -92,14 +118,25 @@ export class AkatsukiClient {
     })
 
     if (error) {
-      throw new Error(`Edge Function error: ${error.message}`)
+      // FunctionsHttpError carries the Response; FunctionsFetchError (network) does not
+      const response: Response | undefined = (error as { context?: Response }).context
+      const status = response instanceof Response ? response.status : undefined
+      const envelope = status !== undefined
+        ? await response!.json().catch(() => null) as AkatsukiResponse | null
+        : null
+      throw new AkatsukiError(
+        envelope?.error?.message || `Edge Function error: ${error.message}`,
+        status,
+        envelope?.error?.code ?? (error.name === 'FunctionsFetchError' ? 'NETWORK_ERROR' : undefined),
+        envelope?.error?.details
+      )
     }
 
     // Parse AkatsukiResponse
     if (data && typeof data === 'object' && 'success' in data) {
       if (!data.success) {
         const errorMsg = data.error?.message || 'Function call failed'
-        throw new Error(errorMsg)
+        throw new AkatsukiError(errorMsg, undefined, data.error?.code, data.error?.details)
       }
       return data.result as T
     }
@@ -32,6 +32,7 @@ export interface ServiceResponse<T = unknown> {
  */
 interface ExtendedError extends Error {
   code?: string
+  status?: number
 }
 
 /**
@@ -110,7 +111,7 @@ export class EdgeFunctionService {
       // Supabase Functions自体のエラー（ネットワークエラー等）
       if (error) {
         console.error(`[EdgeFunctionService] ${functionName} Supabase error:`, error)
-        return { data: null, error: new Error(error.message || 'Edge Function invocation failed') }
+        return { data: null, error: await this.toHttpError(error) }
       }
 
       // dataがない、またはオブジェクトでない場合
@@ -208,6 +209,33 @@ export class EdgeFunctionService {
     // 通常のinvokeを使用（Authorizationヘッダーは自動設定される）
     return this.invoke<T>(functionName, payload, options)
   }
+
+  /**
+   * 非2xxレスポンスのエラーを変換
+   *
+   * Akatsukiハンドラーは 4xx/5xx でも { success: false, error: { message, code } } を返すため、
+   * レスポンスボディから message / code / status を取り出す（409 の競合検知などに使用）
+   */
+  private static async toHttpError(error: { message?: string; context?: unknown }): Promise<ExtendedError> {
+    const fallback: ExtendedError = new Error(error.message || 'Edge Function invocation failed')
+    if (!(error.context instanceof Response)) {
+      return fallback
+    }
+
+    fallback.status = error.context.status
+    try {
+      const body = (await error.context.clone().json()) as AkatsukiResponse
+      if (body.error && typeof body.error === 'object') {
+        const httpError: ExtendedError = new Error(body.error.message)
+        httpError.code = body.error.code
+        httpError.status = error.context.status
+        return httpError
+      }
+    } catch {
+      // ボディがJSONでない場合は元のエラーを使う
+    }
+    return fallback
+  }
 }
 
 /**
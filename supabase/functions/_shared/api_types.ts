@@ -32,6 +32,8 @@ export interface AkatsukiErrorResponse {
     message: string
     /** エラーコード（例: 'VALIDATION_FAILED', 'UNAUTHORIZED', 'QUOTA_EXCEEDED', 'INTERNAL_ERROR'） */
     code?: string
+    /** 追加情報（例: Zod の field issues、相関用の requestId） */
+    details?: unknown
   }
 }
 
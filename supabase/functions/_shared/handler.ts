@@ -251,6 +251,7 @@ export async function createAkatsukiHandler<I, O>(
       error: {
         message: error.message || 'Internal server error',
         code: error.code || ErrorCodes.INTERNAL_ERROR,
+        ...(error.details !== undefined ? { details: error.details } : {}),
       },
     }
 
@@ -377,6 +378,7 @@ export async function createSystemHandler<I, O>(
       error: {
         message: error.message || 'Internal server error',
         code: error.code || ErrorCodes.INTERNAL_ERROR,
+        ...(error.details !== undefined ? { details: error.details } : {}),
       },
     }
 
@@ -0,0 +1,98 @@
+-- Create agent conversation tables
+-- Multi-turn agent history for app-backend (/api/aigen/conversations)
+
+-- ============================================================
+-- 1. Create agent_conversations table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.agent_conversations (
+  id UUID NOT NULL DEFAULT gen_random_uuid() PRIMARY KEY,
+  user_id UUID REFERENCES auth.users(id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  -- Conversation fields
+  title TEXT,
+  system_prompt TEXT,
+  model TEXT,
+  summary TEXT
+);
+
+-- ============================================================
+-- 2. Create agent_messages table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.agent_messages (
+  id UUID NOT NULL DEFAULT gen_random_uuid() PRIMARY KEY,
+  conversation_id UUID NOT NULL REFERENCES public.agent_conversations(id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+
+  -- Message fields
+  role TEXT NOT NULL CHECK (role IN ('system', 'user', 'assistant')),
+  content TEXT NOT NULL,
+  token_estimate INTEGER NOT NULL DEFAULT 0,
+  summarized BOOLEAN NOT NULL DEFAULT false
+);
+
+-- ============================================================
+-- 3. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_agent_conversations_user_id ON public.agent_conversations(user_id);
+CREATE INDEX IF NOT EXISTS idx_agent_messages_conversation_created ON public.agent_messages(conversation_id, created_at);
+
+-- ============================================================
+-- 4. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.agent_conversations ENABLE ROW LEVEL SECURITY;
+ALTER TABLE public.agent_messages ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 5. RLS Policies
+-- ============================================================
+
+-- Users can view their own conversations
+CREATE POLICY "Users can view their own conversations"
+  ON public.agent_conversations
+  FOR SELECT
+  USING (auth.uid() = user_id);
+
+-- Users can view messages of their own conversations
+CREATE POLICY "Users can view their own conversation messages"
+  ON public.agent_messages
+  FOR SELECT
+  USING (
+    EXISTS (
+      SELECT 1 FROM public.agent_conversations c
+      WHERE c.id = conversation_id AND c.user_id = auth.uid()
+    )
+  );
+
+-- Service role (app-backend) can do everything
+CREATE POLICY "Service role has full access to agent_conversations"
+  ON public.agent_conversations
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+CREATE POLICY "Service role has full access to agent_messages"
+  ON public.agent_messages
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+-- ============================================================
+-- 6. Trigger for updated_at
+-- ============================================================
+
+CREATE OR REPLACE FUNCTION public.update_agent_conversations_updated_at()
+RETURNS TRIGGER AS $$
+BEGIN
+  NEW.updated_at = NOW();
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER update_agent_conversations_updated_at_trigger
+  BEFORE UPDATE ON public.agent_conversations
+  FOR EACH ROW
+  EXECUTE FUNCTION public.update_agent_conversations_updated_at();
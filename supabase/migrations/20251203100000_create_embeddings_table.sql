@@ -0,0 +1,50 @@
+-- Create embeddings table
+-- Vector store for app-backend semantic search (/api/aigen/embeddings, /api/aigen/search)
+
+-- ============================================================
+-- 1. Enable pgvector
+-- ============================================================
+
+CREATE EXTENSION IF NOT EXISTS vector WITH SCHEMA extensions;
+
+-- ============================================================
+-- 2. Create embeddings table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.embeddings (
+  id UUID NOT NULL DEFAULT gen_random_uuid() PRIMARY KEY,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  -- Embedding fields
+  namespace TEXT NOT NULL DEFAULT 'default',
+  content TEXT NOT NULL,
+  metadata JSONB NOT NULL DEFAULT '{}'::jsonb,
+  model TEXT NOT NULL,
+  -- Must match EMBEDDING_DIMENSIONS in app-backend
+  embedding extensions.vector(1536) NOT NULL
+);
+
+-- ============================================================
+-- 3. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_embeddings_namespace ON public.embeddings(namespace);
+CREATE INDEX IF NOT EXISTS idx_embeddings_embedding_hnsw
+  ON public.embeddings
+  USING hnsw (embedding extensions.vector_cosine_ops);
+
+-- ============================================================
+-- 4. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.embeddings ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 5. RLS Policies
+-- ============================================================
+
+-- Service role (app-backend) can do everything
+CREATE POLICY "Service role has full access to embeddings"
+  ON public.embeddings
+  FOR ALL
+  USING (auth.role() = 'service_role');
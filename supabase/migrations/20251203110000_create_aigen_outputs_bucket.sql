@@ -0,0 +1,33 @@
+-- ============================================================================
+-- AIGen Outputs Storage Bucket
+-- ============================================================================
+-- Private bucket for assets generated by the backend (TTS audio, transcribed
+-- uploads, generated images). The backend writes with the service role key and
+-- hands out signed URLs, so no client-side policies are needed.
+-- ============================================================================
+
+INSERT INTO storage.buckets (id, name, public, file_size_limit, allowed_mime_types)
+VALUES (
+  'aigen_outputs',
+  'aigen_outputs',
+  false,
+  52428800, -- 50MB in bytes
+  ARRAY[
+    'audio/mpeg', 'audio/ogg', 'audio/aac', 'audio/flac', 'audio/wav', 'audio/pcm',
+    'audio/webm', 'audio/mp4', 'audio/x-m4a',
+    'image/jpeg', 'image/png', 'image/webp'
+  ]
+)
+ON CONFLICT (id) DO UPDATE SET
+  public = EXCLUDED.public,
+  file_size_limit = EXCLUDED.file_size_limit,
+  allowed_mime_types = EXCLUDED.allowed_mime_types;
+
+-- Service role bypasses RLS; signed URLs are used for downloads
+DROP POLICY IF EXISTS "Service role has full access to aigen_outputs" ON storage.objects;
+
+CREATE POLICY "Service role has full access to aigen_outputs"
+ON storage.objects FOR ALL
+TO service_role
+USING (bucket_id = 'aigen_outputs')
+WITH CHECK (bucket_id = 'aigen_outputs');
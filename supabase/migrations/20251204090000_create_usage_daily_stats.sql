@@ -0,0 +1,54 @@
+-- Create usage_daily_stats table
+-- Daily LLM usage rollup maintained by the app-backend "aggregate-usage" task
+
+-- ============================================================
+-- 1. Create usage_daily_stats table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.usage_daily_stats (
+  day DATE NOT NULL,
+  provider TEXT NOT NULL,
+  model_id TEXT NOT NULL,
+  request_type TEXT NOT NULL,
+
+  calls BIGINT NOT NULL DEFAULT 0,
+  failures BIGINT NOT NULL DEFAULT 0,
+  input_tokens BIGINT NOT NULL DEFAULT 0,
+  output_tokens BIGINT NOT NULL DEFAULT 0,
+  -- Distinct users calling this model on this day
+  users BIGINT NOT NULL DEFAULT 0,
+
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  PRIMARY KEY (day, provider, model_id, request_type)
+);
+
+-- ============================================================
+-- 2. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_usage_daily_stats_day ON public.usage_daily_stats(day DESC);
+
+-- ============================================================
+-- 3. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.usage_daily_stats ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 4. RLS Policies
+-- ============================================================
+
+-- Service role (app-backend) writes the rollup
+CREATE POLICY "Service role has full access to usage_daily_stats"
+  ON public.usage_daily_stats
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+-- Admins can read usage for dashboards
+CREATE POLICY "Admins can view usage_daily_stats"
+  ON public.usage_daily_stats
+  FOR SELECT
+  USING (is_admin());
+
+COMMENT ON TABLE public.usage_daily_stats IS 'Daily LLM usage per provider/model (rolled up from llm_call_logs by app-backend)';
@@ -0,0 +1,105 @@
+-- Create email_templates / email_deliveries tables
+-- Notification emails sent by app-backend via Resend (job completion, quota warnings)
+
+-- ============================================================
+-- 1. Create email_templates table
+-- ============================================================
+
+-- Subject and bodies use {{variable}} placeholders
+CREATE TABLE IF NOT EXISTS public.email_templates (
+  key TEXT PRIMARY KEY,
+  subject TEXT NOT NULL,
+  html_body TEXT NOT NULL,
+  text_body TEXT,
+  description TEXT,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================
+-- 2. Create email_deliveries table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.email_deliveries (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  template_key TEXT NOT NULL,
+  recipient TEXT NOT NULL,
+  user_id UUID REFERENCES auth.users(id) ON DELETE SET NULL,
+  -- Prevents sending the same notification twice (e.g. quota_warning:<user>:<month>)
+  dedupe_key TEXT UNIQUE,
+  status TEXT NOT NULL CHECK (status IN ('sent', 'failed')),
+  -- Resend email id
+  provider_id TEXT,
+  error TEXT,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================
+-- 3. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_email_deliveries_user_id ON public.email_deliveries(user_id);
+CREATE INDEX IF NOT EXISTS idx_email_deliveries_created_at ON public.email_deliveries(created_at DESC);
+
+-- ============================================================
+-- 4. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.email_templates ENABLE ROW LEVEL SECURITY;
+ALTER TABLE public.email_deliveries ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 5. RLS Policies
+-- ============================================================
+
+-- Service role (app-backend) renders templates and records deliveries
+CREATE POLICY "Service role has full access to email_templates"
+  ON public.email_templates
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+CREATE POLICY "Service role has full access to email_deliveries"
+  ON public.email_deliveries
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+-- Admins edit templates and review deliveries
+CREATE POLICY "Admins can manage email_templates"
+  ON public.email_templates
+  FOR ALL
+  USING (is_admin());
+
+CREATE POLICY "Admins can view email_deliveries"
+  ON public.email_deliveries
+  FOR SELECT
+  USING (is_admin());
+
+-- ============================================================
+-- 6. Default templates
+-- ============================================================
+
+INSERT INTO public.email_templates (key, subject, html_body, text_body, description) VALUES
+(
+  'job_completed',
+  'Your {{kind}} job has finished',
+  '<p>Your <strong>{{kind}}</strong> job <code>{{job_id}}</code> completed after {{duration}}.</p>',
+  'Your {{kind}} job {{job_id}} completed after {{duration}}.',
+  'Long-running background job completed (vars: kind, job_id, duration)'
+),
+(
+  'job_failed',
+  'Your {{kind}} job failed',
+  '<p>Your <strong>{{kind}}</strong> job <code>{{job_id}}</code> failed after {{duration}}.</p><p>{{error}}</p>',
+  'Your {{kind}} job {{job_id}} failed after {{duration}}: {{error}}',
+  'Long-running background job failed (vars: kind, job_id, duration, error)'
+),
+(
+  'quota_warning',
+  'You have used {{percent}}% of your monthly quota',
+  '<p>You have used {{requests_used}} of {{request_limit}} requests ({{percent}}%) on the {{plan}} plan for {{month}}.</p>',
+  'You have used {{requests_used}} of {{request_limit}} requests ({{percent}}%) on the {{plan}} plan for {{month}}.',
+  'Monthly request quota nearly exhausted (vars: requests_used, request_limit, percent, plan, month)'
+)
+ON CONFLICT (key) DO NOTHING;
+
+COMMENT ON TABLE public.email_templates IS 'Notification email templates with {{variable}} placeholders (rendered by app-backend)';
+COMMENT ON TABLE public.email_deliveries IS 'Notification emails sent by app-backend';
@@ -0,0 +1,79 @@
+-- Create gallery_tags / gallery_favorites tables
+-- Tags and favorites for generated images (files rows with metadata.type = 'generated_image'),
+-- served by the app-backend gallery API
+
+-- ============================================================
+-- 1. Create gallery_tags table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.gallery_tags (
+  file_id UUID NOT NULL REFERENCES public.files(id) ON DELETE CASCADE,
+  -- Lowercased, trimmed
+  tag TEXT NOT NULL CHECK (char_length(tag) BETWEEN 1 AND 50),
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  PRIMARY KEY (file_id, tag)
+);
+
+-- ============================================================
+-- 2. Create gallery_favorites table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.gallery_favorites (
+  user_id UUID NOT NULL REFERENCES auth.users(id) ON DELETE CASCADE,
+  file_id UUID NOT NULL REFERENCES public.files(id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  PRIMARY KEY (user_id, file_id)
+);
+
+-- ============================================================
+-- 3. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_gallery_tags_tag ON public.gallery_tags(tag);
+CREATE INDEX IF NOT EXISTS idx_gallery_favorites_file_id ON public.gallery_favorites(file_id);
+
+-- Gallery listing: a user's generated images, newest first
+CREATE INDEX IF NOT EXISTS idx_files_generated_images
+  ON public.files(owner_id, created_at DESC)
+  WHERE metadata->>'type' = 'generated_image';
+
+-- ============================================================
+-- 4. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.gallery_tags ENABLE ROW LEVEL SECURITY;
+ALTER TABLE public.gallery_favorites ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 5. RLS Policies
+-- ============================================================
+
+-- Service role (app-backend) manages tags and favorites for all users
+CREATE POLICY "Service role has full access to gallery_tags"
+  ON public.gallery_tags
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+CREATE POLICY "Service role has full access to gallery_favorites"
+  ON public.gallery_favorites
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+-- Users manage tags on their own files
+CREATE POLICY "Users can manage tags on their own files"
+  ON public.gallery_tags
+  FOR ALL
+  USING (EXISTS (SELECT 1 FROM public.files f WHERE f.id = file_id AND f.owner_id = auth.uid()))
+  WITH CHECK (EXISTS (SELECT 1 FROM public.files f WHERE f.id = file_id AND f.owner_id = auth.uid()));
+
+-- Users manage their own favorites
+CREATE POLICY "Users can manage their own favorites"
+  ON public.gallery_favorites
+  FOR ALL
+  USING (auth.uid() = user_id)
+  WITH CHECK (auth.uid() = user_id);
+
+COMMENT ON TABLE public.gallery_tags IS 'Tags on generated images (files rows)';
+COMMENT ON TABLE public.gallery_favorites IS 'Generated images a user marked as favorite';
@@ -0,0 +1,57 @@
+-- Create asset_shares table
+-- Expiring public share links for generated assets (files rows), served by the
+-- app-backend at GET /share/:token.
+-- Unlike url_aliases (publicly readable), share tokens are only visible to their owner.
+
+-- ============================================================
+-- 1. Create asset_shares table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.asset_shares (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  file_id UUID NOT NULL REFERENCES public.files(id) ON DELETE CASCADE,
+  -- Random, unguessable token used in the public URL
+  token TEXT NOT NULL UNIQUE CHECK (token ~ '^[a-zA-Z0-9]{32,64}$'),
+  created_by UUID NOT NULL REFERENCES auth.users(id) ON DELETE CASCADE,
+  expires_at TIMESTAMPTZ NOT NULL,
+  -- Set when the owner revokes the link
+  revoked_at TIMESTAMPTZ,
+
+  -- Access statistics
+  access_count INTEGER NOT NULL DEFAULT 0,
+  last_accessed_at TIMESTAMPTZ,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================
+-- 2. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_asset_shares_file_id ON public.asset_shares(file_id);
+CREATE INDEX IF NOT EXISTS idx_asset_shares_created_by ON public.asset_shares(created_by);
+
+-- ============================================================
+-- 3. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.asset_shares ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 4. RLS Policies
+-- ============================================================
+
+-- Service role (app-backend) creates, resolves and revokes shares
+CREATE POLICY "Service role has full access to asset_shares"
+  ON public.asset_shares
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+-- Users see the share links they created
+CREATE POLICY "Users can view their own asset_shares"
+  ON public.asset_shares
+  FOR SELECT
+  USING (auth.uid() = created_by);
+
+COMMENT ON TABLE public.asset_shares IS 'Expiring public share links for files (resolved by app-backend)';
+COMMENT ON COLUMN public.asset_shares.token IS 'Secret part of the public URL /share/:token';
@@ -0,0 +1,125 @@
+-- Create experiments / experiment_variants / experiment_results tables
+-- A/B tests of prompt and model variants for agent-execute and text-to-image,
+-- managed through the app-backend admin API
+
+-- ============================================================
+-- 1. Create experiments table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.experiments (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  name TEXT NOT NULL UNIQUE CHECK (char_length(name) BETWEEN 1 AND 100),
+  description TEXT,
+  -- Only running experiments assign variants
+  status TEXT NOT NULL DEFAULT 'running' CHECK (status IN ('draft', 'running', 'stopped')),
+  created_by UUID REFERENCES auth.users(id) ON DELETE SET NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================
+-- 2. Create experiment_variants table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.experiment_variants (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  experiment_id UUID NOT NULL REFERENCES public.experiments(id) ON DELETE CASCADE,
+  -- Short label such as 'control' or 'b'
+  key TEXT NOT NULL CHECK (char_length(key) BETWEEN 1 AND 50),
+  -- Relative share of users assigned to this variant
+  weight INTEGER NOT NULL DEFAULT 1 CHECK (weight > 0),
+  -- Overrides (NULL keeps the request's value)
+  model TEXT,
+  system_prompt TEXT,
+  -- Wraps the user's input; {{input}} is replaced with the request prompt/task
+  prompt_template TEXT,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  UNIQUE (experiment_id, key)
+);
+
+-- ============================================================
+-- 3. Create experiment_results table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.experiment_results (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  experiment_id UUID NOT NULL REFERENCES public.experiments(id) ON DELETE CASCADE,
+  variant_id UUID NOT NULL REFERENCES public.experiment_variants(id) ON DELETE CASCADE,
+  -- NULL for anonymous callers
+  user_id UUID REFERENCES auth.users(id) ON DELETE SET NULL,
+  endpoint TEXT NOT NULL CHECK (endpoint IN ('agent-execute', 'text-to-image')),
+  succeeded BOOLEAN NOT NULL,
+  latency_ms INTEGER NOT NULL,
+  tokens_used INTEGER,
+  -- User feedback: 1 (thumbs up) / -1 (thumbs down)
+  rating SMALLINT CHECK (rating IN (-1, 1)),
+  comment TEXT,
+  feedback_at TIMESTAMPTZ,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================
+-- 4. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_experiment_variants_experiment_id ON public.experiment_variants(experiment_id);
+CREATE INDEX IF NOT EXISTS idx_experiment_results_variant_id ON public.experiment_results(variant_id);
+CREATE INDEX IF NOT EXISTS idx_experiment_results_experiment_id ON public.experiment_results(experiment_id, created_at DESC);
+
+-- ============================================================
+-- 5. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.experiments ENABLE ROW LEVEL SECURITY;
+ALTER TABLE public.experiment_variants ENABLE ROW LEVEL SECURITY;
+ALTER TABLE public.experiment_results ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 6. RLS Policies
+-- ============================================================
+
+-- Service role (app-backend) assigns variants and records results
+CREATE POLICY "Service role has full access to experiments"
+  ON public.experiments
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+CREATE POLICY "Service role has full access to experiment_variants"
+  ON public.experiment_variants
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+CREATE POLICY "Service role has full access to experiment_results"
+  ON public.experiment_results
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+-- Admins manage experiments and review results
+CREATE POLICY "Admins can manage experiments"
+  ON public.experiments
+  FOR ALL
+  USING (is_admin());
+
+CREATE POLICY "Admins can manage experiment_variants"
+  ON public.experiment_variants
+  FOR ALL
+  USING (is_admin());
+
+CREATE POLICY "Admins can view experiment_results"
+  ON public.experiment_results
+  FOR SELECT
+  USING (is_admin());
+
+-- ============================================================
+-- 7. Triggers
+-- ============================================================
+
+CREATE TRIGGER update_experiments_updated_at
+  BEFORE UPDATE ON public.experiments
+  FOR EACH ROW
+  EXECUTE FUNCTION public.update_updated_at_column();
+
+COMMENT ON TABLE public.experiments IS 'A/B experiments over prompt/model variants';
+COMMENT ON TABLE public.experiment_variants IS 'Prompt/model variants of an experiment with assignment weights';
+COMMENT ON TABLE public.experiment_results IS 'One row per experiment-assigned generation, with optional user feedback';
@@ -0,0 +1,92 @@
+-- Create generation_feedback table
+-- Thumbs up/down, tags and comments on generations (background jobs and
+-- experiment-assigned agent-execute / text-to-image calls), collected by the
+-- app-backend at POST /api/aigen/feedback. Replaces the rating columns of
+-- experiment_results.
+
+-- ============================================================
+-- 1. Create generation_feedback table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.generation_feedback (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  -- Background job id or experiment result id the feedback is about
+  job_id UUID NOT NULL,
+  -- Job kind or endpoint (upscale, text-to-image, agent-execute, ...)
+  kind TEXT NOT NULL,
+  experiment_result_id UUID REFERENCES public.experiment_results(id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES auth.users(id) ON DELETE CASCADE,
+  -- 1 (thumbs up) / -1 (thumbs down)
+  rating SMALLINT NOT NULL CHECK (rating IN (-1, 1)),
+  tags TEXT[] NOT NULL DEFAULT '{}',
+  comment TEXT CHECK (char_length(comment) <= 2000),
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  -- One (updatable) feedback per user and generation
+  UNIQUE (user_id, job_id)
+);
+
+-- ============================================================
+-- 2. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_generation_feedback_kind ON public.generation_feedback(kind, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_generation_feedback_experiment_result_id
+  ON public.generation_feedback(experiment_result_id)
+  WHERE experiment_result_id IS NOT NULL;
+
+-- ============================================================
+-- 3. Move experiment ratings
+-- ============================================================
+
+INSERT INTO public.generation_feedback
+  (job_id, kind, experiment_result_id, user_id, rating, comment, created_at, updated_at)
+SELECT id, endpoint, id, user_id, rating, comment, feedback_at, feedback_at
+FROM public.experiment_results
+WHERE rating IS NOT NULL AND user_id IS NOT NULL
+ON CONFLICT (user_id, job_id) DO NOTHING;
+
+ALTER TABLE public.experiment_results
+  DROP COLUMN IF EXISTS rating,
+  DROP COLUMN IF EXISTS comment,
+  DROP COLUMN IF EXISTS feedback_at;
+
+-- ============================================================
+-- 4. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.generation_feedback ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 5. RLS Policies
+-- ============================================================
+
+-- Service role (app-backend) records feedback and computes aggregates
+CREATE POLICY "Service role has full access to generation_feedback"
+  ON public.generation_feedback
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+-- Users see their own feedback
+CREATE POLICY "Users can view their own generation_feedback"
+  ON public.generation_feedback
+  FOR SELECT
+  USING (auth.uid() = user_id);
+
+-- Admins review all feedback
+CREATE POLICY "Admins can view generation_feedback"
+  ON public.generation_feedback
+  FOR SELECT
+  USING (is_admin());
+
+-- ============================================================
+-- 6. Triggers
+-- ============================================================
+
+CREATE TRIGGER update_generation_feedback_updated_at
+  BEFORE UPDATE ON public.generation_feedback
+  FOR EACH ROW
+  EXECUTE FUNCTION public.update_updated_at_column();
+
+COMMENT ON TABLE public.generation_feedback IS 'User feedback (thumbs up/down, tags, comment) on generations';
@@ -0,0 +1,60 @@
+-- Create llm_cache table
+-- Cached agent-execute completions (app-backend). Exact matches use request_hash;
+-- the optional semantic lookup compares prompt embeddings within the same scope
+-- (model + preceding messages)
+
+-- ============================================================
+-- 1. Create llm_cache table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.llm_cache (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  -- md5 of the canonical request (model + all messages)
+  request_hash TEXT NOT NULL UNIQUE,
+  request TEXT NOT NULL,
+  -- md5 of the model + all messages but the last one
+  scope_hash TEXT NOT NULL,
+  -- Last (user) message, the text that is embedded
+  prompt TEXT NOT NULL,
+
+  -- Cached completion
+  model_used TEXT NOT NULL,
+  content TEXT NOT NULL,
+  tokens_used INTEGER,
+
+  -- Only set when LLM_CACHE_SEMANTIC is enabled; must match EMBEDDING_DIMENSIONS
+  embedding extensions.vector(1536),
+
+  hit_count INTEGER NOT NULL DEFAULT 0,
+  last_hit_at TIMESTAMPTZ,
+  expires_at TIMESTAMPTZ NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================
+-- 2. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_llm_cache_scope_hash ON public.llm_cache(scope_hash);
+CREATE INDEX IF NOT EXISTS idx_llm_cache_expires_at ON public.llm_cache(expires_at);
+CREATE INDEX IF NOT EXISTS idx_llm_cache_embedding_hnsw
+  ON public.llm_cache
+  USING hnsw (embedding extensions.vector_cosine_ops);
+
+-- ============================================================
+-- 3. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.llm_cache ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 4. RLS Policies
+-- ============================================================
+
+-- Service role (app-backend) only: cached prompts may contain user data
+CREATE POLICY "Service role has full access to llm_cache"
+  ON public.llm_cache
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+COMMENT ON TABLE public.llm_cache IS 'Cached LLM completions for agent-execute (exact and semantic lookup)';
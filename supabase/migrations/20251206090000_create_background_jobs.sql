@@ -0,0 +1,54 @@
+-- Create background_jobs table
+-- State of app-backend background jobs (upscale, inpaint, docs-index, ...).
+-- Every state transition is written here so jobs survive a restart: on startup
+-- unfinished jobs are resumed from `input`, or marked failed with the reason
+
+-- ============================================================
+-- 1. Create background_jobs table
+-- ============================================================
+
+CREATE TABLE IF NOT EXISTS public.background_jobs (
+  id UUID PRIMARY KEY,
+  kind TEXT NOT NULL,
+  -- Signed-in user who started the job (no FK: tokens may outlive deleted users)
+  owner_id UUID,
+  status TEXT NOT NULL
+    CHECK (status IN ('queued', 'generating', 'completed', 'failed', 'cancelled')),
+  progress SMALLINT NOT NULL DEFAULT 0 CHECK (progress BETWEEN 0 AND 100),
+  attempts INTEGER NOT NULL DEFAULT 0,
+
+  -- Request needed to run the job again (NULL for jobs that cannot resume)
+  input JSONB,
+  result JSONB,
+  error TEXT,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ============================================================
+-- 2. Create indexes for performance
+-- ============================================================
+
+CREATE INDEX IF NOT EXISTS idx_background_jobs_status_updated_at
+  ON public.background_jobs(status, updated_at);
+CREATE INDEX IF NOT EXISTS idx_background_jobs_created_at
+  ON public.background_jobs(created_at DESC);
+
+-- ============================================================
+-- 3. Enable Row Level Security (RLS)
+-- ============================================================
+
+ALTER TABLE public.background_jobs ENABLE ROW LEVEL SECURITY;
+
+-- ============================================================
+-- 4. RLS Policies
+-- ============================================================
+
+-- Service role (app-backend) only: clients read jobs through /api/jobs/:id
+CREATE POLICY "Service role has full access to background_jobs"
+  ON public.background_jobs
+  FOR ALL
+  USING (auth.role() = 'service_role');
+
+COMMENT ON TABLE public.background_jobs IS 'app-backend background job state, persisted so jobs can resume after a restart';
@@ -0,0 +1,25 @@
+-- ============================================================================
+-- Add retention columns to files
+-- ============================================================================
+-- Soft delete for assets stored by the app-backend (generated images, TTS
+-- audio, transcription uploads): deleting or expiring an asset sets
+-- status = 'deleting' and deleted_at; the purge-assets task deletes the row
+-- after the grace period, and the delete trigger removes the storage object.
+-- ============================================================================
+
+-- Add deleted_at column
+ALTER TABLE public.files
+ADD COLUMN IF NOT EXISTS deleted_at timestamptz;
+
+-- Purge: rows whose grace period is over
+CREATE INDEX IF NOT EXISTS idx_files_deleted_at
+  ON public.files(deleted_at)
+  WHERE deleted_at IS NOT NULL;
+
+-- Expiry: backend assets by age
+CREATE INDEX IF NOT EXISTS idx_files_backend_assets_created_at
+  ON public.files(created_at)
+  WHERE metadata->>'type' IN ('generated_image', 'tts_audio', 'stt_upload');
+
+-- Add comment
+COMMENT ON COLUMN public.files.deleted_at IS 'Soft-delete time (status deleting); purged after the retention grace period';
@@ -0,0 +1,133 @@
+//! Zero-copy pixel buffers for per-frame processing (e.g. video).
+//!
+//! Memory ownership rules:
+//! - A `PixelBuffer` is allocated inside wasm linear memory and owned by wasm.
+//!   JavaScript must call `free()` when it is no longer needed.
+//! - `new Uint8Array(memory.buffer, buffer.ptr(), buffer.len())` is a view, not a copy:
+//!   write the frame into it and read results from it directly.
+//! - Views are invalidated when wasm memory grows (any allocation may grow it).
+//!   The `*_buffer` / `transform_into` functions never allocate, but re-create views after
+//!   constructing new buffers or calling copying APIs.
+
+use wasm_bindgen::prelude::*;
+
+use crate::error::ImageError;
+use crate::transform::Transform;
+use crate::{adjust_in_place, grayscale_in_place};
+
+/// RGBA byte buffer living in wasm memory
+#[wasm_bindgen]
+pub struct PixelBuffer {
+    data: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl PixelBuffer {
+    /// Allocate a zero-filled buffer of `len` bytes (`width * height * 4` for RGBA)
+    #[wasm_bindgen(constructor)]
+    pub fn new(len: usize) -> PixelBuffer {
+        PixelBuffer { data: vec![0; len] }
+    }
+
+    /// Address of the first byte in wasm memory
+    pub fn ptr(&self) -> *const u8 {
+        self.data.as_ptr()
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl PixelBuffer {
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+/// In-place RGBA grayscale (alpha preserved)
+#[wasm_bindgen]
+pub fn grayscale_buffer(buffer: &mut PixelBuffer) -> Result<(), ImageError> {
+    grayscale_in_place(buffer.as_mut_slice())
+}
+
+/// In-place brightness / contrast / saturation adjustment (see `adjust`)
+#[wasm_bindgen]
+pub fn adjust_buffer(
+    buffer: &mut PixelBuffer,
+    brightness: f32,
+    contrast: f32,
+    saturation: f32,
+) -> Result<(), ImageError> {
+    adjust_in_place(buffer.as_mut_slice(), brightness, contrast, saturation)
+}
+
+/// Rotate / flip `input` into `output` (same length; use `Transform` dimensions for the result)
+#[wasm_bindgen]
+pub fn transform_into(
+    input: &PixelBuffer,
+    output: &mut PixelBuffer,
+    width: u32,
+    height: u32,
+    op: Transform,
+) -> Result<(), ImageError> {
+    op.apply_into(input.as_slice(), output.as_mut_slice(), width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(data: &[u8]) -> PixelBuffer {
+        let mut buffer = PixelBuffer::new(data.len());
+        buffer.as_mut_slice().copy_from_slice(data);
+        buffer
+    }
+
+    #[test]
+    fn test_in_place_matches_copying_api() {
+        let rgba = [200, 50, 10, 255, 0, 128, 255, 64];
+
+        let mut buffer = filled(&rgba);
+        let ptr = buffer.ptr();
+        adjust_buffer(&mut buffer, 0.2, 0.1, -0.3).unwrap();
+        assert_eq!(buffer.ptr(), ptr, "must not reallocate");
+        assert_eq!(
+            buffer.as_slice(),
+            crate::adjust(&rgba, 0.2, 0.1, -0.3).unwrap()
+        );
+
+        let mut buffer = filled(&rgba);
+        grayscale_buffer(&mut buffer).unwrap();
+        assert_eq!(buffer.as_slice(), crate::rgba_to_grayscale(&rgba).unwrap());
+    }
+
+    #[test]
+    fn test_transform_into() {
+        let input = filled(&[1, 1, 1, 1, 2, 2, 2, 2]);
+        let mut output = PixelBuffer::new(8);
+        transform_into(&input, &mut output, 2, 1, Transform::Rotate90).unwrap();
+        assert_eq!(Transform::Rotate90.dimensions(2, 1), (1, 2));
+        assert_eq!(output.as_slice(), &[1, 1, 1, 1, 2, 2, 2, 2]);
+
+        transform_into(&input, &mut output, 2, 1, Transform::FlipHorizontal).unwrap();
+        assert_eq!(output.as_slice(), &[2, 2, 2, 2, 1, 1, 1, 1]);
+
+        let mut short = PixelBuffer::new(4);
+        assert_eq!(
+            transform_into(&input, &mut short, 2, 1, Transform::Rotate180),
+            Err(ImageError::OutputLength {
+                expected: 8,
+                actual: 4
+            })
+        );
+    }
+}
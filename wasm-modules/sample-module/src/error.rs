@@ -0,0 +1,77 @@
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Input validation error, surfaced to JavaScript as a thrown `Error`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    /// Buffer length does not match `width * height * 4`
+    DimensionMismatch { len: usize, width: u32, height: u32 },
+    /// Buffer length is not a multiple of the pixel size (3 for RGB, 4 for RGBA)
+    InvalidLength { len: usize, pixel_size: usize },
+    /// Caller-provided output buffer has the wrong length
+    OutputLength { expected: usize, actual: usize },
+    /// Encoded image could not be decoded or re-encoded
+    Codec(String),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::DimensionMismatch { len, width, height } => write!(
+                f,
+                "buffer length {} does not match {}x{} RGBA ({} bytes)",
+                len,
+                width,
+                height,
+                *width as usize * *height as usize * 4
+            ),
+            ImageError::InvalidLength { len, pixel_size } => write!(
+                f,
+                "buffer length {} is not a multiple of {} bytes per pixel",
+                len, pixel_size
+            ),
+            ImageError::OutputLength { expected, actual } => write!(
+                f,
+                "output buffer length {} does not match the expected {} bytes",
+                actual, expected
+            ),
+            ImageError::Codec(message) => write!(f, "invalid image data: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+impl From<ImageError> for JsValue {
+    fn from(err: ImageError) -> Self {
+        JsError::new(&err.to_string()).into()
+    }
+}
+
+/// Ensure `len` is exactly `width * height` RGBA pixels
+pub fn check_rgba_dimensions(len: usize, width: u32, height: u32) -> Result<(), ImageError> {
+    if (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|n| n.checked_mul(4))
+        != Some(len)
+    {
+        return Err(ImageError::DimensionMismatch { len, width, height });
+    }
+    Ok(())
+}
+
+/// Ensure `len` holds whole pixels of `pixel_size` bytes
+pub fn check_pixel_length(len: usize, pixel_size: usize) -> Result<(), ImageError> {
+    if !len.is_multiple_of(pixel_size) {
+        return Err(ImageError::InvalidLength { len, pixel_size });
+    }
+    Ok(())
+}
+
+/// Ensure a caller-provided output buffer has exactly `expected` bytes
+pub fn check_output_length(actual: usize, expected: usize) -> Result<(), ImageError> {
+    if actual != expected {
+        return Err(ImageError::OutputLength { expected, actual });
+    }
+    Ok(())
+}
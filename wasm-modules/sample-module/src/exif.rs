@@ -0,0 +1,160 @@
+use image::codecs::jpeg::{JpegDecoder, JpegEncoder};
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder};
+use std::io::Cursor;
+use wasm_bindgen::prelude::*;
+
+use crate::error::ImageError;
+
+/// Quality used when pixels have to be re-encoded
+const JPEG_QUALITY: u8 = 90;
+
+/// Segments kept when stripping metadata: APP0 (JFIF), APP2 (ICC profile), APP14 (Adobe color transform)
+const KEPT_APP_MARKERS: [u8; 3] = [0xE0, 0xE2, 0xEE];
+
+fn codec_error(err: impl std::fmt::Display) -> ImageError {
+    ImageError::Codec(err.to_string())
+}
+
+/// Apply the EXIF orientation to the pixels and strip metadata
+/// Input: JPEG bytes (e.g. a phone photo)
+/// Output: JPEG bytes that display upright without EXIF (GPS, camera info, orientation)
+/// Already-upright images are stripped losslessly; rotated ones are re-encoded (quality 90)
+/// Errors when the input is not a decodable JPEG
+#[wasm_bindgen]
+pub fn normalize_orientation(jpeg: &[u8]) -> Result<Vec<u8>, ImageError> {
+    let mut decoder = JpegDecoder::new(Cursor::new(jpeg)).map_err(codec_error)?;
+    let orientation = decoder.orientation().map_err(codec_error)?;
+
+    if orientation == Orientation::NoTransforms {
+        return strip_metadata(jpeg);
+    }
+
+    let mut image = DynamicImage::from_decoder(decoder).map_err(codec_error)?;
+    image.apply_orientation(orientation);
+
+    let mut output = Vec::new();
+    JpegEncoder::new_with_quality(&mut output, JPEG_QUALITY)
+        .encode_image(&image)
+        .map_err(codec_error)?;
+    Ok(output)
+}
+
+/// Drop EXIF/XMP (APP1), IPTC (APP13), other APPn and comment segments without re-encoding
+fn strip_metadata(jpeg: &[u8]) -> Result<Vec<u8>, ImageError> {
+    let truncated = || ImageError::Codec("truncated JPEG segment".to_string());
+    if !jpeg.starts_with(&[0xFF, 0xD8]) {
+        return Err(ImageError::Codec("missing JPEG SOI marker".to_string()));
+    }
+
+    let mut output = Vec::with_capacity(jpeg.len());
+    output.extend_from_slice(&jpeg[..2]);
+    let mut pos = 2;
+
+    while pos + 4 <= jpeg.len() {
+        if jpeg[pos] != 0xFF {
+            return Err(ImageError::Codec(format!(
+                "expected marker at byte {}",
+                pos
+            )));
+        }
+        let marker = jpeg[pos + 1];
+        if marker == 0xDA {
+            // Start of scan: entropy-coded data follows, copy the rest as-is
+            break;
+        }
+
+        let length = u16::from_be_bytes([jpeg[pos + 2], jpeg[pos + 3]]) as usize;
+        let end = pos + 2 + length;
+        if length < 2 || end > jpeg.len() {
+            return Err(truncated());
+        }
+
+        let is_app = (0xE0..=0xEF).contains(&marker);
+        let strip = marker == 0xFE || (is_app && !KEPT_APP_MARKERS.contains(&marker));
+        if !strip {
+            output.extend_from_slice(&jpeg[pos..end]);
+        }
+        pos = end;
+    }
+
+    output.extend_from_slice(&jpeg[pos..]);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, Rgb, RgbImage};
+
+    /// 16x8 JPEG: red left half, blue right half
+    fn sample_jpeg() -> Vec<u8> {
+        let image = RgbImage::from_fn(16, 8, |x, _| {
+            if x < 8 {
+                Rgb([255, 0, 0])
+            } else {
+                Rgb([0, 0, 255])
+            }
+        });
+        let mut output = Vec::new();
+        JpegEncoder::new_with_quality(&mut output, 95)
+            .encode_image(&image)
+            .unwrap();
+        output
+    }
+
+    /// Insert an APP1 EXIF segment with the given orientation after SOI
+    fn with_orientation(jpeg: &[u8], orientation: u8) -> Vec<u8> {
+        let mut exif = b"Exif\0\0MM\0\x2a\0\0\0\x08".to_vec();
+        exif.extend_from_slice(&[0, 1]); // one IFD entry
+        exif.extend_from_slice(&[0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, orientation, 0, 0]);
+        exif.extend_from_slice(&[0, 0, 0, 0]); // no next IFD
+
+        let mut output = jpeg[..2].to_vec();
+        output.extend_from_slice(&[0xFF, 0xE1]);
+        output.extend_from_slice(&((exif.len() + 2) as u16).to_be_bytes());
+        output.extend_from_slice(&exif);
+        output.extend_from_slice(&jpeg[2..]);
+        output
+    }
+
+    fn orientation_of(jpeg: &[u8]) -> Orientation {
+        JpegDecoder::new(Cursor::new(jpeg))
+            .unwrap()
+            .orientation()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_rotates_and_strips_exif() {
+        let input = with_orientation(&sample_jpeg(), 6);
+        assert_eq!(orientation_of(&input), Orientation::Rotate90);
+
+        let output = normalize_orientation(&input).unwrap();
+        assert_eq!(orientation_of(&output), Orientation::NoTransforms);
+
+        // Rotated 90° clockwise: red half ends up on top
+        let image = image::load_from_memory(&output).unwrap();
+        assert_eq!(image.dimensions(), (8, 16));
+        let top = image.get_pixel(4, 2);
+        let bottom = image.get_pixel(4, 13);
+        assert!(top[0] > 200 && top[2] < 60, "top {:?}", top);
+        assert!(bottom[2] > 200 && bottom[0] < 60, "bottom {:?}", bottom);
+    }
+
+    #[test]
+    fn test_upright_image_is_stripped_losslessly() {
+        let jpeg = sample_jpeg();
+        let output = normalize_orientation(&with_orientation(&jpeg, 1)).unwrap();
+        assert_eq!(output, jpeg);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(matches!(
+            normalize_orientation(b"not a jpeg"),
+            Err(ImageError::Codec(_))
+        ));
+        assert!(strip_metadata(&[0xFF, 0xD8, 0xFF, 0xE1, 0x00, 0x40]).is_err());
+    }
+}
@@ -1,26 +1,109 @@
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
 
+mod buffer;
+mod error;
+mod exif;
+mod palette;
+mod phash;
+mod transform;
+
+use error::check_pixel_length;
+pub use buffer::{adjust_buffer, grayscale_buffer, transform_into, PixelBuffer};
+pub use error::ImageError;
+pub use exif::normalize_orientation;
+pub use palette::{extract_palette, PaletteColor};
+pub use phash::{hamming_distance, phash};
+pub use transform::{
+    flip_horizontal, flip_vertical, rotate180, rotate270, rotate90, RgbaImage, Transform,
+};
+
+/// Standard grayscale formula: 0.299R + 0.587G + 0.114B
+fn luma(pixel: &[u8]) -> u8 {
+    (0.299 * pixel[0] as f32 + 0.587 * pixel[1] as f32 + 0.114 * pixel[2] as f32) as u8
+}
+
 /// Simple image processing: grayscale conversion
 /// Input: RGB pixel data [r, g, b, r, g, b, ...]
 /// Output: Grayscale pixel data [gray, gray, ...]
+/// Errors when the input length is not a multiple of 3
+#[wasm_bindgen]
+pub fn rgb_to_grayscale(input: &[u8]) -> Result<Vec<u8>, ImageError> {
+    check_pixel_length(input.len(), 3)?;
+    Ok(input.chunks_exact(3).map(luma).collect())
+}
+
+/// RGBA-aware grayscale conversion
+/// Input: RGBA pixel data [r, g, b, a, ...]
+/// Output: RGBA pixel data [gray, gray, gray, a, ...] (alpha is preserved)
+/// Errors when the input length is not a multiple of 4
 #[wasm_bindgen]
-pub fn rgb_to_grayscale(input: &[u8]) -> Vec<u8> {
-    let mut output = Vec::with_capacity(input.len() / 3);
-
-    for chunk in input.chunks(3) {
-        if chunk.len() == 3 {
-            let r = chunk[0] as f32;
-            let g = chunk[1] as f32;
-            let b = chunk[2] as f32;
-
-            // Standard grayscale formula: 0.299R + 0.587G + 0.114B
-            let gray = (0.299 * r + 0.587 * g + 0.114 * b) as u8;
-            output.push(gray);
+pub fn rgba_to_grayscale(input: &[u8]) -> Result<Vec<u8>, ImageError> {
+    let mut output = input.to_vec();
+    grayscale_in_place(&mut output)?;
+    Ok(output)
+}
+
+/// RGBA grayscale conversion over a buffer (shared by the copying and zero-copy APIs)
+pub(crate) fn grayscale_in_place(rgba: &mut [u8]) -> Result<(), ImageError> {
+    check_pixel_length(rgba.len(), 4)?;
+    for pixel in rgba.chunks_exact_mut(4) {
+        let gray = luma(pixel);
+        pixel[..3].fill(gray);
+    }
+    Ok(())
+}
+
+/// Brightness / contrast / saturation adjustment in a single pass
+/// Input: RGBA pixel data [r, g, b, a, ...]
+/// Parameters: each in -1.0..=1.0 (0.0 = unchanged, out-of-range values are clamped)
+/// - brightness: -1.0 = black, 1.0 = white
+/// - contrast: -1.0 = flat gray, 1.0 = maximum contrast
+/// - saturation: -1.0 = grayscale, 1.0 = double saturation
+/// Output: adjusted RGBA pixel data (alpha is preserved)
+/// Errors when the input length is not a multiple of 4
+#[wasm_bindgen]
+pub fn adjust(
+    rgba: &[u8],
+    brightness: f32,
+    contrast: f32,
+    saturation: f32,
+) -> Result<Vec<u8>, ImageError> {
+    let mut output = rgba.to_vec();
+    adjust_in_place(&mut output, brightness, contrast, saturation)?;
+    Ok(output)
+}
+
+/// Adjustment over a buffer (shared by the copying and zero-copy APIs)
+pub(crate) fn adjust_in_place(
+    rgba: &mut [u8],
+    brightness: f32,
+    contrast: f32,
+    saturation: f32,
+) -> Result<(), ImageError> {
+    check_pixel_length(rgba.len(), 4)?;
+
+    let brightness = brightness.clamp(-1.0, 1.0) * 255.0;
+    // Standard contrast correction factor, mapped from -1..1 to -255..255
+    let c = contrast.clamp(-1.0, 1.0) * 255.0;
+    let contrast_factor = (259.0 * (c + 255.0)) / (255.0 * (259.0 - c));
+    let saturation_factor = 1.0 + saturation.clamp(-1.0, 1.0);
+
+    for pixel in rgba.chunks_exact_mut(4) {
+        let mut rgb = [pixel[0] as f32, pixel[1] as f32, pixel[2] as f32];
+
+        for channel in rgb.iter_mut() {
+            *channel = (*channel + brightness - 128.0) * contrast_factor + 128.0;
+        }
+
+        let luma = 0.299 * rgb[0] + 0.587 * rgb[1] + 0.114 * rgb[2];
+        for (index, channel) in rgb.iter().enumerate() {
+            let value = luma + (channel - luma) * saturation_factor;
+            pixel[index] = value.round().clamp(0.0, 255.0) as u8;
         }
     }
 
-    output
+    Ok(())
 }
 
 /// Array sum - simple demonstration function
@@ -79,10 +162,65 @@ mod tests {
     #[test]
     fn test_rgb_to_grayscale() {
         let rgb = vec![255, 0, 0, 0, 255, 0, 0, 0, 255];
-        let gray = rgb_to_grayscale(&rgb);
+        let gray = rgb_to_grayscale(&rgb).unwrap();
         assert_eq!(gray.len(), 3);
     }
 
+    #[test]
+    fn test_rgb_to_grayscale_rejects_partial_pixel() {
+        assert_eq!(
+            rgb_to_grayscale(&[255, 0, 0, 0]),
+            Err(ImageError::InvalidLength {
+                len: 4,
+                pixel_size: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_rgba_to_grayscale() {
+        let rgba = vec![255, 0, 0, 128, 255, 255, 255, 0];
+        assert_eq!(
+            rgba_to_grayscale(&rgba).unwrap(),
+            vec![76, 76, 76, 128, 255, 255, 255, 0]
+        );
+        assert!(rgba_to_grayscale(&rgba[..7]).is_err());
+    }
+
+    #[test]
+    fn test_adjust_identity() {
+        let rgba = vec![12, 128, 250, 255, 0, 0, 0, 0, 255, 255, 255, 10];
+        assert_eq!(adjust(&rgba, 0.0, 0.0, 0.0).unwrap(), rgba);
+        assert!(adjust(&rgba[..5], 0.0, 0.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_adjust_brightness_bounds() {
+        let rgba = vec![12, 128, 250, 200];
+        assert_eq!(adjust(&rgba, 1.0, 0.0, 0.0).unwrap(), vec![255, 255, 255, 200]);
+        assert_eq!(adjust(&rgba, -1.0, 0.0, 0.0).unwrap(), vec![0, 0, 0, 200]);
+        // Out-of-range parameters are clamped
+        assert_eq!(adjust(&rgba, 5.0, 0.0, 0.0).unwrap(), vec![255, 255, 255, 200]);
+    }
+
+    #[test]
+    fn test_adjust_contrast_bounds() {
+        let rgba = vec![0, 100, 255, 255];
+        assert_eq!(adjust(&rgba, 0.0, -1.0, 0.0).unwrap(), vec![128, 128, 128, 255]);
+        assert_eq!(adjust(&rgba, 0.0, 1.0, 0.0).unwrap(), vec![0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn test_adjust_saturation_bounds() {
+        let gray = adjust(&[200, 50, 10, 255], 0.0, 0.0, -1.0).unwrap();
+        assert_eq!(gray[0], gray[1]);
+        assert_eq!(gray[1], gray[2]);
+        assert_eq!(gray[3], 255);
+
+        let vivid = adjust(&[200, 50, 10, 255], 0.0, 0.0, 1.0).unwrap();
+        assert_eq!(vivid, vec![255, 10, 0, 255]);
+    }
+
     #[test]
     fn test_sum_array() {
         assert_eq!(sum_array(&[1, 2, 3, 4, 5]), 15);
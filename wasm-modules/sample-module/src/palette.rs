@@ -0,0 +1,174 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::{check_pixel_length, ImageError};
+
+/// Pixels with lower alpha are ignored (mostly transparent)
+const MIN_ALPHA: u8 = 128;
+/// Larger images are sampled down to about this many pixels
+const MAX_SAMPLES: usize = 65_536;
+const MAX_COLORS: usize = 64;
+
+/// Dominant color with its share of the (opaque) image
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaletteColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// Fraction of sampled pixels in this color's bucket (0.0 - 1.0)
+    pub proportion: f32,
+}
+
+#[wasm_bindgen]
+impl PaletteColor {
+    /// CSS hex notation (`#rrggbb`)
+    pub fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Median-cut bucket of pixels
+struct ColorBox {
+    pixels: Vec<[u8; 3]>,
+}
+
+impl ColorBox {
+    /// Channel with the widest value range and that range
+    fn widest_channel(&self) -> (usize, u8) {
+        (0..3)
+            .map(|channel| {
+                let (min, max) = self
+                    .pixels
+                    .iter()
+                    .fold((u8::MAX, u8::MIN), |(min, max), p| {
+                        (min.min(p[channel]), max.max(p[channel]))
+                    });
+                (channel, max.saturating_sub(min))
+            })
+            .max_by_key(|&(_, range)| range)
+            .unwrap_or((0, 0))
+    }
+
+    fn split(mut self) -> (ColorBox, ColorBox) {
+        let (channel, _) = self.widest_channel();
+        self.pixels.sort_unstable_by_key(|p| p[channel]);
+
+        // Cut at the value boundary closest to the median so identical colors stay together
+        let median = self.pixels.len() / 2;
+        let at = (1..self.pixels.len())
+            .filter(|&i| self.pixels[i - 1][channel] != self.pixels[i][channel])
+            .min_by_key(|&i| i.abs_diff(median))
+            .unwrap_or(median);
+        let upper = self.pixels.split_off(at);
+        (self, ColorBox { pixels: upper })
+    }
+
+    fn average(&self) -> [u8; 3] {
+        let mut sum = [0u64; 3];
+        for pixel in &self.pixels {
+            for channel in 0..3 {
+                sum[channel] += pixel[channel] as u64;
+            }
+        }
+        let count = self.pixels.len().max(1) as u64;
+        sum.map(|total| ((total + count / 2) / count) as u8)
+    }
+}
+
+/// Dominant colors via median cut
+/// Input: RGBA pixel data, number of colors `k` (1 - 64)
+/// Output: up to `k` colors sorted by proportion (descending); transparent pixels are ignored
+/// Errors when the input length is not a multiple of 4
+#[wasm_bindgen]
+pub fn extract_palette(rgba: &[u8], k: usize) -> Result<Vec<PaletteColor>, ImageError> {
+    check_pixel_length(rgba.len(), 4)?;
+
+    let k = k.min(MAX_COLORS);
+    let step = (rgba.len() / 4).div_ceil(MAX_SAMPLES).max(1);
+    let pixels: Vec<[u8; 3]> = rgba
+        .chunks_exact(4)
+        .step_by(step)
+        .filter(|p| p[3] >= MIN_ALPHA)
+        .map(|p| [p[0], p[1], p[2]])
+        .collect();
+    if pixels.is_empty() || k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let total = pixels.len() as f32;
+    let mut boxes = vec![ColorBox { pixels }];
+    while boxes.len() < k {
+        // Split the box with the widest channel range; stop when every box is a single color
+        let Some((index, _)) = boxes
+            .iter()
+            .enumerate()
+            .map(|(index, b)| (index, b.widest_channel().1))
+            .filter(|&(_, range)| range > 0)
+            .max_by_key(|&(_, range)| range)
+        else {
+            break;
+        };
+
+        let (lower, upper) = boxes.swap_remove(index).split();
+        boxes.push(lower);
+        boxes.push(upper);
+    }
+
+    let mut palette: Vec<PaletteColor> = boxes
+        .iter()
+        .map(|color_box| {
+            let [r, g, b] = color_box.average();
+            PaletteColor {
+                r,
+                g,
+                b,
+                proportion: color_box.pixels.len() as f32 / total,
+            }
+        })
+        .collect();
+    palette.sort_by(|a, b| b.proportion.total_cmp(&a.proportion));
+    Ok(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(pixels: &[([u8; 4], usize)]) -> Vec<u8> {
+        pixels
+            .iter()
+            .flat_map(|(pixel, count)| std::iter::repeat_n(*pixel, *count).flatten())
+            .collect()
+    }
+
+    #[test]
+    fn test_single_color() {
+        let palette = extract_palette(&image(&[([10, 20, 30, 255], 16)]), 4).unwrap();
+        assert_eq!(palette.len(), 1);
+        assert_eq!(palette[0].hex(), "#0a141e");
+        assert_eq!(palette[0].proportion, 1.0);
+    }
+
+    #[test]
+    fn test_proportions_sorted() {
+        let rgba = image(&[([255, 0, 0, 255], 12), ([0, 0, 255, 255], 4)]);
+        let palette = extract_palette(&rgba, 2).unwrap();
+        assert_eq!(palette.len(), 2);
+        assert_eq!((palette[0].r, palette[0].b), (255, 0));
+        assert_eq!(palette[0].proportion, 0.75);
+        assert_eq!((palette[1].r, palette[1].b), (0, 255));
+        assert_eq!(palette[1].proportion, 0.25);
+    }
+
+    #[test]
+    fn test_ignores_transparent_and_edge_cases() {
+        let rgba = image(&[([255, 255, 255, 0], 8), ([0, 128, 0, 255], 2)]);
+        let palette = extract_palette(&rgba, 3).unwrap();
+        assert_eq!(palette.len(), 1);
+        assert_eq!(palette[0].g, 128);
+
+        assert!(extract_palette(&rgba, 0).unwrap().is_empty());
+        assert!(extract_palette(&[], 3).unwrap().is_empty());
+        assert!(extract_palette(&rgba[..3], 3).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+use std::f32::consts::PI;
+use wasm_bindgen::prelude::*;
+
+use crate::error::{check_rgba_dimensions, ImageError};
+
+/// Images are reduced to SIZE x SIZE grayscale before the DCT
+const SIZE: usize = 32;
+/// Low-frequency DCT block used for the hash (HASH_SIZE^2 = 64 bits)
+const HASH_SIZE: usize = 8;
+
+/// Area-average downscale (nearest sample for images smaller than SIZE) to SIZE x SIZE luma
+fn reduce(rgba: &[u8], width: usize, height: usize) -> Vec<f32> {
+    let mut output = vec![0.0; SIZE * SIZE];
+    for ty in 0..SIZE {
+        let y0 = ty * height / SIZE;
+        let y1 = ((ty + 1) * height / SIZE).max(y0 + 1);
+        for tx in 0..SIZE {
+            let x0 = tx * width / SIZE;
+            let x1 = ((tx + 1) * width / SIZE).max(x0 + 1);
+
+            let mut sum = 0.0;
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    let p = &rgba[(y * width + x) * 4..];
+                    sum += 0.299 * p[0] as f32 + 0.587 * p[1] as f32 + 0.114 * p[2] as f32;
+                }
+            }
+            output[ty * SIZE + tx] = sum / ((y1 - y0) * (x1 - x0)) as f32;
+        }
+    }
+    output
+}
+
+/// Top-left HASH_SIZE x HASH_SIZE coefficients of the 2D DCT-II
+fn low_frequency_dct(pixels: &[f32]) -> Vec<f32> {
+    let cos: Vec<f32> = (0..HASH_SIZE * SIZE)
+        .map(|i| {
+            let (u, x) = (i / SIZE, i % SIZE);
+            ((2 * x + 1) as f32 * u as f32 * PI / (2 * SIZE) as f32).cos()
+        })
+        .collect();
+
+    // Rows first, then columns (separable)
+    let mut rows = vec![0.0; SIZE * HASH_SIZE];
+    for y in 0..SIZE {
+        for u in 0..HASH_SIZE {
+            rows[y * HASH_SIZE + u] = (0..SIZE)
+                .map(|x| pixels[y * SIZE + x] * cos[u * SIZE + x])
+                .sum();
+        }
+    }
+
+    let mut coefficients = vec![0.0; HASH_SIZE * HASH_SIZE];
+    for v in 0..HASH_SIZE {
+        for u in 0..HASH_SIZE {
+            coefficients[v * HASH_SIZE + u] = (0..SIZE)
+                .map(|y| rows[y * HASH_SIZE + u] * cos[v * SIZE + y])
+                .sum();
+        }
+    }
+    coefficients
+}
+
+/// 64-bit perceptual hash (DCT-based pHash)
+/// Input: RGBA pixel data, width, height
+/// Output: hash as u64 (BigInt in JavaScript); compare with `hamming_distance`
+/// Errors when the buffer does not match the dimensions or the image is empty
+#[wasm_bindgen]
+pub fn phash(rgba: &[u8], width: u32, height: u32) -> Result<u64, ImageError> {
+    check_rgba_dimensions(rgba.len(), width, height)?;
+    if rgba.is_empty() {
+        return Err(ImageError::DimensionMismatch {
+            len: 0,
+            width,
+            height,
+        });
+    }
+
+    let coefficients = low_frequency_dct(&reduce(rgba, width as usize, height as usize));
+
+    // Median of the AC coefficients (the DC term only encodes average brightness)
+    let mut ac: Vec<f32> = coefficients[1..].to_vec();
+    ac.sort_by(f32::total_cmp);
+    let median = ac[ac.len() / 2];
+
+    Ok(coefficients
+        .iter()
+        .enumerate()
+        .filter(|&(_, &c)| c > median)
+        .fold(0u64, |hash, (bit, _)| hash | (1 << bit)))
+}
+
+/// Number of differing bits between two hashes (0 = identical, <= ~10 = near-duplicate)
+#[wasm_bindgen]
+pub fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Diagonal gradient with a bright square, optionally brightened
+    fn sample(width: u32, height: u32, offset: u8) -> Vec<u8> {
+        let mut rgba = Vec::with_capacity((width * height * 4) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let in_square = x > width / 2 && y < height / 3;
+                let base = if in_square {
+                    230
+                } else {
+                    ((x + y) * 200 / (width + height)) as u8
+                };
+                let value = base.saturating_add(offset);
+                rgba.extend_from_slice(&[value, value / 2, 255 - value, 255]);
+            }
+        }
+        rgba
+    }
+
+    #[test]
+    fn test_identical_and_similar_images() {
+        let hash = phash(&sample(64, 48, 0), 64, 48).unwrap();
+        assert_eq!(
+            hamming_distance(hash, phash(&sample(64, 48, 0), 64, 48).unwrap()),
+            0
+        );
+
+        // Brightness change and rescale keep the hash close
+        let brighter = phash(&sample(64, 48, 15), 64, 48).unwrap();
+        assert!(hamming_distance(hash, brighter) <= 10);
+        let resized = phash(&sample(128, 96, 0), 128, 96).unwrap();
+        assert!(hamming_distance(hash, resized) <= 10);
+    }
+
+    #[test]
+    fn test_different_images() {
+        let rgba = sample(64, 48, 0);
+        let flipped = crate::flip_horizontal(&rgba, 64, 48).unwrap();
+        let a = phash(&rgba, 64, 48).unwrap();
+        let b = phash(&flipped, 64, 48).unwrap();
+        assert!(hamming_distance(a, b) > 20);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(phash(&[0; 12], 2, 2).is_err());
+        assert!(phash(&[], 0, 0).is_err());
+        // Images smaller than the reduction size still hash
+        assert!(phash(&sample(3, 2, 0), 3, 2).is_ok());
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        assert_eq!(hamming_distance(0, 0), 0);
+        assert_eq!(hamming_distance(0b1011, 0b0001), 2);
+        assert_eq!(hamming_distance(u64::MAX, 0), 64);
+    }
+}
@@ -0,0 +1,198 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::{check_output_length, check_rgba_dimensions, ImageError};
+
+/// RGBA buffer with its dimensions, returned when an operation changes them
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl RgbaImage {
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Pixel data (copied into a new Uint8Array on each access)
+    #[wasm_bindgen(getter)]
+    pub fn data(&self) -> Vec<u8> {
+        self.data.clone()
+    }
+}
+
+/// Rotation / flip operation
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transform {
+    /// 90° clockwise (swaps width and height)
+    Rotate90,
+    Rotate180,
+    /// 270° clockwise (swaps width and height)
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+}
+
+impl Transform {
+    /// Output dimensions for a `width x height` input
+    pub fn dimensions(self, width: u32, height: u32) -> (u32, u32) {
+        match self {
+            Transform::Rotate90 | Transform::Rotate270 => (height, width),
+            _ => (width, height),
+        }
+    }
+
+    /// Destination pixel index of source pixel `(x, y)`
+    fn target(self, x: usize, y: usize, w: usize, h: usize) -> usize {
+        match self {
+            Transform::Rotate90 => x * h + (h - 1 - y),
+            Transform::Rotate180 => (h - 1 - y) * w + (w - 1 - x),
+            Transform::Rotate270 => (w - 1 - x) * h + y,
+            Transform::FlipHorizontal => y * w + (w - 1 - x),
+            Transform::FlipVertical => (h - 1 - y) * w + x,
+        }
+    }
+
+    /// Write the transformed pixels into `output` (same length as `input`)
+    pub fn apply_into(
+        self,
+        input: &[u8],
+        output: &mut [u8],
+        width: u32,
+        height: u32,
+    ) -> Result<(), ImageError> {
+        check_rgba_dimensions(input.len(), width, height)?;
+        check_output_length(output.len(), input.len())?;
+
+        let (w, h) = (width as usize, height as usize);
+        for (y, row) in input.chunks_exact((w * 4).max(1)).enumerate() {
+            for (x, pixel) in row.chunks_exact(4).enumerate() {
+                let dst = self.target(x, y, w, h) * 4;
+                output[dst..dst + 4].copy_from_slice(pixel);
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(self, input: &[u8], width: u32, height: u32) -> Result<RgbaImage, ImageError> {
+        let mut data = vec![0; input.len()];
+        self.apply_into(input, &mut data, width, height)?;
+        let (width, height) = self.dimensions(width, height);
+        Ok(RgbaImage {
+            width,
+            height,
+            data,
+        })
+    }
+}
+
+/// Rotate 90° clockwise
+/// Input: RGBA pixel data, width, height
+/// Output: RgbaImage with swapped dimensions (height x width)
+#[wasm_bindgen]
+pub fn rotate90(rgba: &[u8], width: u32, height: u32) -> Result<RgbaImage, ImageError> {
+    Transform::Rotate90.apply(rgba, width, height)
+}
+
+/// Rotate 180°
+/// Output: RGBA pixel data (dimensions unchanged)
+#[wasm_bindgen]
+pub fn rotate180(rgba: &[u8], width: u32, height: u32) -> Result<Vec<u8>, ImageError> {
+    Ok(Transform::Rotate180.apply(rgba, width, height)?.data)
+}
+
+/// Rotate 270° clockwise (90° counter-clockwise)
+/// Output: RgbaImage with swapped dimensions (height x width)
+#[wasm_bindgen]
+pub fn rotate270(rgba: &[u8], width: u32, height: u32) -> Result<RgbaImage, ImageError> {
+    Transform::Rotate270.apply(rgba, width, height)
+}
+
+/// Mirror left-to-right
+/// Output: RGBA pixel data (dimensions unchanged)
+#[wasm_bindgen]
+pub fn flip_horizontal(rgba: &[u8], width: u32, height: u32) -> Result<Vec<u8>, ImageError> {
+    Ok(Transform::FlipHorizontal.apply(rgba, width, height)?.data)
+}
+
+/// Mirror top-to-bottom
+/// Output: RGBA pixel data (dimensions unchanged)
+#[wasm_bindgen]
+pub fn flip_vertical(rgba: &[u8], width: u32, height: u32) -> Result<Vec<u8>, ImageError> {
+    Ok(Transform::FlipVertical.apply(rgba, width, height)?.data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3x2 image whose pixels are numbered 1..=6 (row-major), alpha = 255
+    fn numbered() -> Vec<u8> {
+        (1..=6).flat_map(|n| [n, n, n, 255]).collect()
+    }
+
+    fn ids(rgba: &[u8]) -> Vec<u8> {
+        rgba.chunks_exact(4).map(|p| p[0]).collect()
+    }
+
+    #[test]
+    fn test_rotate90() {
+        // 1 2 3      4 1
+        // 4 5 6  ->  5 2
+        //            6 3
+        let rotated = rotate90(&numbered(), 3, 2).unwrap();
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+        assert_eq!(ids(&rotated.data()), vec![4, 1, 5, 2, 6, 3]);
+    }
+
+    #[test]
+    fn test_rotate180() {
+        let rotated = rotate180(&numbered(), 3, 2).unwrap();
+        assert_eq!(ids(&rotated), vec![6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_rotate270() {
+        // 1 2 3      3 6
+        // 4 5 6  ->  2 5
+        //            1 4
+        let rotated = rotate270(&numbered(), 3, 2).unwrap();
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+        assert_eq!(ids(&rotated.data()), vec![3, 6, 2, 5, 1, 4]);
+    }
+
+    #[test]
+    fn test_flips() {
+        assert_eq!(
+            ids(&flip_horizontal(&numbered(), 3, 2).unwrap()),
+            vec![3, 2, 1, 6, 5, 4]
+        );
+        assert_eq!(
+            ids(&flip_vertical(&numbered(), 3, 2).unwrap()),
+            vec![4, 5, 6, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        assert_eq!(
+            rotate90(&numbered(), 2, 2),
+            Err(ImageError::DimensionMismatch {
+                len: 24,
+                width: 2,
+                height: 2
+            })
+        );
+        assert!(flip_vertical(&[], 0, 0).unwrap().is_empty());
+    }
+}